@@ -1,47 +1,132 @@
 //! 加密模块
 //!
 //! 提供 AES-256-GCM 加密/解密功能，用于账户导入导出的加密保护。
+//! 密钥派生支持 PBKDF2-HMAC-SHA256（Version 1-3）与 Argon2id（Version 4 起）。
 
 mod versions;
 
-pub use versions::{get_current_iterations, get_pbkdf2_iterations, CURRENT_FILE_VERSION};
+pub use versions::{
+    get_current_kdf, get_kdf_params, get_pbkdf2_iterations, uses_header_aad, CURRENT_FILE_VERSION,
+};
 
 use aes_gcm::{
-    aead::{Aead, KeyInit},
+    aead::{Aead, KeyInit, Payload},
     Aes256Gcm, Nonce,
 };
+use argon2::{Algorithm, Argon2, Params, Version};
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
 use pbkdf2::pbkdf2_hmac_array;
 use rand::RngCore;
+use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 
 use crate::error::{CoreError, CoreResult};
 
-// 从版本管理自动获取当前版本的迭代次数
-const PBKDF2_ITERATIONS: u32 = get_current_iterations();
+// 从版本管理自动获取当前版本的 KDF 参数
+const CURRENT_KDF: KdfParams = get_current_kdf();
 const SALT_LENGTH: usize = 16;
 const NONCE_LENGTH: usize = 12;
 const KEY_LENGTH: usize = 32; // AES-256
 
-/// 从密码派生加密密钥（支持自定义迭代次数）
-fn derive_key_with_iterations(password: &str, salt: &[u8], iterations: u32) -> [u8; KEY_LENGTH] {
-    pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(password.as_bytes(), salt, iterations)
+/// PBKDF2 迭代次数上限（防止文件头中的参数造成长时间计算）
+const MAX_PBKDF2_ITERATIONS: u32 = 10_000_000;
+/// Argon2id 内存上限（KiB）
+const MAX_ARGON2_MEMORY_KIB: u32 = 1024 * 1024;
+/// Argon2id 迭代次数上限
+const MAX_ARGON2_ITERATIONS: u32 = 64;
+/// Argon2id 并行度上限
+const MAX_ARGON2_PARALLELISM: u32 = 16;
+
+/// 密钥派生算法及参数（记录在导出文件头中）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "algorithm")]
+pub enum KdfParams {
+    /// PBKDF2-HMAC-SHA256
+    #[serde(rename = "pbkdf2-sha256")]
+    Pbkdf2 {
+        /// 迭代次数
+        iterations: u32,
+    },
+    /// Argon2id（RFC 9106）
+    #[serde(rename = "argon2id", rename_all = "camelCase")]
+    Argon2id {
+        /// 内存大小（KiB）
+        memory_kib: u32,
+        /// 迭代次数
+        iterations: u32,
+        /// 并行度
+        parallelism: u32,
+    },
+}
+
+impl KdfParams {
+    /// 从密码派生 AES-256 密钥；参数超出允许范围时返回错误
+    fn derive_key(&self, password: &str, salt: &[u8]) -> CoreResult<[u8; KEY_LENGTH]> {
+        match *self {
+            Self::Pbkdf2 { iterations } => {
+                if iterations == 0 || iterations > MAX_PBKDF2_ITERATIONS {
+                    return Err(invalid_kdf(self));
+                }
+                Ok(pbkdf2_hmac_array::<Sha256, KEY_LENGTH>(
+                    password.as_bytes(),
+                    salt,
+                    iterations,
+                ))
+            }
+            Self::Argon2id {
+                memory_kib,
+                iterations,
+                parallelism,
+            } => {
+                if memory_kib > MAX_ARGON2_MEMORY_KIB
+                    || iterations > MAX_ARGON2_ITERATIONS
+                    || parallelism > MAX_ARGON2_PARALLELISM
+                {
+                    return Err(invalid_kdf(self));
+                }
+                let params = Params::new(memory_kib, iterations, parallelism, Some(KEY_LENGTH))
+                    .map_err(|_| invalid_kdf(self))?;
+                let mut key = [0u8; KEY_LENGTH];
+                Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+                    .hash_password_into(password.as_bytes(), salt, &mut key)
+                    .map_err(|_| invalid_kdf(self))?;
+                Ok(key)
+            }
+        }
+    }
 }
 
-/// 从密码派生加密密钥（使用默认迭代次数）
-fn derive_key(password: &str, salt: &[u8]) -> [u8; KEY_LENGTH] {
-    derive_key_with_iterations(password, salt, PBKDF2_ITERATIONS)
+fn invalid_kdf(kdf: &KdfParams) -> CoreError {
+    CoreError::SerializationError(format!("Invalid KDF parameters: {kdf:?}"))
 }
 
-/// 加密数据
+/// 加密数据（使用当前版本的 KDF 参数）
 ///
 /// # Arguments
 /// * `plaintext` - 要加密的明文数据
 /// * `password` - 加密密码
+/// * `aad` - 附加认证数据（不加密但参与认证，解密时必须完全一致；传空切片表示不绑定）
+///
+/// # Returns
+/// 返回 (`salt_base64`, `nonce_base64`, `ciphertext_base64`) 元组
+pub fn encrypt(
+    plaintext: &[u8],
+    password: &str,
+    aad: &[u8],
+) -> CoreResult<(String, String, String)> {
+    encrypt_with_kdf(plaintext, password, aad, &CURRENT_KDF)
+}
+
+/// 使用指定 KDF 参数加密数据
 ///
 /// # Returns
 /// 返回 (`salt_base64`, `nonce_base64`, `ciphertext_base64`) 元组
-pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String, String)> {
+pub fn encrypt_with_kdf(
+    plaintext: &[u8],
+    password: &str,
+    aad: &[u8],
+    kdf: &KdfParams,
+) -> CoreResult<(String, String, String)> {
     // 生成随机盐和 nonce
     let mut salt = [0u8; SALT_LENGTH];
     let mut nonce_bytes = [0u8; NONCE_LENGTH];
@@ -49,7 +134,7 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
     rand::rng().fill_bytes(&mut nonce_bytes);
 
     // 派生密钥
-    let key = derive_key(password, &salt);
+    let key = kdf.derive_key(password, &salt)?;
 
     // 创建加密器
     let cipher = Aes256Gcm::new_from_slice(&key)
@@ -58,7 +143,13 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
 
     // 加密
     let ciphertext = cipher
-        .encrypt(nonce, plaintext)
+        .encrypt(
+            nonce,
+            Payload {
+                msg: plaintext,
+                aad,
+            },
+        )
         .map_err(|e| CoreError::SerializationError(format!("Encryption failed: {e}")))?;
 
     Ok((
@@ -68,13 +159,14 @@ pub fn encrypt(plaintext: &[u8], password: &str) -> CoreResult<(String, String,
     ))
 }
 
-/// 解密数据
+/// 解密数据（使用当前版本的 KDF 参数）
 ///
 /// # Arguments
 /// * `ciphertext_b64` - Base64 编码的密文
 /// * `password` - 解密密码
 /// * `salt_b64` - Base64 编码的盐值
 /// * `nonce_b64` - Base64 编码的 nonce
+/// * `aad` - 附加认证数据（必须与加密时一致）
 ///
 /// # Returns
 /// 返回解密后的明文数据
@@ -83,17 +175,19 @@ pub fn decrypt(
     password: &str,
     salt_b64: &str,
     nonce_b64: &str,
+    aad: &[u8],
 ) -> CoreResult<Vec<u8>> {
-    decrypt_with_iterations(
+    decrypt_with_kdf(
         ciphertext_b64,
         password,
         salt_b64,
         nonce_b64,
-        PBKDF2_ITERATIONS,
+        &CURRENT_KDF,
+        aad,
     )
 }
 
-/// 使用自定义迭代次数解密数据（用于向后兼容）
+/// 使用 PBKDF2 自定义迭代次数解密数据（用于向后兼容）
 ///
 /// # Arguments
 /// * `ciphertext_b64` - Base64 编码的密文
@@ -101,6 +195,7 @@ pub fn decrypt(
 /// * `salt_b64` - Base64 编码的盐值
 /// * `nonce_b64` - Base64 编码的 nonce
 /// * `iterations` - PBKDF2 迭代次数
+/// * `aad` - 附加认证数据（必须与加密时一致，旧版本文件传空切片）
 ///
 /// # Returns
 /// 返回解密后的明文数据
@@ -110,6 +205,34 @@ pub fn decrypt_with_iterations(
     salt_b64: &str,
     nonce_b64: &str,
     iterations: u32,
+    aad: &[u8],
+) -> CoreResult<Vec<u8>> {
+    decrypt_with_kdf(
+        ciphertext_b64,
+        password,
+        salt_b64,
+        nonce_b64,
+        &KdfParams::Pbkdf2 { iterations },
+        aad,
+    )
+}
+
+/// 使用指定 KDF 参数解密数据
+///
+/// # Arguments
+/// * `ciphertext_b64` - Base64 编码的密文
+/// * `password` - 解密密码
+/// * `salt_b64` - Base64 编码的盐值
+/// * `nonce_b64` - Base64 编码的 nonce
+/// * `kdf` - 加密时使用的 KDF 参数
+/// * `aad` - 附加认证数据（必须与加密时一致）
+pub fn decrypt_with_kdf(
+    ciphertext_b64: &str,
+    password: &str,
+    salt_b64: &str,
+    nonce_b64: &str,
+    kdf: &KdfParams,
+    aad: &[u8],
 ) -> CoreResult<Vec<u8>> {
     // 解码 Base64
     let salt = BASE64
@@ -122,8 +245,8 @@ pub fn decrypt_with_iterations(
         .decode(ciphertext_b64)
         .map_err(|e| CoreError::SerializationError(format!("Invalid ciphertext: {e}")))?;
 
-    // 使用指定迭代次数派生密钥
-    let key = derive_key_with_iterations(password, &salt, iterations);
+    // 使用指定参数派生密钥
+    let key = kdf.derive_key(password, &salt)?;
 
     // 创建解密器
     let cipher = Aes256Gcm::new_from_slice(&key)
@@ -131,9 +254,146 @@ pub fn decrypt_with_iterations(
     let nonce = Nonce::from_slice(&nonce_bytes);
 
     // 解密
-    cipher.decrypt(nonce, ciphertext.as_ref()).map_err(|_| {
+    let payload = Payload {
+        msg: ciphertext.as_ref(),
+        aad,
+    };
+    cipher.decrypt(nonce, payload).map_err(|_| {
         CoreError::SerializationError(
             "Decryption failed: invalid password or corrupted data".to_string(),
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FAST_PBKDF2: KdfParams = KdfParams::Pbkdf2 { iterations: 1_000 };
+    const FAST_ARGON2ID: KdfParams = KdfParams::Argon2id {
+        memory_kib: 64,
+        iterations: 1,
+        parallelism: 2,
+    };
+
+    fn round_trip(kdf: &KdfParams) {
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"hello", "secret", b"aad", kdf).unwrap();
+
+        let plaintext =
+            decrypt_with_kdf(&ciphertext, "secret", &salt, &nonce, kdf, b"aad").unwrap();
+        assert_eq!(plaintext, b"hello");
+
+        assert!(decrypt_with_kdf(&ciphertext, "wrong", &salt, &nonce, kdf, b"aad").is_err());
+        assert!(decrypt_with_kdf(&ciphertext, "secret", &salt, &nonce, kdf, b"other").is_err());
+    }
+
+    #[test]
+    fn test_pbkdf2_round_trip() {
+        round_trip(&FAST_PBKDF2);
+    }
+
+    #[test]
+    fn test_argon2id_round_trip() {
+        round_trip(&FAST_ARGON2ID);
+    }
+
+    #[test]
+    fn test_kdf_mismatch_fails() {
+        let (salt, nonce, ciphertext) =
+            encrypt_with_kdf(b"hello", "secret", &[], &FAST_ARGON2ID).unwrap();
+        assert!(decrypt_with_kdf(&ciphertext, "secret", &salt, &nonce, &FAST_PBKDF2, &[]).is_err());
+    }
+
+    #[test]
+    fn test_legacy_iterations_still_decrypt() {
+        let kdf = KdfParams::Pbkdf2 {
+            iterations: get_pbkdf2_iterations(1).unwrap(),
+        };
+        let (salt, nonce, ciphertext) = encrypt_with_kdf(b"v1", "secret", &[], &kdf).unwrap();
+        let plaintext = decrypt_with_iterations(
+            &ciphertext,
+            "secret",
+            &salt,
+            &nonce,
+            get_pbkdf2_iterations(1).unwrap(),
+            &[],
+        )
+        .unwrap();
+        assert_eq!(plaintext, b"v1");
+    }
+
+    #[test]
+    fn test_argon2id_rfc9106_vector() {
+        // RFC 9106 5.3：Argon2id 测试向量（含 secret 与关联数据）
+        let params = argon2::ParamsBuilder::new()
+            .m_cost(32)
+            .t_cost(3)
+            .p_cost(4)
+            .data(argon2::AssociatedData::new(&[0x04; 12]).unwrap())
+            .output_len(32)
+            .build()
+            .unwrap();
+        let argon2 =
+            Argon2::new_with_secret(&[0x03; 8], Algorithm::Argon2id, Version::V0x13, params)
+                .unwrap();
+        let mut out = [0u8; 32];
+        argon2
+            .hash_password_into(&[0x01; 32], &[0x02; 16], &mut out)
+            .unwrap();
+        assert_eq!(
+            out,
+            [
+                0x0d, 0x64, 0x0d, 0xf5, 0x8d, 0x78, 0x76, 0x6c, 0x08, 0xc0, 0x37, 0xa3, 0x4a, 0x8b,
+                0x53, 0xc9, 0xd0, 0x1e, 0xf0, 0x45, 0x2d, 0x75, 0xb6, 0x5e, 0xb5, 0x25, 0x20, 0xe9,
+                0x6b, 0x01, 0xe6, 0x59,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_kdf() {
+        let huge = KdfParams::Argon2id {
+            memory_kib: MAX_ARGON2_MEMORY_KIB + 1,
+            iterations: 1,
+            parallelism: 1,
+        };
+        assert!(encrypt_with_kdf(b"x", "secret", &[], &huge).is_err());
+        // 低于 Argon2 要求的下限（内存 < 8 × 并行度、无 lane）
+        for (memory_kib, parallelism) in [(4, 1), (64, 0)] {
+            let invalid = KdfParams::Argon2id {
+                memory_kib,
+                iterations: 1,
+                parallelism,
+            };
+            assert!(encrypt_with_kdf(b"x", "secret", &[], &invalid).is_err());
+        }
+        assert!(
+            encrypt_with_kdf(b"x", "secret", &[], &KdfParams::Pbkdf2 { iterations: 0 }).is_err()
+        );
+    }
+
+    #[test]
+    fn test_kdf_params_serialization() {
+        let json = serde_json::to_value(get_kdf_params(4).unwrap()).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "algorithm": "argon2id",
+                "memoryKib": 19456,
+                "iterations": 2,
+                "parallelism": 1
+            })
+        );
+
+        let pbkdf2: KdfParams = serde_json::from_value(
+            serde_json::json!({ "algorithm": "pbkdf2-sha256", "iterations": 600_000 }),
+        )
+        .unwrap();
+        assert_eq!(
+            pbkdf2,
+            KdfParams::Pbkdf2 {
+                iterations: 600_000
+            }
+        );
+    }
+}
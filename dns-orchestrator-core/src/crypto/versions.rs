@@ -6,7 +6,10 @@
 //! - 文件版本号不暴露加密参数，参数在代码中隐式定义
 //! - Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 //! - Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
-//! - 将来可扩展到 Version 3（Argon2 等算法）
+//! - Version 3: 同 Version 2，并将文件头作为 AES-GCM 附加认证数据（AAD），防止篡改头部
+//! - Version 4: Argon2id（19 MiB, 2 次迭代, 并行度 1，OWASP 推荐），文件头记录 KDF 参数
+
+use super::KdfParams;
 
 /// Version 1: PBKDF2-HMAC-SHA256, 100,000 次迭代
 const VERSION_1_ITERATIONS: u32 = 100_000;
@@ -14,33 +17,68 @@ const VERSION_1_ITERATIONS: u32 = 100_000;
 /// Version 2: PBKDF2-HMAC-SHA256, 600,000 次迭代（OWASP 2023 推荐）
 const VERSION_2_ITERATIONS: u32 = 600_000;
 
+/// Version 3: 迭代次数与 Version 2 相同，新增文件头 AAD 绑定
+const VERSION_3_ITERATIONS: u32 = 600_000;
+
+/// Version 4: Argon2id 参数
+const VERSION_4_KDF: KdfParams = KdfParams::Argon2id {
+    memory_kib: 19 * 1024,
+    iterations: 2,
+    parallelism: 1,
+};
+
+/// 首个将文件头绑定为 AAD 的版本
+const FIRST_HEADER_AAD_VERSION: u32 = 3;
+
 /// 当前文件格式版本号
 ///
-/// 修改此常量即可切换版本（迭代次数会自动从版本号派生）
-pub const CURRENT_FILE_VERSION: u32 = 2;
+/// 修改此常量即可切换版本（KDF 参数会自动从版本号派生）
+pub const CURRENT_FILE_VERSION: u32 = 4;
 
-/// 获取当前版本的迭代次数（编译时计算）
+/// 获取当前版本的 KDF 参数（编译时计算）
 ///
 /// 从 `CURRENT_FILE_VERSION` 自动派生，确保加密和解密使用相同参数
-pub const fn get_current_iterations() -> u32 {
-    match get_pbkdf2_iterations(CURRENT_FILE_VERSION) {
-        Some(iterations) => iterations,
+#[allow(clippy::panic)]
+pub const fn get_current_kdf() -> KdfParams {
+    match get_kdf_params(CURRENT_FILE_VERSION) {
+        Some(kdf) => kdf,
         None => panic!("Invalid CURRENT_FILE_VERSION"),
     }
 }
 
-/// 获取指定文件版本的 PBKDF2 迭代次数
+/// 获取指定文件版本默认的 KDF 参数
+///
+/// 文件头未记录 KDF 参数时（Version 1-3）按版本号推断
+pub const fn get_kdf_params(version: u32) -> Option<KdfParams> {
+    match get_pbkdf2_iterations(version) {
+        Some(iterations) => Some(KdfParams::Pbkdf2 { iterations }),
+        None => match version {
+            4 => Some(VERSION_4_KDF),
+            _ => None,
+        },
+    }
+}
+
+/// 获取指定文件版本的 PBKDF2 迭代次数（Version 4 起改用 Argon2id，返回 None）
 ///
 /// # Arguments
 /// * `version` - 文件版本号
 ///
 /// # Returns
 /// - `Some(iterations)` - 该版本对应的迭代次数
-/// - `None` - 不支持的版本号
+/// - `None` - 不支持的版本号或该版本不使用 PBKDF2
 pub const fn get_pbkdf2_iterations(version: u32) -> Option<u32> {
     match version {
         1 => Some(VERSION_1_ITERATIONS),
         2 => Some(VERSION_2_ITERATIONS),
+        3 => Some(VERSION_3_ITERATIONS),
         _ => None,
     }
 }
+
+/// 指定文件版本是否将文件头作为 AAD 参与加密认证
+///
+/// 旧版本（1、2）加密时未使用 AAD，解密时也必须传空
+pub const fn uses_header_aad(version: u32) -> bool {
+    version >= FIRST_HEADER_AAD_VERSION
+}
@@ -70,6 +70,14 @@ pub enum CoreError {
     #[error("Network error: {0}")]
     NetworkError(String),
 
+    /// 资源冲突（如同名记录已存在且值不同）
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// 服务处于维护模式（只读），拒绝写操作
+    #[error("Service is in read-only maintenance mode")]
+    ReadOnly,
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
     Provider(#[from] ProviderError),
@@ -16,10 +16,13 @@ pub mod traits;
 pub mod types;
 pub mod utils;
 
+#[cfg(test)]
+mod test_support;
+
 // Re-export 常用类型
 pub use error::{CoreError, CoreResult};
 pub use services::ServiceContext;
-pub use traits::{AccountRepository, CredentialStore, ProviderRegistry};
+pub use traits::{AccountRepository, CredentialStore, ProviderRegistry, TrashRepository};
 
 // Re-export provider 库的公共类型
 pub use dns_orchestrator_provider::{
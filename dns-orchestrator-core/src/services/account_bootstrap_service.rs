@@ -2,14 +2,16 @@
 //!
 //! 负责应用启动时恢复账户状态，重建 Provider 实例
 
+use std::collections::HashSet;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::{create_provider, ProviderCredentials};
+use dns_orchestrator_provider::ProviderCredentials;
+use tokio::sync::Mutex;
 
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::types::AccountStatus;
 
-use super::{AccountMetadataService, CredentialManagementService};
+use super::{AccountMetadataService, CredentialManagementService, MaintenanceMode};
 
 /// 账户恢复结果
 #[derive(Debug, Clone)]
@@ -24,6 +26,9 @@ pub struct RestoreResult {
 pub struct AccountBootstrapService {
     metadata_service: Arc<AccountMetadataService>,
     credential_service: Arc<CredentialManagementService>,
+    maintenance: MaintenanceMode,
+    /// 串行化孤立凭证清理
+    cleanup_lock: Mutex<()>,
 }
 
 impl AccountBootstrapService {
@@ -36,9 +41,18 @@ impl AccountBootstrapService {
         Self {
             metadata_service,
             credential_service,
+            maintenance: MaintenanceMode::default(),
+            cleanup_lock: Mutex::new(()),
         }
     }
 
+    /// 使用共享的维护模式开关（只读模式下不清理凭证）
+    #[must_use]
+    pub fn with_maintenance(mut self, maintenance: MaintenanceMode) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
     /// 恢复账户（启动时调用）
     pub async fn restore_accounts(&self) -> CoreResult<RestoreResult> {
         let mut success_count = 0;
@@ -116,7 +130,10 @@ impl AccountBootstrapService {
                     }
                 };
 
-            let provider = match create_provider(provider_credentials) {
+            let provider = match self
+                .credential_service
+                .create_provider(provider_credentials)
+            {
                 Ok(p) => p,
                 Err(e) => {
                     log::warn!(
@@ -165,4 +182,152 @@ impl AccountBootstrapService {
             error_count,
         })
     }
+
+    /// 清理孤立凭证（没有对应账户的凭证条目）
+    ///
+    /// 删除账户时凭证删除失败或进程崩溃都可能留下孤立凭证。
+    /// 返回删除的凭证数量；单条删除失败只记录日志，不中断清理。
+    ///
+    /// 清理彼此串行执行；创建账户时先写凭证再写账户，删除前会再次确认账户不存在，
+    /// 避免误删刚创建的账户的凭证。账户列表为空而凭证不为空时（通常是账户数据
+    /// 丢失或加载异常）拒绝清理，返回 `ValidationError`
+    pub async fn cleanup_orphaned_credentials(&self) -> CoreResult<usize> {
+        self.maintenance.ensure_writable()?;
+        let _guard = self.cleanup_lock.lock().await;
+
+        let accounts = self.metadata_service.list_accounts().await?;
+        let account_ids: HashSet<&str> = accounts.iter().map(|a| a.id.as_str()).collect();
+        let all_credentials = self.credential_service.load_all_credentials().await?;
+        if account_ids.is_empty() && !all_credentials.is_empty() {
+            return Err(CoreError::ValidationError(format!(
+                "账户列表为空但存在 {} 条凭证，拒绝清理",
+                all_credentials.len()
+            )));
+        }
+
+        let mut removed = 0;
+        for account_id in all_credentials
+            .keys()
+            .filter(|id| !account_ids.contains(id.as_str()))
+        {
+            match self.metadata_service.get_account(account_id).await {
+                Ok(None) => {}
+                Ok(Some(_)) => continue,
+                Err(e) => {
+                    log::warn!("Failed to recheck account {account_id}, skipping: {e}");
+                    continue;
+                }
+            }
+            match self.credential_service.delete_credentials(account_id).await {
+                Ok(()) => {
+                    log::info!("Removed orphaned credentials: {account_id}");
+                    removed += 1;
+                }
+                Err(e) => {
+                    log::warn!("Failed to remove orphaned credentials {account_id}: {e}");
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use chrono::Utc;
+    use dns_orchestrator_provider::ProviderType;
+
+    use super::*;
+    use crate::test_support::{MemoryAccountRepository, MemoryCredentialStore};
+    use crate::traits::{AccountRepository, CredentialStore, InMemoryProviderRegistry};
+    use crate::types::Account;
+
+    fn account(id: &str) -> Account {
+        Account {
+            id: id.to_string(),
+            name: id.to_string(),
+            provider: ProviderType::Cloudflare,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            status: Some(AccountStatus::Active),
+            error: None,
+            tags: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_orphaned_credentials() {
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        let repository = Arc::new(MemoryAccountRepository::default());
+        repository.save(&account("kept")).await.unwrap();
+        for id in ["kept", "orphan-1", "orphan-2"] {
+            credentials.save(id, &HashMap::new()).await.unwrap();
+        }
+
+        let service = AccountBootstrapService::new(
+            Arc::new(AccountMetadataService::new(repository)),
+            Arc::new(CredentialManagementService::new(
+                credentials.clone(),
+                Arc::new(InMemoryProviderRegistry::new()),
+            )),
+        );
+
+        assert_eq!(service.cleanup_orphaned_credentials().await.unwrap(), 2);
+        let remaining = credentials.load_all().await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert!(remaining.contains_key("kept"));
+
+        // 再次清理不会删除任何条目
+        assert_eq!(service.cleanup_orphaned_credentials().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_refuses_when_no_accounts() {
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        credentials.save("acc-1", &HashMap::new()).await.unwrap();
+
+        let service = AccountBootstrapService::new(
+            Arc::new(AccountMetadataService::new(Arc::new(
+                MemoryAccountRepository::default(),
+            ))),
+            Arc::new(CredentialManagementService::new(
+                credentials.clone(),
+                Arc::new(InMemoryProviderRegistry::new()),
+            )),
+        );
+
+        assert!(matches!(
+            service.cleanup_orphaned_credentials().await,
+            Err(CoreError::ValidationError(_))
+        ));
+        assert_eq!(credentials.load_all().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_cleanup_removes_each_orphan_once() {
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        let repository = Arc::new(MemoryAccountRepository::default());
+        repository.save(&account("kept")).await.unwrap();
+        for id in ["kept", "orphan-1", "orphan-2", "orphan-3"] {
+            credentials.save(id, &HashMap::new()).await.unwrap();
+        }
+
+        let service = AccountBootstrapService::new(
+            Arc::new(AccountMetadataService::new(repository)),
+            Arc::new(CredentialManagementService::new(
+                credentials.clone(),
+                Arc::new(InMemoryProviderRegistry::new()),
+            )),
+        );
+
+        let (first, second) = tokio::join!(
+            service.cleanup_orphaned_credentials(),
+            service.cleanup_orphaned_credentials()
+        );
+        assert_eq!(first.unwrap() + second.unwrap(), 3);
+        assert_eq!(credentials.load_all().await.unwrap().len(), 1);
+    }
 }
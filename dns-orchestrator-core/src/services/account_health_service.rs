@@ -0,0 +1,342 @@
+//! 账户健康检查服务
+//!
+//! 定期验证已注册 Provider 的凭证，并据此更新账户状态；也可由用户手动触发一次全量验证。
+//! 检查失败只标记状态，不会注销 Provider，凭证恢复后状态自动回到 Active。
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use dns_orchestrator_provider::ProviderError;
+use futures::stream::{self, StreamExt};
+
+use crate::error::CoreResult;
+use crate::types::{AccountStatus, AccountVerifyResult, VerifyAllAccountsResult};
+
+use super::ServiceContext;
+
+/// 默认检查间隔
+pub const DEFAULT_HEALTH_CHECK_INTERVAL: Duration = Duration::from_mins(30);
+
+/// 手动全量验证时同时验证的最大账户数（避免集中请求触发服务商限流）
+const VERIFY_CONCURRENCY: usize = 4;
+
+/// 单轮健康检查结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HealthCheckSummary {
+    /// 检查的账户数
+    pub checked: usize,
+    /// 凭证有效的账户数
+    pub healthy: usize,
+    /// 凭证失效的账户数
+    pub unhealthy: usize,
+    /// 因网络等临时错误跳过的账户数（状态保持不变）
+    pub skipped: usize,
+}
+
+/// 单个账户的检查结论
+enum Health {
+    Healthy,
+    Unhealthy(String),
+    /// 网络等临时错误，无法判断凭证是否有效
+    Unknown(String),
+}
+
+/// 账户健康检查服务
+pub struct AccountHealthService {
+    ctx: Arc<ServiceContext>,
+}
+
+impl AccountHealthService {
+    /// 创建账户健康检查服务实例
+    #[must_use]
+    pub fn new(ctx: Arc<ServiceContext>) -> Self {
+        Self { ctx }
+    }
+
+    /// 检查所有已注册账户的凭证并更新状态
+    pub async fn check_all(&self) -> HealthCheckSummary {
+        let mut summary = HealthCheckSummary::default();
+
+        for account_id in self.ctx.provider_registry.list_account_ids().await {
+            let Some(health) = self.check_one(&account_id).await else {
+                continue;
+            };
+            summary.checked += 1;
+            match health {
+                Health::Healthy => summary.healthy += 1,
+                Health::Unhealthy(_) => summary.unhealthy += 1,
+                Health::Unknown(_) => summary.skipped += 1,
+            }
+        }
+
+        summary
+    }
+
+    /// 并发验证账户仓库中所有账户的凭证并更新状态，返回每个账户的结果
+    ///
+    /// 与后台检查一致：网络等临时错误计入失败并返回原因，但不修改账户状态；
+    /// 没有已注册 Provider 的账户同样计入失败，不会被静默跳过
+    pub async fn verify_all(&self) -> CoreResult<VerifyAllAccountsResult> {
+        let accounts = self.ctx.account_repository.find_all().await?;
+        let mut checked: Vec<(AccountVerifyResult, Option<Health>)> = stream::iter(accounts)
+            .map(|account| async move {
+                let health = self.check_one(&account.id).await;
+                let (valid, error) = match &health {
+                    Some(Health::Healthy) => (true, None),
+                    Some(Health::Unhealthy(error) | Health::Unknown(error)) => {
+                        (false, Some(error.clone()))
+                    }
+                    None => (false, Some("Provider 未注册（凭证加载失败）".to_string())),
+                };
+                let result = AccountVerifyResult {
+                    account_id: account.id,
+                    valid,
+                    error,
+                };
+                (result, health)
+            })
+            .buffer_unordered(VERIFY_CONCURRENCY)
+            .collect()
+            .await;
+        checked.sort_by(|a, b| a.0.account_id.cmp(&b.0.account_id));
+
+        let count = |f: fn(&Option<Health>) -> bool| checked.iter().filter(|(_, h)| f(h)).count();
+        let active = count(|h| matches!(h, Some(Health::Healthy)));
+        let invalid = count(|h| matches!(h, Some(Health::Unhealthy(_))));
+        let unreachable = count(|h| matches!(h, Some(Health::Unknown(_))));
+        let unregistered = count(Option::is_none);
+        Ok(VerifyAllAccountsResult {
+            total: checked.len(),
+            active,
+            errored: invalid + unreachable + unregistered,
+            invalid,
+            unreachable,
+            unregistered,
+            results: checked.into_iter().map(|(result, _)| result).collect(),
+        })
+    }
+
+    /// 验证单个账户的凭证并据此更新状态（账户未注册时返回 `None`）
+    async fn check_one(&self, account_id: &str) -> Option<Health> {
+        let provider = self.ctx.provider_registry.get(account_id).await?;
+
+        let health = match provider.validate_credentials().await {
+            Ok(true) => Health::Healthy,
+            Ok(false) | Err(ProviderError::InvalidCredentials { .. }) => {
+                Health::Unhealthy("凭证已失效".to_string())
+            }
+            Err(e) => {
+                log::warn!("Health check for account {account_id} skipped: {e}");
+                Health::Unknown(e.to_string())
+            }
+        };
+
+        match &health {
+            Health::Healthy => {
+                self.set_status(account_id, AccountStatus::Active, None)
+                    .await;
+            }
+            Health::Unhealthy(error) => {
+                self.set_status(account_id, AccountStatus::Error, Some(error.clone()))
+                    .await;
+            }
+            Health::Unknown(_) => {}
+        }
+        Some(health)
+    }
+
+    /// 按固定间隔持续检查（首轮在一个间隔后开始，启动时的恢复流程已验证过凭证）
+    pub async fn run_periodic(&self, interval: Duration) {
+        let mut ticker = tokio::time::interval_at(tokio::time::Instant::now() + interval, interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            let summary = self.check_all().await;
+            log::debug!(
+                "Account health check: {} checked, {} healthy, {} unhealthy, {} skipped",
+                summary.checked,
+                summary.healthy,
+                summary.unhealthy,
+                summary.skipped
+            );
+        }
+    }
+
+    /// 状态变化时才写入仓库
+    async fn set_status(&self, account_id: &str, status: AccountStatus, error: Option<String>) {
+        let current = match self.ctx.account_repository.find_by_id(account_id).await {
+            Ok(Some(account)) => account,
+            Ok(None) => return,
+            Err(e) => {
+                log::warn!("Failed to load account {account_id} for health check: {e}");
+                return;
+            }
+        };
+        if current.status.as_ref() == Some(&status) && current.error == error {
+            return;
+        }
+
+        if let Err(e) = self
+            .ctx
+            .account_repository
+            .update_status(account_id, status.clone(), error)
+            .await
+        {
+            log::error!("Failed to update status of account {account_id}: {e}");
+            return;
+        }
+        log::info!("Account {account_id} status changed to {status:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MemoryAccountRepository, MemoryCredentialStore};
+    use crate::traits::{InMemoryProviderRegistry, ProviderRegistry};
+    use crate::types::{Account, ProviderType};
+    use chrono::Utc;
+    use dns_orchestrator_provider::MockProvider;
+
+    #[tokio::test]
+    async fn test_check_all_flips_status_without_unregistering() {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let provider = Arc::new(MockProvider::empty(None));
+        registry.register("acc".to_string(), provider.clone()).await;
+
+        let accounts = Arc::new(MemoryAccountRepository::default());
+        accounts.0.lock().await.push(Account {
+            id: "acc".to_string(),
+            name: "test".to_string(),
+            provider: ProviderType::Cloudflare,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            status: Some(AccountStatus::Active),
+            error: None,
+            tags: Vec::new(),
+        });
+
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            accounts.clone(),
+            registry.clone(),
+        );
+        let service = AccountHealthService::new(Arc::new(ctx));
+
+        let summary = service.check_all().await;
+        assert_eq!(summary.healthy, 1);
+
+        provider.set_credentials_valid(false);
+        let summary = service.check_all().await;
+        assert_eq!(summary.unhealthy, 1);
+        let account = accounts.0.lock().await[0].clone();
+        assert_eq!(account.status, Some(AccountStatus::Error));
+        assert!(account.error.is_some());
+        assert!(registry.get("acc").await.is_some());
+
+        provider.set_credentials_valid(true);
+        service.check_all().await;
+        let account = accounts.0.lock().await[0].clone();
+        assert_eq!(account.status, Some(AccountStatus::Active));
+        assert!(account.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_verify_all_reports_and_updates_each_account() {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let accounts = Arc::new(MemoryAccountRepository::default());
+        for i in 0..10 {
+            let id = format!("acc-{i:02}");
+            let provider = MockProvider::empty(None);
+            provider.set_credentials_valid(i % 4 != 0);
+            registry.register(id.clone(), Arc::new(provider)).await;
+            accounts.0.lock().await.push(Account {
+                id,
+                name: format!("account {i}"),
+                provider: ProviderType::Cloudflare,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                status: Some(AccountStatus::Active),
+                error: None,
+                tags: Vec::new(),
+            });
+        }
+
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            accounts.clone(),
+            registry,
+        );
+        let service = AccountHealthService::new(Arc::new(ctx));
+
+        let result = service.verify_all().await.unwrap();
+        assert_eq!((result.total, result.active, result.errored), (10, 7, 3));
+        assert_eq!(
+            (result.invalid, result.unreachable, result.unregistered),
+            (3, 0, 0)
+        );
+        let ids: Vec<_> = result
+            .results
+            .iter()
+            .map(|r| r.account_id.as_str())
+            .collect();
+        let mut sorted = ids.clone();
+        sorted.sort_unstable();
+        assert_eq!(ids, sorted);
+
+        for (i, verify) in result.results.iter().enumerate() {
+            assert_eq!(verify.valid, i % 4 != 0);
+            assert_eq!(verify.error.is_some(), i % 4 == 0);
+        }
+        for account in accounts.0.lock().await.iter() {
+            let revoked = ["acc-00", "acc-04", "acc-08"].contains(&account.id.as_str());
+            let expected = if revoked {
+                AccountStatus::Error
+            } else {
+                AccountStatus::Active
+            };
+            assert_eq!(account.status, Some(expected));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_all_reports_unregistered_accounts() {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let accounts = Arc::new(MemoryAccountRepository::default());
+        for id in ["registered", "unregistered"] {
+            accounts.0.lock().await.push(Account {
+                id: id.to_string(),
+                name: id.to_string(),
+                provider: ProviderType::Cloudflare,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                status: Some(AccountStatus::Active),
+                error: None,
+                tags: Vec::new(),
+            });
+        }
+        registry
+            .register(
+                "registered".to_string(),
+                Arc::new(MockProvider::empty(None)),
+            )
+            .await;
+
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            accounts,
+            registry,
+        );
+        let result = AccountHealthService::new(Arc::new(ctx))
+            .verify_all()
+            .await
+            .unwrap();
+        assert_eq!((result.total, result.active, result.errored), (2, 1, 1));
+        assert_eq!(result.unregistered, 1);
+        let missing = &result.results[1];
+        assert_eq!(missing.account_id, "unregistered");
+        assert!(!missing.valid);
+        assert!(missing.error.is_some());
+    }
+}
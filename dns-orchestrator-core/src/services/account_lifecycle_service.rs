@@ -12,12 +12,14 @@ use crate::types::{
     UpdateAccountRequest,
 };
 
-use super::{AccountMetadataService, CredentialManagementService};
+use super::account_metadata_service::normalize_tags;
+use super::{AccountMetadataService, CredentialManagementService, MaintenanceMode};
 
 /// 账户生命周期服务
 pub struct AccountLifecycleService {
     metadata_service: Arc<AccountMetadataService>,
     credential_service: Arc<CredentialManagementService>,
+    maintenance: MaintenanceMode,
 }
 
 impl AccountLifecycleService {
@@ -30,14 +32,29 @@ impl AccountLifecycleService {
         Self {
             metadata_service,
             credential_service,
+            maintenance: MaintenanceMode::default(),
         }
     }
 
+    /// 使用共享的维护模式开关（只读模式下拒绝所有写操作）
+    #[must_use]
+    pub fn with_maintenance(mut self, maintenance: MaintenanceMode) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
     /// 创建账户
     ///
-    /// 完整流程：验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
+    /// 完整流程：检查名称 -> 验证凭证 -> 保存凭证 -> 注册 Provider -> 保存元数据
     /// 如果保存元数据失败，会自动清理已保存的凭证和已注册的 Provider
     pub async fn create_account(&self, request: CreateAccountRequest) -> CoreResult<Account> {
+        self.maintenance.ensure_writable()?;
+
+        // 0. 开启名称唯一约束时检查重名
+        self.metadata_service
+            .ensure_name_available(&request.name, None)
+            .await?;
+
         // 1. 验证凭证并创建 provider
         let provider = self
             .credential_service
@@ -69,6 +86,7 @@ impl AccountLifecycleService {
             updated_at: now,
             status: Some(AccountStatus::Active),
             error: None,
+            tags: Vec::new(),
         };
 
         // 6. 保存元数据，失败时 cleanup
@@ -93,9 +111,16 @@ impl AccountLifecycleService {
 
     /// 更新账户
     ///
-    /// 支持更新账户名称和/或凭证
-    /// 如果更新凭证，会重新验证并重新注册 Provider
+    /// 支持更新账户名称和/或凭证，只改名时不需要提供凭证
+    /// 如果更新凭证，会重新验证、重新加密保存并替换已注册的 Provider
     pub async fn update_account(&self, request: UpdateAccountRequest) -> CoreResult<Account> {
+        self.maintenance.ensure_writable()?;
+
+        let new_name = request.name.map(|name| name.trim().to_string());
+        if new_name.as_deref() == Some("") {
+            return Err(CoreError::ValidationError("账户名称不能为空".to_string()));
+        }
+
         // 1. 获取现有账户
         let mut account = self
             .metadata_service
@@ -103,6 +128,13 @@ impl AccountLifecycleService {
             .await?
             .ok_or_else(|| CoreError::AccountNotFound(request.id.clone()))?;
 
+        // 开启名称唯一约束时，重命名前检查重名
+        if let Some(ref new_name) = new_name {
+            self.metadata_service
+                .ensure_name_available(new_name, Some(&request.id))
+                .await?;
+        }
+
         // 2. 如果提供了新凭证，验证并更新
         if let Some(ref new_credentials) = request.credentials {
             // 2.1 验证新凭证并创建新 provider
@@ -117,13 +149,10 @@ impl AccountLifecycleService {
                 .save_credentials(&request.id, new_credentials)
                 .await?;
 
-            // 2.3 重新注册 provider（先注册新的，避免竞态条件）
+            // 2.3 替换已注册的 provider（注册即覆盖，无需先注销，避免出现无 provider 的窗口）
             self.credential_service
                 .register_provider(request.id.clone(), new_provider)
                 .await;
-            self.credential_service
-                .unregister_provider(&request.id)
-                .await;
 
             // 2.4 更新状态为 Active（凭证验证成功）
             account.status = Some(AccountStatus::Active);
@@ -131,7 +160,7 @@ impl AccountLifecycleService {
         }
 
         // 3. 更新名称（如果提供）
-        if let Some(new_name) = request.name {
+        if let Some(new_name) = new_name {
             account.name = new_name;
         }
 
@@ -144,10 +173,35 @@ impl AccountLifecycleService {
         Ok(account)
     }
 
+    /// 替换账户的分组标签
+    ///
+    /// 标签会去除首尾空白、丢弃空值并忽略大小写去重；传入空列表即清空标签
+    pub async fn update_account_tags(
+        &self,
+        account_id: &str,
+        tags: Vec<String>,
+    ) -> CoreResult<Account> {
+        self.maintenance.ensure_writable()?;
+
+        let mut account = self
+            .metadata_service
+            .get_account(account_id)
+            .await?
+            .ok_or_else(|| CoreError::AccountNotFound(account_id.to_string()))?;
+
+        account.tags = normalize_tags(tags);
+        account.updated_at = Utc::now();
+        self.metadata_service.save_account(&account).await?;
+
+        Ok(account)
+    }
+
     /// 删除账户
     ///
     /// 流程：先删除元数据，再清理内存和凭证（避免出现"幽灵账户"）
     pub async fn delete_account(&self, account_id: &str) -> CoreResult<()> {
+        self.maintenance.ensure_writable()?;
+
         // 1. 检查账户存在
         self.metadata_service
             .get_account(account_id)
@@ -175,6 +229,8 @@ impl AccountLifecycleService {
         &self,
         account_ids: Vec<String>,
     ) -> CoreResult<BatchDeleteResult> {
+        self.maintenance.ensure_writable()?;
+
         let mut success_count = 0;
         let mut failures = Vec::new();
 
@@ -194,6 +250,130 @@ impl AccountLifecycleService {
             success_count,
             failed_count: failures.len(),
             failures,
+            dry_run: false,
+            targets: Vec::new(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use dns_orchestrator_provider::ProviderType;
+
+    use super::*;
+    use crate::test_support::{MemoryAccountRepository, MemoryCredentialStore};
+    use crate::traits::{AccountRepository, CredentialStore, InMemoryProviderRegistry};
+
+    async fn service_with_account() -> (AccountLifecycleService, Arc<MemoryCredentialStore>) {
+        let repository = Arc::new(MemoryAccountRepository::default());
+        repository
+            .save(&Account {
+                id: "acc-1".to_string(),
+                name: "old".to_string(),
+                provider: ProviderType::Cloudflare,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                status: Some(AccountStatus::Active),
+                error: None,
+                tags: Vec::new(),
+            })
+            .await
+            .unwrap();
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        let stored = HashMap::from([("apiToken".to_string(), "token".to_string())]);
+        credentials.save("acc-1", &stored).await.unwrap();
+
+        let service = AccountLifecycleService::new(
+            Arc::new(AccountMetadataService::new(repository)),
+            Arc::new(CredentialManagementService::new(
+                credentials.clone(),
+                Arc::new(InMemoryProviderRegistry::new()),
+            )),
+        );
+        (service, credentials)
+    }
+
+    #[tokio::test]
+    async fn test_rename_without_credentials() {
+        let (service, credentials) = service_with_account().await;
+
+        let account = service
+            .update_account(UpdateAccountRequest {
+                id: "acc-1".to_string(),
+                name: Some("  new name ".to_string()),
+                credentials: None,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(account.name, "new name");
+        // 凭证保持不变
+        let stored = credentials.load("acc-1").await.unwrap();
+        assert_eq!(stored.get("apiToken").map(String::as_str), Some("token"));
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_empty_name_and_unknown_account() {
+        let (service, _) = service_with_account().await;
+
+        let result = service
+            .update_account(UpdateAccountRequest {
+                id: "acc-1".to_string(),
+                name: Some("   ".to_string()),
+                credentials: None,
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::ValidationError(_))));
+
+        let result = service
+            .update_account(UpdateAccountRequest {
+                id: "missing".to_string(),
+                name: Some("x".to_string()),
+                credentials: None,
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::AccountNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_account_tags_and_filter() {
+        let (service, _) = service_with_account().await;
+
+        let account = service
+            .update_account_tags(
+                "acc-1",
+                vec![
+                    " team-a ".to_string(),
+                    "prod".to_string(),
+                    "Team-A".to_string(),
+                    String::new(),
+                ],
+            )
+            .await
+            .unwrap();
+        assert_eq!(account.tags, vec!["team-a", "prod"]);
+
+        let metadata = &service.metadata_service;
+        assert_eq!(
+            metadata.list_accounts_by_tag("TEAM-A").await.unwrap().len(),
+            1
+        );
+        assert!(metadata
+            .list_accounts_by_tag("staging")
+            .await
+            .unwrap()
+            .is_empty());
+
+        // 空列表清空标签
+        let account = service
+            .update_account_tags("acc-1", Vec::new())
+            .await
+            .unwrap();
+        assert!(account.tags.is_empty());
+
+        let result = service.update_account_tags("missing", Vec::new()).await;
+        assert!(matches!(result, Err(CoreError::AccountNotFound(_))));
+    }
+}
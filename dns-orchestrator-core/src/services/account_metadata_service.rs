@@ -4,20 +4,55 @@
 
 use std::sync::Arc;
 
-use crate::error::CoreResult;
+use crate::error::{CoreError, CoreResult};
 use crate::traits::AccountRepository;
 use crate::types::{Account, AccountStatus};
 
 /// 账户元数据服务
 pub struct AccountMetadataService {
     account_repository: Arc<dyn AccountRepository>,
+    /// 是否要求账户名称唯一
+    unique_names: bool,
 }
 
 impl AccountMetadataService {
     /// 创建账户元数据服务实例
     #[must_use]
     pub fn new(account_repository: Arc<dyn AccountRepository>) -> Self {
-        Self { account_repository }
+        Self {
+            account_repository,
+            unique_names: false,
+        }
+    }
+
+    /// 设置是否要求账户名称唯一（默认关闭，保持向后兼容）
+    #[must_use]
+    pub fn with_unique_names(mut self, enabled: bool) -> Self {
+        self.unique_names = enabled;
+        self
+    }
+
+    /// 检查账户名称是否可用
+    ///
+    /// 未开启唯一约束时总是通过；`exclude_id` 用于重命名时排除账户自身
+    pub async fn ensure_name_available(
+        &self,
+        name: &str,
+        exclude_id: Option<&str>,
+    ) -> CoreResult<()> {
+        if !self.unique_names {
+            return Ok(());
+        }
+
+        let key = account_name_key(name);
+        let accounts = self.account_repository.find_all().await?;
+        let taken = accounts
+            .iter()
+            .any(|a| Some(a.id.as_str()) != exclude_id && account_name_key(&a.name) == key);
+        if taken {
+            return Err(CoreError::Conflict(format!("账户名称 {name} 已存在")));
+        }
+        Ok(())
     }
 
     /// 列出所有账户
@@ -25,6 +60,16 @@ impl AccountMetadataService {
         self.account_repository.find_all().await
     }
 
+    /// 列出带有指定标签的账户（忽略大小写）
+    pub async fn list_accounts_by_tag(&self, tag: &str) -> CoreResult<Vec<Account>> {
+        let key = account_tag_key(tag);
+        let accounts = self.account_repository.find_all().await?;
+        Ok(accounts
+            .into_iter()
+            .filter(|a| a.tags.iter().any(|t| account_tag_key(t) == key))
+            .collect())
+    }
+
     /// 根据 ID 获取账户
     pub async fn get_account(&self, account_id: &str) -> CoreResult<Option<Account>> {
         self.account_repository.find_by_id(account_id).await
@@ -52,3 +97,22 @@ impl AccountMetadataService {
             .await
     }
 }
+
+/// 账户名称比较键（忽略首尾空白与大小写）
+pub(crate) fn account_name_key(name: &str) -> String {
+    name.trim().to_lowercase()
+}
+
+/// 账户标签比较键（忽略首尾空白与大小写）
+fn account_tag_key(tag: &str) -> String {
+    tag.trim().to_lowercase()
+}
+
+/// 规范化标签：去除首尾空白、丢弃空标签、忽略大小写去重（保留首次出现的写法）
+pub(crate) fn normalize_tags(tags: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    tags.into_iter()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty() && seen.insert(account_tag_key(t)))
+        .collect()
+}
@@ -5,15 +5,22 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::{create_provider, DnsProvider, ProviderCredentials, ProviderType};
+use dns_orchestrator_provider::{
+    DnsProvider, PaginationParams, ProviderCredentials, ProviderOptions, ProviderType,
+};
 
 use crate::error::{CoreError, CoreResult};
 use crate::traits::{CredentialStore, ProviderRegistry};
+use crate::types::CredentialTestResult;
+
+use super::{build_provider, DomainsCache, ServiceContext};
 
 /// 凭证管理服务
 pub struct CredentialManagementService {
     credential_store: Arc<dyn CredentialStore>,
     provider_registry: Arc<dyn ProviderRegistry>,
+    domains_cache: DomainsCache,
+    provider_options: ProviderOptions,
 }
 
 impl CredentialManagementService {
@@ -26,9 +33,37 @@ impl CredentialManagementService {
         Self {
             credential_store,
             provider_registry,
+            domains_cache: DomainsCache::default(),
+            provider_options: ProviderOptions::default(),
+        }
+    }
+
+    /// 使用共享的域名列表缓存（注册/注销 Provider 时使对应账户的缓存失效）
+    #[must_use]
+    pub fn with_domains_cache(mut self, domains_cache: DomainsCache) -> Self {
+        self.domains_cache = domains_cache;
+        self
+    }
+
+    /// 基于服务上下文创建，共用其凭证存储、Provider 注册表、域名列表缓存和 Provider 创建选项
+    #[must_use]
+    pub fn from_context(ctx: &ServiceContext) -> Self {
+        Self {
+            credential_store: Arc::clone(&ctx.credential_store),
+            provider_registry: Arc::clone(&ctx.provider_registry),
+            domains_cache: ctx.domains_cache.clone(),
+            provider_options: ctx.provider_options.clone(),
         }
     }
 
+    /// 按当前选项创建 Provider 实例（不验证凭证）
+    pub fn create_provider(
+        &self,
+        credentials: ProviderCredentials,
+    ) -> CoreResult<Arc<dyn DnsProvider>> {
+        build_provider(credentials, &self.provider_options)
+    }
+
     /// 验证凭证并创建 Provider 实例
     pub async fn validate_and_create_provider(
         &self,
@@ -40,7 +75,7 @@ impl CredentialManagementService {
             .map_err(CoreError::CredentialValidation)?;
 
         // 2. 创建 Provider
-        let provider = create_provider(provider_credentials)?;
+        let provider = self.create_provider(provider_credentials)?;
 
         // 3. 验证凭证
         let is_valid = provider.validate_credentials().await?;
@@ -51,6 +86,62 @@ impl CredentialManagementService {
         Ok(provider)
     }
 
+    /// 测试凭证连通性，不保存任何数据
+    ///
+    /// `check_read_access` 为 `true` 时额外拉取第一页域名，确认凭证具有读取权限
+    pub async fn test_credentials(
+        &self,
+        provider_type: &ProviderType,
+        credentials: &HashMap<String, String>,
+        check_read_access: bool,
+    ) -> CredentialTestResult {
+        let provider = ProviderCredentials::from_map(provider_type, credentials)
+            .map_err(CoreError::CredentialValidation)
+            .and_then(|c| self.create_provider(c));
+        match provider {
+            Ok(provider) => Self::probe_provider(provider.as_ref(), check_read_access).await,
+            Err(e) => CredentialTestResult::failed(&e),
+        }
+    }
+
+    /// 验证凭证并按需检查读取权限
+    async fn probe_provider(
+        provider: &dyn DnsProvider,
+        check_read_access: bool,
+    ) -> CredentialTestResult {
+        match provider.validate_credentials().await {
+            Ok(true) => {}
+            Ok(false) => {
+                return CredentialTestResult::failed(&CoreError::InvalidCredentials(
+                    provider.id().to_string(),
+                ))
+            }
+            Err(e) => return CredentialTestResult::failed(&e),
+        }
+
+        if !check_read_access {
+            return CredentialTestResult {
+                valid: true,
+                domain_count: None,
+                error: None,
+            };
+        }
+
+        let params = PaginationParams {
+            page: 1,
+            page_size: 1,
+            ..Default::default()
+        };
+        match provider.list_domains(&params).await {
+            Ok(page) => CredentialTestResult {
+                valid: true,
+                domain_count: Some(page.total_count),
+                error: None,
+            },
+            Err(e) => CredentialTestResult::failed(&e),
+        }
+    }
+
     /// 保存凭证
     pub async fn save_credentials(
         &self,
@@ -91,11 +182,52 @@ impl CredentialManagementService {
 
     /// 注册 Provider 到 Registry
     pub async fn register_provider(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
+        self.domains_cache.invalidate(&account_id);
         self.provider_registry.register(account_id, provider).await;
     }
 
     /// 注销 Provider
     pub async fn unregister_provider(&self, account_id: &str) {
         self.provider_registry.unregister(account_id).await;
+        self.domains_cache.invalidate(account_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::MemoryCredentialStore;
+    use crate::traits::InMemoryProviderRegistry;
+    use dns_orchestrator_provider::{MetricsLayer, MockProvider, ProviderLayer, ProviderOperation};
+
+    #[tokio::test]
+    async fn test_credentials_reports_invalid_fields() {
+        let service = CredentialManagementService::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(InMemoryProviderRegistry::new()),
+        );
+
+        let result = service
+            .test_credentials(&ProviderType::Cloudflare, &HashMap::new(), true)
+            .await;
+        assert!(!result.valid);
+        assert!(result.error.is_some());
+        assert!(service.load_all_credentials().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_probe_provider_counts_domains() {
+        let metrics = MetricsLayer::new();
+        let provider = metrics.layer(Arc::new(MockProvider::empty(Some("example.com"))));
+
+        let result = CredentialManagementService::probe_provider(provider.as_ref(), true).await;
+        assert!(result.valid);
+        assert_eq!(result.domain_count, Some(1));
+        assert!(result.error.is_none());
+
+        let result = CredentialManagementService::probe_provider(provider.as_ref(), false).await;
+        assert!(result.valid);
+        assert_eq!(result.domain_count, None);
+        assert_eq!(metrics.snapshot()[&ProviderOperation::ListDomains].calls, 1);
     }
 }
@@ -1,29 +1,90 @@
 //! DNS 记录管理服务
 
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use dns_orchestrator_provider::ProviderError;
+use dns_orchestrator_provider::{
+    default_ttl_for, ensure_unchanged, provider_metadata, translate_line, validate_record,
+    validate_record_support, BatchCreateFailure, DnsProvider, ProviderDomain, ProviderError,
+    RecordIdentity,
+};
+use futures::StreamExt;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 use crate::error::{CoreError, CoreResult};
-use crate::services::ServiceContext;
+use crate::services::{zone_file, RecordChangeEvent, RecordChangeKind, ServiceContext};
 use crate::types::{
-    BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, RecordQueryParams, UpdateDnsRecordRequest,
+    AccountOverview, AppDomain, BatchCreateRequest, BatchCreateResult, BatchDeleteFailure,
+    BatchDeleteRequest, BatchDeleteResult, CloneRecordResult, CloneRecordStatus, CloneZoneOptions,
+    CloneZoneResult, CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainRecordsPreview,
+    IdempotencyEntry, OverviewPartialError, PaginatedResponse, PaginationParams,
+    PatchDnsRecordRequest, ProviderSelfTestReport, RecordDifference, RecordQueryParams,
+    RecordSearchMatch, RecordSearchQuery, ReplaceRecordValueFailure, ReplaceRecordValueRequest,
+    ReplaceRecordValueResult, SelfTestStep, SelfTestStepKind, TrashedRecord,
+    UpdateDnsRecordRequest, ZoneDiff, ZoneFileExport, ZoneFileImportResult, ZoneRecordTypeSummary,
+    ZoneStats,
 };
+use crate::utils::idn;
+
+/// 全量拉取记录时的分页大小
+const ALL_RECORDS_PAGE_SIZE: u32 = 100;
+
+/// 批量替换记录值时的最大并发数
+const BATCH_UPDATE_CONCURRENCY: usize = 5;
+
+/// 跨域名搜索时同时拉取记录的域名数
+const SEARCH_CONCURRENCY: usize = 4;
+
+/// 账号概览时同时拉取首页记录的域名数
+const OVERVIEW_CONCURRENCY: usize = 4;
+
+/// 账号概览默认每个域名拉取的记录数
+const OVERVIEW_DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// 账号概览每个域名最多拉取的记录数
+const OVERVIEW_MAX_PAGE_SIZE: u32 = 100;
+
+/// 服务商自检使用的临时记录名
+const SELF_TEST_RECORD_NAME: &str = "_dnso-test";
+
+/// 服务商自检临时记录的 TTL
+const SELF_TEST_TTL: u32 = 600;
+
+/// 回收站默认保留天数
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+/// 全量记录缓存有效期
+const RECORDS_CACHE_TTL: Duration = Duration::from_mins(1);
+
+/// 幂等键有效期
+const IDEMPOTENCY_WINDOW: Duration = Duration::from_mins(10);
 
 /// DNS 记录管理服务
 pub struct DnsService {
     ctx: Arc<ServiceContext>,
+    /// 全量记录缓存（`account_id/domain_id` -> (拉取时间, 记录)），记录变更时失效
+    records_cache: Mutex<HashMap<String, (Instant, Vec<DnsRecord>)>>,
 }
 
 impl DnsService {
     /// 创建 DNS 服务实例
     #[must_use]
     pub fn new(ctx: Arc<ServiceContext>) -> Self {
-        Self { ctx }
+        Self {
+            ctx,
+            records_cache: Mutex::new(HashMap::new()),
+        }
     }
 
     /// 列出域名下的所有 DNS 记录（分页 + 搜索）
+    ///
+    /// `cursor` 为上一页返回的 `next_cursor`，仅游标分页的服务商使用
+    #[allow(clippy::too_many_arguments)]
     pub async fn list_records(
         &self,
         account_id: &str,
@@ -32,14 +93,18 @@ impl DnsService {
         page_size: Option<u32>,
         keyword: Option<String>,
         record_type: Option<DnsRecordType>,
+        cursor: Option<String>,
     ) -> CoreResult<PaginatedResponse<DnsRecord>> {
         let provider = self.ctx.get_provider(account_id).await?;
 
+        // 服务商按 ASCII 名称搜索；无法编码的关键词（如部分标签）原样传递
+        let keyword = keyword.map(|k| idn::to_ascii(&k).unwrap_or(k));
         let params = RecordQueryParams {
             page: page.unwrap_or(1),
             page_size: page_size.unwrap_or(20),
             keyword,
             record_type,
+            cursor,
         };
 
         match provider.list_records(domain_id, &params).await {
@@ -48,57 +113,420 @@ impl DnsService {
         }
     }
 
+    /// 按 ID 获取单条 DNS 记录
+    pub async fn get_record(
+        &self,
+        account_id: &str,
+        record_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<DnsRecord> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        match provider.get_record(record_id, domain_id).await {
+            Ok(record) => Ok(record),
+            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        }
+    }
+
     /// 创建 DNS 记录
     pub async fn create_record(
         &self,
         account_id: &str,
-        request: CreateDnsRecordRequest,
+        mut request: CreateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
+        self.ctx.maintenance.ensure_writable()?;
+        request.name = idn::to_ascii(&request.name)?;
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.create_record(&request).await {
-            Ok(record) => Ok(record),
+        apply_default_ttl(provider.id(), &mut request);
+        check_record(
+            provider.id(),
+            &request.record_type,
+            &request.name,
+            &request.value,
+            request.priority,
+            request.weight,
+            request.ttl,
+        )?;
+        let result = provider.create_record(&request).await;
+        self.invalidate_records_cache(account_id, &request.domain_id);
+        match result {
+            Ok(record) => {
+                self.ctx.notify_record_change(RecordChangeEvent::for_record(
+                    RecordChangeKind::Created,
+                    account_id,
+                    record.clone(),
+                ));
+                Ok(record)
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 幂等创建 DNS 记录
+    ///
+    /// 已存在内容相同（`record_identity` 一致）的记录时直接返回该记录，不再创建；
+    /// 同名记录与 CNAME 不能共存或服务商报告记录已存在时返回 `Conflict`
+    pub async fn create_record_idempotent(
+        &self,
+        account_id: &str,
+        mut request: CreateDnsRecordRequest,
+    ) -> CoreResult<DnsRecord> {
+        self.ctx.maintenance.ensure_writable()?;
+        request.name = idn::to_ascii(&request.name)?;
+        let same_name = self
+            .find_records_by_name(account_id, &request.domain_id, &request.name)
+            .await?;
+
+        let identity = request.record_identity();
+        if let Some(existing) = same_name.iter().find(|r| r.record_identity() == identity) {
+            log::info!(
+                "Record {} {:?} already exists, skip creating",
+                request.name,
+                request.record_type
+            );
+            return Ok(existing.clone());
+        }
+
+        // CNAME 不能与同名的其他记录共存
+        let cname_conflict = same_name.iter().any(|r| {
+            r.record_type == DnsRecordType::Cname || request.record_type == DnsRecordType::Cname
+        });
+        if cname_conflict {
+            return Err(CoreError::Conflict(format!(
+                "记录 {} 已存在不兼容的同名记录",
+                request.name
+            )));
+        }
+
+        let name = request.name.clone();
+        match self.create_record(account_id, request).await {
+            Err(CoreError::Provider(ProviderError::RecordExists { .. })) => {
+                Err(CoreError::Conflict(format!("记录 {name} 已存在且值不同")))
+            }
+            result => result,
+        }
+    }
+
+    /// 查找指定名称的所有记录（遍历全部分页）
+    async fn find_records_by_name(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        name: &str,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        // 根记录无法通过关键词搜索，需全量拉取后本地过滤
+        let keyword = (!name.is_empty() && name != "@").then(|| name.to_string());
+        let mut records = Vec::new();
+        let mut page = 1;
+        let mut cursor = None;
+
+        loop {
+            let response = self
+                .list_records(
+                    account_id,
+                    domain_id,
+                    Some(page),
+                    Some(ALL_RECORDS_PAGE_SIZE),
+                    keyword.clone(),
+                    None,
+                    cursor,
+                )
+                .await?;
+            records.extend(
+                response
+                    .items
+                    .into_iter()
+                    .filter(|r| r.name.eq_ignore_ascii_case(name)),
+            );
+            if !response.has_more {
+                break;
+            }
+            page += 1;
+            cursor = response.next_cursor;
+        }
+
+        Ok(records)
+    }
+
     /// 更新 DNS 记录
+    ///
+    /// 请求带有 `expected_version` / `expected_value` 时先获取当前记录，
+    /// 记录在读取后已被修改则返回 `ProviderError::Conflict`；记录没有修改时间时
+    /// `expected_version` 无法比较，返回 `ProviderError::InvalidParameter`。
+    /// 比对与更新之间仍有竞态窗口，见 `dns_orchestrator_provider::ensure_unchanged`
     pub async fn update_record(
         &self,
         account_id: &str,
         record_id: &str,
-        request: UpdateDnsRecordRequest,
+        mut request: UpdateDnsRecordRequest,
     ) -> CoreResult<DnsRecord> {
+        self.ctx.maintenance.ensure_writable()?;
+        request.name = idn::to_ascii(&request.name)?;
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.update_record(record_id, &request).await {
-            Ok(record) => Ok(record),
+        check_record(
+            provider.id(),
+            &request.record_type,
+            &request.name,
+            &request.value,
+            request.priority,
+            request.weight,
+            request.ttl,
+        )?;
+        if request.has_precondition() {
+            let current = match provider.get_record(record_id, &request.domain_id).await {
+                Ok(record) => record,
+                Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+            };
+            ensure_unchanged(&current, &request, provider.id())?;
+        }
+        let result = provider.update_record(record_id, &request).await;
+        self.invalidate_records_cache(account_id, &request.domain_id);
+        match result {
+            Ok(record) => {
+                self.ctx.notify_record_change(RecordChangeEvent::for_record(
+                    RecordChangeKind::Updated,
+                    account_id,
+                    record.clone(),
+                ));
+                Ok(record)
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 部分更新 DNS 记录
+    ///
+    /// 先获取现有记录，仅覆盖请求中提供的字段后再提交完整更新
+    pub async fn patch_record(
+        &self,
+        account_id: &str,
+        record_id: &str,
+        request: PatchDnsRecordRequest,
+    ) -> CoreResult<DnsRecord> {
+        self.ctx.maintenance.ensure_writable()?;
+        let existing = self
+            .get_record(account_id, record_id, &request.domain_id)
+            .await?;
+        self.update_record(account_id, record_id, request.merge_into(existing))
+            .await
+    }
+
     /// 删除 DNS 记录
+    ///
+    /// 只删除 ID 对应的单个值，同名同类型的其他值保持不变。
+    /// 启用回收站或 Webhook 时先获取记录快照，删除成功后存入回收站并随事件推送
     pub async fn delete_record(
         &self,
         account_id: &str,
         record_id: &str,
         domain_id: &str,
     ) -> CoreResult<()> {
+        self.ctx.maintenance.ensure_writable()?;
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let snapshot = if self.ctx.trash_repository.is_some() || self.ctx.webhook.is_some() {
+            match provider.get_record(record_id, domain_id).await {
+                Ok(record) => Some(record),
+                Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+            }
+        } else {
+            None
+        };
+
+        let result = provider.delete_record(record_id, domain_id).await;
+        self.invalidate_records_cache(account_id, domain_id);
+        match result {
+            Ok(()) => {
+                self.notify_deleted(account_id, domain_id, record_id, snapshot.clone());
+                self.move_to_trash(account_id, snapshot.into_iter().collect())
+                    .await;
+                Ok(())
+            }
+            Err(e) => Err(self.handle_provider_error(account_id, e).await),
+        }
+    }
+
+    /// 删除整个 `RRset`（指定名称 + 类型下的全部记录值）
+    ///
+    /// `delete_record` 只删除单个值；需要清空同名同类型的所有值时使用此方法。
+    /// 启用 Webhook 时先获取该 `RRset` 的记录，删除成功后逐条推送事件
+    pub async fn delete_rrset(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> CoreResult<()> {
+        self.ctx.maintenance.ensure_writable()?;
+        let name = idn::to_ascii(name)?;
         let provider = self.ctx.get_provider(account_id).await?;
-        match provider.delete_record(record_id, domain_id).await {
-            Ok(()) => Ok(()),
+        let snapshot = if self.ctx.webhook.is_some() {
+            self.find_records_by_name(account_id, domain_id, &name)
+                .await?
+                .into_iter()
+                .filter(|r| r.record_type == *record_type)
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let result = provider.delete_rrset(domain_id, &name, record_type).await;
+        self.invalidate_records_cache(account_id, domain_id);
+        match result {
+            Ok(()) => {
+                for record in snapshot {
+                    self.notify_deleted(account_id, domain_id, &record.id.clone(), Some(record));
+                }
+                Ok(())
+            }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 批量创建 DNS 记录
+    ///
+    /// 先逐条校验，再通过服务商的批量创建接口一次性提交全部合法记录，单条失败不影响其他记录
+    pub async fn batch_create_records(
+        &self,
+        account_id: &str,
+        request: BatchCreateRequest,
+    ) -> CoreResult<BatchCreateResult> {
+        self.ctx.maintenance.ensure_writable()?;
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let domain_id = request.domain_id;
+        let mut records = request.records;
+        for record in &mut records {
+            apply_default_ttl(provider.id(), record);
+        }
+        let results = Self::create_records(&provider, &domain_id, records).await;
+        self.invalidate_records_cache(account_id, &domain_id);
+
+        let mut created = Vec::new();
+        let mut failures = Vec::new();
+        let mut credentials_invalid = false;
+
+        for (request_index, result) in results.into_iter().enumerate() {
+            match result {
+                Ok(record) => {
+                    self.ctx.notify_record_change(RecordChangeEvent::for_record(
+                        RecordChangeKind::Created,
+                        account_id,
+                        record.clone(),
+                    ));
+                    created.push(record);
+                }
+                Err((record, e)) => {
+                    credentials_invalid |= e.is_invalid_credentials();
+                    failures.push(BatchCreateFailure {
+                        request_index,
+                        record_name: record.name,
+                        reason: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        if credentials_invalid {
+            self.ctx
+                .mark_account_invalid(account_id, "凭证已失效")
+                .await;
+        }
+
+        Ok(BatchCreateResult {
+            success_count: created.len(),
+            failed_count: failures.len(),
+            created,
+            failures,
+        })
+    }
+
+    /// 编码记录名并校验后通过 `batch_create_records` 一次性提交，结果与 `records` 顺序一致
+    ///
+    /// 名称无法编码或校验失败的记录单独计为失败；服务商按条报告失败项，其余记录计为成功。
+    /// 批量请求整体失败时，所有已提交的记录均计为失败
+    async fn create_records(
+        provider: &Arc<dyn DnsProvider>,
+        domain_id: &str,
+        records: Vec<CreateDnsRecordRequest>,
+    ) -> Vec<CreateOutcome> {
+        let mut results: Vec<Option<CreateOutcome>> = Vec::with_capacity(records.len());
+        let mut valid = Vec::with_capacity(records.len());
+        let mut positions = Vec::with_capacity(records.len());
+        for mut record in records {
+            record.domain_id = domain_id.to_string();
+            match prepare_record(provider.id(), &mut record) {
+                Ok(()) => {
+                    positions.push(results.len());
+                    results.push(None);
+                    valid.push(record);
+                }
+                Err(e) => results.push(Some(Err((record, e)))),
+            }
+        }
+        if valid.is_empty() {
+            return results.into_iter().flatten().collect();
+        }
+
+        match provider.batch_create_records(&valid).await {
+            Ok(batch) => {
+                let mut reasons: HashMap<usize, String> = batch
+                    .failures
+                    .into_iter()
+                    .map(|f| (f.request_index, f.reason))
+                    .collect();
+                // `created_records` 按请求顺序排列且不含失败项
+                let mut created = batch.created_records.into_iter();
+                for (index, record) in valid.into_iter().enumerate() {
+                    let outcome = match reasons.remove(&index) {
+                        Some(reason) => Err(CreateFailure::Batch(reason)),
+                        None => created
+                            .next()
+                            .ok_or_else(|| CreateFailure::Batch("服务商未返回创建结果".into())),
+                    };
+                    results[positions[index]] = Some(outcome.map_err(|e| (record, e)));
+                }
+            }
+            Err(e) => {
+                for (index, record) in valid.into_iter().enumerate() {
+                    results[positions[index]] =
+                        Some(Err((record, CreateFailure::Provider(e.clone()))));
+                }
+            }
+        }
+        results.into_iter().flatten().collect()
+    }
+
     /// 批量删除 DNS 记录
+    ///
+    /// 启用回收站或 Webhook 时先拉取域名下的全部记录作为快照，删除成功的记录存入回收站并随事件推送；
+    /// `dry_run` 为 true 时只解析目标记录，不执行删除
     pub async fn batch_delete_records(
         &self,
         account_id: &str,
         request: BatchDeleteRequest,
+        dry_run: bool,
     ) -> CoreResult<BatchDeleteResult> {
+        if dry_run {
+            return self.preview_batch_delete(account_id, request).await;
+        }
+        self.ctx.maintenance.ensure_writable()?;
         let provider = self.ctx.get_provider(account_id).await?;
 
+        let mut snapshots: HashMap<String, DnsRecord> =
+            if self.ctx.trash_repository.is_some() || self.ctx.webhook.is_some() {
+                self.list_all_records(account_id, &request.domain_id)
+                    .await?
+                    .into_iter()
+                    .filter(|r| request.record_ids.contains(&r.id))
+                    .map(|r| (r.id.clone(), r))
+                    .collect()
+            } else {
+                HashMap::new()
+            };
+
         let mut success_count = 0;
         let mut failures = Vec::new();
+        let mut deleted = Vec::new();
 
         // 并行删除所有记录
         let delete_futures: Vec<_> = request
@@ -118,10 +546,21 @@ impl DnsService {
             .collect();
 
         let results = futures::future::join_all(delete_futures).await;
+        self.invalidate_records_cache(account_id, &request.domain_id);
 
         for result in results {
             match result {
-                Ok(_) => success_count += 1,
+                Ok(record_id) => {
+                    success_count += 1;
+                    let snapshot = snapshots.remove(&record_id);
+                    self.notify_deleted(
+                        account_id,
+                        &request.domain_id,
+                        &record_id,
+                        snapshot.clone(),
+                    );
+                    deleted.extend(snapshot);
+                }
                 Err((record_id, e)) => {
                     // 检查是否是凭证失效
                     if let ProviderError::InvalidCredentials { .. } = &e {
@@ -137,20 +576,2643 @@ impl DnsService {
             }
         }
 
+        self.move_to_trash(account_id, deleted).await;
+
         Ok(BatchDeleteResult {
             success_count,
             failed_count: failures.len(),
             failures,
+            dry_run: false,
+            targets: Vec::new(),
         })
     }
 
-    /// 处理 Provider 错误，如果是凭证失效则更新账户状态
-    async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
-        if let ProviderError::InvalidCredentials { .. } = &err {
-            self.ctx
-                .mark_account_invalid(account_id, "凭证已失效")
-                .await;
+    /// 预演批量删除：返回将被删除的记录，找不到的 ID 计为失败
+    async fn preview_batch_delete(
+        &self,
+        account_id: &str,
+        request: BatchDeleteRequest,
+    ) -> CoreResult<BatchDeleteResult> {
+        let mut existing: HashMap<String, DnsRecord> = self
+            .list_all_records(account_id, &request.domain_id)
+            .await?
+            .into_iter()
+            .map(|r| (r.id.clone(), r))
+            .collect();
+
+        let mut targets = Vec::new();
+        let mut failures = Vec::new();
+        for record_id in request.record_ids {
+            match existing.remove(&record_id) {
+                Some(record) => targets.push(record),
+                None => failures.push(BatchDeleteFailure {
+                    reason: CoreError::RecordNotFound(record_id.clone()).to_string(),
+                    record_id,
+                }),
+            }
+        }
+
+        Ok(BatchDeleteResult {
+            success_count: targets.len(),
+            failed_count: failures.len(),
+            failures,
+            dry_run: true,
+            targets,
+        })
+    }
+
+    /// 列出域名下的全部 DNS 记录（遍历全部分页，带短期缓存）
+    pub async fn list_all_records(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<Vec<DnsRecord>> {
+        let key = format!("{account_id}/{domain_id}");
+        if let Ok(cache) = self.records_cache.lock() {
+            if let Some((fetched_at, records)) = cache.get(&key) {
+                if fetched_at.elapsed() < RECORDS_CACHE_TTL {
+                    return Ok(records.clone());
+                }
+            }
+        }
+
+        let mut records = Vec::new();
+        let mut page = 1;
+        let mut cursor = None;
+        loop {
+            let response = self
+                .list_records(
+                    account_id,
+                    domain_id,
+                    Some(page),
+                    Some(ALL_RECORDS_PAGE_SIZE),
+                    None,
+                    None,
+                    cursor,
+                )
+                .await?;
+            records.extend(response.items);
+            if !response.has_more {
+                break;
+            }
+            page += 1;
+            cursor = response.next_cursor;
+        }
+
+        if let Ok(mut cache) = self.records_cache.lock() {
+            cache.insert(key, (Instant::now(), records.clone()));
+        }
+        Ok(records)
+    }
+
+    /// 跨域名搜索账号下的记录
+    ///
+    /// 开销较大：需要翻页拉取全部域名，再逐个域名全量拉取记录
+    /// （约 `域名页数 + Σ 每个域名的记录页数` 次 API 调用），
+    /// 域名并发数限制为 `SEARCH_CONCURRENCY`；记录拉取复用短期缓存。
+    /// 任一域名拉取失败时整体返回错误
+    pub async fn search_records(
+        &self,
+        account_id: &str,
+        query: &RecordSearchQuery,
+    ) -> CoreResult<Vec<RecordSearchMatch>> {
+        let domains = self.list_all_domains(account_id).await?;
+        self.collect_matches(account_id, domains, |r| query.matches(r))
+            .await
+    }
+
+    /// 批量替换记录值：将指定类型中值为 `from_value` 的记录逐条更新为 `to_value`
+    ///
+    /// `domain_id` 为空时遍历账号下全部域名（开销同 `search_records`）。
+    /// 值按记录身份规则比较（忽略主机名大小写与末尾的点）；
+    /// 单条更新失败不影响其他记录。`dry_run` 为 true 时只返回将被更新的记录
+    pub async fn replace_record_value(
+        &self,
+        account_id: &str,
+        request: ReplaceRecordValueRequest,
+        dry_run: bool,
+    ) -> CoreResult<ReplaceRecordValueResult> {
+        if !dry_run {
+            self.ctx.maintenance.ensure_writable()?;
+        }
+        if request.to_value.trim().is_empty() {
+            return Err(CoreError::ValidationError("新值不能为空".to_string()));
+        }
+
+        let domains = match &request.domain_id {
+            Some(domain_id) => {
+                let provider = self.ctx.get_provider(account_id).await?;
+                match provider.get_domain(domain_id).await {
+                    Ok(domain) => vec![domain],
+                    Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+                }
+            }
+            None => self.list_all_domains(account_id).await?,
+        };
+
+        let targets = self
+            .collect_matches(account_id, domains, |r| {
+                r.record_type == request.record_type
+                    && r.record_identity()
+                        == RecordIdentity::new(
+                            &r.name,
+                            &r.record_type,
+                            &request.from_value,
+                            r.priority,
+                            r.line.as_deref(),
+                        )
+            })
+            .await?;
+
+        if dry_run {
+            return Ok(ReplaceRecordValueResult {
+                changed_count: targets.len(),
+                dry_run: true,
+                targets,
+                ..Default::default()
+            });
+        }
+
+        let results: Vec<_> = futures::stream::iter(targets)
+            .map(|target| {
+                let to_value = request.to_value.clone();
+                async move {
+                    let record = &target.record;
+                    let update = UpdateDnsRecordRequest {
+                        domain_id: record.domain_id.clone(),
+                        record_type: record.record_type.clone(),
+                        name: record.name.clone(),
+                        value: to_value,
+                        ttl: record.ttl,
+                        priority: record.priority,
+                        proxied: record.proxied,
+                        line: record.line.clone(),
+                        weight: record.weight,
+                        comment: record.comment.clone(),
+                        tags: record.tags.clone(),
+                        expected_version: None,
+                        expected_value: Some(record.value.clone()),
+                    };
+                    let result = self.update_record(account_id, &record.id, update).await;
+                    (target, result)
+                }
+            })
+            .buffered(BATCH_UPDATE_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut result = ReplaceRecordValueResult::default();
+        for (target, outcome) in results {
+            match outcome {
+                Ok(_) => result.changed_count += 1,
+                Err(e) => result.failures.push(ReplaceRecordValueFailure {
+                    domain_name: target.domain_name,
+                    record_id: target.record.id,
+                    name: target.record.name,
+                    reason: e.to_string(),
+                }),
+            }
+        }
+        result.failed_count = result.failures.len();
+        Ok(result)
+    }
+
+    /// 翻页拉取账号下的全部域名
+    async fn list_all_domains(&self, account_id: &str) -> CoreResult<Vec<ProviderDomain>> {
+        let provider = self.ctx.get_provider(account_id).await?;
+
+        let mut domains = Vec::new();
+        let mut params = PaginationParams {
+            page: 1,
+            page_size: ALL_RECORDS_PAGE_SIZE,
+            name_filter: None,
+        };
+        loop {
+            let response = match provider.list_domains(&params).await {
+                Ok(response) => response,
+                Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+            };
+            domains.extend(response.items);
+            if !response.has_more {
+                break;
+            }
+            params.page += 1;
+        }
+        Ok(domains)
+    }
+
+    /// 以有限并发拉取各域名的全部记录，返回满足条件的记录；任一域名拉取失败时整体返回错误
+    async fn collect_matches(
+        &self,
+        account_id: &str,
+        domains: Vec<ProviderDomain>,
+        predicate: impl Fn(&DnsRecord) -> bool,
+    ) -> CoreResult<Vec<RecordSearchMatch>> {
+        let predicate = &predicate;
+        let results: Vec<_> = futures::stream::iter(domains)
+            .map(|domain| async move {
+                let records = self.list_all_records(account_id, &domain.id).await?;
+                Ok::<_, CoreError>(
+                    records
+                        .into_iter()
+                        .filter(|r| predicate(r))
+                        .map(|record| RecordSearchMatch {
+                            domain_name: domain.name.clone(),
+                            record,
+                        })
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .buffered(SEARCH_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut matches = Vec::new();
+        for result in results {
+            matches.extend(result?);
+        }
+        Ok(matches)
+    }
+
+    /// 账号概览：拉取全部域名及每个域名的首页记录，供打开账号时一次加载
+    ///
+    /// 域名列表拉取失败时整体返回错误；单个域名的记录拉取失败记入 `partial_errors`，
+    /// 不影响其他域名。域名并发数限制为 `OVERVIEW_CONCURRENCY`，
+    /// 每个域名的记录数限制在 1 到 `OVERVIEW_MAX_PAGE_SIZE` 之间
+    pub async fn get_account_overview(
+        &self,
+        account_id: &str,
+        page_size: Option<u32>,
+    ) -> CoreResult<AccountOverview> {
+        let page_size = page_size
+            .unwrap_or(OVERVIEW_DEFAULT_PAGE_SIZE)
+            .clamp(1, OVERVIEW_MAX_PAGE_SIZE);
+
+        let domains = self
+            .list_all_domains(account_id)
+            .await?
+            .into_iter()
+            .map(|d| AppDomain::from_provider(d, account_id.to_string()));
+
+        let results: Vec<_> = futures::stream::iter(domains)
+            .map(|domain| async move {
+                let records = self
+                    .list_records(
+                        account_id,
+                        &domain.id,
+                        Some(1),
+                        Some(page_size),
+                        None,
+                        None,
+                        None,
+                    )
+                    .await;
+                (domain, records)
+            })
+            .buffered(OVERVIEW_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut overview = AccountOverview {
+            account_id: account_id.to_string(),
+            domains: Vec::new(),
+            partial_errors: Vec::new(),
+        };
+        for (domain, records) in results {
+            match records {
+                Ok(records) => overview
+                    .domains
+                    .push(DomainRecordsPreview { domain, records }),
+                Err(e) => overview.partial_errors.push(OverviewPartialError {
+                    domain_id: domain.id,
+                    domain_name: domain.name,
+                    message: e.to_string(),
+                }),
+            }
+        }
+        Ok(overview)
+    }
+
+    /// 导出域名下全部记录为 BIND 区域文件
+    pub async fn export_zone_file(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<ZoneFileExport> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        let domain = match provider.get_domain(domain_id).await {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let records = self.list_all_records(account_id, domain_id).await?;
+
+        Ok(ZoneFileExport {
+            filename: format!("{}.zone", domain.name.trim_end_matches('.')),
+            content: zone_file::to_zone_file(&domain.name, &records),
+            record_count: records.len(),
+        })
+    }
+
+    /// 从 BIND 区域文件导入记录
+    ///
+    /// 逐条调用 `create_record`（有限并发），单条失败不影响其他记录；
+    /// SOA 总是跳过，顶点 NS 仅在 `include_apex_ns` 为 true 时导入；
+    /// `dry_run` 为 true 时只解析和校验，返回将被创建的记录
+    pub async fn import_zone_file(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        content: &str,
+        include_apex_ns: bool,
+        dry_run: bool,
+    ) -> CoreResult<ZoneFileImportResult> {
+        if !dry_run {
+            self.ctx.maintenance.ensure_writable()?;
+        }
+        let provider = self.ctx.get_provider(account_id).await?;
+        let domain = match provider.get_domain(domain_id).await {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+
+        let parsed = zone_file::parse_zone_file(content, &domain.name, domain_id, include_apex_ns);
+        if dry_run {
+            let mut planned = Vec::new();
+            let mut errors = parsed.errors;
+            // 与实际导入（`batch_create_records`）相同的默认 TTL 与校验
+            for mut record in parsed.records {
+                apply_default_ttl(provider.id(), &mut record);
+                match prepare_record(provider.id(), &mut record) {
+                    Ok(()) => planned.push(record),
+                    Err(e) => errors.push(format!("{}: {e}", record.name)),
+                }
+            }
+            return Ok(ZoneFileImportResult {
+                success_count: planned.len(),
+                failed_count: errors.len(),
+                created: Vec::new(),
+                errors,
+                skipped: parsed.skipped,
+                dry_run: true,
+                planned,
+            });
+        }
+
+        let created = self
+            .batch_create_records(
+                account_id,
+                BatchCreateRequest {
+                    domain_id: domain_id.to_string(),
+                    records: parsed.records,
+                },
+            )
+            .await?;
+
+        let mut errors = parsed.errors;
+        errors.extend(
+            created
+                .failures
+                .into_iter()
+                .map(|f| format!("{}: {}", f.record_name, f.reason)),
+        );
+        Ok(ZoneFileImportResult {
+            success_count: created.success_count,
+            failed_count: errors.len(),
+            created: created.created,
+            errors,
+            skipped: parsed.skipped,
+            dry_run: false,
+            planned: Vec::new(),
+        })
+    }
+
+    /// 汇总域名的记录统计：总数、各类型数量与开启代理的记录数
+    ///
+    /// 基于 `list_all_records`，重复调用命中记录列表缓存
+    pub async fn zone_stats(&self, account_id: &str, domain_id: &str) -> CoreResult<ZoneStats> {
+        let records = self.list_all_records(account_id, domain_id).await?;
+
+        let mut stats = ZoneStats::default();
+        for record in &records {
+            stats.total += 1;
+            *stats
+                .by_type
+                .entry(zone_file::type_name(&record.record_type).to_string())
+                .or_default() += 1;
+            if record.proxied == Some(true) {
+                stats.proxied += 1;
+            }
+        }
+        Ok(stats)
+    }
+
+    /// 统计域名下各记录类型的数量（`zone_stats` 的子集）
+    pub async fn zone_record_type_summary(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<ZoneRecordTypeSummary> {
+        let stats = self.zone_stats(account_id, domain_id).await?;
+        Ok(ZoneRecordTypeSummary {
+            domain_id: domain_id.to_string(),
+            total: stats.total,
+            by_type: stats.by_type,
+        })
+    }
+
+    /// 对比两个区域的记录（可跨账户、跨服务商），用于迁移后核对
+    ///
+    /// 记录按 (名称, 类型, 值, 优先级) 匹配，名称相对各自域名比较；
+    /// 匹配的记录再比较 TTL（`ignore_ttl` 为 true 时跳过）和代理状态（仅两侧都有时）
+    pub async fn diff_zones(
+        &self,
+        source_account_id: &str,
+        source_domain_id: &str,
+        target_account_id: &str,
+        target_domain_id: &str,
+        ignore_ttl: bool,
+    ) -> CoreResult<ZoneDiff> {
+        let ((source_domain, source_records), (target_domain, target_records)) = futures::try_join!(
+            self.load_zone(source_account_id, source_domain_id),
+            self.load_zone(target_account_id, target_domain_id),
+        )?;
+
+        let mut unmatched: HashMap<RecordIdentity, Vec<DnsRecord>> = HashMap::new();
+        for record in target_records {
+            unmatched
+                .entry(zone_identity(&record, &target_domain))
+                .or_default()
+                .push(record);
+        }
+
+        let mut diff = ZoneDiff::default();
+        for source in source_records {
+            let key = zone_identity(&source, &source_domain);
+            match unmatched.get_mut(&key).and_then(Vec::pop) {
+                Some(target) => {
+                    let ttl_differs = !ignore_ttl && source.ttl != target.ttl;
+                    let proxied_differs = matches!(
+                        (source.proxied, target.proxied),
+                        (Some(a), Some(b)) if a != b
+                    );
+                    if ttl_differs || proxied_differs {
+                        diff.differing.push(RecordDifference { source, target });
+                    }
+                }
+                None => diff.only_in_source.push(source),
+            }
+        }
+        diff.only_in_target = unmatched.into_values().flatten().collect();
+        // 按名称、值排序，结果与服务商返回顺序无关
+        let by_name =
+            |a: &DnsRecord, b: &DnsRecord| a.name.cmp(&b.name).then_with(|| a.value.cmp(&b.value));
+        diff.only_in_source.sort_by(by_name);
+        diff.only_in_target.sort_by(by_name);
+        Ok(diff)
+    }
+
+    /// 将源区域的记录复制到目标区域（可跨账户、跨服务商），用于迁移
+    ///
+    /// 顶点 NS 默认跳过；目标区域已有相同记录时跳过。`overwrite_existing` 为 true 时，
+    /// 先删除目标同名同类型下源区域没有的值，并同步已有记录的 TTL。
+    /// 解析线路按 `translate_line` 转换，目标服务商没有对应线路的记录跳过。
+    /// 新记录优先走原生批量创建，批量请求整体失败时逐条重试，单条失败不影响其他记录
+    pub async fn clone_zone(
+        &self,
+        source_account_id: &str,
+        source_domain_id: &str,
+        target_account_id: &str,
+        target_domain_id: &str,
+        options: &CloneZoneOptions,
+    ) -> CoreResult<CloneZoneResult> {
+        self.ctx.maintenance.ensure_writable()?;
+        let source_provider = self.ctx.get_provider(source_account_id).await?;
+        let provider = self.ctx.get_provider(target_account_id).await?;
+        let ((source_domain, source_records), (target_domain, target_records)) = futures::try_join!(
+            self.load_zone(source_account_id, source_domain_id),
+            self.load_zone(target_account_id, target_domain_id),
+        )?;
+        // 代理状态只在同一服务商之间有意义
+        let keep_proxied = source_provider.id() == provider.id();
+
+        // 目标区域现有记录按 (名称, 类型) 分组，匹配到的记录从分组中移除
+        let mut existing: HashMap<(String, &'static str), Vec<DnsRecord>> = HashMap::new();
+        for record in target_records {
+            existing
+                .entry(rrset_key(&record, &target_domain))
+                .or_default()
+                .push(record);
+        }
+
+        let mut result = CloneZoneResult::default();
+        let mut cloned_sets = HashSet::new();
+        let mut to_update = Vec::new();
+        let mut to_create = Vec::new();
+        for mut source in source_records {
+            let name = zone_file::relative_name(&source.name, &source_domain);
+            if source.record_type == DnsRecordType::Ns && name == "@" && !options.include_apex_ns {
+                result.push(clone_record_result(
+                    &source,
+                    name,
+                    CloneRecordStatus::Skipped,
+                    Some("顶点 NS 由目标服务商管理".to_string()),
+                ));
+                continue;
+            }
+            // 线路代码因服务商而异，转换为目标服务商的代码后再匹配
+            match translate_line(source.line.as_deref(), source_provider.id(), provider.id()) {
+                Ok(line) => source.line = line,
+                Err(reason) => {
+                    result.push(clone_record_result(
+                        &source,
+                        name,
+                        CloneRecordStatus::Skipped,
+                        Some(reason),
+                    ));
+                    continue;
+                }
+            }
+
+            let ttl = if options.preserve_ttl {
+                source.ttl
+            } else {
+                options.ttl
+            };
+            let key = zone_identity(&source, &source_domain);
+            let rrset = rrset_key(&source, &source_domain);
+            let matched = existing.get_mut(&rrset).and_then(|records| {
+                let index = records
+                    .iter()
+                    .position(|r| zone_identity(r, &target_domain) == key)?;
+                Some(records.swap_remove(index))
+            });
+            cloned_sets.insert(rrset);
+
+            match matched {
+                Some(target) if options.overwrite_existing && target.ttl != ttl => {
+                    to_update.push((target, ttl));
+                }
+                Some(_) => result.push(clone_record_result(
+                    &source,
+                    name,
+                    CloneRecordStatus::Skipped,
+                    Some("目标区域已存在".to_string()),
+                )),
+                None => to_create.push(CreateDnsRecordRequest {
+                    domain_id: target_domain_id.to_string(),
+                    record_type: source.record_type,
+                    name,
+                    value: source.value,
+                    ttl,
+                    priority: source.priority,
+                    proxied: source.proxied.filter(|_| keep_proxied),
+                    line: source.line,
+                    weight: weight_for(provider.id(), source.weight),
+                    comment: source.comment,
+                    tags: source.tags,
+                }),
+            }
+        }
+
+        let mut credentials_invalid = false;
+
+        // 先删除被覆盖的值，避免与新记录冲突（如 CNAME）
+        if options.overwrite_existing {
+            let stale = existing
+                .into_iter()
+                .filter(|(rrset, _)| cloned_sets.contains(rrset))
+                .flat_map(|(_, records)| records);
+            let mut deleted = Vec::new();
+            for record in stale {
+                let name = zone_file::relative_name(&record.name, &target_domain);
+                match provider.delete_record(&record.id, target_domain_id).await {
+                    Ok(()) => {
+                        result.push(clone_record_result(
+                            &record,
+                            name,
+                            CloneRecordStatus::Deleted,
+                            None,
+                        ));
+                        self.notify_deleted(
+                            target_account_id,
+                            target_domain_id,
+                            &record.id,
+                            Some(record.clone()),
+                        );
+                        deleted.push(record);
+                    }
+                    Err(e) => {
+                        credentials_invalid |=
+                            matches!(e, ProviderError::InvalidCredentials { .. });
+                        result.push(clone_record_result(
+                            &record,
+                            name,
+                            CloneRecordStatus::Failed,
+                            Some(e.to_string()),
+                        ));
+                    }
+                }
+            }
+            self.move_to_trash(target_account_id, deleted).await;
+        }
+
+        for (target, ttl) in to_update {
+            let request = UpdateDnsRecordRequest {
+                domain_id: target_domain_id.to_string(),
+                record_type: target.record_type.clone(),
+                name: target.name.clone(),
+                value: target.value.clone(),
+                ttl,
+                priority: target.priority,
+                proxied: target.proxied,
+                line: target.line.clone(),
+                weight: target.weight,
+                comment: target.comment.clone(),
+                tags: target.tags.clone(),
+                expected_version: None,
+                expected_value: None,
+            };
+            let name = zone_file::relative_name(&target.name, &target_domain);
+            let (status, detail) = match provider.update_record(&target.id, &request).await {
+                Ok(updated) => {
+                    self.ctx.notify_record_change(RecordChangeEvent::for_record(
+                        RecordChangeKind::Updated,
+                        target_account_id,
+                        updated,
+                    ));
+                    (CloneRecordStatus::Updated, None)
+                }
+                Err(e) => {
+                    credentials_invalid |= matches!(e, ProviderError::InvalidCredentials { .. });
+                    (CloneRecordStatus::Failed, Some(e.to_string()))
+                }
+            };
+            result.push(clone_record_result(&target, name, status, detail));
+        }
+
+        let outcomes = Self::create_records(&provider, target_domain_id, to_create).await;
+        for outcome in outcomes {
+            let (record_type, name, value, status, detail) = match outcome {
+                Ok(record) => {
+                    self.ctx.notify_record_change(RecordChangeEvent::for_record(
+                        RecordChangeKind::Created,
+                        target_account_id,
+                        record.clone(),
+                    ));
+                    (
+                        record.record_type,
+                        zone_file::relative_name(&record.name, &target_domain),
+                        record.value,
+                        CloneRecordStatus::Created,
+                        None,
+                    )
+                }
+                Err((request, e)) => {
+                    credentials_invalid |= e.is_invalid_credentials();
+                    (
+                        request.record_type,
+                        request.name,
+                        request.value,
+                        CloneRecordStatus::Failed,
+                        Some(e.to_string()),
+                    )
+                }
+            };
+            result.push(CloneRecordResult {
+                name,
+                record_type,
+                value,
+                status,
+                detail,
+            });
+        }
+
+        self.invalidate_records_cache(target_account_id, target_domain_id);
+
+        if credentials_invalid {
+            self.ctx
+                .mark_account_invalid(target_account_id, "凭证已失效")
+                .await;
+        }
+        Ok(result)
+    }
+
+    /// 服务商自检：创建临时 TXT 记录 `_dnso-test`，依次读取、更新、删除并记录每步耗时
+    ///
+    /// 已存在同名记录时拒绝执行，避免覆盖用户数据。单个步骤失败不返回错误，
+    /// 记入报告并跳过后续步骤；测试记录创建成功后总会尝试删除
+    pub async fn provider_self_test(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<ProviderSelfTestReport> {
+        self.ctx.maintenance.ensure_writable()?;
+        let provider = self.ctx.get_provider(account_id).await?;
+        if !self
+            .find_records_by_name(account_id, domain_id, SELF_TEST_RECORD_NAME)
+            .await?
+            .is_empty()
+        {
+            return Err(CoreError::Conflict(format!(
+                "记录 {SELF_TEST_RECORD_NAME} 已存在，请删除后再运行自检"
+            )));
+        }
+
+        let create = CreateDnsRecordRequest {
+            domain_id: domain_id.to_string(),
+            record_type: DnsRecordType::Txt,
+            name: SELF_TEST_RECORD_NAME.to_string(),
+            value: "dnso-self-test".to_string(),
+            ttl: SELF_TEST_TTL,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        let mut steps = Vec::new();
+
+        let started = Instant::now();
+        let created = provider.create_record(&create).await;
+        steps.push(self_test_step(
+            SelfTestStepKind::Create,
+            started,
+            created.as_ref().map(|_| ()).map_err(ToString::to_string),
+        ));
+
+        if let Ok(record) = created {
+            let started = Instant::now();
+            let read = match provider.get_record(&record.id, domain_id).await {
+                Ok(r) if r.record_identity() == create.record_identity() => Ok(()),
+                Ok(r) => Err(format!("读取到的值与写入不一致: {}", r.value)),
+                Err(e) => Err(e.to_string()),
+            };
+            let read_passed = read.is_ok();
+            steps.push(self_test_step(SelfTestStepKind::Read, started, read));
+
+            if read_passed {
+                let update = UpdateDnsRecordRequest {
+                    domain_id: domain_id.to_string(),
+                    record_type: DnsRecordType::Txt,
+                    name: SELF_TEST_RECORD_NAME.to_string(),
+                    value: "dnso-self-test-updated".to_string(),
+                    ttl: SELF_TEST_TTL,
+                    priority: None,
+                    proxied: None,
+                    line: None,
+                    weight: None,
+                    comment: None,
+                    tags: None,
+                    expected_version: None,
+                    expected_value: None,
+                };
+                let started = Instant::now();
+                let updated = provider.update_record(&record.id, &update).await;
+                steps.push(self_test_step(
+                    SelfTestStepKind::Update,
+                    started,
+                    updated.map(|_| ()).map_err(|e| e.to_string()),
+                ));
+            }
+
+            let started = Instant::now();
+            let deleted = provider.delete_record(&record.id, domain_id).await;
+            steps.push(self_test_step(
+                SelfTestStepKind::Delete,
+                started,
+                deleted.map_err(|e| e.to_string()),
+            ));
+        }
+        self.invalidate_records_cache(account_id, domain_id);
+
+        Ok(ProviderSelfTestReport {
+            provider: provider.id().to_string(),
+            domain_id: domain_id.to_string(),
+            record_name: SELF_TEST_RECORD_NAME.to_string(),
+            passed: steps.len() == 4 && steps.iter().all(|s| s.passed),
+            steps,
+        })
+    }
+
+    /// 获取域名名称及其全部记录
+    async fn load_zone(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+    ) -> CoreResult<(String, Vec<DnsRecord>)> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        let domain = match provider.get_domain(domain_id).await {
+            Ok(domain) => domain,
+            Err(e) => return Err(self.handle_provider_error(account_id, e).await),
+        };
+        let records = self.list_all_records(account_id, domain_id).await?;
+        Ok((domain.name, records))
+    }
+
+    /// 列出账户回收站中的记录（按删除时间倒序，未启用回收站时为空）
+    pub async fn list_trash(&self, account_id: &str) -> CoreResult<Vec<TrashedRecord>> {
+        match &self.ctx.trash_repository {
+            Some(trash) => trash.find_by_account(account_id).await,
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// 从回收站恢复记录：先取出快照再按快照重新创建记录，创建失败时放回回收站
+    ///
+    /// 取出是原子的，同一快照并发恢复时只会创建一次。重新创建的记录会获得新的 ID
+    pub async fn restore_record(&self, account_id: &str, trash_id: &str) -> CoreResult<DnsRecord> {
+        let trash = self
+            .ctx
+            .trash_repository
+            .as_ref()
+            .ok_or_else(|| CoreError::ValidationError("回收站未启用".to_string()))?;
+        let entry = trash
+            .take(account_id, trash_id)
+            .await?
+            .ok_or_else(|| CoreError::RecordNotFound(trash_id.to_string()))?;
+
+        let result = self
+            .recreate_from_trash(account_id, entry.record.clone())
+            .await;
+        if result.is_err() {
+            if let Err(e) = trash.save_all(std::slice::from_ref(&entry)).await {
+                log::warn!("Failed to return record {trash_id} to trash: {e}");
+            }
+        }
+        result
+    }
+
+    /// 按回收站快照重新创建记录
+    async fn recreate_from_trash(
+        &self,
+        account_id: &str,
+        record: DnsRecord,
+    ) -> CoreResult<DnsRecord> {
+        let provider = self.ctx.get_provider(account_id).await?;
+        self.create_record(
+            account_id,
+            CreateDnsRecordRequest {
+                domain_id: record.domain_id,
+                record_type: record.record_type,
+                name: record.name,
+                value: record.value,
+                ttl: record.ttl,
+                priority: record.priority,
+                proxied: record.proxied,
+                line: record.line,
+                weight: weight_for(provider.id(), record.weight),
+                comment: record.comment,
+                tags: record.tags,
+            },
+        )
+        .await
+    }
+
+    /// 清除回收站中超过保留天数的记录，返回清除数量
+    ///
+    /// `retention_days` 默认为 `DEFAULT_TRASH_RETENTION_DAYS`
+    pub async fn purge_trash(&self, retention_days: Option<u32>) -> CoreResult<usize> {
+        let Some(trash) = &self.ctx.trash_repository else {
+            return Ok(0);
+        };
+        let days = retention_days.unwrap_or(DEFAULT_TRASH_RETENTION_DAYS);
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(i64::from(days));
+        trash.purge_before(cutoff).await
+    }
+
+    /// 按幂等键执行写操作
+    ///
+    /// 执行前先原子地预占键，窗口期内同一账户下重复的键：
+    /// - 首次请求已完成时直接返回其结果，不再调用 `op`
+    /// - 首次请求仍在执行时返回 `Conflict`
+    /// - 操作或请求参数（`payload`）与首次不同时返回 `ValidationError`
+    ///
+    /// 失败的操作会释放预占的键，允许重试。未提供键或未启用幂等键仓库时直接执行
+    pub async fn run_idempotent<T, P, F, Fut>(
+        &self,
+        account_id: &str,
+        operation: &str,
+        key: Option<&str>,
+        payload: &P,
+        op: F,
+    ) -> CoreResult<T>
+    where
+        T: Serialize + DeserializeOwned,
+        P: Serialize + ?Sized,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = CoreResult<T>>,
+    {
+        let (Some(key), Some(repository)) = (key, &self.ctx.idempotency_repository) else {
+            return op().await;
+        };
+
+        let now = chrono::Utc::now();
+        let cutoff = now - chrono::Duration::from_std(IDEMPOTENCY_WINDOW).unwrap_or_default();
+        if let Err(e) = repository.purge_before(cutoff).await {
+            log::warn!("Failed to purge expired idempotency keys: {e}");
+        }
+
+        let mut entry = IdempotencyEntry {
+            account_id: account_id.to_string(),
+            key: key.to_string(),
+            operation: operation.to_string(),
+            request_hash: request_hash(payload)?,
+            response: None,
+            created_at: now,
+        };
+        if let Some(existing) = repository.reserve(&entry).await? {
+            if existing.operation != operation || existing.request_hash != entry.request_hash {
+                return Err(CoreError::ValidationError(format!(
+                    "幂等键已用于其他请求: {}",
+                    existing.operation
+                )));
+            }
+            let Some(response) = existing.response else {
+                return Err(CoreError::Conflict(format!(
+                    "幂等键 {key} 对应的请求仍在执行"
+                )));
+            };
+            log::debug!("Replaying {operation} for idempotency key {key}");
+            return serde_json::from_value(response)
+                .map_err(|e| CoreError::SerializationError(e.to_string()));
+        }
+
+        let result = match op().await {
+            Ok(result) => result,
+            Err(e) => {
+                if let Err(remove_err) = repository.remove(account_id, key).await {
+                    log::warn!("Failed to release idempotency key {key}: {remove_err}");
+                }
+                return Err(e);
+            }
+        };
+
+        // 操作已生效，保存失败只记录日志
+        match serde_json::to_value(&result) {
+            Ok(response) => {
+                entry.response = Some(response);
+                if let Err(e) = repository.save(&entry).await {
+                    log::warn!("Failed to save idempotency key {key}: {e}");
+                }
+            }
+            Err(e) => log::warn!("Failed to serialize result of {operation}: {e}"),
+        }
+        Ok(result)
+    }
+
+    /// 推送记录已删除事件（`snapshot` 为删除前的记录）
+    fn notify_deleted(
+        &self,
+        account_id: &str,
+        domain_id: &str,
+        record_id: &str,
+        snapshot: Option<DnsRecord>,
+    ) {
+        self.ctx.notify_record_change(RecordChangeEvent {
+            event: RecordChangeKind::Deleted,
+            account_id: account_id.to_string(),
+            domain_id: domain_id.to_string(),
+            record_id: record_id.to_string(),
+            record: snapshot,
+            timestamp: chrono::Utc::now(),
+        });
+    }
+
+    /// 将已删除记录的快照存入回收站
+    ///
+    /// 记录已在服务商侧删除，保存失败只记录日志，不影响删除结果
+    async fn move_to_trash(&self, account_id: &str, records: Vec<DnsRecord>) {
+        let Some(trash) = &self.ctx.trash_repository else {
+            return;
+        };
+        if records.is_empty() {
+            return;
+        }
+        let deleted_at = chrono::Utc::now();
+        let entries: Vec<TrashedRecord> = records
+            .into_iter()
+            .map(|record| TrashedRecord {
+                id: uuid::Uuid::new_v4().to_string(),
+                account_id: account_id.to_string(),
+                record,
+                deleted_at,
+            })
+            .collect();
+        if let Err(e) = trash.save_all(&entries).await {
+            log::warn!("Failed to save deleted records to trash: {e}");
+        }
+    }
+
+    /// 使指定域名的全量记录缓存失效
+    fn invalidate_records_cache(&self, account_id: &str, domain_id: &str) {
+        if let Ok(mut cache) = self.records_cache.lock() {
+            cache.remove(&format!("{account_id}/{domain_id}"));
+        }
+    }
+
+    /// 处理 Provider 错误，如果是凭证失效则更新账户状态
+    async fn handle_provider_error(&self, account_id: &str, err: ProviderError) -> CoreError {
+        if let ProviderError::InvalidCredentials { .. } = &err {
+            self.ctx
+                .mark_account_invalid(account_id, "凭证已失效")
+                .await;
+        }
+        CoreError::Provider(err)
+    }
+}
+
+/// 构造克隆区域的单条结果
+fn clone_record_result(
+    record: &DnsRecord,
+    name: String,
+    status: CloneRecordStatus,
+    detail: Option<String>,
+) -> CloneRecordResult {
+    CloneRecordResult {
+        name,
+        record_type: record.record_type.clone(),
+        value: record.value.clone(),
+        status,
+        detail,
+    }
+}
+
+/// 区域对比时的记录匹配键：名称转为相对域名后按 `RecordIdentity` 规范化，
+/// 不同服务商返回的同一记录（如带引号的 TXT、展开的 AAAA）得到相同的键
+fn zone_identity(record: &DnsRecord, domain_name: &str) -> RecordIdentity {
+    RecordIdentity::new(
+        &zone_file::relative_name(&record.name, domain_name),
+        &record.record_type,
+        &record.value,
+        record.priority,
+        record.line.as_deref(),
+    )
+}
+
+/// 记录所属 `RRset` 的键：(小写相对名称, 类型)
+fn rrset_key(record: &DnsRecord, domain_name: &str) -> (String, &'static str) {
+    (
+        zone_file::relative_name(&record.name, domain_name).to_lowercase(),
+        zone_file::type_name(&record.record_type),
+    )
+}
+
+/// 单条记录的创建结果
+type CreateOutcome = Result<DnsRecord, (CreateDnsRecordRequest, CreateFailure)>;
+
+/// 单条记录的创建失败原因
+enum CreateFailure {
+    /// 本地校验或单条创建返回的错误
+    Provider(ProviderError),
+    /// 原生批量创建中服务商报告的该条记录失败原因
+    Batch(String),
+    /// 记录名无法转换为 ASCII（Punycode）
+    InvalidName(CoreError),
+}
+
+impl CreateFailure {
+    fn is_invalid_credentials(&self) -> bool {
+        matches!(
+            self,
+            Self::Provider(ProviderError::InvalidCredentials { .. })
+        )
+    }
+}
+
+impl std::fmt::Display for CreateFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Provider(e) => e.fmt(f),
+            Self::Batch(reason) => f.write_str(reason),
+            Self::InvalidName(e) => e.fmt(f),
+        }
+    }
+}
+
+/// 幂等请求参数的摘要（SHA-256 十六进制）
+fn request_hash<P: Serialize + ?Sized>(payload: &P) -> CoreResult<String> {
+    let body =
+        serde_json::to_vec(payload).map_err(|e| CoreError::SerializationError(e.to_string()))?;
+    Ok(Sha256::digest(body)
+        .iter()
+        .fold(String::new(), |mut out, b| {
+            let _ = write!(out, "{b:02x}");
+            out
+        }))
+}
+
+/// 请求省略 TTL（为 0）时填入服务商默认 TTL
+fn apply_default_ttl(provider_id: &str, request: &mut CreateDnsRecordRequest) {
+    if request.ttl == 0 {
+        request.ttl = default_ttl_for(provider_id);
+    }
+}
+
+/// 批量创建前的准备：记录名转为 ASCII 后执行本地校验（批量创建与区域文件导入预演共用）
+fn prepare_record(
+    provider_id: &str,
+    record: &mut CreateDnsRecordRequest,
+) -> Result<(), CreateFailure> {
+    record.name = idn::to_ascii(&record.name).map_err(CreateFailure::InvalidName)?;
+    check_record(
+        provider_id,
+        &record.record_type,
+        &record.name,
+        &record.value,
+        record.priority,
+        record.weight,
+        record.ttl,
+    )
+    .map_err(CreateFailure::Provider)
+}
+
+/// 提交前的本地校验：记录内容格式，以及服务商元数据声明的支持范围（记录类型、权重、TTL）
+fn check_record(
+    provider: &str,
+    record_type: &DnsRecordType,
+    name: &str,
+    value: &str,
+    priority: Option<u16>,
+    weight: Option<u16>,
+    ttl: u32,
+) -> Result<(), ProviderError> {
+    validate_record(record_type, name, value, priority, provider)?;
+    validate_record_support(provider, record_type, weight, ttl)
+}
+
+/// 目标服务商不支持权重时丢弃权重（跨服务商克隆、恢复）
+fn weight_for(provider_id: &str, weight: Option<u16>) -> Option<u16> {
+    weight.filter(|_| provider_metadata(provider_id).is_none_or(|m| m.features.weight))
+}
+
+/// 生成自检步骤结果
+fn self_test_step(
+    step: SelfTestStepKind,
+    started: Instant,
+    result: Result<(), String>,
+) -> SelfTestStep {
+    SelfTestStep {
+        step,
+        passed: result.is_ok(),
+        duration_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        error: result.err(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::{
+        sign_webhook_payload, WebhookConfig, WebhookNotifier, WEBHOOK_SIGNATURE_HEADER,
+    };
+    use crate::test_support::{
+        MemoryAccountRepository, MemoryCredentialStore, MemoryIdempotencyRepository,
+        MemoryTrashRepository,
+    };
+    use crate::traits::{InMemoryProviderRegistry, ProviderRegistry};
+    use dns_orchestrator_provider::{
+        Interceptor, MetricsLayer, MockProvider, ProviderLayer, ProviderOperation,
+        Result as ProviderResult,
+    };
+
+    /// 只有空域名 `example.com` 的 Mock Provider
+    fn empty_provider() -> MockProvider {
+        MockProvider::empty(Some("example.com"))
+    }
+
+    async fn service_with_trash() -> (DnsService, Arc<MockProvider>) {
+        service_with_provider(empty_provider()).await
+    }
+
+    /// 以带调用统计的 Provider 替换账号 `account_id` 的 Provider
+    async fn register_with_metrics(
+        service: &DnsService,
+        account_id: &str,
+        provider: Arc<MockProvider>,
+    ) -> MetricsLayer {
+        let metrics = MetricsLayer::new();
+        service
+            .ctx
+            .provider_registry
+            .register(account_id.to_string(), metrics.layer(provider))
+            .await;
+        metrics
+    }
+
+    /// 指定操作的调用次数
+    fn calls(metrics: &MetricsLayer, operation: ProviderOperation) -> u64 {
+        metrics
+            .snapshot()
+            .get(&operation)
+            .map_or(0, |stats| stats.calls)
+    }
+
+    /// 写操作先等待 50ms 再执行，用于在写操作进行中插入读取
+    #[derive(Clone)]
+    struct SlowWrites;
+
+    impl Interceptor for SlowWrites {
+        async fn intercept<T, F, Fut>(
+            &self,
+            _provider: &'static str,
+            operation: ProviderOperation,
+            mut call: F,
+        ) -> ProviderResult<T>
+        where
+            T: Send,
+            F: FnMut() -> Fut + Send,
+            Fut: Future<Output = ProviderResult<T>> + Send,
+        {
+            if !operation.is_read_only() {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            call().await
+        }
+    }
+
+    /// 以写操作变慢的 Provider 替换账号 `account_id` 的 Provider
+    async fn register_slow_writes(
+        service: &DnsService,
+        account_id: &str,
+        provider: Arc<MockProvider>,
+    ) {
+        service
+            .ctx
+            .provider_registry
+            .register(account_id.to_string(), SlowWrites.layer(provider))
+            .await;
+    }
+
+    async fn service_with_provider(provider: MockProvider) -> (DnsService, Arc<MockProvider>) {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let provider = Arc::new(provider);
+        registry.register("acc".to_string(), provider.clone()).await;
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(MemoryAccountRepository::default()),
+            registry,
+        )
+        .with_trash_repository(Arc::new(MemoryTrashRepository::default()))
+        .with_idempotency_repository(Arc::new(MemoryIdempotencyRepository::default()));
+        (DnsService::new(Arc::new(ctx)), provider)
+    }
+
+    fn a_record(name: &str, value: &str) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: Some(true),
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_cache_invalidated_after_write_completes() {
+        let (service, provider) = service_with_trash().await;
+        register_slow_writes(&service, "acc", provider).await;
+        // 写操作进行中读取，缓存的是写入前的记录
+        let read_during_write = || async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            service
+                .list_all_records("acc", "example.com")
+                .await
+                .unwrap()
+        };
+
+        let (created, stale) = tokio::join!(
+            service.create_record("acc", a_record("www", "192.0.2.1")),
+            read_during_write()
+        );
+        assert!(stale.is_empty());
+        let record = created.unwrap();
+        let records = service
+            .list_all_records("acc", "example.com")
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+
+        let (deleted, stale) = tokio::join!(
+            service.delete_record("acc", &record.id, "example.com"),
+            read_during_write()
+        );
+        assert_eq!(stale.len(), 1);
+        deleted.unwrap();
+        let records = service
+            .list_all_records("acc", "example.com")
+            .await
+            .unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_bulk_writes_invalidate_cache_after_completion() {
+        let (service, provider) = service_with_trash().await;
+        register_slow_writes(&service, "acc", provider).await;
+        let read_during_write = || async {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            service
+                .list_all_records("acc", "example.com")
+                .await
+                .unwrap()
+        };
+        let batch = BatchCreateRequest {
+            domain_id: "example.com".to_string(),
+            records: vec![a_record("www", "192.0.2.1"), a_record("api", "192.0.2.2")],
+        };
+
+        let (created, stale) = tokio::join!(
+            service.batch_create_records("acc", batch),
+            read_during_write()
+        );
+        assert!(stale.is_empty());
+        let ids: Vec<_> = created.unwrap().created.into_iter().map(|r| r.id).collect();
+        let records = service
+            .list_all_records("acc", "example.com")
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 2);
+
+        let request = BatchDeleteRequest {
+            domain_id: "example.com".to_string(),
+            record_ids: ids,
+        };
+        let (deleted, stale) = tokio::join!(
+            service.batch_delete_records("acc", request, false),
+            read_during_write()
+        );
+        assert_eq!(stale.len(), 2);
+        assert_eq!(deleted.unwrap().success_count, 2);
+        let records = service
+            .list_all_records("acc", "example.com")
+            .await
+            .unwrap();
+        assert!(records.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_failures_carry_request_index() {
+        let (service, _) = service_with_provider(empty_provider().reject_value("192.0.2.99")).await;
+        let result = service
+            .batch_create_records(
+                "acc",
+                BatchCreateRequest {
+                    domain_id: "example.com".to_string(),
+                    records: vec![
+                        a_record("www", "192.0.2.1"),
+                        a_record("bad", "not-an-ip"),
+                        a_record("api", "192.0.2.2"),
+                        a_record("rejected", "192.0.2.99"),
+                    ],
+                },
+            )
+            .await
+            .unwrap();
+
+        let names: Vec<_> = result.created.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["www", "api"]);
+        let failed: Vec<_> = result
+            .failures
+            .iter()
+            .map(|f| (f.request_index, f.record_name.as_str()))
+            .collect();
+        assert_eq!(failed, [(1, "bad"), (3, "rejected")]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_moves_to_trash_and_restore() {
+        let (service, provider) = service_with_trash().await;
+        let record = service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+
+        service
+            .delete_record("acc", &record.id, "example.com")
+            .await
+            .unwrap();
+        assert!(provider.records("example.com").is_empty());
+
+        let trash = service.list_trash("acc").await.unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].record.value, "192.0.2.1");
+        assert!(service.list_trash("other").await.unwrap().is_empty());
+
+        // 其他账户不能恢复
+        assert!(service.restore_record("other", &trash[0].id).await.is_err());
+
+        let restored = service.restore_record("acc", &trash[0].id).await.unwrap();
+        assert_eq!(restored.name, "www");
+        assert_eq!(restored.proxied, Some(true));
+        assert_ne!(restored.id, record.id);
+        assert!(service.list_trash("acc").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_restore_creates_once() {
+        let (service, provider) = service_with_trash().await;
+        let record = service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+        service
+            .delete_record("acc", &record.id, "example.com")
+            .await
+            .unwrap();
+        let trash_id = service.list_trash("acc").await.unwrap()[0].id.clone();
+
+        register_slow_writes(&service, "acc", provider.clone()).await;
+        let (first, second) = tokio::join!(
+            service.restore_record("acc", &trash_id),
+            service.restore_record("acc", &trash_id)
+        );
+        assert_eq!(
+            usize::from(first.is_ok()) + usize::from(second.is_ok()),
+            1,
+            "{first:?} / {second:?}"
+        );
+        assert_eq!(provider.records("example.com").len(), 1);
+        assert!(service.list_trash("acc").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_restore_keeps_trash_entry() {
+        let (service, provider) = service_with_trash().await;
+        let record = service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+        service
+            .delete_record("acc", &record.id, "example.com")
+            .await
+            .unwrap();
+
+        // 把快照改成无法创建的记录
+        let trash = service.ctx.trash_repository.clone().unwrap();
+        let trash_id = service.list_trash("acc").await.unwrap()[0].id.clone();
+        let mut entry = trash.take("acc", &trash_id).await.unwrap().unwrap();
+        entry.record.value = "not-an-ip".to_string();
+        trash.save_all(std::slice::from_ref(&entry)).await.unwrap();
+
+        assert!(service.restore_record("acc", &trash_id).await.is_err());
+        assert!(provider.records("example.com").is_empty());
+        let remaining = service.list_trash("acc").await.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].id, trash_id);
+    }
+
+    #[tokio::test]
+    async fn test_clone_and_restore_drop_unsupported_weight() {
+        let (service, provider) = service_with_trash().await;
+        let target = Arc::new(empty_provider());
+        register_with_metrics(&service, "dst", target.clone()).await;
+
+        // 源记录带权重（如来自支持权重的服务商），Mock 服务商不支持权重
+        let mut weighted = a_record("www", "192.0.2.1");
+        weighted.weight = Some(10);
+        let record = provider.create_record(&weighted).await.unwrap();
+
+        let result = service
+            .clone_zone(
+                "acc",
+                "example.com",
+                "dst",
+                "example.com",
+                &CloneZoneOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.results[0].status, CloneRecordStatus::Created);
+        assert_eq!(target.records("example.com")[0].weight, None);
+
+        service
+            .delete_record("acc", &record.id, "example.com")
+            .await
+            .unwrap();
+        let trash = service.list_trash("acc").await.unwrap();
+        assert_eq!(trash[0].record.weight, Some(10));
+        let restored = service.restore_record("acc", &trash[0].id).await.unwrap();
+        assert_eq!(restored.weight, None);
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_trashes_only_deleted_records() {
+        let (service, _provider) = service_with_trash().await;
+        let first = service
+            .create_record("acc", a_record("a", "192.0.2.1"))
+            .await
+            .unwrap();
+        service
+            .create_record("acc", a_record("b", "192.0.2.2"))
+            .await
+            .unwrap();
+
+        let result = service
+            .batch_delete_records(
+                "acc",
+                BatchDeleteRequest {
+                    domain_id: "example.com".to_string(),
+                    record_ids: vec![first.id.clone()],
+                },
+                false,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.success_count, 1);
+
+        let trash = service.list_trash("acc").await.unwrap();
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].record.id, first.id);
+
+        assert_eq!(service.purge_trash(Some(1)).await.unwrap(), 0);
+        assert_eq!(service.purge_trash(Some(0)).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_delete_dry_run_keeps_records() {
+        let (service, provider) = service_with_trash().await;
+        let record = service
+            .create_record("acc", a_record("a", "192.0.2.1"))
+            .await
+            .unwrap();
+
+        let result = service
+            .batch_delete_records(
+                "acc",
+                BatchDeleteRequest {
+                    domain_id: "example.com".to_string(),
+                    record_ids: vec![record.id.clone(), "missing".to_string()],
+                },
+                true,
+            )
+            .await
+            .unwrap();
+        assert!(result.dry_run);
+        assert_eq!(result.success_count, 1);
+        assert_eq!(result.targets[0].value, "192.0.2.1");
+        assert_eq!(result.failures[0].record_id, "missing");
+
+        assert_eq!(provider.records("example.com").len(), 1);
+        assert!(service.list_trash("acc").await.unwrap().is_empty());
+    }
+
+    fn batch_request() -> BatchCreateRequest {
+        BatchCreateRequest {
+            domain_id: "example.com".to_string(),
+            records: vec![
+                a_record("a", "192.0.2.1"),
+                a_record("b", "not-an-ip"),
+                a_record("c", "192.0.2.3"),
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_submits_valid_records_once() {
+        let (service, provider) = service_with_trash().await;
+        let metrics = register_with_metrics(&service, "acc", provider.clone()).await;
+
+        let result = service
+            .batch_create_records("acc", batch_request())
+            .await
+            .unwrap();
+        assert_eq!(result.success_count, 2);
+        assert_eq!(result.failed_count, 1);
+        let names: Vec<_> = result.created.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["a", "c"]);
+        assert_eq!(calls(&metrics, ProviderOperation::BatchCreateRecords), 1);
+        assert_eq!(provider.records("example.com").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_reports_provider_failures_per_record() {
+        let provider = empty_provider().reject_value("192.0.2.1");
+        let (service, provider) = service_with_provider(provider).await;
+
+        let result = service
+            .batch_create_records("acc", batch_request())
+            .await
+            .unwrap();
+        let names: Vec<_> = result.created.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, ["c"]);
+        assert_eq!(result.failed_count, 2);
+        assert!(result
+            .failures
+            .iter()
+            .any(|f| f.reason.contains("rejected value")));
+        assert_eq!(provider.records("example.com").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_replace_record_value_dry_run_then_apply() {
+        let (service, provider) = service_with_trash().await;
+        for (name, value) in [("a", "192.0.2.1"), ("b", "192.0.2.1"), ("c", "192.0.2.2")] {
+            service
+                .create_record("acc", a_record(name, value))
+                .await
+                .unwrap();
+        }
+        let request = ReplaceRecordValueRequest {
+            domain_id: None,
+            record_type: DnsRecordType::A,
+            from_value: "192.0.2.1".to_string(),
+            to_value: "198.51.100.1".to_string(),
+        };
+
+        let preview = service
+            .replace_record_value("acc", request.clone(), true)
+            .await
+            .unwrap();
+        assert!(preview.dry_run);
+        assert_eq!(preview.changed_count, 2);
+        assert_eq!(preview.targets.len(), 2);
+        assert!(preview
+            .targets
+            .iter()
+            .all(|t| t.domain_name == "example.com"));
+
+        let result = service
+            .replace_record_value("acc", request, false)
+            .await
+            .unwrap();
+        assert_eq!(result.changed_count, 2);
+        assert_eq!(result.failed_count, 0);
+
+        let mut values: Vec<_> = provider
+            .records("example.com")
+            .iter()
+            .map(|r| (r.name.clone(), r.value.clone(), r.proxied))
+            .collect();
+        values.sort();
+        assert_eq!(
+            values,
+            [
+                ("a".to_string(), "198.51.100.1".to_string(), Some(true)),
+                ("b".to_string(), "198.51.100.1".to_string(), Some(true)),
+                ("c".to_string(), "192.0.2.2".to_string(), Some(true)),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_update_rejects_concurrent_modification() {
+        let (service, provider) = service_with_trash().await;
+        let created = service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+
+        // 两个管理员读取同一条记录后各自提交更新
+        let update = |value: &str| UpdateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: value.to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            expected_version: None,
+            expected_value: Some(created.value.clone()),
+        };
+        service
+            .update_record("acc", &created.id, update("192.0.2.2"))
+            .await
+            .unwrap();
+        let err = service
+            .update_record("acc", &created.id, update("192.0.2.3"))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::Provider(ProviderError::Conflict { ref record_id, .. }) if *record_id == created.id
+        ));
+        assert_eq!(provider.records("example.com")[0].value, "192.0.2.2");
+
+        // 记录没有修改时间时不能按版本检查
+        let unversioned = service
+            .create_record("acc", a_record("api", "192.0.2.9"))
+            .await
+            .unwrap();
+        assert!(unversioned.version().is_none());
+        let err = service
+            .update_record(
+                "acc",
+                &unversioned.id,
+                UpdateDnsRecordRequest {
+                    name: "api".to_string(),
+                    expected_version: Some("2024-05-01T12:00:00Z".to_string()),
+                    expected_value: None,
+                    ..update("192.0.2.10")
+                },
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::Provider(ProviderError::InvalidParameter { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_account_overview_clamps_page_size() {
+        let (service, provider) =
+            service_with_provider(MockProvider::empty(Some("example.com,example.org"))).await;
+        for i in 0..150 {
+            provider
+                .create_record(&a_record(&format!("host{i}"), "192.0.2.1"))
+                .await
+                .unwrap();
+        }
+
+        let overview = service.get_account_overview("acc", None).await.unwrap();
+        assert_eq!(overview.account_id, "acc");
+        assert!(overview.partial_errors.is_empty());
+        let names: Vec<_> = overview
+            .domains
+            .iter()
+            .map(|d| d.domain.name.as_str())
+            .collect();
+        assert_eq!(names, ["example.com", "example.org"]);
+        assert_eq!(overview.domains[0].records.items.len(), 20);
+        assert_eq!(overview.domains[0].records.total_count, 150);
+        assert!(overview.domains[1].records.items.is_empty());
+
+        for (requested, expected) in [(0, 1), (10_000, OVERVIEW_MAX_PAGE_SIZE)] {
+            let overview = service
+                .get_account_overview("acc", Some(requested))
+                .await
+                .unwrap();
+            let records = &overview.domains[0].records;
+            assert_eq!(records.page_size, expected);
+            assert_eq!(records.items.len(), expected as usize);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_zone_export_keeps_mixed_ttls() {
+        let (service, provider) = service_with_trash().await;
+        for (name, ttl) in [("a", 300), ("b", 300), ("c", 3600), ("d", 60)] {
+            let request = CreateDnsRecordRequest {
+                ttl,
+                ..a_record(name, "192.0.2.1")
+            };
+            provider.create_record(&request).await.unwrap();
+        }
+
+        let export = service
+            .export_zone_file("acc", "example.com")
+            .await
+            .unwrap();
+        assert!(export.content.contains("$TTL 300\n"));
+
+        let parsed =
+            zone_file::parse_zone_file(&export.content, "example.com", "example.com", false);
+        assert!(parsed.errors.is_empty());
+        let mut ttls: Vec<_> = parsed
+            .records
+            .iter()
+            .map(|r| (r.name.as_str(), r.ttl))
+            .collect();
+        ttls.sort_unstable();
+        assert_eq!(ttls, [("a", 300), ("b", 300), ("c", 3600), ("d", 60)]);
+    }
+
+    #[tokio::test]
+    async fn test_provider_self_test() {
+        let (service, provider) = service_with_trash().await;
+
+        let report = service
+            .provider_self_test("acc", "example.com")
+            .await
+            .unwrap();
+        assert!(report.passed);
+        let steps: Vec<_> = report.steps.iter().map(|s| s.step).collect();
+        assert_eq!(
+            steps,
+            [
+                SelfTestStepKind::Create,
+                SelfTestStepKind::Read,
+                SelfTestStepKind::Update,
+                SelfTestStepKind::Delete,
+            ]
+        );
+        assert!(provider.records("example.com").is_empty());
+
+        // 已存在测试记录时拒绝运行
+        let mut existing = a_record("_dnso-test", "192.0.2.1");
+        existing.record_type = DnsRecordType::Txt;
+        existing.value = "keep me".to_string();
+        provider.create_record(&existing).await.unwrap();
+        assert!(matches!(
+            service.provider_self_test("acc", "example.com").await,
+            Err(CoreError::Conflict(_))
+        ));
+        assert_eq!(provider.records("example.com")[0].value, "keep me");
+    }
+
+    #[tokio::test]
+    async fn test_provider_self_test_reports_failed_create() {
+        let provider = empty_provider().reject_value("dnso-self-test");
+        let (service, _) = service_with_provider(provider).await;
+
+        let report = service
+            .provider_self_test("acc", "example.com")
+            .await
+            .unwrap();
+        assert!(!report.passed);
+        assert_eq!(report.steps.len(), 1);
+        assert_eq!(report.steps[0].step, SelfTestStepKind::Create);
+        assert!(report.steps[0].error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_import_zone_file_dry_run_matches_import() {
+        let (service, provider) = service_with_trash().await;
+        let content = "$ORIGIN example.com.\n\
+            www 300 IN A 192.0.2.1\n\
+            bad 300 IN A not-an-ip\n\
+            @ 600 IN MX 10 mail.example.com.\n";
+
+        let preview = service
+            .import_zone_file("acc", "example.com", content, false, true)
+            .await
+            .unwrap();
+        assert!(preview.dry_run);
+        assert_eq!(preview.success_count, 2);
+        assert_eq!(preview.failed_count, 1);
+        assert!(preview.created.is_empty());
+        let planned: Vec<_> = preview.planned.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(planned, ["www", "@"]);
+        assert!(provider.records("example.com").is_empty());
+
+        let imported = service
+            .import_zone_file("acc", "example.com", content, false, false)
+            .await
+            .unwrap();
+        assert!(!imported.dry_run);
+        assert_eq!(imported.success_count, preview.success_count);
+        assert_eq!(imported.errors, preview.errors);
+        assert_eq!(provider.records("example.com").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_zone_stats() {
+        let (service, _provider) = service_with_trash().await;
+        for record in [
+            a_record("a", "192.0.2.1"),
+            a_record("b", "192.0.2.2"),
+            CreateDnsRecordRequest {
+                record_type: DnsRecordType::Txt,
+                proxied: None,
+                ..a_record("@", "v=spf1 -all")
+            },
+        ] {
+            service.create_record("acc", record).await.unwrap();
+        }
+
+        let stats = service.zone_stats("acc", "example.com").await.unwrap();
+        assert_eq!(stats.total, 3);
+        assert_eq!(stats.by_type.get("A"), Some(&2));
+        assert_eq!(stats.by_type.get("TXT"), Some(&1));
+        assert_eq!(stats.proxied, 2);
+
+        let summary = service
+            .zone_record_type_summary("acc", "example.com")
+            .await
+            .unwrap();
+        assert_eq!(summary.total, stats.total);
+        assert_eq!(summary.by_type, stats.by_type);
+    }
+
+    #[tokio::test]
+    async fn test_create_record_encodes_idn_name() {
+        let (service, provider) = service_with_trash().await;
+        let record = service
+            .create_record("acc", a_record("bücher", "192.0.2.1"))
+            .await
+            .unwrap();
+        assert_eq!(record.name, "xn--bcher-kva");
+        assert_eq!(provider.records("example.com")[0].name, "xn--bcher-kva");
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_reports_unencodable_names() {
+        let (service, provider) = service_with_trash().await;
+        let request = BatchCreateRequest {
+            domain_id: "example.com".to_string(),
+            records: vec![
+                a_record("bücher", "192.0.2.1"),
+                a_record("xn--a.bücher", "192.0.2.2"),
+            ],
+        };
+
+        let result = service.batch_create_records("acc", request).await.unwrap();
+        assert_eq!(result.created[0].name, "xn--bcher-kva");
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].request_index, 1);
+        assert_eq!(result.failures[0].record_name, "xn--a.bücher");
+        assert!(result.failures[0].reason.contains("国际化域名"));
+        assert_eq!(provider.records("example.com").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_records_encodes_idn_keyword() {
+        let (service, _) = service_with_trash().await;
+        service
+            .create_record("acc", a_record("bücher", "192.0.2.1"))
+            .await
+            .unwrap();
+        service
+            .create_record("acc", a_record("www", "192.0.2.2"))
+            .await
+            .unwrap();
+
+        let response = service
+            .list_records(
+                "acc",
+                "example.com",
+                None,
+                None,
+                Some("bücher".to_string()),
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.items.len(), 1);
+        assert_eq!(response.items[0].name, "xn--bcher-kva");
+    }
+
+    #[tokio::test]
+    async fn test_create_record_idempotent() {
+        let (service, provider) = service_with_trash().await;
+        let first = service
+            .create_record_idempotent("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+        let again = service
+            .create_record_idempotent("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+        assert_eq!(again.id, first.id);
+        assert_eq!(provider.records("example.com").len(), 1);
+
+        // 同名不同值的 A 记录可以共存
+        service
+            .create_record_idempotent("acc", a_record("www", "192.0.2.2"))
+            .await
+            .unwrap();
+        assert_eq!(provider.records("example.com").len(), 2);
+
+        // CNAME 不能与同名记录共存
+        let mut cname = a_record("www", "target.example.net");
+        cname.record_type = DnsRecordType::Cname;
+        cname.proxied = None;
+        let result = service.create_record_idempotent("acc", cname).await;
+        assert!(matches!(result, Err(CoreError::Conflict(_))));
+        assert_eq!(provider.records("example.com").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_unsupported_record_type_rejected_before_provider_call() {
+        let (service, provider) = service_with_trash().await;
+        let metrics = register_with_metrics(&service, "acc", provider.clone()).await;
+        let mut request = a_record("@", "lb.example.net");
+        request.record_type = DnsRecordType::Alias;
+
+        let err = service
+            .create_record("acc", request.clone())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            CoreError::Provider(ProviderError::UnsupportedRecordType { ref record_type, .. })
+                if record_type == "ALIAS"
+        ));
+
+        let result = service
+            .batch_create_records(
+                "acc",
+                BatchCreateRequest {
+                    domain_id: "example.com".to_string(),
+                    records: vec![request, a_record("www", "192.0.2.1")],
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!((result.success_count, result.failed_count), (1, 1));
+        assert_eq!(result.failures[0].request_index, 0);
+        assert!(
+            result.failures[0].reason.contains("ALIAS"),
+            "{:?}",
+            result.failures
+        );
+        assert_eq!(calls(&metrics, ProviderOperation::CreateRecord), 0);
+        assert_eq!(provider.records("example.com").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_omitted_ttl_uses_provider_default() {
+        let (service, _) = service_with_trash().await;
+        let mut request = a_record("www", "192.0.2.1");
+        request.ttl = 0;
+        let record = service.create_record("acc", request).await.unwrap();
+        // Mock 服务商的默认 TTL
+        assert_eq!(
+            record.ttl,
+            dns_orchestrator_provider::default_ttl_for("mock")
+        );
+
+        let mut request = a_record("api", "192.0.2.2");
+        request.ttl = 0;
+        let result = service
+            .batch_create_records(
+                "acc",
+                BatchCreateRequest {
+                    domain_id: "example.com".to_string(),
+                    records: vec![request, a_record("explicit", "192.0.2.3")],
+                },
+            )
+            .await
+            .unwrap();
+        let ttls: Vec<_> = result.created.iter().map(|r| r.ttl).collect();
+        assert!(ttls.contains(&dns_orchestrator_provider::default_ttl_for("mock")));
+        assert!(ttls.contains(&300));
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_replays_first_result() {
+        let (service, provider) = service_with_trash().await;
+        let create = || async {
+            service
+                .run_idempotent(
+                    "acc",
+                    "create_dns_record",
+                    Some("key-1"),
+                    &a_record("www", "192.0.2.1"),
+                    || service.create_record("acc", a_record("www", "192.0.2.1")),
+                )
+                .await
+                .unwrap()
+        };
+
+        let first = create().await;
+        let second = create().await;
+        assert_eq!(first.id, second.id);
+        assert_eq!(
+            serde_json::to_value(&first).unwrap(),
+            serde_json::to_value(&second).unwrap()
+        );
+        assert_eq!(provider.records("example.com").len(), 1);
+
+        let reused = service
+            .run_idempotent("acc", "delete_dns_record", Some("key-1"), "www", || async {
+                Ok(())
+            })
+            .await;
+        assert!(matches!(reused, Err(CoreError::ValidationError(_))));
+
+        // 相同操作但请求参数不同
+        let changed = a_record("www", "192.0.2.2");
+        let reused = service
+            .run_idempotent("acc", "create_dns_record", Some("key-1"), &changed, || {
+                service.create_record("acc", changed.clone())
+            })
+            .await;
+        assert!(matches!(reused, Err(CoreError::ValidationError(_))));
+        assert_eq!(provider.records("example.com").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_idempotency_key_reserved_while_in_flight() {
+        let (service, provider) = service_with_trash().await;
+        register_slow_writes(&service, "acc", provider.clone()).await;
+        let request = a_record("www", "192.0.2.1");
+        let create = || {
+            service.run_idempotent("acc", "create_dns_record", Some("key-1"), &request, || {
+                service.create_record("acc", request.clone())
+            })
+        };
+
+        let (first, second) = tokio::join!(create(), create());
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(CoreError::Conflict(_))));
+        assert_eq!(provider.records("example.com").len(), 1);
+        // 完成后重试返回首次的结果
+        assert_eq!(create().await.unwrap().id, first.unwrap().id);
+    }
+
+    #[tokio::test]
+    async fn test_failed_idempotent_operation_releases_key() {
+        let (service, provider) = service_with_trash().await;
+        let request = a_record("www", "192.0.2.1");
+        let failed: CoreResult<DnsRecord> = service
+            .run_idempotent(
+                "acc",
+                "create_dns_record",
+                Some("key-1"),
+                &request,
+                || async { Err(CoreError::ValidationError("boom".to_string())) },
+            )
+            .await;
+        assert!(failed.is_err());
+
+        let record = service
+            .run_idempotent("acc", "create_dns_record", Some("key-1"), &request, || {
+                service.create_record("acc", request.clone())
+            })
+            .await
+            .unwrap();
+        assert_eq!(provider.records("example.com")[0].id, record.id);
+    }
+
+    #[tokio::test]
+    async fn test_diff_zones_across_accounts() {
+        let (service, source) = service_with_trash().await;
+        let target = Arc::new(empty_provider());
+        service
+            .ctx
+            .provider_registry
+            .register("dst".to_string(), target.clone())
+            .await;
+
+        for (name, value) in [
+            ("www", "192.0.2.1"),
+            ("old", "192.0.2.5"),
+            ("api", "192.0.2.2"),
+            ("old", "192.0.2.3"),
+        ] {
+            service
+                .create_record("acc", a_record(name, value))
+                .await
+                .unwrap();
+        }
+        let mut changed_ttl = a_record("api", "192.0.2.2");
+        changed_ttl.ttl = 600;
+        for request in [
+            a_record("www.example.com", "192.0.2.1"),
+            changed_ttl,
+            a_record("new", "192.0.2.4"),
+        ] {
+            service.create_record("dst", request).await.unwrap();
+        }
+        assert_eq!(source.records("example.com").len(), 4);
+
+        let diff = service
+            .diff_zones("acc", "example.com", "dst", "example.com", false)
+            .await
+            .unwrap();
+        let only_in_source: Vec<_> = diff
+            .only_in_source
+            .iter()
+            .map(|r| r.value.as_str())
+            .collect();
+        assert_eq!(only_in_source, ["192.0.2.3", "192.0.2.5"]);
+        assert_eq!(diff.only_in_target.len(), 1);
+        assert_eq!(diff.only_in_target[0].name, "new");
+        assert_eq!(diff.differing.len(), 1);
+        assert_eq!(diff.differing[0].target.ttl, 600);
+
+        let diff = service
+            .diff_zones("acc", "example.com", "dst", "example.com", true)
+            .await
+            .unwrap();
+        assert!(diff.differing.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_diff_zones_keeps_records_on_different_lines_apart() {
+        let (service, _) = service_with_trash().await;
+        let target = Arc::new(empty_provider());
+        service
+            .ctx
+            .provider_registry
+            .register("dst".to_string(), target.clone())
+            .await;
+
+        let on_line = |line: Option<&str>| CreateDnsRecordRequest {
+            line: line.map(ToString::to_string),
+            ..a_record("www", "192.0.2.1")
+        };
+        for line in [Some("telecom"), Some("unicom")] {
+            service.create_record("acc", on_line(line)).await.unwrap();
+        }
+        for line in [Some("telecom"), Some("default")] {
+            service.create_record("dst", on_line(line)).await.unwrap();
+        }
+
+        let diff = service
+            .diff_zones("acc", "example.com", "dst", "example.com", false)
+            .await
+            .unwrap();
+        assert_eq!(diff.only_in_source.len(), 1);
+        assert_eq!(diff.only_in_source[0].line.as_deref(), Some("unicom"));
+        assert_eq!(diff.only_in_target.len(), 1);
+        assert_eq!(diff.only_in_target[0].line.as_deref(), Some("default"));
+    }
+
+    #[tokio::test]
+    async fn test_diff_and_clone_match_values_across_providers() {
+        let (service, _) = service_with_trash().await;
+        let huawei = Arc::new(empty_provider().with_id("huaweicloud"));
+        let cloudflare = Arc::new(empty_provider().with_id("cloudflare"));
+        let registry = &service.ctx.provider_registry;
+        registry.register("hw".to_string(), huawei.clone()).await;
+        registry
+            .register("cf".to_string(), cloudflare.clone())
+            .await;
+
+        let record = |record_type: DnsRecordType, value: &str| CreateDnsRecordRequest {
+            record_type,
+            proxied: None,
+            ..a_record("www", value)
+        };
+        // 华为云返回带引号的分段 TXT，Cloudflare 返回原始内容；AAAA 写法不同
+        for request in [
+            record(DnsRecordType::Txt, "\"v=spf1 \" \"-all\""),
+            record(
+                DnsRecordType::Aaaa,
+                "2001:0db8:0000:0000:0000:0000:0000:0001",
+            ),
+        ] {
+            service.create_record("hw", request).await.unwrap();
+        }
+        for request in [
+            record(DnsRecordType::Txt, "v=spf1 -all"),
+            record(DnsRecordType::Aaaa, "2001:db8::1"),
+        ] {
+            service.create_record("cf", request).await.unwrap();
+        }
+
+        let diff = service
+            .diff_zones("hw", "example.com", "cf", "example.com", false)
+            .await
+            .unwrap();
+        assert!(diff.only_in_source.is_empty());
+        assert!(diff.only_in_target.is_empty());
+
+        let result = service
+            .clone_zone(
+                "hw",
+                "example.com",
+                "cf",
+                "example.com",
+                &CloneZoneOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!((result.created_count, result.skipped_count), (0, 2));
+        assert_eq!(cloudflare.records("example.com").len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_clone_zone_translates_lines_between_providers() {
+        let (service, _) = service_with_trash().await;
+        let aliyun = Arc::new(empty_provider().with_id("aliyun"));
+        let dnspod = Arc::new(empty_provider().with_id("dnspod"));
+        let registry = &service.ctx.provider_registry;
+        registry.register("ali".to_string(), aliyun.clone()).await;
+        registry.register("pod".to_string(), dnspod.clone()).await;
+
+        for (value, line) in [
+            ("192.0.2.1", "default"),
+            ("192.0.2.2", "telecom"),
+            ("192.0.2.3", "cn_telecom_beijing"),
+        ] {
+            let request = CreateDnsRecordRequest {
+                line: Some(line.to_string()),
+                proxied: None,
+                ..a_record("www", value)
+            };
+            service.create_record("ali", request).await.unwrap();
+        }
+
+        let result = service
+            .clone_zone(
+                "ali",
+                "example.com",
+                "pod",
+                "example.com",
+                &CloneZoneOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!((result.created_count, result.skipped_count), (2, 1));
+
+        let mut lines: Vec<_> = dnspod
+            .records("example.com")
+            .into_iter()
+            .map(|r| (r.value, r.line))
+            .collect();
+        lines.sort();
+        assert_eq!(
+            lines,
+            [
+                ("192.0.2.1".to_string(), None),
+                ("192.0.2.2".to_string(), Some("电信".to_string())),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_clone_zone_overwrites_and_survives_bulk_failure() {
+        let (service, _) = service_with_trash().await;
+        let target = Arc::new(empty_provider().reject_value("192.0.2.99"));
+        let metrics = register_with_metrics(&service, "dst", target.clone()).await;
+
+        for (name, value) in [
+            ("www", "192.0.2.1"),
+            ("api", "192.0.2.2"),
+            ("bad", "192.0.2.99"),
+        ] {
+            service
+                .create_record("acc", a_record(name, value))
+                .await
+                .unwrap();
+        }
+        let apex_ns = CreateDnsRecordRequest {
+            record_type: DnsRecordType::Ns,
+            value: "ns1.example.net".to_string(),
+            proxied: None,
+            ..a_record("@", "")
+        };
+        service.create_record("acc", apex_ns).await.unwrap();
+
+        let mut existing_www = a_record("www", "192.0.2.1");
+        existing_www.ttl = 600;
+        for request in [existing_www, a_record("api", "198.51.100.1")] {
+            service.create_record("dst", request).await.unwrap();
+        }
+
+        let seeded = calls(&metrics, ProviderOperation::CreateRecord);
+        let options = CloneZoneOptions {
+            overwrite_existing: true,
+            ..CloneZoneOptions::default()
+        };
+        let result = service
+            .clone_zone("acc", "example.com", "dst", "example.com", &options)
+            .await
+            .unwrap();
+
+        let status_of = |name: &str, value: &str| {
+            result
+                .results
+                .iter()
+                .find(|r| r.name == name && r.value == value)
+                .map(|r| r.status)
+        };
+        assert_eq!(
+            status_of("www", "192.0.2.1"),
+            Some(CloneRecordStatus::Updated)
+        );
+        assert_eq!(
+            status_of("api", "198.51.100.1"),
+            Some(CloneRecordStatus::Deleted)
+        );
+        assert_eq!(
+            status_of("api", "192.0.2.2"),
+            Some(CloneRecordStatus::Created)
+        );
+        assert_eq!(
+            status_of("@", "ns1.example.net"),
+            Some(CloneRecordStatus::Skipped)
+        );
+        assert_eq!(
+            status_of("bad", "192.0.2.99"),
+            Some(CloneRecordStatus::Failed)
+        );
+        assert_eq!(result.failed_count, 1);
+        // 一条记录失败不影响同批其他记录，服务商已确认的记录不会重复创建
+        assert_eq!(calls(&metrics, ProviderOperation::BatchCreateRecords), 1);
+        assert_eq!(calls(&metrics, ProviderOperation::CreateRecord), seeded);
+
+        let records = target.records("example.com");
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().all(|r| r.ttl == 300));
+    }
+
+    #[tokio::test]
+    async fn test_clone_zone_invalidates_target_cache_after_writes() {
+        let (service, _) = service_with_trash().await;
+        let target = Arc::new(empty_provider());
+        register_slow_writes(&service, "dst", target).await;
+        service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+
+        let options = CloneZoneOptions::default();
+        let (cloned, stale) = tokio::join!(
+            service.clone_zone("acc", "example.com", "dst", "example.com", &options),
+            async {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                service
+                    .list_all_records("dst", "example.com")
+                    .await
+                    .unwrap()
+            }
+        );
+        assert!(stale.is_empty());
+        assert_eq!(cloned.unwrap().results.len(), 1);
+        let records = service
+            .list_all_records("dst", "example.com")
+            .await
+            .unwrap();
+        assert_eq!(records.len(), 1);
+    }
+
+    /// 本地 Webhook 接收端：首个请求返回 500，之后返回 200，收到的请求体与签名经通道返回
+    async fn spawn_webhook_receiver() -> (
+        String,
+        tokio::sync::mpsc::UnboundedReceiver<(Option<String>, Vec<u8>)>,
+    ) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut first = true;
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let (head, body) = loop {
+                    let n = socket.read(&mut chunk).await.unwrap();
+                    buf.extend_from_slice(&chunk[..n]);
+                    let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") else {
+                        continue;
+                    };
+                    let head = String::from_utf8_lossy(&buf[..pos]).to_lowercase();
+                    let len: usize = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix("content-length:"))
+                        .map_or(0, |v| v.trim().parse().unwrap());
+                    if buf.len() >= pos + 4 + len {
+                        break (head, buf[pos + 4..pos + 4 + len].to_vec());
+                    }
+                };
+                let status = if first {
+                    "500 Internal Server Error"
+                } else {
+                    "200 OK"
+                };
+                socket
+                    .write_all(
+                        format!(
+                            "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                        )
+                        .as_bytes(),
+                    )
+                    .await
+                    .unwrap();
+                if !first {
+                    let header = format!("{}:", WEBHOOK_SIGNATURE_HEADER.to_lowercase());
+                    let signature = head
+                        .lines()
+                        .find_map(|l| l.strip_prefix(header.as_str()))
+                        .map(|v| v.trim().to_string());
+                    tx.send((signature, body)).unwrap();
+                }
+                first = false;
+            }
+        });
+        (url, rx)
+    }
+
+    #[tokio::test]
+    async fn test_bulk_changes_post_webhooks() {
+        let (url, mut received) = spawn_webhook_receiver().await;
+        let (notifier, dispatcher) = WebhookNotifier::new(WebhookConfig::new(url, "s3cret"));
+        tokio::spawn(dispatcher.run());
+
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        registry
+            .register("acc".to_string(), Arc::new(empty_provider()))
+            .await;
+        registry
+            .register("dst".to_string(), Arc::new(empty_provider()))
+            .await;
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(MemoryAccountRepository::default()),
+            registry,
+        )
+        .with_webhook(notifier);
+        let service = DnsService::new(Arc::new(ctx));
+
+        let created = service
+            .batch_create_records(
+                "acc",
+                BatchCreateRequest {
+                    domain_id: "example.com".to_string(),
+                    records: vec![a_record("www", "192.0.2.1"), a_record("www", "192.0.2.2")],
+                },
+            )
+            .await
+            .unwrap()
+            .created;
+        service
+            .clone_zone(
+                "acc",
+                "example.com",
+                "dst",
+                "example.com",
+                &CloneZoneOptions::default(),
+            )
+            .await
+            .unwrap();
+        service
+            .batch_delete_records(
+                "acc",
+                BatchDeleteRequest {
+                    domain_id: "example.com".to_string(),
+                    record_ids: vec![created[0].id.clone()],
+                },
+                false,
+            )
+            .await
+            .unwrap();
+        service
+            .delete_rrset("dst", "example.com", "www", &DnsRecordType::A)
+            .await
+            .unwrap();
+
+        let mut events = Vec::new();
+        for _ in 0..7 {
+            let (_, body) = received.recv().await.unwrap();
+            let event: RecordChangeEvent = serde_json::from_slice(&body).unwrap();
+            events.push((event.account_id, event.event, event.record.unwrap().value));
+        }
+        let expected = [
+            ("acc", RecordChangeKind::Created, "192.0.2.1"),
+            ("acc", RecordChangeKind::Created, "192.0.2.2"),
+            ("dst", RecordChangeKind::Created, "192.0.2.1"),
+            ("dst", RecordChangeKind::Created, "192.0.2.2"),
+            ("acc", RecordChangeKind::Deleted, "192.0.2.1"),
+            ("dst", RecordChangeKind::Deleted, "192.0.2.1"),
+            ("dst", RecordChangeKind::Deleted, "192.0.2.2"),
+        ];
+        for (account, kind, value) in expected {
+            assert!(
+                events
+                    .iter()
+                    .any(|(a, k, v)| a == account && *k == kind && v == value),
+                "{account} {kind:?} {value}: {events:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_changes_post_signed_webhooks() {
+        let (url, mut received) = spawn_webhook_receiver().await;
+        let (notifier, dispatcher) = WebhookNotifier::new(WebhookConfig::new(url, "s3cret"));
+        tokio::spawn(dispatcher.run());
+
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        registry
+            .register("acc".to_string(), Arc::new(empty_provider()))
+            .await;
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(MemoryAccountRepository::default()),
+            registry,
+        )
+        .with_webhook(notifier);
+        let service = DnsService::new(Arc::new(ctx));
+
+        let record = service
+            .create_record("acc", a_record("www", "192.0.2.1"))
+            .await
+            .unwrap();
+        service
+            .delete_record("acc", &record.id, "example.com")
+            .await
+            .unwrap();
+
+        // 首次投递收到 500，重试后成功
+        for expected in [RecordChangeKind::Created, RecordChangeKind::Deleted] {
+            let (signature, body) = received.recv().await.unwrap();
+            assert_eq!(
+                signature.as_deref(),
+                Some(sign_webhook_payload(b"s3cret", &body).as_str())
+            );
+            let event: RecordChangeEvent = serde_json::from_slice(&body).unwrap();
+            assert_eq!(event.event, expected);
+            assert_eq!(event.account_id, "acc");
+            assert_eq!(event.domain_id, "example.com");
+            assert_eq!(event.record_id, record.id);
+            assert_eq!(event.record.unwrap().value, "192.0.2.1");
         }
-        CoreError::Provider(err)
     }
 }
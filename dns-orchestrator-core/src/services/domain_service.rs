@@ -2,11 +2,18 @@
 
 use std::sync::Arc;
 
-use dns_orchestrator_provider::ProviderError;
+use dns_orchestrator_provider::{DnsProvider, ProviderError};
+use futures::StreamExt;
 
 use crate::error::{CoreError, CoreResult};
 use crate::services::ServiceContext;
-use crate::types::{AppDomain, PaginatedResponse, PaginationParams};
+use crate::types::{AppDomain, PaginatedResponse, PaginationParams, RecordQueryParams};
+
+/// 全量拉取域名时的分页大小
+const ALL_DOMAINS_PAGE_SIZE: u32 = 100;
+
+/// 补全记录数时同时查询的域名数
+const RECORD_COUNT_CONCURRENCY: usize = 4;
 
 /// 域名管理服务
 pub struct DomainService {
@@ -20,39 +27,133 @@ impl DomainService {
         Self { ctx }
     }
 
-    /// 列出账号下的所有域名（分页）
+    /// 列出账号下的所有域名（分页，带短期缓存）
+    ///
+    /// `keyword` 按域名名称过滤（包含匹配，忽略大小写）；
+    /// `include_record_count` 为 true 时，为服务商未返回记录数的域名逐个查询补全（开销较大）
     pub async fn list_domains(
         &self,
         account_id: &str,
         page: Option<u32>,
         page_size: Option<u32>,
+        keyword: Option<String>,
+        include_record_count: bool,
     ) -> CoreResult<PaginatedResponse<AppDomain>> {
         let provider = self.ctx.get_provider(account_id).await?;
 
         let params = PaginationParams {
             page: page.unwrap_or(1),
             page_size: page_size.unwrap_or(20),
+            name_filter: keyword,
         };
 
+        if let Some(mut cached) = self.ctx.domains_cache.get(account_id, &params) {
+            if include_record_count && cached.items.iter().any(|d| d.record_count.is_none()) {
+                Self::fill_record_counts(&provider, &mut cached.items).await;
+                self.ctx.domains_cache.insert(account_id, &params, &cached);
+            }
+            return Ok(cached);
+        }
+
         match provider.list_domains(&params).await {
             Ok(lib_response) => {
-                let domains: Vec<AppDomain> = lib_response
+                let mut domains: Vec<AppDomain> = lib_response
                     .items
                     .into_iter()
                     .map(|d| AppDomain::from_provider(d, account_id.to_string()))
                     .collect();
+                if include_record_count {
+                    Self::fill_record_counts(&provider, &mut domains).await;
+                }
 
-                Ok(PaginatedResponse::new(
+                let response = PaginatedResponse::new(
                     domains,
                     lib_response.page,
                     lib_response.page_size,
                     lib_response.total_count,
-                ))
+                );
+                self.ctx
+                    .domains_cache
+                    .insert(account_id, &params, &response);
+                Ok(response)
             }
             Err(e) => Err(self.handle_provider_error(account_id, e).await),
         }
     }
 
+    /// 为缺少记录数的域名查询记录总数（每个域名只拉取一条记录，读取 `total_count`）
+    ///
+    /// 服务商的 `total_count` 以展开后的记录计（华为云会为此拉取全部 `RecordSet`）；
+    /// 并发数限制为 `RECORD_COUNT_CONCURRENCY`；查询失败的域名保持 `None`
+    async fn fill_record_counts(provider: &Arc<dyn DnsProvider>, domains: &mut [AppDomain]) {
+        let pending: Vec<_> = domains
+            .iter()
+            .enumerate()
+            .filter(|(_, d)| d.record_count.is_none())
+            .map(|(i, d)| (i, d.id.clone()))
+            .collect();
+        let counts: Vec<_> = futures::stream::iter(pending)
+            .map(|(i, domain_id)| Self::count_records(Arc::clone(provider), i, domain_id))
+            .buffer_unordered(RECORD_COUNT_CONCURRENCY)
+            .collect()
+            .await;
+
+        for (i, domain_id, result) in counts {
+            match result {
+                Ok(count) => domains[i].record_count = Some(count),
+                Err(e) => log::warn!("Failed to count records for domain {domain_id}: {e}"),
+            }
+        }
+    }
+
+    /// 查询单个域名的记录总数
+    async fn count_records(
+        provider: Arc<dyn DnsProvider>,
+        index: usize,
+        domain_id: String,
+    ) -> (usize, String, Result<u32, ProviderError>) {
+        let params = RecordQueryParams {
+            page: 1,
+            page_size: 1,
+            ..Default::default()
+        };
+        let result = provider
+            .list_records(&domain_id, &params)
+            .await
+            .map(|response| response.total_count);
+        (index, domain_id, result)
+    }
+
+    /// 使账户的域名列表缓存失效（域名变更后调用）
+    pub fn invalidate_cache(&self, account_id: &str) {
+        self.ctx.domains_cache.invalidate(account_id);
+    }
+
+    /// 列出账号下的全部域名（自动翻页）
+    pub async fn list_all_domains(&self, account_id: &str) -> CoreResult<Vec<AppDomain>> {
+        let mut domains = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response = self
+                .list_domains(
+                    account_id,
+                    Some(page),
+                    Some(ALL_DOMAINS_PAGE_SIZE),
+                    None,
+                    false,
+                )
+                .await?;
+            domains.extend(response.items);
+            if !response.has_more {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(domains)
+    }
+
     /// 获取域名详情
     pub async fn get_domain(&self, account_id: &str, domain_id: &str) -> CoreResult<AppDomain> {
         let provider = self.ctx.get_provider(account_id).await?;
@@ -76,3 +177,136 @@ impl DomainService {
         CoreError::Provider(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::services::DomainsCache;
+    use crate::test_support::{MemoryAccountRepository, MemoryCredentialStore};
+    use crate::traits::{InMemoryProviderRegistry, ProviderRegistry};
+    use crate::types::{CreateDnsRecordRequest, DnsRecordType};
+    use dns_orchestrator_provider::{MetricsLayer, MockProvider, ProviderLayer, ProviderOperation};
+
+    /// 账号 `acc` 只有空域名 `example.com`，返回的统计层记录 Provider 调用次数
+    async fn service(ttl: Duration) -> (DomainService, MetricsLayer) {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let metrics = MetricsLayer::new();
+        let provider = Arc::new(MockProvider::empty(Some("example.com")));
+        registry
+            .register("acc".to_string(), metrics.layer(provider))
+            .await;
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(MemoryAccountRepository::default()),
+            registry,
+        )
+        .with_domains_cache(DomainsCache::new(ttl));
+        (DomainService::new(Arc::new(ctx)), metrics)
+    }
+
+    fn list_domains_calls(metrics: &MetricsLayer) -> u64 {
+        metrics
+            .snapshot()
+            .get(&ProviderOperation::ListDomains)
+            .map_or(0, |stats| stats.calls)
+    }
+
+    #[tokio::test]
+    async fn test_list_domains_cached_within_ttl() {
+        let (service, metrics) = service(Duration::from_mins(1)).await;
+
+        let first = service
+            .list_domains("acc", Some(1), Some(20), None, false)
+            .await
+            .unwrap();
+        let second = service
+            .list_domains("acc", Some(1), Some(20), None, false)
+            .await
+            .unwrap();
+        assert_eq!(first.items.len(), second.items.len());
+        assert_eq!(list_domains_calls(&metrics), 1);
+
+        // 分页参数不同时不命中缓存
+        service
+            .list_domains("acc", Some(2), Some(20), None, false)
+            .await
+            .unwrap();
+        assert_eq!(list_domains_calls(&metrics), 2);
+
+        // 过滤词不同时不命中缓存
+        service
+            .list_domains("acc", Some(1), Some(20), Some("example".to_string()), false)
+            .await
+            .unwrap();
+        assert_eq!(list_domains_calls(&metrics), 3);
+
+        service.invalidate_cache("acc");
+        service
+            .list_domains("acc", Some(1), Some(20), None, false)
+            .await
+            .unwrap();
+        assert_eq!(list_domains_calls(&metrics), 4);
+    }
+
+    #[tokio::test]
+    async fn test_list_domains_fills_record_count() {
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let provider = Arc::new(MockProvider::empty(Some("example.com")).without_record_counts());
+        registry.register("acc".to_string(), provider.clone()).await;
+        let ctx = ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            Arc::new(MemoryAccountRepository::default()),
+            registry,
+        );
+        let service = DomainService::new(Arc::new(ctx));
+
+        for name in ["www", "api", "mail"] {
+            provider
+                .create_record(&CreateDnsRecordRequest {
+                    domain_id: "example.com".to_string(),
+                    record_type: DnsRecordType::A,
+                    name: name.to_string(),
+                    value: "192.0.2.1".to_string(),
+                    ttl: 300,
+                    priority: None,
+                    proxied: None,
+                    line: None,
+                    weight: None,
+                    comment: None,
+                    tags: None,
+                })
+                .await
+                .unwrap();
+        }
+
+        let plain = service
+            .list_domains("acc", None, None, None, false)
+            .await
+            .unwrap();
+        assert_eq!(plain.items[0].record_count, None);
+
+        // 命中缓存时同样补全
+        let counted = service
+            .list_domains("acc", None, None, None, true)
+            .await
+            .unwrap();
+        assert_eq!(counted.items[0].record_count, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_list_domains_zero_ttl_disables_cache() {
+        let (service, metrics) = service(Duration::ZERO).await;
+
+        service
+            .list_domains("acc", None, None, None, false)
+            .await
+            .unwrap();
+        service
+            .list_domains("acc", None, None, None, false)
+            .await
+            .unwrap();
+        assert_eq!(list_domains_calls(&metrics), 2);
+    }
+}
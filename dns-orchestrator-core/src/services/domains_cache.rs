@@ -0,0 +1,94 @@
+//! 域名列表缓存
+//!
+//! 域名列表很少变化，每次打开界面都请求服务商既慢又消耗 API 配额。
+//! 按账户 + 分页参数缓存 `list_domains` 结果，账户变更时显式失效。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::types::{AppDomain, PaginatedResponse, PaginationParams};
+
+/// 默认缓存有效期
+pub const DEFAULT_DOMAINS_CACHE_TTL: Duration = Duration::from_mins(1);
+
+/// 缓存键：(`account_id`, page, `page_size`, `name_filter`)
+type CacheKey = (String, u32, u32, Option<String>);
+
+type CacheEntries = HashMap<CacheKey, (Instant, PaginatedResponse<AppDomain>)>;
+
+/// 域名列表缓存（可克隆，所有副本共享同一份数据）
+#[derive(Debug, Clone)]
+pub struct DomainsCache {
+    ttl: Duration,
+    entries: Arc<Mutex<CacheEntries>>,
+}
+
+impl Default for DomainsCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_DOMAINS_CACHE_TTL)
+    }
+}
+
+impl DomainsCache {
+    /// 创建指定有效期的缓存；`ttl` 为 0 时不缓存
+    #[must_use]
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn key(account_id: &str, params: &PaginationParams) -> CacheKey {
+        (
+            account_id.to_string(),
+            params.page,
+            params.page_size,
+            params.name_filter().map(str::to_lowercase),
+        )
+    }
+
+    /// 读取未过期的缓存
+    pub(crate) fn get(
+        &self,
+        account_id: &str,
+        params: &PaginationParams,
+    ) -> Option<PaginatedResponse<AppDomain>> {
+        let entries = self.entries.lock().ok()?;
+        let (fetched_at, response) = entries.get(&Self::key(account_id, params))?;
+        (fetched_at.elapsed() < self.ttl).then(|| response.clone())
+    }
+
+    /// 写入缓存
+    pub(crate) fn insert(
+        &self,
+        account_id: &str,
+        params: &PaginationParams,
+        response: &PaginatedResponse<AppDomain>,
+    ) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                Self::key(account_id, params),
+                (Instant::now(), response.clone()),
+            );
+        }
+    }
+
+    /// 使账户下的全部缓存失效（账户创建/删除、凭证或域名变更时调用）
+    pub fn invalidate(&self, account_id: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|(id, _, _, _), _| id != account_id);
+        }
+    }
+
+    /// 清空全部缓存
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
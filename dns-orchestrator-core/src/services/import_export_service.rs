@@ -3,96 +3,98 @@
 use std::collections::HashSet;
 use std::sync::Arc;
 
-use dns_orchestrator_provider::{create_provider, ProviderCredentials};
+use dns_orchestrator_provider::ProviderCredentials;
+use futures::stream::{self, StreamExt};
 
 use crate::crypto;
 use crate::error::{CoreError, CoreResult};
-use crate::services::ServiceContext;
+use crate::services::account_metadata_service::account_name_key;
+use crate::services::import_formats;
+use crate::services::{build_provider, ServiceContext};
 use crate::types::{
     Account, AccountStatus, ExportAccountsRequest, ExportAccountsResponse, ExportFile,
     ExportFileHeader, ExportedAccount, ImportAccountsRequest, ImportFailure, ImportPreview,
     ImportPreviewAccount, ImportResult,
 };
 
+/// 导入时并发创建账号的最大数量（避免触发服务商限流）
+const IMPORT_CONCURRENCY: usize = 5;
+
 /// 账户导入导出服务
 pub struct ImportExportService {
     ctx: Arc<ServiceContext>,
+    /// 是否要求账户名称唯一
+    unique_names: bool,
 }
 
 impl ImportExportService {
     /// 创建导入导出服务实例
     #[must_use]
     pub fn new(ctx: Arc<ServiceContext>) -> Self {
-        Self { ctx }
+        Self {
+            ctx,
+            unique_names: false,
+        }
     }
 
-    /// 解析并解密导出文件，返回账户列表
-    fn parse_and_decrypt_accounts(
-        content: &str,
-        password: Option<&str>,
-    ) -> CoreResult<(ExportFile, Option<Vec<ExportedAccount>>)> {
-        // 1. 解析文件
-        let export_file: ExportFile = serde_json::from_str(content)
-            .map_err(|e| CoreError::ImportExportError(format!("无效的导入文件: {e}")))?;
-
-        // 2. 检查文件格式版本并获取加密参数
-        let kdf_iterations = if export_file.header.encrypted {
-            crypto::get_pbkdf2_iterations(export_file.header.version).ok_or_else(|| {
-                CoreError::ImportExportError(format!(
-                    "不支持的文件版本: {}",
-                    export_file.header.version
-                ))
-            })?
-        } else {
-            0 // 未加密文件不需要迭代次数
-        };
-
-        // 3. 如果加密但未提供密码，返回 None 表示需要密码
-        if export_file.header.encrypted && password.is_none() {
-            return Ok((export_file, None));
-        }
+    /// 设置是否要求账户名称唯一（默认关闭，保持向后兼容）
+    #[must_use]
+    pub fn with_unique_names(mut self, enabled: bool) -> Self {
+        self.unique_names = enabled;
+        self
+    }
 
-        // 4. 解密或直接解析账号数据
-        let accounts: Vec<ExportedAccount> = if export_file.header.encrypted {
-            let password = password
-                .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
-
-            log::info!(
-                "解密版本 {} 的文件，使用 PBKDF2-HMAC-SHA256 ({} 次迭代)",
-                export_file.header.version,
-                kdf_iterations
-            );
-
-            let ciphertext = export_file
-                .data
-                .as_str()
-                .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
-            let salt = export_file
-                .header
-                .salt
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密盐值".to_string()))?;
-            let nonce = export_file
-                .header
-                .nonce
-                .as_ref()
-                .ok_or_else(|| CoreError::ImportExportError("缺少加密 nonce".to_string()))?;
+    /// 构建加密的导出文件（文件头作为 AAD 绑定到密文）
+    fn build_encrypted_file(
+        accounts_json: &serde_json::Value,
+        password: &str,
+        exported_at: String,
+        app_version: &str,
+    ) -> CoreResult<ExportFile> {
+        Self::build_encrypted_file_with_kdf(
+            accounts_json,
+            password,
+            exported_at,
+            app_version,
+            crypto::CURRENT_FILE_VERSION,
+            crypto::get_current_kdf(),
+        )
+    }
 
-            // 使用版本对应的迭代次数解密
-            let plaintext =
-                crypto::decrypt_with_iterations(ciphertext, password, salt, nonce, kdf_iterations)
-                    .map_err(|_| {
-                        CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string())
-                    })?;
+    /// 使用指定版本与 KDF 参数构建加密的导出文件
+    ///
+    /// KDF 参数写入文件头，导入时优先使用，因此之后调整某版本的默认参数不影响已导出的文件
+    fn build_encrypted_file_with_kdf(
+        accounts_json: &serde_json::Value,
+        password: &str,
+        exported_at: String,
+        app_version: &str,
+        version: u32,
+        kdf: crypto::KdfParams,
+    ) -> CoreResult<ExportFile> {
+        let plaintext = serde_json::to_vec(accounts_json)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
 
-            serde_json::from_slice(&plaintext)
-                .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?
-        } else {
-            serde_json::from_value(export_file.data.clone())
-                .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?
+        let mut header = ExportFileHeader {
+            version,
+            encrypted: true,
+            salt: None,
+            nonce: None,
+            kdf: Some(kdf),
+            exported_at,
+            app_version: app_version.to_string(),
         };
+        let aad = header.associated_data()?;
 
-        Ok((export_file, Some(accounts)))
+        let (salt, nonce, ciphertext) = crypto::encrypt_with_kdf(&plaintext, password, &aad, &kdf)
+            .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
+        header.salt = Some(salt);
+        header.nonce = Some(nonce);
+
+        Ok(ExportFile {
+            header,
+            data: serde_json::Value::String(ciphertext),
+        })
     }
 
     /// 导出账户
@@ -150,23 +152,7 @@ impl ImportExportService {
                 .as_ref()
                 .ok_or_else(|| CoreError::ValidationError("加密导出需要提供密码".to_string()))?;
 
-            let plaintext = serde_json::to_vec(&accounts_json)
-                .map_err(|e| CoreError::SerializationError(e.to_string()))?;
-
-            let (salt, nonce, ciphertext) = crypto::encrypt(&plaintext, password)
-                .map_err(|e| CoreError::ImportExportError(e.to_string()))?;
-
-            ExportFile {
-                header: ExportFileHeader {
-                    version: crypto::CURRENT_FILE_VERSION,
-                    encrypted: true,
-                    salt: Some(salt),
-                    nonce: Some(nonce),
-                    exported_at: now.to_rfc3339(),
-                    app_version: app_version.to_string(),
-                },
-                data: serde_json::Value::String(ciphertext),
-            }
+            Self::build_encrypted_file(&accounts_json, password, now.to_rfc3339(), app_version)?
         } else {
             ExportFile {
                 header: ExportFileHeader {
@@ -174,6 +160,7 @@ impl ImportExportService {
                     encrypted: false,
                     salt: None,
                     nonce: None,
+                    kdf: None,
                     exported_at: now.to_rfc3339(),
                     app_version: app_version.to_string(),
                 },
@@ -202,12 +189,13 @@ impl ImportExportService {
         content: &str,
         password: Option<&str>,
     ) -> CoreResult<ImportPreview> {
-        // 1. 解析并解密
-        let (export_file, accounts_opt) = Self::parse_and_decrypt_accounts(content, password)?;
+        // 1. 识别格式并解析、解密
+        let parsed = import_formats::parse_import(content, password)?;
 
         // 2. 如果需要密码但未提供，返回提示
-        let Some(accounts) = accounts_opt else {
+        let Some(accounts) = parsed.accounts else {
             return Ok(ImportPreview {
+                format: parsed.format,
                 encrypted: true,
                 account_count: 0,
                 accounts: None,
@@ -229,106 +217,382 @@ impl ImportExportService {
             .collect();
 
         Ok(ImportPreview {
-            encrypted: export_file.header.encrypted,
+            format: parsed.format,
+            encrypted: parsed.encrypted,
             account_count: accounts.len(),
             accounts: Some(preview_accounts),
         })
     }
 
     /// 执行导入
+    ///
+    /// 账号以有界并发导入，完成顺序不固定；返回的失败项按导入文件中的顺序排列
     pub async fn import_accounts(
         &self,
         request: ImportAccountsRequest,
     ) -> CoreResult<ImportResult> {
-        // 1. 解析和解密
-        let (_, accounts_opt) =
-            Self::parse_and_decrypt_accounts(&request.content, request.password.as_deref())?;
+        self.ctx.maintenance.ensure_writable()?;
 
-        let accounts = accounts_opt
-            .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
+        // 1. 识别格式并解析、解密
+        let parsed = import_formats::parse_import(&request.content, request.password.as_deref())?;
 
-        // 2. 逐个导入账号
-        let mut success_count = 0;
-        let mut failures = Vec::new();
-        let now = chrono::Utc::now();
+        let accounts = parsed
+            .accounts
+            .ok_or_else(|| CoreError::ImportExportError("加密文件需要提供密码".to_string()))?;
 
-        for exported in accounts {
-            // 2.1 转换凭证并创建 provider 实例
-            let credentials =
-                match ProviderCredentials::from_map(&exported.provider, &exported.credentials) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        failures.push(ImportFailure {
-                            name: exported.name.clone(),
-                            reason: format!("凭证格式错误: {e}"),
-                        });
-                        continue;
-                    }
+        // 2. 开启名称唯一约束时，先排除与现有账号或文件内重名的账号
+        //    失败项带上账号在文件中的序号，便于最后按文件顺序返回
+        let mut failures: Vec<(usize, ImportFailure)> = Vec::new();
+        let accounts: Vec<(usize, ExportedAccount)> = accounts.into_iter().enumerate().collect();
+        let accounts = if self.unique_names {
+            let existing = self.ctx.account_repository.find_all().await?;
+            let mut taken: HashSet<String> =
+                existing.iter().map(|a| account_name_key(&a.name)).collect();
+            let (accepted, rejected): (Vec<_>, Vec<_>) = accounts
+                .into_iter()
+                .partition(|(_, a)| taken.insert(account_name_key(&a.name)));
+            failures.extend(rejected.into_iter().map(|(index, a)| {
+                let failure = ImportFailure {
+                    reason: format!("账户名称 {} 已存在", a.name),
+                    name: a.name,
                 };
-            let provider = match create_provider(credentials) {
-                Ok(p) => p,
-                Err(e) => {
-                    failures.push(ImportFailure {
-                        name: exported.name.clone(),
-                        reason: format!("创建 Provider 失败: {e}"),
-                    });
-                    continue;
-                }
-            };
+                (index, failure)
+            }));
+            accepted
+        } else {
+            accounts
+        };
 
-            // 2.2 生成新的账号 ID
-            let account_id = uuid::Uuid::new_v4().to_string();
-
-            // 2.3 保存凭证
-            if let Err(e) = self
-                .ctx
-                .credential_store
-                .save(&account_id, &exported.credentials)
-                .await
-            {
-                failures.push(ImportFailure {
-                    name: exported.name.clone(),
-                    reason: format!("保存凭证失败: {e}"),
-                });
-                continue;
+        // 3. 并发导入账号（有界并发，单个账号内部仍按顺序执行）
+        let now = chrono::Utc::now();
+        let results: Vec<(usize, Result<(), ImportFailure>)> = stream::iter(accounts)
+            .map(|(index, exported)| async move { (index, self.import_one(exported, now).await) })
+            .buffer_unordered(IMPORT_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut success_count = 0;
+        for (index, result) in results {
+            match result {
+                Ok(()) => success_count += 1,
+                Err(failure) => failures.push((index, failure)),
             }
+        }
 
-            // 2.4 注册 provider
-            self.ctx
-                .provider_registry
-                .register(account_id.clone(), provider)
-                .await;
-
-            // 2.5 创建账号元数据
-            let account = Account {
-                id: account_id.clone(),
-                name: exported.name.clone(),
-                provider: exported.provider,
-                created_at: now,
-                updated_at: now,
-                status: Some(AccountStatus::Active),
-                error: None,
-            };
+        // 4. 完成顺序取决于各账号耗时，按文件顺序排列失败项
+        failures.sort_by_key(|(index, _)| *index);
 
-            // 2.6 保存到仓库，失败时 cleanup
-            if let Err(e) = self.ctx.account_repository.save(&account).await {
-                // Cleanup: 删除凭证和注销 provider
-                let _ = self.ctx.credential_store.delete(&account_id).await;
-                self.ctx.provider_registry.unregister(&account_id).await;
+        Ok(ImportResult {
+            success_count,
+            failures: failures.into_iter().map(|(_, failure)| failure).collect(),
+        })
+    }
 
-                failures.push(ImportFailure {
+    /// 导入单个账号：转换凭证 -> 创建 Provider -> 保存凭证 -> 注册 Provider -> 保存元数据
+    async fn import_one(
+        &self,
+        exported: ExportedAccount,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> Result<(), ImportFailure> {
+        // 1. 转换凭证并创建 provider 实例
+        let provider = ProviderCredentials::from_map(&exported.provider, &exported.credentials)
+            .map_err(|e| format!("凭证格式错误: {e}"))
+            .and_then(|c| {
+                build_provider(c, &self.ctx.provider_options)
+                    .map_err(|e| format!("创建 Provider 失败: {e}"))
+            });
+        let provider = match provider {
+            Ok(p) => p,
+            Err(reason) => {
+                return Err(ImportFailure {
                     name: exported.name,
-                    reason: format!("保存账户失败: {e}"),
-                });
-                continue;
+                    reason,
+                })
             }
+        };
 
-            success_count += 1;
+        // 2. 生成新的账号 ID
+        let account_id = uuid::Uuid::new_v4().to_string();
+
+        // 3. 保存凭证
+        if let Err(e) = self
+            .ctx
+            .credential_store
+            .save(&account_id, &exported.credentials)
+            .await
+        {
+            return Err(ImportFailure {
+                name: exported.name,
+                reason: format!("保存凭证失败: {e}"),
+            });
         }
 
-        Ok(ImportResult {
-            success_count,
-            failures,
-        })
+        // 4. 注册 provider
+        self.ctx
+            .provider_registry
+            .register(account_id.clone(), provider)
+            .await;
+
+        // 5. 创建账号元数据
+        let account = Account {
+            id: account_id.clone(),
+            name: exported.name.clone(),
+            provider: exported.provider,
+            created_at: now,
+            updated_at: now,
+            status: Some(AccountStatus::Active),
+            error: None,
+            tags: Vec::new(),
+        };
+
+        // 6. 保存到仓库，失败时 cleanup
+        if let Err(e) = self.ctx.account_repository.save(&account).await {
+            // Cleanup: 删除凭证和注销 provider
+            let _ = self.ctx.credential_store.delete(&account_id).await;
+            self.ctx.provider_registry.unregister(&account_id).await;
+
+            return Err(ImportFailure {
+                name: exported.name,
+                reason: format!("保存账户失败: {e}"),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{MemoryAccountRepository, MemoryCredentialStore};
+    use crate::traits::{
+        AccountRepository, CredentialStore, InMemoryProviderRegistry, ProviderRegistry,
+    };
+    use crate::types::ImportFormat;
+
+    fn encrypted_content(password: &str) -> String {
+        let accounts = serde_json::json!([]);
+        let file = ImportExportService::build_encrypted_file(
+            &accounts,
+            password,
+            "2025-01-01T00:00:00+00:00".to_string(),
+            "1.0.0",
+        )
+        .unwrap();
+        serde_json::to_string(&file).unwrap()
+    }
+
+    #[test]
+    fn test_encrypted_round_trip() {
+        let content = encrypted_content("secret");
+        let file: ExportFile = serde_json::from_str(&content).unwrap();
+        assert_eq!(file.header.kdf, Some(crypto::get_current_kdf()));
+
+        let parsed = import_formats::parse_import(&content, Some("secret")).unwrap();
+        assert_eq!(parsed.format, ImportFormat::Native);
+        assert!(parsed.accounts.unwrap().is_empty());
+    }
+
+    /// 模拟某版本默认参数调整前导出的文件：文件头记录的参数与当前版本推断的不同
+    fn encrypted_content_with_kdf(version: u32, kdf: crypto::KdfParams) -> String {
+        let file = ImportExportService::build_encrypted_file_with_kdf(
+            &serde_json::json!([]),
+            "secret",
+            "2025-01-01T00:00:00+00:00".to_string(),
+            "1.0.0",
+            version,
+            kdf,
+        )
+        .unwrap();
+        serde_json::to_string(&file).unwrap()
+    }
+
+    #[test]
+    fn test_stored_kdf_params_survive_default_bump() {
+        let old_pbkdf2 = crypto::KdfParams::Pbkdf2 { iterations: 1_000 };
+        assert_ne!(crypto::get_kdf_params(3), Some(old_pbkdf2));
+        let content = encrypted_content_with_kdf(3, old_pbkdf2);
+        let parsed = import_formats::parse_import(&content, Some("secret")).unwrap();
+        assert!(parsed.accounts.unwrap().is_empty());
+
+        let old_argon2 = crypto::KdfParams::Argon2id {
+            memory_kib: 64,
+            iterations: 1,
+            parallelism: 1,
+        };
+        let content = encrypted_content_with_kdf(crypto::CURRENT_FILE_VERSION, old_argon2);
+        let parsed = import_formats::parse_import(&content, Some("secret")).unwrap();
+        assert!(parsed.accounts.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_stored_kdf_params_are_authenticated() {
+        // 篡改文件头中的 KDF 参数会改变 AAD，解密失败
+        let content =
+            encrypted_content_with_kdf(3, crypto::KdfParams::Pbkdf2 { iterations: 1_000 });
+        let mut file: ExportFile = serde_json::from_str(&content).unwrap();
+        file.header.kdf = Some(crypto::KdfParams::Pbkdf2 { iterations: 1_001 });
+        let tampered = serde_json::to_string(&file).unwrap();
+
+        let result = import_formats::parse_import(&tampered, Some("secret"));
+        assert!(matches!(result, Err(CoreError::ImportExportError(_))));
+    }
+
+    #[test]
+    fn test_tampered_header_fails_decryption() {
+        let content = encrypted_content("secret");
+        let mut file: ExportFile = serde_json::from_str(&content).unwrap();
+        file.header.app_version = "9.9.9".to_string();
+        let tampered = serde_json::to_string(&file).unwrap();
+
+        let result = import_formats::parse_import(&tampered, Some("secret"));
+        assert!(matches!(result, Err(CoreError::ImportExportError(_))));
+    }
+
+    #[test]
+    fn test_legacy_version_without_aad() {
+        let plaintext = serde_json::to_vec(&serde_json::json!([])).unwrap();
+        let kdf = crypto::get_kdf_params(2).unwrap();
+        let (salt, nonce, ciphertext) =
+            crypto::encrypt_with_kdf(&plaintext, "secret", &[], &kdf).unwrap();
+        let file = ExportFile {
+            header: ExportFileHeader {
+                version: 2,
+                encrypted: true,
+                salt: Some(salt),
+                nonce: Some(nonce),
+                kdf: None,
+                exported_at: "2025-01-01T00:00:00+00:00".to_string(),
+                app_version: "1.0.0".to_string(),
+            },
+            data: serde_json::Value::String(ciphertext),
+        };
+        let content = serde_json::to_string(&file).unwrap();
+
+        let parsed = import_formats::parse_import(&content, Some("secret")).unwrap();
+        assert!(parsed.accounts.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_import_mixed_accounts() {
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        let repository = Arc::new(MemoryAccountRepository::default());
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let ctx = Arc::new(ServiceContext::new(
+            credentials.clone(),
+            repository.clone(),
+            registry.clone(),
+        ));
+        let service = ImportExportService::new(ctx);
+
+        let accounts: Vec<_> = (0..12)
+            .map(|i| {
+                if i % 3 == 0 {
+                    serde_json::json!({ "name": format!("bad-{i}"), "provider": "cloudflare" })
+                } else {
+                    serde_json::json!({ "name": format!("ok-{i}"), "provider": "cloudflare", "apiToken": "t" })
+                }
+            })
+            .collect();
+        let request = ImportAccountsRequest {
+            content: serde_json::Value::Array(accounts).to_string(),
+            password: None,
+        };
+
+        let result = service.import_accounts(request).await.unwrap();
+        assert_eq!(result.success_count, 8);
+        assert_eq!(result.failures.len(), 4);
+        assert!(result.failures.iter().all(|f| f.name.starts_with("bad-")));
+        assert_eq!(repository.find_all().await.unwrap().len(), 8);
+        assert_eq!(credentials.load_all().await.unwrap().len(), 8);
+        assert_eq!(registry.list_account_ids().await.len(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_duplicate_names_when_unique() {
+        let credentials = Arc::new(MemoryCredentialStore::default());
+        let repository = Arc::new(MemoryAccountRepository::default());
+        let ctx = Arc::new(ServiceContext::new(
+            credentials,
+            repository.clone(),
+            Arc::new(InMemoryProviderRegistry::new()),
+        ));
+        let service = ImportExportService::new(ctx).with_unique_names(true);
+
+        let account = |name: &str| serde_json::json!({ "name": name, "provider": "cloudflare", "apiToken": "t" });
+        let request = |names: &[&str]| ImportAccountsRequest {
+            content: serde_json::Value::Array(names.iter().map(|n| account(n)).collect())
+                .to_string(),
+            password: None,
+        };
+
+        // 文件内重名（忽略大小写）只导入第一个
+        let result = service
+            .import_accounts(request(&["prod", "PROD ", "dev"]))
+            .await
+            .unwrap();
+        assert_eq!(result.success_count, 2);
+        assert_eq!(result.failures.len(), 1);
+
+        // 与现有账号重名
+        let result = service
+            .import_accounts(request(&["dev", "test"]))
+            .await
+            .unwrap();
+        assert_eq!(result.success_count, 1);
+        assert_eq!(result.failures[0].name, "dev");
+        assert_eq!(repository.find_all().await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_import_failures_follow_file_order() {
+        let repository = Arc::new(MemoryAccountRepository::default());
+        let registry = Arc::new(InMemoryProviderRegistry::new());
+        let ctx = Arc::new(ServiceContext::new(
+            Arc::new(MemoryCredentialStore::default()),
+            repository.clone(),
+            registry.clone(),
+        ));
+        let service = ImportExportService::new(ctx).with_unique_names(true);
+
+        // 凭证缺失、重名和正常账号交错，数量远超并发上限
+        let mut expected_failures = Vec::new();
+        let accounts: Vec<_> = (0..60)
+            .map(|i| match i % 5 {
+                0 => {
+                    expected_failures.push(format!("bad-{i}"));
+                    serde_json::json!({ "name": format!("bad-{i}"), "provider": "cloudflare" })
+                }
+                1 if i > 1 => {
+                    expected_failures.push("dup".to_string());
+                    serde_json::json!({ "name": "dup", "provider": "cloudflare", "apiToken": "t" })
+                }
+                _ => {
+                    let name = if i == 1 {
+                        "dup".to_string()
+                    } else {
+                        format!("ok-{i}")
+                    };
+                    serde_json::json!({ "name": name, "provider": "cloudflare", "apiToken": "t" })
+                }
+            })
+            .collect();
+        let request = ImportAccountsRequest {
+            content: serde_json::Value::Array(accounts).to_string(),
+            password: None,
+        };
+
+        let result = service.import_accounts(request).await.unwrap();
+        let failed: Vec<_> = result.failures.iter().map(|f| f.name.clone()).collect();
+        assert_eq!(result.success_count, 60 - expected_failures.len());
+        assert_eq!(failed, expected_failures);
+        assert_eq!(
+            repository.find_all().await.unwrap().len(),
+            result.success_count
+        );
+        assert_eq!(
+            registry.list_account_ids().await.len(),
+            result.success_count
+        );
     }
 }
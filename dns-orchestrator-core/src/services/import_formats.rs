@@ -0,0 +1,396 @@
+//! 导入文件格式识别与解析
+//!
+//! 按内容结构（而非文件扩展名）识别导入文件格式，并统一转换为 `ExportedAccount` 列表。
+//! 新增格式只需实现 `ImportFormatHandler` 并加入 `HANDLERS`。
+
+use std::collections::HashMap;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use serde_json::Value;
+
+use dns_orchestrator_provider::ProviderType;
+
+use crate::crypto;
+use crate::error::{CoreError, CoreResult};
+use crate::types::{ExportFile, ExportedAccount, ImportFormat};
+
+/// 旧版 Web 端数据块：盐值长度
+const LEGACY_SALT_LENGTH: usize = 16;
+/// 旧版 Web 端数据块：nonce 长度
+const LEGACY_NONCE_LENGTH: usize = 12;
+/// AES-GCM 认证标签长度
+const GCM_TAG_LENGTH: usize = 16;
+/// 旧版 Web 端导出使用的文件版本（决定 PBKDF2 迭代次数）
+const LEGACY_WEB_VERSION: u32 = 1;
+
+/// 通用 JSON 格式中不属于凭证的字段
+const GENERIC_META_FIELDS: &[&str] = &["id", "name", "provider", "type", "createdAt", "updatedAt"];
+
+/// 解析结果
+pub(crate) struct ParsedImport {
+    /// 识别出的格式
+    pub format: ImportFormat,
+    /// 文件是否加密
+    pub encrypted: bool,
+    /// 账号列表（加密且未提供密码时为 None）
+    pub accounts: Option<Vec<ExportedAccount>>,
+}
+
+/// 导入格式处理器
+trait ImportFormatHandler: Sync {
+    /// 处理器对应的格式
+    fn format(&self) -> ImportFormat;
+
+    /// 按内容结构判断是否为该格式
+    fn detect(&self, content: &str) -> bool;
+
+    /// 解析（必要时解密）为账号列表
+    fn parse(&self, content: &str, password: Option<&str>) -> CoreResult<ParsedImport>;
+}
+
+/// 已注册的格式处理器（按顺序匹配）
+static HANDLERS: &[&dyn ImportFormatHandler] =
+    &[&NativeFormat, &LegacyWebFormat, &GenericJsonFormat];
+
+/// 识别格式并解析导入内容
+pub(crate) fn parse_import(content: &str, password: Option<&str>) -> CoreResult<ParsedImport> {
+    let handler = HANDLERS
+        .iter()
+        .find(|h| h.detect(content))
+        .ok_or_else(|| CoreError::ImportExportError("无法识别的导入文件格式".to_string()))?;
+
+    log::info!("识别到导入文件格式: {:?}", handler.format());
+    handler.parse(content, password)
+}
+
+// ============ 本应用导出格式 ============
+
+struct NativeFormat;
+
+impl ImportFormatHandler for NativeFormat {
+    fn format(&self) -> ImportFormat {
+        ImportFormat::Native
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        serde_json::from_str::<Value>(content).is_ok_and(|v| {
+            v.get("header").is_some_and(|h| h.get("version").is_some()) && v.get("data").is_some()
+        })
+    }
+
+    fn parse(&self, content: &str, password: Option<&str>) -> CoreResult<ParsedImport> {
+        // 1. 解析文件
+        let export_file: ExportFile = serde_json::from_str(content)
+            .map_err(|e| CoreError::ImportExportError(format!("无效的导入文件: {e}")))?;
+        let encrypted = export_file.header.encrypted;
+
+        // 2. 未加密文件直接解析账号数据
+        if !encrypted {
+            let accounts = serde_json::from_value(export_file.data)
+                .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?;
+            return Ok(ParsedImport {
+                format: self.format(),
+                encrypted,
+                accounts: Some(accounts),
+            });
+        }
+
+        // 3. 检查文件格式版本并获取加密参数
+        let version = export_file.header.version;
+        let kdf = export_file
+            .header
+            .kdf
+            .or_else(|| crypto::get_kdf_params(version))
+            .ok_or_else(|| CoreError::ImportExportError(format!("不支持的文件版本: {version}")))?;
+
+        // 4. 加密但未提供密码，返回 None 表示需要密码
+        let Some(password) = password else {
+            return Ok(ParsedImport {
+                format: self.format(),
+                encrypted,
+                accounts: None,
+            });
+        };
+
+        log::info!("解密版本 {version} 的文件，KDF: {kdf:?}");
+
+        let ciphertext = export_file
+            .data
+            .as_str()
+            .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
+        let salt = export_file
+            .header
+            .salt
+            .as_ref()
+            .ok_or_else(|| CoreError::ImportExportError("缺少加密盐值".to_string()))?;
+        let nonce = export_file
+            .header
+            .nonce
+            .as_ref()
+            .ok_or_else(|| CoreError::ImportExportError("缺少加密 nonce".to_string()))?;
+
+        // 新版本文件头参与认证，旧版本未使用 AAD
+        let aad = if crypto::uses_header_aad(version) {
+            export_file.header.associated_data()?
+        } else {
+            Vec::new()
+        };
+
+        // 使用文件头记录（或版本对应）的 KDF 参数解密
+        let plaintext = crypto::decrypt_with_kdf(ciphertext, password, salt, nonce, &kdf, &aad)
+            .map_err(|_| {
+                CoreError::ImportExportError(
+                    "解密失败，请检查密码是否正确或文件是否被篡改".to_string(),
+                )
+            })?;
+
+        let accounts = serde_json::from_slice(&plaintext)
+            .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?;
+
+        Ok(ParsedImport {
+            format: self.format(),
+            encrypted,
+            accounts: Some(accounts),
+        })
+    }
+}
+
+// ============ 旧版 Web 端导出格式 ============
+
+/// 旧版 Web 端导出：Base64(`salt || nonce || ciphertext`)，PBKDF2 100,000 次迭代，无 AAD
+struct LegacyWebFormat;
+
+impl LegacyWebFormat {
+    /// 提取 Base64 数据块（允许被 JSON 字符串包裹）
+    fn blob(content: &str) -> Option<Vec<u8>> {
+        let trimmed = content.trim();
+        let raw = match serde_json::from_str::<Value>(trimmed) {
+            Ok(Value::String(s)) => s,
+            Ok(_) => return None,
+            Err(_) => trimmed.to_string(),
+        };
+        let bytes = BASE64.decode(raw.trim()).ok()?;
+        (bytes.len() > LEGACY_SALT_LENGTH + LEGACY_NONCE_LENGTH + GCM_TAG_LENGTH).then_some(bytes)
+    }
+}
+
+impl ImportFormatHandler for LegacyWebFormat {
+    fn format(&self) -> ImportFormat {
+        ImportFormat::LegacyWeb
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        Self::blob(content).is_some()
+    }
+
+    fn parse(&self, content: &str, password: Option<&str>) -> CoreResult<ParsedImport> {
+        let blob = Self::blob(content)
+            .ok_or_else(|| CoreError::ImportExportError("无效的加密数据".to_string()))?;
+
+        let Some(password) = password else {
+            return Ok(ParsedImport {
+                format: self.format(),
+                encrypted: true,
+                accounts: None,
+            });
+        };
+
+        let (salt, rest) = blob.split_at(LEGACY_SALT_LENGTH);
+        let (nonce, ciphertext) = rest.split_at(LEGACY_NONCE_LENGTH);
+        let iterations = crypto::get_pbkdf2_iterations(LEGACY_WEB_VERSION).ok_or_else(|| {
+            CoreError::ImportExportError(format!("不支持的文件版本: {LEGACY_WEB_VERSION}"))
+        })?;
+
+        let plaintext = crypto::decrypt_with_iterations(
+            &BASE64.encode(ciphertext),
+            password,
+            &BASE64.encode(salt),
+            &BASE64.encode(nonce),
+            iterations,
+            &[],
+        )
+        .map_err(|_| CoreError::ImportExportError("解密失败，请检查密码是否正确".to_string()))?;
+
+        let value: Value = serde_json::from_slice(&plaintext)
+            .map_err(|e| CoreError::ImportExportError(format!("解析账号数据失败: {e}")))?;
+
+        Ok(ParsedImport {
+            format: self.format(),
+            encrypted: true,
+            accounts: Some(normalize_accounts(&value)?),
+        })
+    }
+}
+
+// ============ 其他工具的通用 JSON 格式 ============
+
+/// 通用 JSON 账号列表：`[...]` 或 `{ "accounts": [...] }`
+///
+/// 每项需包含 `name` 与 `provider`（或 `type`），凭证可放在 `credentials` 对象中，
+/// 也可直接作为同级字符串字段
+struct GenericJsonFormat;
+
+impl ImportFormatHandler for GenericJsonFormat {
+    fn format(&self) -> ImportFormat {
+        ImportFormat::GenericJson
+    }
+
+    fn detect(&self, content: &str) -> bool {
+        serde_json::from_str::<Value>(content)
+            .ok()
+            .and_then(|v| account_list(&v).map(|items| items.iter().all(looks_like_account)))
+            .unwrap_or(false)
+    }
+
+    fn parse(&self, content: &str, _password: Option<&str>) -> CoreResult<ParsedImport> {
+        let value: Value = serde_json::from_str(content)
+            .map_err(|e| CoreError::ImportExportError(format!("无效的导入文件: {e}")))?;
+
+        Ok(ParsedImport {
+            format: self.format(),
+            encrypted: false,
+            accounts: Some(normalize_accounts(&value)?),
+        })
+    }
+}
+
+/// 取出账号数组
+fn account_list(value: &Value) -> Option<&Vec<Value>> {
+    match value {
+        Value::Array(items) => Some(items),
+        Value::Object(obj) => obj.get("accounts").and_then(Value::as_array),
+        _ => None,
+    }
+}
+
+/// 判断对象是否具有账号的基本结构
+fn looks_like_account(item: &Value) -> bool {
+    item.get("name").is_some_and(Value::is_string)
+        && (item.get("provider").is_some_and(Value::is_string)
+            || item.get("type").is_some_and(Value::is_string))
+}
+
+/// 将宽松的账号 JSON 转换为 `ExportedAccount` 列表
+fn normalize_accounts(value: &Value) -> CoreResult<Vec<ExportedAccount>> {
+    let items = account_list(value)
+        .ok_or_else(|| CoreError::ImportExportError("未找到账号列表".to_string()))?;
+    let now = chrono::Utc::now();
+
+    items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let invalid = |reason: &str| {
+                CoreError::ImportExportError(format!("第 {} 个账号无效: {reason}", index + 1))
+            };
+
+            let name = item
+                .get("name")
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("缺少 name"))?;
+            let provider_raw = item
+                .get("provider")
+                .or_else(|| item.get("type"))
+                .and_then(Value::as_str)
+                .ok_or_else(|| invalid("缺少 provider"))?;
+            let provider: ProviderType =
+                serde_json::from_value(Value::String(provider_raw.to_lowercase()))
+                    .map_err(|_| invalid(&format!("不支持的服务商: {provider_raw}")))?;
+
+            let credentials: HashMap<String, String> = match item.get("credentials") {
+                Some(Value::Object(map)) => string_fields(map.iter()),
+                Some(_) => return Err(invalid("credentials 必须为对象")),
+                None => item
+                    .as_object()
+                    .map(|obj| {
+                        string_fields(
+                            obj.iter()
+                                .filter(|(k, _)| !GENERIC_META_FIELDS.contains(&k.as_str())),
+                        )
+                    })
+                    .unwrap_or_default(),
+            };
+
+            Ok(ExportedAccount {
+                id: uuid::Uuid::new_v4().to_string(),
+                name: name.to_string(),
+                provider,
+                created_at: now,
+                updated_at: now,
+                credentials,
+            })
+        })
+        .collect()
+}
+
+/// 只保留字符串值字段
+fn string_fields<'a>(
+    fields: impl Iterator<Item = (&'a String, &'a Value)>,
+) -> HashMap<String, String> {
+    fields
+        .filter_map(|(k, v)| v.as_str().map(|s| (k.clone(), s.to_string())))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_generic_json() {
+        let content = r#"{"accounts":[{"name":"cf","type":"Cloudflare","apiToken":"t"}]}"#;
+        let parsed = parse_import(content, None).unwrap();
+        assert_eq!(parsed.format, ImportFormat::GenericJson);
+
+        let accounts = parsed.accounts.unwrap();
+        assert_eq!(accounts[0].provider, ProviderType::Cloudflare);
+        assert_eq!(accounts[0].credentials.get("apiToken").unwrap(), "t");
+        assert!(!accounts[0].credentials.contains_key("name"));
+    }
+
+    #[test]
+    fn test_detect_legacy_web_blob() {
+        let blob = BASE64.encode([7u8; 64]);
+        let parsed = parse_import(&blob, None).unwrap();
+        assert_eq!(parsed.format, ImportFormat::LegacyWeb);
+        assert!(parsed.encrypted);
+        assert!(parsed.accounts.is_none());
+    }
+
+    #[test]
+    fn test_legacy_web_blob_decrypts() {
+        // 按旧版 Web 端方式构造：salt || nonce || ciphertext，PBKDF2 100,000 次迭代，无 AAD
+        let plaintext =
+            br#"[{"name":"cf","provider":"cloudflare","credentials":{"apiToken":"t"}}]"#;
+        let kdf = crypto::get_kdf_params(LEGACY_WEB_VERSION).unwrap();
+        let (salt, nonce, ciphertext) =
+            crypto::encrypt_with_kdf(plaintext, "secret", &[], &kdf).unwrap();
+
+        let mut blob = BASE64.decode(salt).unwrap();
+        blob.extend(BASE64.decode(nonce).unwrap());
+        blob.extend(BASE64.decode(ciphertext).unwrap());
+        let content = BASE64.encode(blob);
+
+        let parsed = parse_import(&content, Some("secret")).unwrap();
+        assert_eq!(parsed.format, ImportFormat::LegacyWeb);
+        let accounts = parsed.accounts.unwrap();
+        assert_eq!(accounts[0].name, "cf");
+        assert_eq!(accounts[0].provider, ProviderType::Cloudflare);
+        assert_eq!(accounts[0].credentials.get("apiToken").unwrap(), "t");
+
+        assert!(parse_import(&content, Some("wrong")).is_err());
+    }
+
+    #[test]
+    fn test_detect_native_unencrypted() {
+        let content = r#"{"header":{"version":3,"encrypted":false,"exportedAt":"","appVersion":"1"},"data":[]}"#;
+        let parsed = parse_import(content, None).unwrap();
+        assert_eq!(parsed.format, ImportFormat::Native);
+        assert!(parsed.accounts.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_unknown_format_rejected() {
+        assert!(parse_import(r#"{"foo":1}"#, None).is_err());
+    }
+}
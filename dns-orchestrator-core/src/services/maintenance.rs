@@ -0,0 +1,60 @@
+//! 维护模式（只读开关）
+//!
+//! 迁移或故障处理期间允许读取、拒绝所有写操作，无需停止服务。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use crate::error::{CoreError, CoreResult};
+
+/// 维护模式开关（可克隆，所有副本共享同一状态）
+#[derive(Debug, Clone, Default)]
+pub struct MaintenanceMode(Arc<AtomicBool>);
+
+impl MaintenanceMode {
+    /// 创建维护模式开关
+    #[must_use]
+    pub fn new(read_only: bool) -> Self {
+        Self(Arc::new(AtomicBool::new(read_only)))
+    }
+
+    /// 当前是否为只读模式
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// 切换只读模式
+    pub fn set_read_only(&self, read_only: bool) {
+        self.0.store(read_only, Ordering::SeqCst);
+        log::warn!(
+            "Maintenance mode {}",
+            if read_only { "enabled" } else { "disabled" }
+        );
+    }
+
+    /// 写操作前检查，只读模式下返回 `ReadOnly` 错误
+    pub fn ensure_writable(&self) -> CoreResult<()> {
+        if self.is_read_only() {
+            Err(CoreError::ReadOnly)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clones_share_state() {
+        let mode = MaintenanceMode::default();
+        let shared = mode.clone();
+        assert!(mode.ensure_writable().is_ok());
+
+        shared.set_read_only(true);
+        assert!(mode.is_read_only());
+        assert!(matches!(mode.ensure_writable(), Err(CoreError::ReadOnly)));
+    }
+}
@@ -1,31 +1,51 @@
 //! 业务逻辑服务层
 
 mod account_bootstrap_service;
+mod account_health_service;
 mod account_lifecycle_service;
 mod account_metadata_service;
 mod credential_management_service;
 mod dns_service;
 mod domain_service;
+mod domains_cache;
 mod import_export_service;
+mod import_formats;
+mod maintenance;
 mod provider_metadata_service;
 mod toolbox;
+mod webhook;
+mod zone_file;
 
 pub use account_bootstrap_service::{AccountBootstrapService, RestoreResult};
+pub use account_health_service::{
+    AccountHealthService, HealthCheckSummary, DEFAULT_HEALTH_CHECK_INTERVAL,
+};
 pub use account_lifecycle_service::AccountLifecycleService;
 pub use account_metadata_service::AccountMetadataService;
 pub use credential_management_service::CredentialManagementService;
-pub use dns_service::DnsService;
+pub use dns_service::{DnsService, DEFAULT_TRASH_RETENTION_DAYS};
 pub use domain_service::DomainService;
+pub use domains_cache::{DomainsCache, DEFAULT_DOMAINS_CACHE_TTL};
 pub use import_export_service::ImportExportService;
+pub use maintenance::MaintenanceMode;
 pub use provider_metadata_service::ProviderMetadataService;
 pub use toolbox::ToolboxService;
+pub use webhook::{
+    sign as sign_webhook_payload, RecordChangeEvent, RecordChangeKind, WebhookConfig,
+    WebhookDispatcher, WebhookNotifier, SIGNATURE_HEADER as WEBHOOK_SIGNATURE_HEADER,
+};
 
 use std::sync::Arc;
 
-use dns_orchestrator_provider::DnsProvider;
+use dns_orchestrator_provider::{
+    create_provider_with_layers, DnsProvider, LoggingLayer, ProviderCredentials, ProviderLayer,
+    ProviderOptions,
+};
 
 use crate::error::{CoreError, CoreResult};
-use crate::traits::{AccountRepository, CredentialStore, ProviderRegistry};
+use crate::traits::{
+    AccountRepository, CredentialStore, IdempotencyRepository, ProviderRegistry, TrashRepository,
+};
 use crate::types::AccountStatus;
 
 /// 服务上下文 - 持有所有依赖
@@ -38,6 +58,18 @@ pub struct ServiceContext {
     pub account_repository: Arc<dyn AccountRepository>,
     /// Provider 注册表
     pub provider_registry: Arc<dyn ProviderRegistry>,
+    /// 维护模式开关
+    pub maintenance: MaintenanceMode,
+    /// 域名列表缓存
+    pub domains_cache: DomainsCache,
+    /// 回收站（未设置时删除记录不保留快照）
+    pub trash_repository: Option<Arc<dyn TrashRepository>>,
+    /// 幂等键结果仓库（未设置时忽略客户端提供的幂等键）
+    pub idempotency_repository: Option<Arc<dyn IdempotencyRepository>>,
+    /// 创建 Provider 时使用的 HTTP 超时、代理等选项
+    pub provider_options: ProviderOptions,
+    /// 记录变更 Webhook（未设置时不发送事件）
+    pub webhook: Option<WebhookNotifier>,
 }
 
 impl ServiceContext {
@@ -52,6 +84,64 @@ impl ServiceContext {
             credential_store,
             account_repository,
             provider_registry,
+            maintenance: MaintenanceMode::default(),
+            domains_cache: DomainsCache::default(),
+            trash_repository: None,
+            idempotency_repository: None,
+            provider_options: ProviderOptions::default(),
+            webhook: None,
+        }
+    }
+
+    /// 使用共享的维护模式开关
+    #[must_use]
+    pub fn with_maintenance(mut self, maintenance: MaintenanceMode) -> Self {
+        self.maintenance = maintenance;
+        self
+    }
+
+    /// 使用共享的域名列表缓存
+    #[must_use]
+    pub fn with_domains_cache(mut self, domains_cache: DomainsCache) -> Self {
+        self.domains_cache = domains_cache;
+        self
+    }
+
+    /// 启用回收站：删除记录前保存快照，可通过 `DnsService::restore_record` 恢复
+    #[must_use]
+    pub fn with_trash_repository(mut self, trash_repository: Arc<dyn TrashRepository>) -> Self {
+        self.trash_repository = Some(trash_repository);
+        self
+    }
+
+    /// 启用幂等键：窗口期内重复的写请求直接返回首次的结果
+    #[must_use]
+    pub fn with_idempotency_repository(
+        mut self,
+        idempotency_repository: Arc<dyn IdempotencyRepository>,
+    ) -> Self {
+        self.idempotency_repository = Some(idempotency_repository);
+        self
+    }
+
+    /// 使用自定义的 Provider 创建选项（如上游代理）
+    #[must_use]
+    pub fn with_provider_options(mut self, provider_options: ProviderOptions) -> Self {
+        self.provider_options = provider_options;
+        self
+    }
+
+    /// 启用记录变更 Webhook：记录创建、更新、删除成功后异步推送事件
+    #[must_use]
+    pub fn with_webhook(mut self, webhook: WebhookNotifier) -> Self {
+        self.webhook = Some(webhook);
+        self
+    }
+
+    /// 推送记录变更事件（未启用 Webhook 时忽略）
+    pub fn notify_record_change(&self, event: RecordChangeEvent) {
+        if let Some(webhook) = &self.webhook {
+            webhook.notify(event);
         }
     }
 
@@ -82,3 +172,12 @@ impl ServiceContext {
         log::warn!("Account {account_id} marked as invalid: {error_msg}");
     }
 }
+
+/// 按选项创建 Provider 实例，并叠加日志层记录每次调用的耗时与失败原因
+pub(crate) fn build_provider(
+    credentials: ProviderCredentials,
+    options: &ProviderOptions,
+) -> CoreResult<Arc<dyn DnsProvider>> {
+    let layers: [Arc<dyn ProviderLayer>; 1] = [Arc::new(LoggingLayer)];
+    Ok(create_provider_with_layers(credentials, options, &layers)?)
+}
@@ -4,7 +4,7 @@
 
 use dns_orchestrator_provider::get_all_provider_metadata;
 
-use crate::types::ProviderMetadata;
+use crate::types::{ProviderCapabilities, ProviderCapabilityMatrix, ProviderMetadata};
 
 /// Provider 元数据服务（无状态）
 pub struct ProviderMetadataService;
@@ -20,6 +20,34 @@ impl ProviderMetadataService {
     pub fn list_providers(&self) -> Vec<ProviderMetadata> {
         get_all_provider_metadata()
     }
+
+    /// 汇总所有提供商的能力矩阵
+    pub fn capability_matrix(&self) -> ProviderCapabilityMatrix {
+        let providers: Vec<ProviderCapabilities> = get_all_provider_metadata()
+            .into_iter()
+            .map(|m| ProviderCapabilities {
+                provider: m.id,
+                name: m.name,
+                supported_record_types: m.supported_record_types,
+                min_ttl: m.limits.min_ttl,
+                max_ttl: m.limits.max_ttl,
+                default_ttl: m.limits.default_ttl,
+                features: m.features,
+            })
+            .collect();
+
+        let mut record_types = Vec::new();
+        for record_type in providers.iter().flat_map(|p| &p.supported_record_types) {
+            if !record_types.contains(record_type) {
+                record_types.push(record_type.clone());
+            }
+        }
+
+        ProviderCapabilityMatrix {
+            record_types,
+            providers,
+        }
+    }
 }
 
 impl Default for ProviderMetadataService {
@@ -27,3 +55,47 @@ impl Default for ProviderMetadataService {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{DnsRecordType, ProviderType};
+
+    #[test]
+    fn test_capability_matrix() {
+        let matrix = ProviderMetadataService::new().capability_matrix();
+        assert_eq!(matrix.providers.len(), get_all_provider_metadata().len());
+
+        // 列为各服务商记录类型的并集，且不重复
+        for provider in &matrix.providers {
+            for record_type in &provider.supported_record_types {
+                assert!(matrix.record_types.contains(record_type));
+            }
+        }
+        for (i, record_type) in matrix.record_types.iter().enumerate() {
+            assert!(!matrix.record_types[i + 1..].contains(record_type));
+        }
+        assert!(matrix.record_types.contains(&DnsRecordType::Ptr));
+
+        let row = |id: ProviderType| {
+            matrix
+                .providers
+                .iter()
+                .find(|p| p.provider == id)
+                .unwrap()
+                .clone()
+        };
+        // 解析线路仅阿里云与 DNSPod 支持
+        assert!(row(ProviderType::Aliyun).features.line);
+        assert!(row(ProviderType::Dnspod).features.line);
+        assert!(!row(ProviderType::Cloudflare).features.line);
+        assert!(!row(ProviderType::Huaweicloud).features.line);
+
+        let cloudflare = row(ProviderType::Cloudflare);
+        assert!(cloudflare.features.proxy);
+        assert_eq!(cloudflare.min_ttl, 60);
+        assert!(!row(ProviderType::Aliyun)
+            .supported_record_types
+            .contains(&DnsRecordType::Ptr));
+    }
+}
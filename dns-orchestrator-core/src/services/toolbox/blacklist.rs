@@ -0,0 +1,179 @@
+//! DNSBL 黑名单检查模块
+//!
+//! 将 IP 反转后拼接各黑名单区域（如 `2.0.0.127.zen.spamhaus.org`）查询 A 记录，
+//! 有结果即表示被列入，再查询 TXT 记录获取列入原因。域名先解析为 A 记录再逐个检查。
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use hickory_resolver::{
+    config::{ResolverConfig, ResolverOpts},
+    name_server::TokioConnectionProvider,
+    TokioResolver,
+};
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{BlacklistCheckResult, BlacklistListing};
+
+/// 默认检查的黑名单区域
+pub const DEFAULT_DNSBL_ZONES: &[&str] = &[
+    "zen.spamhaus.org",
+    "bl.spamcop.net",
+    "b.barracudacentral.org",
+    "dnsbl.sorbs.net",
+    "psbl.surriel.com",
+    "dnsbl-1.uceprotect.net",
+];
+
+/// 同时进行的查询数上限
+const MAX_CONCURRENCY: usize = 16;
+
+/// 单次查询超时
+const QUERY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// DNSBL 黑名单检查
+///
+/// `zones` 为空时使用 [`DEFAULT_DNSBL_ZONES`]
+pub async fn blacklist_check(target: &str, zones: &[String]) -> CoreResult<BlacklistCheckResult> {
+    let target = target.trim().trim_end_matches('.');
+    if target.is_empty() {
+        return Err(CoreError::ValidationError(
+            "请输入 IP 地址或域名".to_string(),
+        ));
+    }
+
+    let zones: Vec<String> = if zones.is_empty() {
+        DEFAULT_DNSBL_ZONES
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    } else {
+        zones
+            .iter()
+            .map(|z| z.trim().trim_matches('.').to_lowercase())
+            .filter(|z| !z.is_empty())
+            .collect()
+    };
+
+    let provider = TokioConnectionProvider::default();
+    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
+        .with_options(ResolverOpts::default())
+        .build();
+
+    let (ips, is_domain) = if let Ok(ip) = target.parse::<IpAddr>() {
+        (vec![ip], false)
+    } else {
+        let response = resolver
+            .ipv4_lookup(target)
+            .await
+            .map_err(|e| CoreError::NetworkError(format!("无法解析域名 {target}: {e}")))?;
+        let ips: Vec<IpAddr> = response.iter().map(|a| IpAddr::V4(a.0)).collect();
+        (ips, true)
+    };
+
+    let queries: Vec<(IpAddr, String)> = ips
+        .iter()
+        .flat_map(|ip| zones.iter().map(move |zone| (*ip, zone.clone())))
+        .collect();
+
+    let resolver = &resolver;
+    let mut results: Vec<BlacklistListing> = stream::iter(queries)
+        .map(|(ip, zone)| async move { check_listing(resolver, ip, zone).await })
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await;
+
+    // 按 IP、再按区域在列表中的顺序排列
+    results.sort_by_key(|r| {
+        (
+            ips.iter().position(|ip| ip.to_string() == r.ip),
+            zones.iter().position(|z| *z == r.zone),
+        )
+    });
+
+    Ok(BlacklistCheckResult {
+        target: target.to_string(),
+        is_domain,
+        results,
+    })
+}
+
+/// 查询单个 IP 在单个黑名单区域中的状态
+async fn check_listing(resolver: &TokioResolver, ip: IpAddr, zone: String) -> BlacklistListing {
+    let query = format!("{}.{zone}.", reverse_ip_name(ip));
+    let mut listing = BlacklistListing {
+        ip: ip.to_string(),
+        zone,
+        listed: false,
+        txt: None,
+        error: None,
+    };
+
+    match tokio::time::timeout(QUERY_TIMEOUT, resolver.ipv4_lookup(query.as_str())).await {
+        Ok(Ok(response)) => {
+            let codes: Vec<_> = response.iter().map(|a| a.0).collect();
+            // 127.255.255.x 为黑名单服务的错误码（如通过公共 DNS 查询被拒绝）
+            if codes.iter().any(|c| c.octets()[..3] == [127, 255, 255]) {
+                listing.error = Some("黑名单服务拒绝了本次查询".to_string());
+            } else if !codes.is_empty() {
+                listing.listed = true;
+                listing.txt = lookup_reason(resolver, &query).await;
+            }
+        }
+        // NXDOMAIN 等无记录响应表示未被列入
+        Ok(Err(e)) if e.is_no_records_found() => {}
+        Ok(Err(e)) => listing.error = Some(e.to_string()),
+        Err(_) => listing.error = Some("查询超时".to_string()),
+    }
+
+    listing
+}
+
+/// 查询列入原因（TXT 记录）
+async fn lookup_reason(resolver: &TokioResolver, query: &str) -> Option<String> {
+    let response = tokio::time::timeout(QUERY_TIMEOUT, resolver.txt_lookup(query))
+        .await
+        .ok()?
+        .ok()?;
+    let reasons: Vec<String> = response.iter().map(ToString::to_string).collect();
+    (!reasons.is_empty()).then(|| reasons.join("; "))
+}
+
+/// 反转 IP 用于 DNSBL 查询：IPv4 按字节反转，IPv6 按半字节反转
+fn reverse_ip_name(ip: IpAddr) -> String {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, c, d] = v4.octets();
+            format!("{d}.{c}.{b}.{a}")
+        }
+        IpAddr::V6(v6) => v6
+            .octets()
+            .iter()
+            .rev()
+            .flat_map(|byte| [byte & 0x0f, byte >> 4])
+            .map(|nibble| format!("{nibble:x}"))
+            .collect::<Vec<_>>()
+            .join("."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_ip_name() {
+        assert_eq!(reverse_ip_name("127.0.0.2".parse().unwrap()), "2.0.0.127");
+        assert_eq!(
+            reverse_ip_name("2001:db8::1".parse().unwrap()),
+            "1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_blacklist_check_validation() {
+        assert!(blacklist_check("", &[]).await.is_err());
+        assert!(blacklist_check(" . ", &[]).await.is_err());
+    }
+}
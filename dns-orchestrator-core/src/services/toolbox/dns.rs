@@ -6,12 +6,19 @@ use futures::future::join_all;
 use hickory_resolver::{
     config::{NameServerConfigGroup, ResolverConfig, ResolverOpts},
     name_server::TokioConnectionProvider,
+    proto::rr::RData,
     TokioResolver,
 };
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::{DnsLookupRecord, DnsLookupResult};
 
+/// 支持查询的记录类型（不含 "ALL"）
+pub(super) const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR", "SSHFP", "TLSA", "NAPTR",
+    "HTTPS", "SVCB",
+];
+
 /// DNS 查询
 pub async fn dns_lookup(
     domain: &str,
@@ -81,14 +88,17 @@ pub async fn dns_lookup(
         "SRV" => lookup_srv(&resolver, domain, &mut records).await,
         "CAA" => lookup_caa(&resolver, domain, &mut records).await,
         "PTR" => lookup_ptr(&resolver, domain, &mut records).await,
+        "SSHFP" => lookup_sshfp(&resolver, domain, &mut records).await,
+        "TLSA" => lookup_tlsa(&resolver, domain, &mut records).await,
+        "NAPTR" => lookup_naptr(&resolver, domain, &mut records).await,
+        "HTTPS" | "SVCB" => {
+            lookup_service_binding(&resolver, domain, &record_type_upper, &mut records).await;
+        }
         "ALL" => {
-            let types = vec![
-                "A", "AAAA", "CNAME", "MX", "TXT", "NS", "SOA", "SRV", "CAA", "PTR",
-            ];
             let ns = nameserver.map(String::from);
-            let futures: Vec<_> = types
-                .into_iter()
-                .map(|t| {
+            let futures: Vec<_> = SUPPORTED_RECORD_TYPES
+                .iter()
+                .map(|&t| {
                     let ns = ns.clone();
                     let domain = domain.to_string();
                     async move { dns_lookup(&domain, t, ns.as_deref()).await }
@@ -321,3 +331,108 @@ async fn lookup_ptr(resolver: &TokioResolver, domain: &str, records: &mut Vec<Dn
         }
     }
 }
+
+async fn lookup_sshfp(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
+    if let Ok(response) = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::SSHFP)
+        .await
+    {
+        for record in response.record_iter() {
+            if let Some(sshfp) = record.data().as_sshfp() {
+                // "<algorithm> <fingerprint type> <fingerprint hex>"
+                records.push(DnsLookupRecord {
+                    record_type: "SSHFP".to_string(),
+                    name: domain.to_string(),
+                    value: sshfp.to_string(),
+                    ttl: record.ttl(),
+                    priority: None,
+                });
+            }
+        }
+    }
+}
+
+async fn lookup_tlsa(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
+    if let Ok(response) = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::TLSA)
+        .await
+    {
+        for record in response.record_iter() {
+            if let Some(tlsa) = record.data().as_tlsa() {
+                // "<usage> <selector> <matching type> <data hex>"
+                records.push(DnsLookupRecord {
+                    record_type: "TLSA".to_string(),
+                    name: domain.to_string(),
+                    value: tlsa.to_string(),
+                    ttl: record.ttl(),
+                    priority: None,
+                });
+            }
+        }
+    }
+}
+
+async fn lookup_naptr(resolver: &TokioResolver, domain: &str, records: &mut Vec<DnsLookupRecord>) {
+    if let Ok(response) = resolver
+        .lookup(domain, hickory_resolver::proto::rr::RecordType::NAPTR)
+        .await
+    {
+        for record in response.record_iter() {
+            if let Some(naptr) = record.data().as_naptr() {
+                // 替换域为根 "." 时表示无替换，保留原样
+                let replacement = naptr.replacement().to_string();
+                let replacement = if replacement == "." {
+                    replacement.as_str()
+                } else {
+                    replacement.trim_end_matches('.')
+                };
+                let value = format!(
+                    "{} {} \"{}\" \"{}\" \"{}\" {}",
+                    naptr.order(),
+                    naptr.preference(),
+                    String::from_utf8_lossy(naptr.flags()),
+                    String::from_utf8_lossy(naptr.services()),
+                    String::from_utf8_lossy(naptr.regexp()),
+                    replacement
+                );
+                records.push(DnsLookupRecord {
+                    record_type: "NAPTR".to_string(),
+                    name: domain.to_string(),
+                    value,
+                    ttl: record.ttl(),
+                    priority: None,
+                });
+            }
+        }
+    }
+}
+
+async fn lookup_service_binding(
+    resolver: &TokioResolver,
+    domain: &str,
+    record_type: &str,
+    records: &mut Vec<DnsLookupRecord>,
+) {
+    let query_type = if record_type == "HTTPS" {
+        hickory_resolver::proto::rr::RecordType::HTTPS
+    } else {
+        hickory_resolver::proto::rr::RecordType::SVCB
+    };
+    if let Ok(response) = resolver.lookup(domain, query_type).await {
+        for record in response.record_iter() {
+            // "<priority> <target> <params>"
+            let value = match record.data() {
+                RData::HTTPS(https) => https.0.to_string(),
+                RData::SVCB(svcb) => svcb.to_string(),
+                _ => continue,
+            };
+            records.push(DnsLookupRecord {
+                record_type: record_type.to_string(),
+                name: domain.to_string(),
+                value: value.trim_end().to_string(),
+                ttl: record.ttl(),
+                priority: None,
+            });
+        }
+    }
+}
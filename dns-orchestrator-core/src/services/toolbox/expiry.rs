@@ -0,0 +1,311 @@
+//! 到期监控模块（域名 / SSL 证书）
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use futures::stream::{self, StreamExt};
+
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+use crate::types::SslExpiryInfo;
+use crate::types::{DomainExpiryInfo, WhoisResult};
+
+use super::whois;
+
+/// WHOIS 查询最大并发数
+const MAX_CONCURRENCY: usize = 5;
+
+/// WHOIS 结果缓存有效期（1 小时）
+const CACHE_TTL: Duration = Duration::from_hours(1);
+
+/// WHOIS 结果缓存（域名 -> (查询时间, 结果)）
+static WHOIS_CACHE: OnceLock<Mutex<HashMap<String, (Instant, WhoisResult)>>> = OnceLock::new();
+
+/// SSL 检查单个目标的超时时间
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+const SSL_CHECK_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// WHOIS 中常见的日期时间格式
+const DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y.%m.%d %H:%M:%S",
+    "%Y/%m/%d %H:%M:%S",
+    "%d-%b-%Y %H:%M:%S",
+];
+
+/// WHOIS 中常见的日期格式
+const DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d", "%Y.%m.%d", "%Y/%m/%d", "%d-%b-%Y", "%d.%m.%Y", "%Y%m%d",
+];
+
+/// 批量检查域名到期时间
+///
+/// 结果按剩余天数升序排列，无法获取到期时间的域名排在最后。
+pub async fn check_domain_expiry(
+    domains: &[String],
+    warning_days: i64,
+    whois_servers: &str,
+) -> Vec<DomainExpiryInfo> {
+    let mut results: Vec<DomainExpiryInfo> = stream::iter(domains.iter().cloned())
+        .map(|domain| async move { check_one(domain, warning_days, whois_servers).await })
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await;
+
+    results.sort_by(|a, b| match (a.days_remaining, b.days_remaining) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.domain.cmp(&b.domain),
+    });
+
+    results
+}
+
+/// 检查单个域名
+async fn check_one(domain: String, warning_days: i64, whois_servers: &str) -> DomainExpiryInfo {
+    let whois = match cached_whois_lookup(&domain, whois_servers).await {
+        Ok(w) => w,
+        Err(e) => {
+            return DomainExpiryInfo {
+                domain,
+                expiration_date: None,
+                days_remaining: None,
+                is_expiring: false,
+                is_expired: false,
+                error: Some(e.to_string()),
+            };
+        }
+    };
+
+    let Some(raw_date) = whois.expiration_date else {
+        return DomainExpiryInfo {
+            domain,
+            expiration_date: None,
+            days_remaining: None,
+            is_expiring: false,
+            is_expired: false,
+            error: Some("WHOIS 响应中未包含到期时间".to_string()),
+        };
+    };
+
+    match parse_whois_date(&raw_date) {
+        Some(expires_at) => {
+            let days_remaining = (expires_at - Utc::now()).num_days();
+            DomainExpiryInfo {
+                domain,
+                expiration_date: Some(expires_at.to_rfc3339()),
+                days_remaining: Some(days_remaining),
+                is_expiring: days_remaining <= warning_days,
+                is_expired: days_remaining < 0,
+                error: None,
+            }
+        }
+        None => DomainExpiryInfo {
+            domain,
+            expiration_date: Some(raw_date.clone()),
+            days_remaining: None,
+            is_expiring: false,
+            is_expired: false,
+            error: Some(format!("无法解析到期时间: {raw_date}")),
+        },
+    }
+}
+
+/// 带缓存的 WHOIS 查询
+async fn cached_whois_lookup(
+    domain: &str,
+    whois_servers: &str,
+) -> crate::error::CoreResult<WhoisResult> {
+    let key = domain.to_lowercase();
+    let cache = WHOIS_CACHE.get_or_init(|| Mutex::new(HashMap::new()));
+
+    if let Ok(guard) = cache.lock() {
+        if let Some((fetched_at, result)) = guard.get(&key) {
+            if fetched_at.elapsed() < CACHE_TTL {
+                return Ok(result.clone());
+            }
+        }
+    }
+
+    let result = whois::whois_lookup(domain, whois_servers).await?;
+
+    if let Ok(mut guard) = cache.lock() {
+        guard.retain(|_, (fetched_at, _)| fetched_at.elapsed() < CACHE_TTL);
+        guard.insert(key, (Instant::now(), result.clone()));
+    }
+
+    Ok(result)
+}
+
+/// 批量检查 SSL 证书到期时间
+///
+/// 连接失败的目标单独标记（`connection_failed`），并排在所有证书结果之后。
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub async fn check_ssl_expiry(targets: &[String], warning_days: i64) -> Vec<SslExpiryInfo> {
+    let mut results: Vec<SslExpiryInfo> = stream::iter(targets.iter().cloned())
+        .map(|target| async move { check_ssl_one(target, warning_days).await })
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await;
+
+    results.sort_by(|a, b| match (a.days_remaining, b.days_remaining) {
+        (Some(x), Some(y)) => x.cmp(&y),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.target.cmp(&b.target),
+    });
+
+    results
+}
+
+/// 检查单个 SSL 目标
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+async fn check_ssl_one(target: String, warning_days: i64) -> SslExpiryInfo {
+    let (host, port) = parse_host_port(&target);
+    let mut info = SslExpiryInfo {
+        target: target.clone(),
+        host: host.clone(),
+        port: port.unwrap_or(443),
+        issuer: None,
+        valid_to: None,
+        days_remaining: None,
+        is_expiring: false,
+        is_expired: false,
+        connection_failed: false,
+        error: None,
+    };
+
+    let result = tokio::time::timeout(SSL_CHECK_TIMEOUT, super::ssl::ssl_check(&host, port)).await;
+    let check = match result {
+        Ok(Ok(check)) => check,
+        Ok(Err(e)) => {
+            info.connection_failed = true;
+            info.error = Some(e.to_string());
+            return info;
+        }
+        Err(_) => {
+            info.connection_failed = true;
+            info.error = Some(format!("检查超时（{}秒）", SSL_CHECK_TIMEOUT.as_secs()));
+            return info;
+        }
+    };
+
+    if let Some(cert) = check.cert_info {
+        info.issuer = Some(cert.issuer);
+        info.valid_to = Some(cert.valid_to);
+        info.days_remaining = Some(cert.days_remaining);
+        info.is_expiring = cert.days_remaining <= warning_days;
+        info.is_expired = cert.is_expired;
+    } else {
+        info.connection_failed = true;
+        info.error = Some(
+            check
+                .error
+                .unwrap_or_else(|| format!("未获取到证书（{}）", check.connection_status)),
+        );
+    }
+
+    info
+}
+
+/// 解析 `host:port` 形式的目标（支持 `[IPv6]:port`）
+#[cfg_attr(not(any(feature = "native-tls", feature = "rustls")), allow(dead_code))]
+fn parse_host_port(target: &str) -> (String, Option<u16>) {
+    let target = target.trim();
+
+    if let Some(rest) = target.strip_prefix('[') {
+        if let Some((host, tail)) = rest.split_once(']') {
+            let port = tail.strip_prefix(':').and_then(|p| p.parse().ok());
+            return (host.to_string(), port);
+        }
+    }
+
+    match target.rsplit_once(':') {
+        // 仅包含一个冒号时才视为端口分隔符，避免误拆裸 IPv6 地址
+        Some((host, port)) if !host.contains(':') => match port.parse() {
+            Ok(port) => (host.to_string(), Some(port)),
+            Err(_) => (target.to_string(), None),
+        },
+        _ => (target.to_string(), None),
+    }
+}
+
+/// 解析 WHOIS 中常见的日期格式
+pub(super) fn parse_whois_date(raw: &str) -> Option<DateTime<Utc>> {
+    let s = raw.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    // 去掉尾部时区说明，如 "2025-01-01 00:00:00 (UTC+8)" / "2025-01-01T00:00:00 UTC"
+    let s = s
+        .split(" (")
+        .next()
+        .unwrap_or(s)
+        .trim_end_matches(" UTC")
+        .trim_end_matches('Z')
+        .trim();
+
+    for fmt in DATETIME_FORMATS {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Some(dt.and_utc());
+        }
+    }
+
+    for fmt in DATE_FORMATS {
+        if let Ok(date) = NaiveDate::parse_from_str(s, fmt) {
+            return date.and_hms_opt(0, 0, 0).map(|dt| dt.and_utc());
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_whois_date_formats() {
+        let expected = NaiveDate::from_ymd_opt(2026, 8, 13).unwrap();
+        for raw in [
+            "2026-08-13T04:00:00Z",
+            "2026-08-13T04:00:00.000Z",
+            "2026-08-13T04:00:00+00:00",
+            "2026-08-13 12:48:36",
+            "2026-08-13",
+            "2026.08.13",
+            "13-aug-2026",
+            "2026-08-13T04:00:00 UTC",
+        ] {
+            let parsed = parse_whois_date(raw).unwrap_or_else(|| panic!("failed: {raw}"));
+            assert_eq!(parsed.date_naive(), expected, "{raw}");
+        }
+        assert!(parse_whois_date("not a date").is_none());
+    }
+
+    #[test]
+    fn test_parse_host_port() {
+        assert_eq!(
+            parse_host_port("example.com:8443"),
+            ("example.com".to_string(), Some(8443))
+        );
+        assert_eq!(
+            parse_host_port("example.com"),
+            ("example.com".to_string(), None)
+        );
+        assert_eq!(
+            parse_host_port("[2001:db8::1]:443"),
+            ("2001:db8::1".to_string(), Some(443))
+        );
+        assert_eq!(
+            parse_host_port("2001:db8::1"),
+            ("2001:db8::1".to_string(), None)
+        );
+    }
+}
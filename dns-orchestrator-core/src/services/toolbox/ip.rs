@@ -1,5 +1,6 @@
 //! IP 地理位置查询模块
 
+use dns_orchestrator_provider::{build_http_client, HttpClientConfig};
 use hickory_resolver::{
     config::{ResolverConfig, ResolverOpts},
     name_server::TokioConnectionProvider,
@@ -101,11 +102,35 @@ async fn lookup_single_ip(ip: &str, client: &reqwest::Client) -> CoreResult<IpGe
         org: org.clone(),
         asn,
         as_name: org,
+        hostname: None,
     })
 }
 
+/// 反向解析 IP 的主机名（PTR），失败时返回 None
+async fn reverse_hostname(resolver: &TokioResolver, ip: &str) -> Option<String> {
+    let ip: std::net::IpAddr = ip.parse().ok()?;
+    let response = resolver.reverse_lookup(ip).await.ok()?;
+    response
+        .iter()
+        .next()
+        .map(|name| name.to_string().trim_end_matches('.').to_string())
+}
+
+/// 查询单个 IP 的地理位置，并同时做反向解析
+async fn lookup_ip_with_hostname(
+    ip: &str,
+    client: &reqwest::Client,
+    resolver: &TokioResolver,
+) -> CoreResult<IpGeoInfo> {
+    let (info, hostname) =
+        futures::join!(lookup_single_ip(ip, client), reverse_hostname(resolver, ip));
+    let mut info = info?;
+    info.hostname = hostname;
+    Ok(info)
+}
+
 /// IP/域名 地理位置查询
-pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
+pub async fn ip_lookup(query: &str, http: &HttpClientConfig) -> CoreResult<IpLookupResult> {
     let query = query.trim().to_string();
     if query.is_empty() {
         return Err(CoreError::ValidationError(
@@ -113,11 +138,15 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
         ));
     }
 
-    let client = reqwest::Client::new();
+    let client = build_http_client(http);
+    let provider = TokioConnectionProvider::default();
+    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
+        .with_options(ResolverOpts::default())
+        .build();
 
     // 检查是否为 IP 地址
     if query.parse::<std::net::IpAddr>().is_ok() {
-        let result = lookup_single_ip(&query, &client).await?;
+        let result = lookup_ip_with_hostname(&query, &client, &resolver).await?;
         return Ok(IpLookupResult {
             query,
             is_domain: false,
@@ -126,10 +155,6 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     }
 
     // 作为域名处理，解析 A 和 AAAA 记录
-    let provider = TokioConnectionProvider::default();
-    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
-        .with_options(ResolverOpts::default())
-        .build();
 
     let mut ips: Vec<String> = Vec::new();
 
@@ -154,7 +179,7 @@ pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
     // 查询每个 IP 的地理位置
     let mut results = Vec::new();
     for ip in ips {
-        match lookup_single_ip(&ip, &client).await {
+        match lookup_ip_with_hostname(&ip, &client, &resolver).await {
             Ok(info) => results.push(info),
             Err(e) => {
                 log::warn!("查询 IP {ip} 失败: {e}");
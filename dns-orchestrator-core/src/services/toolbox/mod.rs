@@ -2,24 +2,62 @@
 //!
 //! 提供各种 DNS 相关的工具函数，所有方法都是无状态的关联函数。
 
+mod blacklist;
 mod dns;
+mod expiry;
 mod ip;
+mod mx;
+mod overview;
+mod port;
+mod propagation;
 mod ssl;
 mod whois;
 
+use dns_orchestrator_provider::HttpClientConfig;
+
 use crate::error::CoreResult;
-use crate::types::{DnsLookupResult, IpLookupResult, WhoisResult};
+use crate::types::{
+    BlacklistCheckResult, DnsLookupResult, DnsPropagationResult, DomainExpiryInfo, DomainOverview,
+    IpLookupResult, MxCheckResult, PortCheckResult, WhoisResult,
+};
+use crate::utils::idn;
 
 /// 嵌入 WHOIS 服务器配置
 const WHOIS_SERVERS: &str = include_str!("whois_servers.json");
 
+/// 默认到期预警天数
+const DEFAULT_EXPIRY_WARNING_DAYS: u32 = 30;
+
 /// 工具箱服务（无状态，所有方法为关联函数）
 pub struct ToolboxService;
 
 impl ToolboxService {
     /// WHOIS 查询
     pub async fn whois_lookup(domain: &str) -> CoreResult<WhoisResult> {
-        whois::whois_lookup(domain, WHOIS_SERVERS).await
+        whois::whois_lookup(&idn::to_ascii(domain)?, WHOIS_SERVERS).await
+    }
+
+    /// 批量检查 SSL 证书到期时间
+    ///
+    /// `targets` 为 `host` 或 `host:port` 列表（默认端口 443）
+    #[cfg(any(feature = "native-tls", feature = "rustls"))]
+    pub async fn check_ssl_expiry(
+        targets: &[String],
+        warning_days: Option<u32>,
+    ) -> Vec<crate::types::SslExpiryInfo> {
+        let warning_days = i64::from(warning_days.unwrap_or(DEFAULT_EXPIRY_WARNING_DAYS));
+        expiry::check_ssl_expiry(targets, warning_days).await
+    }
+
+    /// 批量检查域名到期时间
+    ///
+    /// 按剩余天数升序返回，`warning_days` 内到期的域名会被标记为 `is_expiring`。
+    pub async fn check_domain_expiry(
+        domains: &[String],
+        warning_days: Option<u32>,
+    ) -> Vec<DomainExpiryInfo> {
+        let warning_days = i64::from(warning_days.unwrap_or(DEFAULT_EXPIRY_WARNING_DAYS));
+        expiry::check_domain_expiry(domains, warning_days, WHOIS_SERVERS).await
     }
 
     /// DNS 查询
@@ -28,12 +66,49 @@ impl ToolboxService {
         record_type: &str,
         nameserver: Option<&str>,
     ) -> CoreResult<DnsLookupResult> {
-        dns::dns_lookup(domain, record_type, nameserver).await
+        dns::dns_lookup(&idn::to_ascii(domain)?, record_type, nameserver).await
+    }
+
+    /// DNS 传播检查（并发查询多个公共 DNS 并比较结果）
+    pub async fn dns_propagation_check(
+        domain: &str,
+        record_type: &str,
+    ) -> CoreResult<DnsPropagationResult> {
+        propagation::dns_propagation_check(domain, record_type).await
+    }
+
+    /// 端口连通性检查（TCP 连接，最多 100 个端口）
+    pub async fn port_check(host: &str, ports: &[u16]) -> CoreResult<PortCheckResult> {
+        port::port_check(host, ports).await
+    }
+
+    /// IP/域名 地理位置查询（通过 `http` 中的代理配置访问外部接口）
+    pub async fn ip_lookup(query: &str, http: &HttpClientConfig) -> CoreResult<IpLookupResult> {
+        ip::ip_lookup(query, http).await
+    }
+
+    /// MX 健康检查（解析邮件主机地址、PTR 并检查 `FCrDNS`）
+    ///
+    /// `check_smtp` 为 true 时额外尝试连接各地址的 25 端口（不少网络会屏蔽出站 25 端口）
+    pub async fn mx_check(domain: &str, check_smtp: Option<bool>) -> CoreResult<MxCheckResult> {
+        mx::mx_check(domain, check_smtp.unwrap_or(false)).await
+    }
+
+    /// DNSBL 黑名单检查（IP 或域名，域名先解析 A 记录）
+    ///
+    /// `zones` 为空时使用内置的常用黑名单列表
+    pub async fn blacklist_check(
+        target: &str,
+        zones: Option<Vec<String>>,
+    ) -> CoreResult<BlacklistCheckResult> {
+        blacklist::blacklist_check(target, &zones.unwrap_or_default()).await
     }
 
-    /// IP/域名 地理位置查询
-    pub async fn ip_lookup(query: &str) -> CoreResult<IpLookupResult> {
-        ip::ip_lookup(query).await
+    /// 域名健康概览（WHOIS + NS 委派 + SOA + 常用记录 + SSL）
+    ///
+    /// 各子检查并发执行且互相独立，单项失败不影响其他结果。
+    pub async fn domain_overview(domain: &str) -> DomainOverview {
+        overview::domain_overview(domain, WHOIS_SERVERS).await
     }
 
     /// SSL 证书检查
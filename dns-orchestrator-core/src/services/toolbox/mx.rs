@@ -0,0 +1,178 @@
+//! MX 健康检查模块
+//!
+//! 解析域名的 MX 记录，对每个邮件主机解析 A/AAAA、反向解析 PTR，
+//! 并检查正反向解析是否一致（FCrDNS）。可选检查 25 端口连通性。
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use futures::future::join_all;
+use hickory_resolver::{
+    config::{ResolverConfig, ResolverOpts},
+    name_server::TokioConnectionProvider,
+    TokioResolver,
+};
+use tokio::net::TcpStream;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{MxAddressCheck, MxCheckResult, MxHostCheck};
+
+/// SMTP 端口
+const SMTP_PORT: u16 = 25;
+
+/// SMTP 连接超时
+const SMTP_CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// MX 健康检查
+pub async fn mx_check(domain: &str, check_smtp: bool) -> CoreResult<MxCheckResult> {
+    let domain = domain.trim().trim_end_matches('.');
+    if domain.is_empty() {
+        return Err(CoreError::ValidationError("域名不能为空".to_string()));
+    }
+
+    let provider = TokioConnectionProvider::default();
+    let resolver = TokioResolver::builder_with_config(ResolverConfig::default(), provider)
+        .with_options(ResolverOpts::default())
+        .build();
+
+    let response = resolver
+        .mx_lookup(domain)
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("查询 MX 记录失败: {e}")))?;
+
+    let mut mx_records: Vec<(u16, String)> = response
+        .iter()
+        .map(|mx| {
+            (
+                mx.preference(),
+                mx.exchange().to_string().trim_end_matches('.').to_string(),
+            )
+        })
+        .collect();
+    mx_records.sort();
+    mx_records.dedup();
+
+    let hosts = join_all(
+        mx_records
+            .into_iter()
+            .map(|(preference, exchange)| check_host(&resolver, preference, exchange, check_smtp)),
+    )
+    .await;
+
+    Ok(MxCheckResult {
+        domain: domain.to_string(),
+        hosts,
+    })
+}
+
+/// 检查单个邮件主机
+async fn check_host(
+    resolver: &TokioResolver,
+    preference: u16,
+    exchange: String,
+    check_smtp: bool,
+) -> MxHostCheck {
+    // Null MX（RFC 7505）：域名声明不接收邮件
+    if exchange.is_empty() {
+        return MxHostCheck {
+            exchange: ".".to_string(),
+            preference,
+            addresses: Vec::new(),
+            fcrdns_ok: false,
+            error: Some("Null MX：该域名不接收邮件".to_string()),
+        };
+    }
+
+    let ips = resolve_ips(resolver, &exchange).await;
+    if ips.is_empty() {
+        return MxHostCheck {
+            exchange,
+            preference,
+            addresses: Vec::new(),
+            fcrdns_ok: false,
+            error: Some("邮件主机没有 A/AAAA 记录".to_string()),
+        };
+    }
+
+    let addresses = join_all(
+        ips.into_iter()
+            .map(|ip| check_address(resolver, ip, check_smtp)),
+    )
+    .await;
+    let fcrdns_ok = addresses.iter().all(|a| a.fcrdns_ok);
+
+    MxHostCheck {
+        exchange,
+        preference,
+        addresses,
+        fcrdns_ok,
+        error: None,
+    }
+}
+
+/// 检查单个 IP：反向解析 PTR，再正向解析 PTR 主机名确认包含该 IP
+async fn check_address(resolver: &TokioResolver, ip: IpAddr, check_smtp: bool) -> MxAddressCheck {
+    let ptr: Vec<String> = match resolver.reverse_lookup(ip).await {
+        Ok(response) => response
+            .iter()
+            .map(|name| name.to_string().trim_end_matches('.').to_string())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    let mut fcrdns_ok = false;
+    for name in &ptr {
+        if resolve_ips(resolver, name).await.contains(&ip) {
+            fcrdns_ok = true;
+            break;
+        }
+    }
+
+    let smtp_reachable = if check_smtp {
+        Some(smtp_reachable(ip).await)
+    } else {
+        None
+    };
+
+    MxAddressCheck {
+        ip: ip.to_string(),
+        ptr,
+        fcrdns_ok,
+        smtp_reachable,
+    }
+}
+
+/// 解析主机的全部 A/AAAA 地址
+async fn resolve_ips(resolver: &TokioResolver, host: &str) -> Vec<IpAddr> {
+    let mut ips = Vec::new();
+    if let Ok(response) = resolver.ipv4_lookup(host).await {
+        ips.extend(response.iter().map(|a| IpAddr::V4(a.0)));
+    }
+    if let Ok(response) = resolver.ipv6_lookup(host).await {
+        ips.extend(response.iter().map(|aaaa| IpAddr::V6(aaaa.0)));
+    }
+    ips
+}
+
+/// 尝试连接 25 端口
+async fn smtp_reachable(ip: IpAddr) -> bool {
+    matches!(
+        tokio::time::timeout(
+            SMTP_CONNECT_TIMEOUT,
+            TcpStream::connect(SocketAddr::new(ip, SMTP_PORT))
+        )
+        .await,
+        Ok(Ok(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_mx_check_validation() {
+        assert!(mx_check("", false).await.is_err());
+        assert!(mx_check(" . ", false).await.is_err());
+    }
+}
@@ -0,0 +1,198 @@
+//! 域名健康概览模块
+//!
+//! 并发执行 WHOIS、NS 委派、SOA、常用记录和 SSL 检查，各子检查互不影响。
+
+use chrono::Utc;
+use futures::future::join_all;
+
+use crate::error::CoreResult;
+use crate::types::{
+    DelegationCheck, DnsLookupRecord, DnsLookupResult, DomainOverview, OverviewSection, WhoisResult,
+};
+
+use super::{dns, expiry, ssl, whois};
+
+/// 概览中查询的常用记录类型
+const COMMON_RECORD_TYPES: &[&str] = &["A", "AAAA", "MX", "TXT"];
+
+/// 获取域名健康概览
+pub async fn domain_overview(domain: &str, whois_servers: &str) -> DomainOverview {
+    let (whois_result, ns_result, soa_result, record_results, ssl_result) = futures::join!(
+        whois::whois_lookup(domain, whois_servers),
+        dns::dns_lookup(domain, "NS", None),
+        dns::dns_lookup(domain, "SOA", None),
+        join_all(
+            COMMON_RECORD_TYPES
+                .iter()
+                .map(|t| dns::dns_lookup(domain, t, None))
+        ),
+        ssl::ssl_check(domain, None),
+    );
+
+    let days_until_expiry = whois_result
+        .as_ref()
+        .ok()
+        .and_then(|w| w.expiration_date.as_deref())
+        .and_then(expiry::parse_whois_date)
+        .map(|expires_at| (expires_at - Utc::now()).num_days());
+
+    let delegation = match &ns_result {
+        Ok(ns) => OverviewSection::ok(build_delegation_check(
+            &ns.records,
+            whois_result.as_ref().ok(),
+        )),
+        Err(e) => OverviewSection::err(e.to_string()),
+    };
+
+    DomainOverview {
+        domain: domain.to_string(),
+        whois: whois_result.into(),
+        days_until_expiry,
+        delegation,
+        soa: soa_result.map(|r| r.records).into(),
+        records: merge_record_results(record_results),
+        ssl: ssl_result.into(),
+    }
+}
+
+/// 合并各类型记录的查询结果：部分类型失败时仍返回已查到的记录，全部失败才返回错误
+fn merge_record_results(
+    results: Vec<CoreResult<DnsLookupResult>>,
+) -> OverviewSection<Vec<DnsLookupRecord>> {
+    let mut records = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(r) => records.extend(r.records),
+            Err(e) => errors.push(e.to_string()),
+        }
+    }
+    if records.is_empty() && !errors.is_empty() {
+        OverviewSection::err(errors.join("; "))
+    } else {
+        OverviewSection::ok(records)
+    }
+}
+
+/// 对比 DNS 实际返回的 NS 与 WHOIS 登记的 NS
+fn build_delegation_check(
+    ns_records: &[DnsLookupRecord],
+    whois: Option<&WhoisResult>,
+) -> DelegationCheck {
+    let normalize = |s: &str| s.trim().trim_end_matches('.').to_lowercase();
+
+    let mut dns_name_servers: Vec<String> = ns_records
+        .iter()
+        .filter(|r| r.record_type == "NS")
+        .map(|r| normalize(&r.value))
+        .collect();
+    dns_name_servers.sort();
+    dns_name_servers.dedup();
+
+    let mut whois_name_servers: Vec<String> = whois
+        .map(|w| w.name_servers.iter().map(|s| normalize(s)).collect())
+        .unwrap_or_default();
+    whois_name_servers.sort();
+    whois_name_servers.dedup();
+
+    // WHOIS 未返回 NS 时无法判断，视为未知
+    let consistent = if whois_name_servers.is_empty() || dns_name_servers.is_empty() {
+        None
+    } else {
+        Some(dns_name_servers == whois_name_servers)
+    };
+
+    DelegationCheck {
+        dns_name_servers,
+        whois_name_servers,
+        consistent,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::CoreError;
+
+    fn ns(value: &str) -> DnsLookupRecord {
+        DnsLookupRecord {
+            record_type: "NS".to_string(),
+            name: "example.com".to_string(),
+            value: value.to_string(),
+            ttl: 3600,
+            priority: None,
+        }
+    }
+
+    fn whois(name_servers: &[&str]) -> WhoisResult {
+        WhoisResult {
+            domain: "example.com".to_string(),
+            registrar: None,
+            creation_date: None,
+            expiration_date: None,
+            updated_date: None,
+            name_servers: name_servers.iter().map(ToString::to_string).collect(),
+            status: Vec::new(),
+            raw: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_delegation_check_normalizes_name_servers() {
+        let records = [
+            ns("NS2.Example.net."),
+            ns("ns1.example.net."),
+            ns("ns1.example.net"),
+        ];
+        let check = build_delegation_check(
+            &records,
+            Some(&whois(&["ns1.example.net", "NS2.EXAMPLE.NET"])),
+        );
+        assert_eq!(
+            check.dns_name_servers,
+            ["ns1.example.net", "ns2.example.net"]
+        );
+        assert_eq!(check.consistent, Some(true));
+
+        let check = build_delegation_check(&records, Some(&whois(&["ns1.other.net"])));
+        assert_eq!(check.consistent, Some(false));
+    }
+
+    #[test]
+    fn test_delegation_check_unknown_without_whois_name_servers() {
+        let records = [ns("ns1.example.net.")];
+        assert_eq!(build_delegation_check(&records, None).consistent, None);
+        assert_eq!(
+            build_delegation_check(&records, Some(&whois(&[]))).consistent,
+            None
+        );
+        assert_eq!(
+            build_delegation_check(&[], Some(&whois(&["ns1.example.net"]))).consistent,
+            None
+        );
+    }
+
+    #[test]
+    fn test_merge_record_results() {
+        let found = || {
+            Ok(DnsLookupResult {
+                nameserver: "1.1.1.1".to_string(),
+                records: vec![ns("ns1.example.net.")],
+            })
+        };
+        let failed = || Err(CoreError::NetworkError("timeout".to_string()));
+
+        // 部分类型失败时保留已查到的记录
+        let section = merge_record_results(vec![found(), failed()]);
+        assert_eq!(section.data.map(|r| r.len()), Some(1));
+        assert!(section.error.is_none());
+
+        let section = merge_record_results(vec![failed(), failed()]);
+        assert!(section.data.is_none());
+        assert!(section.error.is_some());
+
+        // 全部成功但没有记录不是错误
+        let section = merge_record_results(Vec::new());
+        assert_eq!(section.data.map(|r| r.len()), Some(0));
+    }
+}
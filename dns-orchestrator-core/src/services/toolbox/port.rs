@@ -0,0 +1,128 @@
+//! 端口连通性检查模块
+//!
+//! 先解析主机地址，再并发尝试 TCP 连接各端口（限制并发数和端口数量）。
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use futures::stream::{self, StreamExt};
+use tokio::net::TcpStream;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{PortCheckResult, PortStatus};
+
+/// 单次检查允许的最大端口数
+const MAX_PORTS: usize = 100;
+
+/// 同时进行的连接数上限
+const MAX_CONCURRENCY: usize = 20;
+
+/// 单个端口的连接超时
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// 端口连通性检查
+pub async fn port_check(host: &str, ports: &[u16]) -> CoreResult<PortCheckResult> {
+    let host = host.trim();
+    if host.is_empty() {
+        return Err(CoreError::ValidationError("主机不能为空".to_string()));
+    }
+
+    let mut ports: Vec<u16> = ports.iter().copied().filter(|&p| p != 0).collect();
+    ports.sort_unstable();
+    ports.dedup();
+    if ports.is_empty() {
+        return Err(CoreError::ValidationError("请至少提供一个端口".to_string()));
+    }
+    if ports.len() > MAX_PORTS {
+        return Err(CoreError::ValidationError(format!(
+            "单次最多检查 {MAX_PORTS} 个端口"
+        )));
+    }
+
+    let ip = resolve_host(host).await?;
+
+    let mut results: Vec<PortStatus> = stream::iter(ports)
+        .map(|port| check_port(SocketAddr::new(ip, port)))
+        .buffer_unordered(MAX_CONCURRENCY)
+        .collect()
+        .await;
+    results.sort_by_key(|r| r.port);
+
+    Ok(PortCheckResult {
+        host: host.to_string(),
+        ip: ip.to_string(),
+        results,
+    })
+}
+
+/// 解析主机地址（IP 直接使用，域名取第一个解析结果）
+async fn resolve_host(host: &str) -> CoreResult<IpAddr> {
+    if let Ok(ip) = host.trim_matches(['[', ']']).parse::<IpAddr>() {
+        return Ok(ip);
+    }
+
+    tokio::net::lookup_host((host, 0))
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("解析主机失败: {e}")))?
+        .next()
+        .map(|addr| addr.ip())
+        .ok_or_else(|| CoreError::NetworkError(format!("无法解析主机: {host}")))
+}
+
+/// 尝试连接单个端口
+async fn check_port(addr: SocketAddr) -> PortStatus {
+    let started = Instant::now();
+    let (open, error) = match tokio::time::timeout(CONNECT_TIMEOUT, TcpStream::connect(addr)).await
+    {
+        Ok(Ok(_)) => (true, None),
+        Ok(Err(e)) => (false, Some(e.to_string())),
+        Err(_) => (false, Some("连接超时".to_string())),
+    };
+
+    PortStatus {
+        port: addr.port(),
+        open,
+        latency_ms: open.then(|| u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX)),
+        error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_port_check_open_and_closed() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open_port = listener.local_addr().unwrap().port();
+        let closed_port = {
+            let l = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            l.local_addr().unwrap().port()
+        };
+
+        let result = port_check("127.0.0.1", &[closed_port, open_port, open_port])
+            .await
+            .unwrap();
+
+        assert_eq!(result.ip, "127.0.0.1");
+        assert_eq!(result.results.len(), 2);
+        let open = result.results.iter().find(|r| r.port == open_port).unwrap();
+        assert!(open.open);
+        assert!(open.latency_ms.is_some());
+        let closed = result
+            .results
+            .iter()
+            .find(|r| r.port == closed_port)
+            .unwrap();
+        assert!(!closed.open);
+        assert!(closed.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_port_check_validation() {
+        assert!(port_check("127.0.0.1", &[]).await.is_err());
+        assert!(port_check("", &[80]).await.is_err());
+        let too_many: Vec<u16> = (1..=u16::try_from(MAX_PORTS + 1).unwrap()).collect();
+        assert!(port_check("127.0.0.1", &too_many).await.is_err());
+    }
+}
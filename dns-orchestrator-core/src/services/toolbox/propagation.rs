@@ -0,0 +1,169 @@
+//! DNS 传播检查模块
+//!
+//! 并发向多个公共 DNS 查询同一记录，比较各自返回的结果是否一致。
+
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+
+use crate::error::{CoreError, CoreResult};
+use crate::types::{DnsLookupRecord, DnsPropagationResult, PropagationServerResult};
+
+use super::dns;
+
+/// 单个 DNS 服务器的查询超时
+const RESOLVER_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 参与传播检查的公共 DNS（名称, 地址, 地区）
+const PUBLIC_RESOLVERS: &[(&str, &str, &str)] = &[
+    ("Google", "8.8.8.8", "Global"),
+    ("Cloudflare", "1.1.1.1", "Global"),
+    ("Quad9", "9.9.9.9", "Global"),
+    ("OpenDNS", "208.67.222.222", "Global"),
+    ("AliDNS", "223.5.5.5", "CN"),
+    ("DNSPod", "119.29.29.29", "CN"),
+    ("Yandex", "77.88.8.8", "RU"),
+];
+
+/// DNS 传播检查
+pub async fn dns_propagation_check(
+    domain: &str,
+    record_type: &str,
+) -> CoreResult<DnsPropagationResult> {
+    let record_type = record_type.to_uppercase();
+    if !dns::SUPPORTED_RECORD_TYPES.contains(&record_type.as_str()) {
+        return Err(CoreError::ValidationError(format!(
+            "不支持的记录类型: {record_type}"
+        )));
+    }
+
+    let results = join_all(
+        PUBLIC_RESOLVERS
+            .iter()
+            .map(|(name, ip, region)| query_resolver(domain, &record_type, name, ip, region)),
+    )
+    .await;
+
+    Ok(DnsPropagationResult {
+        domain: domain.to_string(),
+        record_type,
+        consistent: answers_consistent(&results),
+        results,
+    })
+}
+
+/// 向单个 DNS 服务器查询，超时或失败时在结果中记录错误
+async fn query_resolver(
+    domain: &str,
+    record_type: &str,
+    name: &str,
+    ip: &str,
+    region: &str,
+) -> PropagationServerResult {
+    let started = Instant::now();
+    let outcome = tokio::time::timeout(
+        RESOLVER_TIMEOUT,
+        dns::dns_lookup(domain, record_type, Some(ip)),
+    )
+    .await;
+    let response_time_ms = u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+    let (records, error) = match outcome {
+        Ok(Ok(lookup)) => (lookup.records, None),
+        Ok(Err(e)) => (Vec::new(), Some(e.to_string())),
+        Err(_) => (
+            Vec::new(),
+            Some(format!("查询超时（{}秒）", RESOLVER_TIMEOUT.as_secs())),
+        ),
+    };
+
+    PropagationServerResult {
+        name: name.to_string(),
+        nameserver: ip.to_string(),
+        region: region.to_string(),
+        records,
+        response_time_ms,
+        error,
+    }
+}
+
+/// 所有服务器均成功响应且返回的记录集合相同时视为一致
+fn answers_consistent(results: &[PropagationServerResult]) -> bool {
+    let mut answers = results
+        .iter()
+        .map(|r| r.error.is_none().then(|| normalized_answer(&r.records)));
+    let Some(Some(first)) = answers.next() else {
+        return false;
+    };
+    answers.all(|answer| answer.as_ref() == Some(&first))
+}
+
+/// 忽略顺序、大小写和 TTL 的记录集合
+fn normalized_answer(records: &[DnsLookupRecord]) -> Vec<String> {
+    let mut values: Vec<String> = records
+        .iter()
+        .map(|r| {
+            let value = r.value.to_lowercase();
+            match r.priority {
+                Some(priority) => format!("{} {priority} {value}", r.record_type),
+                None => format!("{} {value}", r.record_type),
+            }
+        })
+        .collect();
+    values.sort();
+    values.dedup();
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn server(values: &[&str], error: Option<&str>) -> PropagationServerResult {
+        PropagationServerResult {
+            name: "test".to_string(),
+            nameserver: "127.0.0.1".to_string(),
+            region: "Global".to_string(),
+            records: values
+                .iter()
+                .map(|v| DnsLookupRecord {
+                    record_type: "A".to_string(),
+                    name: "example.com".to_string(),
+                    value: (*v).to_string(),
+                    ttl: 300,
+                    priority: None,
+                })
+                .collect(),
+            response_time_ms: 10,
+            error: error.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_answers_consistent_ignores_order() {
+        let results = [
+            server(&["1.1.1.1", "2.2.2.2"], None),
+            server(&["2.2.2.2", "1.1.1.1"], None),
+        ];
+        assert!(answers_consistent(&results));
+    }
+
+    #[test]
+    fn test_answers_inconsistent() {
+        assert!(!answers_consistent(&[
+            server(&["1.1.1.1"], None),
+            server(&["2.2.2.2"], None),
+        ]));
+        // 未传播（空结果）视为不一致
+        assert!(!answers_consistent(&[
+            server(&["1.1.1.1"], None),
+            server(&[], None),
+        ]));
+        // 任一服务器失败视为不一致
+        assert!(!answers_consistent(&[
+            server(&["1.1.1.1"], None),
+            server(&[], Some("查询超时")),
+        ]));
+        assert!(!answers_consistent(&[]));
+    }
+}
@@ -4,13 +4,18 @@
 //! - `native-tls`: 桌面端使用系统 TLS
 //! - `rustls`: Android/Web 使用纯 Rust TLS
 
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
 use std::io::{Read, Write};
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
 use std::net::TcpStream;
 
 use crate::error::{CoreError, CoreResult};
-use crate::types::{CertChainItem, SslCertInfo, SslCheckResult};
+use crate::types::SslCheckResult;
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+use crate::types::{CertChainItem, SslCertInfo};
 
 /// 检查 HTTP 连接是否可用
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
 fn check_http_connection(domain: &str, port: u16) -> bool {
     if let Ok(mut stream) = TcpStream::connect(format!("{domain}:{port}")) {
         stream
@@ -33,287 +38,260 @@ fn check_http_connection(domain: &str, port: u16) -> bool {
     false
 }
 
-/// SSL 证书检查（使用 native-tls）
-#[cfg(feature = "native-tls")]
-pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckResult> {
-    use native_tls_crate::TlsConnector;
-    use x509_parser::prelude::*;
-
-    let port = port.unwrap_or(443);
-    let domain = domain.to_string();
+/// TLS 握手失败原因
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+enum HandshakeFailure {
+    /// TCP 连接失败（或目标无效），无需再尝试
+    Connect(String),
+    /// TLS 握手失败（证书校验失败或非 TLS 服务）
+    Tls(String),
+}
 
-    tokio::task::spawn_blocking(move || {
-        // 尝试建立 TCP 连接
-        let stream = match TcpStream::connect(format!("{domain}:{port}")) {
-            Ok(s) => s,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("连接失败: {e}")),
-                });
-            }
-        };
-        stream
-            .set_read_timeout(Some(std::time::Duration::from_secs(10)))
-            .ok();
+/// 握手获得的对端证书
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+struct PeerCertificates {
+    /// DER 编码证书（叶子证书在前）
+    ders: Vec<Vec<u8>>,
+    /// 证书链是否通过信任根校验
+    chain_trusted: bool,
+    /// 证书链校验失败原因
+    verification_error: Option<String>,
+}
 
-        // 尝试建立 TLS 连接
-        let connector = match TlsConnector::builder()
-            .danger_accept_invalid_certs(true)
-            .build()
-        {
-            Ok(c) => c,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("TLS 初始化失败: {e}")),
-                });
-            }
-        };
+/// 建立 TCP 连接
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn connect_tcp(domain: &str, port: u16) -> Result<TcpStream, HandshakeFailure> {
+    let stream = TcpStream::connect(format!("{domain}:{port}"))
+        .map_err(|e| HandshakeFailure::Connect(format!("连接失败: {e}")))?;
+    stream
+        .set_read_timeout(Some(std::time::Duration::from_secs(10)))
+        .ok();
+    Ok(stream)
+}
 
-        let Ok(mut tls_stream) = connector.connect(&domain, stream) else {
-            if check_http_connection(&domain, port) {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "http".to_string(),
-                    cert_info: None,
-                    error: None,
-                });
-            }
-            return Ok(SslCheckResult {
-                domain,
-                port,
-                connection_status: "failed".to_string(),
-                cert_info: None,
-                error: Some("TLS 握手失败，且非 HTTP 连接".to_string()),
-            });
-        };
-
-        // 发送 HTTP 请求
-        let request = format!("HEAD / HTTP/1.1\r\nHost: {domain}\r\nConnection: close\r\n\r\n");
-        tls_stream.write_all(request.as_bytes()).ok();
-        let mut response = vec![0u8; 1024];
-        tls_stream.read(&mut response).ok();
+/// 发送 HEAD 请求（rustls 借此触发握手）
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn send_head_request(stream: &mut impl Write, domain: &str) -> std::io::Result<()> {
+    let request = format!("HEAD / HTTP/1.1\r\nHost: {domain}\r\nConnection: close\r\n\r\n");
+    stream.write_all(request.as_bytes())
+}
 
-        // 获取证书
-        let Ok(Some(cert_chain)) = tls_stream.peer_certificate() else {
-            return Ok(SslCheckResult {
-                domain,
-                port,
-                connection_status: "https".to_string(),
-                cert_info: None,
-                error: Some("未找到证书".to_string()),
-            });
-        };
-
-        let cert_der = match cert_chain.to_der() {
-            Ok(d) => d,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some(format!("证书编码失败: {e}")),
-                });
-            }
-        };
-
-        // 解析证书
-        let (_, cert) = match X509Certificate::from_der(&cert_der) {
-            Ok(c) => c,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some(format!("证书解析失败: {e}")),
-                });
-            }
-        };
+/// 获取对端证书
+///
+/// 先按信任根校验证书链（主机名单独检查）；校验失败时再以不校验的方式重连，
+/// 以便仍能查看过期、自签名等异常证书，并记录首次失败原因。
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn fetch_peer_certificates(domain: &str, port: u16) -> Result<PeerCertificates, HandshakeFailure> {
+    match handshake(domain, port, true) {
+        Ok(ders) => Ok(PeerCertificates {
+            ders,
+            chain_trusted: true,
+            verification_error: None,
+        }),
+        Err(HandshakeFailure::Tls(verify_error)) => {
+            handshake(domain, port, false).map(|ders| PeerCertificates {
+                ders,
+                chain_trusted: false,
+                verification_error: Some(verify_error),
+            })
+        }
+        Err(e) => Err(e),
+    }
+}
 
-        let cert_info = parse_certificate(&domain, port, &cert);
+/// TLS 握手并返回对端证书（使用 native-tls，只能获取叶子证书）
+#[cfg(feature = "native-tls")]
+fn handshake(domain: &str, port: u16, verify: bool) -> Result<Vec<Vec<u8>>, HandshakeFailure> {
+    use native_tls_crate::TlsConnector;
 
-        Ok(SslCheckResult {
-            domain: domain.clone(),
-            port,
-            connection_status: "https".to_string(),
-            cert_info: Some(cert_info),
-            error: None,
-        })
-    })
-    .await
-    .map_err(|e| CoreError::NetworkError(format!("任务执行失败: {e}")))?
+    let stream = connect_tcp(domain, port)?;
+    let connector = TlsConnector::builder()
+        .danger_accept_invalid_hostnames(true)
+        .danger_accept_invalid_certs(!verify)
+        .build()
+        .map_err(|e| HandshakeFailure::Tls(format!("TLS 初始化失败: {e}")))?;
+    let mut tls_stream = connector
+        .connect(domain, stream)
+        .map_err(|e| HandshakeFailure::Tls(e.to_string()))?;
+
+    send_head_request(&mut tls_stream, domain).ok();
+    let mut response = vec![0u8; 1024];
+    let _ = tls_stream.read(&mut response);
+
+    Ok(tls_stream
+        .peer_certificate()
+        .ok()
+        .flatten()
+        .and_then(|cert| cert.to_der().ok())
+        .into_iter()
+        .collect())
 }
 
-/// SSL 证书检查（使用 rustls）
+/// TLS 握手并返回对端证书链（使用 rustls）
 #[cfg(all(feature = "rustls", not(feature = "native-tls")))]
-pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckResult> {
+fn handshake(domain: &str, port: u16, verify: bool) -> Result<Vec<Vec<u8>>, HandshakeFailure> {
+    use rustls::pki_types::ServerName;
     use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
     use std::sync::Arc;
-    use x509_parser::prelude::*;
 
+    let server_name = ServerName::try_from(domain.to_string())
+        .map_err(|_| HandshakeFailure::Connect("无效的域名".to_string()))?;
+
+    let mut root_store = RootCertStore::empty();
+    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    let inner = rustls::client::WebPkiServerVerifier::builder(Arc::new(root_store))
+        .build()
+        .map_err(|e| HandshakeFailure::Tls(format!("TLS 初始化失败: {e}")))?;
+
+    let config = ClientConfig::builder()
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(InspectingVerifier {
+            inner,
+            accept_any: !verify,
+        }))
+        .with_no_client_auth();
+
+    let conn = ClientConnection::new(Arc::new(config), server_name)
+        .map_err(|e| HandshakeFailure::Tls(format!("TLS 初始化失败: {e}")))?;
+    let mut tls_stream = StreamOwned::new(conn, connect_tcp(domain, port)?);
+
+    send_head_request(&mut tls_stream, domain).map_err(|e| HandshakeFailure::Tls(e.to_string()))?;
+    let mut response = vec![0u8; 1024];
+    let _ = tls_stream.read(&mut response);
+
+    Ok(tls_stream
+        .conn
+        .peer_certificates()
+        .map(|certs| certs.iter().map(|c| c.as_ref().to_vec()).collect())
+        .unwrap_or_default())
+}
+
+/// 证书校验器：校验证书链但忽略主机名（主机名单独检查），`accept_any` 时接受任意证书
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+#[derive(Debug)]
+struct InspectingVerifier {
+    inner: std::sync::Arc<rustls::client::WebPkiServerVerifier>,
+    accept_any: bool,
+}
+
+#[cfg(all(feature = "rustls", not(feature = "native-tls")))]
+impl rustls::client::danger::ServerCertVerifier for InspectingVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &rustls::pki_types::CertificateDer<'_>,
+        intermediates: &[rustls::pki_types::CertificateDer<'_>],
+        server_name: &rustls::pki_types::ServerName<'_>,
+        ocsp_response: &[u8],
+        now: rustls::pki_types::UnixTime,
+    ) -> Result<rustls::client::danger::ServerCertVerified, rustls::Error> {
+        use rustls::{CertificateError, Error};
+
+        if self.accept_any {
+            return Ok(rustls::client::danger::ServerCertVerified::assertion());
+        }
+        match self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        ) {
+            Err(Error::InvalidCertificate(
+                CertificateError::NotValidForName | CertificateError::NotValidForNameContext { .. },
+            )) => Ok(rustls::client::danger::ServerCertVerified::assertion()),
+            result => result,
+        }
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &rustls::pki_types::CertificateDer<'_>,
+        dss: &rustls::DigitallySignedStruct,
+    ) -> Result<rustls::client::danger::HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<rustls::SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// SSL 证书检查
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+pub async fn ssl_check(domain: &str, port: Option<u16>) -> CoreResult<SslCheckResult> {
     let port = port.unwrap_or(443);
     let domain = domain.to_string();
 
-    tokio::task::spawn_blocking(move || {
-        // 尝试建立 TCP 连接
-        let stream = match TcpStream::connect(format!("{domain}:{port}")) {
-            Ok(s) => s,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("连接失败: {e}")),
-                });
-            }
-        };
-        stream
-            .set_read_timeout(Some(std::time::Duration::from_secs(10)))
-            .ok();
-
-        // 配置 rustls
-        let mut root_store = RootCertStore::empty();
-        root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
-
-        let config = ClientConfig::builder()
-            .with_root_certificates(root_store)
-            .with_no_client_auth();
-
-        let server_name = match domain.clone().try_into() {
-            Ok(n) => n,
-            Err(_) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some("无效的域名".to_string()),
-                });
-            }
-        };
-
-        let conn = match ClientConnection::new(Arc::new(config), server_name) {
-            Ok(c) => c,
-            Err(e) => {
-                if check_http_connection(&domain, port) {
-                    return Ok(SslCheckResult {
-                        domain,
-                        port,
-                        connection_status: "http".to_string(),
-                        cert_info: None,
-                        error: None,
-                    });
-                }
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "failed".to_string(),
-                    cert_info: None,
-                    error: Some(format!("TLS 初始化失败: {e}")),
-                });
-            }
-        };
+    tokio::task::spawn_blocking(move || check_blocking(domain, port))
+        .await
+        .map_err(|e| CoreError::NetworkError(format!("任务执行失败: {e}")))
+}
 
-        let mut tls_stream = StreamOwned::new(conn, stream);
+/// 阻塞执行 SSL 检查
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn check_blocking(domain: String, port: u16) -> SslCheckResult {
+    use x509_parser::prelude::*;
 
-        // 发送请求触发握手
-        let request = format!("HEAD / HTTP/1.1\r\nHost: {domain}\r\nConnection: close\r\n\r\n");
-        if tls_stream.write_all(request.as_bytes()).is_err() {
+    let result = |domain: String, status: &str, cert_info, error| SslCheckResult {
+        domain,
+        port,
+        connection_status: status.to_string(),
+        cert_info,
+        error,
+    };
+
+    let peer = match fetch_peer_certificates(&domain, port) {
+        Ok(peer) => peer,
+        Err(HandshakeFailure::Connect(e)) => return result(domain, "failed", None, Some(e)),
+        Err(HandshakeFailure::Tls(e)) => {
             if check_http_connection(&domain, port) {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "http".to_string(),
-                    cert_info: None,
-                    error: None,
-                });
+                return result(domain, "http", None, None);
             }
-            return Ok(SslCheckResult {
+            return result(
                 domain,
-                port,
-                connection_status: "failed".to_string(),
-                cert_info: None,
-                error: Some("TLS 握手失败".to_string()),
-            });
+                "failed",
+                None,
+                Some(format!("TLS 握手失败，且非 HTTP 连接: {e}")),
+            );
         }
-        let mut response = vec![0u8; 1024];
-        tls_stream.read(&mut response).ok();
-
-        // 获取证书
-        let certs = match tls_stream.conn.peer_certificates() {
-            Some(c) if !c.is_empty() => c,
-            _ => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some("未找到证书".to_string()),
-                });
-            }
-        };
-
-        let cert_der = certs[0].as_ref();
-
-        // 解析证书
-        let (_, cert) = match X509Certificate::from_der(cert_der) {
-            Ok(c) => c,
-            Err(e) => {
-                return Ok(SslCheckResult {
-                    domain,
-                    port,
-                    connection_status: "https".to_string(),
-                    cert_info: None,
-                    error: Some(format!("证书解析失败: {e}")),
-                });
-            }
-        };
-
-        let mut cert_info = parse_certificate(&domain, port, &cert);
-
-        // 解析完整证书链
-        cert_info.certificate_chain = certs
-            .iter()
-            .filter_map(|c| {
-                X509Certificate::from_der(c.as_ref())
-                    .ok()
-                    .map(|(_, parsed)| CertChainItem {
-                        subject: parsed.subject().to_string(),
-                        issuer: parsed.issuer().to_string(),
-                        is_ca: parsed.is_ca(),
-                    })
-            })
-            .collect();
-
-        Ok(SslCheckResult {
-            domain: domain.clone(),
-            port,
-            connection_status: "https".to_string(),
-            cert_info: Some(cert_info),
-            error: None,
-        })
-    })
-    .await
-    .map_err(|e| CoreError::NetworkError(format!("任务执行失败: {e}")))?
+    };
+
+    let Some(leaf_der) = peer.ders.first() else {
+        return result(domain, "https", None, Some("未找到证书".to_string()));
+    };
+
+    let (_, cert) = match X509Certificate::from_der(leaf_der) {
+        Ok(c) => c,
+        Err(e) => {
+            return result(domain, "https", None, Some(format!("证书解析失败: {e}")));
+        }
+    };
+
+    let mut cert_info = parse_certificate(&domain, &cert, peer.chain_trusted);
+    cert_info.verification_error = peer.verification_error;
+    if peer.ders.len() > 1 {
+        cert_info.certificate_chain = build_certificate_chain(peer.ders.iter().map(Vec::as_slice));
+    }
+
+    result(domain, "https", Some(cert_info), None)
 }
 
 /// 解析证书信息
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 fn parse_certificate(
     query: &str,
-    _port: u16,
     cert: &x509_parser::certificate::X509Certificate,
+    chain_trusted: bool,
 ) -> SslCertInfo {
     let subject = cert.subject().to_string();
     let issuer = cert.issuer().to_string();
@@ -323,12 +301,11 @@ fn parse_certificate(
     // 计算剩余天数
     let now = chrono::Utc::now();
     let not_after = chrono::DateTime::parse_from_rfc2822(&valid_to)
-        .map(|dt| dt.with_timezone(&chrono::Utc))
-        .unwrap_or(now);
+        .map_or(now, |dt| dt.with_timezone(&chrono::Utc));
     let days_remaining = (not_after - now).num_days();
     let is_expired = days_remaining < 0;
 
-    // 提取 SAN
+    // 提取 SAN（DNS 名称与 IP 地址）
     let san: Vec<String> = cert
         .subject_alternative_name()
         .ok()
@@ -337,10 +314,7 @@ fn parse_certificate(
             ext.value
                 .general_names
                 .iter()
-                .filter_map(|name| match name {
-                    x509_parser::extensions::GeneralName::DNSName(dns) => Some((*dns).to_string()),
-                    _ => None,
-                })
+                .filter_map(format_general_name)
                 .collect()
         })
         .unwrap_or_default();
@@ -361,19 +335,17 @@ fn parse_certificate(
         .unwrap_or_else(|| query.to_string());
 
     // 检查域名是否匹配（CN 或 SAN 中任意一个）
-    let domain_matches = check_domain_match(query, cn.as_deref(), &san);
+    let hostname_matches = check_domain_match(query, cn.as_deref(), &san);
 
-    // is_valid = 未过期 且 域名匹配
-    let is_valid = !is_expired && domain_matches;
+    // is_valid = 未过期 且 域名匹配 且 证书链可信
+    let is_valid = !is_expired && hostname_matches && chain_trusted;
 
     let serial_number = cert.serial.to_str_radix(16).to_uppercase();
-    let signature_algorithm = cert.signature_algorithm.algorithm.to_string();
+    let signature_algorithm =
+        signature_algorithm_name(&cert.signature_algorithm.algorithm.to_id_string());
 
-    let certificate_chain = vec![CertChainItem {
-        subject: subject.clone(),
-        issuer: issuer.clone(),
-        is_ca: cert.is_ca(),
-    }];
+    // 仅有叶子证书时链只包含自身（native-tls 无法获取中间证书）
+    let certificate_chain = vec![chain_item(cert)];
 
     SslCertInfo {
         domain: cert_domain,
@@ -384,6 +356,9 @@ fn parse_certificate(
         days_remaining,
         is_expired,
         is_valid,
+        hostname_matches,
+        chain_trusted,
+        verification_error: None,
         san,
         serial_number,
         signature_algorithm,
@@ -391,6 +366,69 @@ fn parse_certificate(
     }
 }
 
+/// 格式化 SAN 条目，只保留 DNS 名称和 IP 地址
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn format_general_name(name: &x509_parser::extensions::GeneralName) -> Option<String> {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use x509_parser::extensions::GeneralName;
+
+    match name {
+        GeneralName::DNSName(dns) => Some((*dns).to_string()),
+        GeneralName::IPAddress(bytes) => match bytes.len() {
+            4 => <[u8; 4]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(Ipv4Addr::from(b)).to_string()),
+            16 => <[u8; 16]>::try_from(*bytes)
+                .ok()
+                .map(|b| IpAddr::from(Ipv6Addr::from(b)).to_string()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// 将签名算法 OID 转换为可读名称，未知 OID 原样返回
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn signature_algorithm_name(oid: &str) -> String {
+    match oid {
+        "1.2.840.113549.1.1.5" => "sha1WithRSAEncryption",
+        "1.2.840.113549.1.1.11" => "sha256WithRSAEncryption",
+        "1.2.840.113549.1.1.12" => "sha384WithRSAEncryption",
+        "1.2.840.113549.1.1.13" => "sha512WithRSAEncryption",
+        "1.2.840.113549.1.1.10" => "RSASSA-PSS",
+        "1.2.840.10045.4.3.2" => "ecdsa-with-SHA256",
+        "1.2.840.10045.4.3.3" => "ecdsa-with-SHA384",
+        "1.2.840.10045.4.3.4" => "ecdsa-with-SHA512",
+        "1.3.101.112" => "Ed25519",
+        "1.3.101.113" => "Ed448",
+        other => other,
+    }
+    .to_string()
+}
+
+/// 证书链条目
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn chain_item(cert: &x509_parser::certificate::X509Certificate) -> CertChainItem {
+    CertChainItem {
+        subject: cert.subject().to_string(),
+        issuer: cert.issuer().to_string(),
+        is_ca: cert.is_ca(),
+    }
+}
+
+/// 从 DER 证书列表构建证书链，无法解析的证书会被跳过
+#[cfg(any(feature = "native-tls", feature = "rustls"))]
+fn build_certificate_chain<'a>(ders: impl Iterator<Item = &'a [u8]>) -> Vec<CertChainItem> {
+    use x509_parser::prelude::FromDer;
+
+    ders.filter_map(|der| {
+        x509_parser::certificate::X509Certificate::from_der(der)
+            .ok()
+            .map(|(_, cert)| chain_item(&cert))
+    })
+    .collect()
+}
+
 /// 检查查询的域名/IP 是否与证书的 CN 或 SAN 匹配
 #[cfg(any(feature = "native-tls", feature = "rustls"))]
 fn check_domain_match(query: &str, cn: Option<&str>, san: &[String]) -> bool {
@@ -443,3 +481,81 @@ pub async fn ssl_check(_domain: &str, _port: Option<u16>) -> CoreResult<SslCheck
         "SSL 检查功能未启用，请编译时启用 native-tls 或 rustls feature".to_string(),
     ))
 }
+
+#[cfg(all(test, any(feature = "native-tls", feature = "rustls")))]
+mod tests {
+    use super::*;
+    use x509_parser::extensions::GeneralName;
+
+    #[test]
+    fn test_format_general_name() {
+        assert_eq!(
+            format_general_name(&GeneralName::DNSName("example.com")),
+            Some("example.com".to_string())
+        );
+        assert_eq!(
+            format_general_name(&GeneralName::IPAddress(&[192, 0, 2, 1])),
+            Some("192.0.2.1".to_string())
+        );
+        let v6 = [0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(
+            format_general_name(&GeneralName::IPAddress(&v6)),
+            Some("2001:db8::1".to_string())
+        );
+        assert_eq!(format_general_name(&GeneralName::RFC822Name("a@b.c")), None);
+    }
+
+    #[test]
+    fn test_signature_algorithm_name() {
+        assert_eq!(
+            signature_algorithm_name("1.2.840.113549.1.1.11"),
+            "sha256WithRSAEncryption"
+        );
+        assert_eq!(
+            signature_algorithm_name("1.2.840.10045.4.3.2"),
+            "ecdsa-with-SHA256"
+        );
+        assert_eq!(signature_algorithm_name("1.2.3.4"), "1.2.3.4");
+    }
+
+    #[test]
+    fn test_san_ip_matches_query() {
+        let san = vec!["192.0.2.1".to_string(), "*.example.com".to_string()];
+        assert!(check_domain_match("192.0.2.1", None, &san));
+        assert!(check_domain_match("www.example.com", None, &san));
+        assert!(!check_domain_match("a.b.example.com", None, &san));
+    }
+
+    #[tokio::test]
+    #[ignore = "需要访问外网"]
+    async fn test_self_signed_certificate() {
+        let result = ssl_check("self-signed.badssl.com", None).await.unwrap();
+        let cert = result.cert_info.expect("自签名证书仍应能获取证书信息");
+        assert_eq!(result.connection_status, "https");
+        assert!(!cert.chain_trusted);
+        assert!(cert.verification_error.is_some());
+        assert!(cert.hostname_matches);
+        assert!(!cert.is_valid);
+    }
+
+    #[tokio::test]
+    #[ignore = "需要访问外网"]
+    async fn test_expired_certificate() {
+        let result = ssl_check("expired.badssl.com", None).await.unwrap();
+        let cert = result.cert_info.expect("过期证书仍应能获取证书信息");
+        assert!(cert.is_expired);
+        assert!(!cert.chain_trusted);
+        assert!(cert.verification_error.is_some());
+        assert!(!cert.is_valid);
+    }
+
+    #[tokio::test]
+    #[ignore = "需要访问外网"]
+    async fn test_hostname_mismatch_certificate() {
+        let result = ssl_check("wrong.host.badssl.com", None).await.unwrap();
+        let cert = result.cert_info.unwrap();
+        assert!(cert.chain_trusted);
+        assert!(!cert.hostname_matches);
+        assert!(!cert.is_valid);
+    }
+}
@@ -1,140 +1,446 @@
 //! WHOIS 查询模块
 
-use regex::Regex;
-use whois_rust::{WhoIs, WhoIsLookupOptions};
+use whois_rust::{WhoIs, WhoIsLookupOptions, WhoIsServerValue};
 
 use crate::error::{CoreError, CoreResult};
 use crate::types::WhoisResult;
 
+/// 注册商字段名
+const REGISTRAR_KEYS: &[&str] = &[
+    "registrar",
+    "sponsoring registrar",
+    "registrar name",
+    "registrar organization",
+];
+
+/// 创建日期字段名
+const CREATION_DATE_KEYS: &[&str] = &[
+    "creation date",
+    "created date",
+    "created",
+    "created on",
+    "registered on",
+    "registration time",
+    "registration date",
+    "domain registration date",
+];
+
+/// 过期日期字段名
+const EXPIRATION_DATE_KEYS: &[&str] = &[
+    "registry expiry date",
+    "expiration date",
+    "expiry date",
+    "expiration time",
+    "expire date",
+    "expires",
+    "expires on",
+    "paid-till",
+    "registrar registration expiration date",
+];
+
+/// 更新日期字段名
+const UPDATED_DATE_KEYS: &[&str] = &[
+    "updated date",
+    "last updated",
+    "last modified",
+    "last update",
+    "last-update",
+    "changed",
+];
+
+/// 域名服务器字段名
+const NAME_SERVER_KEYS: &[&str] = &[
+    "name server",
+    "name servers",
+    "nameserver",
+    "nameservers",
+    "nserver",
+    "dns",
+];
+
+/// 状态字段名
+const STATUS_KEYS: &[&str] = &["domain status", "status", "state", "registration status"];
+
+/// 注册商 WHOIS 服务器字段名（thin registry 的转介信息）
+const REFERRAL_KEYS: &[&str] = &["registrar whois server", "whois server", "referralserver"];
+
 /// WHOIS 查询
+///
+/// 先查询注册局；响应中带有注册商 WHOIS 服务器时再查询注册商，
+/// 用注册商响应补全注册局未返回的字段（转介查询失败时仅使用注册局结果）
 pub async fn whois_lookup(domain: &str, whois_servers: &str) -> CoreResult<WhoisResult> {
     let whois = WhoIs::from_string(whois_servers)
         .map_err(|e| CoreError::NetworkError(format!("初始化 WHOIS 客户端失败: {e}")))?;
 
-    let options = WhoIsLookupOptions::from_string(domain)
+    let mut options = WhoIsLookupOptions::from_string(domain)
         .map_err(|e| CoreError::ValidationError(format!("无效的域名: {e}")))?;
+    options.follow = 0;
 
     let raw = whois
-        .lookup_async(options)
+        .lookup_async(options.clone())
         .await
         .map_err(|e| CoreError::NetworkError(format!("WHOIS 查询失败: {e}")))?;
+    let mut result = parse_whois_response(domain, &raw);
+
+    let referral = referral_server(&raw).and_then(|s| WhoIsServerValue::from_string(s).ok());
+    if let Some(server) = referral {
+        options.server = Some(server);
+        match whois.lookup_async(options).await {
+            Ok(referral_raw) => {
+                result.merge_referral(parse_whois_response(domain, &referral_raw));
+            }
+            Err(e) => log::debug!("注册商 WHOIS 查询失败，使用注册局结果: {e}"),
+        }
+    }
 
-    Ok(parse_whois_response(domain, &raw))
+    Ok(result)
 }
 
 /// 解析 WHOIS 原始响应
 fn parse_whois_response(domain: &str, raw: &str) -> WhoisResult {
+    let fields = WhoisFields::parse(raw);
     WhoisResult {
         domain: domain.to_string(),
-        registrar: extract_field(
-            raw,
-            &[
-                r"(?i)Registrar:\s*(.+)",
-                r"(?i)Registrar Name:\s*(.+)",
-                r"(?i)Sponsoring Registrar:\s*(.+)",
-            ],
-        ),
-        creation_date: extract_field(
-            raw,
-            &[
-                r"(?i)Creation Date:\s*(.+)",
-                r"(?i)Created Date:\s*(.+)",
-                r"(?i)Created:\s*(.+)",
-                r"(?i)Registration Time:\s*(.+)",
-                r"(?i)Registration Date:\s*(.+)",
-            ],
-        ),
-        expiration_date: extract_field(
-            raw,
-            &[
-                r"(?i)Expir(?:y|ation) Date:\s*(.+)",
-                r"(?i)Registry Expiry Date:\s*(.+)",
-                r"(?i)Expiration Time:\s*(.+)",
-                r"(?i)paid-till:\s*(.+)",
-            ],
-        ),
-        updated_date: extract_field(
-            raw,
-            &[
-                r"(?i)Updated Date:\s*(.+)",
-                r"(?i)Last Updated:\s*(.+)",
-                r"(?i)Last Modified:\s*(.+)",
-            ],
-        ),
-        name_servers: extract_name_servers(raw),
-        status: extract_status(raw),
+        registrar: fields.first(REGISTRAR_KEYS).map(|v| clean_registrar(&v)),
+        creation_date: fields.first(CREATION_DATE_KEYS),
+        expiration_date: fields.first(EXPIRATION_DATE_KEYS),
+        updated_date: fields.first(UPDATED_DATE_KEYS),
+        name_servers: extract_name_servers(&fields),
+        status: extract_status(&fields),
         raw: raw.to_string(),
     }
 }
 
-/// 使用多个正则模式提取字段
-fn extract_field(text: &str, patterns: &[&str]) -> Option<String> {
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            if let Some(caps) = re.captures(text) {
-                if let Some(m) = caps.get(1) {
-                    let value = m.as_str().trim().to_string();
-                    if !value.is_empty() {
-                        return Some(value);
-                    }
-                }
-            }
+/// 提取注册商 WHOIS 服务器（与注册局相同或为空时忽略）
+fn referral_server(raw: &str) -> Option<String> {
+    let server = WhoisFields::parse(raw).first(REFERRAL_KEYS)?;
+    let server = server
+        .trim_start_matches("rwhois://")
+        .trim_start_matches("whois://")
+        .trim_end_matches('/')
+        .to_lowercase();
+    (!server.is_empty() && server.contains('.')).then_some(server)
+}
+
+impl WhoisResult {
+    /// 用注册商响应补全缺失字段，原始响应追加在注册局响应之后
+    fn merge_referral(&mut self, referral: Self) {
+        self.registrar = self.registrar.take().or(referral.registrar);
+        self.creation_date = self.creation_date.take().or(referral.creation_date);
+        self.expiration_date = self.expiration_date.take().or(referral.expiration_date);
+        self.updated_date = self.updated_date.take().or(referral.updated_date);
+        if self.name_servers.is_empty() {
+            self.name_servers = referral.name_servers;
+        }
+        if self.status.is_empty() {
+            self.status = referral.status;
         }
+        self.raw = format!("{}\n\n{}", self.raw, referral.raw);
     }
-    None
 }
 
-/// 提取域名服务器
-fn extract_name_servers(text: &str) -> Vec<String> {
-    let mut servers = Vec::new();
-    let patterns = [
-        r"(?i)Name Server:\s*(.+)",
-        r"(?i)nserver:\s*(.+)",
-        r"(?i)DNS:\s*(.+)",
-    ];
-
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            for caps in re.captures_iter(text) {
-                if let Some(m) = caps.get(1) {
-                    let server = m.as_str().trim().to_lowercase();
-                    if !server.is_empty() && !servers.contains(&server) {
-                        servers.push(server);
-                    }
+/// 按行解析出的 WHOIS 字段
+///
+/// 支持三种常见格式：
+/// - `Key: value`（gTLD、.cn、.de、.ru 等）
+/// - `[Key]   value`（.jp）
+/// - 标题行后跟缩进的多行值（.uk、.eu、.it 等），如 `Name servers:` 下逐行列出
+#[derive(Debug, Default)]
+struct WhoisFields {
+    /// 单行字段（键已转为小写）
+    entries: Vec<(String, String)>,
+    /// 多行字段块（键已转为小写）
+    blocks: Vec<(String, Vec<String>)>,
+}
+
+impl WhoisFields {
+    fn parse(raw: &str) -> Self {
+        let mut fields = Self::default();
+        // 当前打开的字段块：(标题行缩进, 块下标)
+        let mut open: Option<(usize, usize)> = None;
+
+        for line in raw.lines() {
+            let text = line.trim();
+            if text.is_empty() || text.starts_with('%') || text.starts_with('#') {
+                open = None;
+                continue;
+            }
+            // gTLD 响应末尾的说明文字
+            if text.starts_with(">>>") || text.starts_with("NOTICE:") {
+                break;
+            }
+
+            let indent = line.len() - line.trim_start().len();
+            let continued = match open {
+                Some((block_indent, idx)) if indent > block_indent => {
+                    fields.blocks[idx].1.push(text.to_string());
+                    true
+                }
+                _ => {
+                    open = None;
+                    false
                 }
+            };
+
+            match split_field(text) {
+                Some((key, value)) if value.is_empty() => {
+                    fields.blocks.push((key, Vec::new()));
+                    open = Some((indent, fields.blocks.len() - 1));
+                }
+                Some((key, value)) => fields.entries.push((key, value)),
+                // 不带冒号的标题行（如 .it 的 "Nameservers"）
+                None if !continued && indent == 0 => {
+                    fields.blocks.push((text.to_lowercase(), Vec::new()));
+                    open = Some((indent, fields.blocks.len() - 1));
+                }
+                None => {}
             }
         }
+
+        fields
     }
 
+    /// 按字段名优先级取第一个非空值
+    fn first(&self, keys: &[&str]) -> Option<String> {
+        keys.iter().find_map(|key| {
+            self.entries
+                .iter()
+                .find(|(k, v)| k == key && !v.is_empty())
+                .map(|(_, v)| v.clone())
+                .or_else(|| {
+                    self.blocks
+                        .iter()
+                        .find(|(k, _)| k == key)
+                        .and_then(|(_, values)| values.first().cloned())
+                })
+        })
+    }
+
+    /// 收集所有匹配字段名的值（单行字段与多行字段块）
+    fn all(&self, keys: &[&str]) -> Vec<String> {
+        let single = self
+            .entries
+            .iter()
+            .filter(|(k, _)| keys.contains(&k.as_str()))
+            .map(|(_, v)| v.clone());
+        let multi = self
+            .blocks
+            .iter()
+            .filter(|(k, _)| keys.contains(&k.as_str()))
+            .flat_map(|(_, values)| values.iter().cloned());
+        single.chain(multi).collect()
+    }
+}
+
+/// 拆分 `Key: value` 或 `[Key] value`；不是字段行时返回 None
+fn split_field(text: &str) -> Option<(String, String)> {
+    let (key, value) = if let Some(rest) = text.strip_prefix('[') {
+        rest.split_once(']')?
+    } else {
+        text.split_once(':')?
+    };
+    let key = key.trim();
+    let value = value.trim();
+    // 排除 URL 等值中包含冒号的行
+    if key.is_empty()
+        || key.len() > 40
+        || value.starts_with("//")
+        || !key
+            .chars()
+            .all(|c| c.is_alphanumeric() || " -_./()".contains(c))
+    {
+        return None;
+    }
+    Some((key.to_lowercase(), value.to_string()))
+}
+
+/// 去掉注册商块中的 `Name:` 前缀与 .uk 的 `[Tag = ...]` 后缀
+fn clean_registrar(value: &str) -> String {
+    let value = ["name:", "organization:"]
+        .iter()
+        .find_map(|prefix| {
+            value
+                .get(..prefix.len())
+                .filter(|p| p.eq_ignore_ascii_case(prefix))
+                .map(|_| &value[prefix.len()..])
+        })
+        .unwrap_or(value);
+    value
+        .split(" [Tag =")
+        .next()
+        .unwrap_or(value)
+        .trim()
+        .to_string()
+}
+
+/// 提取域名服务器（只保留主机名，去掉附带的 IP 和末尾的点）
+fn extract_name_servers(fields: &WhoisFields) -> Vec<String> {
+    let mut servers = Vec::new();
+    for value in fields.all(NAME_SERVER_KEYS) {
+        let Some(server) = value.split_whitespace().next() else {
+            continue;
+        };
+        let server = server.trim_end_matches('.').to_lowercase();
+        if server.contains('.') && !servers.contains(&server) {
+            servers.push(server);
+        }
+    }
     servers
 }
 
-/// 提取域名状态
-fn extract_status(text: &str) -> Vec<String> {
+/// 提取域名状态（EPP 状态码后的说明链接、逗号分隔的多个状态均拆开）
+fn extract_status(fields: &WhoisFields) -> Vec<String> {
     let mut statuses = Vec::new();
-    let patterns = [
-        r"(?i)Domain Status:\s*(.+)",
-        r"(?i)Status:\s*(.+)",
-        r"(?i)state:\s*(.+)",
-    ];
-
-    for pattern in patterns {
-        if let Ok(re) = Regex::new(pattern) {
-            for caps in re.captures_iter(text) {
-                if let Some(m) = caps.get(1) {
-                    let status = m.as_str().trim().to_string();
-                    let status = status
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or(&status)
-                        .to_string();
-                    if !status.is_empty() && !statuses.contains(&status) {
-                        statuses.push(status);
-                    }
-                }
+    for value in fields.all(STATUS_KEYS) {
+        for part in value.split(',') {
+            let Some(status) = part.split_whitespace().next() else {
+                continue;
+            };
+            let status = status.to_string();
+            if !statuses.contains(&status) {
+                statuses.push(status);
             }
         }
     }
-
     statuses
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(fixture: &str) -> WhoisResult {
+        parse_whois_response("example", fixture)
+    }
+
+    #[test]
+    fn test_parse_gtld_registry() {
+        let raw = include_str!("fixtures/whois/com_registry.txt");
+        let result = parse(raw);
+        assert_eq!(
+            result.registrar.as_deref(),
+            Some("RESERVED-Internet Assigned Numbers Authority")
+        );
+        assert_eq!(
+            result.creation_date.as_deref(),
+            Some("1995-08-14T04:00:00Z")
+        );
+        assert_eq!(
+            result.expiration_date.as_deref(),
+            Some("2026-08-13T04:00:00Z")
+        );
+        assert_eq!(result.updated_date.as_deref(), Some("2025-08-14T07:01:34Z"));
+        assert_eq!(
+            result.name_servers,
+            ["a.iana-servers.net", "b.iana-servers.net"]
+        );
+        assert_eq!(
+            result.status,
+            [
+                "clientDeleteProhibited",
+                "clientTransferProhibited",
+                "clientUpdateProhibited"
+            ]
+        );
+        assert_eq!(referral_server(raw).as_deref(), Some("whois.iana.org"));
+    }
+
+    #[test]
+    fn test_merge_referral() {
+        let mut result = parse(include_str!("fixtures/whois/com_registry.txt"));
+        result.creation_date = None;
+        result.merge_referral(parse(include_str!("fixtures/whois/com_registrar.txt")));
+        // 注册局已有的字段不被覆盖
+        assert_eq!(
+            result.registrar.as_deref(),
+            Some("RESERVED-Internet Assigned Numbers Authority")
+        );
+        assert_eq!(
+            result.creation_date.as_deref(),
+            Some("1995-08-14T04:00:00Z")
+        );
+        assert!(result
+            .raw
+            .contains("Registrar Registration Expiration Date"));
+    }
+
+    #[test]
+    fn test_parse_cn() {
+        let raw = include_str!("fixtures/whois/cn.txt");
+        let result = parse(raw);
+        assert_eq!(
+            result.registrar.as_deref(),
+            Some("阿里云计算有限公司（万网）")
+        );
+        assert_eq!(result.creation_date.as_deref(), Some("2003-03-17 12:20:05"));
+        assert_eq!(
+            result.expiration_date.as_deref(),
+            Some("2027-03-17 12:48:36")
+        );
+        assert_eq!(result.name_servers, ["ns1.example.cn", "ns2.example.cn"]);
+        assert_eq!(
+            result.status,
+            ["clientDeleteProhibited", "clientTransferProhibited"]
+        );
+        assert!(referral_server(raw).is_none());
+    }
+
+    #[test]
+    fn test_parse_de() {
+        let result = parse(include_str!("fixtures/whois/de.txt"));
+        assert_eq!(result.registrar, None);
+        assert_eq!(
+            result.updated_date.as_deref(),
+            Some("2018-03-12T21:44:25+01:00")
+        );
+        assert_eq!(
+            result.name_servers,
+            ["a.iana-servers.net", "b.iana-servers.net"]
+        );
+        assert_eq!(result.status, ["connect"]);
+    }
+
+    #[test]
+    fn test_parse_jp() {
+        let result = parse(include_str!("fixtures/whois/jp.txt"));
+        assert_eq!(result.creation_date.as_deref(), Some("2001/02/26"));
+        assert_eq!(result.expiration_date.as_deref(), Some("2027/02/28"));
+        assert_eq!(
+            result.updated_date.as_deref(),
+            Some("2026/03/01 01:05:03 (JST)")
+        );
+        assert_eq!(result.name_servers, ["ns1.example.jp", "ns2.example.jp"]);
+        assert_eq!(result.status, ["Active"]);
+    }
+
+    #[test]
+    fn test_parse_uk_blocks() {
+        let result = parse(include_str!("fixtures/whois/uk.txt"));
+        assert_eq!(result.registrar.as_deref(), Some("Nominet UK"));
+        assert_eq!(result.creation_date.as_deref(), Some("26-Aug-1996"));
+        assert_eq!(result.expiration_date.as_deref(), Some("26-Aug-2027"));
+        assert_eq!(result.updated_date.as_deref(), Some("25-Jul-2025"));
+        assert_eq!(
+            result.name_servers,
+            ["ns1.example.co.uk", "ns2.example.co.uk"]
+        );
+        assert_eq!(result.status, ["Registered"]);
+    }
+
+    #[test]
+    fn test_split_field() {
+        assert_eq!(
+            split_field("Registrar URL: http://www.example.com"),
+            Some((
+                "registrar url".to_string(),
+                "http://www.example.com".to_string()
+            ))
+        );
+        assert_eq!(split_field("https://icann.org/epp"), None);
+        assert_eq!(
+            split_field("[Name Server]   ns1.example.jp"),
+            Some(("name server".to_string(), "ns1.example.jp".to_string()))
+        );
+        assert_eq!(split_field("ns1.example.co.uk"), None);
+    }
+}
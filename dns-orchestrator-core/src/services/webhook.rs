@@ -0,0 +1,244 @@
+//! 记录变更 Webhook
+//!
+//! DNS 记录创建、更新、删除成功后，向配置的 URL POST 一条 JSON 事件，
+//! 请求头 [`SIGNATURE_HEADER`] 携带以密钥计算的 HMAC-SHA256 签名。
+//!
+//! 事件先进入有界队列，由 [`WebhookDispatcher`] 在后台逐条投递并退避重试，
+//! 不会阻塞记录操作；队列已满时丢弃事件并记录警告。
+
+use std::fmt::Write as _;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use tokio::sync::mpsc;
+
+use dns_orchestrator_provider::{build_http_client, DnsRecord, HttpClientConfig};
+
+/// 签名请求头，值为 `sha256=<hex>`
+pub const SIGNATURE_HEADER: &str = "X-DNS-Orchestrator-Signature";
+
+/// 默认队列容量
+pub const DEFAULT_QUEUE_CAPACITY: usize = 1000;
+
+/// 默认最大投递次数（包含首次）
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// 首次重试前的延迟，之后每次翻倍
+const BASE_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+/// 单次重试延迟上限
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(30);
+
+/// Webhook 配置
+#[derive(Clone)]
+pub struct WebhookConfig {
+    /// 接收事件的 URL
+    pub url: String,
+    /// 签名密钥
+    pub secret: String,
+    /// 队列容量
+    pub queue_capacity: usize,
+    /// 最大投递次数（包含首次）
+    pub max_attempts: u32,
+    /// HTTP 超时与代理配置
+    pub http: HttpClientConfig,
+}
+
+impl WebhookConfig {
+    /// 创建配置（使用默认队列容量与重试次数）
+    #[must_use]
+    pub fn new(url: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            secret: secret.into(),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            http: HttpClientConfig::default(),
+        }
+    }
+
+    /// 设置最大投递次数
+    #[must_use]
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// 设置 HTTP 超时与代理配置
+    #[must_use]
+    pub fn with_http(mut self, http: HttpClientConfig) -> Self {
+        self.http = http;
+        self
+    }
+}
+
+impl std::fmt::Debug for WebhookConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WebhookConfig")
+            .field("url", &self.url)
+            .field("secret", &"***")
+            .field("queue_capacity", &self.queue_capacity)
+            .field("max_attempts", &self.max_attempts)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 记录变更类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordChangeKind {
+    /// 记录已创建
+    #[serde(rename = "record.created")]
+    Created,
+    /// 记录已更新
+    #[serde(rename = "record.updated")]
+    Updated,
+    /// 记录已删除
+    #[serde(rename = "record.deleted")]
+    Deleted,
+}
+
+/// 记录变更事件（Webhook 请求体）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordChangeEvent {
+    /// 变更类型
+    pub event: RecordChangeKind,
+    /// 账户 ID
+    pub account_id: String,
+    /// 域名 ID
+    pub domain_id: String,
+    /// 记录 ID
+    pub record_id: String,
+    /// 变更后的记录；删除时为删除前的快照（未获取快照时为 `None`）
+    pub record: Option<DnsRecord>,
+    /// 变更时间
+    pub timestamp: DateTime<Utc>,
+}
+
+impl RecordChangeEvent {
+    /// 由变更后的记录构造事件
+    #[must_use]
+    pub fn for_record(event: RecordChangeKind, account_id: &str, record: DnsRecord) -> Self {
+        Self {
+            event,
+            account_id: account_id.to_string(),
+            domain_id: record.domain_id.clone(),
+            record_id: record.id.clone(),
+            record: Some(record),
+            timestamp: Utc::now(),
+        }
+    }
+}
+
+/// Webhook 通知器（可克隆，克隆共享同一队列）
+#[derive(Debug, Clone)]
+pub struct WebhookNotifier {
+    sender: mpsc::Sender<RecordChangeEvent>,
+}
+
+impl WebhookNotifier {
+    /// 创建通知器及其后台投递任务
+    ///
+    /// 平台层需在异步运行时中执行 [`WebhookDispatcher::run`]
+    #[must_use]
+    pub fn new(config: WebhookConfig) -> (Self, WebhookDispatcher) {
+        let (sender, receiver) = mpsc::channel(config.queue_capacity.max(1));
+        (Self { sender }, WebhookDispatcher { config, receiver })
+    }
+
+    /// 将事件放入投递队列（不等待投递完成）
+    pub fn notify(&self, event: RecordChangeEvent) {
+        match self.sender.try_send(event) {
+            Ok(()) => {}
+            Err(mpsc::error::TrySendError::Full(event)) => log::warn!(
+                "Webhook queue is full, dropping {:?} event for record {}",
+                event.event,
+                event.record_id
+            ),
+            Err(mpsc::error::TrySendError::Closed(_)) => {
+                log::warn!("Webhook dispatcher is not running, event dropped");
+            }
+        }
+    }
+}
+
+/// Webhook 后台投递任务
+pub struct WebhookDispatcher {
+    config: WebhookConfig,
+    receiver: mpsc::Receiver<RecordChangeEvent>,
+}
+
+impl WebhookDispatcher {
+    /// 逐条投递队列中的事件，所有通知器被丢弃后结束
+    pub async fn run(mut self) {
+        let client = build_http_client(&self.config.http);
+        while let Some(event) = self.receiver.recv().await {
+            self.deliver(&client, &event).await;
+        }
+    }
+
+    /// 投递单条事件，失败时退避重试，超过最大次数后放弃
+    async fn deliver(&self, client: &reqwest::Client, event: &RecordChangeEvent) {
+        let body = match serde_json::to_vec(event) {
+            Ok(body) => body,
+            Err(e) => {
+                log::error!("Failed to serialize webhook event: {e}");
+                return;
+            }
+        };
+        let signature = sign(self.config.secret.as_bytes(), &body);
+
+        let mut delay = BASE_RETRY_DELAY;
+        for attempt in 1..=self.config.max_attempts {
+            let result = client
+                .post(&self.config.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(SIGNATURE_HEADER, &signature)
+                .body(body.clone())
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status);
+            match result {
+                Ok(_) => return,
+                Err(e) if attempt < self.config.max_attempts => {
+                    log::debug!("Webhook delivery attempt {attempt} failed: {e}");
+                    tokio::time::sleep(delay).await;
+                    delay = (delay * 2).min(MAX_RETRY_DELAY);
+                }
+                Err(e) => log::warn!(
+                    "Webhook delivery for record {} failed after {attempt} attempts: {e}",
+                    event.record_id
+                ),
+            }
+        }
+    }
+}
+
+/// 计算请求体签名：`sha256=<hex(HMAC-SHA256(secret, body))>`
+#[must_use]
+#[allow(clippy::expect_used)] // HMAC 接受任意长度的密钥，不会失败
+pub fn sign(secret: &[u8], body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    let digest = mac.finalize().into_bytes();
+    digest.iter().fold(String::from("sha256="), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        // RFC 4231 测试用例 2
+        assert_eq!(
+            sign(b"Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
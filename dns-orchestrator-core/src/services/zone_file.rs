@@ -0,0 +1,796 @@
+//! BIND 区域文件（RFC 1035 master file）序列化与解析
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use dns_orchestrator_provider::quote_txt_chunks;
+
+use crate::types::{CreateDnsRecordRequest, DnsRecord, DnsRecordType};
+
+/// 无记录时使用的默认 `$TTL`
+const DEFAULT_ZONE_TTL: u32 = 600;
+
+/// 将记录序列化为 BIND 区域文件
+///
+/// `$TTL` 取记录中出现最多的 TTL，每条记录仍显式写出自身 TTL
+pub(crate) fn to_zone_file(domain_name: &str, records: &[DnsRecord]) -> String {
+    let origin = fqdn(domain_name);
+    let default_ttl = most_common_ttl(records);
+
+    let mut out = String::new();
+    let _ = writeln!(out, "; Zone file for {origin}");
+    let _ = writeln!(out, "$ORIGIN {origin}");
+    let _ = writeln!(out, "$TTL {default_ttl}");
+
+    for record in records {
+        let name = relative_name(&record.name, domain_name);
+        let _ = writeln!(
+            out,
+            "{name}\t{}\tIN\t{}\t{}",
+            record.ttl,
+            type_name(&record.record_type),
+            rdata(record)
+        );
+    }
+
+    out
+}
+
+/// 记录类型在区域文件中的名称
+pub(crate) fn type_name(record_type: &DnsRecordType) -> &'static str {
+    match record_type {
+        DnsRecordType::A => "A",
+        DnsRecordType::Aaaa => "AAAA",
+        DnsRecordType::Cname => "CNAME",
+        DnsRecordType::Mx => "MX",
+        DnsRecordType::Txt => "TXT",
+        DnsRecordType::Ns => "NS",
+        DnsRecordType::Srv => "SRV",
+        DnsRecordType::Caa => "CAA",
+        DnsRecordType::Ptr => "PTR",
+        DnsRecordType::Https => "HTTPS",
+        DnsRecordType::Svcb => "SVCB",
+        DnsRecordType::Alias => "ALIAS",
+    }
+}
+
+/// 记录数据部分
+fn rdata(record: &DnsRecord) -> String {
+    let value = record.value.trim();
+    match record.record_type {
+        DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr | DnsRecordType::Alias => {
+            fqdn(value)
+        }
+        DnsRecordType::Mx => format!("{} {}", record.priority.unwrap_or(0), fqdn(value)),
+        DnsRecordType::Srv => {
+            // 值为 `weight port target`，优先级单独存放
+            let fields: Vec<&str> = value.split_whitespace().collect();
+            match fields.as_slice() {
+                [weight, port, target] => format!(
+                    "{} {weight} {port} {}",
+                    record.priority.unwrap_or(0),
+                    fqdn(target)
+                ),
+                _ => value.to_string(),
+            }
+        }
+        DnsRecordType::Https | DnsRecordType::Svcb => {
+            // 值为 `priority target params`，目标 `.` 表示记录名自身
+            let mut fields = value.splitn(3, ' ');
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some(priority), Some(target), params) => {
+                    let target = if target == "." {
+                        ".".to_string()
+                    } else {
+                        fqdn(target)
+                    };
+                    match params {
+                        Some(params) => format!("{priority} {target} {params}"),
+                        None => format!("{priority} {target}"),
+                    }
+                }
+                _ => value.to_string(),
+            }
+        }
+        DnsRecordType::Txt => quote_txt_chunks(value),
+        DnsRecordType::A | DnsRecordType::Aaaa | DnsRecordType::Caa => value.to_string(),
+    }
+}
+
+/// 转为带末尾点的完整域名
+fn fqdn(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{name}.")
+    }
+}
+
+/// 转为相对 `$ORIGIN` 的记录名（根记录为 `@`）
+pub(crate) fn relative_name(name: &str, domain_name: &str) -> String {
+    let name = name.trim_end_matches('.');
+    if name.is_empty() || name == "@" || name.eq_ignore_ascii_case(domain_name) {
+        return "@".to_string();
+    }
+    let suffix = format!(".{domain_name}");
+    if name.len() > suffix.len() && name.to_lowercase().ends_with(&suffix.to_lowercase()) {
+        return name[..name.len() - suffix.len()].to_string();
+    }
+    name.to_string()
+}
+
+/// 出现次数最多的 TTL（相同次数取较小值）
+fn most_common_ttl(records: &[DnsRecord]) -> u32 {
+    let mut counts: HashMap<u32, usize> = HashMap::new();
+    for record in records {
+        *counts.entry(record.ttl).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by(|(ttl_a, a), (ttl_b, b)| a.cmp(b).then(ttl_b.cmp(ttl_a)))
+        .map_or(DEFAULT_ZONE_TTL, |(ttl, _)| ttl)
+}
+
+/// 区域文件解析结果
+#[derive(Debug, Default)]
+pub(crate) struct ParsedZoneFile {
+    /// 待创建的记录
+    pub records: Vec<CreateDnsRecordRequest>,
+    /// 跳过的记录（SOA、顶点 NS、不支持的类型）
+    pub skipped: Vec<String>,
+    /// 无法解析的行
+    pub errors: Vec<String>,
+}
+
+/// 解析 BIND 区域文件为待创建的记录
+///
+/// 支持 `$ORIGIN` / `$TTL`、相对与完整域名、括号跨行（如多段 TXT）。
+/// SOA 总是跳过；顶点 NS 由服务商托管，仅在 `include_apex_ns` 时导入
+pub(crate) fn parse_zone_file(
+    content: &str,
+    domain_name: &str,
+    domain_id: &str,
+    include_apex_ns: bool,
+) -> ParsedZoneFile {
+    let domain_name = domain_name.trim_end_matches('.');
+    let mut origin = domain_name.to_string();
+    let mut default_ttl = None;
+    let mut last_owner: Option<String> = None;
+    let mut parsed = ParsedZoneFile::default();
+
+    for (line_no, line) in logical_lines(content) {
+        let tokens = tokenize(&line);
+        let Some(first) = tokens.first() else {
+            continue;
+        };
+
+        match first.to_ascii_uppercase().as_str() {
+            "$ORIGIN" => match tokens.get(1) {
+                Some(name) => origin = absolute_name(name, &origin),
+                None => parsed
+                    .errors
+                    .push(format!("第 {line_no} 行: $ORIGIN 缺少域名")),
+            },
+            "$TTL" => match tokens.get(1).and_then(|t| parse_ttl(t)) {
+                Some(ttl) => default_ttl = Some(ttl),
+                None => parsed.errors.push(format!("第 {line_no} 行: $TTL 无效")),
+            },
+            directive if directive.starts_with('$') => parsed
+                .skipped
+                .push(format!("第 {line_no} 行: 不支持的指令 {first}")),
+            _ => {
+                let owner_inherited = line.starts_with(char::is_whitespace);
+                match parse_record_line(
+                    &tokens,
+                    owner_inherited,
+                    &mut last_owner,
+                    &origin,
+                    default_ttl.unwrap_or(DEFAULT_ZONE_TTL),
+                ) {
+                    Ok((owner, record_type, ttl, rdata)) => {
+                        let Some(name) = relative_to_domain(&owner, domain_name) else {
+                            parsed
+                                .errors
+                                .push(format!("第 {line_no} 行: {owner} 不属于域名 {domain_name}"));
+                            continue;
+                        };
+
+                        let upper = record_type.to_ascii_uppercase();
+                        let Some(record_type) = parse_record_type(&upper) else {
+                            parsed.skipped.push(format!(
+                                "第 {line_no} 行: {name} {upper}（不支持的记录类型）"
+                            ));
+                            continue;
+                        };
+                        if record_type == DnsRecordType::Ns && name == "@" && !include_apex_ns {
+                            parsed
+                                .skipped
+                                .push(format!("第 {line_no} 行: @ NS（由服务商托管）"));
+                            continue;
+                        }
+
+                        match parse_rdata(&record_type, &rdata, &origin) {
+                            Ok((value, priority)) => parsed.records.push(CreateDnsRecordRequest {
+                                domain_id: domain_id.to_string(),
+                                record_type,
+                                name,
+                                value,
+                                ttl,
+                                priority,
+                                proxied: None,
+                                line: None,
+                                weight: None,
+                                comment: None,
+                                tags: None,
+                            }),
+                            Err(e) => parsed
+                                .errors
+                                .push(format!("第 {line_no} 行: {name} {upper}: {e}")),
+                        }
+                    }
+                    Err(RecordLineError::Skip(reason)) => {
+                        parsed.skipped.push(format!("第 {line_no} 行: {reason}"));
+                    }
+                    Err(RecordLineError::Invalid(reason)) => {
+                        parsed.errors.push(format!("第 {line_no} 行: {reason}"));
+                    }
+                }
+            }
+        }
+    }
+
+    parsed
+}
+
+/// 记录行解析失败原因
+enum RecordLineError {
+    /// 合法但无需导入（如 SOA）
+    Skip(String),
+    /// 格式错误
+    Invalid(String),
+}
+
+/// 解析记录行为 (完整记录名, 类型, TTL, 数据字段)
+fn parse_record_line(
+    tokens: &[String],
+    owner_inherited: bool,
+    last_owner: &mut Option<String>,
+    origin: &str,
+    default_ttl: u32,
+) -> Result<(String, String, u32, Vec<String>), RecordLineError> {
+    let mut rest = tokens;
+    let owner = if owner_inherited {
+        last_owner
+            .clone()
+            .ok_or_else(|| RecordLineError::Invalid("缺少记录名".to_string()))?
+    } else {
+        let owner = absolute_name(&rest[0], origin);
+        rest = &rest[1..];
+        owner
+    };
+    *last_owner = Some(owner.clone());
+
+    // TTL 与 CLASS 均可省略且顺序不固定
+    let mut ttl = default_ttl;
+    for _ in 0..2 {
+        match rest.first() {
+            Some(t) if is_class(t) => rest = &rest[1..],
+            Some(t) => match parse_ttl(t) {
+                Some(value) => {
+                    ttl = value;
+                    rest = &rest[1..];
+                }
+                None => break,
+            },
+            None => break,
+        }
+    }
+
+    let (record_type, rdata) = rest
+        .split_first()
+        .ok_or_else(|| RecordLineError::Invalid("缺少记录类型".to_string()))?;
+    if record_type.eq_ignore_ascii_case("SOA") {
+        return Err(RecordLineError::Skip(format!(
+            "{owner} SOA（由服务商托管）"
+        )));
+    }
+    Ok((owner, record_type.clone(), ttl, rdata.to_vec()))
+}
+
+/// 解析记录数据为 (值, 优先级)，格式与 `DnsRecord` 一致
+fn parse_rdata(
+    record_type: &DnsRecordType,
+    rdata: &[String],
+    origin: &str,
+) -> Result<(String, Option<u16>), String> {
+    let parse_u16 =
+        |s: &str, field: &str| s.parse::<u16>().map_err(|_| format!("{field} 无效: {s}"));
+
+    match (record_type, rdata) {
+        (DnsRecordType::A, [ip]) => ip
+            .parse::<Ipv4Addr>()
+            .map(|_| (ip.clone(), None))
+            .map_err(|_| format!("IPv4 地址无效: {ip}")),
+        (DnsRecordType::Aaaa, [ip]) => ip
+            .parse::<Ipv6Addr>()
+            .map(|_| (ip.clone(), None))
+            .map_err(|_| format!("IPv6 地址无效: {ip}")),
+        (
+            DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr | DnsRecordType::Alias,
+            [target],
+        ) => Ok((absolute_name(target, origin), None)),
+        (DnsRecordType::Mx, [priority, target]) => Ok((
+            absolute_name(target, origin),
+            Some(parse_u16(priority, "优先级")?),
+        )),
+        (DnsRecordType::Srv, [priority, weight, port, target]) => {
+            let priority = parse_u16(priority, "优先级")?;
+            let weight = parse_u16(weight, "权重")?;
+            let port = parse_u16(port, "端口")?;
+            Ok((
+                format!("{weight} {port} {}", absolute_name(target, origin)),
+                Some(priority),
+            ))
+        }
+        (DnsRecordType::Txt, chunks) if !chunks.is_empty() => {
+            Ok((chunks.iter().map(|c| unquote(c)).collect(), None))
+        }
+        (DnsRecordType::Caa, [flags, tag, value @ ..]) if !value.is_empty() => {
+            let flags = flags
+                .parse::<u8>()
+                .map_err(|_| format!("CAA flags 无效: {flags}"))?;
+            let value: String = value.iter().map(|v| unquote(v)).collect();
+            Ok((format!("{flags} {} \"{value}\"", tag.to_lowercase()), None))
+        }
+        (DnsRecordType::Https | DnsRecordType::Svcb, [priority, target, params @ ..]) => {
+            let priority = parse_u16(priority, "优先级")?;
+            let target = if target == "." {
+                ".".to_string()
+            } else {
+                absolute_name(target, origin)
+            };
+            let mut value = format!("{priority} {target}");
+            for param in params {
+                value.push(' ');
+                value.push_str(param);
+            }
+            Ok((value, None))
+        }
+        _ => Err("记录数据格式无效".to_string()),
+    }
+}
+
+/// 去掉注释并合并括号跨行的记录，返回 (起始行号, 内容)
+fn logical_lines(content: &str) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut start_line = 0;
+    let mut depth = 0usize;
+
+    for (idx, raw) in content.lines().enumerate() {
+        let mut text = String::new();
+        let mut in_quote = false;
+        let mut escaped = false;
+        for c in raw.chars() {
+            if escaped {
+                escaped = false;
+                text.push(c);
+                continue;
+            }
+            match c {
+                '\\' => {
+                    escaped = true;
+                    text.push(c);
+                }
+                '"' => {
+                    in_quote = !in_quote;
+                    text.push(c);
+                }
+                ';' if !in_quote => break,
+                '(' if !in_quote => {
+                    depth += 1;
+                    text.push(' ');
+                }
+                ')' if !in_quote => {
+                    depth = depth.saturating_sub(1);
+                    text.push(' ');
+                }
+                _ => text.push(c),
+            }
+        }
+
+        if current.is_empty() {
+            start_line = idx + 1;
+            current = text;
+        } else {
+            current.push(' ');
+            current.push_str(&text);
+        }
+
+        if depth == 0 {
+            if !current.trim().is_empty() {
+                lines.push((start_line, std::mem::take(&mut current)));
+            }
+            current.clear();
+        }
+    }
+    if !current.trim().is_empty() {
+        lines.push((start_line, current));
+    }
+
+    lines
+}
+
+/// 按空白拆分，引号内的内容（保留引号）作为一个字段
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quote = false;
+    let mut escaped = false;
+
+    for c in line.chars() {
+        if escaped {
+            escaped = false;
+            current.push(c);
+            continue;
+        }
+        match c {
+            '\\' => {
+                escaped = true;
+                current.push(c);
+            }
+            '"' => {
+                in_quote = !in_quote;
+                current.push(c);
+            }
+            c if c.is_whitespace() && !in_quote => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+}
+
+/// 去掉字符串两侧引号并还原转义
+fn unquote(token: &str) -> String {
+    let inner = token
+        .strip_prefix('"')
+        .and_then(|t| t.strip_suffix('"'))
+        .unwrap_or(token);
+
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            if let Some(next) = chars.next() {
+                out.push(next);
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// 将记录名解析为完整域名（不带末尾的点）
+fn absolute_name(name: &str, origin: &str) -> String {
+    if name == "@" {
+        origin.to_string()
+    } else if let Some(name) = name.strip_suffix('.') {
+        name.to_string()
+    } else {
+        format!("{name}.{origin}")
+    }
+}
+
+/// 完整域名转为相对于域名的记录名；不属于该域名时返回 None
+fn relative_to_domain(name: &str, domain_name: &str) -> Option<String> {
+    if name.eq_ignore_ascii_case(domain_name) {
+        return Some("@".to_string());
+    }
+    let suffix = format!(".{domain_name}");
+    (name.len() > suffix.len() && name.to_lowercase().ends_with(&suffix.to_lowercase()))
+        .then(|| name[..name.len() - suffix.len()].to_string())
+}
+
+/// 解析 TTL，支持 `1h30m` 形式的单位（s/m/h/d/w）
+fn parse_ttl(token: &str) -> Option<u32> {
+    if let Ok(ttl) = token.parse::<u32>() {
+        return Some(ttl);
+    }
+    if !token.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let mut total: u32 = 0;
+    let mut number = String::new();
+    for c in token.chars() {
+        if c.is_ascii_digit() {
+            number.push(c);
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86_400,
+            'w' => 604_800,
+            _ => return None,
+        };
+        let value: u32 = std::mem::take(&mut number).parse().ok()?;
+        total = total.checked_add(value.checked_mul(unit)?)?;
+    }
+    if !number.is_empty() {
+        total = total.checked_add(number.parse().ok()?)?;
+    }
+    Some(total)
+}
+
+/// 是否为记录类别字段
+fn is_class(token: &str) -> bool {
+    matches!(
+        token.to_ascii_uppercase().as_str(),
+        "IN" | "CH" | "HS" | "CS"
+    )
+}
+
+/// 区域文件中的类型名转为记录类型
+fn parse_record_type(name: &str) -> Option<DnsRecordType> {
+    Some(match name {
+        "A" => DnsRecordType::A,
+        "AAAA" => DnsRecordType::Aaaa,
+        "CNAME" => DnsRecordType::Cname,
+        "MX" => DnsRecordType::Mx,
+        "TXT" => DnsRecordType::Txt,
+        "NS" => DnsRecordType::Ns,
+        "SRV" => DnsRecordType::Srv,
+        "CAA" => DnsRecordType::Caa,
+        "PTR" => DnsRecordType::Ptr,
+        "HTTPS" => DnsRecordType::Https,
+        "SVCB" => DnsRecordType::Svcb,
+        "ALIAS" | "ANAME" => DnsRecordType::Alias,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(
+        name: &str,
+        record_type: DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> DnsRecord {
+        DnsRecord {
+            id: "1".to_string(),
+            domain_id: "d1".to_string(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_zone_file_header_and_records() {
+        let records = vec![
+            record("@", DnsRecordType::A, "192.0.2.1", None),
+            record("www.example.com", DnsRecordType::Cname, "example.com", None),
+            record(
+                "_sip._tcp",
+                DnsRecordType::Srv,
+                "5 5060 sip.example.com",
+                Some(10),
+            ),
+            record("@", DnsRecordType::Caa, "0 issue \"letsencrypt.org\"", None),
+        ];
+        let zone = to_zone_file("example.com", &records);
+        let lines: Vec<&str> = zone.lines().collect();
+
+        assert_eq!(lines[1], "$ORIGIN example.com.");
+        assert_eq!(lines[2], "$TTL 600");
+        assert_eq!(lines[3], "@\t600\tIN\tA\t192.0.2.1");
+        assert_eq!(lines[4], "www\t600\tIN\tCNAME\texample.com.");
+        assert_eq!(
+            lines[5],
+            "_sip._tcp\t600\tIN\tSRV\t10 5 5060 sip.example.com."
+        );
+        assert_eq!(lines[6], "@\t600\tIN\tCAA\t0 issue \"letsencrypt.org\"");
+    }
+
+    #[test]
+    fn test_zone_file_mx_priority() {
+        let records = vec![
+            record("@", DnsRecordType::Mx, "mx1.example.com", Some(10)),
+            record("@", DnsRecordType::Mx, "mx2.example.com.", None),
+        ];
+        let zone = to_zone_file("example.com", &records);
+
+        assert!(zone.contains("@\t600\tIN\tMX\t10 mx1.example.com.\n"));
+        assert!(zone.contains("@\t600\tIN\tMX\t0 mx2.example.com.\n"));
+    }
+
+    #[test]
+    fn test_zone_file_txt_quoting() {
+        assert_eq!(quote_txt_chunks("v=spf1 -all"), "\"v=spf1 -all\"");
+        assert_eq!(quote_txt_chunks("say \"hi\""), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote_txt_chunks("a\\b"), "\"a\\\\b\"");
+        assert_eq!(quote_txt_chunks("\"already quoted\""), "\"already quoted\"");
+        assert_eq!(quote_txt_chunks(""), "\"\"");
+
+        let long = "a".repeat(300);
+        assert_eq!(
+            quote_txt_chunks(&long),
+            format!("\"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+        );
+    }
+
+    #[test]
+    fn test_most_common_ttl() {
+        assert_eq!(most_common_ttl(&[]), DEFAULT_ZONE_TTL);
+
+        let mut records = vec![
+            record("a", DnsRecordType::A, "192.0.2.1", None),
+            record("b", DnsRecordType::A, "192.0.2.2", None),
+            record("c", DnsRecordType::A, "192.0.2.3", None),
+        ];
+        records[0].ttl = 300;
+        assert_eq!(most_common_ttl(&records), 600);
+    }
+
+    const SAMPLE_ZONE: &str = r#"
+$ORIGIN example.com.
+$TTL 1h
+@       IN  SOA ns1.example.com. admin.example.com. (
+                2024010101 ; serial
+                7200 3600 1209600 300 )
+@       IN  NS  ns1.provider.net.
+@           A   192.0.2.1           ; apex
+www     300 IN  CNAME @
+mail        IN  MX 10 mx1
+            IN  MX 20 mx2.example.net.
+_sip._tcp   IN  SRV 10 5 5060 sip
+@           CAA 0 issue "letsencrypt.org"
+long        TXT ( "v=DKIM1; k=rsa; "
+                  "p=MIGf" )
+quoted      TXT "say \"hi\""
+sub.example.com. 60 IN AAAA 2001:db8::1
+$ORIGIN dev.example.com.
+api         A   192.0.2.2
+other.org.  A   192.0.2.3
+x           HINFO "cpu" "os"
+bad         A   not-an-ip
+"#;
+
+    fn find<'a>(
+        records: &'a [CreateDnsRecordRequest],
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Vec<&'a CreateDnsRecordRequest> {
+        records
+            .iter()
+            .filter(|r| r.name == name && r.record_type == *record_type)
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_zone_file_records() {
+        let parsed = parse_zone_file(SAMPLE_ZONE, "example.com", "d1", false);
+        let records = &parsed.records;
+
+        let apex = find(records, "@", &DnsRecordType::A);
+        assert_eq!(apex[0].value, "192.0.2.1");
+        assert_eq!(apex[0].ttl, 3600);
+        assert_eq!(apex[0].domain_id, "d1");
+
+        let www = find(records, "www", &DnsRecordType::Cname);
+        assert_eq!(www[0].value, "example.com");
+        assert_eq!(www[0].ttl, 300);
+
+        let mx = find(records, "mail", &DnsRecordType::Mx);
+        assert_eq!(mx.len(), 2);
+        assert_eq!(
+            (mx[0].value.as_str(), mx[0].priority),
+            ("mx1.example.com", Some(10))
+        );
+        assert_eq!(
+            (mx[1].value.as_str(), mx[1].priority),
+            ("mx2.example.net", Some(20))
+        );
+
+        let srv = find(records, "_sip._tcp", &DnsRecordType::Srv);
+        assert_eq!(srv[0].value, "5 5060 sip.example.com");
+        assert_eq!(srv[0].priority, Some(10));
+
+        let caa = find(records, "@", &DnsRecordType::Caa);
+        assert_eq!(caa[0].value, "0 issue \"letsencrypt.org\"");
+
+        let txt = find(records, "long", &DnsRecordType::Txt);
+        assert_eq!(txt[0].value, "v=DKIM1; k=rsa; p=MIGf");
+        let quoted = find(records, "quoted", &DnsRecordType::Txt);
+        assert_eq!(quoted[0].value, "say \"hi\"");
+
+        let aaaa = find(records, "sub", &DnsRecordType::Aaaa);
+        assert_eq!(aaaa[0].ttl, 60);
+
+        assert_eq!(find(records, "api.dev", &DnsRecordType::A).len(), 1);
+    }
+
+    #[test]
+    fn test_parse_zone_file_skips_and_errors() {
+        let parsed = parse_zone_file(SAMPLE_ZONE, "example.com", "d1", false);
+
+        assert!(find(&parsed.records, "@", &DnsRecordType::Ns).is_empty());
+        assert_eq!(parsed.skipped.len(), 3, "{:?}", parsed.skipped);
+        assert!(parsed.skipped.iter().any(|s| s.contains("SOA")));
+        assert!(parsed.skipped.iter().any(|s| s.contains("HINFO")));
+
+        assert_eq!(parsed.errors.len(), 2, "{:?}", parsed.errors);
+        assert!(parsed.errors.iter().any(|e| e.contains("other.org")));
+        assert!(parsed.errors.iter().any(|e| e.contains("not-an-ip")));
+
+        let with_ns = parse_zone_file(SAMPLE_ZONE, "example.com", "d1", true);
+        let ns = find(&with_ns.records, "@", &DnsRecordType::Ns);
+        assert_eq!(ns[0].value, "ns1.provider.net");
+    }
+
+    #[test]
+    fn test_zone_file_round_trip() {
+        let records = vec![
+            record("@", DnsRecordType::Mx, "mx.example.com", Some(10)),
+            record("txt", DnsRecordType::Txt, "a \"quoted\" \\ value", None),
+            record(
+                "_sip._tcp",
+                DnsRecordType::Srv,
+                "5 5060 sip.example.com",
+                Some(1),
+            ),
+            record("@", DnsRecordType::Https, "1 . alpn=h3,h2", None),
+            record("@", DnsRecordType::Alias, "lb.example.net", None),
+            record(
+                "_dns",
+                DnsRecordType::Svcb,
+                "1 dns.example.com alpn=dot port=853",
+                None,
+            ),
+        ];
+        let zone = to_zone_file("example.com", &records);
+        let parsed = parse_zone_file(&zone, "example.com", "d1", false);
+
+        assert!(parsed.errors.is_empty(), "{:?}", parsed.errors);
+        assert_eq!(parsed.records.len(), records.len());
+        for (original, imported) in records.iter().zip(&parsed.records) {
+            assert_eq!(original.name, imported.name);
+            assert_eq!(original.value, imported.value);
+            assert_eq!(original.priority, imported.priority);
+            assert_eq!(original.ttl, imported.ttl);
+        }
+    }
+
+    #[test]
+    fn test_parse_ttl_units() {
+        assert_eq!(parse_ttl("300"), Some(300));
+        assert_eq!(parse_ttl("1h30m"), Some(5400));
+        assert_eq!(parse_ttl("1d"), Some(86_400));
+        assert_eq!(parse_ttl("IN"), None);
+        assert_eq!(parse_ttl("1x"), None);
+    }
+}
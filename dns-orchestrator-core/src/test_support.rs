@@ -0,0 +1,189 @@
+//! 测试辅助：内存版存储实现（Provider 使用 `MockProvider`）
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use tokio::sync::Mutex;
+
+use crate::error::CoreResult;
+use crate::traits::{
+    AccountRepository, CredentialStore, CredentialsMap, IdempotencyRepository, TrashRepository,
+};
+use crate::types::{Account, AccountStatus, IdempotencyEntry, TrashedRecord};
+
+/// 内存凭证存储
+#[derive(Default)]
+pub(crate) struct MemoryCredentialStore(pub(crate) Mutex<CredentialsMap>);
+
+#[async_trait]
+impl CredentialStore for MemoryCredentialStore {
+    async fn load_all(&self) -> CoreResult<CredentialsMap> {
+        Ok(self.0.lock().await.clone())
+    }
+
+    async fn save(
+        &self,
+        account_id: &str,
+        credentials: &HashMap<String, String>,
+    ) -> CoreResult<()> {
+        self.0
+            .lock()
+            .await
+            .insert(account_id.to_string(), credentials.clone());
+        Ok(())
+    }
+
+    async fn load(&self, account_id: &str) -> CoreResult<HashMap<String, String>> {
+        Ok(self
+            .0
+            .lock()
+            .await
+            .get(account_id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn delete(&self, account_id: &str) -> CoreResult<()> {
+        self.0.lock().await.remove(account_id);
+        Ok(())
+    }
+
+    async fn exists(&self, account_id: &str) -> CoreResult<bool> {
+        Ok(self.0.lock().await.contains_key(account_id))
+    }
+}
+
+/// 内存账户仓库
+#[derive(Default)]
+pub(crate) struct MemoryAccountRepository(pub(crate) Mutex<Vec<Account>>);
+
+#[async_trait]
+impl AccountRepository for MemoryAccountRepository {
+    async fn find_all(&self) -> CoreResult<Vec<Account>> {
+        Ok(self.0.lock().await.clone())
+    }
+
+    async fn find_by_id(&self, id: &str) -> CoreResult<Option<Account>> {
+        Ok(self.0.lock().await.iter().find(|a| a.id == id).cloned())
+    }
+
+    async fn save(&self, account: &Account) -> CoreResult<()> {
+        self.0.lock().await.push(account.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, id: &str) -> CoreResult<()> {
+        self.0.lock().await.retain(|a| a.id != id);
+        Ok(())
+    }
+
+    async fn save_all(&self, accounts: &[Account]) -> CoreResult<()> {
+        *self.0.lock().await = accounts.to_vec();
+        Ok(())
+    }
+
+    async fn update_status(
+        &self,
+        id: &str,
+        status: AccountStatus,
+        error: Option<String>,
+    ) -> CoreResult<()> {
+        if let Some(account) = self.0.lock().await.iter_mut().find(|a| a.id == id) {
+            account.status = Some(status);
+            account.error = error;
+        }
+        Ok(())
+    }
+}
+
+/// 内存回收站
+#[derive(Default)]
+pub(crate) struct MemoryTrashRepository(pub(crate) Mutex<Vec<TrashedRecord>>);
+
+#[async_trait]
+impl TrashRepository for MemoryTrashRepository {
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<TrashedRecord>> {
+        let mut entries: Vec<_> = self
+            .0
+            .lock()
+            .await
+            .iter()
+            .filter(|e| e.account_id == account_id)
+            .cloned()
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+        Ok(entries)
+    }
+
+    async fn save_all(&self, entries: &[TrashedRecord]) -> CoreResult<()> {
+        self.0.lock().await.extend_from_slice(entries);
+        Ok(())
+    }
+
+    async fn take(&self, account_id: &str, id: &str) -> CoreResult<Option<TrashedRecord>> {
+        let mut entries = self.0.lock().await;
+        let index = entries
+            .iter()
+            .position(|e| e.id == id && e.account_id == account_id);
+        Ok(index.map(|i| entries.remove(i)))
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize> {
+        let mut entries = self.0.lock().await;
+        let before = entries.len();
+        entries.retain(|e| e.deleted_at >= cutoff);
+        Ok(before - entries.len())
+    }
+}
+
+/// 内存幂等键仓库
+#[derive(Default)]
+pub(crate) struct MemoryIdempotencyRepository(pub(crate) Mutex<Vec<IdempotencyEntry>>);
+
+#[async_trait]
+impl IdempotencyRepository for MemoryIdempotencyRepository {
+    async fn find(&self, account_id: &str, key: &str) -> CoreResult<Option<IdempotencyEntry>> {
+        Ok(self
+            .0
+            .lock()
+            .await
+            .iter()
+            .find(|e| e.account_id == account_id && e.key == key)
+            .cloned())
+    }
+
+    async fn save(&self, entry: &IdempotencyEntry) -> CoreResult<()> {
+        let mut entries = self.0.lock().await;
+        entries.retain(|e| !(e.account_id == entry.account_id && e.key == entry.key));
+        entries.push(entry.clone());
+        Ok(())
+    }
+
+    async fn reserve(&self, entry: &IdempotencyEntry) -> CoreResult<Option<IdempotencyEntry>> {
+        let mut entries = self.0.lock().await;
+        if let Some(existing) = entries
+            .iter()
+            .find(|e| e.account_id == entry.account_id && e.key == entry.key)
+        {
+            return Ok(Some(existing.clone()));
+        }
+        entries.push(entry.clone());
+        Ok(None)
+    }
+
+    async fn remove(&self, account_id: &str, key: &str) -> CoreResult<()> {
+        self.0
+            .lock()
+            .await
+            .retain(|e| !(e.account_id == account_id && e.key == key));
+        Ok(())
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize> {
+        let mut entries = self.0.lock().await;
+        let before = entries.len();
+        entries.retain(|e| e.created_at >= cutoff);
+        Ok(before - entries.len())
+    }
+}
@@ -0,0 +1,47 @@
+//! 幂等键持久化抽象 Trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::error::CoreResult;
+use crate::types::IdempotencyEntry;
+
+/// 幂等键结果仓库 Trait
+///
+/// 平台实现:
+/// - Tauri: `TauriIdempotencyRepository` (tauri-plugin-store)
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// 查找账户下的幂等键
+    ///
+    /// # Arguments
+    /// * `account_id` - 账户 ID
+    /// * `key` - 幂等键
+    async fn find(&self, account_id: &str, key: &str) -> CoreResult<Option<IdempotencyEntry>>;
+
+    /// 保存幂等键及其结果（同一账户下的相同键会被覆盖）
+    ///
+    /// # Arguments
+    /// * `entry` - 幂等键记录
+    async fn save(&self, entry: &IdempotencyEntry) -> CoreResult<()>;
+
+    /// 预占幂等键：账户下不存在该键时写入 `entry` 并返回 `None`，
+    /// 否则不写入并返回已有的记录。检查与写入必须是原子的
+    ///
+    /// # Arguments
+    /// * `entry` - 执行中的幂等键记录（`response` 为 `None`）
+    async fn reserve(&self, entry: &IdempotencyEntry) -> CoreResult<Option<IdempotencyEntry>>;
+
+    /// 删除幂等键（操作失败时释放预占）
+    ///
+    /// # Arguments
+    /// * `account_id` - 账户 ID
+    /// * `key` - 幂等键
+    async fn remove(&self, account_id: &str, key: &str) -> CoreResult<()>;
+
+    /// 清除早于指定时间的幂等键，返回清除数量
+    ///
+    /// # Arguments
+    /// * `cutoff` - 截止时间
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize>;
+}
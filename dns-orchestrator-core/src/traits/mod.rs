@@ -2,8 +2,12 @@
 
 mod account_repository;
 mod credential_store;
+mod idempotency_repository;
 mod provider_registry;
+mod trash_repository;
 
 pub use account_repository::AccountRepository;
 pub use credential_store::{CredentialStore, CredentialsMap};
-pub use provider_registry::{InMemoryProviderRegistry, ProviderRegistry};
+pub use idempotency_repository::IdempotencyRepository;
+pub use provider_registry::{ConcurrencyLimit, InMemoryProviderRegistry, ProviderRegistry};
+pub use trash_repository::TrashRepository;
@@ -3,9 +3,15 @@
 use async_trait::async_trait;
 use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use std::time::Duration;
+use tokio::sync::{RwLock, Semaphore, SemaphorePermit};
 
-use dns_orchestrator_provider::DnsProvider;
+use dns_orchestrator_provider::{
+    BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
+    CreateDnsRecordRequest, DnsProvider, DnsRecord, DnsRecordType, PaginatedResponse,
+    PaginationParams, ProviderDomain, ProviderError, RecordQueryParams, Result as ProviderResult,
+    UpdateDnsRecordRequest,
+};
 
 /// Provider 注册表 Trait
 ///
@@ -36,12 +42,43 @@ pub trait ProviderRegistry: Send + Sync {
     async fn list_account_ids(&self) -> Vec<String>;
 }
 
+/// 单账户并发限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConcurrencyLimit {
+    /// 单账户同时进行的最大请求数
+    pub max_in_flight: usize,
+    /// 超出上限时排队等待的最长时间
+    pub acquire_timeout: Duration,
+}
+
+impl ConcurrencyLimit {
+    /// 默认排队超时时间
+    pub const DEFAULT_ACQUIRE_TIMEOUT: Duration = Duration::from_secs(30);
+
+    /// 创建并发限制（使用默认排队超时）
+    #[must_use]
+    pub fn new(max_in_flight: usize) -> Self {
+        Self {
+            max_in_flight: max_in_flight.max(1),
+            acquire_timeout: Self::DEFAULT_ACQUIRE_TIMEOUT,
+        }
+    }
+
+    /// 设置排队超时时间
+    #[must_use]
+    pub fn with_acquire_timeout(mut self, acquire_timeout: Duration) -> Self {
+        self.acquire_timeout = acquire_timeout;
+        self
+    }
+}
+
 /// 内存实现的 Provider 注册表
 ///
 /// 默认实现，适用于所有平台。
 #[derive(Clone)]
 pub struct InMemoryProviderRegistry {
     providers: Arc<RwLock<HashMap<String, Arc<dyn DnsProvider>>>>,
+    concurrency_limit: Option<ConcurrencyLimit>,
 }
 
 impl InMemoryProviderRegistry {
@@ -50,8 +87,19 @@ impl InMemoryProviderRegistry {
     pub fn new() -> Self {
         Self {
             providers: Arc::new(RwLock::new(HashMap::new())),
+            concurrency_limit: None,
         }
     }
+
+    /// 设置单账户并发限制
+    ///
+    /// 之后注册的 Provider 会被包装，同一账户的所有操作共享一个有界的请求池；
+    /// 超出上限的请求排队等待，超时后返回错误。
+    #[must_use]
+    pub fn with_concurrency_limit(mut self, limit: ConcurrencyLimit) -> Self {
+        self.concurrency_limit = Some(limit);
+        self
+    }
 }
 
 impl Default for InMemoryProviderRegistry {
@@ -63,6 +111,10 @@ impl Default for InMemoryProviderRegistry {
 #[async_trait]
 impl ProviderRegistry for InMemoryProviderRegistry {
     async fn register(&self, account_id: String, provider: Arc<dyn DnsProvider>) {
+        let provider = match self.concurrency_limit {
+            Some(limit) => Arc::new(ConcurrencyLimitedProvider::new(provider, limit)),
+            None => provider,
+        };
         self.providers.write().await.insert(account_id, provider);
     }
 
@@ -78,3 +130,207 @@ impl ProviderRegistry for InMemoryProviderRegistry {
         self.providers.read().await.keys().cloned().collect()
     }
 }
+
+/// 限制并发请求数的 Provider 包装
+///
+/// 每次调用前获取信号量许可；批量操作与默认实现（如 `delete_rrset`）
+/// 作为一次调用计数，由内部 Provider 自行展开。
+struct ConcurrencyLimitedProvider {
+    inner: Arc<dyn DnsProvider>,
+    semaphore: Semaphore,
+    acquire_timeout: Duration,
+}
+
+impl ConcurrencyLimitedProvider {
+    fn new(inner: Arc<dyn DnsProvider>, limit: ConcurrencyLimit) -> Self {
+        Self {
+            inner,
+            semaphore: Semaphore::new(limit.max_in_flight),
+            acquire_timeout: limit.acquire_timeout,
+        }
+    }
+
+    /// 获取许可，超时返回 `NetworkError`
+    async fn acquire(&self) -> ProviderResult<SemaphorePermit<'_>> {
+        match tokio::time::timeout(self.acquire_timeout, self.semaphore.acquire()).await {
+            Ok(Ok(permit)) => Ok(permit),
+            // 信号量不会被关闭，此分支仅为完整性
+            Ok(Err(_)) | Err(_) => Err(ProviderError::NetworkError {
+                provider: self.inner.id().to_string(),
+                detail: format!(
+                    "并发请求排队超时（{} 秒）",
+                    self.acquire_timeout.as_secs_f64()
+                ),
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for ConcurrencyLimitedProvider {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    async fn validate_credentials(&self) -> ProviderResult<bool> {
+        let _permit = self.acquire().await?;
+        self.inner.validate_credentials().await
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> ProviderResult<PaginatedResponse<ProviderDomain>> {
+        let _permit = self.acquire().await?;
+        self.inner.list_domains(params).await
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> ProviderResult<ProviderDomain> {
+        let _permit = self.acquire().await?;
+        self.inner.get_domain(domain_id).await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> ProviderResult<PaginatedResponse<DnsRecord>> {
+        let _permit = self.acquire().await?;
+        self.inner.list_records(domain_id, params).await
+    }
+
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> ProviderResult<DnsRecord> {
+        let _permit = self.acquire().await?;
+        self.inner.get_record(record_id, domain_id).await
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> ProviderResult<DnsRecord> {
+        let _permit = self.acquire().await?;
+        self.inner.create_record(req).await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> ProviderResult<DnsRecord> {
+        let _permit = self.acquire().await?;
+        self.inner.update_record(record_id, req).await
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> ProviderResult<()> {
+        let _permit = self.acquire().await?;
+        self.inner.delete_record(record_id, domain_id).await
+    }
+
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> ProviderResult<()> {
+        let _permit = self.acquire().await?;
+        self.inner.delete_rrset(domain_id, name, record_type).await
+    }
+
+    async fn batch_create_records(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+    ) -> ProviderResult<BatchCreateResult> {
+        let _permit = self.acquire().await?;
+        self.inner.batch_create_records(requests).await
+    }
+
+    async fn batch_update_records(
+        &self,
+        updates: &[BatchUpdateItem],
+    ) -> ProviderResult<BatchUpdateResult> {
+        let _permit = self.acquire().await?;
+        self.inner.batch_update_records(updates).await
+    }
+
+    async fn batch_delete_records(
+        &self,
+        domain_id: &str,
+        record_ids: &[String],
+    ) -> ProviderResult<BatchDeleteResult> {
+        let _permit = self.acquire().await?;
+        self.inner.batch_delete_records(domain_id, record_ids).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dns_orchestrator_provider::{Interceptor, MockProvider, ProviderLayer, ProviderOperation};
+    use std::future::Future;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// 记录最大并发数的装饰层，每次调用额外等待 10ms 以制造并发
+    #[derive(Clone, Default)]
+    struct ConcurrencyProbe {
+        in_flight: Arc<AtomicUsize>,
+        max_seen: Arc<AtomicUsize>,
+    }
+
+    impl Interceptor for ConcurrencyProbe {
+        async fn intercept<T, F, Fut>(
+            &self,
+            _provider: &'static str,
+            _operation: ProviderOperation,
+            mut call: F,
+        ) -> ProviderResult<T>
+        where
+            T: Send,
+            F: FnMut() -> Fut + Send,
+            Fut: Future<Output = ProviderResult<T>> + Send,
+        {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_seen.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            let result = call().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            result
+        }
+    }
+
+    fn probed_provider(probe: &ConcurrencyProbe) -> Arc<dyn DnsProvider> {
+        probe.layer(Arc::new(MockProvider::empty(None)))
+    }
+
+    #[tokio::test]
+    async fn concurrency_never_exceeds_limit() {
+        let probe = ConcurrencyProbe::default();
+        let registry =
+            InMemoryProviderRegistry::new().with_concurrency_limit(ConcurrencyLimit::new(3));
+        registry
+            .register("acc".to_string(), probed_provider(&probe))
+            .await;
+        let provider = registry.get("acc").await.unwrap();
+
+        let calls = (0..20).map(|_| provider.validate_credentials());
+        let results = futures::future::join_all(calls).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(probe.max_seen.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn queued_request_times_out() {
+        let probe = ConcurrencyProbe::default();
+        let limit = ConcurrencyLimit::new(1).with_acquire_timeout(Duration::from_millis(1));
+        let registry = InMemoryProviderRegistry::new().with_concurrency_limit(limit);
+        registry
+            .register("acc".to_string(), probed_provider(&probe))
+            .await;
+        let provider = registry.get("acc").await.unwrap();
+
+        let (first, second) = tokio::join!(
+            provider.validate_credentials(),
+            provider.validate_credentials()
+        );
+
+        assert!(first.is_ok());
+        assert!(matches!(second, Err(ProviderError::NetworkError { .. })));
+    }
+}
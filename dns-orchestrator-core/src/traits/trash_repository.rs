@@ -0,0 +1,42 @@
+//! 回收站持久化抽象 Trait
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::error::CoreResult;
+use crate::types::TrashedRecord;
+
+/// 已删除记录快照仓库 Trait
+///
+/// 平台实现:
+/// - Tauri: `TauriTrashRepository` (tauri-plugin-store)
+#[async_trait]
+pub trait TrashRepository: Send + Sync {
+    /// 获取账户下的全部快照（按删除时间倒序）
+    ///
+    /// # Arguments
+    /// * `account_id` - 账户 ID
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<TrashedRecord>>;
+
+    /// 保存快照
+    ///
+    /// # Arguments
+    /// * `entries` - 快照列表
+    async fn save_all(&self, entries: &[TrashedRecord]) -> CoreResult<()>;
+
+    /// 原子地取出账户下的快照（查找与移除须在同一临界区内完成），
+    /// 快照不存在或属于其他账户时返回 `None`
+    ///
+    /// 并发恢复同一快照时只有一个调用方能取到
+    ///
+    /// # Arguments
+    /// * `account_id` - 账户 ID
+    /// * `id` - 快照 ID
+    async fn take(&self, account_id: &str, id: &str) -> CoreResult<Option<TrashedRecord>>;
+
+    /// 清除早于指定时间删除的快照，返回清除数量
+    ///
+    /// # Arguments
+    /// * `cutoff` - 截止时间
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize>;
+}
@@ -39,6 +39,9 @@ pub struct Account {
     /// 错误信息（状态为 Error 时）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// 分组标签（旧数据无此字段时为空）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 /// 创建账户请求
@@ -62,3 +65,61 @@ pub struct UpdateAccountRequest {
     /// 新的凭证（可选，提供时会覆盖原有凭证）
     pub credentials: Option<HashMap<String, String>>,
 }
+
+/// 凭证连通性测试结果（不创建账户）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CredentialTestResult {
+    /// 凭证是否可用
+    pub valid: bool,
+    /// 账户下的域名数量（请求检查读取权限且成功时）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_count: Option<u32>,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 单个账户的凭证验证结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountVerifyResult {
+    /// 账户 ID
+    pub account_id: String,
+    /// 凭证是否有效
+    pub valid: bool,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批量验证所有账户凭证的汇总
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VerifyAllAccountsResult {
+    /// 验证的账户数（账户仓库中的全部账户）
+    pub total: usize,
+    /// 凭证有效的账户数
+    pub active: usize,
+    /// 验证失败的账户数，等于 `invalid + unreachable + unregistered`
+    pub errored: usize,
+    /// 凭证被服务商拒绝的账户数（状态已更新为 Error）
+    pub invalid: usize,
+    /// 因网络等临时错误无法验证的账户数（状态保持不变）
+    pub unreachable: usize,
+    /// 没有已注册 Provider 的账户数（启动时凭证加载或 Provider 创建失败）
+    pub unregistered: usize,
+    /// 各账户的验证结果（按账户 ID 排序）
+    pub results: Vec<AccountVerifyResult>,
+}
+
+impl CredentialTestResult {
+    /// 构造失败结果
+    pub fn failed(error: &impl ToString) -> Self {
+        Self {
+            valid: false,
+            domain_count: None,
+            error: Some(error.to_string()),
+        }
+    }
+}
@@ -0,0 +1,655 @@
+//! DNS 记录更新、统计、搜索与区域文件相关类型定义
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use dns_orchestrator_provider::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, PaginatedResponse, UpdateDnsRecordRequest,
+};
+
+use super::AppDomain;
+
+/// 部分更新 DNS 记录请求（未提供的字段保留现有值）
+///
+/// 可选属性（优先级、线路、权重、备注、标签）显式传 `null` 时清除；
+/// 修改记录类型且未提供优先级时，不沿用原记录的优先级
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatchDnsRecordRequest {
+    /// 域名 ID
+    pub domain_id: String,
+    /// 记录类型
+    #[serde(rename = "type")]
+    pub record_type: Option<DnsRecordType>,
+    /// 记录名
+    pub name: Option<String>,
+    /// 记录值
+    pub value: Option<String>,
+    /// TTL
+    pub ttl: Option<u32>,
+    /// 优先级（MX/SRV）
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub priority: Option<Option<u16>>,
+    /// 是否代理（Cloudflare）
+    pub proxied: Option<bool>,
+    /// 解析线路（阿里云/DNSPod），`null` 恢复默认线路
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub line: Option<Option<String>>,
+    /// 记录权重
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub weight: Option<Option<u16>>,
+    /// 记录备注（Cloudflare）
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub comment: Option<Option<String>>,
+    /// 记录标签（Cloudflare）
+    #[serde(
+        default,
+        deserialize_with = "double_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tags: Option<Option<Vec<String>>>,
+    /// 乐观并发：读取记录时的版本
+    pub expected_version: Option<String>,
+    /// 乐观并发：读取记录时的值
+    pub expected_value: Option<String>,
+}
+
+impl PatchDnsRecordRequest {
+    /// 将提供的字段覆盖到现有记录上，生成完整的更新请求
+    #[must_use]
+    pub fn merge_into(self, existing: DnsRecord) -> UpdateDnsRecordRequest {
+        // 优先级的含义随类型而定，类型变化时不沿用
+        let type_changed = self
+            .record_type
+            .as_ref()
+            .is_some_and(|t| *t != existing.record_type);
+        let existing_priority = if type_changed {
+            None
+        } else {
+            existing.priority
+        };
+
+        UpdateDnsRecordRequest {
+            domain_id: self.domain_id,
+            record_type: self.record_type.unwrap_or(existing.record_type),
+            name: self.name.unwrap_or(existing.name),
+            value: self.value.unwrap_or(existing.value),
+            ttl: self.ttl.unwrap_or(existing.ttl),
+            priority: self.priority.unwrap_or(existing_priority),
+            proxied: self.proxied.or(existing.proxied),
+            line: self.line.unwrap_or(existing.line),
+            weight: self.weight.unwrap_or(existing.weight),
+            comment: self.comment.unwrap_or(existing.comment),
+            tags: self.tags.unwrap_or(existing.tags),
+            expected_version: self.expected_version,
+            expected_value: self.expected_value,
+        }
+    }
+}
+
+/// 区分“字段未提供”（外层 `None`）与“显式传 `null`”（`Some(None)`）
+#[allow(clippy::option_option)]
+fn double_option<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Zone 内记录类型统计
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneRecordTypeSummary {
+    /// 域名 ID
+    pub domain_id: String,
+    /// 记录总数
+    pub total: u32,
+    /// 各记录类型的数量（键为类型名，如 `A`、`CNAME`）
+    pub by_type: HashMap<String, u32>,
+}
+
+/// Zone 统计（仪表盘用）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneStats {
+    /// 记录总数
+    pub total: u32,
+    /// 各记录类型的数量（键为类型名，如 `A`、`CNAME`）
+    pub by_type: HashMap<String, u32>,
+    /// 开启代理的记录数（仅 Cloudflare 返回代理状态，其他服务商为 0）
+    pub proxied: u32,
+}
+
+/// 两个区域之间的记录差异（记录按名称、类型、值和优先级匹配）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneDiff {
+    /// 仅存在于源区域的记录
+    pub only_in_source: Vec<DnsRecord>,
+    /// 仅存在于目标区域的记录
+    pub only_in_target: Vec<DnsRecord>,
+    /// 两侧都存在但 TTL 或代理状态不同的记录
+    pub differing: Vec<RecordDifference>,
+}
+
+/// 两侧匹配但属性不同的记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordDifference {
+    /// 源区域中的记录
+    pub source: DnsRecord,
+    /// 目标区域中的记录
+    pub target: DnsRecord,
+}
+
+/// 克隆区域选项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CloneZoneOptions {
+    /// 目标区域已有同名同类型记录时，删除其中源区域没有的值并同步 TTL
+    pub overwrite_existing: bool,
+    /// 保留源记录的 TTL；为 false 时使用 [`CloneZoneOptions::ttl`]
+    pub preserve_ttl: bool,
+    /// 不保留 TTL 时统一使用的 TTL（秒）
+    pub ttl: u32,
+    /// 是否复制顶点 NS 记录（默认跳过，由目标服务商管理）
+    pub include_apex_ns: bool,
+}
+
+impl Default for CloneZoneOptions {
+    fn default() -> Self {
+        Self {
+            overwrite_existing: false,
+            preserve_ttl: true,
+            ttl: 600,
+            include_apex_ns: false,
+        }
+    }
+}
+
+/// 克隆区域时单条记录的处理状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CloneRecordStatus {
+    /// 已在目标区域创建
+    Created,
+    /// 目标区域已存在，已同步 TTL
+    Updated,
+    /// 目标区域中被覆盖删除
+    Deleted,
+    /// 跳过（已存在或顶点 NS）
+    Skipped,
+    /// 失败
+    Failed,
+}
+
+/// 克隆区域时单条记录的处理结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneRecordResult {
+    /// 记录名称（相对域名）
+    pub name: String,
+    /// 记录类型
+    pub record_type: DnsRecordType,
+    /// 记录值
+    pub value: String,
+    /// 处理状态
+    pub status: CloneRecordStatus,
+    /// 跳过或失败的原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// 克隆区域结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloneZoneResult {
+    /// 新建的记录数
+    pub created_count: usize,
+    /// 同步 TTL 的记录数
+    pub updated_count: usize,
+    /// 覆盖删除的记录数
+    pub deleted_count: usize,
+    /// 跳过的记录数
+    pub skipped_count: usize,
+    /// 失败的记录数
+    pub failed_count: usize,
+    /// 逐条处理结果
+    pub results: Vec<CloneRecordResult>,
+}
+
+impl CloneZoneResult {
+    /// 追加一条结果并更新计数
+    pub(crate) fn push(&mut self, result: CloneRecordResult) {
+        match result.status {
+            CloneRecordStatus::Created => self.created_count += 1,
+            CloneRecordStatus::Updated => self.updated_count += 1,
+            CloneRecordStatus::Deleted => self.deleted_count += 1,
+            CloneRecordStatus::Skipped => self.skipped_count += 1,
+            CloneRecordStatus::Failed => self.failed_count += 1,
+        }
+        self.results.push(result);
+    }
+}
+
+/// 跨域名记录搜索条件（各条件为 AND 关系，字符串匹配不区分大小写）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSearchQuery {
+    /// 记录值包含
+    pub value_contains: Option<String>,
+    /// 记录名包含
+    pub name_contains: Option<String>,
+    /// 记录类型
+    pub record_type: Option<DnsRecordType>,
+}
+
+impl RecordSearchQuery {
+    /// 记录是否满足搜索条件
+    #[must_use]
+    pub fn matches(&self, record: &DnsRecord) -> bool {
+        let contains = |haystack: &str, needle: &Option<String>| {
+            needle
+                .as_deref()
+                .is_none_or(|n| haystack.to_lowercase().contains(&n.to_lowercase()))
+        };
+
+        self.record_type
+            .as_ref()
+            .is_none_or(|t| *t == record.record_type)
+            && contains(&record.value, &self.value_contains)
+            && contains(&record.name, &self.name_contains)
+    }
+}
+
+/// 跨域名搜索命中的记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSearchMatch {
+    /// 所属域名
+    pub domain_name: String,
+    /// 记录（包含 `domainId`）
+    #[serde(flatten)]
+    pub record: DnsRecord,
+}
+
+/// 批量替换记录值请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceRecordValueRequest {
+    /// 域名 ID（为空时替换账号下全部域名）
+    #[serde(default)]
+    pub domain_id: Option<String>,
+    /// 记录类型
+    pub record_type: DnsRecordType,
+    /// 要替换的旧值
+    pub from_value: String,
+    /// 新值
+    pub to_value: String,
+}
+
+/// 批量替换记录值结果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceRecordValueResult {
+    /// 成功更新的数量（预演时为将被更新的数量）
+    pub changed_count: usize,
+    /// 失败的数量
+    pub failed_count: usize,
+    /// 失败详情
+    pub failures: Vec<ReplaceRecordValueFailure>,
+    /// 是否为预演（未实际更新）
+    #[serde(default)]
+    pub dry_run: bool,
+    /// 预演时将被更新的记录
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<RecordSearchMatch>,
+}
+
+/// 批量替换记录值失败项
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceRecordValueFailure {
+    /// 所属域名
+    pub domain_name: String,
+    /// 记录 ID
+    pub record_id: String,
+    /// 记录名
+    pub name: String,
+    /// 失败原因
+    pub reason: String,
+}
+
+/// 服务商自检步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SelfTestStepKind {
+    /// 创建测试记录
+    Create,
+    /// 读取并核对测试记录
+    Read,
+    /// 更新测试记录的值
+    Update,
+    /// 删除测试记录
+    Delete,
+}
+
+/// 服务商自检单个步骤的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestStep {
+    /// 步骤
+    pub step: SelfTestStepKind,
+    /// 是否通过
+    pub passed: bool,
+    /// 耗时（毫秒）
+    pub duration_ms: u64,
+    /// 失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 服务商自检报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderSelfTestReport {
+    /// 服务商标识
+    pub provider: String,
+    /// 域名 ID
+    pub domain_id: String,
+    /// 测试记录名
+    pub record_name: String,
+    /// 全部步骤是否通过
+    pub passed: bool,
+    /// 各步骤结果（前置步骤失败时后续步骤不执行，删除步骤除外）
+    pub steps: Vec<SelfTestStep>,
+}
+
+/// 账号概览：域名列表及每个域名的首页记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountOverview {
+    /// 账号 ID
+    pub account_id: String,
+    /// 各域名及其首页记录（与域名列表顺序一致，拉取失败的域名不在其中）
+    pub domains: Vec<DomainRecordsPreview>,
+    /// 拉取记录失败的域名
+    pub partial_errors: Vec<OverviewPartialError>,
+}
+
+/// 单个域名的首页记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainRecordsPreview {
+    /// 域名
+    pub domain: AppDomain,
+    /// 首页记录
+    pub records: PaginatedResponse<DnsRecord>,
+}
+
+/// 账号概览中单个域名的拉取错误
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewPartialError {
+    /// 域名 ID
+    pub domain_id: String,
+    /// 域名名称
+    pub domain_name: String,
+    /// 错误信息
+    pub message: String,
+}
+
+/// 回收站中的已删除记录快照
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedRecord {
+    /// 快照 ID
+    pub id: String,
+    /// 所属账户 ID
+    pub account_id: String,
+    /// 删除时的完整记录（包含 `domainId`）
+    pub record: DnsRecord,
+    /// 删除时间
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// 幂等键及其首次执行的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdempotencyEntry {
+    /// 所属账户 ID
+    pub account_id: String,
+    /// 客户端提供的幂等键
+    pub key: String,
+    /// 操作名（如 `create_dns_record`）
+    pub operation: String,
+    /// 请求参数的 SHA-256 摘要，同一个键不能用于不同的请求
+    #[serde(default)]
+    pub request_hash: String,
+    /// 序列化后的执行结果，`None` 表示首次请求仍在执行
+    #[serde(default)]
+    pub response: Option<serde_json::Value>,
+    /// 首次执行时间
+    pub created_at: DateTime<Utc>,
+}
+
+/// 导出的 BIND 区域文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFileExport {
+    /// 建议文件名（如 `example.com.zone`）
+    pub filename: String,
+    /// 区域文件内容
+    pub content: String,
+    /// 导出的记录数
+    pub record_count: usize,
+}
+
+/// BIND 区域文件导入结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFileImportResult {
+    /// 成功创建的数量
+    pub success_count: usize,
+    /// 失败的数量（含无法解析的行）
+    pub failed_count: usize,
+    /// 创建成功的记录
+    pub created: Vec<DnsRecord>,
+    /// 失败原因
+    pub errors: Vec<String>,
+    /// 跳过的记录（SOA、顶点 NS、不支持的类型等）
+    pub skipped: Vec<String>,
+    /// 是否为预演（未实际创建）
+    #[serde(default)]
+    pub dry_run: bool,
+    /// 预演时将被创建的记录
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub planned: Vec<CreateDnsRecordRequest>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(name: &str, record_type: DnsRecordType, value: &str) -> DnsRecord {
+        DnsRecord {
+            id: "1".to_string(),
+            domain_id: "d1".to_string(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_patch_ttl_only() {
+        let mut existing = record("www", DnsRecordType::A, "192.0.2.1");
+        existing.proxied = Some(true);
+        existing.line = Some("电信".to_string());
+        existing.comment = Some("legacy".to_string());
+
+        let patch = PatchDnsRecordRequest {
+            domain_id: "d1".to_string(),
+            ttl: Some(3600),
+            ..Default::default()
+        };
+        let merged = patch.merge_into(existing);
+
+        assert_eq!(merged.ttl, 3600);
+        assert_eq!(merged.name, "www");
+        assert_eq!(merged.record_type, DnsRecordType::A);
+        assert_eq!(merged.value, "192.0.2.1");
+        assert_eq!(merged.proxied, Some(true));
+        assert_eq!(merged.line.as_deref(), Some("电信"));
+        assert_eq!(merged.comment.as_deref(), Some("legacy"));
+    }
+
+    #[test]
+    fn test_patch_clears_line_with_null() {
+        let mut existing = record("www", DnsRecordType::A, "192.0.2.1");
+        existing.line = Some("电信".to_string());
+
+        let keep: PatchDnsRecordRequest =
+            serde_json::from_str(r#"{"domainId":"d1","ttl":300}"#).unwrap();
+        assert_eq!(
+            keep.merge_into(existing.clone()).line.as_deref(),
+            Some("电信")
+        );
+
+        let clear: PatchDnsRecordRequest =
+            serde_json::from_str(r#"{"domainId":"d1","line":null}"#).unwrap();
+        assert_eq!(clear.merge_into(existing.clone()).line, None);
+
+        let change: PatchDnsRecordRequest =
+            serde_json::from_str(r#"{"domainId":"d1","line":"联通"}"#).unwrap();
+        assert_eq!(change.merge_into(existing).line.as_deref(), Some("联通"));
+    }
+
+    #[test]
+    fn test_patch_value_only() {
+        let mut existing = record("@", DnsRecordType::Mx, "mx1.example.com");
+        existing.priority = Some(10);
+
+        let patch = PatchDnsRecordRequest {
+            domain_id: "d1".to_string(),
+            value: Some("mx2.example.com".to_string()),
+            ..Default::default()
+        };
+        let merged = patch.merge_into(existing);
+
+        assert_eq!(merged.value, "mx2.example.com");
+        assert_eq!(merged.ttl, 600);
+        assert_eq!(merged.priority, Some(10));
+        assert_eq!(merged.name, "@");
+    }
+
+    #[test]
+    fn test_patch_type_change_drops_priority() {
+        let mut existing = record("mail", DnsRecordType::Mx, "mx1.example.com");
+        existing.priority = Some(10);
+
+        let patch = PatchDnsRecordRequest {
+            domain_id: "d1".to_string(),
+            record_type: Some(DnsRecordType::Cname),
+            ..Default::default()
+        };
+        assert_eq!(patch.merge_into(existing.clone()).priority, None);
+
+        // 同时提供新优先级时使用新值
+        let patch = PatchDnsRecordRequest {
+            domain_id: "d1".to_string(),
+            record_type: Some(DnsRecordType::Srv),
+            priority: Some(Some(5)),
+            ..Default::default()
+        };
+        assert_eq!(patch.merge_into(existing.clone()).priority, Some(5));
+
+        // 类型未变化时保留
+        let patch = PatchDnsRecordRequest {
+            domain_id: "d1".to_string(),
+            record_type: Some(DnsRecordType::Mx),
+            ..Default::default()
+        };
+        assert_eq!(patch.merge_into(existing).priority, Some(10));
+    }
+
+    #[test]
+    fn test_patch_clears_optional_fields_with_null() {
+        let mut existing = record("www", DnsRecordType::A, "192.0.2.1");
+        existing.weight = Some(30);
+        existing.comment = Some("legacy".to_string());
+        existing.tags = Some(vec!["prod".to_string()]);
+
+        let clear: PatchDnsRecordRequest =
+            serde_json::from_str(r#"{"domainId":"d1","weight":null,"comment":null,"tags":null}"#)
+                .unwrap();
+        let merged = clear.merge_into(existing.clone());
+        assert_eq!(merged.weight, None);
+        assert_eq!(merged.comment, None);
+        assert_eq!(merged.tags, None);
+
+        let keep: PatchDnsRecordRequest = serde_json::from_str(r#"{"domainId":"d1"}"#).unwrap();
+        let merged = keep.merge_into(existing);
+        assert_eq!(merged.weight, Some(30));
+        assert_eq!(merged.comment.as_deref(), Some("legacy"));
+        assert_eq!(merged.tags, Some(vec!["prod".to_string()]));
+    }
+
+    #[test]
+    fn test_search_query_matches() {
+        let a = record("www", DnsRecordType::A, "192.0.2.1");
+        let cname = record("Blog", DnsRecordType::Cname, "example.github.io");
+
+        assert!(RecordSearchQuery::default().matches(&a));
+
+        let by_value = RecordSearchQuery {
+            value_contains: Some("192.0.2".to_string()),
+            ..Default::default()
+        };
+        assert!(by_value.matches(&a));
+        assert!(!by_value.matches(&cname));
+
+        let by_name = RecordSearchQuery {
+            name_contains: Some("blog".to_string()),
+            record_type: Some(DnsRecordType::Cname),
+            ..Default::default()
+        };
+        assert!(by_name.matches(&cname));
+        assert!(!by_name.matches(&a));
+
+        let wrong_type = RecordSearchQuery {
+            name_contains: Some("blog".to_string()),
+            record_type: Some(DnsRecordType::A),
+            ..Default::default()
+        };
+        assert!(!wrong_type.matches(&cname));
+    }
+}
@@ -1,5 +1,6 @@
 //! 域名相关类型定义
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use dns_orchestrator_provider::{DomainStatus, ProviderDomain, ProviderType};
@@ -21,6 +22,19 @@ pub struct AppDomain {
     /// DNS 记录数量
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
+    /// 权威 DNS 服务器（服务商未提供时为空）
+    #[serde(rename = "nameServers", default, skip_serializing_if = "Vec::is_empty")]
+    pub name_servers: Vec<String>,
+    /// 域名（Zone）创建时间
+    #[serde(rename = "createdAt", default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// 域名（Zone）最后修改时间
+    #[serde(
+        rename = "modifiedAt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub modified_at: Option<DateTime<Utc>>,
 }
 
 impl AppDomain {
@@ -34,6 +48,9 @@ impl AppDomain {
             provider: provider_domain.provider,
             status: provider_domain.status,
             record_count: provider_domain.record_count,
+            name_servers: provider_domain.name_servers,
+            created_at: provider_domain.created_at,
+            modified_at: provider_domain.modified_at,
         }
     }
 }
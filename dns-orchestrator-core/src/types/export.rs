@@ -6,6 +6,9 @@ use std::collections::HashMap;
 
 use dns_orchestrator_provider::ProviderType;
 
+use crate::crypto::KdfParams;
+use crate::error::{CoreError, CoreResult};
+
 /// 单个账号的导出数据（包含凭证）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -40,12 +43,31 @@ pub struct ExportFileHeader {
     /// 加密时使用的 IV/Nonce（Base64 编码）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub nonce: Option<String>,
+    /// 密钥派生算法及参数（含 PBKDF2 迭代次数）
+    ///
+    /// 导入时优先使用此值；缺省（Version 3 及更早导出的文件）时按版本号推断
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kdf: Option<KdfParams>,
     /// 导出时间
     pub exported_at: String,
     /// 应用版本
     pub app_version: String,
 }
 
+impl ExportFileHeader {
+    /// 计算文件头的附加认证数据（AAD）
+    ///
+    /// salt 与 nonce 在加密时才生成，且已通过密钥派生和 GCM 隐式认证，因此不纳入 AAD
+    pub(crate) fn associated_data(&self) -> CoreResult<Vec<u8>> {
+        let header = Self {
+            salt: None,
+            nonce: None,
+            ..self.clone()
+        };
+        serde_json::to_vec(&header).map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+}
+
 /// 完整的导出文件结构
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -90,10 +112,24 @@ pub struct ImportAccountsRequest {
     pub password: Option<String>,
 }
 
+/// 导入文件格式（按内容结构识别）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ImportFormat {
+    /// 本应用的导出文件（header + data）
+    Native,
+    /// 旧版 Web 端导出的 `salt||nonce||ciphertext` Base64 数据块
+    LegacyWeb,
+    /// 其他工具导出的通用 JSON 账号列表
+    GenericJson,
+}
+
 /// 导入预览（用于显示将要导入的账号）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportPreview {
+    /// 识别出的文件格式
+    pub format: ImportFormat,
     /// 文件是否加密
     pub encrypted: bool,
     /// 账号数量
@@ -121,7 +157,7 @@ pub struct ImportPreviewAccount {
 pub struct ImportResult {
     /// 成功导入的账号数量
     pub success_count: usize,
-    /// 失败的账号及原因
+    /// 失败的账号及原因（按导入文件中的顺序）
     pub failures: Vec<ImportFailure>,
 }
 
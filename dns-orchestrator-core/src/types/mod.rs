@@ -1,26 +1,46 @@
 //! 类型定义模块
 
 mod account;
+mod dns;
 mod domain;
 mod export;
+mod provider;
 mod response;
 mod toolbox;
 
-pub use account::{Account, AccountStatus, CreateAccountRequest, UpdateAccountRequest};
+pub use account::{
+    Account, AccountStatus, AccountVerifyResult, CreateAccountRequest, CredentialTestResult,
+    UpdateAccountRequest, VerifyAllAccountsResult,
+};
+pub use dns::{
+    AccountOverview, CloneRecordResult, CloneRecordStatus, CloneZoneOptions, CloneZoneResult,
+    DomainRecordsPreview, IdempotencyEntry, OverviewPartialError, PatchDnsRecordRequest,
+    ProviderSelfTestReport, RecordDifference, RecordSearchMatch, RecordSearchQuery,
+    ReplaceRecordValueFailure, ReplaceRecordValueRequest, ReplaceRecordValueResult, SelfTestStep,
+    SelfTestStepKind, TrashedRecord, ZoneDiff, ZoneFileExport, ZoneFileImportResult,
+    ZoneRecordTypeSummary, ZoneStats,
+};
 pub use domain::AppDomain;
 pub use export::{
     ExportAccountsRequest, ExportAccountsResponse, ExportFile, ExportFileHeader, ExportedAccount,
-    ImportAccountsRequest, ImportFailure, ImportPreview, ImportPreviewAccount, ImportResult,
+    ImportAccountsRequest, ImportFailure, ImportFormat, ImportPreview, ImportPreviewAccount,
+    ImportResult,
+};
+pub use provider::{ProviderCapabilities, ProviderCapabilityMatrix};
+pub use response::{
+    ApiResponse, BatchCreateRequest, BatchCreateResult, BatchDeleteFailure, BatchDeleteRequest,
+    BatchDeleteResult, CompactPage, ListResponse,
 };
-pub use response::{ApiResponse, BatchDeleteFailure, BatchDeleteRequest, BatchDeleteResult};
 pub use toolbox::{
-    CertChainItem, DnsLookupRecord, DnsLookupResult, IpGeoInfo, IpLookupResult, SslCertInfo,
-    SslCheckResult, WhoisResult,
+    BlacklistCheckResult, BlacklistListing, CertChainItem, DelegationCheck, DnsLookupRecord,
+    DnsLookupResult, DnsPropagationResult, DomainExpiryInfo, DomainOverview, IpGeoInfo,
+    IpLookupResult, MxAddressCheck, MxCheckResult, MxHostCheck, OverviewSection, PortCheckResult,
+    PortStatus, PropagationServerResult, SslCertInfo, SslCheckResult, SslExpiryInfo, WhoisResult,
 };
 
 // Re-export provider 库的公共类型
 pub use dns_orchestrator_provider::{
     CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse,
-    PaginationParams, ProviderCredentials, ProviderDomain, ProviderMetadata, ProviderType,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    PaginationMode, PaginationParams, ProviderCredentials, ProviderDomain, ProviderFeatures,
+    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
@@ -0,0 +1,35 @@
+//! Provider 能力矩阵相关类型定义
+
+use serde::{Deserialize, Serialize};
+
+use dns_orchestrator_provider::{DnsRecordType, ProviderFeatures, ProviderType};
+
+/// 单个服务商的能力
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderCapabilities {
+    /// 服务商类型
+    pub provider: ProviderType,
+    /// 服务商名称
+    pub name: String,
+    /// 支持的记录类型
+    pub supported_record_types: Vec<DnsRecordType>,
+    /// 最小 TTL（秒）
+    pub min_ttl: u32,
+    /// 最大 TTL（秒）
+    pub max_ttl: u32,
+    /// 省略 TTL 时的默认值（秒）
+    pub default_ttl: u32,
+    /// 功能支持
+    pub features: ProviderFeatures,
+}
+
+/// 全部服务商的能力矩阵
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProviderCapabilityMatrix {
+    /// 所有服务商支持的记录类型并集（矩阵列）
+    pub record_types: Vec<DnsRecordType>,
+    /// 各服务商能力（矩阵行）
+    pub providers: Vec<ProviderCapabilities>,
+}
@@ -2,6 +2,12 @@
 
 use serde::{Deserialize, Serialize};
 
+use dns_orchestrator_provider::{
+    BatchCreateFailure, CreateDnsRecordRequest, DnsRecord, PaginatedResponse,
+};
+
+use crate::error::{CoreError, CoreResult};
+
 /// API 响应包装类型
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApiResponse<T> {
@@ -22,6 +28,127 @@ impl<T> ApiResponse<T> {
     }
 }
 
+/// 紧凑（列式）分页响应
+///
+/// 用于大列表减少传输体积：字段名只在 `columns` 中出现一次，`rows` 中每行按
+/// `columns` 的顺序给出字段值，缺失字段为 `null`。例如：
+///
+/// ```json
+/// {
+///   "columns": ["id", "type", "name", "value"],
+///   "rows": [["1", "A", "www", "1.1.1.1"], ["2", "MX", "@", "mx.example.com"]],
+///   "page": 1, "pageSize": 20, "totalCount": 2, "hasMore": false
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactPage {
+    /// 列名（与完整格式的字段名一致）
+    pub columns: Vec<String>,
+    /// 值行
+    pub rows: Vec<Vec<serde_json::Value>>,
+    /// 当前页码
+    pub page: u32,
+    /// 每页数量
+    pub page_size: u32,
+    /// 总数
+    pub total_count: u32,
+    /// 是否有下一页
+    pub has_more: bool,
+    /// 下一页游标（仅游标分页模式返回）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl CompactPage {
+    /// 将完整分页响应转换为列式格式（列顺序按字段首次出现的顺序）
+    pub fn from_paginated<T: Serialize>(response: &PaginatedResponse<T>) -> CoreResult<Self> {
+        let objects = response
+            .items
+            .iter()
+            .map(|item| match serde_json::to_value(item) {
+                Ok(serde_json::Value::Object(map)) => Ok(map),
+                Ok(_) => Err(CoreError::SerializationError(
+                    "紧凑格式仅支持对象列表".to_string(),
+                )),
+                Err(e) => Err(CoreError::SerializationError(e.to_string())),
+            })
+            .collect::<CoreResult<Vec<_>>>()?;
+
+        let mut columns: Vec<String> = Vec::new();
+        for key in objects.iter().flat_map(serde_json::Map::keys) {
+            if !columns.contains(key) {
+                columns.push(key.clone());
+            }
+        }
+
+        let rows = objects
+            .into_iter()
+            .map(|mut obj| {
+                columns
+                    .iter()
+                    .map(|c| obj.remove(c).unwrap_or(serde_json::Value::Null))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            columns,
+            rows,
+            page: response.page,
+            page_size: response.page_size,
+            total_count: response.total_count,
+            has_more: response.has_more,
+            next_cursor: response.next_cursor.clone(),
+        })
+    }
+}
+
+/// 列表响应：默认为完整格式，请求 `compact` 时为列式格式
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ListResponse<T> {
+    /// 完整格式
+    Full(PaginatedResponse<T>),
+    /// 紧凑列式格式
+    Compact(CompactPage),
+}
+
+impl<T: Serialize> ListResponse<T> {
+    /// 按请求的格式构建列表响应
+    pub fn new(response: PaginatedResponse<T>, compact: bool) -> CoreResult<Self> {
+        if compact {
+            Ok(Self::Compact(CompactPage::from_paginated(&response)?))
+        } else {
+            Ok(Self::Full(response))
+        }
+    }
+}
+
+/// 批量创建 DNS 记录请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateRequest {
+    /// 域名 ID
+    pub domain_id: String,
+    /// 待创建的记录（`domain_id` 以外层为准）
+    pub records: Vec<CreateDnsRecordRequest>,
+}
+
+/// 批量创建结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateResult {
+    /// 成功创建的数量
+    pub success_count: usize,
+    /// 失败的数量
+    pub failed_count: usize,
+    /// 创建成功的记录（与请求顺序一致）
+    pub created: Vec<DnsRecord>,
+    /// 失败项（`request_index` 为请求中的下标）
+    pub failures: Vec<BatchCreateFailure>,
+}
+
 /// 批量删除 DNS 记录请求
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -42,6 +169,12 @@ pub struct BatchDeleteResult {
     pub failed_count: usize,
     /// 失败详情
     pub failures: Vec<BatchDeleteFailure>,
+    /// 是否为预演（未实际删除）
+    #[serde(default)]
+    pub dry_run: bool,
+    /// 预演时将被删除的记录
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<DnsRecord>,
 }
 
 /// 批量删除失败项
@@ -53,3 +186,29 @@ pub struct BatchDeleteFailure {
     /// 失败原因
     pub reason: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_page_columns_and_rows() {
+        let items = vec![
+            serde_json::json!({ "id": "1", "type": "A" }),
+            serde_json::json!({ "id": "2", "type": "MX", "priority": 10 }),
+        ];
+        let page = CompactPage::from_paginated(&PaginatedResponse::new(items, 1, 20, 2)).unwrap();
+
+        assert_eq!(page.columns, ["id", "type", "priority"]);
+        assert_eq!(
+            page.rows[0],
+            [
+                serde_json::json!("1"),
+                serde_json::json!("A"),
+                serde_json::Value::Null
+            ]
+        );
+        assert_eq!(page.rows[1][2], serde_json::json!(10));
+        assert!(!page.has_more);
+    }
+}
@@ -24,6 +24,119 @@ pub struct WhoisResult {
     pub raw: String,
 }
 
+/// 域名到期检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainExpiryInfo {
+    /// 域名
+    pub domain: String,
+    /// 到期时间（RFC3339，无法解析时为原始字符串）
+    pub expiration_date: Option<String>,
+    /// 剩余天数
+    pub days_remaining: Option<i64>,
+    /// 是否在预警期内
+    pub is_expiring: bool,
+    /// 是否已过期
+    pub is_expired: bool,
+    /// 错误信息（查询失败或无到期信息时）
+    pub error: Option<String>,
+}
+
+/// SSL 证书到期检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SslExpiryInfo {
+    /// 原始检查目标
+    pub target: String,
+    /// 主机名
+    pub host: String,
+    /// 端口
+    pub port: u16,
+    /// 颁发者
+    pub issuer: Option<String>,
+    /// 有效期截止
+    pub valid_to: Option<String>,
+    /// 剩余天数
+    pub days_remaining: Option<i64>,
+    /// 是否在预警期内
+    pub is_expiring: bool,
+    /// 是否已过期
+    pub is_expired: bool,
+    /// 是否连接失败（与证书到期区分）
+    pub connection_failed: bool,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+/// 概览中的单项检查结果（成功时有 `data`，失败时有 `error`）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OverviewSection<T> {
+    /// 检查结果
+    pub data: Option<T>,
+    /// 错误信息
+    pub error: Option<String>,
+}
+
+impl<T> OverviewSection<T> {
+    /// 成功结果
+    pub fn ok(data: T) -> Self {
+        Self {
+            data: Some(data),
+            error: None,
+        }
+    }
+
+    /// 失败结果
+    pub fn err(error: impl Into<String>) -> Self {
+        Self {
+            data: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+impl<T> From<crate::error::CoreResult<T>> for OverviewSection<T> {
+    fn from(result: crate::error::CoreResult<T>) -> Self {
+        match result {
+            Ok(data) => Self::ok(data),
+            Err(e) => Self::err(e.to_string()),
+        }
+    }
+}
+
+/// NS 委派检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationCheck {
+    /// DNS 实际返回的 NS
+    pub dns_name_servers: Vec<String>,
+    /// WHOIS 登记的 NS
+    pub whois_name_servers: Vec<String>,
+    /// 两者是否一致（任一侧缺失时为 None）
+    pub consistent: Option<bool>,
+}
+
+/// 域名健康概览
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DomainOverview {
+    /// 域名
+    pub domain: String,
+    /// WHOIS 信息
+    pub whois: OverviewSection<WhoisResult>,
+    /// 距离到期天数
+    pub days_until_expiry: Option<i64>,
+    /// NS 委派检查
+    pub delegation: OverviewSection<DelegationCheck>,
+    /// SOA 记录
+    pub soa: OverviewSection<Vec<DnsLookupRecord>>,
+    /// 常用记录（A/AAAA/MX/TXT）
+    pub records: OverviewSection<Vec<DnsLookupRecord>>,
+    /// SSL 检查（443 端口）
+    pub ssl: OverviewSection<SslCheckResult>,
+}
+
 /// DNS 查询记录结果
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -50,6 +163,132 @@ pub struct DnsLookupResult {
     pub records: Vec<DnsLookupRecord>,
 }
 
+/// 单个公共 DNS 的传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PropagationServerResult {
+    /// DNS 服务商名称
+    pub name: String,
+    /// DNS 服务器地址
+    pub nameserver: String,
+    /// 所在地区
+    pub region: String,
+    /// 返回的记录
+    pub records: Vec<DnsLookupRecord>,
+    /// 响应耗时（毫秒）
+    pub response_time_ms: u64,
+    /// 查询失败或超时的错误信息
+    pub error: Option<String>,
+}
+
+/// DNS 传播检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsPropagationResult {
+    /// 查询的域名
+    pub domain: String,
+    /// 查询的记录类型
+    pub record_type: String,
+    /// 各 DNS 服务器的结果
+    pub results: Vec<PropagationServerResult>,
+    /// 所有服务器返回的结果是否一致
+    pub consistent: bool,
+}
+
+/// 单个端口的连通性
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortStatus {
+    /// 端口
+    pub port: u16,
+    /// 是否可连接
+    pub open: bool,
+    /// 建立连接耗时（毫秒，仅可连接时有值）
+    pub latency_ms: Option<u64>,
+    /// 连接失败原因
+    pub error: Option<String>,
+}
+
+/// 端口连通性检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortCheckResult {
+    /// 查询的主机
+    pub host: String,
+    /// 实际连接的 IP
+    pub ip: String,
+    /// 各端口结果（按端口升序）
+    pub results: Vec<PortStatus>,
+}
+
+/// MX 主机单个地址的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MxAddressCheck {
+    /// IP 地址
+    pub ip: String,
+    /// 反向解析得到的主机名
+    pub ptr: Vec<String>,
+    /// 正反向解析是否一致（PTR 主机名正向解析包含该 IP）
+    pub fcrdns_ok: bool,
+    /// 25 端口是否可连接（未检查时为空）
+    pub smtp_reachable: Option<bool>,
+}
+
+/// 单个 MX 主机的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MxHostCheck {
+    /// 邮件主机
+    pub exchange: String,
+    /// 优先级
+    pub preference: u16,
+    /// 各地址检查结果
+    pub addresses: Vec<MxAddressCheck>,
+    /// 所有地址均通过 `FCrDNS` 检查
+    pub fcrdns_ok: bool,
+    /// 主机无法检查的原因（Null MX、无地址等）
+    pub error: Option<String>,
+}
+
+/// MX 健康检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MxCheckResult {
+    /// 查询的域名
+    pub domain: String,
+    /// 各 MX 主机（按优先级升序）
+    pub hosts: Vec<MxHostCheck>,
+}
+
+/// 单个 IP 在单个黑名单中的检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlacklistListing {
+    /// 检查的 IP
+    pub ip: String,
+    /// 黑名单区域（如 `zen.spamhaus.org`）
+    pub zone: String,
+    /// 是否被列入
+    pub listed: bool,
+    /// 列入原因（黑名单 TXT 记录）
+    pub txt: Option<String>,
+    /// 查询失败原因（超时、查询被拒绝等）
+    pub error: Option<String>,
+}
+
+/// DNSBL 黑名单检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlacklistCheckResult {
+    /// 查询目标（IP 或域名）
+    pub target: String,
+    /// 目标是否为域名（域名会先解析 A 记录）
+    pub is_domain: bool,
+    /// 各 IP 在各黑名单中的结果
+    pub results: Vec<BlacklistListing>,
+}
+
 /// IP 地理位置信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -80,6 +319,8 @@ pub struct IpGeoInfo {
     pub asn: Option<String>,
     /// AS 名称
     pub as_name: Option<String>,
+    /// 反向解析主机名（PTR）
+    pub hostname: Option<String>,
 }
 
 /// IP 查询结果（支持域名解析多个 IP）
@@ -97,6 +338,7 @@ pub struct IpLookupResult {
 /// SSL 证书信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_excessive_bools)]
 pub struct SslCertInfo {
     /// 域名
     pub domain: String,
@@ -112,8 +354,14 @@ pub struct SslCertInfo {
     pub days_remaining: i64,
     /// 是否已过期
     pub is_expired: bool,
-    /// 是否有效
+    /// 是否有效（未过期、主机名匹配且证书链可信）
     pub is_valid: bool,
+    /// 查询的域名是否与 CN/SAN 匹配
+    pub hostname_matches: bool,
+    /// 证书链是否通过信任根校验
+    pub chain_trusted: bool,
+    /// 证书链校验失败原因
+    pub verification_error: Option<String>,
     /// 主题备用名称
     pub san: Vec<String>,
     /// 序列号
@@ -41,9 +41,8 @@ where
             // Unix 时间戳（自动判断秒/毫秒）
             parse_unix_timestamp(ts).ok_or_else(|| Error::custom("Invalid Unix timestamp"))
         }
-        TimestampOrString::U64(ts) => {
-            parse_unix_timestamp(ts as i64).ok_or_else(|| Error::custom("Invalid Unix timestamp"))
-        }
+        TimestampOrString::U64(ts) => parse_unix_timestamp(ts.cast_signed())
+            .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
     }
 }
 
@@ -82,7 +81,7 @@ pub mod option {
             Some(OptionalTimestamp::I64(ts)) => parse_unix_timestamp(ts)
                 .map(Some)
                 .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
-            Some(OptionalTimestamp::U64(ts)) => parse_unix_timestamp(ts as i64)
+            Some(OptionalTimestamp::U64(ts)) => parse_unix_timestamp(ts.cast_signed())
                 .map(Some)
                 .ok_or_else(|| Error::custom("Invalid Unix timestamp")),
             None => Ok(None),
@@ -0,0 +1,65 @@
+//! 国际化域名（IDN）转换
+//!
+//! 服务商 API 与 DNS 查询只接受 ASCII 域名：调用前用 [`to_ascii`] 转为 Punycode，
+//! 展示时用 [`to_unicode`] 还原。纯 ASCII 的名称（含 `@`、通配符）原样返回。
+
+use crate::error::{CoreError, CoreResult};
+
+/// Punycode 标签前缀
+const ACE_PREFIX: &str = "xn--";
+
+/// 将域名或记录名转换为 ASCII（Punycode）
+///
+/// # Errors
+/// 名称包含无法编码的字符时返回 `ValidationError`
+pub fn to_ascii(name: &str) -> CoreResult<String> {
+    if name.is_ascii() {
+        return Ok(name.to_string());
+    }
+    idna::domain_to_ascii(name)
+        .map_err(|_| CoreError::ValidationError(format!("无效的国际化域名: {name}")))
+}
+
+/// 将包含 Punycode 标签的名称还原为 Unicode（无法解码时原样返回）
+#[must_use]
+pub fn to_unicode(name: &str) -> String {
+    let has_ace_label = name.split('.').any(|label| {
+        label.len() >= ACE_PREFIX.len()
+            && label[..ACE_PREFIX.len()].eq_ignore_ascii_case(ACE_PREFIX)
+    });
+    if !has_ace_label {
+        return name.to_string();
+    }
+    match idna::domain_to_unicode(name) {
+        (unicode, Ok(())) => unicode,
+        (_, Err(_)) => name.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for (unicode, ascii) in [
+            ("例え.テスト", "xn--r8jz45g.xn--zckzah"),
+            ("bücher.example", "xn--bcher-kva.example"),
+            ("www.münchen.de.", "www.xn--mnchen-3ya.de."),
+            ("*.пример.рф", "*.xn--e1afmkfd.xn--p1ai"),
+        ] {
+            assert_eq!(to_ascii(unicode).unwrap(), ascii);
+            assert_eq!(to_unicode(ascii), unicode);
+        }
+    }
+
+    #[test]
+    fn test_ascii_names_unchanged() {
+        for name in ["@", "www", "Example.COM", "_sip._tcp.example.com"] {
+            assert_eq!(to_ascii(name).unwrap(), name);
+            assert_eq!(to_unicode(name), name);
+        }
+        // 非法 Punycode 原样返回
+        assert_eq!(to_unicode("xn--a.example"), "xn--a.example");
+    }
+}
@@ -1,3 +1,4 @@
 //! 工具模块
 
 pub mod datetime;
+pub mod idn;
@@ -61,6 +61,26 @@ pub enum ProviderError {
         raw_message: Option<String>,
     },
 
+    /// 记录在读取后已被修改（乐观并发检查失败）
+    Conflict {
+        provider: String,
+        record_id: String,
+        detail: String,
+    },
+
+    /// 记录内容与类型不符（发送前的本地校验）
+    InvalidRecord {
+        provider: String,
+        record_type: String,
+        detail: String,
+    },
+
+    /// 不支持的记录类型
+    UnsupportedRecordType {
+        provider: String,
+        record_type: String,
+    },
+
     /// 响应解析失败
     ParseError { provider: String, detail: String },
 
@@ -147,6 +167,29 @@ impl std::fmt::Display for ProviderError {
                     write!(f, "[{provider}] Permission denied")
                 }
             }
+            Self::Conflict {
+                provider,
+                record_id,
+                detail,
+            } => {
+                write!(
+                    f,
+                    "[{provider}] Record '{record_id}' was modified concurrently: {detail}"
+                )
+            }
+            Self::InvalidRecord {
+                provider,
+                record_type,
+                detail,
+            } => {
+                write!(f, "[{provider}] Invalid {record_type} record: {detail}")
+            }
+            Self::UnsupportedRecordType {
+                provider,
+                record_type,
+            } => {
+                write!(f, "[{provider}] Unsupported record type: {record_type}")
+            }
             Self::ParseError { provider, detail } => {
                 write!(f, "[{provider}] Parse error: {detail}")
             }
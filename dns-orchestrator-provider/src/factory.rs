@@ -3,8 +3,11 @@
 use std::sync::Arc;
 
 use crate::error::Result;
-use crate::traits::DnsProvider;
-use crate::types::{ProviderCredentials, ProviderMetadata};
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::layer::{ProviderLayer, apply_layers};
+use crate::rate_limit::RateLimit;
+use crate::traits::{DnsProvider, ProviderDescriptor};
+use crate::types::{ProviderCredentials, ProviderMetadata, ProviderType};
 
 #[cfg(feature = "aliyun")]
 use crate::providers::AliyunProvider;
@@ -12,40 +15,181 @@ use crate::providers::AliyunProvider;
 use crate::providers::CloudflareProvider;
 #[cfg(feature = "dnspod")]
 use crate::providers::DnspodProvider;
+#[cfg(feature = "gclouddns")]
+use crate::providers::GcloudDnsProvider;
 #[cfg(feature = "huaweicloud")]
 use crate::providers::HuaweicloudProvider;
+#[cfg(feature = "mock")]
+use crate::providers::MockProvider;
+#[cfg(feature = "namecheap")]
+use crate::providers::NamecheapProvider;
+#[cfg(feature = "porkbun")]
+use crate::providers::PorkbunProvider;
+#[cfg(feature = "rfc2136")]
+use crate::providers::Rfc2136Provider;
+
+/// Provider 创建选项（高级配置）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProviderOptions {
+    /// HTTP 超时与代理配置
+    pub http: HttpClientConfig,
+    /// 请求重试策略
+    pub retry_policy: RetryPolicy,
+    /// 速率限制（`None` 时使用服务商默认配额）
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl ProviderOptions {
+    /// 指定服务商实际生效的速率限制
+    pub fn rate_limit_for(&self, provider: &ProviderType) -> RateLimit {
+        self.rate_limit
+            .unwrap_or_else(|| RateLimit::default_for(provider))
+    }
+}
 
 /// 工厂函数 - 根据凭证类型创建 Provider 实例
 pub fn create_provider(credentials: ProviderCredentials) -> Result<Arc<dyn DnsProvider>> {
+    create_provider_with_options(credentials, &ProviderOptions::default())
+}
+
+/// 工厂函数 - 根据凭证类型和自定义选项创建 Provider 实例
+pub fn create_provider_with_options(
+    credentials: ProviderCredentials,
+    options: &ProviderOptions,
+) -> Result<Arc<dyn DnsProvider>> {
     match credentials {
         #[cfg(feature = "cloudflare")]
-        ProviderCredentials::Cloudflare { api_token } => {
-            Ok(Arc::new(CloudflareProvider::new(api_token)))
-        }
+        ProviderCredentials::Cloudflare { auth } => Ok(Arc::new(
+            CloudflareProvider::builder_with_auth(auth)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Cloudflare))
+                .build(),
+        )),
         #[cfg(feature = "aliyun")]
         ProviderCredentials::Aliyun {
             access_key_id,
             access_key_secret,
-        } => Ok(Arc::new(AliyunProvider::new(
-            access_key_id,
-            access_key_secret,
-        ))),
+        } => Ok(Arc::new(
+            AliyunProvider::builder(access_key_id, access_key_secret)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Aliyun))
+                .build(),
+        )),
         #[cfg(feature = "dnspod")]
         ProviderCredentials::Dnspod {
             secret_id,
             secret_key,
-        } => Ok(Arc::new(DnspodProvider::new(secret_id, secret_key))),
+        } => Ok(Arc::new(
+            DnspodProvider::builder(secret_id, secret_key)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Dnspod))
+                .build(),
+        )),
         #[cfg(feature = "huaweicloud")]
         ProviderCredentials::Huaweicloud {
             access_key_id,
             secret_access_key,
-        } => Ok(Arc::new(HuaweicloudProvider::new(
-            access_key_id,
-            secret_access_key,
-        ))),
+        } => Ok(Arc::new(
+            HuaweicloudProvider::builder(access_key_id, secret_access_key)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Huaweicloud))
+                .build(),
+        )),
+        #[cfg(feature = "gclouddns")]
+        ProviderCredentials::GcloudDns {
+            project_id,
+            service_account_json,
+        } => Ok(Arc::new(
+            GcloudDnsProvider::builder(project_id, &service_account_json)?
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::GcloudDns))
+                .build(),
+        )),
+        #[cfg(feature = "namecheap")]
+        ProviderCredentials::Namecheap {
+            api_user,
+            api_key,
+            username,
+            client_ip,
+        } => Ok(Arc::new(
+            NamecheapProvider::builder(api_user, api_key, username, client_ip)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Namecheap))
+                .build(),
+        )),
+        #[cfg(feature = "porkbun")]
+        ProviderCredentials::Porkbun {
+            api_key,
+            secret_api_key,
+        } => Ok(Arc::new(
+            PorkbunProvider::builder(api_key, secret_api_key)
+                .http_config(options.http.clone())
+                .retry_policy(options.retry_policy)
+                .rate_limit(options.rate_limit_for(&ProviderType::Porkbun))
+                .build(),
+        )),
+        #[cfg(feature = "rfc2136")]
+        ProviderCredentials::Rfc2136 {
+            server,
+            port,
+            zone,
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+        } => Ok(Arc::new(
+            Rfc2136Provider::builder(
+                server,
+                port,
+                &zone,
+                &tsig_key_name,
+                &tsig_algorithm,
+                &tsig_secret,
+            )?
+            .http_config(options.http.clone())
+            .retry_policy(options.retry_policy)
+            .rate_limit(options.rate_limit_for(&ProviderType::Rfc2136))
+            .build(),
+        )),
+        #[cfg(feature = "mock")]
+        ProviderCredentials::Mock { seed } => Ok(Arc::new(MockProvider::new(seed.as_deref()))),
     }
 }
 
+/// 工厂函数 - 创建 Provider 实例并依次叠加装饰层
+///
+/// `layers` 中靠前的层更靠近服务商实现，如 `[metrics, retry]` 中每次重试都会计入指标
+pub fn create_provider_with_layers(
+    credentials: ProviderCredentials,
+    options: &ProviderOptions,
+    layers: &[Arc<dyn ProviderLayer>],
+) -> Result<Arc<dyn DnsProvider>> {
+    let provider = create_provider_with_options(credentials, options)?;
+    Ok(apply_layers(provider, layers))
+}
+
+/// 服务商元数据未知时使用的默认 TTL（秒）
+pub const DEFAULT_TTL: u32 = 600;
+
+/// 获取服务商的默认 TTL
+///
+/// `provider_id` 为 `DnsProvider::id()` 的返回值；未知服务商返回 [`DEFAULT_TTL`]
+pub fn default_ttl_for(provider_id: &str) -> u32 {
+    provider_metadata(provider_id).map_or(DEFAULT_TTL, |m| m.limits.default_ttl)
+}
+
+/// 按服务商 ID 查找元数据，未知服务商返回 `None`
+pub fn provider_metadata(provider_id: &str) -> Option<ProviderMetadata> {
+    get_all_provider_metadata()
+        .into_iter()
+        .find(|m| m.id.to_string() == provider_id)
+}
+
 /// 获取所有支持的提供商元数据
 pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
     vec![
@@ -57,5 +201,136 @@ pub fn get_all_provider_metadata() -> Vec<ProviderMetadata> {
         DnspodProvider::metadata(),
         #[cfg(feature = "huaweicloud")]
         HuaweicloudProvider::metadata(),
+        #[cfg(feature = "gclouddns")]
+        GcloudDnsProvider::metadata(),
+        #[cfg(feature = "namecheap")]
+        NamecheapProvider::metadata(),
+        #[cfg(feature = "porkbun")]
+        PorkbunProvider::metadata(),
+        #[cfg(feature = "rfc2136")]
+        Rfc2136Provider::metadata(),
+        #[cfg(feature = "mock")]
+        MockProvider::metadata(),
     ]
 }
+
+#[cfg(all(test, feature = "cloudflare", feature = "dnspod"))]
+mod tests {
+    use super::*;
+    use crate::types::DnsRecordType;
+
+    fn metadata(provider: &ProviderType) -> ProviderMetadata {
+        get_all_provider_metadata()
+            .into_iter()
+            .find(|m| m.id == *provider)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_provider_feature_flags() {
+        let cloudflare = metadata(&ProviderType::Cloudflare);
+        assert!(cloudflare.features.proxy);
+
+        let dnspod = metadata(&ProviderType::Dnspod);
+        assert!(!dnspod.features.proxy);
+        assert!(dnspod.supported_record_types.contains(&DnsRecordType::Caa));
+        assert!(dnspod.limits.min_ttl <= dnspod.limits.max_ttl);
+    }
+
+    #[test]
+    fn test_default_ttl_for() {
+        // Cloudflare 省略 TTL 时使用"自动"
+        assert_eq!(default_ttl_for("cloudflare"), 1);
+        assert_eq!(default_ttl_for("dnspod"), 600);
+        assert_eq!(default_ttl_for("unknown"), DEFAULT_TTL);
+
+        // 除 Cloudflare 的"自动"外，默认 TTL 须落在服务商允许范围内
+        for m in get_all_provider_metadata() {
+            if m.id == ProviderType::Cloudflare {
+                continue;
+            }
+            assert!(
+                (m.limits.min_ttl..=m.limits.max_ttl).contains(&m.limits.default_ttl),
+                "{}",
+                m.id
+            );
+        }
+    }
+
+    #[test]
+    fn test_record_type_support_follows_metadata() {
+        let all_types = [
+            DnsRecordType::A,
+            DnsRecordType::Aaaa,
+            DnsRecordType::Cname,
+            DnsRecordType::Mx,
+            DnsRecordType::Txt,
+            DnsRecordType::Ns,
+            DnsRecordType::Srv,
+            DnsRecordType::Caa,
+            DnsRecordType::Ptr,
+            DnsRecordType::Https,
+            DnsRecordType::Svcb,
+            DnsRecordType::Alias,
+        ];
+        for m in get_all_provider_metadata() {
+            let id = m.id.to_string();
+            for record_type in &all_types {
+                let result = crate::validate_record_support(&id, record_type, None, 0);
+                if m.supported_record_types.contains(record_type) {
+                    assert!(result.is_ok(), "{id} {record_type:?}: {result:?}");
+                } else {
+                    assert!(
+                        matches!(
+                            result,
+                            Err(crate::ProviderError::UnsupportedRecordType { ref provider, .. })
+                                if *provider == id
+                        ),
+                        "{id} {record_type:?}: {result:?}"
+                    );
+                }
+            }
+        }
+        assert!(
+            crate::validate_record_support("custom", &DnsRecordType::Alias, Some(5), u32::MAX)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_weight_support_follows_metadata() {
+        for m in get_all_provider_metadata() {
+            let id = m.id.to_string();
+            let a = crate::validate_record_support(&id, &DnsRecordType::A, Some(10), 0);
+            assert_eq!(a.is_ok(), m.features.weight, "{id}: {a:?}");
+            let mx = crate::validate_record_support(&id, &DnsRecordType::Mx, Some(10), 0);
+            assert!(
+                matches!(mx, Err(crate::ProviderError::InvalidRecord { .. })),
+                "{id}: {mx:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ttl_limits_follow_metadata() {
+        for m in get_all_provider_metadata() {
+            let id = m.id.to_string();
+            let limits = &m.limits;
+            let check = |ttl| crate::validate_record_support(&id, &DnsRecordType::A, None, ttl);
+
+            for ttl in [0, limits.default_ttl, limits.min_ttl, limits.max_ttl] {
+                assert!(check(ttl).is_ok(), "{id} ttl {ttl}");
+            }
+            // 低于最小值只警告，由服务商按套餐判断
+            if limits.min_ttl > 1 {
+                assert!(check(limits.min_ttl - 1).is_ok(), "{id}");
+            }
+            if let Some(ttl) = limits.max_ttl.checked_add(1) {
+                assert!(
+                    matches!(check(ttl), Err(crate::ProviderError::InvalidRecord { ref provider, .. }) if *provider == id),
+                    "{id} ttl {ttl}"
+                );
+            }
+        }
+    }
+}
@@ -13,6 +13,7 @@ use serde::de::DeserializeOwned;
 use std::time::Duration;
 
 use crate::error::ProviderError;
+use crate::rate_limit::RateLimiter;
 
 /// HTTP 工具函数集
 pub struct HttpUtils;
@@ -91,52 +92,78 @@ impl HttpUtils {
         })
     }
 
-    /// 组合：执行请求并解析 JSON
-    ///
-    /// 最常用的场景：发送请求 -> 获取响应 -> 解析 JSON
-    pub async fn execute_and_parse_json<T>(
-        request_builder: RequestBuilder,
-        provider_name: &str,
-        method_name: &str,
-        url_or_action: &str,
-    ) -> Result<T, ProviderError>
-    where
-        T: DeserializeOwned,
-    {
-        let (_status, text) =
-            Self::execute_request(request_builder, provider_name, method_name, url_or_action)
-                .await?;
-        Self::parse_json(&text, provider_name)
-    }
-
     /// 执行 HTTP 请求并返回响应文本（带重试）
     ///
-    /// 自动重试网络错误，使用指数退避策略。
+    /// 通过克隆 `request_builder` 重放请求，适用于签名在有效期内可重复使用的 Provider。
+    /// 仅幂等方法（GET/HEAD/PUT/DELETE 等）按 [`RetryPolicy`] 重试；POST/PATCH 等
+    /// 请求可能已在服务端生效，只在明确未被处理的 429 时重试。
+    /// 请求无法克隆时（通常是 body stream 导致）退化为不重试。
     ///
     /// # Arguments
     /// * `request_builder` - 已配置好的请求构造器
     /// * `provider_name` - Provider 名称
-    /// * `method_name` - 请求方法名
+    /// * `method_name` - HTTP 方法名，用于判断是否幂等
     /// * `url_or_action` - URL 或 Action 名称
-    /// * `max_retries` - 最大重试次数（0 表示不重试）
-    ///
-    /// # Returns
-    /// * `Ok((status_code, response_text))` - 成功时返回状态码和响应文本
-    /// * `Err(ProviderError)` - 所有重试都失败后返回最后一个错误
-    ///
-    /// # 重试策略
-    /// - 只重试网络错误（`ProviderError::NetworkError`）
-    /// - 指数退避：100ms, 200ms, 400ms, 800ms, ... (最大 10 秒)
-    /// - 业务错误（认证失败、记录不存在等）不会重试
-    pub async fn execute_request_with_retry(
+    /// * `policy` - 重试策略
+    /// * `limiter` - 速率限制器（每次尝试前等待）
+    pub(crate) async fn execute_request_with_retry(
+        request_builder: RequestBuilder,
+        provider_name: &str,
+        method_name: &str,
+        url_or_action: &str,
+        policy: &RetryPolicy,
+        limiter: &RateLimiter,
+    ) -> Result<(u16, String), ProviderError> {
+        let idempotent = reqwest::Method::from_bytes(method_name.as_bytes())
+            .is_ok_and(|method| method.is_idempotent());
+        Self::retry_cloned(
+            request_builder,
+            idempotent,
+            provider_name,
+            method_name,
+            url_or_action,
+            policy,
+            limiter,
+        )
+        .await
+    }
+
+    /// 同 [`Self::execute_request_with_retry`]，但调用方确认该请求可安全重放
+    /// （如只读或整体替换语义的 POST 接口），按完整的重试策略重试
+    pub(crate) async fn execute_idempotent_request_with_retry(
+        request_builder: RequestBuilder,
+        provider_name: &str,
+        method_name: &str,
+        url_or_action: &str,
+        policy: &RetryPolicy,
+        limiter: &RateLimiter,
+    ) -> Result<(u16, String), ProviderError> {
+        Self::retry_cloned(
+            request_builder,
+            true,
+            provider_name,
+            method_name,
+            url_or_action,
+            policy,
+            limiter,
+        )
+        .await
+    }
+
+    async fn retry_cloned(
         request_builder: RequestBuilder,
+        idempotent: bool,
         provider_name: &str,
         method_name: &str,
         url_or_action: &str,
-        max_retries: u32,
+        policy: &RetryPolicy,
+        limiter: &RateLimiter,
     ) -> Result<(u16, String), ProviderError> {
-        if max_retries == 0 {
-            // 不重试，直接执行
+        if policy.max_attempts <= 1 || request_builder.try_clone().is_none() {
+            if policy.max_attempts > 1 {
+                log::warn!("[{}] 无法克隆请求，禁用重试", provider_name);
+            }
+            limiter.acquire().await;
             return Self::execute_request(
                 request_builder,
                 provider_name,
@@ -146,46 +173,266 @@ impl HttpUtils {
             .await;
         }
 
-        let mut last_error = None;
-
-        for attempt in 0..=max_retries {
-            // 克隆请求（RequestBuilder 只能使用一次）
-            let req = match request_builder.try_clone() {
-                Some(r) => r,
-                None => {
-                    // 无法克隆（通常是 body stream 导致），回退到不重试
-                    log::warn!("[{}] 无法克隆请求，禁用重试", provider_name);
-                    return Self::execute_request(
-                        request_builder,
-                        provider_name,
-                        method_name,
-                        url_or_action,
-                    )
-                    .await;
+        Self::retry_loop(
+            || {
+                request_builder
+                    .try_clone()
+                    .ok_or_else(|| ProviderError::Unknown {
+                        provider: provider_name.to_string(),
+                        raw_code: None,
+                        raw_message: format!("无法重放请求: {method_name} {url_or_action}"),
+                    })
+            },
+            idempotent,
+            provider_name,
+            method_name,
+            url_or_action,
+            policy,
+            limiter,
+        )
+        .await
+    }
+
+    /// 执行 HTTP 请求并返回响应文本（带重试，每次尝试重新构造请求）
+    ///
+    /// `RequestBuilder` 发送后无法复用，且部分 Provider 的签名包含时间戳和一次性 nonce，
+    /// 因此每次尝试都调用 `build` 重新构造（并重新签名）请求。
+    /// `idempotent` 为 false 时请求可能已在服务端生效，只在明确未被处理的 429 时重试。
+    ///
+    /// # Returns
+    /// * `Ok((status_code, response_text))` - 成功，或可重试状态码在次数用尽后的最后一次响应
+    /// * `Err(ProviderError)` - 不可重试的错误，或所有重试都失败后的最后一个网络错误
+    ///
+    /// # 重试策略
+    /// - 重试网络错误（`ProviderError::NetworkError`）和可重试状态码（408/429/5xx 网关类）
+    /// - 指数退避并叠加随机抖动，见 [`RetryPolicy`]
+    /// - 业务错误（认证失败、记录不存在等）不会重试
+    /// - 每次尝试（包括重试）都会先等待速率限制器放行
+    pub(crate) async fn execute_request_with_retry_fn<F>(
+        build: F,
+        idempotent: bool,
+        provider_name: &str,
+        method_name: &str,
+        url_or_action: &str,
+        policy: &RetryPolicy,
+        limiter: &RateLimiter,
+    ) -> Result<(u16, String), ProviderError>
+    where
+        F: Fn() -> RequestBuilder,
+    {
+        Self::retry_loop(
+            || Ok(build()),
+            idempotent,
+            provider_name,
+            method_name,
+            url_or_action,
+            policy,
+            limiter,
+        )
+        .await
+    }
+
+    async fn retry_loop<F>(
+        build: F,
+        idempotent: bool,
+        provider_name: &str,
+        method_name: &str,
+        url_or_action: &str,
+        policy: &RetryPolicy,
+        limiter: &RateLimiter,
+    ) -> Result<(u16, String), ProviderError>
+    where
+        F: Fn() -> Result<RequestBuilder, ProviderError>,
+    {
+        let max_attempts = policy.max_attempts.max(1);
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            limiter.acquire().await;
+            let result =
+                Self::execute_request(build()?, provider_name, method_name, url_or_action).await;
+
+            let reason = match &result {
+                Ok((429, _)) => "HTTP 429".to_string(),
+                Ok((status, _)) if idempotent && is_retryable_status(*status) => {
+                    format!("HTTP {status}")
                 }
+                Err(e) if idempotent && is_retryable(e) => e.to_string(),
+                _ => return result,
             };
+            if attempt >= max_attempts {
+                return result;
+            }
 
-            match Self::execute_request(req, provider_name, method_name, url_or_action).await {
-                Ok(resp) => return Ok(resp),
-                Err(e) if attempt < max_retries && is_retryable(&e) => {
-                    let delay = backoff_delay(attempt);
-                    log::warn!(
-                        "[{}] 请求失败（尝试 {}/{}），{:.1}秒后重试: {}",
-                        provider_name,
-                        attempt + 1,
-                        max_retries,
-                        delay.as_secs_f32(),
-                        e
-                    );
-                    tokio::time::sleep(delay).await;
-                    last_error = Some(e);
-                    continue;
-                }
-                Err(e) => return Err(e),
+            let delay = policy.delay(attempt - 1);
+            log::warn!(
+                "[{}] 请求失败（尝试 {}/{}），{:.1}秒后重试: {}",
+                provider_name,
+                attempt,
+                max_attempts,
+                delay.as_secs_f32(),
+                reason
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// 默认连接超时
+const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+/// 默认请求总超时
+const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// HTTP 客户端配置
+///
+/// 默认请求总超时 30 秒、连接超时 10 秒，超时以 `ProviderError::NetworkError` 返回；
+/// 相同配置的 Provider 共享同一个 HTTP Client（及其连接池）
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HttpClientConfig {
+    /// 请求总超时（连接 + 发送 + 读取响应）
+    pub timeout: Duration,
+    /// 建立连接超时
+    pub connect_timeout: Duration,
+    /// 上游代理；未设置时沿用 `HTTP_PROXY` / `HTTPS_PROXY` / `NO_PROXY` 环境变量
+    pub proxy: Option<ProxyConfig>,
+}
+
+impl Default for HttpClientConfig {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_REQUEST_TIMEOUT,
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            proxy: None,
+        }
+    }
+}
+
+/// 上游代理配置
+///
+/// 所有请求（HTTP 与 HTTPS）都经由该代理转发，设置后忽略代理相关环境变量；
+/// 地址在创建时校验，无效地址直接返回错误而不是回退为直连
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct ProxyConfig {
+    /// 代理地址，如 `http://proxy.internal:3128`
+    url: String,
+    /// 代理认证用户名
+    username: Option<String>,
+    /// 代理认证密码
+    password: Option<String>,
+}
+
+impl ProxyConfig {
+    /// 使用代理地址创建（无认证），地址无效时返回 `InvalidParameter`
+    pub fn new(url: impl Into<String>) -> Result<Self, ProviderError> {
+        let url = url.into();
+        reqwest::Proxy::all(&url).map_err(|e| ProviderError::InvalidParameter {
+            provider: "http_client".to_string(),
+            param: "proxy".to_string(),
+            detail: format!("代理地址无效: {url} ({e})"),
+        })?;
+        Ok(Self {
+            url,
+            username: None,
+            password: None,
+        })
+    }
+
+    /// 设置代理 Basic 认证
+    #[must_use]
+    pub fn with_auth(mut self, username: impl Into<String>, password: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self.password = Some(password.into());
+        self
+    }
+
+    /// 代理地址
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// 代理认证用户名
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// 转换为 reqwest 代理
+    pub(crate) fn to_reqwest(&self) -> reqwest::Proxy {
+        let proxy =
+            reqwest::Proxy::all(&self.url).expect("proxy url validated in ProxyConfig::new");
+        match &self.username {
+            Some(username) => {
+                proxy.basic_auth(username, self.password.as_deref().unwrap_or_default())
             }
+            None => proxy,
         }
+    }
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("url", &self.url)
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| "***"))
+            .finish()
+    }
+}
 
-        Err(last_error.unwrap())
+/// 请求重试策略
+///
+/// 默认最多尝试 3 次（首次请求 + 2 次重试），退避延迟 100ms, 200ms, 400ms, ...，最大 10 秒
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（包含首次请求，1 表示不重试）
+    pub max_attempts: u32,
+    /// 首次重试前的基础延迟
+    pub base_delay: Duration,
+    /// 单次延迟上限
+    pub max_delay: Duration,
+    /// 随机抖动比例（0.0 ~ 1.0），实际延迟在 `[delay * (1 - jitter), delay]` 之间
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不重试
+    pub fn no_retry() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// 按最大重试次数（不含首次请求）构造默认策略
+    pub fn with_max_retries(max_retries: u32) -> Self {
+        Self {
+            max_attempts: max_retries.saturating_add(1),
+            ..Self::default()
+        }
+    }
+
+    /// 第 `retry` 次重试（从 0 开始）前的延迟
+    pub fn delay(&self, retry: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(retry))
+            .min(self.max_delay);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - jitter * random_unit())
     }
 }
 
@@ -196,12 +443,241 @@ fn is_retryable(error: &ProviderError) -> bool {
     matches!(error, ProviderError::NetworkError { .. })
 }
 
-/// 计算指数退避延迟
-///
-/// 退避策略：100ms, 200ms, 400ms, 800ms, 1.6s, ...
-/// 最大延迟限制为 10 秒
-fn backoff_delay(attempt: u32) -> Duration {
-    let delay_ms = 100 * 2_u64.pow(attempt);
-    let delay_ms = delay_ms.min(10_000); // 最大 10 秒
-    Duration::from_millis(delay_ms)
+/// 判断 HTTP 状态码是否可重试（超时、限流、服务端临时故障）
+fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 429 | 500 | 502 | 503 | 504)
+}
+
+/// 生成 `[0, 1)` 区间的伪随机数（仅用于退避抖动）
+fn random_unit() -> f64 {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.subsec_nanos());
+    f64::from(nanos % 1_000_000) / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// 启动按顺序返回指定状态码的本地 HTTP 服务，返回地址和请求计数
+    async fn mock_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Ok((mut socket, _)) = listener.accept().await else {
+                    return;
+                };
+                let index = counter.fetch_add(1, Ordering::SeqCst);
+                let status = statuses[index.min(statuses.len() - 1)];
+                let mut buf = [0_u8; 4096];
+                let _ = socket.read(&mut buf).await;
+                let body = format!("{{\"status\":{status}}}");
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = socket.write_all(response.as_bytes()).await;
+                let _ = socket.shutdown().await;
+            }
+        });
+
+        (format!("http://{addr}/"), hits)
+    }
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            jitter: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retry_on_429_then_success() {
+        let (url, hits) = mock_server(vec![429, 429, 200]).await;
+        let client = reqwest::Client::new();
+        let builds = AtomicUsize::new(0);
+
+        let (status, body) = HttpUtils::execute_request_with_retry_fn(
+            || {
+                builds.fetch_add(1, Ordering::SeqCst);
+                client.get(&url)
+            },
+            true,
+            "test",
+            "GET",
+            &url,
+            &fast_policy(3),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, 200);
+        assert_eq!(body, "{\"status\":200}");
+        assert_eq!(hits.load(Ordering::SeqCst), 3);
+        assert_eq!(builds.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exhausted_returns_last_response() {
+        let (url, hits) = mock_server(vec![503]).await;
+        let client = reqwest::Client::new();
+
+        let (status, _) = HttpUtils::execute_request_with_retry(
+            client.get(&url),
+            "test",
+            "GET",
+            &url,
+            &fast_policy(2),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, 503);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_idempotent_request_not_replayed() {
+        // POST 可能已生效，5xx 不重放
+        let (url, hits) = mock_server(vec![503, 200]).await;
+        let client = reqwest::Client::new();
+        let (status, _) = HttpUtils::execute_request_with_retry(
+            client.post(&url).body("{}"),
+            "test",
+            "POST",
+            &url,
+            &fast_policy(3),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, 503);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+
+        // 429 表示请求未被处理，可以重试
+        let (url, hits) = mock_server(vec![429, 200]).await;
+        let (status, _) = HttpUtils::execute_request_with_retry(
+            client.post(&url).body("{}"),
+            "test",
+            "POST",
+            &url,
+            &fast_policy(3),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+
+        // 调用方确认幂等的 POST 按完整策略重试
+        let (url, hits) = mock_server(vec![503, 200]).await;
+        let (status, _) = HttpUtils::execute_idempotent_request_with_retry(
+            client.post(&url).body("{}"),
+            "test",
+            "POST",
+            &url,
+            &fast_policy(3),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_non_retryable_status_not_retried() {
+        let (url, hits) = mock_server(vec![401, 200]).await;
+        let client = reqwest::Client::new();
+
+        let (status, _) = HttpUtils::execute_request_with_retry(
+            client.get(&url),
+            "test",
+            "GET",
+            &url,
+            &RetryPolicy::default(),
+            &RateLimiter::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(status, 401);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_slow_server_times_out() {
+        // 接受连接但迟迟不响应的服务
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(async move {
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                    drop(socket);
+                });
+            }
+        });
+
+        let client = crate::providers::common::build_http_client(&HttpClientConfig {
+            timeout: Duration::from_millis(200),
+            connect_timeout: Duration::from_millis(200),
+            proxy: None,
+        });
+        let started = std::time::Instant::now();
+        let result = HttpUtils::execute_request(client.get(&url), "test", "GET", &url).await;
+
+        assert!(matches!(result, Err(ProviderError::NetworkError { .. })));
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_invalid_proxy_url_is_rejected() {
+        let result = ProxyConfig::new("not a url");
+        assert!(matches!(
+            result,
+            Err(ProviderError::InvalidParameter { ref param, .. }) if param == "proxy"
+        ));
+        assert!(ProxyConfig::new("http://proxy.internal:3128").is_ok());
+    }
+
+    #[test]
+    fn test_proxy_debug_redacts_password() {
+        let proxy = ProxyConfig::new("http://proxy.internal:3128")
+            .unwrap()
+            .with_auth("user", "s3cret");
+        let debug = format!("{proxy:?}");
+        assert!(debug.contains("user"));
+        assert!(!debug.contains("s3cret"));
+    }
+
+    #[test]
+    fn test_backoff_delay() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(3), Duration::from_millis(800));
+        assert_eq!(policy.delay(20), Duration::from_secs(10));
+
+        let jittered = RetryPolicy::default().delay(2);
+        assert!(jittered <= Duration::from_millis(400));
+        assert!(jittered >= Duration::from_millis(320));
+
+        assert_eq!(RetryPolicy::with_max_retries(0), RetryPolicy::no_retry());
+    }
 }
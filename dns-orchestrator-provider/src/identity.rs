@@ -0,0 +1,331 @@
+//! 与服务商无关的记录身份标识
+//!
+//! 记录 ID 由各服务商生成，迁移后不保留，因此对比、复制、同步 Zone 时必须按内容匹配记录。
+//! `RecordIdentity` 由规范化后的 (名称, 类型, 值, 优先级, 线路) 计算，保证所有对比逻辑使用同一套规则。
+//! 解析线路代码因服务商而异，跨服务商复制时用 `translate_line` 转换。
+
+use std::net::Ipv6Addr;
+
+use serde::Serialize;
+
+use crate::providers::common::record_type_to_string;
+use crate::types::{CreateDnsRecordRequest, DnsRecord, DnsRecordType};
+
+/// 记录身份标识（规范化后的内容键）
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+pub struct RecordIdentity(String);
+
+impl RecordIdentity {
+    /// 由记录内容计算身份标识
+    pub fn new(
+        name: &str,
+        record_type: &DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+        line: Option<&str>,
+    ) -> Self {
+        // 只有 MX / SRV 的优先级属于记录内容
+        let priority = match record_type {
+            DnsRecordType::Mx | DnsRecordType::Srv => priority.map(|p| p.to_string()),
+            _ => None,
+        };
+
+        Self(format!(
+            "{}|{}|{}|{}|{}",
+            normalize_name(name),
+            record_type_to_string(record_type),
+            normalize_value(record_type, value),
+            priority.unwrap_or_default(),
+            normalize_line(line).unwrap_or_default()
+        ))
+    }
+
+    /// 标识字符串
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for RecordIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl DnsRecord {
+    /// 计算与服务商无关的记录身份标识
+    pub fn record_identity(&self) -> RecordIdentity {
+        RecordIdentity::new(
+            &self.name,
+            &self.record_type,
+            &self.value,
+            self.priority,
+            self.line.as_deref(),
+        )
+    }
+}
+
+impl CreateDnsRecordRequest {
+    /// 计算待创建记录的身份标识（与 `DnsRecord::record_identity` 规则一致）
+    pub fn record_identity(&self) -> RecordIdentity {
+        RecordIdentity::new(
+            &self.name,
+            &self.record_type,
+            &self.value,
+            self.priority,
+            self.line.as_deref(),
+        )
+    }
+}
+
+/// 规范化相对名称：小写、去掉末尾的点，根记录统一为 "@"
+fn normalize_name(name: &str) -> String {
+    let name = name.trim().trim_end_matches('.').to_ascii_lowercase();
+    if name.is_empty() {
+        "@".to_string()
+    } else {
+        name
+    }
+}
+
+/// 规范化解析线路：未设置、空值与各服务商的默认线路统一为 `None`
+///
+/// 阿里云默认线路为 `default`，DNSPod 为 `默认`，其他线路代码原样保留
+pub fn normalize_line(line: Option<&str>) -> Option<String> {
+    match line.map(str::trim) {
+        None | Some("" | "default" | "默认") => None,
+        Some(line) => Some(line.to_string()),
+    }
+}
+
+/// 阿里云线路代码与 DNSPod 线路名称的对照表
+const LINE_TRANSLATIONS: &[(&str, &str)] = &[
+    ("telecom", "电信"),
+    ("unicom", "联通"),
+    ("mobile", "移动"),
+    ("edu", "教育网"),
+    ("oversea", "境外"),
+    ("search", "搜索引擎"),
+    ("drpeng", "鹏博士"),
+    ("btvn", "广电网"),
+];
+
+/// 将解析线路转换为目标服务商的线路代码（`source`/`target` 为 `DnsProvider::id`）
+///
+/// 默认线路返回 `None`；同一服务商之间原样保留；阿里云与 DNSPod 之间按对照表转换。
+/// 目标服务商没有对应线路时返回原因
+pub fn translate_line(
+    line: Option<&str>,
+    source: &str,
+    target: &str,
+) -> std::result::Result<Option<String>, String> {
+    let Some(line) = normalize_line(line) else {
+        return Ok(None);
+    };
+    if source == target {
+        return Ok(Some(line));
+    }
+    let translated = match (source, target) {
+        ("aliyun", "dnspod") => LINE_TRANSLATIONS
+            .iter()
+            .find(|(aliyun, _)| *aliyun == line)
+            .map(|(_, dnspod)| *dnspod),
+        ("dnspod", "aliyun") => LINE_TRANSLATIONS
+            .iter()
+            .find(|(_, dnspod)| *dnspod == line)
+            .map(|(aliyun, _)| *aliyun),
+        _ => None,
+    };
+    translated
+        .map(|line| Some(line.to_string()))
+        .ok_or_else(|| format!("线路 {line} 在目标服务商中没有对应线路"))
+}
+
+/// 是否为根记录名称（"@" 或空）
+pub(crate) fn is_apex_name(name: &str) -> bool {
+    normalize_name(name) == "@"
+}
+
+/// 比较两个相对名称是否指向同一记录名
+pub(crate) fn same_record_name(a: &str, b: &str) -> bool {
+    normalize_name(a) == normalize_name(b)
+}
+
+/// 规范化记录值
+fn normalize_value(record_type: &DnsRecordType, value: &str) -> String {
+    let value = value.trim();
+    match record_type {
+        // 部分服务商返回带引号或被拆分为多段的 TXT，合并为原始内容
+        DnsRecordType::Txt => unquote_txt(value),
+        DnsRecordType::Aaaa => value
+            .parse::<Ipv6Addr>()
+            .map_or_else(|_| value.to_ascii_lowercase(), |ip| ip.to_string()),
+        // 主机名类值忽略大小写与末尾的点
+        DnsRecordType::Cname
+        | DnsRecordType::Mx
+        | DnsRecordType::Ns
+        | DnsRecordType::Ptr
+        | DnsRecordType::Srv => value.trim_end_matches('.').to_ascii_lowercase(),
+        _ => value.to_string(),
+    }
+}
+
+/// 去掉 TXT 记录的引号并合并多段字符串：`"a" "b"` -> `ab`
+pub(crate) fn unquote_txt(value: &str) -> String {
+    if !(value.starts_with('"') && value.ends_with('"') && value.len() >= 2) {
+        return value.to_string();
+    }
+
+    let mut result = String::with_capacity(value.len());
+    let mut in_quotes = false;
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => {
+                if let Some(escaped) = chars.next() {
+                    result.push(escaped);
+                }
+            }
+            _ if in_quotes => result.push(c),
+            _ => {}
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(
+        name: &str,
+        record_type: DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> DnsRecord {
+        record_with_line(name, record_type, value, priority, None)
+    }
+
+    fn record_with_line(
+        name: &str,
+        record_type: DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+        line: Option<&str>,
+    ) -> DnsRecord {
+        DnsRecord {
+            id: String::new(),
+            domain_id: String::new(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority,
+            proxied: None,
+            line: line.map(ToString::to_string),
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_equivalent_records_across_providers() {
+        // Cloudflare 风格 vs DNSPod / 华为云风格
+        let pairs = [
+            (
+                record("WWW", DnsRecordType::Cname, "Target.Example.com", None),
+                record("www", DnsRecordType::Cname, "target.example.com.", None),
+            ),
+            (
+                record("@", DnsRecordType::Txt, "v=spf1 -all", None),
+                record("", DnsRecordType::Txt, "\"v=spf1 -all\"", None),
+            ),
+            (
+                record("_dmarc", DnsRecordType::Txt, "abcdef", None),
+                record("_dmarc", DnsRecordType::Txt, "\"abc\" \"def\"", None),
+            ),
+            (
+                record("v6", DnsRecordType::Aaaa, "2001:db8::1", None),
+                record(
+                    "v6",
+                    DnsRecordType::Aaaa,
+                    "2001:0DB8:0000:0000:0000:0000:0000:0001",
+                    None,
+                ),
+            ),
+            (
+                record("@", DnsRecordType::Mx, "mx.example.com.", Some(10)),
+                record("@", DnsRecordType::Mx, "MX.example.com", Some(10)),
+            ),
+            // 非 MX/SRV 的优先级不参与比较
+            (
+                record("a", DnsRecordType::A, "1.1.1.1", Some(0)),
+                record("a", DnsRecordType::A, "1.1.1.1", None),
+            ),
+        ];
+
+        for (a, b) in pairs {
+            assert_eq!(a.record_identity(), b.record_identity(), "{a:?} vs {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_different_records_differ() {
+        let a = record("@", DnsRecordType::Mx, "mx.example.com", Some(10));
+        let b = record("@", DnsRecordType::Mx, "mx.example.com", Some(20));
+        assert_ne!(a.record_identity(), b.record_identity());
+
+        let a = record("@", DnsRecordType::Txt, "ABC", None);
+        let b = record("@", DnsRecordType::Txt, "abc", None);
+        assert_ne!(a.record_identity(), b.record_identity());
+
+        let a = record("@", DnsRecordType::A, "1.1.1.1", None);
+        let b = record("@", DnsRecordType::A, "1.1.1.2", None);
+        assert_ne!(a.record_identity(), b.record_identity());
+    }
+
+    #[test]
+    fn test_line_is_part_of_identity() {
+        let telecom = record_with_line("www", DnsRecordType::A, "1.1.1.1", None, Some("telecom"));
+        let unicom = record_with_line("www", DnsRecordType::A, "1.1.1.1", None, Some("unicom"));
+        assert_ne!(telecom.record_identity(), unicom.record_identity());
+
+        // 默认线路与未设置线路视为相同
+        let plain = record("www", DnsRecordType::A, "1.1.1.1", None);
+        for default in ["default", "默认", ""] {
+            let with_default =
+                record_with_line("www", DnsRecordType::A, "1.1.1.1", None, Some(default));
+            assert_eq!(plain.record_identity(), with_default.record_identity());
+        }
+        assert_ne!(plain.record_identity(), telecom.record_identity());
+    }
+
+    #[test]
+    fn test_translate_line_between_providers() {
+        assert_eq!(
+            translate_line(Some("telecom"), "aliyun", "dnspod"),
+            Ok(Some("电信".to_string()))
+        );
+        assert_eq!(
+            translate_line(Some("联通"), "dnspod", "aliyun"),
+            Ok(Some("unicom".to_string()))
+        );
+        // 默认线路在任何服务商之间都可以复制
+        assert_eq!(
+            translate_line(Some("默认"), "dnspod", "cloudflare"),
+            Ok(None)
+        );
+        assert_eq!(translate_line(None, "aliyun", "dnspod"), Ok(None));
+        // 同一服务商原样保留（含对照表之外的线路）
+        assert_eq!(
+            translate_line(Some("cn_telecom_beijing"), "aliyun", "aliyun"),
+            Ok(Some("cn_telecom_beijing".to_string()))
+        );
+        assert!(translate_line(Some("cn_telecom_beijing"), "aliyun", "dnspod").is_err());
+        assert!(translate_line(Some("telecom"), "aliyun", "cloudflare").is_err());
+    }
+}
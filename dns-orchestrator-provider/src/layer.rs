@@ -0,0 +1,477 @@
+//! Provider 装饰层
+//!
+//! 重试、日志、指标等横切逻辑实现为包装 `Arc<dyn DnsProvider>` 的装饰层，
+//! 按需叠加，而不必在每个服务商内部实现。
+//!
+//! 新的装饰层只需实现 [`Interceptor`]：[`Intercepted`] 负责把每个 `DnsProvider`
+//! 方法委托给内部 Provider，并在调用外层包裹拦截器。
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::RetryPolicy;
+use crate::traits::DnsProvider;
+use crate::types::{
+    BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, PaginatedResponse, PaginationParams,
+    ProviderDomain, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+/// Provider 操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProviderOperation {
+    ValidateCredentials,
+    ListDomains,
+    GetDomain,
+    ListRecords,
+    GetRecord,
+    CreateRecord,
+    UpdateRecord,
+    DeleteRecord,
+    DeleteRrset,
+    BatchCreateRecords,
+    BatchUpdateRecords,
+    BatchDeleteRecords,
+}
+
+impl ProviderOperation {
+    /// 是否为只读操作（可安全重试）
+    pub fn is_read_only(self) -> bool {
+        matches!(
+            self,
+            Self::ValidateCredentials
+                | Self::ListDomains
+                | Self::GetDomain
+                | Self::ListRecords
+                | Self::GetRecord
+        )
+    }
+
+    /// 操作名称（用于日志）
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ValidateCredentials => "validate_credentials",
+            Self::ListDomains => "list_domains",
+            Self::GetDomain => "get_domain",
+            Self::ListRecords => "list_records",
+            Self::GetRecord => "get_record",
+            Self::CreateRecord => "create_record",
+            Self::UpdateRecord => "update_record",
+            Self::DeleteRecord => "delete_record",
+            Self::DeleteRrset => "delete_rrset",
+            Self::BatchCreateRecords => "batch_create_records",
+            Self::BatchUpdateRecords => "batch_update_records",
+            Self::BatchDeleteRecords => "batch_delete_records",
+        }
+    }
+}
+
+/// 调用拦截器：包裹每一次 Provider 调用
+///
+/// `call` 每调用一次即向内部 Provider 发起一次请求，可多次调用（如重试）或不调用。
+pub trait Interceptor: Send + Sync + 'static {
+    fn intercept<T, F, Fut>(
+        &self,
+        provider: &'static str,
+        operation: ProviderOperation,
+        call: F,
+    ) -> impl Future<Output = Result<T>> + Send
+    where
+        T: Send,
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send;
+}
+
+/// 装饰层：将 Provider 包装为新的 Provider
+pub trait ProviderLayer: Send + Sync {
+    fn layer(&self, inner: Arc<dyn DnsProvider>) -> Arc<dyn DnsProvider>;
+}
+
+impl<I: Interceptor + Clone> ProviderLayer for I {
+    fn layer(&self, inner: Arc<dyn DnsProvider>) -> Arc<dyn DnsProvider> {
+        Arc::new(Intercepted::new(inner, self.clone()))
+    }
+}
+
+/// 依次叠加装饰层：列表中靠前的层更靠近内部 Provider
+pub fn apply_layers(
+    provider: Arc<dyn DnsProvider>,
+    layers: &[Arc<dyn ProviderLayer>],
+) -> Arc<dyn DnsProvider> {
+    layers
+        .iter()
+        .fold(provider, |provider, layer| layer.layer(provider))
+}
+
+/// 由拦截器驱动的 Provider 包装
+pub struct Intercepted<I> {
+    inner: Arc<dyn DnsProvider>,
+    interceptor: I,
+}
+
+impl<I: Interceptor> Intercepted<I> {
+    pub fn new(inner: Arc<dyn DnsProvider>, interceptor: I) -> Self {
+        Self { inner, interceptor }
+    }
+}
+
+#[async_trait]
+impl<I: Interceptor> DnsProvider for Intercepted<I> {
+    fn id(&self) -> &'static str {
+        self.inner.id()
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::ValidateCredentials, || {
+                self.inner.validate_credentials()
+            })
+            .await
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::ListDomains, || {
+                self.inner.list_domains(params)
+            })
+            .await
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::GetDomain, || {
+                self.inner.get_domain(domain_id)
+            })
+            .await
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::ListRecords, || {
+                self.inner.list_records(domain_id, params)
+            })
+            .await
+    }
+
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::GetRecord, || {
+                self.inner.get_record(record_id, domain_id)
+            })
+            .await
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::CreateRecord, || {
+                self.inner.create_record(req)
+            })
+            .await
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::UpdateRecord, || {
+                self.inner.update_record(record_id, req)
+            })
+            .await
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::DeleteRecord, || {
+                self.inner.delete_record(record_id, domain_id)
+            })
+            .await
+    }
+
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::DeleteRrset, || {
+                self.inner.delete_rrset(domain_id, name, record_type)
+            })
+            .await
+    }
+
+    async fn batch_create_records(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+    ) -> Result<BatchCreateResult> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::BatchCreateRecords, || {
+                self.inner.batch_create_records(requests)
+            })
+            .await
+    }
+
+    async fn batch_update_records(&self, updates: &[BatchUpdateItem]) -> Result<BatchUpdateResult> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::BatchUpdateRecords, || {
+                self.inner.batch_update_records(updates)
+            })
+            .await
+    }
+
+    async fn batch_delete_records(
+        &self,
+        domain_id: &str,
+        record_ids: &[String],
+    ) -> Result<BatchDeleteResult> {
+        self.interceptor
+            .intercept(self.id(), ProviderOperation::BatchDeleteRecords, || {
+                self.inner.batch_delete_records(domain_id, record_ids)
+            })
+            .await
+    }
+}
+
+// ============ 内置装饰层 ============
+
+/// 重试层：只读操作遇到网络错误时按 [`RetryPolicy`] 退避重试
+///
+/// 写操作不重试（请求可能已生效，重试会导致重复创建）。
+/// HTTP 层已对单个请求重试，此层作用于整个操作（如多次分页请求组成的 `get_record`）。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RetryLayer {
+    policy: RetryPolicy,
+}
+
+impl RetryLayer {
+    pub fn new(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+impl Interceptor for RetryLayer {
+    async fn intercept<T, F, Fut>(
+        &self,
+        provider: &'static str,
+        operation: ProviderOperation,
+        mut call: F,
+    ) -> Result<T>
+    where
+        T: Send,
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        let mut retry = 0;
+        loop {
+            match call().await {
+                Err(ProviderError::NetworkError { detail, .. })
+                    if operation.is_read_only() && retry + 1 < self.policy.max_attempts =>
+                {
+                    let delay = self.policy.delay(retry);
+                    log::debug!(
+                        "[{provider}] {} failed ({detail}), retrying in {delay:?}",
+                        operation.as_str()
+                    );
+                    tokio::time::sleep(delay).await;
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// 日志层：记录每次调用的耗时与失败原因
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LoggingLayer;
+
+impl Interceptor for LoggingLayer {
+    async fn intercept<T, F, Fut>(
+        &self,
+        provider: &'static str,
+        operation: ProviderOperation,
+        mut call: F,
+    ) -> Result<T>
+    where
+        T: Send,
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        let started = Instant::now();
+        let result = call().await;
+        match &result {
+            Ok(_) => log::debug!(
+                "[{provider}] {} ok in {:?}",
+                operation.as_str(),
+                started.elapsed()
+            ),
+            Err(e) => log::warn!(
+                "[{provider}] {} failed in {:?}: {e}",
+                operation.as_str(),
+                started.elapsed()
+            ),
+        }
+        result
+    }
+}
+
+/// 单个操作的调用统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OperationStats {
+    /// 调用次数
+    pub calls: u64,
+    /// 失败次数
+    pub errors: u64,
+    /// 累计耗时
+    pub total_duration: Duration,
+}
+
+/// 指标层：按操作统计调用次数、失败次数与耗时
+///
+/// 克隆的实例共享同一份统计，可在叠加后通过原实例读取。
+#[derive(Debug, Clone, Default)]
+pub struct MetricsLayer {
+    stats: Arc<Mutex<HashMap<ProviderOperation, OperationStats>>>,
+}
+
+impl MetricsLayer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 当前统计快照
+    pub fn snapshot(&self) -> HashMap<ProviderOperation, OperationStats> {
+        self.stats
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl Interceptor for MetricsLayer {
+    async fn intercept<T, F, Fut>(
+        &self,
+        _provider: &'static str,
+        operation: ProviderOperation,
+        mut call: F,
+    ) -> Result<T>
+    where
+        T: Send,
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T>> + Send,
+    {
+        let started = Instant::now();
+        let result = call().await;
+        let mut stats = self
+            .stats
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entry = stats.entry(operation).or_default();
+        entry.calls += 1;
+        entry.errors += u64::from(result.is_err());
+        entry.total_duration += started.elapsed();
+        result
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+    use crate::providers::MockProvider;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// 前 `n` 次调用直接返回网络错误的测试层
+    #[derive(Clone)]
+    struct FailFirst(Arc<AtomicU32>);
+
+    impl Interceptor for FailFirst {
+        async fn intercept<T, F, Fut>(
+            &self,
+            provider: &'static str,
+            _operation: ProviderOperation,
+            mut call: F,
+        ) -> Result<T>
+        where
+            T: Send,
+            F: FnMut() -> Fut + Send,
+            Fut: Future<Output = Result<T>> + Send,
+        {
+            let remaining = self.0.load(Ordering::SeqCst);
+            if remaining > 0 {
+                self.0.store(remaining - 1, Ordering::SeqCst);
+                return Err(ProviderError::NetworkError {
+                    provider: provider.to_string(),
+                    detail: "connection reset".to_string(),
+                });
+            }
+            call().await
+        }
+    }
+
+    fn no_delay() -> RetryPolicy {
+        RetryPolicy {
+            base_delay: Duration::ZERO,
+            ..RetryPolicy::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stacked_layers_over_mock() {
+        let failures = Arc::new(AtomicU32::new(1));
+        let metrics = MetricsLayer::new();
+        let provider = apply_layers(
+            Arc::new(MockProvider::new(None)),
+            &[
+                Arc::new(FailFirst(failures.clone())),
+                Arc::new(metrics.clone()),
+                Arc::new(RetryLayer::new(no_delay())),
+            ],
+        );
+        assert_eq!(provider.id(), "mock");
+
+        // 只读操作：首次网络错误被重试层吸收，指标层记录两次调用
+        let domains = provider
+            .list_domains(&PaginationParams::default())
+            .await
+            .unwrap();
+        assert_eq!(domains.total_count, 2);
+        let stats = metrics.snapshot()[&ProviderOperation::ListDomains];
+        assert_eq!((stats.calls, stats.errors), (2, 1));
+
+        // 写操作不重试
+        failures.store(1, Ordering::SeqCst);
+        let req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "192.0.2.1".to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        let result = provider.create_record(&req).await;
+        assert!(matches!(result, Err(ProviderError::NetworkError { .. })));
+        let stats = metrics.snapshot()[&ProviderOperation::CreateRecord];
+        assert_eq!((stats.calls, stats.errors), (1, 1));
+
+        assert!(provider.create_record(&req).await.is_ok());
+    }
+}
@@ -1,7 +1,9 @@
 //! DNS Provider abstraction library for multiple cloud platforms
 //!
 //! This library provides a unified interface for managing DNS records across
-//! different cloud providers including Cloudflare, Aliyun, DNSPod, and Huaweicloud.
+//! different cloud providers including Cloudflare, Aliyun, DNSPod, Huaweicloud,
+//! Google Cloud DNS, Namecheap, Porkbun and self-hosted servers via RFC 2136
+//! dynamic updates.
 //!
 //! # Features
 //!
@@ -9,19 +11,28 @@
 //! - `aliyun` - Enable Aliyun DNS provider
 //! - `dnspod` - Enable Tencent Cloud DNSPod provider
 //! - `huaweicloud` - Enable Huawei Cloud DNS provider
+//! - `gclouddns` - Enable Google Cloud DNS provider
+//! - `namecheap` - Enable Namecheap provider
+//! - `porkbun` - Enable Porkbun provider
+//! - `rfc2136` - Enable RFC 2136 dynamic update provider (BIND, Knot, PowerDNS...)
 //! - `all-providers` - Enable all providers
+//! - `mock` - Enable the in-memory mock provider (testing and offline demos)
 //! - `native-tls` - Use native TLS backend (default)
 //! - `rustls` - Use rustls TLS backend (recommended for Android)
 //!
 //! # Example
 //!
 //! ```rust,no_run
-//! use dns_orchestrator_provider::{create_provider, DnsProvider, PaginationParams, ProviderCredentials};
+//! use dns_orchestrator_provider::{
+//!     create_provider, CloudflareAuth, DnsProvider, PaginationParams, ProviderCredentials,
+//! };
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
 //!     let credentials = ProviderCredentials::Cloudflare {
-//!         api_token: "your-token".to_string(),
+//!         auth: CloudflareAuth::ApiToken {
+//!             api_token: "your-token".to_string(),
+//!         },
 //!     };
 //!
 //!     let provider = create_provider(credentials)?;
@@ -38,33 +49,71 @@
 mod error;
 mod factory;
 mod http_client;
+mod identity;
+mod layer;
+mod precondition;
 mod providers;
+mod rate_limit;
 mod traits;
 mod types;
 mod utils;
+mod validation;
 
 // Re-export error types
 pub use error::{ProviderError, Result};
 
+// Re-export retry policy
+pub use http_client::{HttpClientConfig, ProxyConfig, RetryPolicy};
+
+// Re-export shared HTTP client (for outbound requests outside providers)
+pub use providers::common::build_http_client;
+
+// Re-export TXT quoting (shared with zone file export)
+pub use providers::common::quote_txt_chunks;
+
+// Re-export rate limit config
+pub use rate_limit::RateLimit;
+
+// Re-export provider layers
+pub use layer::{
+    Intercepted, Interceptor, LoggingLayer, MetricsLayer, OperationStats, ProviderLayer,
+    ProviderOperation, RetryLayer, apply_layers,
+};
+
+// Re-export record identity
+pub use identity::{RecordIdentity, normalize_line, translate_line};
+
+// Re-export optimistic concurrency check
+pub use precondition::ensure_unchanged;
+
+// Re-export record validation
+pub use validation::{validate_record, validate_record_support};
+
 // Re-export factory functions
-pub use factory::{create_provider, get_all_provider_metadata};
+pub use factory::{
+    DEFAULT_TTL, ProviderOptions, create_provider, create_provider_with_layers,
+    create_provider_with_options, default_ttl_for, get_all_provider_metadata, provider_metadata,
+};
 
 // Re-export core trait only (internal traits are not exported)
-pub use traits::DnsProvider;
+pub use traits::{DnsProvider, ProviderDescriptor};
 
 // Re-export types
 pub use types::{
     BatchCreateFailure, BatchCreateResult, BatchDeleteFailure, BatchDeleteResult,
     BatchUpdateFailure, BatchUpdateItem, BatchUpdateResult, CreateDnsRecordRequest,
     CredentialValidationError, DnsRecord, DnsRecordType, DomainStatus, FieldType,
-    PaginatedResponse, PaginationParams, ProviderCredentialField, ProviderCredentials,
-    ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata, ProviderType,
-    RecordQueryParams, UpdateDnsRecordRequest,
+    PaginatedResponse, PaginationMode, PaginationParams, ProviderCredentialField,
+    ProviderCredentials, ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata,
+    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 // Re-export utils module
 pub use utils::datetime;
 
+#[cfg(feature = "cloudflare")]
+pub use types::CloudflareAuth;
+
 // Re-export concrete providers (behind feature flags)
 #[cfg(feature = "cloudflare")]
 pub use providers::CloudflareProvider;
@@ -77,3 +126,18 @@ pub use providers::DnspodProvider;
 
 #[cfg(feature = "huaweicloud")]
 pub use providers::HuaweicloudProvider;
+
+#[cfg(feature = "gclouddns")]
+pub use providers::GcloudDnsProvider;
+
+#[cfg(feature = "namecheap")]
+pub use providers::NamecheapProvider;
+
+#[cfg(feature = "porkbun")]
+pub use providers::PorkbunProvider;
+
+#[cfg(feature = "rfc2136")]
+pub use providers::Rfc2136Provider;
+
+#[cfg(feature = "mock")]
+pub use providers::MockProvider;
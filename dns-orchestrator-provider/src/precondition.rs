@@ -0,0 +1,168 @@
+//! 记录更新的乐观并发检查
+//!
+//! 服务商的记录更新接口普遍不支持 If-Match，两个管理员同时编辑同一条记录时后写者会静默覆盖。
+//! 调用方在更新请求中带上读取时的版本（修改时间）或记录值，更新前与服务商当前的记录比对，
+//! 不一致时返回 `ProviderError::Conflict`。
+//!
+//! 比对与更新是两次独立的请求，两者之间仍存在短暂的竞态窗口（TOCTOU）：
+//! 比对通过后、更新到达前被其他人修改的记录仍会被覆盖。该检查只能发现读取后到比对前的修改，
+//! 完全消除竞态需要服务商原生支持条件更新。
+
+use chrono::DateTime;
+
+use crate::error::{ProviderError, Result};
+use crate::identity::RecordIdentity;
+use crate::types::{DnsRecord, UpdateDnsRecordRequest};
+
+impl DnsRecord {
+    /// 记录版本（最后修改时间，RFC3339），服务商不返回修改时间时为 `None`
+    pub fn version(&self) -> Option<String> {
+        self.updated_at.map(|t| t.to_rfc3339())
+    }
+}
+
+impl UpdateDnsRecordRequest {
+    /// 是否带有乐观并发条件
+    pub fn has_precondition(&self) -> bool {
+        self.expected_version.is_some() || self.expected_value.is_some()
+    }
+}
+
+/// 检查当前记录是否仍与调用方读取时一致
+///
+/// - `expected_version`：与记录的修改时间比较；服务商不返回修改时间时无法比较，
+///   返回 `InvalidParameter`（应改用 `expected_value`）
+/// - `expected_value`：按记录身份规则比较记录值（忽略主机名大小写与末尾的点）
+pub fn ensure_unchanged(
+    current: &DnsRecord,
+    req: &UpdateDnsRecordRequest,
+    provider: &str,
+) -> Result<()> {
+    let conflict = |detail: String| ProviderError::Conflict {
+        provider: provider.to_string(),
+        record_id: current.id.clone(),
+        detail,
+    };
+
+    if let Some(expected) = &req.expected_version {
+        let Some(actual) = current.updated_at else {
+            return Err(ProviderError::InvalidParameter {
+                provider: provider.to_string(),
+                param: "expectedVersion".to_string(),
+                detail: "该服务商不返回记录修改时间，无法按版本检查，请改用 expectedValue"
+                    .to_string(),
+            });
+        };
+        let unchanged = DateTime::parse_from_rfc3339(expected)
+            .map_or_else(|_| *expected == actual.to_rfc3339(), |t| t == actual);
+        if !unchanged {
+            return Err(conflict(format!(
+                "version {expected} != {}",
+                actual.to_rfc3339()
+            )));
+        }
+    }
+
+    if let Some(expected) = &req.expected_value {
+        let expected_identity = RecordIdentity::new(
+            &current.name,
+            &current.record_type,
+            expected,
+            current.priority,
+            current.line.as_deref(),
+        );
+        if current.record_identity() != expected_identity {
+            return Err(conflict(format!(
+                "value '{expected}' != '{}'",
+                current.value
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+    use crate::types::DnsRecordType;
+
+    fn current() -> DnsRecord {
+        DnsRecord {
+            id: "r1".to_string(),
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::Cname,
+            name: "www".to_string(),
+            value: "origin.example.net".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: Some(Utc.with_ymd_and_hms(2024, 5, 1, 12, 0, 0).unwrap()),
+        }
+    }
+
+    fn update(
+        expected_version: Option<&str>,
+        expected_value: Option<&str>,
+    ) -> UpdateDnsRecordRequest {
+        UpdateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::Cname,
+            name: "www".to_string(),
+            value: "new.example.net".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            expected_version: expected_version.map(str::to_string),
+            expected_value: expected_value.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_version_check() {
+        let record = current();
+        let version = record.version().unwrap();
+        assert!(ensure_unchanged(&record, &update(Some(&version), None), "mock").is_ok());
+        // 同一时刻的不同时区表示视为相同版本
+        assert!(
+            ensure_unchanged(
+                &record,
+                &update(Some("2024-05-01T20:00:00+08:00"), None),
+                "mock"
+            )
+            .is_ok()
+        );
+
+        let err = ensure_unchanged(&record, &update(Some("2024-04-30T00:00:00Z"), None), "mock")
+            .unwrap_err();
+        assert!(matches!(err, ProviderError::Conflict { ref record_id, .. } if record_id == "r1"));
+    }
+
+    #[test]
+    fn test_value_check_without_version() {
+        let mut record = current();
+        record.updated_at = None;
+        assert!(matches!(
+            ensure_unchanged(&record, &update(Some("anything"), None), "mock"),
+            Err(ProviderError::InvalidParameter { ref param, .. }) if param == "expectedVersion"
+        ));
+        assert!(
+            ensure_unchanged(&record, &update(None, Some("Origin.example.net.")), "mock").is_ok()
+        );
+        assert!(matches!(
+            ensure_unchanged(&record, &update(None, Some("other.example.net")), "mock"),
+            Err(ProviderError::Conflict { .. })
+        ));
+    }
+}
@@ -23,37 +23,40 @@ impl AliyunProvider {
         // 1. 序列化参数为 query string
         let query_string = serialize_to_query_string(params)?;
 
-        let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
-        let nonce = uuid::Uuid::new_v4().to_string();
-
-        // 2. 生成签名 (使用 query string)
-        let authorization = self.sign(action, &query_string, &timestamp, &nonce);
-
-        // 3. 构造 URL (参数在 query string 中)
+        // 2. 构造 URL (参数在 query string 中)
         let url = if query_string.is_empty() {
-            format!("https://{ALIYUN_DNS_HOST}/")
+            format!("{}/", self.api_base)
         } else {
-            format!("https://{ALIYUN_DNS_HOST}/?{query_string}")
+            format!("{}/?{query_string}", self.api_base)
         };
 
-        // 4. 发送请求 (body 为空，使用 HttpUtils)
-        let request = self
-            .client
-            .post(&url)
-            .header("Host", ALIYUN_DNS_HOST)
-            .header("x-acs-action", action)
-            .header("x-acs-version", ALIYUN_DNS_VERSION)
-            .header("x-acs-date", &timestamp)
-            .header("x-acs-signature-nonce", &nonce)
-            .header("x-acs-content-sha256", EMPTY_BODY_SHA256)
-            .header("Authorization", authorization);
+        // 3. 每次尝试重新签名 (SignatureNonce 不可重复使用)
+        let build_request = || {
+            let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+            let nonce = uuid::Uuid::new_v4().to_string();
+            let authorization = self.sign(action, &query_string, &timestamp, &nonce);
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
-            request,
+            self.client
+                .post(&url)
+                .header("Host", ALIYUN_DNS_HOST)
+                .header("x-acs-action", action)
+                .header("x-acs-version", ALIYUN_DNS_VERSION)
+                .header("x-acs-date", &timestamp)
+                .header("x-acs-signature-nonce", &nonce)
+                .header("x-acs-content-sha256", EMPTY_BODY_SHA256)
+                .header("Authorization", authorization)
+        };
+
+        // 4. 发送请求 (body 为空，使用 HttpUtils)
+        // 查询类 Action 可安全重放，写操作只在请求未被处理时重试
+        let (_status, response_text) = HttpUtils::execute_request_with_retry_fn(
+            build_request,
+            action.starts_with("Describe"),
             self.provider_name(),
             "POST",
             &format!("{} (Action: {})", url, action),
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -6,14 +6,20 @@ mod provider;
 mod sign;
 mod types;
 
+use std::time::Duration;
+
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
 
 pub(crate) use types::{
-    AddDomainRecordResponse, AliyunResponse, DeleteDomainRecordResponse,
-    DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
-    UpdateDomainRecordResponse, serialize_to_query_string,
+    AddDomainRecordResponse, AliyunRecord, AliyunResponse, DeleteDomainRecordResponse,
+    DeleteSubDomainRecordsResponse, DescribeDomainInfoResponse, DescribeDomainRecordsResponse,
+    DescribeDomainsResponse, UpdateDnsSlbWeightResponse, UpdateDomainRecordResponse,
+    serialize_to_query_string,
 };
 
 pub(crate) const ALIYUN_DNS_HOST: &str = "alidns.cn-hangzhou.aliyuncs.com";
@@ -23,20 +29,27 @@ pub(crate) const EMPTY_BODY_SHA256: &str =
     "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
 /// 阿里云 API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+/// 阿里云负载均衡权重上限（取值 1-100）
+pub(crate) const MAX_WEIGHT: u16 = 100;
 
 /// 阿里云 DNS Provider
 pub struct AliyunProvider {
     pub(crate) client: Client,
+    /// API 根地址（测试时指向本地服务）
+    pub(crate) api_base: String,
     pub(crate) access_key_id: String,
     pub(crate) access_key_secret: String,
-    pub(crate) max_retries: u32,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 /// 阿里云 Provider Builder
 pub struct AliyunProviderBuilder {
     access_key_id: String,
     access_key_secret: String,
-    max_retries: u32,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
 }
 
 impl AliyunProviderBuilder {
@@ -44,21 +57,56 @@ impl AliyunProviderBuilder {
         Self {
             access_key_id,
             access_key_secret,
-            max_retries: 2,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Aliyun),
+            http_config: HttpClientConfig::default(),
         }
     }
 
+    /// 最大重试次数（不含首次请求）
     pub fn max_retries(mut self, retries: u32) -> Self {
-        self.max_retries = retries;
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
         self
     }
 
     pub fn build(self) -> AliyunProvider {
         AliyunProvider {
-            client: create_http_client(),
+            client: build_http_client(&self.http_config),
+            api_base: format!("https://{ALIYUN_DNS_HOST}"),
             access_key_id: self.access_key_id,
             access_key_secret: self.access_key_secret,
-            max_retries: self.max_retries,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
         }
     }
 }
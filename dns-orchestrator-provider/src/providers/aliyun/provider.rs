@@ -5,18 +5,22 @@ use chrono::DateTime;
 use serde::Serialize;
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::{parse_record_type, record_type_to_string};
-use crate::traits::{DnsProvider, ErrorContext};
+use crate::providers::common::{
+    combine_record_value, mx_priority, parse_record_type, record_type_to_string,
+    split_combined_value,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor, ProviderErrorMapper};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::{
-    AddDomainRecordResponse, AliyunProvider, DeleteDomainRecordResponse,
-    DescribeDomainInfoResponse, DescribeDomainRecordsResponse, DescribeDomainsResponse,
-    MAX_PAGE_SIZE, UpdateDomainRecordResponse,
+    AddDomainRecordResponse, AliyunProvider, AliyunRecord, DeleteDomainRecordResponse,
+    DeleteSubDomainRecordsResponse, DescribeDomainInfoResponse, DescribeDomainRecordsResponse,
+    DescribeDomainsResponse, MAX_PAGE_SIZE, MAX_WEIGHT, UpdateDnsSlbWeightResponse,
+    UpdateDomainRecordResponse,
 };
 
 impl AliyunProvider {
@@ -35,14 +39,86 @@ impl AliyunProvider {
     pub(crate) fn timestamp_to_datetime(timestamp: Option<i64>) -> Option<DateTime<chrono::Utc>> {
         timestamp.and_then(DateTime::from_timestamp_millis)
     }
-}
 
-#[async_trait]
-impl DnsProvider for AliyunProvider {
-    fn id(&self) -> &'static str {
-        "aliyun"
+    /// 将阿里云记录转换为 `DnsRecord`（拆分 MX/SRV/CAA 组合值）
+    pub(crate) fn convert_record(record: AliyunRecord, domain_id: &str) -> Option<DnsRecord> {
+        let record_type = parse_record_type(&record.record_type, "aliyun").ok()?;
+        let (value, priority) = split_combined_value(&record_type, &record.value, record.priority);
+        Some(DnsRecord {
+            id: record.record_id,
+            domain_id: domain_id.to_string(),
+            record_type,
+            name: record.rr,
+            value,
+            ttl: record.ttl,
+            priority,
+            proxied: None, // 阿里云不支持代理
+            line: record.line,
+            weight: record.weight,
+            comment: None,
+            tags: None,
+            created_at: Self::timestamp_to_datetime(record.create_timestamp),
+            updated_at: Self::timestamp_to_datetime(record.update_timestamp),
+        })
+    }
+
+    /// 检查权重是否在阿里云允许的范围内（1-100）
+    pub(crate) fn ensure_weight_in_range(
+        record_type: &DnsRecordType,
+        weight: Option<u16>,
+    ) -> Result<()> {
+        match weight {
+            Some(w) if w == 0 || w > MAX_WEIGHT => Err(ProviderError::InvalidRecord {
+                provider: "aliyun".to_string(),
+                record_type: record_type_to_string(record_type).to_string(),
+                detail: format!("权重必须在 1-{MAX_WEIGHT} 之间: {w}"),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// 设置记录的负载均衡权重（`UpdateDNSSLBWeight`）
+    async fn set_weight(&self, record_id: &str, weight: u16, ctx: ErrorContext) -> Result<()> {
+        #[derive(Serialize)]
+        struct UpdateDnsSlbWeightRequest {
+            #[serde(rename = "RecordId")]
+            record_id: String,
+            #[serde(rename = "Weight")]
+            weight: u16,
+        }
+
+        let api_req = UpdateDnsSlbWeightRequest {
+            record_id: record_id.to_string(),
+            weight,
+        };
+        let _response: UpdateDnsSlbWeightResponse =
+            self.request("UpdateDNSSLBWeight", &api_req, ctx).await?;
+        Ok(())
+    }
+
+    /// 将 `DescribeDomainRecords` 响应转换为分页结果
+    ///
+    /// `total_count` 取自 API 返回的 `TotalCount`，`page_size` 须为实际请求的 `PageSize`
+    pub(crate) fn records_page(
+        response: DescribeDomainRecordsResponse,
+        domain_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> PaginatedResponse<DnsRecord> {
+        let total_count = response.total_count.unwrap_or(0);
+        let records = response
+            .domain_records
+            .and_then(|r| r.record)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|r| Self::convert_record(r, domain_id))
+            .collect();
+
+        PaginatedResponse::new(records, page, page_size, total_count)
     }
+}
 
+impl ProviderDescriptor for AliyunProvider {
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Aliyun,
@@ -55,6 +131,7 @@ impl DnsProvider for AliyunProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 AccessKey ID".to_string()),
                     help_text: None,
+                    group: None,
                 },
                 ProviderCredentialField {
                     key: "accessKeySecret".to_string(),
@@ -62,15 +139,41 @@ impl DnsProvider for AliyunProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 AccessKey Secret".to_string()),
                     help_text: None,
+                    group: None,
                 },
             ],
-            features: ProviderFeatures::default(),
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
+            features: ProviderFeatures {
+                line: true,
+                weight: true,
+                ..Default::default()
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 100,
                 max_page_size_records: 100,
+                min_ttl: 600,
+                max_ttl: 86_400,
+                default_ttl: 600,
             },
+            pagination: PaginationMode::Page,
         }
     }
+}
+
+#[async_trait]
+impl DnsProvider for AliyunProvider {
+    fn id(&self) -> &'static str {
+        "aliyun"
+    }
 
     async fn validate_credentials(&self) -> Result<bool> {
         #[derive(Serialize)]
@@ -109,11 +212,15 @@ impl DnsProvider for AliyunProvider {
             page_number: u32,
             #[serde(rename = "PageSize")]
             page_size: u32,
+            #[serde(rename = "KeyWord", skip_serializing_if = "Option::is_none")]
+            key_word: Option<String>,
         }
 
+        let page_size = params.page_size.min(MAX_PAGE_SIZE);
         let req = DescribeDomainsRequest {
             page_number: params.page,
-            page_size: params.page_size.min(MAX_PAGE_SIZE),
+            page_size,
+            key_word: params.name_filter().map(ToString::to_string),
         };
 
         let response: DescribeDomainsResponse = self
@@ -133,13 +240,16 @@ impl DnsProvider for AliyunProvider {
                 provider: ProviderType::Aliyun,
                 status: Self::convert_domain_status(d.domain_status.as_deref()),
                 record_count: d.record_count,
+                name_servers: d.dns_servers.map(|s| s.dns_server).unwrap_or_default(),
+                created_at: Self::timestamp_to_datetime(d.create_timestamp),
+                modified_at: None,
             })
             .collect();
 
         Ok(PaginatedResponse::new(
             domains,
             params.page,
-            params.page_size,
+            page_size,
             total_count,
         ))
     }
@@ -172,6 +282,12 @@ impl DnsProvider for AliyunProvider {
             provider: ProviderType::Aliyun,
             status: Self::convert_domain_status(response.domain_status.as_deref()),
             record_count: response.record_count,
+            name_servers: response
+                .dns_servers
+                .map(|s| s.dns_server)
+                .unwrap_or_default(),
+            created_at: None,
+            modified_at: None,
         })
     }
 
@@ -197,10 +313,11 @@ impl DnsProvider for AliyunProvider {
         }
 
         // 阿里云的 domain_id 就是域名名称，可以直接使用
+        let page_size = params.page_size.min(MAX_PAGE_SIZE);
         let req = DescribeDomainRecordsRequest {
             domain_name: domain_id.to_string(),
             page_number: params.page,
-            page_size: params.page_size.min(MAX_PAGE_SIZE),
+            page_size,
             rr_keyword: params.keyword.clone().filter(|k| !k.is_empty()),
             record_type: params
                 .record_type
@@ -216,37 +333,37 @@ impl DnsProvider for AliyunProvider {
         let response: DescribeDomainRecordsResponse =
             self.request("DescribeDomainRecords", &req, ctx).await?;
 
-        let total_count = response.total_count.unwrap_or(0);
-        let records = response
-            .domain_records
-            .and_then(|r| r.record)
-            .unwrap_or_default()
-            .into_iter()
-            .filter_map(|r| {
-                let record_type = parse_record_type(&r.record_type, "aliyun").ok()?;
-                Some(DnsRecord {
-                    id: r.record_id,
-                    domain_id: domain_id.to_string(),
-                    record_type,
-                    name: r.rr,
-                    value: r.value,
-                    ttl: r.ttl,
-                    priority: r.priority,
-                    proxied: None, // 阿里云不支持代理
-                    created_at: Self::timestamp_to_datetime(r.create_timestamp),
-                    updated_at: Self::timestamp_to_datetime(r.update_timestamp),
-                })
-            })
-            .collect();
-
-        Ok(PaginatedResponse::new(
-            records,
+        Ok(Self::records_page(
+            response,
+            domain_id,
             params.page,
-            params.page_size,
-            total_count,
+            page_size,
         ))
     }
 
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        #[derive(Serialize)]
+        struct DescribeDomainRecordInfoRequest {
+            #[serde(rename = "RecordId")]
+            record_id: String,
+        }
+
+        let req = DescribeDomainRecordInfoRequest {
+            record_id: record_id.to_string(),
+        };
+
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let record: AliyunRecord = self.request("DescribeDomainRecordInfo", &req, ctx).await?;
+        let record_type = record.record_type.clone();
+        Self::convert_record(record, domain_id)
+            .ok_or_else(|| self.parse_error(format!("unsupported record type: {record_type}")))
+    }
+
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
         #[derive(Serialize)]
         struct AddDomainRecordRequest {
@@ -262,16 +379,21 @@ impl DnsProvider for AliyunProvider {
             ttl: u32,
             #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
             priority: Option<u16>,
+            #[serde(rename = "Line", skip_serializing_if = "Option::is_none")]
+            line: Option<String>,
         }
 
+        Self::ensure_weight_in_range(&req.record_type, req.weight)?;
+
         // 阿里云的 domain_id 就是域名名称，可以直接使用
         let api_req = AddDomainRecordRequest {
             domain_name: req.domain_id.clone(),
             rr: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            value: req.value.clone(),
+            value: combine_record_value(&req.record_type, &req.value, req.priority),
             ttl: req.ttl,
-            priority: req.priority,
+            priority: mx_priority(&req.record_type, req.priority),
+            line: req.line.clone(),
         };
 
         let ctx = ErrorContext {
@@ -280,8 +402,24 @@ impl DnsProvider for AliyunProvider {
             ..Default::default()
         };
 
-        let response: AddDomainRecordResponse =
-            self.request("AddDomainRecord", &api_req, ctx).await?;
+        let response: AddDomainRecordResponse = self
+            .request("AddDomainRecord", &api_req, ctx.clone())
+            .await?;
+        // 权重设置失败时删除刚创建的记录，避免留下未按要求设置权重的记录
+        if let Some(weight) = req.weight
+            && let Err(e) = self.set_weight(&response.record_id, weight, ctx).await
+        {
+            if let Err(rollback) = self
+                .delete_record(&response.record_id, &req.domain_id)
+                .await
+            {
+                log::warn!(
+                    "[aliyun] 设置权重失败后删除记录 {} 失败，需手动清理: {rollback}",
+                    response.record_id
+                );
+            }
+            return Err(e);
+        }
 
         let now = chrono::Utc::now();
         Ok(DnsRecord {
@@ -293,6 +431,10 @@ impl DnsProvider for AliyunProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            weight: req.weight,
+            comment: None,
+            tags: None,
             created_at: Some(now),
             updated_at: Some(now),
         })
@@ -317,15 +459,20 @@ impl DnsProvider for AliyunProvider {
             ttl: u32,
             #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
             priority: Option<u16>,
+            #[serde(rename = "Line", skip_serializing_if = "Option::is_none")]
+            line: Option<String>,
         }
 
+        Self::ensure_weight_in_range(&req.record_type, req.weight)?;
+
         let api_req = UpdateDomainRecordRequest {
             record_id: record_id.to_string(),
             rr: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            value: req.value.clone(),
+            value: combine_record_value(&req.record_type, &req.value, req.priority),
             ttl: req.ttl,
-            priority: req.priority,
+            priority: mx_priority(&req.record_type, req.priority),
+            line: req.line.clone(),
         };
 
         let ctx = ErrorContext {
@@ -334,8 +481,12 @@ impl DnsProvider for AliyunProvider {
             domain: Some(req.domain_id.clone()),
         };
 
-        let _response: UpdateDomainRecordResponse =
-            self.request("UpdateDomainRecord", &api_req, ctx).await?;
+        let _response: UpdateDomainRecordResponse = self
+            .request("UpdateDomainRecord", &api_req, ctx.clone())
+            .await?;
+        if let Some(weight) = req.weight {
+            self.set_weight(record_id, weight, ctx).await?;
+        }
 
         let now = chrono::Utc::now();
         Ok(DnsRecord {
@@ -347,6 +498,10 @@ impl DnsProvider for AliyunProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: req.line.clone(),
+            weight: req.weight,
+            comment: None,
+            tags: None,
             created_at: None,
             updated_at: Some(now),
         })
@@ -374,4 +529,400 @@ impl DnsProvider for AliyunProvider {
 
         Ok(())
     }
+
+    /// 使用 `DeleteSubDomainRecords` 原生删除整个 RRset
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        #[derive(Serialize)]
+        struct DeleteSubDomainRecordsRequest {
+            #[serde(rename = "DomainName")]
+            domain_name: String,
+            #[serde(rename = "RR")]
+            rr: String,
+            #[serde(rename = "Type")]
+            record_type: String,
+        }
+
+        let api_req = DeleteSubDomainRecordsRequest {
+            domain_name: domain_id.to_string(),
+            rr: name.to_string(),
+            record_type: record_type_to_string(record_type).to_string(),
+        };
+
+        let ctx = ErrorContext {
+            record_name: Some(name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let _response: DeleteSubDomainRecordsResponse = self
+            .request("DeleteSubDomainRecords", &api_req, ctx)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::test_server::TestServer;
+
+    /// `DescribeDomainRecords` 响应（取自真实返回，已脱敏）
+    const DESCRIBE_DOMAIN_RECORDS_FIXTURE: &str = r#"{
+        "TotalCount": 3,
+        "PageNumber": 1,
+        "PageSize": 20,
+        "DomainRecords": {
+            "Record": [
+                {
+                    "RecordId": "9999985", "RR": "@", "Type": "MX",
+                    "Value": "mxw.mxhichina.com", "TTL": 600, "Priority": 5,
+                    "Line": "default", "Status": "ENABLE", "Locked": false
+                },
+                {
+                    "RecordId": "9999986", "RR": "_sip._tcp", "Type": "SRV",
+                    "Value": "10 60 5060 sip.example.com", "TTL": 600,
+                    "Line": "default", "Status": "ENABLE", "Locked": false
+                },
+                {
+                    "RecordId": "9999987", "RR": "@", "Type": "CAA",
+                    "Value": "0 issue \"letsencrypt.org\"", "TTL": 600,
+                    "Line": "default", "Status": "ENABLE", "Locked": false
+                }
+            ]
+        }
+    }"#;
+
+    #[test]
+    fn test_convert_records_from_fixture() {
+        let response: DescribeDomainRecordsResponse =
+            serde_json::from_str(DESCRIBE_DOMAIN_RECORDS_FIXTURE).unwrap();
+        let records: Vec<DnsRecord> = response
+            .domain_records
+            .and_then(|r| r.record)
+            .unwrap()
+            .into_iter()
+            .filter_map(|r| AliyunProvider::convert_record(r, "example.com"))
+            .collect();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].value, "mxw.mxhichina.com");
+        assert_eq!(records[0].priority, Some(5));
+        assert_eq!(records[1].value, "60 5060 sip.example.com");
+        assert_eq!(records[1].priority, Some(10));
+        assert_eq!(records[2].value, "0 issue \"letsencrypt.org\"");
+        assert_eq!(records[2].priority, None);
+        assert!(records.iter().all(|r| r.line.as_deref() == Some("default")));
+    }
+
+    fn records_response(count: usize, total_count: u32) -> DescribeDomainRecordsResponse {
+        let records: Vec<_> = (0..count)
+            .map(|i| {
+                serde_json::json!({
+                    "RecordId": i.to_string(), "RR": format!("r{i}"), "Type": "A",
+                    "Value": "192.0.2.1", "TTL": 600, "Line": "default"
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "TotalCount": total_count,
+            "DomainRecords": { "Record": records }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_records_page_boundaries() {
+        // 40 条记录、每页 20：第 1 页还有下一页，第 2 页恰好结束
+        let page = AliyunProvider::records_page(records_response(20, 40), "example.com", 1, 20);
+        assert_eq!(page.total_count, 40);
+        assert!(page.has_more);
+
+        let page = AliyunProvider::records_page(records_response(20, 40), "example.com", 2, 20);
+        assert_eq!(page.items.len(), 20);
+        assert!(!page.has_more);
+
+        let page = AliyunProvider::records_page(records_response(0, 0), "example.com", 1, 20);
+        assert_eq!(page.total_count, 0);
+        assert!(!page.has_more);
+
+        // 请求的 page_size 超过上限时，按实际生效的 page_size 判断
+        let page_size = 500.min(MAX_PAGE_SIZE);
+        let page =
+            AliyunProvider::records_page(records_response(100, 250), "example.com", 1, page_size);
+        assert_eq!(page.page_size, MAX_PAGE_SIZE);
+        assert!(page.has_more);
+    }
+
+    #[test]
+    fn test_convert_describe_domain_record_info() {
+        // `DescribeDomainRecordInfo` 直接返回扁平的记录字段
+        let record: AliyunRecord = serde_json::from_str(
+            r#"{
+                "RequestId": "536E9CAD-DB30-4647-AC87-AA5CC38C5382",
+                "DomainId": "00efb6a3-e3d0-4aaa-a20e-b1e3c6ec4cd5",
+                "DomainName": "example.com",
+                "RecordId": "9999985", "RR": "www", "Type": "A",
+                "Value": "192.0.2.1", "TTL": 600,
+                "Line": "default", "Status": "ENABLE", "Locked": false
+            }"#,
+        )
+        .unwrap();
+        let record = AliyunProvider::convert_record(record, "example.com").unwrap();
+        assert_eq!(record.id, "9999985");
+        assert_eq!(record.name, "www");
+        assert_eq!(record.value, "192.0.2.1");
+        assert_eq!(record.ttl, 600);
+    }
+
+    #[test]
+    fn test_srv_value_round_trip() {
+        for (value, priority) in [
+            ("5 5060 sip.example.com", Some(1)),
+            ("1 5 5060 sip.example.com.", None),
+        ] {
+            // 写入时 SRV 优先级拼入 Value，Priority 参数只用于 MX
+            let record = AliyunRecord {
+                record_id: "1".to_string(),
+                rr: "_sip._tcp".to_string(),
+                record_type: "SRV".to_string(),
+                value: combine_record_value(&DnsRecordType::Srv, value, priority),
+                ttl: 600,
+                priority: mx_priority(&DnsRecordType::Srv, priority),
+                line: None,
+                weight: None,
+                create_timestamp: None,
+                update_timestamp: None,
+            };
+            let record = AliyunProvider::convert_record(record, "example.com").unwrap();
+            assert_eq!(record.value, "5 5060 sip.example.com");
+            assert_eq!(record.priority, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_caa_value_round_trip() {
+        for value in ["0 issue letsencrypt.org", "0 issue \"letsencrypt.org\""] {
+            // 阿里云使用扁平字符串，写入规范格式
+            let raw = combine_record_value(&DnsRecordType::Caa, value, None);
+            assert_eq!(raw, "0 issue \"letsencrypt.org\"");
+
+            let record = AliyunRecord {
+                record_id: "1".to_string(),
+                rr: "@".to_string(),
+                record_type: "CAA".to_string(),
+                value: raw,
+                ttl: 600,
+                priority: mx_priority(&DnsRecordType::Caa, None),
+                line: None,
+                weight: None,
+                create_timestamp: None,
+                update_timestamp: None,
+            };
+            let record = AliyunProvider::convert_record(record, "example.com").unwrap();
+            assert_eq!(record.value, "0 issue \"letsencrypt.org\"");
+            assert_eq!(record.priority, None);
+        }
+    }
+
+    #[test]
+    fn test_ptr_rejected() {
+        let err =
+            crate::validate_record_support("aliyun", &DnsRecordType::Ptr, None, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::UnsupportedRecordType { ref record_type, .. } if record_type == "PTR"
+        ));
+    }
+
+    #[test]
+    fn test_alias_rejected() {
+        // 根域名 CNAME 读回时无法与 ALIAS 区分，不支持 ALIAS
+        let err =
+            crate::validate_record_support("aliyun", &DnsRecordType::Alias, None, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::UnsupportedRecordType { ref record_type, .. } if record_type == "ALIAS"
+        ));
+    }
+
+    #[test]
+    fn test_weight_round_trip() {
+        let record: AliyunRecord = serde_json::from_str(
+            r#"{
+                "RecordId": "9999985", "RR": "www", "Type": "A",
+                "Value": "192.0.2.1", "TTL": 600, "Weight": 30,
+                "Line": "default", "Status": "ENABLE", "Locked": false
+            }"#,
+        )
+        .unwrap();
+        let record = AliyunProvider::convert_record(record, "example.com").unwrap();
+        assert_eq!(record.weight, Some(30));
+
+        assert!(AliyunProvider::ensure_weight_in_range(&record.record_type, record.weight).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_record_rolls_back_when_weight_fails() {
+        let server = TestServer::start(|req| match req.header("x-acs-action") {
+            Some("AddDomainRecord") => (200, r#"{"RecordId": "9999990"}"#.to_string()),
+            Some("UpdateDNSSLBWeight") => (
+                400,
+                r#"{"Code": "DisableDNSSLB", "Message": "SLB is disabled"}"#.to_string(),
+            ),
+            _ => (200, r#"{"RecordId": "9999990"}"#.to_string()),
+        })
+        .await;
+        let mut provider = AliyunProvider::new("id".to_string(), "secret".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "192.0.2.1".to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: Some(30),
+            comment: None,
+            tags: None,
+        };
+        assert!(provider.create_record(&req).await.is_err());
+
+        let actions: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|r| r.header("x-acs-action").unwrap_or_default().to_string())
+            .collect();
+        assert_eq!(
+            actions,
+            [
+                "AddDomainRecord",
+                "UpdateDNSSLBWeight",
+                "DeleteDomainRecord"
+            ]
+        );
+        assert!(server.requests()[2].path.contains("RecordId=9999990"));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_weight_rejected_before_request() {
+        let provider = AliyunProvider::new("id".to_string(), "secret".to_string());
+        for (record_type, weight) in [(DnsRecordType::A, 0), (DnsRecordType::A, 101)] {
+            let req = CreateDnsRecordRequest {
+                domain_id: "example.com".to_string(),
+                record_type,
+                name: "www".to_string(),
+                value: "192.0.2.1".to_string(),
+                ttl: 600,
+                priority: Some(10),
+                proxied: None,
+                line: None,
+                weight: Some(weight),
+                comment: None,
+                tags: None,
+            };
+
+            let err = provider.create_record(&req).await.unwrap_err();
+            assert!(
+                matches!(err, ProviderError::InvalidRecord { .. }),
+                "expected InvalidRecord, got {err:?}"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_line_sent_in_requests() {
+        let server = TestServer::start(|_| (200, r#"{"RecordId": "9999990"}"#.to_string())).await;
+        let mut provider = AliyunProvider::new("id".to_string(), "secret".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let mut req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "192.0.2.1".to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: Some("telecom".to_string()),
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        provider.create_record(&req).await.unwrap();
+        req.line = None;
+        provider.create_record(&req).await.unwrap();
+        let update = UpdateDnsRecordRequest {
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value: req.value.clone(),
+            ttl: req.ttl,
+            priority: None,
+            proxied: None,
+            line: Some("unicom".to_string()),
+            weight: None,
+            comment: None,
+            tags: None,
+            expected_version: None,
+            expected_value: None,
+        };
+        provider.update_record("9999990", &update).await.unwrap();
+
+        // 未设置线路时不发送 Line，由阿里云使用默认线路
+        let lines: Vec<_> = server
+            .requests()
+            .iter()
+            .map(|r| {
+                r.path
+                    .split(['?', '&'])
+                    .find_map(|p| p.strip_prefix("Line="))
+                    .map(ToString::to_string)
+            })
+            .collect();
+        assert_eq!(
+            lines,
+            [
+                Some("telecom".to_string()),
+                None,
+                Some("unicom".to_string())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_record_uses_describe_domain_record_info() {
+        let server = TestServer::start(|_| {
+            let body = serde_json::json!({
+                "RequestId": "req-1", "DomainName": "example.com",
+                "RecordId": "9999985", "RR": "www", "Type": "A",
+                "Value": "192.0.2.1", "TTL": 600, "Line": "telecom"
+            });
+            (200, body.to_string())
+        })
+        .await;
+        let mut provider = AliyunProvider::new("id".to_string(), "secret".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let record = provider.get_record("9999985", "example.com").await.unwrap();
+        assert_eq!(record.id, "9999985");
+        assert_eq!(record.name, "www");
+        assert_eq!(record.value, "192.0.2.1");
+        assert_eq!(record.line.as_deref(), Some("telecom"));
+
+        let requests = server.requests();
+        assert_eq!(
+            requests[0].header("x-acs-action"),
+            Some("DescribeDomainRecordInfo")
+        );
+        assert!(requests[0].path.contains("RecordId=9999985"));
+    }
 }
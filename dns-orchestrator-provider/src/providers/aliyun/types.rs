@@ -111,27 +111,36 @@ pub struct DomainsWrapper {
 
 #[derive(Debug, Deserialize)]
 pub struct AliyunDomain {
-    #[serde(rename = "DomainId")]
-    pub domain_id: Option<String>,
     #[serde(rename = "DomainName")]
     pub domain_name: String,
     #[serde(rename = "DomainStatus")]
     pub domain_status: Option<String>,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    #[serde(rename = "DnsServers")]
+    pub dns_servers: Option<DnsServers>,
+    #[serde(rename = "CreateTimestamp")]
+    pub create_timestamp: Option<i64>,
+}
+
+/// 域名的 DNS 服务器列表
+#[derive(Debug, Deserialize)]
+pub struct DnsServers {
+    #[serde(rename = "DnsServer", default)]
+    pub dns_server: Vec<String>,
 }
 
 /// ErrorRequireCheck: DescribeDomainInfo API 响应结构，需验证字段映射是否正确
 #[derive(Debug, Deserialize)]
 pub struct DescribeDomainInfoResponse {
-    #[serde(rename = "DomainId")]
-    pub domain_id: Option<String>,
     #[serde(rename = "DomainName")]
     pub domain_name: String,
     #[serde(rename = "DomainStatus")]
     pub domain_status: Option<String>,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    #[serde(rename = "DnsServers")]
+    pub dns_servers: Option<DnsServers>,
 }
 
 // ============ 记录相关结构 ============
@@ -164,6 +173,12 @@ pub struct AliyunRecord {
     pub ttl: u32,
     #[serde(rename = "Priority")]
     pub priority: Option<u16>,
+    /// 解析线路（`default`、`telecom`、`unicom`、`oversea` 等）
+    #[serde(rename = "Line")]
+    pub line: Option<String>,
+    /// 负载均衡权重（仅开启权重配置的子域名返回）
+    #[serde(rename = "Weight")]
+    pub weight: Option<u16>,
     #[serde(rename = "CreateTimestamp")]
     pub create_timestamp: Option<i64>,
     #[serde(rename = "UpdateTimestamp")]
@@ -183,9 +198,23 @@ pub struct UpdateDomainRecordResponse {
     pub record_id: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct UpdateDnsSlbWeightResponse {
+    #[serde(rename = "RequestId")]
+    #[allow(dead_code)]
+    pub request_id: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DeleteDomainRecordResponse {
     #[serde(rename = "RecordId")]
     #[allow(dead_code)]
     pub record_id: Option<String>,
 }
+
+#[derive(Debug, Deserialize)]
+pub struct DeleteSubDomainRecordsResponse {
+    #[serde(rename = "RR")]
+    #[allow(dead_code)]
+    pub rr: Option<String>,
+}
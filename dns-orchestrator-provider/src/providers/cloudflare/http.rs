@@ -7,9 +7,7 @@ use crate::http_client::HttpUtils;
 use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
 use crate::types::PaginationParams;
 
-use super::{
-    CF_API_BASE, CloudflareDnsRecord, CloudflareProvider, CloudflareResponse, MAX_PAGE_SIZE_ZONES,
-};
+use super::{CloudflareDnsRecord, CloudflareProvider, CloudflareResponse, MAX_PAGE_SIZE_ZONES};
 
 impl CloudflareProvider {
     /// 执行 GET 请求
@@ -18,20 +16,18 @@ impl CloudflareProvider {
         path: &str,
         ctx: ErrorContext,
     ) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.api_base);
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.authorize(self.client.get(&url));
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -58,6 +54,22 @@ impl CloudflareProvider {
             .ok_or_else(|| self.parse_error("响应中缺少 result 字段"))
     }
 
+    /// 构造分页请求 URL（名称过滤使用 `contains:` 运算符下推）
+    pub(crate) fn paginated_url(&self, path: &str, params: &PaginationParams) -> String {
+        // Cloudflare zones API 最大 per_page 是 50
+        let mut url = format!(
+            "{}{}?page={}&per_page={}",
+            self.api_base,
+            path,
+            params.page,
+            params.page_size.min(MAX_PAGE_SIZE_ZONES)
+        );
+        if let Some(filter) = params.name_filter() {
+            url.push_str(&format!("&name=contains:{}", urlencoding::encode(filter)));
+        }
+        url
+    }
+
     /// 执行 GET 请求 (带分页)
     pub(crate) async fn get_paginated<T: for<'de> Deserialize<'de>>(
         &self,
@@ -65,27 +77,18 @@ impl CloudflareProvider {
         params: &PaginationParams,
         ctx: ErrorContext,
     ) -> Result<(Vec<T>, u32)> {
-        // Cloudflare zones API 最大 per_page 是 50
-        let url = format!(
-            "{}{}?page={}&per_page={}",
-            CF_API_BASE,
-            path,
-            params.page,
-            params.page_size.min(MAX_PAGE_SIZE_ZONES)
-        );
+        let url = self.paginated_url(path, params);
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .get(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.authorize(self.client.get(&url));
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -114,25 +117,25 @@ impl CloudflareProvider {
     }
 
     /// 执行 GET 请求 (带自定义 URL，用于 list_records)
+    ///
+    /// 返回 (记录, 总数, 下一页游标)
     pub(crate) async fn get_records(
         &self,
         url: &str,
         ctx: ErrorContext,
-    ) -> Result<(Vec<CloudflareDnsRecord>, u32)> {
-        let full_url = format!("{CF_API_BASE}{url}");
+    ) -> Result<(Vec<CloudflareDnsRecord>, u32, Option<String>)> {
+        let full_url = format!("{}{url}", self.api_base);
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .get(&full_url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.authorize(self.client.get(&full_url));
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "GET",
             &full_url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -153,10 +156,12 @@ impl CloudflareProvider {
             return Err(self.map_error(RawApiError::with_code(code, message), ctx));
         }
 
-        let total_count = cf_response.result_info.map_or(0, |i| i.total_count);
+        let (total_count, next_cursor) = cf_response.result_info.map_or((0, None), |i| {
+            (i.total_count, i.cursor.filter(|c| !c.is_empty()))
+        });
         let records = cf_response.result.unwrap_or_default();
 
-        Ok((records, total_count))
+        Ok((records, total_count, next_cursor))
     }
 
     /// 执行 POST 请求
@@ -166,24 +171,21 @@ impl CloudflareProvider {
         body: &B,
         ctx: ErrorContext,
     ) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.api_base);
         let body_json =
             serde_json::to_string_pretty(body).unwrap_or_else(|_| "无法序列化请求体".to_string());
         log::debug!("Request Body: {body_json}");
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .json(body);
+        let request = self.authorize(self.client.post(&url)).json(body);
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "POST",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -217,24 +219,21 @@ impl CloudflareProvider {
         body: &B,
         ctx: ErrorContext,
     ) -> Result<T> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.api_base);
         let body_json =
             serde_json::to_string_pretty(body).unwrap_or_else(|_| "无法序列化请求体".to_string());
         log::debug!("Request Body: {body_json}");
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .patch(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token))
-            .json(body);
+        let request = self.authorize(self.client.patch(&url)).json(body);
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "PATCH",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -263,20 +262,18 @@ impl CloudflareProvider {
 
     /// 执行 DELETE 请求
     pub(crate) async fn delete(&self, path: &str, ctx: ErrorContext) -> Result<()> {
-        let url = format!("{CF_API_BASE}{path}");
+        let url = format!("{}{path}", self.api_base);
 
         // 使用 HttpUtils 发送请求（带重试）
-        let request = self
-            .client
-            .delete(&url)
-            .header("Authorization", format!("Bearer {}", self.api_token));
+        let request = self.authorize(self.client.delete(&url));
 
         let (_status, response_text) = HttpUtils::execute_request_with_retry(
             request,
             self.provider_name(),
             "DELETE",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -5,59 +5,133 @@ mod http;
 mod provider;
 mod types;
 
-use reqwest::Client;
+use std::time::Duration;
 
-use crate::providers::common::create_http_client;
+use reqwest::{Client, RequestBuilder};
 
-pub(crate) use types::{CloudflareDnsRecord, CloudflareResponse, CloudflareZone};
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::{CloudflareAuth, ProviderType};
+
+pub(crate) use types::{
+    CloudflareBatchResult, CloudflareDnsRecord, CloudflareResponse, CloudflareSvcbData,
+    CloudflareZone,
+};
 
 pub(crate) const CF_API_BASE: &str = "https://api.cloudflare.com/client/v4";
 /// Cloudflare Zones API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE_ZONES: u32 = 50;
 /// Cloudflare DNS Records API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE_RECORDS: u32 = 100;
+/// Cloudflare 批量 DNS 记录 API 单次请求最大变更数（免费套餐上限）
+pub(crate) const MAX_BATCH_CHANGES: usize = 200;
+/// Cloudflare 的特殊 TTL 值，表示"自动"（由 Cloudflare 决定，代理记录固定为 300）
+pub(crate) const AUTO_TTL: u32 = 1;
 
 /// Cloudflare DNS Provider
 pub struct CloudflareProvider {
     pub(crate) client: Client,
-    pub(crate) api_token: String,
-    pub(crate) max_retries: u32,
+    /// API 根地址（测试时指向本地服务）
+    pub(crate) api_base: String,
+    pub(crate) auth: CloudflareAuth,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 /// Cloudflare Provider Builder
 pub struct CloudflareProviderBuilder {
-    api_token: String,
-    max_retries: u32,
+    auth: CloudflareAuth,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
 }
 
 impl CloudflareProviderBuilder {
-    fn new(api_token: String) -> Self {
+    fn new(auth: CloudflareAuth) -> Self {
         Self {
-            api_token,
-            max_retries: 2, // 默认重试 2 次
+            auth,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Cloudflare),
+            http_config: HttpClientConfig::default(),
         }
     }
 
+    /// 最大重试次数（不含首次请求）
     pub fn max_retries(mut self, retries: u32) -> Self {
-        self.max_retries = retries;
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
         self
     }
 
     pub fn build(self) -> CloudflareProvider {
         CloudflareProvider {
-            client: create_http_client(),
-            api_token: self.api_token,
-            max_retries: self.max_retries,
+            client: build_http_client(&self.http_config),
+            api_base: CF_API_BASE.to_string(),
+            auth: self.auth,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
         }
     }
 }
 
 impl CloudflareProvider {
+    /// 使用 API Token 创建
     pub fn new(api_token: String) -> Self {
         Self::builder(api_token).build()
     }
 
+    /// 使用 API Token 创建 Builder
     pub fn builder(api_token: String) -> CloudflareProviderBuilder {
-        CloudflareProviderBuilder::new(api_token)
+        Self::builder_with_auth(CloudflareAuth::ApiToken { api_token })
+    }
+
+    /// 使用指定认证方式创建 Builder
+    pub fn builder_with_auth(auth: CloudflareAuth) -> CloudflareProviderBuilder {
+        CloudflareProviderBuilder::new(auth)
+    }
+
+    /// 根据认证方式设置请求头
+    ///
+    /// - API Token: `Authorization: Bearer <token>`
+    /// - Global API Key: `X-Auth-Email` + `X-Auth-Key`
+    pub(crate) fn authorize(&self, request: RequestBuilder) -> RequestBuilder {
+        match &self.auth {
+            CloudflareAuth::ApiToken { api_token } => {
+                request.header("Authorization", format!("Bearer {api_token}"))
+            }
+            CloudflareAuth::GlobalApiKey { api_email, api_key } => request
+                .header("X-Auth-Email", api_email)
+                .header("X-Auth-Key", api_key),
+        }
     }
 }
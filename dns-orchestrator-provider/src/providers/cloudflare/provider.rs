@@ -3,18 +3,32 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
+use crate::error::{ProviderError, Result};
 use crate::providers::common::{
-    full_name_to_relative, parse_record_type, record_type_to_string, relative_to_full_name,
+    CaaValue, SrvValue, SvcbValue, combine_record_value, full_name_to_relative,
+    is_service_binding_type, parse_record_type, parse_rfc3339, record_type_to_string,
+    relative_to_full_name, split_combined_value, validate_ptr_name,
 };
-use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor, ProviderErrorMapper};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    BatchCreateFailure, BatchCreateResult, CloudflareAuth, CreateDnsRecordRequest, DnsRecord,
+    DnsRecordType, DomainStatus, FieldType, PaginatedResponse, PaginationMode, PaginationParams,
+    ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits, ProviderMetadata,
+    ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
-use super::{CloudflareDnsRecord, CloudflareProvider, CloudflareZone, MAX_PAGE_SIZE_RECORDS};
+use super::{
+    AUTO_TTL, CloudflareBatchResult, CloudflareDnsRecord, CloudflareProvider, CloudflareSvcbData,
+    CloudflareZone, MAX_BATCH_CHANGES, MAX_PAGE_SIZE_RECORDS,
+};
+
+/// 提交给 Cloudflare 的记录类型：ALIAS 以根域名 CNAME 提交，由 Cloudflare 自动拉平
+fn wire_record_type(record_type: &DnsRecordType) -> &'static str {
+    match record_type {
+        DnsRecordType::Alias => "CNAME",
+        _ => record_type_to_string(record_type),
+    }
+}
 
 impl CloudflareProvider {
     /// 将 Cloudflare zone 转换为 ProviderDomain
@@ -33,6 +47,9 @@ impl CloudflareProvider {
             provider: ProviderType::Cloudflare,
             status,
             record_count: None,
+            name_servers: zone.name_servers,
+            created_at: zone.created_on.as_deref().and_then(parse_rfc3339),
+            modified_at: zone.modified_on.as_deref().and_then(parse_rfc3339),
         }
     }
 
@@ -43,17 +60,52 @@ impl CloudflareProvider {
         zone_id: &str,
         zone_name: &str,
     ) -> Result<DnsRecord> {
-        let record_type = parse_record_type(&cf_record.record_type, self.provider_name())?;
+        let mut record_type = parse_record_type(&cf_record.record_type, self.provider_name())?;
+        // Cloudflare 对根域名 CNAME 自动拉平，读回为 ALIAS
+        if record_type == DnsRecordType::Cname && cf_record.name.eq_ignore_ascii_case(zone_name) {
+            record_type = DnsRecordType::Alias;
+        }
+        // SRV 的 content 为 "<weight> <port> <target>"，统一规范化目标主机名；CAA 统一为规范格式；
+        // 拆分为多段的 TXT 合并为一个值
+        // HTTPS/SVCB 优先使用结构化的 data，统一为 "<priority> <target> <params>"
+        let (value, priority) = if is_service_binding_type(&record_type) {
+            let data = cf_record
+                .data
+                .and_then(|d| serde_json::from_value::<CloudflareSvcbData>(d).ok());
+            match data {
+                Some(data) => (
+                    SvcbValue {
+                        priority: data.priority,
+                        target: data.target,
+                        params: data.value,
+                    }
+                    .to_canonical(),
+                    None,
+                ),
+                None => split_combined_value(&record_type, &cf_record.content, None),
+            }
+        } else if matches!(
+            record_type,
+            DnsRecordType::Srv | DnsRecordType::Caa | DnsRecordType::Txt
+        ) {
+            split_combined_value(&record_type, &cf_record.content, cf_record.priority)
+        } else {
+            (cf_record.content, cf_record.priority)
+        };
 
         Ok(DnsRecord {
             id: cf_record.id,
             domain_id: zone_id.to_string(),
             record_type,
             name: full_name_to_relative(&cf_record.name, zone_name),
-            value: cf_record.content,
+            value,
             ttl: cf_record.ttl,
-            priority: cf_record.priority,
+            priority,
             proxied: cf_record.proxied,
+            line: None,
+            weight: None,
+            comment: cf_record.comment.filter(|c| !c.is_empty()),
+            tags: (!cf_record.tags.is_empty()).then_some(cf_record.tags),
             created_at: cf_record.created_on.and_then(|s| {
                 chrono::DateTime::parse_from_rfc3339(&s)
                     .ok()
@@ -66,35 +118,213 @@ impl CloudflareProvider {
             }),
         })
     }
-}
 
-#[async_trait]
-impl DnsProvider for CloudflareProvider {
-    fn id(&self) -> &'static str {
-        "cloudflare"
+    /// 构造写入记录的内容字段：SRV/CAA/HTTPS/SVCB 必须使用结构化的 `data`，其他类型使用 `content`
+    /// （超过 255 字节的 TXT 拆分为多个带引号的字符串）
+    pub(crate) fn record_content(
+        &self,
+        record_type: &DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> Result<RecordContent> {
+        if *record_type == DnsRecordType::Srv {
+            let srv = SrvValue::parse_or_invalid(value, priority, self.provider_name())?;
+            return Ok(RecordContent {
+                content: None,
+                priority: Some(srv.priority),
+                data: Some(RecordData::Srv(srv)),
+            });
+        }
+        if *record_type == DnsRecordType::Caa {
+            let caa = CaaValue::parse_or_invalid(value, self.provider_name())?;
+            return Ok(RecordContent {
+                content: None,
+                priority: None,
+                data: Some(RecordData::Caa(caa)),
+            });
+        }
+        if is_service_binding_type(record_type) {
+            let svcb = SvcbValue::parse_or_invalid(value, self.provider_name())?;
+            return Ok(RecordContent {
+                content: None,
+                priority: None,
+                data: Some(RecordData::Svcb(CloudflareSvcbData {
+                    priority: svcb.priority,
+                    target: svcb.target,
+                    value: svcb.params,
+                })),
+            });
+        }
+        Ok(RecordContent {
+            content: Some(combine_record_value(record_type, value, priority)),
+            data: None,
+            priority,
+        })
     }
 
+    /// 代理（橙色云朵）仅适用于 A/AAAA/CNAME（含 ALIAS）
+    fn ensure_proxiable(record_type: &DnsRecordType, proxied: Option<bool>) -> Result<()> {
+        let proxiable = matches!(
+            record_type,
+            DnsRecordType::A | DnsRecordType::Aaaa | DnsRecordType::Cname | DnsRecordType::Alias
+        );
+        if proxied == Some(true) && !proxiable {
+            return Err(ProviderError::InvalidParameter {
+                provider: "cloudflare".to_string(),
+                param: "proxied".to_string(),
+                detail: format!(
+                    "{} 记录不支持代理，仅 A/AAAA/CNAME 记录可开启",
+                    record_type_to_string(record_type)
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// 构造创建记录的请求体（单条创建与批量创建共用）
+    ///
+    /// ALIAS 以根域名 CNAME 提交，Cloudflare 对根域名 CNAME 自动拉平
+    fn create_record_body(
+        &self,
+        req: &CreateDnsRecordRequest,
+        zone_name: &str,
+    ) -> Result<CreateRecordBody> {
+        let full_name = relative_to_full_name(&req.name, zone_name);
+        if req.record_type == DnsRecordType::Ptr {
+            validate_ptr_name(&full_name, self.provider_name())?;
+        }
+
+        Ok(CreateRecordBody {
+            record_type: wire_record_type(&req.record_type).to_string(),
+            name: full_name,
+            content: self.record_content(&req.record_type, &req.value, req.priority)?,
+            // 省略 TTL（0）时使用 Cloudflare 的"自动"
+            ttl: if req.ttl == 0 { AUTO_TTL } else { req.ttl },
+            proxied: req.proxied,
+            comment: req.comment.clone(),
+            tags: req.tags.clone(),
+        })
+    }
+}
+
+/// 创建记录请求体
+#[derive(Debug, Serialize)]
+struct CreateRecordBody {
+    #[serde(rename = "type")]
+    record_type: String,
+    name: String,
+    #[serde(flatten)]
+    content: RecordContent,
+    ttl: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+}
+
+/// 批量操作请求体（仅使用 `posts`）
+#[derive(Debug, Serialize)]
+struct BatchBody<'a> {
+    posts: &'a [CreateRecordBody],
+}
+
+/// 记录内容字段（创建与更新共用）
+#[derive(Debug, Serialize)]
+pub(crate) struct RecordContent {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<RecordData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    priority: Option<u16>,
+}
+
+/// 结构化记录数据
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub(crate) enum RecordData {
+    Srv(SrvValue),
+    Caa(CaaValue),
+    Svcb(CloudflareSvcbData),
+}
+
+impl ProviderDescriptor for CloudflareProvider {
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Cloudflare,
             name: "Cloudflare".to_string(),
             description: "全球领先的 CDN 和 DNS 服务商".to_string(),
-            required_fields: vec![ProviderCredentialField {
-                key: "apiToken".to_string(),
-                label: "API Token".to_string(),
-                field_type: FieldType::Password,
-                placeholder: Some("输入 Cloudflare API Token".to_string()),
-                help_text: Some(
-                    "在 Cloudflare Dashboard -> My Profile -> API Tokens 创建".to_string(),
-                ),
-            }],
-            features: ProviderFeatures { proxy: true },
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "apiToken".to_string(),
+                    label: "API Token".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Cloudflare API Token".to_string()),
+                    help_text: Some(
+                        "推荐。在 Cloudflare Dashboard -> My Profile -> API Tokens 创建".to_string(),
+                    ),
+                    group: Some("apiToken".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "apiEmail".to_string(),
+                    label: "Email".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Cloudflare 账户邮箱".to_string()),
+                    help_text: Some("仅使用 Global API Key 时填写".to_string()),
+                    group: Some("globalApiKey".to_string()),
+                },
+                ProviderCredentialField {
+                    key: "globalApiKey".to_string(),
+                    label: "Global API Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Cloudflare Global API Key".to_string()),
+                    help_text: Some(
+                        "旧版认证方式，权限过大，建议改用 API Token。在 My Profile -> API Tokens -> Global API Key 查看"
+                            .to_string(),
+                    ),
+                    group: Some("globalApiKey".to_string()),
+                },
+            ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Ptr,
+                DnsRecordType::Https,
+                DnsRecordType::Svcb,
+                DnsRecordType::Alias,
+            ],
+            features: ProviderFeatures {
+                proxy: true,
+                apex_cname: true,
+                comment: true,
+                batch_native: true,
+                ..Default::default()
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 50,
                 max_page_size_records: 5000,
+                min_ttl: 60,
+                max_ttl: 86_400,
+                default_ttl: AUTO_TTL,
             },
+            pagination: PaginationMode::Cursor,
         }
     }
+}
+
+#[async_trait]
+impl DnsProvider for CloudflareProvider {
+    fn id(&self) -> &'static str {
+        "cloudflare"
+    }
 
     async fn validate_credentials(&self) -> Result<bool> {
         #[derive(Deserialize)]
@@ -102,12 +332,19 @@ impl DnsProvider for CloudflareProvider {
             status: String,
         }
 
-        match self
-            .get::<VerifyResponse>("/user/tokens/verify", ErrorContext::default())
-            .await
-        {
-            Ok(resp) => Ok(resp.status == "active"),
-            Err(_) => Ok(false),
+        match &self.auth {
+            CloudflareAuth::ApiToken { .. } => match self
+                .get::<VerifyResponse>("/user/tokens/verify", ErrorContext::default())
+                .await
+            {
+                Ok(resp) => Ok(resp.status == "active"),
+                Err(_) => Ok(false),
+            },
+            // Global API Key 无 verify 接口，能获取用户信息即视为有效
+            CloudflareAuth::GlobalApiKey { .. } => Ok(self
+                .get::<serde_json::Value>("/user", ErrorContext::default())
+                .await
+                .is_ok()),
         }
     }
 
@@ -152,13 +389,18 @@ impl DnsProvider for CloudflareProvider {
             .await?;
         let zone_name = zone.name;
 
-        // 构建查询 URL，包含搜索参数
-        let mut url = format!(
-            "/zones/{}/dns_records?page={}&per_page={}",
-            domain_id,
-            params.page,
-            params.page_size.min(MAX_PAGE_SIZE_RECORDS)
-        );
+        // 构建查询 URL，包含搜索参数；有游标时按游标翻页，否则按页码
+        let per_page = params.page_size.min(MAX_PAGE_SIZE_RECORDS);
+        let mut url = match params.cursor.as_deref().filter(|c| !c.is_empty()) {
+            Some(cursor) => format!(
+                "/zones/{domain_id}/dns_records?cursor={}&per_page={per_page}",
+                urlencoding::encode(cursor)
+            ),
+            None => format!(
+                "/zones/{domain_id}/dns_records?page={}&per_page={per_page}",
+                params.page
+            ),
+        };
 
         // 添加搜索关键词（只搜索记录名称）
         if let Some(ref keyword) = params.keyword
@@ -167,28 +409,55 @@ impl DnsProvider for CloudflareProvider {
             url.push_str(&format!("&name.contains={}", urlencoding::encode(keyword)));
         }
 
-        // 添加记录类型过滤
+        // 添加记录类型过滤；ALIAS 即根域名 CNAME
         if let Some(ref record_type) = params.record_type {
-            let type_str = record_type_to_string(record_type);
+            let type_str = wire_record_type(record_type);
             url.push_str(&format!("&type={}", urlencoding::encode(type_str)));
+            if *record_type == DnsRecordType::Alias {
+                url.push_str(&format!("&name.exact={}", urlencoding::encode(&zone_name)));
+            }
         }
 
-        let (cf_records, total_count) = self.get_records(&url, ctx).await?;
+        let (cf_records, mut total_count, next_cursor) = self.get_records(&url, ctx).await?;
 
-        let records: Result<Vec<DnsRecord>> = cf_records
+        let mut records = cf_records
             .into_iter()
             .map(|r| self.cf_record_to_dns_record(r, domain_id, &zone_name))
-            .collect();
+            .collect::<Result<Vec<DnsRecord>>>()?;
+        // 按 CNAME 过滤时排除读回为 ALIAS 的根域名记录（每个区域至多一条）
+        if params.record_type == Some(DnsRecordType::Cname) {
+            let before = records.len();
+            records.retain(|r| r.record_type == DnsRecordType::Cname);
+            let removed = u32::try_from(before - records.len()).unwrap_or(0);
+            total_count = total_count.saturating_sub(removed);
+        }
 
-        Ok(PaginatedResponse::new(
-            records?,
-            params.page,
-            params.page_size,
-            total_count,
-        ))
+        Ok(
+            PaginatedResponse::new(records, params.page, params.page_size, total_count)
+                .with_next_cursor(next_cursor),
+        )
+    }
+
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let zone: CloudflareZone = self
+            .get(&format!("/zones/{domain_id}"), ctx.clone())
+            .await?;
+
+        let cf_record: CloudflareDnsRecord = self
+            .get(&format!("/zones/{domain_id}/dns_records/{record_id}"), ctx)
+            .await?;
+
+        self.cf_record_to_dns_record(cf_record, domain_id, &zone.name)
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        Self::ensure_proxiable(&req.record_type, req.proxied)?;
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             domain: Some(req.domain_id.clone()),
@@ -201,29 +470,7 @@ impl DnsProvider for CloudflareProvider {
             .await?;
         let zone_name = zone.name;
 
-        let full_name = relative_to_full_name(&req.name, &zone_name);
-
-        #[derive(Serialize)]
-        struct CreateRecordBody {
-            #[serde(rename = "type")]
-            record_type: String,
-            name: String,
-            content: String,
-            ttl: u32,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            priority: Option<u16>,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            proxied: Option<bool>,
-        }
-
-        let body = CreateRecordBody {
-            record_type: record_type_to_string(&req.record_type).to_string(),
-            name: full_name,
-            content: req.value.clone(),
-            ttl: req.ttl,
-            priority: req.priority,
-            proxied: req.proxied,
-        };
+        let body = self.create_record_body(req, &zone_name)?;
 
         let cf_record: CloudflareDnsRecord = self
             .post(&format!("/zones/{}/dns_records", req.domain_id), &body, ctx)
@@ -237,6 +484,7 @@ impl DnsProvider for CloudflareProvider {
         record_id: &str,
         req: &UpdateDnsRecordRequest,
     ) -> Result<DnsRecord> {
+        Self::ensure_proxiable(&req.record_type, req.proxied)?;
         let ctx = ErrorContext {
             record_name: Some(req.name.clone()),
             record_id: Some(record_id.to_string()),
@@ -250,27 +498,34 @@ impl DnsProvider for CloudflareProvider {
         let zone_name = zone.name;
 
         let full_name = relative_to_full_name(&req.name, &zone_name);
+        if req.record_type == DnsRecordType::Ptr {
+            validate_ptr_name(&full_name, self.provider_name())?;
+        }
 
         #[derive(Serialize)]
         struct UpdateRecordBody {
             #[serde(rename = "type")]
             record_type: String,
             name: String,
-            content: String,
+            #[serde(flatten)]
+            content: RecordContent,
             ttl: u32,
             #[serde(skip_serializing_if = "Option::is_none")]
-            priority: Option<u16>,
-            #[serde(skip_serializing_if = "Option::is_none")]
             proxied: Option<bool>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            comment: Option<String>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            tags: Option<Vec<String>>,
         }
 
         let body = UpdateRecordBody {
-            record_type: record_type_to_string(&req.record_type).to_string(),
+            record_type: wire_record_type(&req.record_type).to_string(),
             name: full_name,
-            content: req.value.clone(),
+            content: self.record_content(&req.record_type, &req.value, req.priority)?,
             ttl: req.ttl,
-            priority: req.priority,
             proxied: req.proxied,
+            comment: req.comment.clone(),
+            tags: req.tags.clone(),
         };
 
         let cf_record: CloudflareDnsRecord = self
@@ -293,4 +548,835 @@ impl DnsProvider for CloudflareProvider {
         self.delete(&format!("/zones/{domain_id}/dns_records/{record_id}"), ctx)
             .await
     }
+
+    /// 使用 `POST /zones/{zone_id}/dns_records/batch` 批量创建
+    ///
+    /// 每次请求最多提交 [`MAX_BATCH_CHANGES`] 条，单次请求内的记录要么全部创建、要么全部失败；
+    /// 某一批被拒绝（如其中一条记录无效）时改为逐条创建该批记录，定位具体失败的记录。
+    /// 网络错误时该批请求可能已生效，不再重试，该批记录均计为失败
+    async fn batch_create_records(
+        &self,
+        requests: &[CreateDnsRecordRequest],
+    ) -> Result<BatchCreateResult> {
+        let failure = |index: usize, reason: String| BatchCreateFailure {
+            request_index: index,
+            record_name: requests[index].name.clone(),
+            reason,
+        };
+        let mut created = Vec::with_capacity(requests.len());
+        let mut failures = Vec::new();
+        let mut credentials_error = None;
+
+        // 按域名分组（保留请求下标），每个域名只查询一次 zone
+        let mut zones: Vec<(&str, Vec<usize>)> = Vec::new();
+        for (index, req) in requests.iter().enumerate() {
+            match zones
+                .iter_mut()
+                .find(|(domain_id, _)| *domain_id == req.domain_id)
+            {
+                Some((_, indices)) => indices.push(index),
+                None => zones.push((&req.domain_id, vec![index])),
+            }
+        }
+
+        for (domain_id, indices) in zones {
+            let ctx = ErrorContext {
+                domain: Some(domain_id.to_string()),
+                ..Default::default()
+            };
+            let zone: CloudflareZone =
+                match self.get(&format!("/zones/{domain_id}"), ctx.clone()).await {
+                    Ok(zone) => zone,
+                    Err(e) => {
+                        failures.extend(indices.into_iter().map(|i| failure(i, e.to_string())));
+                        if matches!(e, ProviderError::InvalidCredentials { .. }) {
+                            credentials_error = Some(e);
+                        }
+                        continue;
+                    }
+                };
+
+            // 先在本地构造请求体，构造失败的记录单独计为失败，不影响同批其他记录
+            let mut submitted = Vec::with_capacity(indices.len());
+            let mut bodies = Vec::with_capacity(indices.len());
+            for index in indices {
+                let req = &requests[index];
+                match Self::ensure_proxiable(&req.record_type, req.proxied)
+                    .and_then(|()| self.create_record_body(req, &zone.name))
+                {
+                    Ok(body) => {
+                        submitted.push(index);
+                        bodies.push(body);
+                    }
+                    Err(e) => failures.push(failure(index, e.to_string())),
+                }
+            }
+
+            for (chunk, chunk_indices) in bodies
+                .chunks(MAX_BATCH_CHANGES)
+                .zip(submitted.chunks(MAX_BATCH_CHANGES))
+            {
+                let result: CloudflareBatchResult = match self
+                    .post(
+                        &format!("/zones/{domain_id}/dns_records/batch"),
+                        &BatchBody { posts: chunk },
+                        ctx.clone(),
+                    )
+                    .await
+                {
+                    Ok(result) => result,
+                    Err(
+                        e @ (ProviderError::NetworkError { .. }
+                        | ProviderError::InvalidCredentials { .. }),
+                    ) => {
+                        failures.extend(chunk_indices.iter().map(|&i| failure(i, e.to_string())));
+                        if matches!(e, ProviderError::InvalidCredentials { .. }) {
+                            credentials_error = Some(e);
+                        }
+                        continue;
+                    }
+                    Err(e) => {
+                        log::warn!(
+                            "[{}] 批量创建被拒绝，改为逐条创建: {e}",
+                            self.provider_name()
+                        );
+                        for &index in chunk_indices {
+                            match self.create_record(&requests[index]).await {
+                                Ok(record) => created.push((index, record)),
+                                Err(e) => failures.push(failure(index, e.to_string())),
+                            }
+                        }
+                        continue;
+                    }
+                };
+
+                let mut posts = result.posts.into_iter();
+                for &index in chunk_indices {
+                    let record = posts
+                        .next()
+                        .ok_or_else(|| self.parse_error("批量创建响应缺少记录"))
+                        .and_then(|cf_record| {
+                            self.cf_record_to_dns_record(cf_record, domain_id, &zone.name)
+                        });
+                    match record {
+                        Ok(record) => created.push((index, record)),
+                        Err(e) => failures.push(failure(index, e.to_string())),
+                    }
+                }
+            }
+        }
+
+        // 凭证失效导致全部失败时整体返回错误，便于调用方标记账号状态
+        if let Some(e) = credentials_error.filter(|_| created.is_empty()) {
+            return Err(e);
+        }
+        created.sort_by_key(|(index, _)| *index);
+        failures.sort_by_key(|f| f.request_index);
+        Ok(BatchCreateResult {
+            success_count: created.len(),
+            failed_count: failures.len(),
+            created_records: created.into_iter().map(|(_, record)| record).collect(),
+            failures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ProviderError;
+    use crate::providers::cloudflare::CF_API_BASE;
+    use crate::providers::test_server::TestServer;
+
+    fn provider() -> CloudflareProvider {
+        CloudflareProvider::builder_with_auth(CloudflareAuth::ApiToken {
+            api_token: "token".to_string(),
+        })
+        .build()
+    }
+
+    #[test]
+    fn test_zone_to_domain_metadata() {
+        let zone: CloudflareZone = serde_json::from_value(serde_json::json!({
+            "id": "zone-1",
+            "name": "example.com",
+            "status": "active",
+            "name_servers": ["ada.ns.cloudflare.com", "bob.ns.cloudflare.com"],
+            "created_on": "2024-01-02T03:04:05.123456Z",
+            "modified_on": "2024-02-03T04:05:06Z"
+        }))
+        .unwrap();
+
+        let domain = CloudflareProvider::zone_to_domain(zone);
+        assert_eq!(
+            domain.name_servers,
+            ["ada.ns.cloudflare.com", "bob.ns.cloudflare.com"]
+        );
+        assert_eq!(
+            domain.created_at.map(|t| t.to_rfc3339()),
+            Some("2024-01-02T03:04:05.123456+00:00".to_string())
+        );
+        assert!(domain.modified_at.is_some());
+    }
+
+    #[test]
+    fn test_zones_url_pushes_name_filter() {
+        let mut params = PaginationParams {
+            page: 2,
+            page_size: 100,
+            name_filter: None,
+        };
+        assert_eq!(
+            provider().paginated_url("/zones", &params),
+            format!("{CF_API_BASE}/zones?page=2&per_page=50")
+        );
+
+        params.name_filter = Some("my shop".to_string());
+        assert_eq!(
+            provider().paginated_url("/zones", &params),
+            format!("{CF_API_BASE}/zones?page=2&per_page=50&name=contains:my%20shop")
+        );
+    }
+
+    #[test]
+    fn test_batch_create_body_and_result() {
+        let provider = provider();
+        let req = CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "1.2.3.4".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: Some(true),
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        let bodies = vec![provider.create_record_body(&req, "example.com").unwrap()];
+        let body = serde_json::to_value(BatchBody { posts: &bodies }).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "posts": [{
+                    "type": "A", "name": "www.example.com", "content": "1.2.3.4",
+                    "ttl": 300, "proxied": true
+                }]
+            })
+        );
+
+        let result: CloudflareBatchResult = serde_json::from_value(serde_json::json!({
+            "posts": [
+                { "id": "rec1", "type": "A", "name": "www.example.com", "content": "1.2.3.4", "ttl": 300 },
+                { "id": "rec2", "type": "A", "name": "api.example.com", "content": "1.2.3.5", "ttl": 300 }
+            ]
+        }))
+        .unwrap();
+        let ids: Vec<_> = result.posts.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["rec1", "rec2"]);
+    }
+
+    /// 指向本地测试服务的 Provider
+    fn local_provider(server: &TestServer) -> CloudflareProvider {
+        let mut provider = provider();
+        provider.api_base.clone_from(&server.url);
+        provider
+    }
+
+    fn zone_response() -> String {
+        serde_json::json!({
+            "success": true,
+            "result": { "id": "zone-1", "name": "example.com", "status": "active" }
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_keeps_records_of_earlier_chunks() {
+        // 第一批成功，第二批被拒绝
+        let server = TestServer::start(|req| {
+            if req.method == "GET" {
+                return (200, zone_response());
+            }
+            let body: serde_json::Value = serde_json::from_str(&req.body).unwrap();
+            let rejected = serde_json::json!({
+                "success": false,
+                "errors": [{ "code": 81057, "message": "Record already exists." }]
+            });
+            // 逐条创建时同样拒绝
+            let Some(posts) = body["posts"].as_array() else {
+                return (400, rejected.to_string());
+            };
+            if posts.len() < MAX_BATCH_CHANGES {
+                return (400, rejected.to_string());
+            }
+            let created: Vec<_> = posts
+                .iter()
+                .enumerate()
+                .map(|(i, post)| {
+                    serde_json::json!({
+                        "id": format!("rec{i}"), "type": post["type"], "name": post["name"],
+                        "content": post["content"], "ttl": post["ttl"]
+                    })
+                })
+                .collect();
+            let result = serde_json::json!({ "success": true, "result": { "posts": created } });
+            (200, result.to_string())
+        })
+        .await;
+
+        let requests: Vec<_> = (0..=MAX_BATCH_CHANGES)
+            .map(|i| CreateDnsRecordRequest {
+                domain_id: "zone-1".to_string(),
+                record_type: DnsRecordType::A,
+                name: format!("host{i}"),
+                value: "192.0.2.1".to_string(),
+                ttl: 300,
+                priority: None,
+                proxied: None,
+                line: None,
+                weight: None,
+                comment: None,
+                tags: None,
+            })
+            .collect();
+        let result = local_provider(&server)
+            .batch_create_records(&requests)
+            .await
+            .unwrap();
+
+        assert_eq!(result.success_count, MAX_BATCH_CHANGES);
+        assert_eq!(result.created_records[0].name, "host0");
+        assert_eq!(result.failed_count, 1);
+        assert_eq!(result.failures[0].request_index, MAX_BATCH_CHANGES);
+        assert_eq!(
+            result.failures[0].record_name,
+            format!("host{MAX_BATCH_CHANGES}")
+        );
+
+        // 被拒绝的一批改为逐条创建，已成功的一批不会重复提交
+        let posts: Vec<_> = server
+            .requests()
+            .into_iter()
+            .filter(|r| r.method == "POST")
+            .map(|r| r.path)
+            .collect();
+        assert_eq!(
+            posts,
+            [
+                "/zones/zone-1/dns_records/batch",
+                "/zones/zone-1/dns_records/batch",
+                "/zones/zone-1/dns_records",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comment_and_tags_round_trip() {
+        let provider = provider();
+        let req: CreateDnsRecordRequest = serde_json::from_value(serde_json::json!({
+            "domainId": "zone-1", "type": "A", "name": "www", "value": "1.2.3.4",
+            "ttl": 300, "comment": "legacy web server", "tags": ["owner:web"]
+        }))
+        .unwrap();
+        let body = provider.create_record_body(&req, "example.com").unwrap();
+        let body = serde_json::to_value(&body).unwrap();
+        assert_eq!(body["comment"], "legacy web server");
+        assert_eq!(body["tags"], serde_json::json!(["owner:web"]));
+
+        // 列表接口返回的备注与标签保留到 DnsRecord
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec1", "type": "A", "name": "www.example.com", "content": "1.2.3.4",
+            "ttl": 300, "comment": "legacy web server", "tags": ["owner:web"]
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone-1", "example.com")
+            .unwrap();
+        assert_eq!(record.comment.as_deref(), Some("legacy web server"));
+        assert_eq!(record.tags, Some(vec!["owner:web".to_string()]));
+
+        // 未设置时不发送、不返回
+        let req = CreateDnsRecordRequest {
+            comment: None,
+            tags: None,
+            ..req
+        };
+        let body = provider.create_record_body(&req, "example.com").unwrap();
+        let body = serde_json::to_value(&body).unwrap();
+        assert!(body.get("comment").is_none() && body.get("tags").is_none());
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec1", "type": "A", "name": "www.example.com", "content": "1.2.3.4",
+            "ttl": 300, "comment": null, "tags": []
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone-1", "example.com")
+            .unwrap();
+        assert_eq!((record.comment, record.tags), (None, None));
+    }
+
+    #[test]
+    fn test_omitted_ttl_uses_automatic() {
+        let provider = provider();
+        let req: CreateDnsRecordRequest = serde_json::from_value(serde_json::json!({
+            "domainId": "zone-1", "type": "A", "name": "www", "value": "1.2.3.4"
+        }))
+        .unwrap();
+        assert_eq!(req.ttl, 0);
+
+        let body = provider.create_record_body(&req, "example.com").unwrap();
+        assert_eq!(body.ttl, AUTO_TTL);
+    }
+
+    #[test]
+    fn test_alias_sent_as_apex_cname() {
+        let provider = provider();
+        let req: CreateDnsRecordRequest = serde_json::from_value(serde_json::json!({
+            "domainId": "zone-1", "type": "ALIAS", "name": "@",
+            "value": "lb.example.net", "proxied": true
+        }))
+        .unwrap();
+        assert!(CloudflareProvider::ensure_proxiable(&req.record_type, req.proxied).is_ok());
+
+        let body = provider.create_record_body(&req, "example.com").unwrap();
+        assert_eq!(body.record_type, "CNAME");
+        assert_eq!(body.name, "example.com");
+        assert_eq!(body.content.content.as_deref(), Some("lb.example.net"));
+        assert_eq!(body.proxied, Some(true));
+
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec-1", "type": "CNAME", "name": "example.com",
+            "content": "lb.example.net", "ttl": 1, "proxied": true
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone-1", "example.com")
+            .unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Alias);
+        assert_eq!(record.name, "@");
+    }
+
+    #[tokio::test]
+    async fn test_alias_filter_matches_apex_cname_only() {
+        let server = TestServer::start(|req| {
+            if !req.path.contains("/dns_records") {
+                return (200, zone_response());
+            }
+            let cname = |id: &str, name: &str| {
+                serde_json::json!({
+                    "id": id, "type": "CNAME", "name": name,
+                    "content": "lb.example.net", "ttl": 1
+                })
+            };
+            let result = if req.path.contains("name.exact=") {
+                vec![cname("apex", "example.com")]
+            } else {
+                vec![
+                    cname("apex", "example.com"),
+                    cname("www", "www.example.com"),
+                ]
+            };
+            let total = result.len();
+            let body = serde_json::json!({
+                "success": true,
+                "result": result,
+                "result_info": { "page": 1, "per_page": 20, "total_count": total }
+            });
+            (200, body.to_string())
+        })
+        .await;
+        let provider = local_provider(&server);
+
+        let query = |record_type| RecordQueryParams {
+            record_type: Some(record_type),
+            ..RecordQueryParams::default()
+        };
+        let aliases = provider
+            .list_records("zone-1", &query(DnsRecordType::Alias))
+            .await
+            .unwrap();
+        assert_eq!(aliases.items.len(), 1);
+        assert_eq!(aliases.items[0].record_type, DnsRecordType::Alias);
+        assert_eq!(aliases.items[0].name, "@");
+
+        let cnames = provider
+            .list_records("zone-1", &query(DnsRecordType::Cname))
+            .await
+            .unwrap();
+        let ids: Vec<_> = cnames.items.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["www"]);
+        assert_eq!(cnames.total_count, 1);
+
+        let paths: Vec<_> = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .filter(|p| p.contains("/dns_records"))
+            .collect();
+        assert!(paths[0].ends_with("&type=CNAME&name.exact=example.com"));
+        assert!(paths[1].ends_with("&type=CNAME"));
+    }
+
+    #[test]
+    fn test_srv_value_round_trip() {
+        let provider = provider();
+        for (value, priority) in [
+            ("5 5060 sip.example.com", Some(1)),
+            ("1 5 5060 sip.example.com.", None),
+        ] {
+            let content = provider
+                .record_content(&DnsRecordType::Srv, value, priority)
+                .unwrap();
+            let body = serde_json::to_value(&content).unwrap();
+            assert!(body.get("content").is_none());
+            assert_eq!(
+                body["data"],
+                serde_json::json!({
+                    "priority": 1, "weight": 5, "port": 5060, "target": "sip.example.com"
+                })
+            );
+
+            // Cloudflare 返回的 SRV 记录：content 为 "<weight> <port> <target>"，优先级单独返回
+            let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+                "id": "rec1",
+                "type": "SRV",
+                "name": "_sip._tcp.example.com",
+                "content": "5 5060 sip.example.com",
+                "priority": body["priority"],
+                "ttl": 300,
+            }))
+            .unwrap();
+            let record = provider
+                .cf_record_to_dns_record(cf_record, "zone1", "example.com")
+                .unwrap();
+            assert_eq!(record.name, "_sip._tcp");
+            assert_eq!(record.value, "5 5060 sip.example.com");
+            assert_eq!(record.priority, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_caa_value_round_trip() {
+        let provider = provider();
+        let content = provider
+            .record_content(&DnsRecordType::Caa, "0 issue \"letsencrypt.org\"", None)
+            .unwrap();
+        let body = serde_json::to_value(&content).unwrap();
+        assert!(body.get("content").is_none());
+        assert!(body.get("priority").is_none());
+        assert_eq!(
+            body["data"],
+            serde_json::json!({ "flags": 0, "tag": "issue", "value": "letsencrypt.org" })
+        );
+
+        // Cloudflare 返回的 CAA content 为 "0 issue letsencrypt.org"
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec1",
+            "type": "CAA",
+            "name": "example.com",
+            "content": "0 issue letsencrypt.org",
+            "ttl": 300,
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone1", "example.com")
+            .unwrap();
+        assert_eq!(record.value, "0 issue \"letsencrypt.org\"");
+
+        assert!(
+            provider
+                .record_content(&DnsRecordType::Caa, "letsencrypt.org", None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_long_txt_value_round_trip() {
+        let provider = provider();
+        let dkim = format!("v=DKIM1; k=rsa; p={}", "M".repeat(600 - 18));
+        assert_eq!(dkim.len(), 600);
+
+        let content = provider
+            .record_content(&DnsRecordType::Txt, &dkim, None)
+            .unwrap();
+        let wire = content.content.clone().unwrap();
+        let chunks: Vec<_> = wire.split('"').skip(1).step_by(2).collect();
+        assert_eq!(chunks.len(), 3);
+        assert!(chunks.iter().all(|c| c.len() <= 255));
+
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec1",
+            "type": "TXT",
+            "name": "selector._domainkey.example.com",
+            "content": wire,
+            "ttl": 300,
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone1", "example.com")
+            .unwrap();
+        assert_eq!(record.value, dkim);
+
+        // 短 TXT 原样写入
+        let content = provider
+            .record_content(&DnsRecordType::Txt, "v=spf1 -all", None)
+            .unwrap();
+        assert_eq!(content.content.as_deref(), Some("v=spf1 -all"));
+    }
+
+    #[test]
+    fn test_https_value_round_trip() {
+        let provider = provider();
+        let content = provider
+            .record_content(
+                &DnsRecordType::Https,
+                "1 cdn.example.com. alpn=\"h3,h2\" ipv4hint=192.0.2.1",
+                None,
+            )
+            .unwrap();
+        let body = serde_json::to_value(&content).unwrap();
+        assert!(body.get("content").is_none());
+        assert_eq!(
+            body["data"],
+            serde_json::json!({
+                "priority": 1,
+                "target": "cdn.example.com",
+                "value": "alpn=\"h3,h2\" ipv4hint=192.0.2.1"
+            })
+        );
+
+        // 回读 Cloudflare 返回的 data 对象
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec1",
+            "type": "HTTPS",
+            "name": "example.com",
+            "data": body["data"],
+            "ttl": 300,
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone1", "example.com")
+            .unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Https);
+        assert_eq!(
+            record.value,
+            "1 cdn.example.com alpn=\"h3,h2\" ipv4hint=192.0.2.1"
+        );
+        assert_eq!(record.priority, None);
+
+        // 只有 content 的别名模式 SVCB 记录
+        let cf_record: CloudflareDnsRecord = serde_json::from_value(serde_json::json!({
+            "id": "rec2",
+            "type": "SVCB",
+            "name": "_dns.example.com",
+            "content": "0 svc.example.net.",
+            "ttl": 300,
+        }))
+        .unwrap();
+        let record = provider
+            .cf_record_to_dns_record(cf_record, "zone1", "example.com")
+            .unwrap();
+        assert_eq!(record.value, "0 svc.example.net");
+
+        assert!(
+            provider
+                .record_content(&DnsRecordType::Svcb, "svc.example.net", None)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_invalid_srv_value_rejected() {
+        let result = provider().record_content(&DnsRecordType::Srv, "sip.example.com", Some(1));
+        assert!(matches!(
+            result,
+            Err(crate::error::ProviderError::InvalidParameter { .. })
+        ));
+
+        let content = provider()
+            .record_content(&DnsRecordType::Mx, "mx.example.com", Some(10))
+            .unwrap();
+        let body = serde_json::to_value(&content).unwrap();
+        assert_eq!(body["content"], "mx.example.com");
+        assert_eq!(body["priority"], 10);
+    }
+
+    #[tokio::test]
+    async fn test_list_records_follows_result_info_cursor() {
+        let server = TestServer::start(|req| {
+            if !req.path.contains("/dns_records") {
+                return (200, zone_response());
+            }
+            let (id, cursor) = if req.path.contains("cursor=c2") {
+                ("rec2", serde_json::Value::Null)
+            } else {
+                ("rec1", serde_json::json!("c2"))
+            };
+            let result = serde_json::json!({
+                "success": true,
+                "result": [{
+                    "id": id, "type": "A", "name": format!("{id}.example.com"),
+                    "content": "192.0.2.1", "ttl": 300
+                }],
+                "result_info": { "per_page": 1, "total_count": 2, "cursor": cursor }
+            });
+            (200, result.to_string())
+        })
+        .await;
+        let provider = local_provider(&server);
+
+        let mut params = RecordQueryParams {
+            page_size: 1,
+            ..RecordQueryParams::default()
+        };
+        let first = provider.list_records("zone-1", &params).await.unwrap();
+        assert_eq!(first.items[0].id, "rec1");
+        assert_eq!(first.next_cursor.as_deref(), Some("c2"));
+
+        params.cursor = first.next_cursor;
+        let second = provider.list_records("zone-1", &params).await.unwrap();
+        assert_eq!(second.items[0].id, "rec2");
+        assert!(second.next_cursor.is_none());
+
+        let paths: Vec<_> = server
+            .requests()
+            .into_iter()
+            .map(|r| r.path)
+            .filter(|p| p.contains("/dns_records"))
+            .collect();
+        assert_eq!(
+            paths,
+            [
+                "/zones/zone-1/dns_records?page=1&per_page=1",
+                "/zones/zone-1/dns_records?cursor=c2&per_page=1",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_proxied_unsupported_type_rejected_before_request() {
+        let req = CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::Mx,
+            name: "@".to_string(),
+            value: "mail.example.com".to_string(),
+            ttl: 300,
+            priority: Some(10),
+            proxied: Some(true),
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+
+        let err = provider().create_record(&req).await.unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::InvalidParameter { ref provider, ref param, .. }
+                if provider == "cloudflare" && param == "proxied"
+        ));
+        assert!(CloudflareProvider::ensure_proxiable(&DnsRecordType::Mx, None).is_ok());
+        assert!(CloudflareProvider::ensure_proxiable(&DnsRecordType::Cname, Some(true)).is_ok());
+    }
+
+    fn ptr_request() -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "zone-1".to_string(),
+            record_type: DnsRecordType::Ptr,
+            name: "1".to_string(),
+            value: "host.example.com".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ptr_record_created_in_reverse_zone() {
+        let server = TestServer::start(|req| {
+            if req.method == "GET" {
+                let body = serde_json::json!({
+                    "success": true,
+                    "result": { "id": "zone-1", "name": "2.0.192.in-addr.arpa", "status": "active" }
+                });
+                return (200, body.to_string());
+            }
+            let sent: serde_json::Value = serde_json::from_str(&req.body).unwrap();
+            let body = serde_json::json!({
+                "success": true,
+                "result": {
+                    "id": "rec-1", "type": sent["type"], "name": sent["name"],
+                    "content": sent["content"], "ttl": sent["ttl"]
+                }
+            });
+            (200, body.to_string())
+        })
+        .await;
+
+        let record = local_provider(&server)
+            .create_record(&ptr_request())
+            .await
+            .unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Ptr);
+        assert_eq!(record.name, "1");
+        assert_eq!(record.value, "host.example.com");
+
+        let requests = server.requests();
+        let post = requests.iter().find(|r| r.method == "POST").unwrap();
+        let sent: serde_json::Value = serde_json::from_str(&post.body).unwrap();
+        assert_eq!(sent["type"], "PTR");
+        assert_eq!(sent["name"], "1.2.0.192.in-addr.arpa");
+    }
+
+    #[tokio::test]
+    async fn test_ptr_record_outside_reverse_zone_rejected() {
+        let server = TestServer::start(|_| (200, zone_response())).await;
+
+        let err = local_provider(&server)
+            .create_record(&ptr_request())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::InvalidParameter { ref param, .. } if param == "name"
+        ));
+        assert!(server.requests().iter().all(|r| r.method == "GET"));
+    }
+
+    #[tokio::test]
+    async fn test_get_record_fetches_single_record() {
+        let server = TestServer::start(|req| {
+            if !req.path.contains("/dns_records/") {
+                return (200, zone_response());
+            }
+            let body = serde_json::json!({
+                "success": true,
+                "result": {
+                    "id": "rec-1", "type": "MX", "name": "example.com",
+                    "content": "mx.example.com", "ttl": 300, "priority": 10
+                }
+            });
+            (200, body.to_string())
+        })
+        .await;
+        let provider = local_provider(&server);
+
+        let record = provider.get_record("rec-1", "zone-1").await.unwrap();
+        assert_eq!(record.id, "rec-1");
+        assert_eq!(record.name, "@");
+        assert_eq!(record.record_type, DnsRecordType::Mx);
+        assert_eq!(record.priority, Some(10));
+        assert!(
+            server
+                .requests()
+                .iter()
+                .any(|r| r.path.ends_with("/zones/zone-1/dns_records/rec-1"))
+        );
+    }
 }
@@ -21,10 +21,16 @@ pub struct CloudflareError {
 #[derive(Debug, Deserialize)]
 pub struct CloudflareResultInfo {
     #[allow(dead_code)]
+    #[serde(default)]
     pub page: u32,
     #[allow(dead_code)]
+    #[serde(default)]
     pub per_page: u32,
+    #[serde(default)]
     pub total_count: u32,
+    /// 下一页游标（游标分页时返回，最后一页时为空）
+    #[serde(default)]
+    pub cursor: Option<String>,
 }
 
 /// Cloudflare Zone 结构
@@ -33,6 +39,10 @@ pub struct CloudflareZone {
     pub id: String,
     pub name: String,
     pub status: String,
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+    pub created_on: Option<String>,
+    pub modified_on: Option<String>,
 }
 
 /// Cloudflare DNS Record 结构
@@ -42,14 +52,41 @@ pub struct CloudflareDnsRecord {
     #[serde(rename = "type")]
     pub record_type: String,
     pub name: String,
+    /// HTTPS/SVCB 等结构化记录可能只返回 `data`
+    #[serde(default)]
     pub content: String,
+    /// 结构化记录数据（SRV/CAA/HTTPS/SVCB）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
     pub ttl: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub priority: Option<u16>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub proxied: Option<bool>,
+    /// 记录备注
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// 记录标签（`name:value` 形式）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_on: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub modified_on: Option<String>,
 }
+
+/// HTTPS/SVCB 记录的结构化数据（`value` 为服务参数）
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CloudflareSvcbData {
+    pub priority: u16,
+    pub target: String,
+    #[serde(default)]
+    pub value: String,
+}
+
+/// Cloudflare 批量操作结果（`POST /zones/{zone_id}/dns_records/batch`）
+#[derive(Debug, Deserialize)]
+pub struct CloudflareBatchResult {
+    #[serde(default)]
+    pub posts: Vec<CloudflareDnsRecord>,
+}
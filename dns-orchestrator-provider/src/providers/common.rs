@@ -1,40 +1,54 @@
 //! Provider 公共工具函数
 
-use std::sync::OnceLock;
-use std::time::Duration;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
 
+use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
 use hmac::{Hmac, Mac};
 use reqwest::Client;
+use serde::{Deserialize, Serialize};
 use sha2::Sha256;
 
 use crate::error::{ProviderError, Result};
-use crate::types::DnsRecordType;
+use crate::http_client::HttpClientConfig;
+use crate::identity::unquote_txt;
+use crate::types::{
+    DnsRecord, DnsRecordType, PaginatedResponse, PaginationParams, ProviderDomain,
+    RecordQueryParams,
+};
 
 type HmacSha256 = Hmac<Sha256>;
 
 // ============ HTTP Client ============
 
-/// 默认连接超时（秒）
-const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
-/// 默认请求超时（秒）
-const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
-
-/// 全局共享的 HTTP Client
-static SHARED_HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
-
-/// 获取共享的 HTTP Client（懒初始化，线程安全）
-pub fn create_http_client() -> Client {
-    SHARED_HTTP_CLIENT
-        .get_or_init(|| {
-            Client::builder()
-                .connect_timeout(Duration::from_secs(DEFAULT_CONNECT_TIMEOUT_SECS))
-                .timeout(Duration::from_secs(DEFAULT_REQUEST_TIMEOUT_SECS))
-                .build()
-                .expect("Failed to create HTTP client")
-        })
+/// 按配置共享的 HTTP Client
+///
+/// `Client` 内部持有连接池，克隆只增加引用计数；多个账户共用同一服务商时
+/// 可复用已建立的 TCP/TLS 连接。认证信息在请求头中，不绑定到 Client
+static SHARED_HTTP_CLIENTS: OnceLock<Mutex<HashMap<HttpClientConfig, Client>>> = OnceLock::new();
+
+/// 按配置获取共享的 HTTP Client（懒初始化，线程安全），相同配置复用同一个 Client
+pub fn build_http_client(config: &HttpClientConfig) -> Client {
+    let mut clients = SHARED_HTTP_CLIENTS
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    clients
+        .entry(config.clone())
+        .or_insert_with(|| new_http_client(config))
         .clone()
 }
 
+fn new_http_client(config: &HttpClientConfig) -> Client {
+    let mut builder = Client::builder()
+        .connect_timeout(config.connect_timeout)
+        .timeout(config.timeout);
+    if let Some(proxy) = &config.proxy {
+        builder = builder.proxy(proxy.to_reqwest());
+    }
+    builder.build().expect("Failed to create HTTP client")
+}
+
 // ============ 记录类型转换 ============
 
 /// 将字符串转换为 `DnsRecordType`
@@ -48,6 +62,10 @@ pub fn parse_record_type(record_type: &str, provider: &str) -> Result<DnsRecordT
         "NS" => Ok(DnsRecordType::Ns),
         "SRV" => Ok(DnsRecordType::Srv),
         "CAA" => Ok(DnsRecordType::Caa),
+        "PTR" => Ok(DnsRecordType::Ptr),
+        "HTTPS" => Ok(DnsRecordType::Https),
+        "SVCB" => Ok(DnsRecordType::Svcb),
+        "ALIAS" | "ANAME" => Ok(DnsRecordType::Alias),
         _ => Err(ProviderError::InvalidParameter {
             provider: provider.to_string(),
             param: "record_type".to_string(),
@@ -67,7 +85,438 @@ pub fn record_type_to_string(record_type: &DnsRecordType) -> &'static str {
         DnsRecordType::Ns => "NS",
         DnsRecordType::Srv => "SRV",
         DnsRecordType::Caa => "CAA",
+        DnsRecordType::Ptr => "PTR",
+        DnsRecordType::Https => "HTTPS",
+        DnsRecordType::Svcb => "SVCB",
+        DnsRecordType::Alias => "ALIAS",
+    }
+}
+
+/// 构造"不支持的记录类型"错误
+pub fn unsupported_record_type(record_type: &DnsRecordType, provider: &str) -> ProviderError {
+    ProviderError::UnsupportedRecordType {
+        provider: provider.to_string(),
+        record_type: record_type_to_string(record_type).to_string(),
+    }
+}
+
+/// 是否为 HTTPS/SVCB 记录（仅部分服务商支持）
+pub fn is_service_binding_type(record_type: &DnsRecordType) -> bool {
+    matches!(record_type, DnsRecordType::Https | DnsRecordType::Svcb)
+}
+
+// ============ 反向解析 ============
+
+/// 判断名称是否位于反向解析区域（in-addr.arpa / ip6.arpa）
+pub fn is_reverse_zone_name(name: &str) -> bool {
+    let name = normalize_domain_name(name).to_lowercase();
+    name == "in-addr.arpa"
+        || name == "ip6.arpa"
+        || name.ends_with(".in-addr.arpa")
+        || name.ends_with(".ip6.arpa")
+}
+
+/// 校验 PTR 记录的完整名称位于反向解析区域内
+pub fn validate_ptr_name(full_name: &str, provider: &str) -> Result<()> {
+    if is_reverse_zone_name(full_name) {
+        Ok(())
+    } else {
+        Err(ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: "name".to_string(),
+            detail: format!("PTR 记录必须位于反向解析区域 (in-addr.arpa / ip6.arpa): {full_name}"),
+        })
+    }
+}
+
+// ============ 记录值解析 ============
+
+/// 拆分服务商返回的组合记录值，统一为 Cloudflare 的结构
+///
+/// - MX: `"10 mx.example.com."` -> (`"mx.example.com"`, 10)
+/// - SRV: `"1 5 5060 sip.example.com."` -> (`"5 5060 sip.example.com"`, 1)，
+///   即值为 `weight port target`，优先级单独返回
+/// - CAA: `0 issue letsencrypt.org` -> `0 issue "letsencrypt.org"`
+/// - HTTPS/SVCB: `1 cdn.example.com.  alpn=h3` -> `1 cdn.example.com alpn=h3`（优先级保留在值中）
+/// - TXT: `"abc" "def"` -> `abcdef`（合并拆分的多段字符串）
+///
+/// 已拆分的值原样返回（仅去掉目标主机名末尾的点）
+pub fn split_combined_value(
+    record_type: &DnsRecordType,
+    value: &str,
+    priority: Option<u16>,
+) -> (String, Option<u16>) {
+    let value = value.trim();
+    let fields: Vec<&str> = value.split_whitespace().collect();
+
+    match record_type {
+        DnsRecordType::Mx => match fields.as_slice() {
+            [p, target] if p.parse::<u16>().is_ok() => {
+                (normalize_domain_name(target), p.parse().ok().or(priority))
+            }
+            _ => (normalize_domain_name(value), priority),
+        },
+        DnsRecordType::Srv => match SrvValue::parse(value, priority) {
+            Some(srv) => (srv.record_value(), Some(srv.priority)),
+            None => match fields.as_slice() {
+                [weight, port, target] => (
+                    format!("{weight} {port} {}", normalize_domain_name(target)),
+                    priority,
+                ),
+                _ => (value.to_string(), priority),
+            },
+        },
+        DnsRecordType::Caa => (
+            CaaValue::parse(value).map_or_else(|| value.to_string(), |c| c.to_canonical()),
+            None,
+        ),
+        DnsRecordType::Https | DnsRecordType::Svcb => (
+            SvcbValue::parse(value).map_or_else(|| value.to_string(), |s| s.to_canonical()),
+            None,
+        ),
+        DnsRecordType::Txt => (unquote_txt(value), priority),
+        _ => (value.to_string(), priority),
+    }
+}
+
+/// 单独的优先级参数只对 MX 有效（SRV 优先级已拼入记录值）
+pub fn mx_priority(record_type: &DnsRecordType, priority: Option<u16>) -> Option<u16> {
+    if *record_type == DnsRecordType::Mx {
+        priority
+    } else {
+        None
+    }
+}
+
+/// 构造写入服务商的组合记录值（`split_combined_value` 的逆操作）
+///
+/// SRV 的优先级需要拼回值中：`"5 5060 sip.example.com"` + 1 -> `"1 5 5060 sip.example.com"`；
+/// CAA、HTTPS/SVCB 统一为规范格式；超过 255 字节的 TXT 拆分为多个带引号的字符串；
+/// 其他类型原样返回
+pub fn combine_record_value(
+    record_type: &DnsRecordType,
+    value: &str,
+    priority: Option<u16>,
+) -> String {
+    match record_type {
+        DnsRecordType::Srv => {
+            SrvValue::parse(value, priority).map_or_else(|| value.to_string(), |s| s.to_canonical())
+        }
+        DnsRecordType::Caa => {
+            CaaValue::parse(value).map_or_else(|| value.to_string(), |c| c.to_canonical())
+        }
+        DnsRecordType::Https | DnsRecordType::Svcb => {
+            SvcbValue::parse(value).map_or_else(|| value.to_string(), |s| s.to_canonical())
+        }
+        DnsRecordType::Txt if value.len() > TXT_CHUNK_LEN => quote_txt_chunks(value),
+        _ => value.to_string(),
+    }
+}
+
+/// SRV 记录值（RFC 2782）
+///
+/// 规范格式为 `"<priority> <weight> <port> <target>"`；`DnsRecord` 中存储为
+/// `"<weight> <port> <target>"`，优先级放在 `priority` 字段（与 Cloudflare 一致）
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SrvValue {
+    pub priority: u16,
+    pub weight: u16,
+    pub port: u16,
+    /// 目标主机名（不带末尾的点）
+    pub target: String,
+}
+
+impl SrvValue {
+    /// 解析规范格式，或 `"<weight> <port> <target>"` 配合单独的优先级；格式无效返回 None
+    pub fn parse(value: &str, priority: Option<u16>) -> Option<Self> {
+        let fields: Vec<&str> = value.split_whitespace().collect();
+        let (priority, rest) = match fields.as_slice() {
+            [p, rest @ ..] if rest.len() == 3 => (p.parse().ok()?, rest),
+            rest if rest.len() == 3 => (priority?, rest),
+            _ => return None,
+        };
+        let target = normalize_domain_name(rest[2]);
+        if target.is_empty() {
+            return None;
+        }
+        Some(Self {
+            priority,
+            weight: rest[0].parse().ok()?,
+            port: rest[1].parse().ok()?,
+            target,
+        })
+    }
+
+    /// 解析失败时返回 `InvalidParameter`
+    pub fn parse_or_invalid(value: &str, priority: Option<u16>, provider: &str) -> Result<Self> {
+        Self::parse(value, priority).ok_or_else(|| ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: "value".to_string(),
+            detail: format!("SRV 记录值格式应为 \"<priority> <weight> <port> <target>\": {value}"),
+        })
+    }
+
+    /// 规范格式 `"<priority> <weight> <port> <target>"`
+    pub fn to_canonical(&self) -> String {
+        format!("{} {}", self.priority, self.record_value())
+    }
+
+    /// `DnsRecord` 中的值格式 `"<weight> <port> <target>"`
+    pub fn record_value(&self) -> String {
+        format!("{} {} {}", self.weight, self.port, self.target)
+    }
+}
+
+// ============ TXT 拆分 ============
+
+/// 单个 TXT 字符串的最大字节数（RFC 1035 character-string）
+pub const TXT_CHUNK_LEN: usize = 255;
+
+/// 将 TXT 值按 255 字节在 UTF-8 字符边界处拆分（空值返回一个空字符串）
+pub fn split_txt_chunks(value: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < value.len() {
+        let mut end = (start + TXT_CHUNK_LEN).min(value.len());
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        chunks.push(&value[start..end]);
+        start = end;
+    }
+    if chunks.is_empty() {
+        chunks.push("");
     }
+    chunks
+}
+
+/// 将 TXT 值按 255 字节拆分为多个带引号的字符串：`"a..." "b..."`
+///
+/// 引号和反斜杠转义；已带引号的值视为调用方已拆分，原样返回
+pub fn quote_txt_chunks(value: &str) -> String {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        return value.to_string();
+    }
+
+    split_txt_chunks(value)
+        .iter()
+        .map(|chunk| format!("\"{}\"", chunk.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// ============ 时间解析 ============
+
+/// 解析不带时区的时间字符串（`YYYY-MM-DD HH:MM:SS` 或 `YYYY-MM-DDTHH:MM:SS[.fff]`）
+///
+/// `utc_offset_hours` 为服务商返回时间所在时区，如 DNSPod 为北京时间（+8）
+pub fn parse_naive_datetime(value: &str, utc_offset_hours: i32) -> Option<DateTime<Utc>> {
+    let naive = NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()?;
+    let offset = FixedOffset::east_opt(utc_offset_hours * 3600)?;
+    naive
+        .and_local_timezone(offset)
+        .single()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// 解析 RFC3339 时间字符串
+pub fn parse_rfc3339(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+// ============ 本地分页 ============
+
+/// 对已全量拉取的数据做本地分页（用于 API 不支持页码分页的服务商）
+pub fn paginate_locally<T>(items: Vec<T>, page: u32, page_size: u32) -> PaginatedResponse<T> {
+    let total_count = u32::try_from(items.len()).unwrap_or(u32::MAX);
+    let offset = (page.max(1) - 1) as usize * page_size as usize;
+    let items = items
+        .into_iter()
+        .skip(offset)
+        .take(page_size as usize)
+        .collect();
+    PaginatedResponse::new(items, page, page_size, total_count)
+}
+
+/// 按名称过滤域名（包含匹配，忽略大小写；用于不支持服务端过滤的服务商）
+pub fn filter_domains_by_name(
+    domains: Vec<ProviderDomain>,
+    params: &PaginationParams,
+) -> Vec<ProviderDomain> {
+    let Some(filter) = params.name_filter().map(str::to_lowercase) else {
+        return domains;
+    };
+    domains
+        .into_iter()
+        .filter(|d| d.name.to_lowercase().contains(&filter))
+        .collect()
+}
+
+/// 对已全量拉取的记录按类型和关键词（匹配名称或值，忽略大小写）过滤后本地分页
+pub fn query_records_locally(
+    records: Vec<DnsRecord>,
+    params: &RecordQueryParams,
+) -> PaginatedResponse<DnsRecord> {
+    let keyword = params
+        .keyword
+        .as_deref()
+        .filter(|k| !k.is_empty())
+        .map(str::to_lowercase);
+    let records = records
+        .into_iter()
+        .filter(|r| {
+            params
+                .record_type
+                .as_ref()
+                .is_none_or(|t| *t == r.record_type)
+        })
+        .filter(|r| {
+            keyword.as_ref().is_none_or(|k| {
+                r.name.to_lowercase().contains(k) || r.value.to_lowercase().contains(k)
+            })
+        })
+        .collect();
+    paginate_locally(records, params.page, params.page_size)
+}
+
+/// CAA 记录值（RFC 8659）
+///
+/// 规范格式为 `<flags> <tag> "<value>"`，如 `0 issue "letsencrypt.org"`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaaValue {
+    pub flags: u8,
+    pub tag: String,
+    /// 属性值（不带引号）
+    pub value: String,
+}
+
+impl CaaValue {
+    /// 解析 `<flags> <tag> <value>`，值可带或不带引号；格式无效返回 None
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.trim().splitn(3, char::is_whitespace);
+        let flags = parts.next()?.parse().ok()?;
+        let tag = parts.next()?.to_ascii_lowercase();
+        if tag.is_empty() || !tag.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return None;
+        }
+        let raw = parts.next()?.trim();
+        let value = raw
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(raw);
+        Some(Self {
+            flags,
+            tag,
+            value: value.to_string(),
+        })
+    }
+
+    /// 解析失败时返回 `InvalidParameter`
+    pub fn parse_or_invalid(value: &str, provider: &str) -> Result<Self> {
+        Self::parse(value).ok_or_else(|| ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: "value".to_string(),
+            detail: format!("CAA 记录值格式应为 \"<flags> <tag> <value>\": {value}"),
+        })
+    }
+
+    /// 规范格式 `<flags> <tag> "<value>"`
+    pub fn to_canonical(&self) -> String {
+        format!("{} {} \"{}\"", self.flags, self.tag, self.value)
+    }
+}
+
+/// HTTPS/SVCB 记录值（RFC 9460）
+///
+/// 规范格式为 `<priority> <target> [<key>=<value> ...]`，如 `1 . alpn="h3,h2"`；
+/// 优先级 0 为别名模式。与 SRV 不同，优先级保留在记录值中
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvcbValue {
+    pub priority: u16,
+    /// 目标主机名（不带末尾的点，`.` 表示记录名自身）
+    pub target: String,
+    /// 服务参数（以单个空格分隔，可为空）
+    pub params: String,
+}
+
+impl SvcbValue {
+    /// 解析 `<priority> <target> [params]`；格式无效（含引号未闭合）返回 None
+    ///
+    /// 参数之间的空白统一为单个空格，引号内的内容原样保留
+    pub fn parse(value: &str) -> Option<Self> {
+        let (priority, rest) = value.trim().split_once(char::is_whitespace)?;
+        let priority = priority.parse().ok()?;
+        let rest = rest.trim_start();
+        let (target, params) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+        if target.is_empty() {
+            return None;
+        }
+        let target = if target == "." {
+            ".".to_string()
+        } else {
+            normalize_domain_name(target)
+        };
+        Some(Self {
+            priority,
+            target,
+            params: split_svc_params(params)?.join(" "),
+        })
+    }
+
+    /// 解析失败时返回 `InvalidParameter`
+    pub fn parse_or_invalid(value: &str, provider: &str) -> Result<Self> {
+        Self::parse(value).ok_or_else(|| ProviderError::InvalidParameter {
+            provider: provider.to_string(),
+            param: "value".to_string(),
+            detail: format!("HTTPS/SVCB 记录值格式应为 \"<priority> <target> <params>\": {value}"),
+        })
+    }
+
+    /// 规范格式 `<priority> <target> <params>`
+    pub fn to_canonical(&self) -> String {
+        if self.params.is_empty() {
+            format!("{} {}", self.priority, self.target)
+        } else {
+            format!("{} {} {}", self.priority, self.target, self.params)
+        }
+    }
+}
+
+/// 按引号外的空白拆分服务参数（支持 `\` 转义）；引号未闭合返回 None
+fn split_svc_params(params: &str) -> Option<Vec<&str>> {
+    let mut parts = Vec::new();
+    let mut start = None;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (i, c) in params.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+        match c {
+            c if c.is_whitespace() && !in_quotes => {
+                if let Some(s) = start.take() {
+                    parts.push(&params[s..i]);
+                }
+                continue;
+            }
+            '\\' => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if in_quotes {
+        return None;
+    }
+    if let Some(s) = start {
+        parts.push(&params[s..]);
+    }
+    Some(parts)
 }
 
 // ============ HMAC-SHA256 ============
@@ -114,3 +563,366 @@ pub fn relative_to_full_name(relative_name: &str, zone_name: &str) -> String {
         format!("{relative_name}.{zone}")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ptr_record_type_round_trip() {
+        let parsed = parse_record_type("ptr", "test").unwrap();
+        assert_eq!(parsed, DnsRecordType::Ptr);
+        assert_eq!(record_type_to_string(&parsed), "PTR");
+    }
+
+    #[test]
+    fn test_reverse_zone_validation() {
+        assert!(validate_ptr_name("1.1.168.192.in-addr.arpa", "test").is_ok());
+        assert!(validate_ptr_name("1.1.168.192.IN-ADDR.ARPA.", "test").is_ok());
+        assert!(
+            validate_ptr_name(
+                "b.a.9.8.7.6.5.0.4.0.0.0.3.0.0.0.2.0.0.0.1.0.0.0.0.0.0.0.1.2.3.4.ip6.arpa",
+                "test"
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            validate_ptr_name("www.example.com", "test"),
+            Err(ProviderError::InvalidParameter { .. })
+        ));
+        assert!(!is_reverse_zone_name("fakein-addr.arpa"));
+    }
+
+    #[test]
+    fn test_filter_domains_by_name() {
+        let domain = |name: &str| ProviderDomain {
+            id: name.to_string(),
+            name: name.to_string(),
+            provider: crate::types::ProviderType::Cloudflare,
+            status: crate::types::DomainStatus::Active,
+            record_count: None,
+            name_servers: Vec::new(),
+            created_at: None,
+            modified_at: None,
+        };
+        let domains = vec![
+            domain("example.com"),
+            domain("Shop.Example.net"),
+            domain("foo.org"),
+        ];
+
+        let params = PaginationParams {
+            name_filter: Some(" EXAMPLE ".to_string()),
+            ..Default::default()
+        };
+        let names: Vec<_> = filter_domains_by_name(domains.clone(), &params)
+            .into_iter()
+            .map(|d| d.name)
+            .collect();
+        assert_eq!(names, ["example.com", "Shop.Example.net"]);
+
+        // 空过滤词不过滤
+        let params = PaginationParams {
+            name_filter: Some(String::new()),
+            ..Default::default()
+        };
+        assert_eq!(filter_domains_by_name(domains, &params).len(), 3);
+    }
+
+    #[test]
+    fn test_parse_naive_datetime() {
+        let expected = parse_rfc3339("2024-05-01T04:30:00Z").unwrap();
+        assert_eq!(
+            parse_naive_datetime("2024-05-01 12:30:00", 8),
+            Some(expected)
+        );
+        assert_eq!(
+            parse_naive_datetime("2024-05-01T04:30:00.123", 0).map(|dt| dt.timestamp()),
+            Some(expected.timestamp())
+        );
+        assert_eq!(parse_naive_datetime("not a date", 0), None);
+    }
+
+    #[test]
+    fn test_split_and_combine_srv_value() {
+        let (value, priority) =
+            split_combined_value(&DnsRecordType::Srv, "1 5 5060 sip.example.com.", None);
+        assert_eq!(value, "5 5060 sip.example.com");
+        assert_eq!(priority, Some(1));
+        assert_eq!(
+            combine_record_value(&DnsRecordType::Srv, &value, priority),
+            "1 5 5060 sip.example.com"
+        );
+
+        // 已拆分的值保持不变
+        let (value, priority) =
+            split_combined_value(&DnsRecordType::Srv, "5 5060 sip.example.com", Some(1));
+        assert_eq!(value, "5 5060 sip.example.com");
+        assert_eq!(priority, Some(1));
+    }
+
+    #[test]
+    fn test_srv_value_parse() {
+        let canonical = SrvValue::parse("10 60 25565 mc.example.com.", None).unwrap();
+        let split = SrvValue::parse("60 25565 mc.example.com", Some(10)).unwrap();
+        assert_eq!(canonical, split);
+        assert_eq!(canonical.to_canonical(), "10 60 25565 mc.example.com");
+        assert_eq!(canonical.record_value(), "60 25565 mc.example.com");
+
+        assert!(SrvValue::parse("60 25565 mc.example.com", None).is_none());
+        assert!(SrvValue::parse("10 60 port mc.example.com", None).is_none());
+        assert!(SrvValue::parse("10 60 70000 mc.example.com", None).is_none());
+        assert!(SrvValue::parse_or_invalid("bogus", None, "test").is_err());
+    }
+
+    #[test]
+    fn test_svcb_value_canonical() {
+        let svcb = SvcbValue::parse("1  cdn.example.com.   alpn=h3 port=443").unwrap();
+        assert_eq!(svcb.priority, 1);
+        assert_eq!(svcb.target, "cdn.example.com");
+        assert_eq!(svcb.to_canonical(), "1 cdn.example.com alpn=h3 port=443");
+        assert_eq!(SvcbValue::parse("0 .").unwrap().to_canonical(), "0 .");
+        assert!(SvcbValue::parse("cdn.example.com").is_none());
+        assert!(SvcbValue::parse("1").is_none());
+
+        // 引号内的空白原样保留
+        let quoted = SvcbValue::parse(r#"1 .  alpn="h3, h2"   ech="AB  CD\" E" port=443"#).unwrap();
+        assert_eq!(quoted.params, r#"alpn="h3, h2" ech="AB  CD\" E" port=443"#);
+        assert!(SvcbValue::parse(r#"1 . alpn="h3 h2"#).is_none());
+
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Https, "1 . alpn=h3", Some(1)),
+            ("1 . alpn=h3".to_string(), None)
+        );
+        assert_eq!(
+            parse_record_type("https", "test").unwrap(),
+            DnsRecordType::Https
+        );
+        assert_eq!(record_type_to_string(&DnsRecordType::Svcb), "SVCB");
+    }
+
+    #[test]
+    fn test_alias_wire_type() {
+        assert_eq!(
+            parse_record_type("aname", "test").unwrap(),
+            DnsRecordType::Alias
+        );
+        assert_eq!(record_type_to_string(&DnsRecordType::Alias), "ALIAS");
+    }
+
+    #[test]
+    fn test_caa_value_parse() {
+        let quoted = CaaValue::parse("0 issue \"letsencrypt.org\"").unwrap();
+        let bare = CaaValue::parse("0 ISSUE letsencrypt.org").unwrap();
+        assert_eq!(quoted, bare);
+        assert_eq!(quoted.to_canonical(), "0 issue \"letsencrypt.org\"");
+
+        let iodef = CaaValue::parse("128 iodef \"mailto:security@example.com\"").unwrap();
+        assert_eq!(iodef.flags, 128);
+        assert_eq!(iodef.value, "mailto:security@example.com");
+
+        assert!(CaaValue::parse("issue letsencrypt.org").is_none());
+        assert!(CaaValue::parse("256 issue letsencrypt.org").is_none());
+        assert!(CaaValue::parse("0 is-sue letsencrypt.org").is_none());
+        assert!(CaaValue::parse_or_invalid("0 issue", "test").is_err());
+        assert_eq!(
+            combine_record_value(&DnsRecordType::Caa, "0 issue letsencrypt.org", None),
+            "0 issue \"letsencrypt.org\""
+        );
+    }
+
+    #[test]
+    fn test_split_mx_and_caa_values() {
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Mx, "10 mx.example.com.", None),
+            ("mx.example.com".to_string(), Some(10))
+        );
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Mx, "mx.example.com.", Some(20)),
+            ("mx.example.com".to_string(), Some(20))
+        );
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Caa, "0 issue letsencrypt.org", None),
+            ("0 issue \"letsencrypt.org\"".to_string(), None)
+        );
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Caa, "0 issue \"letsencrypt.org\"", None),
+            ("0 issue \"letsencrypt.org\"".to_string(), None)
+        );
+    }
+
+    #[test]
+    fn test_quote_txt_chunks() {
+        assert_eq!(quote_txt_chunks("v=spf1 -all"), "\"v=spf1 -all\"");
+        assert_eq!(quote_txt_chunks("say \"hi\""), r#""say \"hi\"""#);
+        assert_eq!(quote_txt_chunks("\"a\" \"b\""), "\"a\" \"b\"");
+
+        // 多字节字符不会被拆开
+        let value = "é".repeat(200);
+        let quoted = quote_txt_chunks(&value);
+        let chunks: Vec<_> = quoted.split('"').skip(1).step_by(2).collect();
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|c| c.len() <= TXT_CHUNK_LEN));
+        assert_eq!(unquote_txt(&quoted), value);
+
+        let long = "k".repeat(600);
+        assert_eq!(
+            combine_record_value(&DnsRecordType::Txt, &long, None)
+                .matches('"')
+                .count(),
+            6
+        );
+        assert_eq!(
+            split_combined_value(&DnsRecordType::Txt, &quote_txt_chunks(&long), None).0,
+            long
+        );
+        assert_eq!(
+            combine_record_value(&DnsRecordType::Txt, "short", None),
+            "short"
+        );
+    }
+
+    #[test]
+    fn test_query_records_locally() {
+        let record = |name: &str, record_type: DnsRecordType, value: &str| DnsRecord {
+            id: name.to_string(),
+            domain_id: "example.com".to_string(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        };
+        let records = vec![
+            record("www", DnsRecordType::A, "1.1.1.1"),
+            record("api", DnsRecordType::A, "1.1.1.2"),
+            record("WWW2", DnsRecordType::Cname, "www.example.com"),
+        ];
+        let params =
+            |keyword: Option<&str>, record_type: Option<DnsRecordType>| RecordQueryParams {
+                page: 1,
+                page_size: 1,
+                keyword: keyword.map(str::to_string),
+                record_type,
+                cursor: None,
+            };
+
+        let page = query_records_locally(records.clone(), &params(Some("www"), None));
+        assert_eq!(page.total_count, 2);
+        assert_eq!(page.items.len(), 1);
+        assert!(page.has_more);
+
+        let page = query_records_locally(records, &params(Some("www"), Some(DnsRecordType::A)));
+        assert_eq!(page.total_count, 1);
+        assert_eq!(page.items[0].name, "www");
+    }
+
+    /// 启动保持长连接的本地 HTTP 服务，返回地址和已接受的连接数
+    async fn keep_alive_server() -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                counter.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut buf = [0_u8; 4096];
+                    while matches!(socket.read(&mut buf).await, Ok(n) if n > 0) {
+                        let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\n{}";
+                        if socket.write_all(response.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        (format!("http://{addr}/"), connections)
+    }
+
+    #[cfg(feature = "cloudflare")]
+    #[tokio::test]
+    async fn test_providers_with_same_config_share_client() {
+        use crate::providers::CloudflareProvider;
+        use std::sync::atomic::Ordering;
+        use std::time::Duration;
+
+        let (url, connections) = keep_alive_server().await;
+        // 使用独立的超时配置，避免与其他测试共享的 Client 互相影响
+        let build = |token: &str| {
+            CloudflareProvider::builder(token.to_string())
+                .timeout(Duration::from_secs(17))
+                .build()
+        };
+        let first = build("token-a");
+        let second = build("token-b");
+
+        for provider in [&first, &second] {
+            let response = provider.client.get(&url).send().await.unwrap();
+            assert_eq!(response.text().await.unwrap(), "{}");
+        }
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        // 独立创建的 Client 需要新建连接
+        let separate = new_http_client(&HttpClientConfig {
+            timeout: Duration::from_secs(17),
+            ..HttpClientConfig::default()
+        });
+        separate.get(&url).send().await.unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_client_routes_through_configured_proxy() {
+        use crate::http_client::ProxyConfig;
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::TcpListener;
+
+        // 本地代理：记录收到的请求头后直接应答
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = [0_u8; 4096];
+            let n = socket.read(&mut buf).await.unwrap();
+            let _ = tx.send(String::from_utf8_lossy(&buf[..n]).to_string());
+            let response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{}";
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+
+        let client = build_http_client(&HttpClientConfig {
+            proxy: Some(
+                ProxyConfig::new(format!("http://{addr}"))
+                    .unwrap()
+                    .with_auth("user", "pass"),
+            ),
+            ..HttpClientConfig::default()
+        });
+        let response = client
+            .get("http://api.example.invalid/zones")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.text().await.unwrap(), "{}");
+
+        let request = rx.await.unwrap();
+        assert!(request.starts_with("GET http://api.example.invalid/zones HTTP/1.1"));
+        // user:pass 的 Base64
+        assert!(request.contains("proxy-authorization: Basic dXNlcjpwYXNz"));
+    }
+}
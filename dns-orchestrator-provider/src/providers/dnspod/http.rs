@@ -26,29 +26,33 @@ impl DnspodProvider {
 
         log::debug!("Request Body: {payload}");
 
-        // 2. 生成签名
-        let timestamp = Utc::now().timestamp();
-        let authorization = self.sign(action, &payload, timestamp);
+        // 2. 每次尝试重新生成签名（签名与时间戳绑定）
+        let url = &self.api_base;
+        let build_request = || {
+            let timestamp = Utc::now().timestamp();
+            let authorization = self.sign(action, &payload, timestamp);
 
-        // 3. 发送请求（使用 HttpUtils）
-        let url = format!("https://{DNSPOD_API_HOST}");
-        let request = self
-            .client
-            .post(&url)
-            .header("Content-Type", "application/json; charset=utf-8")
-            .header("Host", DNSPOD_API_HOST)
-            .header("X-TC-Action", action)
-            .header("X-TC-Version", DNSPOD_VERSION)
-            .header("X-TC-Timestamp", timestamp.to_string())
-            .header("Authorization", authorization)
-            .body(payload);
+            self.client
+                .post(url)
+                .header("Content-Type", "application/json; charset=utf-8")
+                .header("Host", DNSPOD_API_HOST)
+                .header("X-TC-Action", action)
+                .header("X-TC-Version", DNSPOD_VERSION)
+                .header("X-TC-Timestamp", timestamp.to_string())
+                .header("Authorization", authorization)
+                .body(payload.clone())
+        };
 
-        let (_status, response_text) = HttpUtils::execute_request_with_retry(
-            request,
+        // 3. 发送请求（使用 HttpUtils）
+        // 查询类 Action 可安全重放，写操作只在请求未被处理时重试
+        let (_status, response_text) = HttpUtils::execute_request_with_retry_fn(
+            build_request,
+            action.starts_with("Describe"),
             self.provider_name(),
             "POST",
             &format!("Action: {}", action),
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -6,13 +6,18 @@ mod provider;
 mod sign;
 mod types;
 
+use std::time::Duration;
+
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
 
 pub(crate) use types::{
-    CreateRecordResponse, DescribeDomainResponse, DomainListResponse, ModifyRecordResponse,
-    RecordListResponse, TencentResponse,
+    CreateRecordResponse, DescribeDomainResponse, DnspodRecord, DomainListResponse,
+    ModifyRecordResponse, RecordListResponse, TencentResponse,
 };
 
 pub(crate) const DNSPOD_API_HOST: &str = "dnspod.tencentcloudapi.com";
@@ -21,19 +26,27 @@ pub(crate) const DNSPOD_VERSION: &str = "2021-03-23";
 /// DNSPod API 单页最大记录数
 pub(crate) const MAX_PAGE_SIZE: u32 = 100;
 
+/// 未指定解析线路时使用的默认线路
+pub(crate) const DEFAULT_LINE: &str = "默认";
+
 /// 腾讯云 DNSPod Provider
 pub struct DnspodProvider {
     pub(crate) client: Client,
+    /// API 根地址（测试时指向本地服务）
+    pub(crate) api_base: String,
     pub(crate) secret_id: String,
     pub(crate) secret_key: String,
-    pub(crate) max_retries: u32,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 /// DNSPod Provider Builder
 pub struct DnspodProviderBuilder {
     secret_id: String,
     secret_key: String,
-    max_retries: u32,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
 }
 
 impl DnspodProviderBuilder {
@@ -41,21 +54,56 @@ impl DnspodProviderBuilder {
         Self {
             secret_id,
             secret_key,
-            max_retries: 2,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Dnspod),
+            http_config: HttpClientConfig::default(),
         }
     }
 
+    /// 最大重试次数（不含首次请求）
     pub fn max_retries(mut self, retries: u32) -> Self {
-        self.max_retries = retries;
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
         self
     }
 
     pub fn build(self) -> DnspodProvider {
         DnspodProvider {
-            client: create_http_client(),
+            client: build_http_client(&self.http_config),
+            api_base: format!("https://{DNSPOD_API_HOST}"),
             secret_id: self.secret_id,
             secret_key: self.secret_key,
-            max_retries: self.max_retries,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
         }
     }
 }
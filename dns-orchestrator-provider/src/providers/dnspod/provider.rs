@@ -4,20 +4,28 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
 use crate::error::{ProviderError, Result};
-use crate::providers::common::{parse_record_type, record_type_to_string};
-use crate::traits::{DnsProvider, ErrorContext, ProviderErrorMapper};
+use crate::providers::common::{
+    combine_record_value, mx_priority, parse_naive_datetime, parse_record_type,
+    record_type_to_string, split_combined_value,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor, ProviderErrorMapper};
 use crate::types::{
-    CreateDnsRecordRequest, DnsRecord, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::{
-    CreateRecordResponse, DescribeDomainResponse, DnspodProvider, DomainListResponse,
-    MAX_PAGE_SIZE, ModifyRecordResponse, RecordListResponse,
+    CreateRecordResponse, DEFAULT_LINE, DescribeDomainResponse, DnspodProvider, DnspodRecord,
+    DomainListResponse, MAX_PAGE_SIZE, ModifyRecordResponse, RecordListResponse,
 };
 
 impl DnspodProvider {
+    /// 解析 DNSPod 返回的时间（北京时间，`YYYY-MM-DD HH:MM:SS`）
+    fn parse_time(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_naive_datetime(value, 8)
+    }
+
     /// 将 DNSPod 域名状态转换为内部状态
     pub(crate) fn convert_domain_status(status: &str, dns_status: &str) -> DomainStatus {
         match (status, dns_status) {
@@ -28,14 +36,55 @@ impl DnspodProvider {
             _ => DomainStatus::Unknown,
         }
     }
-}
 
-#[async_trait]
-impl DnsProvider for DnspodProvider {
-    fn id(&self) -> &'static str {
-        "dnspod"
+    /// 将 DNSPod 记录转换为 `DnsRecord`（拆分 MX/SRV/CAA 组合值）
+    pub(crate) fn convert_record(record: DnspodRecord, domain_id: &str) -> Option<DnsRecord> {
+        let record_type = parse_record_type(&record.record_type, "dnspod").ok()?;
+        let (value, priority) = split_combined_value(&record_type, &record.value, record.mx);
+        Some(DnsRecord {
+            id: record.record_id.to_string(),
+            domain_id: domain_id.to_string(),
+            record_type,
+            name: record.name,
+            value,
+            ttl: record.ttl,
+            priority,
+            proxied: None,
+            line: record.line,
+            weight: None,
+            comment: None,
+            created_at: None,
+            updated_at: record.updated_on.as_deref().and_then(Self::parse_time),
+            tags: None,
+        })
+    }
+
+    /// 将 `DescribeRecordList` 响应转换为分页结果
+    ///
+    /// `total_count` 取自 API 返回的 `TotalCount`，`page_size` 须为实际请求的 limit
+    pub(crate) fn records_page(
+        data: RecordListResponse,
+        domain_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> PaginatedResponse<DnsRecord> {
+        let total_count = data
+            .record_count_info
+            .and_then(|c| c.total_count)
+            .unwrap_or(0);
+
+        let records = data
+            .record_list
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|r| Self::convert_record(r, domain_id))
+            .collect();
+
+        PaginatedResponse::new(records, page, page_size, total_count)
     }
+}
 
+impl ProviderDescriptor for DnspodProvider {
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Dnspod,
@@ -48,6 +97,7 @@ impl DnsProvider for DnspodProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 SecretId".to_string()),
                     help_text: None,
+                    group: None,
                 },
                 ProviderCredentialField {
                     key: "secretKey".to_string(),
@@ -55,15 +105,40 @@ impl DnsProvider for DnspodProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 SecretKey".to_string()),
                     help_text: None,
+                    group: None,
                 },
             ],
-            features: ProviderFeatures::default(),
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
+            features: ProviderFeatures {
+                line: true,
+                ..Default::default()
+            },
             limits: ProviderLimits {
                 max_page_size_domains: 3000,
                 max_page_size_records: 3000,
+                min_ttl: 600,
+                max_ttl: 604_800,
+                default_ttl: 600,
             },
+            pagination: PaginationMode::Page,
         }
     }
+}
+
+#[async_trait]
+impl DnsProvider for DnspodProvider {
+    fn id(&self) -> &'static str {
+        "dnspod"
+    }
 
     async fn validate_credentials(&self) -> Result<bool> {
         #[derive(Serialize)]
@@ -102,13 +177,17 @@ impl DnsProvider for DnspodProvider {
             offset: u32,
             #[serde(rename = "Limit")]
             limit: u32,
+            #[serde(rename = "Keyword", skip_serializing_if = "Option::is_none")]
+            keyword: Option<String>,
         }
 
-        // 将 page/page_size 转换为 offset/limit
-        let offset = (params.page - 1) * params.page_size;
+        // 将 page/page_size 转换为 offset/limit（按实际生效的 limit 计算偏移）
+        let page_size = params.page_size.min(MAX_PAGE_SIZE);
+        let offset = (params.page.max(1) - 1) * page_size;
         let req = DescribeDomainListRequest {
             offset,
-            limit: params.page_size.min(MAX_PAGE_SIZE),
+            limit: page_size,
+            keyword: params.name_filter().map(ToString::to_string),
         };
 
         let response: DomainListResponse = self
@@ -130,13 +209,16 @@ impl DnsProvider for DnspodProvider {
                 provider: ProviderType::Dnspod,
                 status: Self::convert_domain_status(&d.status, &d.dns_status),
                 record_count: d.record_count,
+                name_servers: d.effective_dns,
+                created_at: d.created_on.as_deref().and_then(Self::parse_time),
+                modified_at: d.updated_on.as_deref().and_then(Self::parse_time),
             })
             .collect();
 
         Ok(PaginatedResponse::new(
             domains,
             params.page,
-            params.page_size,
+            page_size,
             total_count,
         ))
     }
@@ -171,6 +253,9 @@ impl DnsProvider for DnspodProvider {
                 provider: ProviderType::Dnspod,
                 status: Self::convert_domain_status(&info.status, &info.dns_status),
                 record_count: info.record_count,
+                name_servers: info.effective_dns,
+                created_at: info.created_on.as_deref().and_then(Self::parse_time),
+                modified_at: info.updated_on.as_deref().and_then(Self::parse_time),
             });
         }
 
@@ -178,6 +263,7 @@ impl DnsProvider for DnspodProvider {
         let params = PaginationParams {
             page: 1,
             page_size: 100,
+            name_filter: None,
         };
         let response = self.list_domains(&params).await?;
 
@@ -213,11 +299,12 @@ impl DnsProvider for DnspodProvider {
 
         let domain_info = self.get_domain(domain_id).await?;
 
-        let offset = (params.page - 1) * params.page_size;
+        let page_size = params.page_size.min(MAX_PAGE_SIZE);
+        let offset = (params.page.max(1) - 1) * page_size;
         let req = DescribeRecordListRequest {
             domain: domain_info.name,
             offset,
-            limit: params.page_size.min(MAX_PAGE_SIZE),
+            limit: page_size,
             keyword: params.keyword.clone().filter(|k| !k.is_empty()),
             record_type: params
                 .record_type
@@ -234,58 +321,91 @@ impl DnsProvider for DnspodProvider {
             self.request("DescribeRecordList", &req, ctx).await;
 
         match response {
-            Ok(data) => {
-                let total_count = data
-                    .record_count_info
-                    .and_then(|c| c.total_count)
-                    .unwrap_or(0);
-
-                let records = data
-                    .record_list
-                    .unwrap_or_default()
-                    .into_iter()
-                    .filter_map(|r| {
-                        let record_type = parse_record_type(&r.record_type, "dnspod").ok()?;
-                        Some(DnsRecord {
-                            id: r.record_id.to_string(),
-                            domain_id: domain_id.to_string(),
-                            record_type,
-                            name: r.name,
-                            value: r.value,
-                            ttl: r.ttl,
-                            priority: r.mx,
-                            proxied: None,
-                            created_at: None,
-                            updated_at: r.updated_on.and_then(|s| {
-                                chrono::DateTime::parse_from_rfc3339(&s)
-                                    .ok()
-                                    .map(|dt| dt.with_timezone(&chrono::Utc))
-                            }),
-                        })
-                    })
-                    .collect();
-
-                Ok(PaginatedResponse::new(
-                    records,
-                    params.page,
-                    params.page_size,
-                    total_count,
-                ))
-            }
+            Ok(data) => Ok(Self::records_page(data, domain_id, params.page, page_size)),
             Err(ProviderError::Unknown { raw_code, .. })
                 if raw_code.as_deref() == Some("ResourceNotFound.NoDataOfRecord") =>
             {
-                Ok(PaginatedResponse::new(
-                    vec![],
-                    params.page,
-                    params.page_size,
-                    0,
-                ))
+                Ok(PaginatedResponse::new(vec![], params.page, page_size, 0))
             }
             Err(e) => Err(e),
         }
     }
 
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        #[derive(Serialize)]
+        struct DescribeRecordRequest {
+            #[serde(rename = "Domain")]
+            domain: String,
+            #[serde(rename = "RecordId")]
+            record_id: u64,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct DescribeRecordResponse {
+            #[serde(rename = "RecordInfo")]
+            record_info: RecordInfo,
+        }
+
+        #[derive(Debug, Deserialize)]
+        struct RecordInfo {
+            #[serde(rename = "Id")]
+            id: u64,
+            #[serde(rename = "SubDomain")]
+            sub_domain: String,
+            #[serde(rename = "RecordType")]
+            record_type: String,
+            #[serde(rename = "Value")]
+            value: String,
+            #[serde(rename = "TTL")]
+            ttl: u32,
+            #[serde(rename = "MX")]
+            mx: Option<u16>,
+            #[serde(rename = "RecordLine")]
+            record_line: Option<String>,
+            #[serde(rename = "UpdatedOn")]
+            updated_on: Option<String>,
+        }
+
+        let record_id_num: u64 = record_id
+            .parse()
+            .map_err(|_| ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+            })?;
+
+        let domain_info = self.get_domain(domain_id).await?;
+
+        let api_req = DescribeRecordRequest {
+            domain: domain_info.name,
+            record_id: record_id_num,
+        };
+
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+
+        let response: DescribeRecordResponse =
+            self.request("DescribeRecord", &api_req, ctx).await?;
+        let info = response.record_info;
+
+        let record = DnspodRecord {
+            record_id: info.id,
+            name: info.sub_domain,
+            record_type: info.record_type,
+            value: info.value,
+            ttl: info.ttl,
+            mx: info.mx,
+            line: info.record_line,
+            updated_on: info.updated_on,
+        };
+        let record_type = record.record_type.clone();
+        Self::convert_record(record, domain_id)
+            .ok_or_else(|| self.parse_error(format!("unsupported record type: {record_type}")))
+    }
+
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
         #[derive(Serialize)]
         struct CreateRecordRequest {
@@ -311,10 +431,10 @@ impl DnsProvider for DnspodProvider {
             domain: domain_info.name,
             sub_domain: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            record_line: "默认".to_string(),
-            value: req.value.clone(),
+            record_line: req.line.clone().unwrap_or_else(|| DEFAULT_LINE.to_string()),
+            value: combine_record_value(&req.record_type, &req.value, req.priority),
             ttl: req.ttl,
-            mx: req.priority,
+            mx: mx_priority(&req.record_type, req.priority),
         };
 
         let ctx = ErrorContext {
@@ -335,6 +455,10 @@ impl DnsProvider for DnspodProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: Some(api_req.record_line),
+            weight: None,
+            comment: None,
+            tags: None,
             created_at: Some(now),
             updated_at: Some(now),
         })
@@ -380,10 +504,10 @@ impl DnsProvider for DnspodProvider {
             record_id: record_id_num,
             sub_domain: req.name.clone(),
             record_type: record_type_to_string(&req.record_type).to_string(),
-            record_line: "默认".to_string(),
-            value: req.value.clone(),
+            record_line: req.line.clone().unwrap_or_else(|| DEFAULT_LINE.to_string()),
+            value: combine_record_value(&req.record_type, &req.value, req.priority),
             ttl: req.ttl,
-            mx: req.priority,
+            mx: mx_priority(&req.record_type, req.priority),
         };
 
         let ctx = ErrorContext {
@@ -404,6 +528,10 @@ impl DnsProvider for DnspodProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: Some(api_req.record_line),
+            weight: None,
+            comment: None,
+            tags: None,
             created_at: None,
             updated_at: Some(now),
         })
@@ -447,3 +575,292 @@ impl DnsProvider for DnspodProvider {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::test_server::TestServer;
+
+    /// `DescribeRecordList` 响应（取自真实返回，已脱敏）
+    const DESCRIBE_RECORD_LIST_FIXTURE: &str = r#"{
+        "RecordCountInfo": { "SubdomainCount": 3, "ListCount": 3, "TotalCount": 3 },
+        "RecordList": [
+            {
+                "RecordId": 1234567801, "Name": "@", "Type": "MX",
+                "Value": "mxbiz1.qq.com.", "TTL": 600, "MX": 5, "Line": "默认",
+                "Status": "ENABLE", "UpdatedOn": "2024-05-01 12:00:00"
+            },
+            {
+                "RecordId": 1234567802, "Name": "_sip._tcp", "Type": "SRV",
+                "Value": "0 5 5060 sip.example.com.", "TTL": 600, "MX": 0, "Line": "默认",
+                "Status": "ENABLE", "UpdatedOn": "2024-05-01 12:00:00"
+            },
+            {
+                "RecordId": 1234567803, "Name": "@", "Type": "CAA",
+                "Value": "0 issue \"letsencrypt.org\"", "TTL": 600, "MX": 0, "Line": "默认",
+                "Status": "ENABLE", "UpdatedOn": "2024-05-01 12:00:00"
+            }
+        ],
+        "RequestId": "ab4f1426-ea15-42ea-8183-dc1b44151166"
+    }"#;
+
+    #[test]
+    fn test_convert_records_from_fixture() {
+        let response: RecordListResponse =
+            serde_json::from_str(DESCRIBE_RECORD_LIST_FIXTURE).unwrap();
+        let records: Vec<DnsRecord> = response
+            .record_list
+            .unwrap()
+            .into_iter()
+            .filter_map(|r| DnspodProvider::convert_record(r, "123"))
+            .collect();
+
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].value, "mxbiz1.qq.com");
+        assert_eq!(records[0].priority, Some(5));
+        assert_eq!(records[1].value, "5 5060 sip.example.com");
+        assert_eq!(records[1].priority, Some(0));
+        assert_eq!(records[2].value, "0 issue \"letsencrypt.org\"");
+        assert_eq!(records[2].priority, None);
+        assert!(records.iter().all(|r| r.line.as_deref() == Some("默认")));
+    }
+
+    fn record_list_response(count: usize, total_count: u32) -> RecordListResponse {
+        let records: Vec<_> = (0..count)
+            .map(|i| {
+                serde_json::json!({
+                    "RecordId": i, "Name": format!("r{i}"), "Type": "A",
+                    "Value": "192.0.2.1", "TTL": 600, "Line": "默认"
+                })
+            })
+            .collect();
+        serde_json::from_value(serde_json::json!({
+            "RecordList": records,
+            "RecordCountInfo": { "TotalCount": total_count }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_records_page_boundaries() {
+        // 40 条记录、每页 20：第 1 页还有下一页，第 2 页恰好结束
+        let page = DnspodProvider::records_page(record_list_response(20, 40), "123", 1, 20);
+        assert_eq!(page.total_count, 40);
+        assert!(page.has_more);
+
+        let page = DnspodProvider::records_page(record_list_response(20, 40), "123", 2, 20);
+        assert_eq!(page.items.len(), 20);
+        assert!(!page.has_more);
+
+        // 请求的 page_size 超过上限时，按实际生效的 limit 判断
+        let page_size = 500.min(MAX_PAGE_SIZE);
+        let page =
+            DnspodProvider::records_page(record_list_response(100, 250), "123", 1, page_size);
+        assert_eq!(page.page_size, MAX_PAGE_SIZE);
+        assert!(page.has_more);
+    }
+
+    #[test]
+    fn test_long_txt_value_round_trip() {
+        let dkim = format!("v=DKIM1; k=rsa; p={}", "M".repeat(600 - 18));
+        let wire = combine_record_value(&DnsRecordType::Txt, &dkim, None);
+        assert_eq!(wire.matches("\" \"").count(), 2);
+
+        let record = DnspodRecord {
+            record_id: 1,
+            name: "selector._domainkey".to_string(),
+            record_type: "TXT".to_string(),
+            value: wire,
+            ttl: 600,
+            mx: Some(0),
+            line: None,
+            updated_on: None,
+        };
+        let record = DnspodProvider::convert_record(record, "123").unwrap();
+        assert_eq!(record.value, dkim);
+    }
+
+    #[test]
+    fn test_srv_value_round_trip() {
+        for (value, priority) in [
+            ("5 5060 sip.example.com", Some(1)),
+            ("1 5 5060 sip.example.com.", None),
+        ] {
+            // 写入时 SRV 优先级拼入 Value，MX 字段不传
+            let record = DnspodRecord {
+                record_id: 1,
+                name: "_sip._tcp".to_string(),
+                record_type: "SRV".to_string(),
+                value: combine_record_value(&DnsRecordType::Srv, value, priority),
+                ttl: 600,
+                mx: mx_priority(&DnsRecordType::Srv, priority).or(Some(0)),
+                line: None,
+                updated_on: None,
+            };
+            let record = DnspodProvider::convert_record(record, "123").unwrap();
+            assert_eq!(record.value, "5 5060 sip.example.com");
+            assert_eq!(record.priority, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_caa_value_round_trip() {
+        for value in ["0 issue letsencrypt.org", "0 issue \"letsencrypt.org\""] {
+            // DNSPod 使用扁平字符串，写入规范格式
+            let raw = combine_record_value(&DnsRecordType::Caa, value, None);
+            assert_eq!(raw, "0 issue \"letsencrypt.org\"");
+            assert_eq!(mx_priority(&DnsRecordType::Caa, None), None);
+
+            let record = DnspodRecord {
+                record_id: 1,
+                name: "@".to_string(),
+                record_type: "CAA".to_string(),
+                value: raw,
+                ttl: 600,
+                mx: Some(0),
+                line: None,
+                updated_on: None,
+            };
+            let record = DnspodProvider::convert_record(record, "123").unwrap();
+            assert_eq!(record.value, "0 issue \"letsencrypt.org\"");
+            assert_eq!(record.priority, None);
+        }
+    }
+
+    #[test]
+    fn test_ptr_rejected() {
+        let err =
+            crate::validate_record_support("dnspod", &DnsRecordType::Ptr, None, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::UnsupportedRecordType { ref record_type, .. } if record_type == "PTR"
+        ));
+    }
+
+    #[test]
+    fn test_alias_rejected() {
+        // 根域名 CNAME 读回时无法与 ALIAS 区分，不支持 ALIAS
+        let err =
+            crate::validate_record_support("dnspod", &DnsRecordType::Alias, None, 0).unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::UnsupportedRecordType { ref record_type, .. } if record_type == "ALIAS"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_record_line_sent_in_requests() {
+        let server = TestServer::start(|req| {
+            let mut body = match req.header("x-tc-action") {
+                Some("DescribeDomain") => serde_json::json!({
+                    "DomainInfo": {
+                        "DomainId": 1, "Domain": "example.com",
+                        "Status": "ENABLE", "DNSStatus": ""
+                    }
+                }),
+                _ => serde_json::json!({ "RecordId": 42 }),
+            };
+            body["RequestId"] = "req-1".into();
+            (200, serde_json::json!({ "Response": body }).to_string())
+        })
+        .await;
+        let mut provider = DnspodProvider::new("id".to_string(), "key".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let mut req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "192.0.2.1".to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: Some("电信".to_string()),
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        provider.create_record(&req).await.unwrap();
+        req.line = None;
+        provider.create_record(&req).await.unwrap();
+        let update = UpdateDnsRecordRequest {
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value: req.value.clone(),
+            ttl: req.ttl,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            expected_version: None,
+            expected_value: None,
+        };
+        let updated = provider.update_record("42", &update).await.unwrap();
+        assert_eq!(updated.line.as_deref(), Some(DEFAULT_LINE));
+
+        let lines: Vec<_> = server
+            .requests()
+            .iter()
+            .filter(|r| r.header("x-tc-action") != Some("DescribeDomain"))
+            .map(|r| {
+                let body: serde_json::Value = serde_json::from_str(&r.body).unwrap();
+                body["RecordLine"].as_str().unwrap_or_default().to_string()
+            })
+            .collect();
+        assert_eq!(lines, ["电信", DEFAULT_LINE, DEFAULT_LINE]);
+    }
+
+    #[tokio::test]
+    async fn test_get_record_uses_describe_record() {
+        let server = TestServer::start(|req| {
+            let mut body = match req.header("x-tc-action") {
+                Some("DescribeDomain") => serde_json::json!({
+                    "DomainInfo": {
+                        "DomainId": 1, "Domain": "example.com",
+                        "Status": "ENABLE", "DNSStatus": ""
+                    }
+                }),
+                _ => serde_json::json!({
+                    "RecordInfo": {
+                        "Id": 42, "SubDomain": "@", "RecordType": "MX",
+                        "Value": "mx.example.com.", "TTL": 600, "MX": 5,
+                        "RecordLine": "默认", "UpdatedOn": "2024-05-01 12:00:00"
+                    }
+                }),
+            };
+            body["RequestId"] = "req-1".into();
+            (200, serde_json::json!({ "Response": body }).to_string())
+        })
+        .await;
+        let mut provider = DnspodProvider::new("id".to_string(), "key".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let record = provider.get_record("42", "example.com").await.unwrap();
+        assert_eq!(record.id, "42");
+        assert_eq!(record.record_type, DnsRecordType::Mx);
+        assert_eq!(record.priority, Some(5));
+        // UpdatedOn 为北京时间
+        assert_eq!(
+            record.updated_at.map(|t| t.to_rfc3339()).as_deref(),
+            Some("2024-05-01T04:00:00+00:00")
+        );
+
+        let requests = server.requests();
+        let describe = requests
+            .iter()
+            .find(|r| r.header("x-tc-action") == Some("DescribeRecord"))
+            .unwrap();
+        let body: serde_json::Value = serde_json::from_str(&describe.body).unwrap();
+        assert_eq!(body["Domain"], "example.com");
+        assert_eq!(body["RecordId"], 42);
+
+        // 非数字 ID 不是 DNSPod 记录 ID，直接返回 RecordNotFound
+        let count = server.requests().len();
+        let err = provider.get_record("abc", "example.com").await.unwrap_err();
+        assert!(matches!(err, ProviderError::RecordNotFound { .. }));
+        assert_eq!(server.requests().len(), count);
+    }
+}
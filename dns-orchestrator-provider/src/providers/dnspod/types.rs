@@ -57,6 +57,12 @@ pub struct DnspodDomain {
     pub dns_status: String,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    #[serde(rename = "EffectiveDNS", default)]
+    pub effective_dns: Vec<String>,
+    #[serde(rename = "CreatedOn")]
+    pub created_on: Option<String>,
+    #[serde(rename = "UpdatedOn")]
+    pub updated_on: Option<String>,
 }
 
 /// DescribeDomain API 响应结构
@@ -79,6 +85,12 @@ pub struct DescribeDomainInfo {
     pub dns_status: String,
     #[serde(rename = "RecordCount")]
     pub record_count: Option<u32>,
+    #[serde(rename = "EffectiveDNS", default)]
+    pub effective_dns: Vec<String>,
+    #[serde(rename = "CreatedOn")]
+    pub created_on: Option<String>,
+    #[serde(rename = "UpdatedOn")]
+    pub updated_on: Option<String>,
 }
 
 // ============ DNSPod 记录相关结构 ============
@@ -111,6 +123,9 @@ pub struct DnspodRecord {
     pub ttl: u32,
     #[serde(rename = "MX")]
     pub mx: Option<u16>,
+    /// 解析线路（`默认`、`电信`、`联通`、`境外` 等）
+    #[serde(rename = "Line")]
+    pub line: Option<String>,
     #[serde(rename = "UpdatedOn")]
     pub updated_on: Option<String>,
 }
@@ -0,0 +1,87 @@
+//! Google Cloud DNS 错误映射
+//!
+//! 参考: <https://cloud.google.com/dns/docs/troubleshooting>
+//!
+//! 错误码取 `error.errors[0].reason`，缺失时使用 `error.status`
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::GcloudDnsProvider;
+
+/// Google Cloud DNS 错误码映射实现
+impl ProviderErrorMapper for GcloudDnsProvider {
+    fn provider_name(&self) -> &'static str {
+        "gclouddns"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some("UNAUTHENTICATED" | "authError" | "required") => {
+                ProviderError::InvalidCredentials {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 权限拒绝 ============
+            Some("PERMISSION_DENIED" | "forbidden" | "accessNotConfigured") => {
+                ProviderError::PermissionDenied {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 配额/频率限制 ============
+            Some("RESOURCE_EXHAUSTED" | "quotaExceeded" | "rateLimitExceeded") => {
+                ProviderError::QuotaExceeded {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 记录已存在 ============
+            Some("ALREADY_EXISTS" | "alreadyExists") => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 记录/Zone 不存在 ============
+            Some("NOT_FOUND" | "notFound") if context.record_id.is_some() => {
+                ProviderError::RecordNotFound {
+                    provider: self.provider_name().to_string(),
+                    record_id: context.record_id.unwrap_or_default(),
+                    raw_message: Some(raw.message),
+                }
+            }
+            Some("NOT_FOUND" | "notFound") => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 并发修改（删除的 RRset 与服务端不一致） ============
+            Some("FAILED_PRECONDITION" | "conditionNotMet" | "ABORTED") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "rrset".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 参数无效 ============
+            Some("INVALID_ARGUMENT" | "invalid" | "invalidValue") => {
+                ProviderError::InvalidParameter {
+                    provider: self.provider_name().to_string(),
+                    param: "record".to_string(),
+                    detail: raw.message,
+                }
+            }
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Google Cloud DNS HTTP 请求方法
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::ErrorResponse;
+use super::{GCLOUD_DNS_API_BASE, GcloudDnsProvider};
+
+impl GcloudDnsProvider {
+    /// 项目级 API 地址
+    fn project_url(&self, path: &str) -> String {
+        format!(
+            "{GCLOUD_DNS_API_BASE}/projects/{}{path}",
+            urlencoding::encode(&self.project_id)
+        )
+    }
+
+    /// 执行 GET 请求
+    pub(crate) async fn get<T: for<'de> Deserialize<'de>>(
+        &self,
+        path: &str,
+        query: &str,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let token = self.access_token().await?;
+        let url = if query.is_empty() {
+            self.project_url(path)
+        } else {
+            format!("{}?{query}", self.project_url(path))
+        };
+
+        let request = self.client.get(&url).bearer_auth(token);
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "GET",
+            &url,
+            &self.retry_policy,
+            &self.rate_limiter,
+        )
+        .await?;
+
+        // 处理错误响应
+        self.handle_response_error(status, &response_text, ctx)?;
+
+        // 解析成功响应
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 执行 POST 请求
+    pub(crate) async fn post<T: for<'de> Deserialize<'de>, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let payload =
+            serde_json::to_string(body).map_err(|e| ProviderError::SerializationError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            })?;
+
+        log::debug!("Request Body: {payload}");
+
+        let token = self.access_token().await?;
+        let url = self.project_url(path);
+
+        let request = self
+            .client
+            .post(&url)
+            .bearer_auth(token)
+            .header("Content-Type", "application/json")
+            .body(payload);
+
+        let (status, response_text) = HttpUtils::execute_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            &url,
+            &self.retry_policy,
+            &self.rate_limiter,
+        )
+        .await?;
+
+        // 处理错误响应
+        self.handle_response_error(status, &response_text, ctx)?;
+
+        // 解析成功响应
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 统一处理 Cloud DNS 响应错误
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        if (200..300).contains(&status) {
+            return Ok(());
+        }
+
+        // 尝试解析结构化错误
+        if let Ok(response) = serde_json::from_str::<ErrorResponse>(response_text) {
+            let error = response.error;
+            let code = error
+                .errors
+                .into_iter()
+                .find_map(|e| e.reason)
+                .or(error.status)
+                .unwrap_or_else(|| error.code.unwrap_or(status).to_string());
+            return Err(self.map_error(
+                RawApiError::with_code(code, error.message.unwrap_or_default()),
+                ctx,
+            ));
+        }
+
+        // 回退到通用错误
+        Err(self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))))
+    }
+}
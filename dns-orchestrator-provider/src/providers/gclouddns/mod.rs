@@ -0,0 +1,123 @@
+//! Google Cloud DNS Provider
+
+mod auth;
+mod error;
+mod http;
+mod provider;
+pub(crate) mod types;
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
+
+pub(crate) use auth::ServiceAccountKey;
+
+pub(crate) const GCLOUD_DNS_API_BASE: &str = "https://dns.googleapis.com/dns/v1";
+/// Cloud DNS API 单页最大条目数
+pub(crate) const MAX_PAGE_SIZE: u32 = 1000;
+
+/// Google Cloud DNS Provider
+pub struct GcloudDnsProvider {
+    pub(crate) client: Client,
+    pub(crate) project_id: String,
+    pub(crate) service_account: ServiceAccountKey,
+    /// 缓存的 OAuth2 访问令牌
+    pub(crate) token: Mutex<Option<auth::AccessToken>>,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
+}
+
+/// Google Cloud DNS Provider Builder
+pub struct GcloudDnsProviderBuilder {
+    project_id: String,
+    service_account: ServiceAccountKey,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
+}
+
+impl GcloudDnsProviderBuilder {
+    fn new(project_id: String, service_account: ServiceAccountKey) -> Self {
+        Self {
+            project_id,
+            service_account,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::GcloudDns),
+            http_config: HttpClientConfig::default(),
+        }
+    }
+
+    /// 最大重试次数（不含首次请求）
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
+        self
+    }
+
+    pub fn build(self) -> GcloudDnsProvider {
+        GcloudDnsProvider {
+            client: build_http_client(&self.http_config),
+            project_id: self.project_id,
+            service_account: self.service_account,
+            token: Mutex::new(None),
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
+        }
+    }
+}
+
+impl GcloudDnsProvider {
+    /// 使用项目 ID 与服务账号密钥 JSON 创建实例，密钥无法解析时返回 `InvalidCredentials`
+    pub fn new(project_id: String, service_account_json: &str) -> Result<Self> {
+        Ok(Self::builder(project_id, service_account_json)?.build())
+    }
+
+    pub fn builder(
+        project_id: String,
+        service_account_json: &str,
+    ) -> Result<GcloudDnsProviderBuilder> {
+        let service_account = ServiceAccountKey::parse(service_account_json).map_err(|e| {
+            ProviderError::InvalidCredentials {
+                provider: "gclouddns".to_string(),
+                raw_message: Some(e),
+            }
+        })?;
+        Ok(GcloudDnsProviderBuilder::new(project_id, service_account))
+    }
+}
@@ -0,0 +1,681 @@
+//! Google Cloud DNS DnsProvider trait 实现
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::error::{ProviderError, Result};
+use crate::identity::unquote_txt;
+use crate::providers::common::{
+    SvcbValue, combine_record_value, filter_domains_by_name, full_name_to_relative,
+    normalize_domain_name, paginate_locally, parse_record_type, parse_rfc3339,
+    query_records_locally, quote_txt_chunks, record_type_to_string, relative_to_full_name,
+    split_combined_value,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    Change, ChangeResponse, ListManagedZonesResponse, ListRrsetsResponse, ManagedZone,
+    ResourceRecordSet,
+};
+use super::{GcloudDnsProvider, MAX_PAGE_SIZE};
+
+/// 记录 ID 各部分的分隔符：`{fqdn}/{TYPE}/{值摘要}`
+const RECORD_ID_SEPARATOR: char = '/';
+
+impl GcloudDnsProvider {
+    /// 生成单个记录值的 ID：`{fqdn}/{TYPE}/{值摘要}`
+    ///
+    /// Cloud DNS 没有记录 ID，RRset 由名称 + 类型唯一确定；
+    /// 使用值摘要区分同一 RRset 中的多个值
+    pub(crate) fn value_record_id(fqdn: &str, record_type: &str, rrdata: &str) -> String {
+        let digest = hex::encode(Sha256::digest(rrdata.as_bytes()));
+        format!(
+            "{fqdn}{RECORD_ID_SEPARATOR}{record_type}{RECORD_ID_SEPARATOR}{}",
+            &digest[..12]
+        )
+    }
+
+    /// 拆分记录 ID 为 (fqdn, 类型)
+    pub(crate) fn split_record_id(record_id: &str) -> Option<(&str, &str)> {
+        let mut parts = record_id.rsplitn(3, RECORD_ID_SEPARATOR);
+        let _digest = parts.next()?;
+        let record_type = parts.next()?;
+        let fqdn = parts.next()?;
+        Some((fqdn, record_type))
+    }
+
+    /// 构造写入 Cloud DNS 的 rrdata（区域文件格式：主机名带末尾点，TXT 带引号并按 255 字节拆分）
+    pub(crate) fn format_rrdata(
+        record_type: &DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> String {
+        let fqdn = |host: &str| format!("{}.", normalize_domain_name(host.trim()));
+        match record_type {
+            DnsRecordType::Mx => format!("{} {}", priority.unwrap_or(10), fqdn(value)),
+            DnsRecordType::Srv => {
+                let combined = combine_record_value(record_type, value, priority);
+                match combined.rsplit_once(' ') {
+                    Some((head, target)) => format!("{head} {}", fqdn(target)),
+                    None => combined,
+                }
+            }
+            DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr => fqdn(value),
+            DnsRecordType::Txt => quote_txt_chunks(value),
+            DnsRecordType::Caa => split_combined_value(record_type, value, None).0,
+            DnsRecordType::Https | DnsRecordType::Svcb => match SvcbValue::parse(value) {
+                Some(mut svcb) => {
+                    if svcb.target != "." {
+                        svcb.target = fqdn(&svcb.target);
+                    }
+                    svcb.to_canonical()
+                }
+                None => value.to_string(),
+            },
+            _ => value.to_string(),
+        }
+    }
+
+    /// 解析 rrdata，返回 (值, 优先级)
+    pub(crate) fn parse_rrdata(record_type: &DnsRecordType, rrdata: &str) -> (String, Option<u16>) {
+        match record_type {
+            DnsRecordType::Txt => (unquote_txt(rrdata), None),
+            DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr => {
+                (normalize_domain_name(rrdata), None)
+            }
+            _ => split_combined_value(record_type, rrdata, None),
+        }
+    }
+
+    /// 将 RRset 展开为记录列表（每个值一条记录）
+    pub(crate) fn rrset_to_records(
+        rrset: &ResourceRecordSet,
+        domain_id: &str,
+        zone_name: &str,
+    ) -> Vec<DnsRecord> {
+        let Ok(record_type) = parse_record_type(&rrset.record_type, "gclouddns") else {
+            return Vec::new();
+        };
+        let name = full_name_to_relative(&rrset.name, zone_name);
+
+        rrset
+            .rrdatas
+            .iter()
+            .map(|rrdata| {
+                let (value, priority) = Self::parse_rrdata(&record_type, rrdata);
+                DnsRecord {
+                    id: Self::value_record_id(&rrset.name, &rrset.record_type, rrdata),
+                    domain_id: domain_id.to_string(),
+                    record_type: record_type.clone(),
+                    name: name.clone(),
+                    value,
+                    ttl: rrset.ttl.unwrap_or(300),
+                    priority,
+                    proxied: None,
+                    line: None,
+                    weight: None,
+                    comment: None,
+                    tags: None,
+                    created_at: None,
+                    updated_at: None,
+                }
+            })
+            .collect()
+    }
+
+    /// 获取 Managed Zone
+    async fn get_zone(&self, domain_id: &str) -> Result<ManagedZone> {
+        let path = format!("/managedZones/{}", urlencoding::encode(domain_id));
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.get(&path, "", ctx).await
+    }
+
+    /// 获取单个 RRset，不存在时返回 None
+    async fn get_rrset(
+        &self,
+        domain_id: &str,
+        fqdn: &str,
+        record_type: &str,
+    ) -> Result<Option<ResourceRecordSet>> {
+        let path = format!(
+            "/managedZones/{}/rrsets/{}/{}",
+            urlencoding::encode(domain_id),
+            urlencoding::encode(fqdn),
+            record_type
+        );
+        let ctx = ErrorContext {
+            record_id: Some(format!("{fqdn}{RECORD_ID_SEPARATOR}{record_type}")),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        match self.get(&path, "", ctx).await {
+            Ok(rrset) => Ok(Some(rrset)),
+            Err(ProviderError::RecordNotFound { .. }) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 列出 Zone 下的全部 RRset（遍历 pageToken）
+    async fn list_all_rrsets(&self, domain_id: &str) -> Result<Vec<ResourceRecordSet>> {
+        let path = format!("/managedZones/{}/rrsets", urlencoding::encode(domain_id));
+        let mut rrsets = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut query = format!("maxResults={MAX_PAGE_SIZE}");
+            if let Some(token) = &page_token {
+                query.push_str(&format!("&pageToken={}", urlencoding::encode(token)));
+            }
+            let ctx = ErrorContext {
+                domain: Some(domain_id.to_string()),
+                ..Default::default()
+            };
+            let response: ListRrsetsResponse = self.get(&path, &query, ctx).await?;
+            rrsets.extend(response.rrsets.unwrap_or_default());
+
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(rrsets)
+    }
+
+    /// 提交变更（changes.create），删除与添加原子生效
+    async fn apply_change(
+        &self,
+        domain_id: &str,
+        change: &Change,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let path = format!("/managedZones/{}/changes", urlencoding::encode(domain_id));
+        let response: ChangeResponse = self.post(&path, change, ctx).await?;
+        log::debug!(
+            "Change {} submitted, status: {}",
+            response.id.unwrap_or_default(),
+            response.status.unwrap_or_default()
+        );
+        Ok(())
+    }
+
+    /// 查找 ID 对应的 RRset 与原始值
+    async fn find_value(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+    ) -> Result<(ResourceRecordSet, String)> {
+        let not_found = || ProviderError::RecordNotFound {
+            provider: "gclouddns".to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        };
+        let (fqdn, record_type) = Self::split_record_id(record_id).ok_or_else(not_found)?;
+        let rrset = self
+            .get_rrset(domain_id, fqdn, record_type)
+            .await?
+            .ok_or_else(not_found)?;
+        let rrdata = rrset
+            .rrdatas
+            .iter()
+            .find(|v| Self::value_record_id(&rrset.name, &rrset.record_type, v) == record_id)
+            .cloned()
+            .ok_or_else(not_found)?;
+        Ok((rrset, rrdata))
+    }
+}
+
+/// 从 RRset 中移除单个值，返回剩余值组成的 RRset（为空时返回 None）
+fn without_value(rrset: &ResourceRecordSet, rrdata: &str) -> Option<ResourceRecordSet> {
+    let remaining: Vec<String> = rrset
+        .rrdatas
+        .iter()
+        .filter(|v| v.as_str() != rrdata)
+        .cloned()
+        .collect();
+    (!remaining.is_empty()).then(|| ResourceRecordSet {
+        rrdatas: remaining,
+        ..rrset.clone()
+    })
+}
+
+impl ProviderDescriptor for GcloudDnsProvider {
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::GcloudDns,
+            name: "Google Cloud DNS".to_string(),
+            description: "Google Cloud 托管 DNS 服务".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "projectId".to_string(),
+                    label: "Project ID".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Google Cloud 项目 ID".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "serviceAccountJson".to_string(),
+                    label: "Service Account JSON".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("粘贴服务账号密钥 JSON".to_string()),
+                    help_text: Some("服务账号需要 DNS Administrator 角色".to_string()),
+                    group: None,
+                },
+            ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Ptr,
+                DnsRecordType::Https,
+                DnsRecordType::Svcb,
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: MAX_PAGE_SIZE,
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                default_ttl: 300,
+            },
+            pagination: PaginationMode::Page,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for GcloudDnsProvider {
+    fn id(&self) -> &'static str {
+        "gclouddns"
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .get::<ListManagedZonesResponse>(
+                "/managedZones",
+                "maxResults=1",
+                ErrorContext::default(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// Cloud DNS 只支持 pageToken 分页，拉取全部 Zone 后本地分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let mut zones = Vec::new();
+        let mut page_token: Option<String> = None;
+        loop {
+            let mut query = format!("maxResults={MAX_PAGE_SIZE}");
+            if let Some(token) = &page_token {
+                query.push_str(&format!("&pageToken={}", urlencoding::encode(token)));
+            }
+            let response: ListManagedZonesResponse = self
+                .get("/managedZones", &query, ErrorContext::default())
+                .await?;
+            zones.extend(
+                response
+                    .managed_zones
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter(|z| z.visibility.as_deref() != Some("private")),
+            );
+            match response.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        let domains = zones
+            .into_iter()
+            .map(|z| ProviderDomain {
+                id: z.name,
+                name: normalize_domain_name(&z.dns_name),
+                provider: ProviderType::GcloudDns,
+                status: DomainStatus::Active,
+                record_count: None,
+                name_servers: z
+                    .name_servers
+                    .iter()
+                    .map(|ns| normalize_domain_name(ns))
+                    .collect(),
+                created_at: z.creation_time.as_deref().and_then(parse_rfc3339),
+                modified_at: None,
+            })
+            .collect();
+        let domains = filter_domains_by_name(domains, params);
+
+        Ok(paginate_locally(domains, params.page, params.page_size))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let zone = self.get_zone(domain_id).await?;
+        Ok(ProviderDomain {
+            id: zone.name,
+            name: normalize_domain_name(&zone.dns_name),
+            provider: ProviderType::GcloudDns,
+            status: DomainStatus::Active,
+            record_count: None,
+            name_servers: zone
+                .name_servers
+                .iter()
+                .map(|ns| normalize_domain_name(ns))
+                .collect(),
+            created_at: zone.creation_time.as_deref().and_then(parse_rfc3339),
+            modified_at: None,
+        })
+    }
+
+    /// Cloud DNS 的名称过滤只支持精确匹配，拉取全部 RRset 后本地搜索与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let zone = self.get_zone(domain_id).await?;
+        let rrsets = self.list_all_rrsets(domain_id).await?;
+
+        let records: Vec<DnsRecord> = rrsets
+            .iter()
+            .filter(|r| r.record_type != "SOA")
+            .flat_map(|r| Self::rrset_to_records(r, domain_id, &zone.dns_name))
+            .collect();
+
+        Ok(query_records_locally(records, params))
+    }
+
+    /// 向同名同类型的 RRset 追加值（不存在则新建）
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let zone = self.get_zone(&req.domain_id).await?;
+        let fqdn = format!("{}.", relative_to_full_name(&req.name, &zone.dns_name));
+        let record_type = record_type_to_string(&req.record_type);
+        let rrdata = Self::format_rrdata(&req.record_type, &req.value, req.priority);
+
+        let existing = self.get_rrset(&req.domain_id, &fqdn, record_type).await?;
+        if existing
+            .as_ref()
+            .is_some_and(|r| r.rrdatas.contains(&rrdata))
+        {
+            return Err(ProviderError::RecordExists {
+                provider: "gclouddns".to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+
+        let mut rrdatas = existing
+            .as_ref()
+            .map(|r| r.rrdatas.clone())
+            .unwrap_or_default();
+        rrdatas.push(rrdata.clone());
+        let change = Change {
+            additions: vec![ResourceRecordSet {
+                name: fqdn.clone(),
+                record_type: record_type.to_string(),
+                ttl: Some(req.ttl),
+                rrdatas,
+            }],
+            deletions: existing.into_iter().collect(),
+        };
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        self.apply_change(&req.domain_id, &change, ctx).await?;
+
+        let (value, priority) = Self::parse_rrdata(&req.record_type, &rrdata);
+        let now = chrono::Utc::now();
+        Ok(DnsRecord {
+            id: Self::value_record_id(&fqdn, record_type, &rrdata),
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value,
+            ttl: req.ttl,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+        })
+    }
+
+    /// changes.create 以整个 RRset 为单位替换，需先读取原 RRset 再替换其中一个值
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let zone = self.get_zone(&req.domain_id).await?;
+        let fqdn = format!("{}.", relative_to_full_name(&req.name, &zone.dns_name));
+        let record_type = record_type_to_string(&req.record_type);
+        let rrdata = Self::format_rrdata(&req.record_type, &req.value, req.priority);
+
+        let (old_rrset, old_rrdata) = self.find_value(&req.domain_id, record_id).await?;
+        let same_rrset =
+            old_rrset.name.eq_ignore_ascii_case(&fqdn) && old_rrset.record_type == record_type;
+
+        let mut change = Change::default();
+        let target = if same_rrset {
+            // 同一 RRset：替换该值，其余值保持不变
+            Some(old_rrset.clone())
+        } else {
+            // 名称或类型变更：从原 RRset 移出该值，再并入目标 RRset
+            change
+                .additions
+                .extend(without_value(&old_rrset, &old_rrdata));
+            self.get_rrset(&req.domain_id, &fqdn, record_type).await?
+        };
+
+        let mut rrdatas = match &target {
+            Some(target) if same_rrset => without_value(target, &old_rrdata)
+                .map(|r| r.rrdatas)
+                .unwrap_or_default(),
+            Some(target) => target.rrdatas.clone(),
+            None => Vec::new(),
+        };
+        if !rrdatas.contains(&rrdata) {
+            rrdatas.push(rrdata.clone());
+        }
+
+        if !same_rrset {
+            change.deletions.push(old_rrset);
+        }
+        change.deletions.extend(target);
+        change.additions.push(ResourceRecordSet {
+            name: fqdn.clone(),
+            record_type: record_type.to_string(),
+            ttl: Some(req.ttl),
+            rrdatas,
+        });
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        self.apply_change(&req.domain_id, &change, ctx).await?;
+
+        let (value, priority) = Self::parse_rrdata(&req.record_type, &rrdata);
+        Ok(DnsRecord {
+            id: Self::value_record_id(&fqdn, record_type, &rrdata),
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value,
+            ttl: req.ttl,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: Some(chrono::Utc::now()),
+        })
+    }
+
+    /// 只删除 ID 对应的单个值；RRset 中没有剩余值时整体删除
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let (rrset, rrdata) = self.find_value(domain_id, record_id).await?;
+        let change = Change {
+            additions: without_value(&rrset, &rrdata).into_iter().collect(),
+            deletions: vec![rrset],
+        };
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.apply_change(domain_id, &change, ctx).await
+    }
+
+    /// RRset 由名称 + 类型唯一确定，直接整体删除
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        let zone = self.get_zone(domain_id).await?;
+        let fqdn = format!("{}.", relative_to_full_name(name, &zone.dns_name));
+        let Some(rrset) = self
+            .get_rrset(domain_id, &fqdn, record_type_to_string(record_type))
+            .await?
+        else {
+            return Ok(());
+        };
+
+        let change = Change {
+            deletions: vec![rrset],
+            ..Default::default()
+        };
+        let ctx = ErrorContext {
+            record_name: Some(name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.apply_change(domain_id, &change, ctx).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_id_round_trip() {
+        let id = GcloudDnsProvider::value_record_id("www.example.com.", "A", "1.1.1.1");
+        assert_eq!(
+            GcloudDnsProvider::split_record_id(&id),
+            Some(("www.example.com.", "A"))
+        );
+        assert_ne!(
+            id,
+            GcloudDnsProvider::value_record_id("www.example.com.", "A", "1.1.1.2")
+        );
+        assert_eq!(GcloudDnsProvider::split_record_id("legacy"), None);
+    }
+
+    #[test]
+    fn test_rrdata_round_trip() {
+        let cases = [
+            (
+                DnsRecordType::Mx,
+                "mx.example.com",
+                Some(10),
+                "10 mx.example.com.",
+            ),
+            (
+                DnsRecordType::Srv,
+                "5 5060 sip.example.com",
+                Some(1),
+                "1 5 5060 sip.example.com.",
+            ),
+            (
+                DnsRecordType::Cname,
+                "target.example.com",
+                None,
+                "target.example.com.",
+            ),
+            (DnsRecordType::Txt, "v=spf1 -all", None, "\"v=spf1 -all\""),
+            (
+                DnsRecordType::Caa,
+                "0 issue letsencrypt.org",
+                None,
+                "0 issue \"letsencrypt.org\"",
+            ),
+            (DnsRecordType::A, "1.1.1.1", None, "1.1.1.1"),
+            (
+                DnsRecordType::Https,
+                "1 . alpn=\"h3,h2\"",
+                None,
+                "1 . alpn=\"h3,h2\"",
+            ),
+            (
+                DnsRecordType::Svcb,
+                "2 svc.example.com port=8443",
+                None,
+                "2 svc.example.com. port=8443",
+            ),
+        ];
+
+        for (record_type, value, priority, rrdata) in cases {
+            assert_eq!(
+                GcloudDnsProvider::format_rrdata(&record_type, value, priority),
+                rrdata
+            );
+            let (parsed, parsed_priority) = GcloudDnsProvider::parse_rrdata(&record_type, rrdata);
+            assert_eq!(parsed_priority, priority, "{rrdata}");
+            if record_type != DnsRecordType::Caa {
+                assert_eq!(parsed, value, "{rrdata}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_rrset_expands_per_value() {
+        let rrset: ResourceRecordSet = serde_json::from_str(
+            r#"{"kind": "dns#resourceRecordSet", "name": "example.com.", "type": "TXT", "ttl": 300,
+                "rrdatas": ["\"v=spf1 -all\"", "\"google-site-verification=abc\""]}"#,
+        )
+        .unwrap();
+
+        let records = GcloudDnsProvider::rrset_to_records(&rrset, "zone-1", "example.com.");
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].name, "@");
+        assert_eq!(records[0].value, "v=spf1 -all");
+        assert_ne!(records[0].id, records[1].id);
+
+        let remaining = without_value(&rrset, &rrset.rrdatas[0]).unwrap();
+        assert_eq!(remaining.rrdatas, vec![rrset.rrdatas[1].clone()]);
+        assert!(without_value(&remaining, &remaining.rrdatas[0]).is_none());
+    }
+}
@@ -0,0 +1,86 @@
+//! Google Cloud DNS API 类型定义
+//!
+//! 参考: <https://cloud.google.com/dns/docs/reference/rest/v1>
+
+use serde::{Deserialize, Serialize};
+
+// ============ Cloud DNS API 响应结构 ============
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListManagedZonesResponse {
+    pub managed_zones: Option<Vec<ManagedZone>>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZone {
+    /// Zone 资源名称（作为 domain_id 使用）
+    pub name: String,
+    /// 域名，末尾带点
+    pub dns_name: String,
+    /// public / private
+    pub visibility: Option<String>,
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+    /// RFC3339 格式创建时间
+    pub creation_time: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListRrsetsResponse {
+    pub rrsets: Option<Vec<ResourceRecordSet>>,
+    pub next_page_token: Option<String>,
+}
+
+/// 资源记录集（同名同类型的一组值）
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResourceRecordSet {
+    /// 完整域名，末尾带点
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub ttl: Option<u32>,
+    #[serde(default)]
+    pub rrdatas: Vec<String>,
+}
+
+/// changes.create 请求体：先删除 deletions 再添加 additions，整体原子生效
+#[derive(Debug, Default, Serialize)]
+pub struct Change {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub additions: Vec<ResourceRecordSet>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub deletions: Vec<ResourceRecordSet>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangeResponse {
+    pub id: Option<String>,
+    pub status: Option<String>,
+}
+
+// ============ 错误响应 ============
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorResponse {
+    pub error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorBody {
+    pub code: Option<u16>,
+    pub message: Option<String>,
+    /// gRPC 风格状态，如 `NOT_FOUND`
+    pub status: Option<String>,
+    #[serde(default)]
+    pub errors: Vec<ErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorDetail {
+    /// 细分原因，如 `alreadyExists`
+    pub reason: Option<String>,
+}
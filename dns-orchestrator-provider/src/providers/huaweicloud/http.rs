@@ -29,9 +29,9 @@ impl HuaweicloudProvider {
         let authorization = self.sign("GET", path, query, &headers, "", &timestamp);
 
         let url = if query.is_empty() {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}")
+            format!("{}{path}", self.api_base)
         } else {
-            format!("https://{HUAWEICLOUD_DNS_HOST}{path}?{query}")
+            format!("{}{path}?{query}", self.api_base)
         };
 
         // 使用 HttpUtils 发送请求
@@ -47,7 +47,8 @@ impl HuaweicloudProvider {
             self.provider_name(),
             "GET",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -84,7 +85,7 @@ impl HuaweicloudProvider {
 
         let authorization = self.sign("POST", path, "", &headers, &payload, &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
+        let url = format!("{}{path}", self.api_base);
 
         // 使用 HttpUtils 发送请求
         let request = self
@@ -101,7 +102,8 @@ impl HuaweicloudProvider {
             self.provider_name(),
             "POST",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -138,7 +140,7 @@ impl HuaweicloudProvider {
 
         let authorization = self.sign("PUT", path, "", &headers, &payload, &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
+        let url = format!("{}{path}", self.api_base);
 
         // 使用 HttpUtils 发送请求
         let request = self
@@ -155,7 +157,8 @@ impl HuaweicloudProvider {
             self.provider_name(),
             "PUT",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
@@ -178,7 +181,7 @@ impl HuaweicloudProvider {
 
         let authorization = self.sign("DELETE", path, "", &headers, "", &timestamp);
 
-        let url = format!("https://{HUAWEICLOUD_DNS_HOST}{path}");
+        let url = format!("{}{path}", self.api_base);
 
         // 使用 HttpUtils 发送请求
         let request = self
@@ -193,7 +196,8 @@ impl HuaweicloudProvider {
             self.provider_name(),
             "DELETE",
             &url,
-            self.max_retries,
+            &self.retry_policy,
+            &self.rate_limiter,
         )
         .await?;
 
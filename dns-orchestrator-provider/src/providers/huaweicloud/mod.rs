@@ -6,9 +6,14 @@ mod provider;
 mod sign;
 pub(crate) mod types;
 
+use std::time::Duration;
+
 use reqwest::Client;
 
-use crate::providers::common::create_http_client;
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
 
 pub(crate) const HUAWEICLOUD_DNS_HOST: &str = "dns.myhuaweicloud.com";
 /// 华为云 API 单页最大记录数
@@ -17,16 +22,21 @@ pub(crate) const MAX_PAGE_SIZE: u32 = 500;
 /// 华为云 DNS Provider
 pub struct HuaweicloudProvider {
     pub(crate) client: Client,
+    /// API 根地址（测试时指向本地服务）
+    pub(crate) api_base: String,
     pub(crate) access_key_id: String,
     pub(crate) secret_access_key: String,
-    pub(crate) max_retries: u32,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
 }
 
 /// 华为云 Provider Builder
 pub struct HuaweicloudProviderBuilder {
     access_key_id: String,
     secret_access_key: String,
-    max_retries: u32,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
 }
 
 impl HuaweicloudProviderBuilder {
@@ -34,21 +44,56 @@ impl HuaweicloudProviderBuilder {
         Self {
             access_key_id,
             secret_access_key,
-            max_retries: 2,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Huaweicloud),
+            http_config: HttpClientConfig::default(),
         }
     }
 
+    /// 最大重试次数（不含首次请求）
     pub fn max_retries(mut self, retries: u32) -> Self {
-        self.max_retries = retries;
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
         self
     }
 
     pub fn build(self) -> HuaweicloudProvider {
         HuaweicloudProvider {
-            client: create_http_client(),
+            client: build_http_client(&self.http_config),
+            api_base: format!("https://{HUAWEICLOUD_DNS_HOST}"),
             access_key_id: self.access_key_id,
             secret_access_key: self.secret_access_key,
-            max_retries: self.max_retries,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
         }
     }
 }
@@ -2,25 +2,41 @@
 
 use async_trait::async_trait;
 use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 use crate::error::{ProviderError, Result};
 use crate::providers::common::{
-    full_name_to_relative, normalize_domain_name, parse_record_type, record_type_to_string,
-    relative_to_full_name,
+    combine_record_value, full_name_to_relative, normalize_domain_name, paginate_locally,
+    parse_naive_datetime, parse_record_type, record_type_to_string, relative_to_full_name,
+    split_combined_value,
 };
-use crate::traits::{DnsProvider, ErrorContext};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor};
 use crate::types::{
     CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
-    PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures, ProviderLimits,
-    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
 use super::HuaweicloudProvider;
 use super::MAX_PAGE_SIZE;
 use super::types::{
-    CreateRecordSetResponse, ListRecordSetsResponse, ListZonesResponse, ShowPublicZoneResponse,
+    CreateRecordSetResponse, HuaweicloudRecordSet, ListRecordSetsResponse, ListZonesResponse,
+    ShowPublicZoneResponse,
 };
 
+/// 记录 ID 中 RecordSet ID 与值摘要的分隔符
+const VALUE_ID_SEPARATOR: char = ':';
+
+/// 写入 RecordSet 的请求体（创建与更新共用）
+#[derive(Serialize)]
+struct RecordSetBody {
+    name: String,
+    #[serde(rename = "type")]
+    record_type: String,
+    records: Vec<String>,
+    ttl: u32,
+}
+
 impl HuaweicloudProvider {
     /// 将华为云域名状态转换为内部状态
     /// 华为云状态：ACTIVE, `PENDING_CREATE`, `PENDING_UPDATE`, `PENDING_DELETE`,
@@ -39,14 +55,186 @@ impl HuaweicloudProvider {
             _ => DomainStatus::Unknown,
         }
     }
-}
 
-#[async_trait]
-impl DnsProvider for HuaweicloudProvider {
-    fn id(&self) -> &'static str {
-        "huaweicloud"
+    /// 生成单个记录值的 ID：`{recordset_id}:{值摘要}`
+    ///
+    /// 华为云以 RecordSet（同名同类型的一组值）为单位存储记录，
+    /// 每个值需要独立 ID 才能只修改/删除其中一个值。
+    /// 使用值摘要而非下标，避免列表过期后误删其他值。
+    pub(crate) fn value_record_id(recordset_id: &str, raw_value: &str) -> String {
+        let digest = hex::encode(Sha256::digest(raw_value.as_bytes()));
+        format!("{recordset_id}{VALUE_ID_SEPARATOR}{}", &digest[..12])
+    }
+
+    /// 拆分记录 ID 为 (RecordSet ID, 值摘要)；旧格式 ID 不含值摘要
+    pub(crate) fn split_record_id(record_id: &str) -> (&str, Option<&str>) {
+        match record_id.split_once(VALUE_ID_SEPARATOR) {
+            Some((set_id, digest)) => (set_id, Some(digest)),
+            None => (record_id, None),
+        }
+    }
+
+    /// 判断 RecordSet 中的原始值是否对应指定值摘要
+    fn value_matches(recordset_id: &str, raw_value: &str, record_id: &str) -> bool {
+        Self::value_record_id(recordset_id, raw_value) == record_id
+    }
+
+    /// 构造写入华为云的原始值（MX 需要包含优先级，SRV/CAA 使用规范格式，长 TXT 拆分为多段）
+    fn format_raw_value(record_type: &DnsRecordType, value: &str, priority: Option<u16>) -> String {
+        match record_type {
+            DnsRecordType::Mx => format!("{} {}", priority.unwrap_or(10), value),
+            DnsRecordType::Srv | DnsRecordType::Caa | DnsRecordType::Txt => {
+                combine_record_value(record_type, value, priority)
+            }
+            _ => value.to_string(),
+        }
+    }
+
+    /// 解析原始值，返回 (优先级, 值)
+    fn parse_raw_value(record_type: &str, raw_value: &str) -> (Option<u16>, String) {
+        if record_type == "SRV" {
+            let (value, priority) = split_combined_value(&DnsRecordType::Srv, raw_value, None);
+            return (priority, value);
+        }
+        if record_type == "CAA" {
+            let (value, _) = split_combined_value(&DnsRecordType::Caa, raw_value, None);
+            return (None, value);
+        }
+        if record_type == "TXT" {
+            let (value, _) = split_combined_value(&DnsRecordType::Txt, raw_value, None);
+            return (None, value);
+        }
+        if record_type == "MX"
+            && let Some((priority, value)) = raw_value.split_once(' ')
+        {
+            return (priority.parse().ok(), value.to_string());
+        }
+        (None, raw_value.to_string())
+    }
+
+    /// 将 RecordSet 展开为记录列表（每个值一条记录）
+    pub(crate) fn recordset_to_records(
+        recordset: HuaweicloudRecordSet,
+        domain_id: &str,
+        domain_name: &str,
+    ) -> Vec<DnsRecord> {
+        let Ok(record_type) = parse_record_type(&recordset.record_type, "huaweicloud") else {
+            return Vec::new();
+        };
+        let parse_time = |s: Option<&String>| {
+            s.and_then(|s| {
+                chrono::DateTime::parse_from_rfc3339(s)
+                    .ok()
+                    .map(|dt| dt.with_timezone(&chrono::Utc))
+            })
+        };
+        let created_at = parse_time(recordset.created_at.as_ref());
+        let updated_at = parse_time(recordset.updated_at.as_ref());
+        let name = full_name_to_relative(&recordset.name, domain_name);
+
+        recordset
+            .records
+            .unwrap_or_default()
+            .iter()
+            .map(|raw_value| {
+                let (priority, value) = Self::parse_raw_value(&recordset.record_type, raw_value);
+                DnsRecord {
+                    id: Self::value_record_id(&recordset.id, raw_value),
+                    domain_id: domain_id.to_string(),
+                    record_type: record_type.clone(),
+                    name: name.clone(),
+                    value,
+                    ttl: recordset.ttl.unwrap_or(300),
+                    priority,
+                    proxied: None,
+                    line: None,
+                    weight: None,
+                    comment: None,
+                    tags: None,
+                    created_at,
+                    updated_at,
+                }
+            })
+            .collect()
+    }
+
+    /// 获取单个 RecordSet
+    async fn get_recordset(
+        &self,
+        domain_id: &str,
+        recordset_id: &str,
+    ) -> Result<HuaweicloudRecordSet> {
+        let path = format!("/v2/zones/{domain_id}/recordsets/{recordset_id}");
+        let ctx = ErrorContext {
+            record_id: Some(recordset_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.get(&path, "", ctx).await
     }
 
+    /// 覆盖写入 RecordSet 的全部值
+    async fn put_recordset(
+        &self,
+        domain_id: &str,
+        recordset_id: &str,
+        body: &RecordSetBody,
+    ) -> Result<()> {
+        let path = format!("/v2/zones/{domain_id}/recordsets/{recordset_id}");
+        let ctx = ErrorContext {
+            record_name: Some(body.name.clone()),
+            record_id: Some(recordset_id.to_string()),
+            domain: Some(domain_id.to_string()),
+        };
+        let _response: CreateRecordSetResponse = self.put(&path, body, ctx).await?;
+        Ok(())
+    }
+
+    /// 删除整个 RecordSet
+    async fn delete_recordset(&self, domain_id: &str, recordset_id: &str) -> Result<()> {
+        let path = format!("/v2/zones/{domain_id}/recordsets/{recordset_id}");
+        let ctx = ErrorContext {
+            record_id: Some(recordset_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.delete(&path, ctx).await
+    }
+
+    /// 从 RecordSet 中移除单个值；移除后为空则删除整个 RecordSet
+    async fn remove_value(&self, domain_id: &str, record_id: &str) -> Result<()> {
+        let (set_id, _) = Self::split_record_id(record_id);
+        let recordset = self.get_recordset(domain_id, set_id).await?;
+        let records = recordset.records.unwrap_or_default();
+
+        let remaining: Vec<String> = records
+            .iter()
+            .filter(|v| !Self::value_matches(set_id, v, record_id))
+            .cloned()
+            .collect();
+        if remaining.len() == records.len() {
+            return Err(ProviderError::RecordNotFound {
+                provider: "huaweicloud".to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+            });
+        }
+
+        if remaining.is_empty() {
+            return self.delete_recordset(domain_id, set_id).await;
+        }
+
+        let body = RecordSetBody {
+            name: recordset.name,
+            record_type: recordset.record_type,
+            records: remaining,
+            ttl: recordset.ttl.unwrap_or(300),
+        };
+        self.put_recordset(domain_id, set_id, &body).await
+    }
+}
+
+impl ProviderDescriptor for HuaweicloudProvider {
     fn metadata() -> ProviderMetadata {
         ProviderMetadata {
             id: ProviderType::Huaweicloud,
@@ -59,6 +247,7 @@ impl DnsProvider for HuaweicloudProvider {
                     field_type: FieldType::Text,
                     placeholder: Some("输入 Access Key ID".to_string()),
                     help_text: None,
+                    group: None,
                 },
                 ProviderCredentialField {
                     key: "secretAccessKey".to_string(),
@@ -66,15 +255,37 @@ impl DnsProvider for HuaweicloudProvider {
                     field_type: FieldType::Password,
                     placeholder: Some("输入 Secret Access Key".to_string()),
                     help_text: None,
+                    group: None,
                 },
             ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+            ],
             features: ProviderFeatures::default(),
             limits: ProviderLimits {
                 max_page_size_domains: 500,
                 max_page_size_records: 500,
+                min_ttl: 1,
+                max_ttl: 2_147_483_647,
+                default_ttl: 300,
             },
+            pagination: PaginationMode::Page,
         }
     }
+}
+
+#[async_trait]
+impl DnsProvider for HuaweicloudProvider {
+    fn id(&self) -> &'static str {
+        "huaweicloud"
+    }
 
     async fn validate_credentials(&self) -> Result<bool> {
         match self
@@ -97,7 +308,11 @@ impl DnsProvider for HuaweicloudProvider {
         // 华为云使用 offset/limit 分页
         let offset = (params.page - 1) * params.page_size;
         let limit = params.page_size.min(MAX_PAGE_SIZE);
-        let query = format!("type=public&offset={offset}&limit={limit}");
+        let mut query = format!("type=public&offset={offset}&limit={limit}");
+        // name 参数默认模糊匹配
+        if let Some(filter) = params.name_filter() {
+            query.push_str(&format!("&name={}", urlencoding::encode(filter)));
+        }
 
         let response: ListZonesResponse = self
             .get("/v2/zones", &query, ErrorContext::default())
@@ -114,7 +329,17 @@ impl DnsProvider for HuaweicloudProvider {
                 name: normalize_domain_name(&z.name),
                 provider: ProviderType::Huaweicloud,
                 status: Self::convert_domain_status(z.status.as_deref()),
-                record_count: z.record_num,
+                // record_num 统计的是 RecordSet 数，与展开后的记录数不符
+                record_count: None,
+                name_servers: Vec::new(),
+                created_at: z
+                    .created_at
+                    .as_deref()
+                    .and_then(|t| parse_naive_datetime(t, 0)),
+                modified_at: z
+                    .updated_at
+                    .as_deref()
+                    .and_then(|t| parse_naive_datetime(t, 0)),
             })
             .collect();
 
@@ -140,7 +365,16 @@ impl DnsProvider for HuaweicloudProvider {
             name: normalize_domain_name(&response.name),
             provider: ProviderType::Huaweicloud,
             status: Self::convert_domain_status(response.status.as_deref()),
-            record_count: response.record_num,
+            record_count: None,
+            name_servers: Vec::new(),
+            created_at: response
+                .created_at
+                .as_deref()
+                .and_then(|t| parse_naive_datetime(t, 0)),
+            modified_at: response
+                .updated_at
+                .as_deref()
+                .and_then(|t| parse_naive_datetime(t, 0)),
         })
     }
 
@@ -152,22 +386,18 @@ impl DnsProvider for HuaweicloudProvider {
         // 获取域名信息以获取域名名称
         let domain_info = self.get_domain(domain_id).await?;
 
-        // 华为云使用 offset/limit 分页
-        let offset = (params.page - 1) * params.page_size;
-        let limit = params.page_size.min(MAX_PAGE_SIZE);
-        let mut query = format!("offset={offset}&limit={limit}");
-
         // 添加搜索关键词（华为云支持 name 参数模糊匹配）
+        let mut filters = String::new();
         if let Some(ref keyword) = params.keyword
             && !keyword.is_empty()
         {
-            query.push_str(&format!("&name={}", urlencoding::encode(keyword)));
+            filters.push_str(&format!("&name={}", urlencoding::encode(keyword)));
         }
 
         // 添加记录类型过滤
         if let Some(ref record_type) = params.record_type {
             let type_str = record_type_to_string(record_type);
-            query.push_str(&format!("&type={}", urlencoding::encode(type_str)));
+            filters.push_str(&format!("&type={}", urlencoding::encode(type_str)));
         }
 
         let path = format!("/v2/zones/{domain_id}/recordsets");
@@ -175,64 +405,51 @@ impl DnsProvider for HuaweicloudProvider {
             domain: Some(domain_id.to_string()),
             ..Default::default()
         };
-        let response: ListRecordSetsResponse = self.get(&path, &query, ctx).await?;
 
-        let total_count = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
+        // 华为云按 RecordSet 分页，而多值 RecordSet 会展开为多条记录，
+        // 按 RecordSet 计算的 total_count 与页码对不上记录数。
+        // 因此拉取全部 RecordSet 后按记录在本地分页
+        let mut recordsets = Vec::new();
+        let mut offset = 0;
+        loop {
+            let query = format!("offset={offset}&limit={MAX_PAGE_SIZE}{filters}");
+            let response: ListRecordSetsResponse = self.get(&path, &query, ctx.clone()).await?;
+            let total = response.metadata.and_then(|m| m.total_count).unwrap_or(0);
+            let page = response.recordsets.unwrap_or_default();
+            offset += u32::try_from(page.len()).unwrap_or(u32::MAX);
+            let done = page.is_empty() || offset >= total;
+            recordsets.extend(page);
+            if done {
+                break;
+            }
+        }
 
-        let records = response
-            .recordsets
-            .unwrap_or_default()
+        // 每个 RecordSet 展开为多条记录（每个值一条），跳过 SOA
+        let records = recordsets
             .into_iter()
-            .filter_map(|r| {
-                // 跳过 SOA 和 NS 根记录
-                if r.record_type == "SOA" {
-                    return None;
-                }
-
-                let record_type = parse_record_type(&r.record_type, "huaweicloud").ok()?;
-                let value = r.records.as_ref()?.first()?.clone();
-
-                // 提取优先级（对于 MX 记录）
-                let (priority, actual_value) = if r.record_type == "MX" {
-                    let parts: Vec<&str> = value.splitn(2, ' ').collect();
-                    if parts.len() == 2 {
-                        (parts[0].parse().ok(), parts[1].to_string())
-                    } else {
-                        (None, value)
-                    }
-                } else {
-                    (None, value)
-                };
-
-                Some(DnsRecord {
-                    id: r.id,
-                    domain_id: domain_id.to_string(),
-                    record_type,
-                    name: full_name_to_relative(&r.name, &domain_info.name),
-                    value: actual_value,
-                    ttl: r.ttl.unwrap_or(300),
-                    priority,
-                    proxied: None,
-                    created_at: r.created_at.and_then(|s| {
-                        chrono::DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                    }),
-                    updated_at: r.updated_at.and_then(|s| {
-                        chrono::DateTime::parse_from_rfc3339(&s)
-                            .ok()
-                            .map(|dt| dt.with_timezone(&chrono::Utc))
-                    }),
-                })
-            })
+            .filter(|r| r.record_type != "SOA")
+            .flat_map(|r| Self::recordset_to_records(r, domain_id, &domain_info.name))
             .collect();
 
-        Ok(PaginatedResponse::new(
-            records,
-            params.page,
-            params.page_size,
-            total_count,
-        ))
+        Ok(paginate_locally(records, params.page, params.page_size))
+    }
+
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        let (set_id, digest) = Self::split_record_id(record_id);
+        let domain_info = self.get_domain(domain_id).await?;
+        let recordset = self.get_recordset(domain_id, set_id).await?;
+
+        // 旧格式 ID 不含值摘要，取 RecordSet 的第一个值
+        let mut records = Self::recordset_to_records(recordset, domain_id, &domain_info.name);
+        let found = match digest {
+            Some(_) => records.into_iter().find(|r| r.id == record_id),
+            None => (!records.is_empty()).then(|| records.remove(0)),
+        };
+        found.ok_or_else(|| ProviderError::RecordNotFound {
+            provider: "huaweicloud".to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        })
     }
 
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
@@ -242,26 +459,12 @@ impl DnsProvider for HuaweicloudProvider {
         // 构造完整的记录名称（华为云需要末尾带点）
         let full_name = format!("{}.", relative_to_full_name(&req.name, &domain_info.name));
 
-        // 构造记录值（MX 需要包含优先级）
-        let record_value = if req.record_type == DnsRecordType::Mx {
-            format!("{} {}", req.priority.unwrap_or(10), req.value)
-        } else {
-            req.value.clone()
-        };
+        let record_value = Self::format_raw_value(&req.record_type, &req.value, req.priority);
 
-        #[derive(Serialize)]
-        struct CreateRecordSetRequest {
-            name: String,
-            #[serde(rename = "type")]
-            record_type: String,
-            records: Vec<String>,
-            ttl: u32,
-        }
-
-        let api_req = CreateRecordSetRequest {
+        let api_req = RecordSetBody {
             name: full_name,
             record_type: record_type_to_string(&req.record_type).to_string(),
-            records: vec![record_value],
+            records: vec![record_value.clone()],
             ttl: req.ttl,
         };
 
@@ -275,7 +478,7 @@ impl DnsProvider for HuaweicloudProvider {
 
         let now = chrono::Utc::now();
         Ok(DnsRecord {
-            id: response.id,
+            id: Self::value_record_id(&response.id, &record_value),
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
@@ -283,6 +486,10 @@ impl DnsProvider for HuaweicloudProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
             created_at: Some(now),
             updated_at: Some(now),
         })
@@ -299,40 +506,70 @@ impl DnsProvider for HuaweicloudProvider {
         // 构造完整的记录名称（华为云需要末尾带点）
         let full_name = format!("{}.", relative_to_full_name(&req.name, &domain_info.name));
 
-        // 构造记录值（MX 需要包含优先级）
-        let record_value = if req.record_type == DnsRecordType::Mx {
-            format!("{} {}", req.priority.unwrap_or(10), req.value)
+        let record_type = record_type_to_string(&req.record_type).to_string();
+        let record_value = Self::format_raw_value(&req.record_type, &req.value, req.priority);
+        let (set_id, digest) = Self::split_record_id(record_id);
+
+        // 多值 RecordSet：只替换 ID 对应的值，其余值保持不变
+        let records = if digest.is_some() {
+            let recordset = self.get_recordset(&req.domain_id, set_id).await?;
+            let existing = recordset.records.unwrap_or_default();
+            if !existing
+                .iter()
+                .any(|v| Self::value_matches(set_id, v, record_id))
+            {
+                return Err(ProviderError::RecordNotFound {
+                    provider: "huaweicloud".to_string(),
+                    record_id: record_id.to_string(),
+                    raw_message: None,
+                });
+            }
+
+            let same_rrset = recordset.name.eq_ignore_ascii_case(&full_name)
+                && recordset.record_type == record_type;
+            if !same_rrset && existing.len() > 1 {
+                // 名称或类型变更：从原 RecordSet 移出该值，再写入目标 RecordSet
+                self.remove_value(&req.domain_id, record_id).await?;
+                return self
+                    .create_record(&CreateDnsRecordRequest {
+                        domain_id: req.domain_id.clone(),
+                        record_type: req.record_type.clone(),
+                        name: req.name.clone(),
+                        value: req.value.clone(),
+                        ttl: req.ttl,
+                        priority: req.priority,
+                        proxied: None,
+                        line: None,
+                        weight: None,
+                        comment: None,
+                        tags: None,
+                    })
+                    .await;
+            }
+
+            let mut records: Vec<String> = existing
+                .into_iter()
+                .filter(|v| !Self::value_matches(set_id, v, record_id))
+                .collect();
+            if !records.contains(&record_value) {
+                records.push(record_value.clone());
+            }
+            records
         } else {
-            req.value.clone()
+            vec![record_value.clone()]
         };
 
-        #[derive(Serialize)]
-        struct UpdateRecordSetRequest {
-            name: String,
-            #[serde(rename = "type")]
-            record_type: String,
-            records: Vec<String>,
-            ttl: u32,
-        }
-
-        let api_req = UpdateRecordSetRequest {
+        let api_req = RecordSetBody {
             name: full_name,
-            record_type: record_type_to_string(&req.record_type).to_string(),
-            records: vec![record_value],
+            record_type,
+            records,
             ttl: req.ttl,
         };
-
-        let path = format!("/v2/zones/{}/recordsets/{}", req.domain_id, record_id);
-        let ctx = ErrorContext {
-            record_name: Some(req.name.clone()),
-            record_id: Some(record_id.to_string()),
-            domain: Some(req.domain_id.clone()),
-        };
-        let _response: CreateRecordSetResponse = self.put(&path, &api_req, ctx).await?;
+        self.put_recordset(&req.domain_id, set_id, &api_req).await?;
 
         let now = chrono::Utc::now();
         Ok(DnsRecord {
-            id: record_id.to_string(),
+            id: Self::value_record_id(set_id, &record_value),
             domain_id: req.domain_id.clone(),
             record_type: req.record_type.clone(),
             name: req.name.clone(),
@@ -340,18 +577,284 @@ impl DnsProvider for HuaweicloudProvider {
             ttl: req.ttl,
             priority: req.priority,
             proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
             created_at: None,
             updated_at: Some(now),
         })
     }
 
+    /// 只删除 ID 对应的单个值；旧格式 ID（不含值摘要）删除整个 RecordSet
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
-        let path = format!("/v2/zones/{domain_id}/recordsets/{record_id}");
+        match Self::split_record_id(record_id) {
+            (_, Some(_)) => self.remove_value(domain_id, record_id).await,
+            (set_id, None) => self.delete_recordset(domain_id, set_id).await,
+        }
+    }
+
+    /// 原生按 RecordSet 删除
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        let domain_info = self.get_domain(domain_id).await?;
+        let full_name = format!("{}.", relative_to_full_name(name, &domain_info.name));
+        let type_str = record_type_to_string(record_type);
+        let query = format!(
+            "name={}&type={}&search_mode=equal",
+            urlencoding::encode(&full_name),
+            urlencoding::encode(type_str)
+        );
+
+        let path = format!("/v2/zones/{domain_id}/recordsets");
         let ctx = ErrorContext {
-            record_id: Some(record_id.to_string()),
+            record_name: Some(name.to_string()),
             domain: Some(domain_id.to_string()),
             ..Default::default()
         };
-        self.delete(&path, ctx).await
+        let response: ListRecordSetsResponse = self.get(&path, &query, ctx).await?;
+
+        for recordset in response.recordsets.unwrap_or_default() {
+            if recordset.record_type == type_str && recordset.name.eq_ignore_ascii_case(&full_name)
+            {
+                self.delete_recordset(domain_id, &recordset.id).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::providers::test_server::TestServer;
+
+    fn recordset(record_type: &str, records: &[&str]) -> HuaweicloudRecordSet {
+        HuaweicloudRecordSet {
+            id: "set1".to_string(),
+            name: "www.example.com.".to_string(),
+            record_type: record_type.to_string(),
+            records: Some(records.iter().map(ToString::to_string).collect()),
+            ttl: Some(600),
+            status: None,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+
+    #[test]
+    fn test_ptr_rejected() {
+        let err = crate::validate_record_support("huaweicloud", &DnsRecordType::Ptr, None, 0)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            ProviderError::UnsupportedRecordType { ref record_type, .. } if record_type == "PTR"
+        ));
+    }
+
+    #[test]
+    fn test_multi_value_recordset_expands_per_value() {
+        let records = HuaweicloudProvider::recordset_to_records(
+            recordset("A", &["1.1.1.1", "2.2.2.2"]),
+            "zone1",
+            "example.com",
+        );
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].value, "1.1.1.1");
+        assert_eq!(records[1].value, "2.2.2.2");
+        assert!(records.iter().all(|r| r.name == "www"));
+        // 每个值拥有独立 ID，且都指向同一个 RecordSet
+        assert_ne!(records[0].id, records[1].id);
+        for record in &records {
+            let (set_id, digest) = HuaweicloudProvider::split_record_id(&record.id);
+            assert_eq!(set_id, "set1");
+            assert!(digest.is_some());
+        }
+    }
+
+    #[test]
+    fn test_value_id_identifies_single_value() {
+        let id = HuaweicloudProvider::value_record_id("set1", "2.2.2.2");
+        let values = ["1.1.1.1", "2.2.2.2", "3.3.3.3"];
+        let remaining: Vec<_> = values
+            .iter()
+            .filter(|v| !HuaweicloudProvider::value_matches("set1", v, &id))
+            .collect();
+        assert_eq!(remaining, [&"1.1.1.1", &"3.3.3.3"]);
+
+        // ID 只依赖值内容，值顺序变化后仍指向同一个值
+        assert_eq!(id, HuaweicloudProvider::value_record_id("set1", "2.2.2.2"));
+    }
+
+    #[test]
+    fn test_legacy_record_id_targets_whole_recordset() {
+        assert_eq!(HuaweicloudProvider::split_record_id("set1"), ("set1", None));
+    }
+
+    #[test]
+    fn test_mx_recordset_values_keep_priority() {
+        let records = HuaweicloudProvider::recordset_to_records(
+            recordset("MX", &["10 mx1.example.com.", "20 mx2.example.com."]),
+            "zone1",
+            "example.com",
+        );
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].priority, Some(10));
+        assert_eq!(records[0].value, "mx1.example.com.");
+        assert_eq!(records[1].priority, Some(20));
+    }
+
+    #[test]
+    fn test_srv_value_round_trip() {
+        // 拆分格式与规范格式提交后读回的值一致
+        for (value, priority) in [
+            ("5 5060 sip.example.com", Some(1)),
+            ("1 5 5060 sip.example.com", None),
+        ] {
+            let raw = HuaweicloudProvider::format_raw_value(&DnsRecordType::Srv, value, priority);
+            assert_eq!(raw, "1 5 5060 sip.example.com");
+
+            let records = HuaweicloudProvider::recordset_to_records(
+                recordset("SRV", &[&raw]),
+                "zone1",
+                "example.com",
+            );
+            assert_eq!(records[0].value, "5 5060 sip.example.com");
+            assert_eq!(records[0].priority, Some(1));
+        }
+    }
+
+    #[test]
+    fn test_long_txt_value_round_trip() {
+        let dkim = format!("v=DKIM1; k=rsa; p={}", "M".repeat(600 - 18));
+        let raw = HuaweicloudProvider::format_raw_value(&DnsRecordType::Txt, &dkim, None);
+        assert_eq!(raw.matches("\" \"").count(), 2);
+
+        let records = HuaweicloudProvider::recordset_to_records(
+            recordset("TXT", &[&raw, "\"v=spf1 -all\""]),
+            "zone1",
+            "example.com",
+        );
+        assert_eq!(records[0].value, dkim);
+        assert_eq!(records[1].value, "v=spf1 -all");
+    }
+
+    #[test]
+    fn test_caa_value_round_trip() {
+        let raw = HuaweicloudProvider::format_raw_value(
+            &DnsRecordType::Caa,
+            "0 issue letsencrypt.org",
+            None,
+        );
+        assert_eq!(raw, "0 issue \"letsencrypt.org\"");
+
+        let records = HuaweicloudProvider::recordset_to_records(
+            recordset("CAA", &[&raw, "128 iodef mailto:security@example.com"]),
+            "zone1",
+            "example.com",
+        );
+        assert_eq!(records[0].value, "0 issue \"letsencrypt.org\"");
+        assert_eq!(records[0].priority, None);
+        assert_eq!(
+            records[1].value,
+            "128 iodef \"mailto:security@example.com\""
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_records_counts_expanded_values() {
+        // 共 502 个 RecordSet（含 SOA），最后一个 A RecordSet 有 3 个值：
+        // 按 RecordSet 计数为 502，展开后为 500 + 3 = 503 条记录
+        let server = TestServer::start(|req| {
+            let set = |i: usize, record_type: &str, records: &[&str]| {
+                serde_json::json!({
+                    "id": format!("set{i}"), "name": format!("r{i}.example.com."),
+                    "type": record_type, "records": records, "ttl": 300
+                })
+            };
+            let body = if !req.path.contains("/recordsets") {
+                serde_json::json!({ "id": "zone1", "name": "example.com.", "record_num": 502 })
+            } else if req.path.contains("offset=0&") {
+                let sets: Vec<_> = (0..500).map(|i| set(i, "A", &["192.0.2.1"])).collect();
+                serde_json::json!({ "recordsets": sets, "metadata": { "total_count": 502 } })
+            } else {
+                let sets = [
+                    set(
+                        500,
+                        "SOA",
+                        &["ns1.example.com. admin.example.com. 1 7200 900 1209600 300"],
+                    ),
+                    set(501, "A", &["192.0.2.1", "192.0.2.2", "192.0.2.3"]),
+                ];
+                serde_json::json!({ "recordsets": sets, "metadata": { "total_count": 502 } })
+            };
+            (200, body.to_string())
+        })
+        .await;
+        let mut provider = HuaweicloudProvider::new("ak".to_string(), "sk".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let params = |page, page_size| RecordQueryParams {
+            page,
+            page_size,
+            ..Default::default()
+        };
+        let first = provider
+            .list_records("zone1", &params(1, 500))
+            .await
+            .unwrap();
+        assert_eq!(first.total_count, 503);
+        assert_eq!(first.items.len(), 500);
+        assert!(first.has_more);
+
+        let last = provider
+            .list_records("zone1", &params(2, 500))
+            .await
+            .unwrap();
+        assert_eq!(last.items.len(), 3);
+        assert!(!last.has_more);
+        assert!(last.items.iter().all(|r| r.name == "r501"));
+
+        // 区域的 record_num 按 RecordSet 计数，不作为记录数返回
+        let zone = provider.get_domain("zone1").await.unwrap();
+        assert_eq!(zone.record_count, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_record_selects_value_from_recordset() {
+        let server = TestServer::start(|req| {
+            let body = if req.path.contains("/recordsets/set1") {
+                serde_json::json!({
+                    "id": "set1", "name": "www.example.com.", "type": "A",
+                    "records": ["192.0.2.1", "192.0.2.2"], "ttl": 300
+                })
+            } else {
+                serde_json::json!({ "id": "zone1", "name": "example.com." })
+            };
+            (200, body.to_string())
+        })
+        .await;
+        let mut provider = HuaweicloudProvider::new("ak".to_string(), "sk".to_string());
+        provider.api_base.clone_from(&server.url);
+
+        let id = HuaweicloudProvider::value_record_id("set1", "192.0.2.2");
+        let record = provider.get_record(&id, "zone1").await.unwrap();
+        assert_eq!(record.id, id);
+        assert_eq!(record.value, "192.0.2.2");
+        assert_eq!(record.name, "www");
+
+        // 旧格式 ID 取 RecordSet 的第一个值
+        let record = provider.get_record("set1", "zone1").await.unwrap();
+        assert_eq!(record.value, "192.0.2.1");
+
+        let gone = HuaweicloudProvider::value_record_id("set1", "192.0.2.9");
+        let err = provider.get_record(&gone, "zone1").await.unwrap_err();
+        assert!(matches!(err, ProviderError::RecordNotFound { .. }));
     }
 }
@@ -36,7 +36,7 @@ impl HuaweicloudProvider {
 
         // 3. 构造规范请求头
         let mut sorted_headers: Vec<_> = headers.iter().collect();
-        sorted_headers.sort_by(|a, b| a.0.to_lowercase().cmp(&b.0.to_lowercase()));
+        sorted_headers.sort_by_key(|a| a.0.to_lowercase());
 
         let canonical_headers: String = sorted_headers
             .iter()
@@ -21,7 +21,9 @@ pub struct HuaweicloudZone {
     pub id: String,
     pub name: String,
     pub status: Option<String>,
-    pub record_num: Option<u32>,
+    /// UTC 时间，格式 `YYYY-MM-DDTHH:MM:SS.fff`
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
 }
 
 /// ShowPublicZone API 响应结构，需验证是否直接返回 zone 对象
@@ -0,0 +1,699 @@
+//! 内存 Mock Provider
+//!
+//! 不访问任何网络，域名和记录保存在内存中，用于集成测试和离线演示。
+//! 仅在启用 `mock` feature 时编译。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{filter_domains_by_name, record_type_to_string};
+use crate::traits::{DnsProvider, ProviderDescriptor};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderDomain, ProviderFeatures, ProviderLimits,
+    ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+const PROVIDER_NAME: &str = "mock";
+
+/// 未指定种子时预置的演示域名
+const DEFAULT_SEED_DOMAINS: [&str; 2] = ["example.com", "example.org"];
+
+/// 内存状态
+#[derive(Default)]
+struct MockState {
+    /// 域名 ID -> 域名
+    domains: HashMap<String, ProviderDomain>,
+    /// 域名 ID -> 记录列表
+    records: HashMap<String, Vec<DnsRecord>>,
+}
+
+/// 内存 Mock Provider
+pub struct MockProvider {
+    state: Mutex<MockState>,
+    next_id: AtomicU64,
+    credentials_valid: AtomicBool,
+    /// 创建该值的记录时返回错误
+    rejected_value: Option<String>,
+    /// 列表接口是否返回域名的记录数
+    record_counts: bool,
+    /// `DnsProvider::id` 的返回值
+    id: &'static str,
+}
+
+impl MockProvider {
+    /// 创建 Mock Provider
+    ///
+    /// `seed` 为逗号分隔的域名列表，每个域名预置一组常见记录；
+    /// 为 `None` 时使用 `example.com` 和 `example.org`
+    pub fn new(seed: Option<&str>) -> Self {
+        let provider = Self::bare();
+        for domain in Self::seed_domains(seed) {
+            provider.seed_domain(&domain);
+        }
+        provider
+    }
+
+    /// 创建只包含空域名（不预置记录）的 Mock Provider，`seed` 含义同 [`MockProvider::new`]
+    pub fn empty(seed: Option<&str>) -> Self {
+        let provider = Self::bare();
+        for domain in Self::seed_domains(seed) {
+            provider.add_domain(&domain, Vec::new());
+        }
+        provider
+    }
+
+    /// 创建该值的记录时返回错误（用于测试部分失败）
+    #[must_use]
+    pub fn reject_value(mut self, value: impl Into<String>) -> Self {
+        self.rejected_value = Some(value.into());
+        self
+    }
+
+    /// 域名列表不返回记录数（模拟列表接口缺少 `record_count` 的服务商）
+    #[must_use]
+    pub fn without_record_counts(mut self) -> Self {
+        self.record_counts = false;
+        self
+    }
+
+    /// 以其他服务商的 ID 出现（用于测试跨服务商的复制与转换）
+    #[must_use]
+    pub fn with_id(mut self, id: &'static str) -> Self {
+        self.id = id;
+        self
+    }
+
+    /// 设置 `validate_credentials` 的返回值（模拟凭证被吊销）
+    pub fn set_credentials_valid(&self, valid: bool) {
+        self.credentials_valid.store(valid, Ordering::SeqCst);
+    }
+
+    /// 域名下当前的全部记录
+    pub fn records(&self, domain_id: &str) -> Vec<DnsRecord> {
+        self.lock()
+            .records
+            .get(domain_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn bare() -> Self {
+        Self {
+            state: Mutex::new(MockState::default()),
+            next_id: AtomicU64::new(1),
+            credentials_valid: AtomicBool::new(true),
+            rejected_value: None,
+            record_counts: true,
+            id: PROVIDER_NAME,
+        }
+    }
+
+    fn seed_domains(seed: Option<&str>) -> Vec<String> {
+        match seed {
+            Some(seed) => seed
+                .split(',')
+                .map(|d| d.trim().trim_end_matches('.').to_lowercase())
+                .filter(|d| !d.is_empty())
+                .collect(),
+            None => DEFAULT_SEED_DOMAINS
+                .iter()
+                .map(|d| (*d).to_string())
+                .collect(),
+        }
+    }
+
+    /// 预置域名及其演示记录
+    fn seed_domain(&self, name: &str) {
+        let seeds = [
+            (DnsRecordType::A, "@", "192.0.2.1", None),
+            (DnsRecordType::Cname, "www", name, None),
+            (DnsRecordType::Mx, "@", "mail.example.net", Some(10)),
+            (DnsRecordType::Txt, "@", "v=spf1 -all", None),
+        ];
+        let records = seeds
+            .into_iter()
+            .map(|(record_type, record_name, value, priority)| DnsRecord {
+                id: self.next_id(),
+                domain_id: name.to_string(),
+                record_type,
+                name: record_name.to_string(),
+                value: value.to_string(),
+                ttl: 600,
+                priority,
+                proxied: None,
+                line: None,
+                weight: None,
+                comment: None,
+                tags: None,
+                created_at: Some(chrono::Utc::now()),
+                updated_at: None,
+            })
+            .collect();
+        self.add_domain(name, records);
+    }
+
+    fn add_domain(&self, name: &str, records: Vec<DnsRecord>) {
+        let mut state = self.lock();
+        state.domains.insert(
+            name.to_string(),
+            ProviderDomain {
+                id: name.to_string(),
+                name: name.to_string(),
+                provider: ProviderType::Mock,
+                status: DomainStatus::Active,
+                record_count: None,
+                name_servers: vec![
+                    "ns1.mock.invalid".to_string(),
+                    "ns2.mock.invalid".to_string(),
+                ],
+                created_at: Some(chrono::Utc::now()),
+                modified_at: None,
+            },
+        );
+        state.records.insert(name.to_string(), records);
+    }
+
+    fn next_id(&self) -> String {
+        format!("mock-{}", self.next_id.fetch_add(1, Ordering::SeqCst))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn domain_not_found(domain_id: &str) -> ProviderError {
+        ProviderError::DomainNotFound {
+            provider: PROVIDER_NAME.to_string(),
+            domain: domain_id.to_string(),
+            raw_message: None,
+        }
+    }
+
+    fn record_not_found(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: PROVIDER_NAME.to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        }
+    }
+
+    /// 按页截取
+    fn paginate<T: Clone>(items: &[T], page: u32, page_size: u32) -> PaginatedResponse<T> {
+        let start = (page.saturating_sub(1) as usize).saturating_mul(page_size as usize);
+        let page_items = items
+            .iter()
+            .skip(start)
+            .take(page_size as usize)
+            .cloned()
+            .collect();
+        PaginatedResponse::new(page_items, page, page_size, items.len() as u32)
+    }
+}
+
+impl ProviderDescriptor for MockProvider {
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Mock,
+            name: "Mock".to_string(),
+            description: "内存模拟服务商，用于测试和离线演示".to_string(),
+            required_fields: vec![],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Ptr,
+                DnsRecordType::Https,
+                DnsRecordType::Svcb,
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: 100,
+                max_page_size_records: 100,
+                min_ttl: 1,
+                max_ttl: 604_800,
+                default_ttl: 600,
+            },
+            pagination: PaginationMode::Page,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for MockProvider {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        Ok(self.credentials_valid.load(Ordering::SeqCst))
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let state = self.lock();
+        let mut domains: Vec<ProviderDomain> = state
+            .domains
+            .values()
+            .map(|d| ProviderDomain {
+                record_count: state
+                    .records
+                    .get(&d.id)
+                    .filter(|_| self.record_counts)
+                    .map(|r| r.len() as u32),
+                ..d.clone()
+            })
+            .collect();
+        domains.sort_by(|a, b| a.name.cmp(&b.name));
+        let domains = filter_domains_by_name(domains, params);
+        Ok(Self::paginate(&domains, params.page, params.page_size))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let state = self.lock();
+        let domain = state
+            .domains
+            .get(domain_id)
+            .ok_or_else(|| Self::domain_not_found(domain_id))?;
+        Ok(ProviderDomain {
+            record_count: state.records.get(domain_id).map(|r| r.len() as u32),
+            ..domain.clone()
+        })
+    }
+
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let state = self.lock();
+        let records = state
+            .records
+            .get(domain_id)
+            .ok_or_else(|| Self::domain_not_found(domain_id))?;
+
+        let keyword = params
+            .keyword
+            .as_deref()
+            .filter(|k| !k.is_empty())
+            .map(str::to_lowercase);
+        let matched: Vec<DnsRecord> = records
+            .iter()
+            .filter(|r| {
+                params
+                    .record_type
+                    .as_ref()
+                    .is_none_or(|t| *t == r.record_type)
+            })
+            .filter(|r| {
+                keyword
+                    .as_deref()
+                    .is_none_or(|k| r.name.to_lowercase().contains(k))
+            })
+            .cloned()
+            .collect();
+        Ok(Self::paginate(&matched, params.page, params.page_size))
+    }
+
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        let state = self.lock();
+        let records = state
+            .records
+            .get(domain_id)
+            .ok_or_else(|| Self::domain_not_found(domain_id))?;
+        records
+            .iter()
+            .find(|r| r.id == record_id)
+            .cloned()
+            .ok_or_else(|| Self::record_not_found(record_id))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        if self.rejected_value.as_deref() == Some(req.value.as_str()) {
+            return Err(ProviderError::InvalidRecord {
+                provider: PROVIDER_NAME.to_string(),
+                record_type: record_type_to_string(&req.record_type).to_string(),
+                detail: format!("rejected value {}", req.value),
+            });
+        }
+        let id = self.next_id();
+        let mut state = self.lock();
+        let records = state
+            .records
+            .get_mut(&req.domain_id)
+            .ok_or_else(|| Self::domain_not_found(&req.domain_id))?;
+
+        let identity = req.record_identity();
+        if records.iter().any(|r| r.record_identity() == identity) {
+            return Err(ProviderError::RecordExists {
+                provider: PROVIDER_NAME.to_string(),
+                record_name: req.name.clone(),
+                raw_message: None,
+            });
+        }
+
+        let record = DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value: req.value.clone(),
+            ttl: req.ttl,
+            priority: req.priority,
+            proxied: req.proxied,
+            line: req.line.clone(),
+            weight: req.weight,
+            comment: req.comment.clone(),
+            tags: req.tags.clone(),
+            created_at: Some(chrono::Utc::now()),
+            updated_at: None,
+        };
+        records.push(record.clone());
+        Ok(record)
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let mut state = self.lock();
+        let record = state
+            .records
+            .get_mut(&req.domain_id)
+            .ok_or_else(|| Self::domain_not_found(&req.domain_id))?
+            .iter_mut()
+            .find(|r| r.id == record_id)
+            .ok_or_else(|| Self::record_not_found(record_id))?;
+
+        record.record_type = req.record_type.clone();
+        record.name = req.name.clone();
+        record.value = req.value.clone();
+        record.ttl = req.ttl;
+        record.priority = req.priority;
+        record.proxied = req.proxied;
+        record.line = req.line.clone();
+        record.weight = req.weight;
+        record.comment = req.comment.clone();
+        record.tags = req.tags.clone();
+        record.updated_at = Some(chrono::Utc::now());
+        Ok(record.clone())
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let mut state = self.lock();
+        let records = state
+            .records
+            .get_mut(domain_id)
+            .ok_or_else(|| Self::domain_not_found(domain_id))?;
+        let before = records.len();
+        records.retain(|r| r.id != record_id);
+        if records.len() == before {
+            return Err(Self::record_not_found(record_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(page: u32, page_size: u32) -> RecordQueryParams {
+        RecordQueryParams {
+            page,
+            page_size,
+            keyword: None,
+            record_type: None,
+            cursor: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_seed_and_pagination() {
+        let provider = MockProvider::new(Some("b.test, a.test"));
+
+        let first = provider
+            .list_domains(&PaginationParams {
+                page: 1,
+                page_size: 1,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(first.total_count, 2);
+        assert!(first.has_more);
+        assert_eq!(first.items[0].name, "a.test");
+        assert_eq!(first.items[0].record_count, Some(4));
+
+        let records = provider.list_records("a.test", &query(2, 3)).await.unwrap();
+        assert_eq!(records.total_count, 4);
+        assert_eq!(records.items.len(), 1);
+        assert!(!records.has_more);
+    }
+
+    #[tokio::test]
+    async fn test_list_domains_name_filter() {
+        let provider = MockProvider::new(Some("shop.test, blog.test, shop.example"));
+
+        let filtered = provider
+            .list_domains(&PaginationParams {
+                name_filter: Some("SHOP".to_string()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(filtered.total_count, 2);
+        let names: Vec<_> = filtered.items.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, ["shop.example", "shop.test"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_records_filters() {
+        let provider = MockProvider::new(None);
+
+        let by_type = provider
+            .list_records(
+                "example.com",
+                &RecordQueryParams {
+                    record_type: Some(DnsRecordType::Mx),
+                    ..query(1, 20)
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(by_type.items.len(), 1);
+        assert_eq!(by_type.items[0].priority, Some(10));
+
+        let by_keyword = provider
+            .list_records(
+                "example.com",
+                &RecordQueryParams {
+                    keyword: Some("WW".to_string()),
+                    ..query(1, 20)
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(by_keyword.items.len(), 1);
+        assert_eq!(by_keyword.items[0].name, "www");
+    }
+
+    #[tokio::test]
+    async fn test_record_crud() {
+        let provider = MockProvider::new(None);
+        let req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "api".to_string(),
+            value: "192.0.2.10".to_string(),
+            ttl: 300,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+
+        let created = provider.create_record(&req).await.unwrap();
+        assert!(matches!(
+            provider.create_record(&req).await,
+            Err(ProviderError::RecordExists { .. })
+        ));
+
+        let updated = provider
+            .update_record(
+                &created.id,
+                &UpdateDnsRecordRequest {
+                    domain_id: "example.com".to_string(),
+                    record_type: DnsRecordType::A,
+                    name: "api".to_string(),
+                    value: "192.0.2.11".to_string(),
+                    ttl: 300,
+                    priority: None,
+                    proxied: None,
+                    line: None,
+                    weight: None,
+                    comment: None,
+                    tags: None,
+                    expected_version: None,
+                    expected_value: None,
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(updated.value, "192.0.2.11");
+        assert_eq!(
+            provider
+                .get_record(&created.id, "example.com")
+                .await
+                .unwrap()
+                .value,
+            "192.0.2.11"
+        );
+
+        provider
+            .delete_record(&created.id, "example.com")
+            .await
+            .unwrap();
+        assert!(matches!(
+            provider.get_record(&created.id, "example.com").await,
+            Err(ProviderError::RecordNotFound { .. })
+        ));
+        assert!(matches!(
+            provider.delete_record(&created.id, "example.com").await,
+            Err(ProviderError::RecordNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_line_returned_by_list() {
+        let provider = MockProvider::new(Some("line.test"));
+        let created = provider
+            .create_record(&CreateDnsRecordRequest {
+                domain_id: "line.test".to_string(),
+                record_type: DnsRecordType::A,
+                name: "cn".to_string(),
+                value: "192.0.2.20".to_string(),
+                ttl: 600,
+                priority: None,
+                proxied: None,
+                line: Some("电信".to_string()),
+                weight: None,
+                comment: None,
+                tags: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(created.line.as_deref(), Some("电信"));
+
+        let listed = provider
+            .list_records("line.test", &query(1, 20))
+            .await
+            .unwrap();
+        let record = listed.items.iter().find(|r| r.id == created.id).unwrap();
+        assert_eq!(record.line.as_deref(), Some("电信"));
+    }
+
+    #[tokio::test]
+    async fn test_unknown_domain() {
+        let provider = MockProvider::new(None);
+        assert!(matches!(
+            provider.get_domain("missing.test").await,
+            Err(ProviderError::DomainNotFound { .. })
+        ));
+        assert!(matches!(
+            provider.list_records("missing.test", &query(1, 20)).await,
+            Err(ProviderError::DomainNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_batch_create_records_default() {
+        let provider = MockProvider::new(Some("bulk.test"));
+
+        let records: Vec<_> = ["a", "b", "a"]
+            .into_iter()
+            .map(|name| CreateDnsRecordRequest {
+                domain_id: "bulk.test".to_string(),
+                record_type: DnsRecordType::A,
+                name: name.to_string(),
+                value: "192.0.2.10".to_string(),
+                ttl: 600,
+                priority: None,
+                proxied: None,
+                line: None,
+                weight: None,
+                comment: None,
+                tags: None,
+            })
+            .collect();
+        let result = provider.batch_create_records(&records).await.unwrap();
+        let names: Vec<_> = result
+            .created_records
+            .iter()
+            .map(|r| r.name.as_str())
+            .collect();
+        assert_eq!(names, ["a", "b"]);
+        // 重复的第三条失败，不影响前两条
+        assert_eq!(result.failed_count, 1);
+        assert_eq!(result.failures[0].request_index, 2);
+
+        let listed = provider
+            .list_records("bulk.test", &query(1, 20))
+            .await
+            .unwrap();
+        assert_eq!(listed.total_count, 6);
+    }
+
+    #[tokio::test]
+    async fn test_empty_domain_and_rejected_value() {
+        let provider = MockProvider::empty(Some("empty.test"))
+            .reject_value("192.0.2.99")
+            .without_record_counts();
+        assert!(provider.records("empty.test").is_empty());
+
+        let domains = provider
+            .list_domains(&PaginationParams::default())
+            .await
+            .unwrap();
+        assert_eq!(domains.items[0].record_count, None);
+
+        let request = |value: &str| CreateDnsRecordRequest {
+            domain_id: "empty.test".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        assert!(matches!(
+            provider.create_record(&request("192.0.2.99")).await,
+            Err(ProviderError::InvalidRecord { .. })
+        ));
+        provider.create_record(&request("192.0.2.1")).await.unwrap();
+        assert_eq!(provider.records("empty.test").len(), 1);
+
+        provider.set_credentials_valid(false);
+        assert!(!provider.validate_credentials().await.unwrap());
+    }
+}
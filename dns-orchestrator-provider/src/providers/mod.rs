@@ -1,6 +1,8 @@
 //! DNS Provider implementations
 
 pub mod common;
+#[cfg(test)]
+pub(crate) mod test_server;
 
 #[cfg(feature = "aliyun")]
 mod aliyun;
@@ -8,8 +10,18 @@ mod aliyun;
 mod cloudflare;
 #[cfg(feature = "dnspod")]
 mod dnspod;
+#[cfg(feature = "gclouddns")]
+mod gclouddns;
 #[cfg(feature = "huaweicloud")]
 mod huaweicloud;
+#[cfg(feature = "mock")]
+mod mock;
+#[cfg(feature = "namecheap")]
+mod namecheap;
+#[cfg(feature = "porkbun")]
+mod porkbun;
+#[cfg(feature = "rfc2136")]
+mod rfc2136;
 
 #[cfg(feature = "aliyun")]
 pub use aliyun::AliyunProvider;
@@ -17,5 +29,33 @@ pub use aliyun::AliyunProvider;
 pub use cloudflare::CloudflareProvider;
 #[cfg(feature = "dnspod")]
 pub use dnspod::DnspodProvider;
+#[cfg(feature = "gclouddns")]
+pub use gclouddns::GcloudDnsProvider;
 #[cfg(feature = "huaweicloud")]
 pub use huaweicloud::HuaweicloudProvider;
+#[cfg(feature = "mock")]
+pub use mock::MockProvider;
+#[cfg(feature = "namecheap")]
+pub use namecheap::NamecheapProvider;
+#[cfg(feature = "porkbun")]
+pub use porkbun::PorkbunProvider;
+#[cfg(feature = "rfc2136")]
+pub use rfc2136::Rfc2136Provider;
+
+/// 校验 RFC 2136 TSIG 算法名称（供凭证解析使用）
+#[cfg(feature = "rfc2136")]
+pub(crate) fn rfc2136_tsig_algorithm(algorithm: &str) -> Result<(), String> {
+    rfc2136::parse_tsig_algorithm(algorithm).map(|_| ())
+}
+
+/// 校验 RFC 2136 TSIG 密钥（供凭证解析使用）
+#[cfg(feature = "rfc2136")]
+pub(crate) fn rfc2136_tsig_secret(secret: &str) -> Result<(), String> {
+    rfc2136::decode_tsig_secret(secret).map(|_| ())
+}
+
+/// 校验 Google Cloud 服务账号 JSON（供凭证解析使用）
+#[cfg(feature = "gclouddns")]
+pub(crate) fn gclouddns_service_account(json: &str) -> Result<(), String> {
+    gclouddns::ServiceAccountKey::parse(json).map(|_| ())
+}
@@ -0,0 +1,67 @@
+//! Namecheap 错误映射
+//!
+//! 参考: <https://www.namecheap.com/support/api/error-codes/>
+//!
+//! 错误码取 `<Error Number="...">`，HTTP 状态码始终为 200
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::NamecheapProvider;
+
+/// Namecheap 错误码映射实现
+impl ProviderErrorMapper for NamecheapProvider {
+    fn provider_name(&self) -> &'static str {
+        "namecheap"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        match raw.code.as_deref() {
+            // ============ 认证错误 ============
+            Some(
+                "1010101" // ApiUser 缺失
+                | "1011102" // ApiKey 无效
+                | "1010104" // UserName 缺失
+                | "1011150" // 调用方 IP 不在白名单中
+                | "1017150" // 调用方 IP 被禁用
+                | "1017105", // ApiUser 被禁用
+            ) => ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名不存在 ============
+            Some(
+                "2019166" // 域名不存在
+                | "2016166", // 域名不属于当前账户
+            ) => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 域名未使用 Namecheap DNS / 无编辑权限 ============
+            Some("2030166" | "2030288") => ProviderError::DomainLocked {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 频率限制 ============
+            Some("500000") => ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 参数无效 ============
+            Some("2011166" | "2050900" | "2015280") => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "hosts".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
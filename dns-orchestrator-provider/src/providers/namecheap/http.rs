@@ -0,0 +1,89 @@
+//! Namecheap HTTP 请求方法
+
+use serde::Deserialize;
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::NamecheapProvider;
+use super::types::ApiResponse;
+
+impl NamecheapProvider {
+    /// 执行 API 命令
+    ///
+    /// 统一使用表单 POST，避免 setHosts 携带大量记录时超出 URL 长度限制
+    pub(crate) async fn call<T: for<'de> Deserialize<'de>>(
+        &self,
+        command: &str,
+        params: &[(String, String)],
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let auth_params = [
+            ("ApiUser", self.api_user.as_str()),
+            ("ApiKey", self.api_key.as_str()),
+            ("UserName", self.username.as_str()),
+            ("ClientIp", self.client_ip.as_str()),
+            ("Command", command),
+        ];
+        let body = auth_params
+            .into_iter()
+            .chain(params.iter().map(|(k, v)| (k.as_str(), v.as_str())))
+            .map(|(k, v)| format!("{k}={}", urlencoding::encode(v)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let request = self
+            .client
+            .post(&self.api_url)
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body(body);
+
+        // 查询与 setHosts（整体替换）均可安全重放
+        let (status, response_text) = HttpUtils::execute_idempotent_request_with_retry(
+            request,
+            self.provider_name(),
+            "POST",
+            command,
+            &self.retry_policy,
+            &self.rate_limiter,
+        )
+        .await?;
+
+        if !(200..300).contains(&status) {
+            return Err(
+                self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}")))
+            );
+        }
+
+        let response: ApiResponse<T> = self.parse_xml(&response_text)?;
+
+        // 处理错误响应
+        if !response.status.eq_ignore_ascii_case("OK") {
+            let raw = response.errors.items.into_iter().next().map_or_else(
+                || RawApiError::new(format!("{command} failed")),
+                |e| match e.number {
+                    Some(number) => RawApiError::with_code(number, e.message),
+                    None => RawApiError::new(e.message),
+                },
+            );
+            return Err(self.map_error(raw, ctx));
+        }
+
+        response
+            .command_response
+            .ok_or_else(|| self.parse_error("missing CommandResponse"))
+    }
+
+    /// 解析 XML 响应
+    fn parse_xml<T: for<'de> Deserialize<'de>>(&self, response_text: &str) -> Result<T> {
+        quick_xml::de::from_str(response_text).map_err(|e| {
+            log::error!("[{}] XML 解析失败: {}", self.provider_name(), e);
+            log::error!("[{}] 原始响应: {}", self.provider_name(), response_text);
+            ProviderError::ParseError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            }
+        })
+    }
+}
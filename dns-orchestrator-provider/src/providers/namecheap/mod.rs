@@ -0,0 +1,125 @@
+//! Namecheap DNS Provider
+
+mod error;
+mod http;
+mod provider;
+pub(crate) mod types;
+
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
+
+pub(crate) const NAMECHEAP_API_URL: &str = "https://api.namecheap.com/xml.response";
+/// Namecheap domains.getList 单页最大域名数
+pub(crate) const MAX_PAGE_SIZE: u32 = 100;
+/// Namecheap 面板中"Automatic" TTL 对应的值
+pub(crate) const AUTOMATIC_TTL: u32 = 1799;
+
+/// Namecheap DNS Provider
+///
+/// Namecheap API 只接受白名单中的调用方 IP，`client_ip` 需填写当前公网 IP
+pub struct NamecheapProvider {
+    pub(crate) client: Client,
+    /// API 地址（测试时指向本地服务）
+    pub(crate) api_url: String,
+    pub(crate) api_user: String,
+    pub(crate) api_key: String,
+    pub(crate) username: String,
+    pub(crate) client_ip: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
+}
+
+/// Namecheap Provider Builder
+pub struct NamecheapProviderBuilder {
+    api_user: String,
+    api_key: String,
+    username: String,
+    client_ip: String,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
+}
+
+impl NamecheapProviderBuilder {
+    fn new(api_user: String, api_key: String, username: String, client_ip: String) -> Self {
+        Self {
+            api_user,
+            api_key,
+            username,
+            client_ip,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Namecheap),
+            http_config: HttpClientConfig::default(),
+        }
+    }
+
+    /// 最大重试次数（不含首次请求）
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
+        self
+    }
+
+    pub fn build(self) -> NamecheapProvider {
+        NamecheapProvider {
+            client: build_http_client(&self.http_config),
+            api_url: NAMECHEAP_API_URL.to_string(),
+            api_user: self.api_user,
+            api_key: self.api_key,
+            username: self.username,
+            client_ip: self.client_ip,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
+        }
+    }
+}
+
+impl NamecheapProvider {
+    pub fn new(api_user: String, api_key: String, username: String, client_ip: String) -> Self {
+        Self::builder(api_user, api_key, username, client_ip).build()
+    }
+
+    pub fn builder(
+        api_user: String,
+        api_key: String,
+        username: String,
+        client_ip: String,
+    ) -> NamecheapProviderBuilder {
+        NamecheapProviderBuilder::new(api_user, api_key, username, client_ip)
+    }
+}
@@ -0,0 +1,801 @@
+//! Namecheap DnsProvider trait 实现
+
+use async_trait::async_trait;
+use sha2::{Digest, Sha256};
+
+use crate::error::{ProviderError, Result};
+use crate::identity::{is_apex_name, same_record_name};
+use crate::providers::common::{
+    normalize_domain_name, parse_record_type, query_records_locally, record_type_to_string,
+    split_combined_value, unsupported_record_type,
+};
+use crate::traits::{
+    DnsProvider, ErrorContext, ProviderDescriptor, ProviderErrorMapper, RawApiError,
+};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::types::{
+    DomainDnsGetHostsResult, DomainInfoResponse, DomainListResponse, GetHostsResponse,
+    NamecheapHost, SetHostsResponse,
+};
+use super::{AUTOMATIC_TTL, MAX_PAGE_SIZE, NamecheapProvider};
+
+/// 记录 ID 各部分的分隔符：`{name}/{TYPE}/{值摘要}`
+const RECORD_ID_SEPARATOR: char = '/';
+/// Namecheap 默认 TTL
+const DEFAULT_TTL: u32 = 1800;
+/// 未指定优先级时的 MX 优先级
+const DEFAULT_MX_PREF: u16 = 10;
+
+impl NamecheapProvider {
+    /// 拆分域名为 (SLD, TLD)：`example.co.uk` -> (`example`, `co.uk`)
+    pub(crate) fn split_domain(domain: &str) -> (&str, &str) {
+        domain.split_once('.').unwrap_or((domain, ""))
+    }
+
+    /// 域名请求参数
+    fn domain_params(domain: &str) -> Vec<(String, String)> {
+        let (sld, tld) = Self::split_domain(domain);
+        vec![
+            ("SLD".to_string(), sld.to_string()),
+            ("TLD".to_string(), tld.to_string()),
+        ]
+    }
+
+    /// 生成记录 ID：`{name}/{TYPE}/{值摘要}`
+    ///
+    /// Namecheap 的 HostId 在每次 setHosts 后都会重新生成，无法作为稳定 ID，
+    /// 因此按记录内容生成。getHosts 对所有类型都返回 MXPref（非 MX 记录恒为 10），
+    /// 只有 MX 记录把优先级计入摘要，创建与列表两条路径才能得到相同的 ID
+    pub(crate) fn value_record_id(host: &NamecheapHost) -> String {
+        let content = if host.record_type.eq_ignore_ascii_case("MX") {
+            format!(
+                "{}|{}",
+                host.address,
+                host.mx_pref.unwrap_or(DEFAULT_MX_PREF)
+            )
+        } else {
+            host.address.clone()
+        };
+        let digest = hex::encode(Sha256::digest(content.as_bytes()));
+        format!(
+            "{}{RECORD_ID_SEPARATOR}{}{RECORD_ID_SEPARATOR}{}",
+            host.name.to_ascii_lowercase(),
+            host.record_type.to_ascii_uppercase(),
+            &digest[..12]
+        )
+    }
+
+    /// 将主机记录转换为 `DnsRecord`；URL 转发等非标准类型返回 None
+    pub(crate) fn host_to_record(host: &NamecheapHost, domain_id: &str) -> Option<DnsRecord> {
+        let record_type = parse_record_type(&host.record_type, "namecheap").ok()?;
+        let priority = (record_type == DnsRecordType::Mx)
+            .then_some(host.mx_pref)
+            .flatten();
+        let (value, priority) = match record_type {
+            DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Alias => {
+                (normalize_domain_name(&host.address), None)
+            }
+            _ => split_combined_value(&record_type, &host.address, priority),
+        };
+
+        Some(DnsRecord {
+            id: Self::value_record_id(host),
+            domain_id: domain_id.to_string(),
+            record_type,
+            name: host.name.clone(),
+            value,
+            ttl: host.ttl.unwrap_or(DEFAULT_TTL),
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        })
+    }
+
+    /// 由请求内容构造主机记录
+    fn build_host(
+        record_type: &DnsRecordType,
+        name: &str,
+        value: &str,
+        ttl: u32,
+        priority: Option<u16>,
+    ) -> Result<NamecheapHost> {
+        let address = match record_type {
+            DnsRecordType::Caa => split_combined_value(record_type, value, None).0,
+            DnsRecordType::Srv
+            | DnsRecordType::Ptr
+            | DnsRecordType::Https
+            | DnsRecordType::Svcb => {
+                return Err(unsupported_record_type(record_type, "namecheap"));
+            }
+            _ => value.trim().to_string(),
+        };
+        let mx_pref =
+            (*record_type == DnsRecordType::Mx).then(|| priority.unwrap_or(DEFAULT_MX_PREF));
+
+        Ok(NamecheapHost {
+            name: if is_apex_name(name) {
+                "@".to_string()
+            } else {
+                name.to_string()
+            },
+            record_type: record_type_to_string(record_type).to_string(),
+            address,
+            mx_pref,
+            ttl: Some(ttl),
+        })
+    }
+
+    /// 计算 setHosts 的 EmailType：存在 MX 记录时必须为 MX，
+    /// 否则保留原有的邮件设置（如邮件转发），原为 MX 则置为 NONE
+    pub(crate) fn email_type(hosts: &[NamecheapHost], original: Option<&str>) -> String {
+        if hosts
+            .iter()
+            .any(|h| h.record_type.eq_ignore_ascii_case("MX"))
+        {
+            return "MX".to_string();
+        }
+        match original {
+            Some(t) if !t.is_empty() && !t.eq_ignore_ascii_case("MX") => t.to_string(),
+            _ => "NONE".to_string(),
+        }
+    }
+
+    /// 构造 setHosts 的记录参数（HostName1、RecordType1 ...）
+    pub(crate) fn set_hosts_params(
+        hosts: &[NamecheapHost],
+        email_type: &str,
+    ) -> Vec<(String, String)> {
+        let mut params = vec![("EmailType".to_string(), email_type.to_string())];
+        for (i, host) in hosts.iter().enumerate() {
+            let n = i + 1;
+            params.push((format!("HostName{n}"), host.name.clone()));
+            params.push((format!("RecordType{n}"), host.record_type.clone()));
+            params.push((format!("Address{n}"), host.address.clone()));
+            params.push((
+                format!("TTL{n}"),
+                host.ttl.unwrap_or(DEFAULT_TTL).to_string(),
+            ));
+            if let Some(mx_pref) = host.mx_pref {
+                params.push((format!("MXPref{n}"), mx_pref.to_string()));
+            }
+        }
+        params
+    }
+
+    /// 获取域名下的全部主机记录
+    async fn get_hosts(&self, domain: &str) -> Result<DomainDnsGetHostsResult> {
+        let ctx = ErrorContext {
+            domain: Some(domain.to_string()),
+            ..Default::default()
+        };
+        let response: GetHostsResponse = self
+            .call(
+                "namecheap.domains.dns.getHosts",
+                &Self::domain_params(domain),
+                ctx,
+            )
+            .await?;
+        Ok(response.result)
+    }
+
+    /// 整体替换域名下的主机记录
+    async fn set_hosts(
+        &self,
+        domain: &str,
+        hosts: &[NamecheapHost],
+        original_email_type: Option<&str>,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        let mut params = Self::domain_params(domain);
+        params.extend(Self::set_hosts_params(
+            hosts,
+            &Self::email_type(hosts, original_email_type),
+        ));
+
+        let response: SetHostsResponse = self
+            .call("namecheap.domains.dns.setHosts", &params, ctx)
+            .await?;
+        if response.result.is_success {
+            Ok(())
+        } else {
+            Err(self.unknown_error(RawApiError::new(format!("setHosts failed for {domain}"))))
+        }
+    }
+
+    /// 读取全部记录 -> 修改 -> 整体写回
+    ///
+    /// Namecheap 每次写入都会替换整个主机列表，所有修改都必须基于最新的完整列表
+    async fn modify_hosts<F>(&self, domain: &str, ctx: ErrorContext, mutate: F) -> Result<()>
+    where
+        F: FnOnce(&mut Vec<NamecheapHost>) -> Result<()> + Send,
+    {
+        let current = self.get_hosts(domain).await?;
+        let mut hosts = current.hosts;
+        mutate(&mut hosts)?;
+        self.set_hosts(domain, &hosts, current.email_type.as_deref(), ctx)
+            .await
+    }
+
+    fn record_not_found(record_id: &str) -> ProviderError {
+        ProviderError::RecordNotFound {
+            provider: "namecheap".to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        }
+    }
+}
+
+impl ProviderDescriptor for NamecheapProvider {
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Namecheap,
+            name: "Namecheap".to_string(),
+            description: "Namecheap 域名 DNS 解析".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "apiUser".to_string(),
+                    label: "API User".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 API User".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "apiKey".to_string(),
+                    label: "API Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 API Key".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "username".to_string(),
+                    label: "Username".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 Namecheap 用户名".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "clientIp".to_string(),
+                    label: "Client IP".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入已加入白名单的公网 IP".to_string()),
+                    help_text: Some("需在 Namecheap 后台将该 IP 加入 API 白名单".to_string()),
+                    group: None,
+                },
+            ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Caa,
+                DnsRecordType::Alias,
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: MAX_PAGE_SIZE,
+                max_page_size_records: 100,
+                min_ttl: 60,
+                max_ttl: 60_000,
+                default_ttl: AUTOMATIC_TTL,
+            },
+            pagination: PaginationMode::Page,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for NamecheapProvider {
+    fn id(&self) -> &'static str {
+        "namecheap"
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        let params = [("PageSize".to_string(), "10".to_string())];
+        match self
+            .call::<DomainListResponse>(
+                "namecheap.domains.getList",
+                &params,
+                ErrorContext::default(),
+            )
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        // Namecheap 的 PageSize 取值范围为 10 ~ 100
+        let page_size = params.page_size.clamp(10, MAX_PAGE_SIZE);
+        let mut query = vec![
+            ("Page".to_string(), params.page.to_string()),
+            ("PageSize".to_string(), page_size.to_string()),
+        ];
+        if let Some(filter) = params.name_filter() {
+            query.push(("SearchTerm".to_string(), filter.to_string()));
+        }
+        let response: DomainListResponse = self
+            .call("namecheap.domains.getList", &query, ErrorContext::default())
+            .await?;
+
+        let total_count = response.paging.map_or(0, |p| p.total_items);
+        let domains = response
+            .result
+            .domains
+            .into_iter()
+            .map(|d| ProviderDomain {
+                id: d.name.clone(),
+                name: d.name,
+                provider: ProviderType::Namecheap,
+                status: if d.is_expired {
+                    DomainStatus::Error
+                } else if !d.is_our_dns {
+                    // 使用第三方 DNS 的域名无法通过 API 管理记录
+                    DomainStatus::Paused
+                } else {
+                    DomainStatus::Active
+                },
+                record_count: None,
+                name_servers: Vec::new(),
+                created_at: None,
+                modified_at: None,
+            })
+            .collect();
+
+        Ok(PaginatedResponse::new(
+            domains,
+            params.page,
+            params.page_size,
+            total_count,
+        ))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        let ctx = ErrorContext {
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let params = [("DomainName".to_string(), domain_id.to_string())];
+        let response: DomainInfoResponse =
+            self.call("namecheap.domains.getInfo", &params, ctx).await?;
+
+        Ok(ProviderDomain {
+            id: response.result.domain_name.clone(),
+            name: response.result.domain_name,
+            provider: ProviderType::Namecheap,
+            status: match response.result.status.as_deref() {
+                Some(s) if s.eq_ignore_ascii_case("ok") => DomainStatus::Active,
+                Some(s) if s.eq_ignore_ascii_case("locked") => DomainStatus::Paused,
+                Some(s) if s.eq_ignore_ascii_case("expired") => DomainStatus::Error,
+                _ => DomainStatus::Unknown,
+            },
+            record_count: None,
+            name_servers: Vec::new(),
+            created_at: None,
+            modified_at: None,
+        })
+    }
+
+    /// getHosts 一次返回全部记录，本地搜索与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let hosts = self.get_hosts(domain_id).await?.hosts;
+
+        let records: Vec<DnsRecord> = hosts
+            .iter()
+            .filter_map(|h| Self::host_to_record(h, domain_id))
+            .collect();
+
+        Ok(query_records_locally(records, params))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let host = Self::build_host(
+            &req.record_type,
+            &req.name,
+            &req.value,
+            req.ttl,
+            req.priority,
+        )?;
+        let identity = req.record_identity();
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+
+        let new_host = host.clone();
+        self.modify_hosts(&req.domain_id, ctx, |hosts| {
+            let exists = hosts.iter().any(|h| {
+                Self::host_to_record(h, &req.domain_id)
+                    .is_some_and(|r| r.record_identity() == identity)
+            });
+            if exists {
+                return Err(ProviderError::RecordExists {
+                    provider: "namecheap".to_string(),
+                    record_name: req.name.clone(),
+                    raw_message: None,
+                });
+            }
+            hosts.push(new_host);
+            Ok(())
+        })
+        .await?;
+
+        Self::host_to_record(&host, &req.domain_id)
+            .ok_or_else(|| unsupported_record_type(&req.record_type, "namecheap"))
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let host = Self::build_host(
+            &req.record_type,
+            &req.name,
+            &req.value,
+            req.ttl,
+            req.priority,
+        )?;
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+
+        let new_host = host.clone();
+        self.modify_hosts(&req.domain_id, ctx, |hosts| {
+            let existing = hosts
+                .iter_mut()
+                .find(|h| Self::value_record_id(h) == record_id)
+                .ok_or_else(|| Self::record_not_found(record_id))?;
+            *existing = new_host;
+            Ok(())
+        })
+        .await?;
+
+        Self::host_to_record(&host, &req.domain_id)
+            .ok_or_else(|| unsupported_record_type(&req.record_type, "namecheap"))
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.modify_hosts(domain_id, ctx, |hosts| {
+            let before = hosts.len();
+            hosts.retain(|h| Self::value_record_id(h) != record_id);
+            if hosts.len() == before {
+                return Err(Self::record_not_found(record_id));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// 一次 setHosts 移除同名同类型的全部记录
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        let type_str = record_type_to_string(record_type);
+        let ctx = ErrorContext {
+            record_name: Some(name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.modify_hosts(domain_id, ctx, |hosts| {
+            hosts.retain(|h| {
+                !(h.record_type.eq_ignore_ascii_case(type_str) && same_record_name(&h.name, name))
+            });
+            Ok(())
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use super::super::types::ApiResponse;
+    use super::*;
+    use crate::providers::test_server::TestServer;
+
+    const GET_HOSTS_XML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<ApiResponse Status="OK" xmlns="http://api.namecheap.com/xml.response">
+  <Errors />
+  <RequestedCommand>namecheap.domains.dns.getHosts</RequestedCommand>
+  <CommandResponse Type="namecheap.domains.dns.getHosts">
+    <DomainDNSGetHostsResult Domain="example.com" EmailType="MX" IsUsingOurDNS="true">
+      <host HostId="12" Name="@" Type="A" Address="1.2.3.4" MXPref="10" TTL="1800" />
+      <host HostId="14" Name="@" Type="MX" Address="mail.example.com." MXPref="20" TTL="1800" />
+      <host HostId="15" Name="_dmarc" Type="TXT" Address="v=DMARC1; p=none" MXPref="10" TTL="300" />
+      <host HostId="16" Name="old" Type="URL301" Address="https://example.org" MXPref="10" TTL="1800" />
+    </DomainDNSGetHostsResult>
+  </CommandResponse>
+</ApiResponse>"#;
+
+    #[test]
+    fn test_parse_get_hosts() {
+        let response: ApiResponse<GetHostsResponse> =
+            quick_xml::de::from_str(GET_HOSTS_XML).unwrap();
+        assert_eq!(response.status, "OK");
+        let result = response.command_response.unwrap().result;
+        assert_eq!(result.email_type.as_deref(), Some("MX"));
+        assert_eq!(result.hosts.len(), 4);
+
+        let records: Vec<DnsRecord> = result
+            .hosts
+            .iter()
+            .filter_map(|h| NamecheapProvider::host_to_record(h, "example.com"))
+            .collect();
+        // URL 转发记录不属于标准 DNS 记录，跳过
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].priority, None);
+        assert_eq!(records[1].value, "mail.example.com");
+        assert_eq!(records[1].priority, Some(20));
+        assert_eq!(records[2].ttl, 300);
+    }
+
+    #[test]
+    fn test_parse_error_response() {
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<ApiResponse Status="ERROR" xmlns="http://api.namecheap.com/xml.response">
+  <Errors>
+    <Error Number="1011150">Invalid request IP: 10.0.0.1</Error>
+  </Errors>
+  <RequestedCommand />
+</ApiResponse>"#;
+        let response: ApiResponse<GetHostsResponse> = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(response.status, "ERROR");
+        assert!(response.command_response.is_none());
+        assert_eq!(response.errors.items[0].number.as_deref(), Some("1011150"));
+        assert_eq!(
+            response.errors.items[0].message,
+            "Invalid request IP: 10.0.0.1"
+        );
+    }
+
+    #[test]
+    fn test_set_hosts_params() {
+        let hosts = vec![
+            NamecheapProvider::build_host(&DnsRecordType::A, "", "1.2.3.4", 600, None).unwrap(),
+            NamecheapProvider::build_host(&DnsRecordType::Mx, "@", "mx.example.com", 1800, Some(5))
+                .unwrap(),
+        ];
+        let params = NamecheapProvider::set_hosts_params(&hosts, "MX");
+        let get = |key: &str| {
+            params
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+        assert_eq!(get("HostName1"), Some("@"));
+        assert_eq!(get("TTL1"), Some("600"));
+        assert_eq!(get("MXPref1"), None);
+        assert_eq!(get("RecordType2"), Some("MX"));
+        assert_eq!(get("MXPref2"), Some("5"));
+
+        assert_eq!(
+            NamecheapProvider::email_type(&hosts[..1], Some("MX")),
+            "NONE"
+        );
+        assert_eq!(
+            NamecheapProvider::email_type(&hosts[..1], Some("FWD")),
+            "FWD"
+        );
+        assert_eq!(NamecheapProvider::email_type(&hosts, Some("FWD")), "MX");
+        assert_eq!(
+            NamecheapProvider::split_domain("example.co.uk"),
+            ("example", "co.uk")
+        );
+    }
+
+    #[test]
+    fn test_alias_sent_natively() {
+        let host =
+            NamecheapProvider::build_host(&DnsRecordType::Alias, "@", "lb.example.net.", 300, None)
+                .unwrap();
+        assert_eq!(host.record_type, "ALIAS");
+
+        let record = NamecheapProvider::host_to_record(&host, "example.com").unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Alias);
+        assert_eq!(record.value, "lb.example.net");
+        assert_eq!(record.priority, None);
+    }
+
+    /// 模拟 Namecheap：getHosts 返回当前主机列表（与真实接口一样，非 MX 记录的 MXPref 为 10），
+    /// setHosts 整体替换主机列表
+    async fn local_provider() -> (NamecheapProvider, TestServer) {
+        let hosts: Arc<Mutex<Vec<[String; 5]>>> = Arc::default();
+        let server = TestServer::start(move |req| {
+            let form: HashMap<String, String> = req
+                .body
+                .split('&')
+                .filter_map(|pair| pair.split_once('='))
+                .map(|(k, v)| (k.to_string(), urlencoding::decode(v).unwrap().into_owned()))
+                .collect();
+            let mut hosts = hosts.lock().unwrap();
+            let result = match form["Command"].as_str() {
+                "namecheap.domains.dns.getHosts" => {
+                    let items: String = hosts
+                        .iter()
+                        .enumerate()
+                        .map(|(i, [name, record_type, address, mx_pref, ttl])| {
+                            format!(
+                                r#"<host HostId="{i}" Name="{name}" Type="{record_type}" Address="{address}" MXPref="{mx_pref}" TTL="{ttl}" />"#
+                            )
+                        })
+                        .collect();
+                    format!(
+                        r#"<DomainDNSGetHostsResult Domain="example.com" EmailType="NONE" IsUsingOurDNS="true">{items}</DomainDNSGetHostsResult>"#
+                    )
+                }
+                "namecheap.domains.dns.setHosts" => {
+                    *hosts = (1..)
+                        .map_while(|n| {
+                            let field = |key: &str| form.get(&format!("{key}{n}")).cloned();
+                            Some([
+                                field("HostName")?,
+                                field("RecordType")?,
+                                field("Address")?,
+                                field("MXPref").unwrap_or_else(|| "10".to_string()),
+                                field("TTL")?,
+                            ])
+                        })
+                        .collect();
+                    r#"<DomainDNSSetHostsResult Domain="example.com" IsSuccess="true" />"#
+                        .to_string()
+                }
+                other => panic!("unexpected command {other}"),
+            };
+            let body = format!(
+                r#"<?xml version="1.0" encoding="utf-8"?>
+<ApiResponse Status="OK" xmlns="http://api.namecheap.com/xml.response">
+  <Errors />
+  <RequestedCommand>{}</RequestedCommand>
+  <CommandResponse Type="{}">{result}</CommandResponse>
+</ApiResponse>"#,
+                form["Command"], form["Command"]
+            );
+            (200, body)
+        })
+        .await;
+
+        let mut provider = NamecheapProvider::builder(
+            "user".to_string(),
+            "key".to_string(),
+            "user".to_string(),
+            "127.0.0.1".to_string(),
+        )
+        .max_retries(0)
+        .build();
+        provider.api_url.clone_from(&server.url);
+        (provider, server)
+    }
+
+    fn create_request(
+        record_type: DnsRecordType,
+        name: &str,
+        value: &str,
+        priority: Option<u16>,
+    ) -> CreateDnsRecordRequest {
+        CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type,
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl: 600,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        }
+    }
+
+    fn update_request(req: &CreateDnsRecordRequest, value: &str) -> UpdateDnsRecordRequest {
+        UpdateDnsRecordRequest {
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value: value.to_string(),
+            ttl: req.ttl,
+            priority: req.priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            expected_version: None,
+            expected_value: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_id_round_trip() {
+        let (provider, _server) = local_provider().await;
+        let requests = [
+            create_request(DnsRecordType::A, "www", "192.0.2.1", None),
+            create_request(DnsRecordType::Txt, "_acme", "token", None),
+            // 未指定优先级的 MX 与列表返回的默认优先级一致
+            create_request(DnsRecordType::Mx, "@", "mail.example.com", None),
+        ];
+        let mut created = Vec::new();
+        for req in &requests {
+            created.push(provider.create_record(req).await.unwrap());
+        }
+
+        let listed = provider
+            .list_records("example.com", &RecordQueryParams::default())
+            .await
+            .unwrap()
+            .items;
+        let listed_ids: Vec<_> = listed.iter().map(|r| r.id.as_str()).collect();
+        let created_ids: Vec<_> = created.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(listed_ids, created_ids);
+
+        // 用创建时返回的 ID 更新，更新结果的 ID 同样能在列表中找到
+        for (req, record) in requests.iter().zip(&created) {
+            let value = if req.record_type == DnsRecordType::A {
+                "192.0.2.2"
+            } else {
+                "changed.example.com"
+            };
+            let updated = provider
+                .update_record(&record.id, &update_request(req, value))
+                .await
+                .unwrap();
+            let listed = provider
+                .list_records("example.com", &RecordQueryParams::default())
+                .await
+                .unwrap()
+                .items;
+            assert!(
+                listed
+                    .iter()
+                    .any(|r| r.id == updated.id && r.value == value)
+            );
+        }
+
+        let mx = &provider
+            .list_records("example.com", &RecordQueryParams::default())
+            .await
+            .unwrap()
+            .items[2];
+        provider.delete_record(&mx.id, "example.com").await.unwrap();
+    }
+}
@@ -0,0 +1,126 @@
+//! Namecheap API 类型定义（XML 响应）
+//!
+//! 参考: <https://www.namecheap.com/support/api/methods/>
+
+use serde::Deserialize;
+
+// ============ 通用响应结构 ============
+
+/// `<ApiResponse Status="OK|ERROR">` 外层结构
+#[derive(Debug, Deserialize)]
+pub struct ApiResponse<T> {
+    #[serde(rename = "@Status")]
+    pub status: String,
+    #[serde(rename = "Errors", default)]
+    pub errors: ApiErrors,
+    #[serde(rename = "CommandResponse")]
+    pub command_response: Option<T>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ApiErrors {
+    #[serde(rename = "Error", default)]
+    pub items: Vec<ApiError>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiError {
+    #[serde(rename = "@Number")]
+    pub number: Option<String>,
+    #[serde(rename = "$text", default)]
+    pub message: String,
+}
+
+// ============ namecheap.domains.getList ============
+
+#[derive(Debug, Deserialize)]
+pub struct DomainListResponse {
+    #[serde(rename = "DomainGetListResult")]
+    pub result: DomainGetListResult,
+    #[serde(rename = "Paging")]
+    pub paging: Option<Paging>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainGetListResult {
+    #[serde(rename = "Domain", default)]
+    pub domains: Vec<NamecheapDomain>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NamecheapDomain {
+    #[serde(rename = "@Name")]
+    pub name: String,
+    #[serde(rename = "@IsExpired", default)]
+    pub is_expired: bool,
+    /// 是否使用 Namecheap 自身的 DNS（否则无法通过 API 管理记录）
+    #[serde(rename = "@IsOurDNS", default)]
+    pub is_our_dns: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Paging {
+    #[serde(rename = "TotalItems")]
+    pub total_items: u32,
+}
+
+// ============ namecheap.domains.getInfo ============
+
+#[derive(Debug, Deserialize)]
+pub struct DomainInfoResponse {
+    #[serde(rename = "DomainGetInfoResult")]
+    pub result: DomainGetInfoResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainGetInfoResult {
+    /// Ok / Locked / Expired
+    #[serde(rename = "@Status")]
+    pub status: Option<String>,
+    #[serde(rename = "@DomainName")]
+    pub domain_name: String,
+}
+
+// ============ namecheap.domains.dns.getHosts / setHosts ============
+
+#[derive(Debug, Deserialize)]
+pub struct GetHostsResponse {
+    #[serde(rename = "DomainDNSGetHostsResult")]
+    pub result: DomainDnsGetHostsResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainDnsGetHostsResult {
+    /// 邮件设置类型（MX / MXE / FWD / OX / GMAIL / NONE）
+    #[serde(rename = "@EmailType")]
+    pub email_type: Option<String>,
+    #[serde(rename = "host", default)]
+    pub hosts: Vec<NamecheapHost>,
+}
+
+/// 单条主机记录；setHosts 会整体替换列表，HostId 在每次写入后都会变化
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct NamecheapHost {
+    #[serde(rename = "@Name")]
+    pub name: String,
+    #[serde(rename = "@Type")]
+    pub record_type: String,
+    #[serde(rename = "@Address")]
+    pub address: String,
+    #[serde(rename = "@MXPref")]
+    pub mx_pref: Option<u16>,
+    #[serde(rename = "@TTL")]
+    pub ttl: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SetHostsResponse {
+    #[serde(rename = "DomainDNSSetHostsResult")]
+    pub result: DomainDnsSetHostsResult,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DomainDnsSetHostsResult {
+    #[serde(rename = "@IsSuccess", default)]
+    pub is_success: bool,
+}
@@ -0,0 +1,89 @@
+//! Porkbun 错误映射
+//!
+//! Porkbun 只返回 `{"status":"ERROR","message":"..."}`，没有错误码，按消息内容分类
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::PorkbunProvider;
+
+/// Porkbun 错误消息映射实现
+impl ProviderErrorMapper for PorkbunProvider {
+    fn provider_name(&self) -> &'static str {
+        "porkbun"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        let message = raw.message.to_ascii_lowercase();
+
+        // ============ 认证错误 ============
+        if message.contains("invalid api key") || message.contains("invalid secret") {
+            return ProviderError::InvalidCredentials {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 域名未开启 API 访问 ============
+        if message.contains("not opted in to api access") {
+            return ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 域名不存在 ============
+        if message.contains("invalid domain") || message.contains("domain not found") {
+            return ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 记录不存在 ============
+        if message.contains("invalid record id") || message.contains("record not found") {
+            return ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: context.record_id.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 记录已存在 ============
+        if message.contains("already exists") || message.contains("duplicate") {
+            return ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 频率限制 ============
+        if message.contains("rate limit") || message.contains("too many") {
+            return ProviderError::QuotaExceeded {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            };
+        }
+
+        // ============ 参数无效 ============
+        if message.contains("ttl") {
+            return ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "ttl".to_string(),
+                detail: raw.message,
+            };
+        }
+        if message.contains("invalid type") {
+            return ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "type".to_string(),
+                detail: raw.message,
+            };
+        }
+
+        // ============ 其他错误 fallback ============
+        self.unknown_error(raw)
+    }
+}
@@ -0,0 +1,103 @@
+//! Porkbun HTTP 请求方法
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::HttpUtils;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::types::StatusResponse;
+use super::{PORKBUN_API_BASE, PorkbunProvider};
+
+impl PorkbunProvider {
+    /// 执行 POST 请求（Porkbun 所有接口均为 POST，认证字段放在 JSON body 中）
+    pub(crate) async fn post<T: for<'de> Deserialize<'de>, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+        ctx: ErrorContext,
+    ) -> Result<T> {
+        let mut payload =
+            serde_json::to_value(body).map_err(|e| ProviderError::SerializationError {
+                provider: self.provider_name().to_string(),
+                detail: e.to_string(),
+            })?;
+        if !payload.is_object() {
+            payload = serde_json::Value::Object(serde_json::Map::new());
+        }
+
+        log::debug!("Request Body: {payload}");
+
+        // 日志输出后再补充认证字段，避免密钥进入日志
+        if let Some(object) = payload.as_object_mut() {
+            object.insert("apikey".to_string(), self.api_key.clone().into());
+            object.insert(
+                "secretapikey".to_string(),
+                self.secret_api_key.clone().into(),
+            );
+        }
+
+        let url = format!("{PORKBUN_API_BASE}{path}");
+        let request = self
+            .client
+            .post(&url)
+            .header("Content-Type", "application/json")
+            .body(payload.to_string());
+
+        // 查询接口可安全重放，增删改只在请求未被处理时重试
+        let (status, response_text) = if is_read_only_path(path) {
+            HttpUtils::execute_idempotent_request_with_retry(
+                request,
+                self.provider_name(),
+                "POST",
+                &url,
+                &self.retry_policy,
+                &self.rate_limiter,
+            )
+            .await?
+        } else {
+            HttpUtils::execute_request_with_retry(
+                request,
+                self.provider_name(),
+                "POST",
+                &url,
+                &self.retry_policy,
+                &self.rate_limiter,
+            )
+            .await?
+        };
+
+        // 处理错误响应
+        self.handle_response_error(status, &response_text, ctx)?;
+
+        // 解析成功响应
+        HttpUtils::parse_json(&response_text, self.provider_name())
+    }
+
+    /// 统一处理 Porkbun 响应错误（错误可能以 200 或 4xx 返回）
+    fn handle_response_error(
+        &self,
+        status: u16,
+        response_text: &str,
+        ctx: ErrorContext,
+    ) -> Result<()> {
+        match serde_json::from_str::<StatusResponse>(response_text) {
+            Ok(response) if response.status.eq_ignore_ascii_case("SUCCESS") => Ok(()),
+            Ok(response) => {
+                Err(self.map_error(RawApiError::new(response.message.unwrap_or_default()), ctx))
+            }
+            Err(_) if (200..300).contains(&status) => Ok(()),
+            // 回退到通用错误
+            Err(_) => {
+                Err(self.unknown_error(RawApiError::new(format!("HTTP {status}: {response_text}"))))
+            }
+        }
+    }
+}
+
+/// 是否为只读接口（Porkbun 所有接口均为 POST，按路径区分）
+fn is_read_only_path(path: &str) -> bool {
+    ["/ping", "/domain/listAll", "/dns/retrieve"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+}
@@ -0,0 +1,103 @@
+//! Porkbun DNS Provider
+
+mod error;
+mod http;
+mod provider;
+pub(crate) mod types;
+
+use std::time::Duration;
+
+use reqwest::Client;
+
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::build_http_client;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
+
+pub(crate) const PORKBUN_API_BASE: &str = "https://api.porkbun.com/api/json/v3";
+
+/// Porkbun DNS Provider
+pub struct PorkbunProvider {
+    pub(crate) client: Client,
+    pub(crate) api_key: String,
+    pub(crate) secret_api_key: String,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
+}
+
+/// Porkbun Provider Builder
+pub struct PorkbunProviderBuilder {
+    api_key: String,
+    secret_api_key: String,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
+}
+
+impl PorkbunProviderBuilder {
+    fn new(api_key: String, secret_api_key: String) -> Self {
+        Self {
+            api_key,
+            secret_api_key,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Porkbun),
+            http_config: HttpClientConfig::default(),
+        }
+    }
+
+    /// 最大重试次数（不含首次请求）
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 自定义 HTTP 客户端超时配置
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
+        self
+    }
+
+    pub fn build(self) -> PorkbunProvider {
+        PorkbunProvider {
+            client: build_http_client(&self.http_config),
+            api_key: self.api_key,
+            secret_api_key: self.secret_api_key,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
+        }
+    }
+}
+
+impl PorkbunProvider {
+    pub fn new(api_key: String, secret_api_key: String) -> Self {
+        Self::builder(api_key, secret_api_key).build()
+    }
+
+    pub fn builder(api_key: String, secret_api_key: String) -> PorkbunProviderBuilder {
+        PorkbunProviderBuilder::new(api_key, secret_api_key)
+    }
+}
@@ -0,0 +1,476 @@
+//! Porkbun DnsProvider trait 实现
+
+use async_trait::async_trait;
+
+use crate::error::{ProviderError, Result};
+use crate::identity::is_apex_name;
+use crate::providers::common::{
+    SvcbValue, filter_domains_by_name, full_name_to_relative, paginate_locally, parse_record_type,
+    query_records_locally, record_type_to_string, split_combined_value,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::PorkbunProvider;
+use super::types::{
+    CreateRecordResponse, ListDomainsResponse, PorkbunDomain, PorkbunRecord, RecordBody,
+    RetrieveRecordsResponse, StatusResponse,
+};
+
+/// domain/listAll 单次返回的最大域名数
+const LIST_ALL_BATCH_SIZE: usize = 1000;
+/// Porkbun 允许的最小 TTL
+const MIN_TTL: u32 = 600;
+
+impl PorkbunProvider {
+    /// 将 Porkbun 域名状态转换为内部状态
+    fn convert_domain_status(status: Option<&str>) -> DomainStatus {
+        match status {
+            Some(s) if s.eq_ignore_ascii_case("ACTIVE") => DomainStatus::Active,
+            _ => DomainStatus::Unknown,
+        }
+    }
+
+    fn convert_domain(domain: PorkbunDomain) -> ProviderDomain {
+        ProviderDomain {
+            id: domain.domain.clone(),
+            name: domain.domain,
+            provider: ProviderType::Porkbun,
+            status: Self::convert_domain_status(domain.status.as_deref()),
+            record_count: None,
+            name_servers: Vec::new(),
+            created_at: None,
+            modified_at: None,
+        }
+    }
+
+    /// 将 Porkbun 记录转换为 `DnsRecord`；不支持的类型返回 None
+    pub(crate) fn convert_record(record: PorkbunRecord, domain: &str) -> Option<DnsRecord> {
+        let record_type = parse_record_type(&record.record_type, "porkbun").ok()?;
+        let priority = match record_type {
+            DnsRecordType::Mx | DnsRecordType::Srv => record.prio,
+            _ => None,
+        };
+        // HTTPS/SVCB 的优先级在 prio 字段中，拼回记录值
+        let content = match (&record_type, record.prio) {
+            (DnsRecordType::Https | DnsRecordType::Svcb, Some(prio))
+                if SvcbValue::parse(&record.content).is_none() =>
+            {
+                format!("{prio} {}", record.content)
+            }
+            _ => record.content,
+        };
+        let (value, priority) = split_combined_value(&record_type, &content, priority);
+
+        Some(DnsRecord {
+            id: record.id,
+            domain_id: domain.to_string(),
+            record_type,
+            name: full_name_to_relative(&record.name, domain),
+            value,
+            ttl: record.ttl.unwrap_or(MIN_TTL),
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        })
+    }
+
+    /// 构造创建/编辑记录的请求体
+    pub(crate) fn record_body(
+        record_type: &DnsRecordType,
+        name: &str,
+        value: &str,
+        ttl: u32,
+        priority: Option<u16>,
+    ) -> RecordBody {
+        // 与 MX/SRV 一样，HTTPS/SVCB 的优先级单独放在 prio 字段，content 为 `<target> [params]`
+        let (content, priority) = match record_type {
+            DnsRecordType::Https | DnsRecordType::Svcb => match SvcbValue::parse(value) {
+                Some(svcb) => {
+                    let content = format!("{} {}", svcb.target, svcb.params);
+                    (content.trim_end().to_string(), Some(svcb.priority))
+                }
+                None => (value.to_string(), None),
+            },
+            _ => split_combined_value(record_type, value, priority),
+        };
+        let prio = match record_type {
+            DnsRecordType::Mx | DnsRecordType::Srv | DnsRecordType::Https | DnsRecordType::Svcb => {
+                priority.map(|p| p.to_string())
+            }
+            _ => None,
+        };
+
+        RecordBody {
+            name: if is_apex_name(name) {
+                String::new()
+            } else {
+                name.to_string()
+            },
+            record_type: record_type_to_string(record_type).to_string(),
+            content,
+            ttl: ttl.to_string(),
+            prio,
+        }
+    }
+
+    /// 获取账户下的全部域名（domain/listAll 按 start 偏移分批返回）
+    async fn list_all_domains(&self) -> Result<Vec<PorkbunDomain>> {
+        let mut domains = Vec::new();
+        loop {
+            let body = serde_json::json!({ "start": domains.len().to_string() });
+            let response: ListDomainsResponse = self
+                .post("/domain/listAll", &body, ErrorContext::default())
+                .await?;
+            let count = response.domains.len();
+            domains.extend(response.domains);
+            if count < LIST_ALL_BATCH_SIZE {
+                break;
+            }
+        }
+        Ok(domains)
+    }
+
+    fn domain_ctx(domain: &str) -> ErrorContext {
+        ErrorContext {
+            domain: Some(domain.to_string()),
+            ..Default::default()
+        }
+    }
+}
+
+impl ProviderDescriptor for PorkbunProvider {
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Porkbun,
+            name: "Porkbun".to_string(),
+            description: "Porkbun 域名 DNS 解析".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "apiKey".to_string(),
+                    label: "API Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 API Key (pk1_...)".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "secretApiKey".to_string(),
+                    label: "Secret API Key".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Secret API Key (sk1_...)".to_string()),
+                    help_text: Some("域名需在 Porkbun 后台开启 API Access".to_string()),
+                    group: None,
+                },
+            ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Alias,
+                DnsRecordType::Https,
+                DnsRecordType::Svcb,
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: 1000,
+                max_page_size_records: 1000,
+                min_ttl: MIN_TTL,
+                max_ttl: 86_400,
+                default_ttl: MIN_TTL,
+            },
+            pagination: PaginationMode::Page,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for PorkbunProvider {
+    fn id(&self) -> &'static str {
+        "porkbun"
+    }
+
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self
+            .post::<StatusResponse, _>("/ping", &serde_json::json!({}), ErrorContext::default())
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// domain/listAll 不支持页码分页，拉取全部域名后本地分页
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let domains = self
+            .list_all_domains()
+            .await?
+            .into_iter()
+            .map(Self::convert_domain)
+            .collect();
+        let domains = filter_domains_by_name(domains, params);
+        Ok(paginate_locally(domains, params.page, params.page_size))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.list_all_domains()
+            .await?
+            .into_iter()
+            .find(|d| d.domain.eq_ignore_ascii_case(domain_id))
+            .map(Self::convert_domain)
+            .ok_or_else(|| ProviderError::DomainNotFound {
+                provider: "porkbun".to_string(),
+                domain: domain_id.to_string(),
+                raw_message: None,
+            })
+    }
+
+    /// dns/retrieve 一次返回全部记录，本地搜索与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        let path = format!("/dns/retrieve/{domain_id}");
+        let response: RetrieveRecordsResponse = self
+            .post(&path, &serde_json::json!({}), Self::domain_ctx(domain_id))
+            .await?;
+
+        let records = response
+            .records
+            .into_iter()
+            .filter_map(|r| Self::convert_record(r, domain_id))
+            .collect();
+
+        Ok(query_records_locally(records, params))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        let body = Self::record_body(
+            &req.record_type,
+            &req.name,
+            &req.value,
+            req.ttl,
+            req.priority,
+        );
+        let path = format!("/dns/create/{}", req.domain_id);
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        let response: CreateRecordResponse = self.post(&path, &body, ctx).await?;
+
+        let id = match response.id {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        };
+        let (value, priority) = split_combined_value(&req.record_type, &req.value, req.priority);
+        let now = chrono::Utc::now();
+        Ok(DnsRecord {
+            id,
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value,
+            ttl: req.ttl,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: Some(now),
+            updated_at: Some(now),
+        })
+    }
+
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        let body = Self::record_body(
+            &req.record_type,
+            &req.name,
+            &req.value,
+            req.ttl,
+            req.priority,
+        );
+        let path = format!("/dns/edit/{}/{record_id}", req.domain_id);
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let _response: StatusResponse = self.post(&path, &body, ctx).await?;
+
+        let (value, priority) = split_combined_value(&req.record_type, &req.value, req.priority);
+        Ok(DnsRecord {
+            id: record_id.to_string(),
+            domain_id: req.domain_id.clone(),
+            record_type: req.record_type.clone(),
+            name: req.name.clone(),
+            value,
+            ttl: req.ttl,
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: Some(chrono::Utc::now()),
+        })
+    }
+
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        let path = format!("/dns/delete/{domain_id}/{record_id}");
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let _response: StatusResponse = self.post(&path, &serde_json::json!({}), ctx).await?;
+        Ok(())
+    }
+
+    /// 使用 dns/deleteByNameType 一次删除同名同类型的全部记录
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        let subdomain = if is_apex_name(name) { "" } else { name };
+        let path = format!(
+            "/dns/deleteByNameType/{domain_id}/{}/{}",
+            record_type_to_string(record_type),
+            urlencoding::encode(subdomain)
+        );
+        let ctx = ErrorContext {
+            record_name: Some(name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let _response: StatusResponse = self.post(&path, &serde_json::json!({}), ctx).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_records_from_fixture() {
+        let json = r#"{"status":"SUCCESS","records":[
+            {"id":"1001","name":"example.com","type":"MX","content":"mail.example.com","ttl":"600","prio":"10","notes":""},
+            {"id":"1002","name":"_sip._tcp.example.com","type":"SRV","content":"5 5060 sip.example.com","ttl":"600","prio":"1","notes":null},
+            {"id":"1003","name":"www.example.com","type":"A","content":"1.2.3.4","ttl":"3600","prio":"0","notes":""}
+        ]}"#;
+        let response: RetrieveRecordsResponse = serde_json::from_str(json).unwrap();
+        let records: Vec<DnsRecord> = response
+            .records
+            .into_iter()
+            .filter_map(|r| PorkbunProvider::convert_record(r, "example.com"))
+            .collect();
+
+        assert_eq!(records[0].name, "@");
+        assert_eq!(records[0].priority, Some(10));
+        assert_eq!(records[1].name, "_sip._tcp");
+        assert_eq!(records[1].value, "5 5060 sip.example.com");
+        assert_eq!(records[1].priority, Some(1));
+        // A 记录的 prio 字段无意义，不保留
+        assert_eq!(records[2].priority, None);
+        assert_eq!(records[2].ttl, 3600);
+    }
+
+    #[test]
+    fn test_record_body() {
+        let body = PorkbunProvider::record_body(&DnsRecordType::A, "@", "1.2.3.4", 600, Some(5));
+        assert_eq!(body.name, "");
+        assert_eq!(body.prio, None);
+
+        let body = PorkbunProvider::record_body(
+            &DnsRecordType::Srv,
+            "_sip._tcp",
+            "1 5 5060 sip.example.com.",
+            600,
+            None,
+        );
+        assert_eq!(body.content, "5 5060 sip.example.com");
+        assert_eq!(body.prio.as_deref(), Some("1"));
+    }
+
+    #[test]
+    fn test_alias_sent_natively() {
+        let body =
+            PorkbunProvider::record_body(&DnsRecordType::Alias, "@", "lb.example.net", 600, None);
+        assert_eq!(body.record_type, "ALIAS");
+        assert_eq!(body.name, "");
+
+        let record: PorkbunRecord = serde_json::from_value(serde_json::json!({
+            "id": "1", "name": "example.com", "type": "ALIAS",
+            "content": "lb.example.net", "ttl": "600"
+        }))
+        .unwrap();
+        let record = PorkbunProvider::convert_record(record, "example.com").unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Alias);
+        assert_eq!(record.name, "@");
+    }
+
+    #[test]
+    fn test_https_priority_in_prio_field() {
+        let body = PorkbunProvider::record_body(
+            &DnsRecordType::Https,
+            "@",
+            r#"1 . alpn="h3,h2" ech="AB CD""#,
+            600,
+            None,
+        );
+        assert_eq!(body.content, r#". alpn="h3,h2" ech="AB CD""#);
+        assert_eq!(body.prio.as_deref(), Some("1"));
+
+        let body = PorkbunProvider::record_body(
+            &DnsRecordType::Svcb,
+            "_svc",
+            "0 svc.example.com.",
+            600,
+            None,
+        );
+        assert_eq!(body.content, "svc.example.com");
+        assert_eq!(body.prio.as_deref(), Some("0"));
+
+        let record: PorkbunRecord = serde_json::from_value(serde_json::json!({
+            "id": "1", "name": "example.com", "type": "HTTPS",
+            "content": ". alpn=\"h3,h2\" ech=\"AB CD\"", "ttl": "600", "prio": "1"
+        }))
+        .unwrap();
+        let record = PorkbunProvider::convert_record(record, "example.com").unwrap();
+        assert_eq!(record.record_type, DnsRecordType::Https);
+        assert_eq!(record.value, r#"1 . alpn="h3,h2" ech="AB CD""#);
+        assert_eq!(record.priority, None);
+    }
+}
@@ -0,0 +1,85 @@
+//! Porkbun API 类型定义
+//!
+//! 参考: <https://porkbun.com/api/json/v3/documentation>
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+// ============ 通用响应结构 ============
+
+/// 所有响应都包含 `status`（SUCCESS / ERROR），失败时附带 `message`
+#[derive(Debug, Deserialize)]
+pub struct StatusResponse {
+    pub status: String,
+    pub message: Option<String>,
+}
+
+// ============ 域名 ============
+
+#[derive(Debug, Deserialize)]
+pub struct ListDomainsResponse {
+    #[serde(default)]
+    pub domains: Vec<PorkbunDomain>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PorkbunDomain {
+    pub domain: String,
+    pub status: Option<String>,
+}
+
+// ============ DNS 记录 ============
+
+#[derive(Debug, Deserialize)]
+pub struct RetrieveRecordsResponse {
+    #[serde(default)]
+    pub records: Vec<PorkbunRecord>,
+}
+
+/// Porkbun 记录：ttl / prio 以字符串返回
+#[derive(Debug, Deserialize)]
+pub struct PorkbunRecord {
+    pub id: String,
+    /// 完整域名
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub content: String,
+    #[serde(default, deserialize_with = "number_from_string")]
+    pub ttl: Option<u32>,
+    #[serde(default, deserialize_with = "number_from_string")]
+    pub prio: Option<u16>,
+}
+
+/// 创建/编辑记录的请求字段（与认证字段合并发送）
+#[derive(Debug, Serialize)]
+pub struct RecordBody {
+    /// 子域名，根记录为空字符串
+    pub name: String,
+    #[serde(rename = "type")]
+    pub record_type: String,
+    pub content: String,
+    pub ttl: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prio: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateRecordResponse {
+    /// 新记录 ID（数字）
+    pub id: serde_json::Value,
+}
+
+/// 兼容字符串与数字两种格式的数值字段
+fn number_from_string<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr,
+{
+    Ok(
+        match Option::<serde_json::Value>::deserialize(deserializer)? {
+            Some(serde_json::Value::String(s)) => s.parse().ok(),
+            Some(serde_json::Value::Number(n)) => n.to_string().parse().ok(),
+            _ => None,
+        },
+    )
+}
@@ -0,0 +1,82 @@
+//! RFC 2136 错误映射
+//!
+//! 错误码为 DNS 响应码（RCODE），TSIG 相关错误由服务器以 NOTAUTH 返回
+
+use hickory_proto::op::ResponseCode;
+
+use crate::error::ProviderError;
+use crate::traits::{ErrorContext, ProviderErrorMapper, RawApiError};
+
+use super::Rfc2136Provider;
+
+impl Rfc2136Provider {
+    /// 将错误响应码映射为统一错误
+    pub(crate) fn response_error(
+        &self,
+        rcode: ResponseCode,
+        context: ErrorContext,
+    ) -> ProviderError {
+        let code = u16::from(rcode).to_string();
+        self.map_error(RawApiError::with_code(code, rcode.to_str()), context)
+    }
+}
+
+/// RFC 2136 响应码映射实现
+impl ProviderErrorMapper for Rfc2136Provider {
+    fn provider_name(&self) -> &'static str {
+        "rfc2136"
+    }
+
+    fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError {
+        let rcode: Option<ResponseCode> = raw
+            .code
+            .as_deref()
+            .and_then(|c| c.parse::<u16>().ok())
+            .map(Into::into);
+
+        match rcode {
+            // ============ 认证错误（TSIG 密钥/签名/时间） ============
+            Some(ResponseCode::NotAuth | ResponseCode::BADKEY | ResponseCode::BADTIME) => {
+                ProviderError::InvalidCredentials {
+                    provider: self.provider_name().to_string(),
+                    raw_message: Some(raw.message),
+                }
+            }
+
+            // ============ 服务器拒绝（未授权该密钥更新或区域传送） ============
+            Some(ResponseCode::Refused) => ProviderError::PermissionDenied {
+                provider: self.provider_name().to_string(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 区域不存在 ============
+            Some(ResponseCode::NotZone | ResponseCode::NXDomain) => ProviderError::DomainNotFound {
+                provider: self.provider_name().to_string(),
+                domain: context.domain.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 前置条件失败 ============
+            Some(ResponseCode::YXRRSet | ResponseCode::YXDomain) => ProviderError::RecordExists {
+                provider: self.provider_name().to_string(),
+                record_name: context.record_name.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+            Some(ResponseCode::NXRRSet) => ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: context.record_id.unwrap_or_default(),
+                raw_message: Some(raw.message),
+            },
+
+            // ============ 请求格式错误 ============
+            Some(ResponseCode::FormErr) => ProviderError::InvalidParameter {
+                provider: self.provider_name().to_string(),
+                param: "message".to_string(),
+                detail: raw.message,
+            },
+
+            // ============ 其他错误 fallback ============
+            _ => self.unknown_error(raw),
+        }
+    }
+}
@@ -0,0 +1,183 @@
+//! RFC 2136 动态更新 Provider（自建 BIND/Knot/PowerDNS 等权威服务器）
+//!
+//! 通过 TCP 发送 TSIG 签名的 DNS UPDATE 消息修改记录，记录列表使用 AXFR 区域传送获取
+
+mod error;
+mod provider;
+mod transport;
+
+use std::time::Duration;
+
+use base64::Engine;
+use hickory_proto::dnssec::rdata::tsig::TsigAlgorithm;
+use hickory_proto::dnssec::tsig::TSigner;
+use hickory_proto::rr::Name;
+
+use crate::error::{ProviderError, Result};
+use crate::http_client::{HttpClientConfig, RetryPolicy};
+use crate::providers::common::normalize_domain_name;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::types::ProviderType;
+
+/// TSIG 允许的时钟偏差（秒）
+const TSIG_FUDGE: u16 = 300;
+
+/// 解析 TSIG 算法名称（仅支持 HMAC-SHA2 系列）
+pub(crate) fn parse_tsig_algorithm(algorithm: &str) -> std::result::Result<TsigAlgorithm, String> {
+    match normalize_domain_name(algorithm.trim())
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "hmac-sha256" => Ok(TsigAlgorithm::HmacSha256),
+        "hmac-sha384" => Ok(TsigAlgorithm::HmacSha384),
+        "hmac-sha512" => Ok(TsigAlgorithm::HmacSha512),
+        other => Err(format!(
+            "不支持的 TSIG 算法 {other}，可选 hmac-sha256、hmac-sha384、hmac-sha512"
+        )),
+    }
+}
+
+/// 解码 Base64 格式的 TSIG 密钥
+pub(crate) fn decode_tsig_secret(secret: &str) -> std::result::Result<Vec<u8>, String> {
+    base64::engine::general_purpose::STANDARD
+        .decode(secret.trim())
+        .map_err(|e| format!("TSIG 密钥不是有效的 Base64: {e}"))
+}
+
+/// 解析绝对域名（补全末尾的点）
+pub(crate) fn parse_fqdn(name: &str) -> std::result::Result<Name, String> {
+    Name::from_ascii(format!("{}.", normalize_domain_name(name.trim())))
+        .map_err(|e| format!("无效的域名 {name}: {e}"))
+}
+
+/// RFC 2136 动态更新 Provider
+pub struct Rfc2136Provider {
+    /// 权威服务器地址（主机名或 IP）
+    pub(crate) server: String,
+    pub(crate) port: u16,
+    /// 区域名（不带末尾的点）
+    pub(crate) zone: String,
+    pub(crate) zone_origin: Name,
+    pub(crate) signer: TSigner,
+    pub(crate) timeout: Duration,
+    pub(crate) connect_timeout: Duration,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) rate_limiter: RateLimiter,
+}
+
+/// RFC 2136 Provider Builder
+pub struct Rfc2136ProviderBuilder {
+    server: String,
+    port: u16,
+    zone: String,
+    zone_origin: Name,
+    signer: TSigner,
+    retry_policy: RetryPolicy,
+    rate_limit: RateLimit,
+    http_config: HttpClientConfig,
+}
+
+impl Rfc2136ProviderBuilder {
+    /// 最大重试次数（不含首次请求）
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.retry_policy.max_attempts = retries.saturating_add(1);
+        self
+    }
+
+    /// 自定义重试策略（仅对连接/传输失败重试，UPDATE 操作本身是幂等的）
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// 自定义速率限制（默认使用该服务商的推荐配额）
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = limit;
+        self
+    }
+
+    /// 请求总超时
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.timeout = timeout;
+        self
+    }
+
+    /// 连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.http_config.connect_timeout = timeout;
+        self
+    }
+
+    /// 复用 HTTP 客户端的超时配置（代理配置对 DNS 协议无效）
+    pub fn http_config(mut self, config: HttpClientConfig) -> Self {
+        self.http_config = config;
+        self
+    }
+
+    pub fn build(self) -> Rfc2136Provider {
+        Rfc2136Provider {
+            server: self.server,
+            port: self.port,
+            zone: self.zone,
+            zone_origin: self.zone_origin,
+            signer: self.signer,
+            timeout: self.http_config.timeout,
+            connect_timeout: self.http_config.connect_timeout,
+            retry_policy: self.retry_policy,
+            rate_limiter: RateLimiter::new(self.rate_limit),
+        }
+    }
+}
+
+impl Rfc2136Provider {
+    /// 创建实例，区域名、TSIG 算法或密钥无效时返回 `InvalidCredentials`
+    pub fn new(
+        server: String,
+        port: u16,
+        zone: &str,
+        tsig_key_name: &str,
+        tsig_algorithm: &str,
+        tsig_secret: &str,
+    ) -> Result<Self> {
+        Ok(Self::builder(
+            server,
+            port,
+            zone,
+            tsig_key_name,
+            tsig_algorithm,
+            tsig_secret,
+        )?
+        .build())
+    }
+
+    pub fn builder(
+        server: String,
+        port: u16,
+        zone: &str,
+        tsig_key_name: &str,
+        tsig_algorithm: &str,
+        tsig_secret: &str,
+    ) -> Result<Rfc2136ProviderBuilder> {
+        let invalid = |reason: String| ProviderError::InvalidCredentials {
+            provider: "rfc2136".to_string(),
+            raw_message: Some(reason),
+        };
+        let zone_origin = parse_fqdn(zone).map_err(invalid)?;
+        let key_name = parse_fqdn(tsig_key_name).map_err(invalid)?;
+        let algorithm = parse_tsig_algorithm(tsig_algorithm).map_err(invalid)?;
+        let key = decode_tsig_secret(tsig_secret).map_err(invalid)?;
+        let signer = TSigner::new(key, algorithm, key_name, TSIG_FUDGE)
+            .map_err(|e| invalid(e.to_string()))?;
+
+        Ok(Rfc2136ProviderBuilder {
+            server: server.trim().to_string(),
+            port,
+            zone: normalize_domain_name(zone.trim()).to_ascii_lowercase(),
+            zone_origin,
+            signer,
+            retry_policy: RetryPolicy::default(),
+            rate_limit: RateLimit::default_for(&ProviderType::Rfc2136),
+            http_config: HttpClientConfig::default(),
+        })
+    }
+}
@@ -0,0 +1,938 @@
+//! RFC 2136 DnsProvider trait 实现
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use async_trait::async_trait;
+use hickory_proto::op::{Message, MessageType, OpCode, Query, UpdateMessage, update_message};
+use hickory_proto::rr::rdata::{A, AAAA, CNAME, MX, NS, PTR, SRV, TXT};
+use hickory_proto::rr::{DNSClass, Name, RData, Record, RecordSet, RecordType};
+use hickory_proto::serialize::binary::{BinDecoder, BinEncodable, Restrict};
+
+use crate::error::{ProviderError, Result};
+use crate::providers::common::{
+    CaaValue, SrvValue, filter_domains_by_name, full_name_to_relative, normalize_domain_name,
+    paginate_locally, query_records_locally, record_type_to_string, relative_to_full_name,
+    split_combined_value, split_txt_chunks, unsupported_record_type,
+};
+use crate::traits::{DnsProvider, ErrorContext, ProviderDescriptor, ProviderErrorMapper};
+use crate::types::{
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, DomainStatus, FieldType, PaginatedResponse,
+    PaginationMode, PaginationParams, ProviderCredentialField, ProviderDomain, ProviderFeatures,
+    ProviderLimits, ProviderMetadata, ProviderType, RecordQueryParams, UpdateDnsRecordRequest,
+};
+
+use super::{Rfc2136Provider, parse_fqdn};
+
+/// 记录 ID 各部分的分隔符：`{fqdn}/{TYPE}/{rdata}`
+const RECORD_ID_SEPARATOR: char = '/';
+/// 区域传送一次返回全部记录，本地分页的最大条目数
+const MAX_PAGE_SIZE: u32 = 1000;
+
+impl Rfc2136Provider {
+    /// 生成记录 ID：`{fqdn}/{TYPE}/{rdata 线格式的十六进制}`
+    ///
+    /// 动态更新按记录内容删除，ID 中保存完整的 rdata，更新/删除时无需依赖区域传送
+    pub(crate) fn record_id(record: &Record) -> Option<String> {
+        let rdata = record.data().to_bytes().ok()?;
+        Some(format!(
+            "{}{RECORD_ID_SEPARATOR}{}{RECORD_ID_SEPARATOR}{}",
+            normalize_domain_name(&record.name().to_lowercase().to_ascii()),
+            record.record_type(),
+            hex::encode(rdata)
+        ))
+    }
+
+    /// 从记录 ID 还原记录（名称 + 类型 + rdata）；格式无效或不在本区域内返回 None
+    pub(crate) fn parse_record_id(&self, record_id: &str) -> Option<Record> {
+        let mut parts = record_id.splitn(3, RECORD_ID_SEPARATOR);
+        let name = parse_fqdn(parts.next()?).ok()?;
+        let record_type: RecordType = parts.next()?.parse().ok()?;
+        let bytes = hex::decode(parts.next()?).ok()?;
+        if !self.zone_origin.zone_of(&name) {
+            return None;
+        }
+
+        let length = Restrict::new(u16::try_from(bytes.len()).ok()?);
+        let rdata = RData::read(&mut BinDecoder::new(&bytes), record_type, length).ok()?;
+        Some(Record::from_rdata(name, 0, rdata))
+    }
+
+    /// 将记录值转换为 rdata
+    pub(crate) fn build_rdata(
+        record_type: &DnsRecordType,
+        value: &str,
+        priority: Option<u16>,
+    ) -> Result<RData> {
+        let invalid = |detail: String| ProviderError::InvalidRecord {
+            provider: "rfc2136".to_string(),
+            record_type: record_type_to_string(record_type).to_string(),
+            detail,
+        };
+        let host = |value: &str| parse_fqdn(value).map_err(invalid);
+        let (value, priority) = split_combined_value(record_type, value, priority);
+
+        let rdata = match record_type {
+            DnsRecordType::A => value
+                .parse::<Ipv4Addr>()
+                .map(|ip| RData::A(A(ip)))
+                .map_err(|e| invalid(format!("{value}: {e}")))?,
+            DnsRecordType::Aaaa => value
+                .parse::<Ipv6Addr>()
+                .map(|ip| RData::AAAA(AAAA(ip)))
+                .map_err(|e| invalid(format!("{value}: {e}")))?,
+            DnsRecordType::Cname => RData::CNAME(CNAME(host(&value)?)),
+            DnsRecordType::Ns => RData::NS(NS(host(&value)?)),
+            DnsRecordType::Ptr => RData::PTR(PTR(host(&value)?)),
+            DnsRecordType::Mx => RData::MX(MX::new(priority.unwrap_or(10), host(&value)?)),
+            DnsRecordType::Srv => {
+                let srv = SrvValue::parse_or_invalid(&value, priority, "rfc2136")?;
+                RData::SRV(SRV::new(
+                    srv.priority,
+                    srv.weight,
+                    srv.port,
+                    host(&srv.target)?,
+                ))
+            }
+            DnsRecordType::Txt => RData::TXT(TXT::new(
+                split_txt_chunks(&value)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect(),
+            )),
+            DnsRecordType::Caa => {
+                // 按线格式构造（flags + tag 长度 + tag + value），保留任意属性值
+                let caa = CaaValue::parse_or_invalid(&value, "rfc2136")?;
+                let tag_len = u8::try_from(caa.tag.len())
+                    .map_err(|_| invalid(format!("CAA 标签过长: {}", caa.tag)))?;
+                let mut bytes = vec![caa.flags, tag_len];
+                bytes.extend_from_slice(caa.tag.as_bytes());
+                bytes.extend_from_slice(caa.value.as_bytes());
+                let length = u16::try_from(bytes.len())
+                    .map_err(|_| invalid("CAA 记录值过长".to_string()))?;
+                RData::read(
+                    &mut BinDecoder::new(&bytes),
+                    RecordType::CAA,
+                    Restrict::new(length),
+                )
+                .map_err(|e| invalid(format!("{value}: {e}")))?
+            }
+            _ => return Err(unsupported_record_type(record_type, "rfc2136")),
+        };
+        Ok(rdata)
+    }
+
+    /// 将 rdata 转换为 (类型, 值, 优先级)；不支持的类型返回 None
+    fn parse_rdata(rdata: &RData) -> Option<(DnsRecordType, String, Option<u16>)> {
+        let host = |name: &Name| normalize_domain_name(&name.to_lowercase().to_ascii());
+        let parsed = match rdata {
+            RData::A(a) => (DnsRecordType::A, a.0.to_string(), None),
+            RData::AAAA(aaaa) => (DnsRecordType::Aaaa, aaaa.0.to_string(), None),
+            RData::CNAME(cname) => (DnsRecordType::Cname, host(&cname.0), None),
+            RData::NS(ns) => (DnsRecordType::Ns, host(&ns.0), None),
+            RData::PTR(ptr) => (DnsRecordType::Ptr, host(&ptr.0), None),
+            RData::MX(mx) => (
+                DnsRecordType::Mx,
+                host(mx.exchange()),
+                Some(mx.preference()),
+            ),
+            RData::SRV(srv) => {
+                let value = SrvValue {
+                    priority: srv.priority(),
+                    weight: srv.weight(),
+                    port: srv.port(),
+                    target: host(srv.target()),
+                };
+                (
+                    DnsRecordType::Srv,
+                    value.record_value(),
+                    Some(value.priority),
+                )
+            }
+            RData::TXT(txt) => {
+                let value = txt
+                    .txt_data()
+                    .iter()
+                    .map(|chunk| String::from_utf8_lossy(chunk))
+                    .collect();
+                (DnsRecordType::Txt, value, None)
+            }
+            RData::CAA(caa) => {
+                let value = caa.to_string();
+                let value = CaaValue::parse(&value).map_or(value, |c| c.to_canonical());
+                (DnsRecordType::Caa, value, None)
+            }
+            _ => return None,
+        };
+        Some(parsed)
+    }
+
+    /// 将区域传送返回的记录转换为 `DnsRecord`；SOA 等不支持的类型返回 None
+    pub(crate) fn convert_record(&self, record: &Record) -> Option<DnsRecord> {
+        let (record_type, value, priority) = Self::parse_rdata(record.data())?;
+        Some(DnsRecord {
+            id: Self::record_id(record)?,
+            domain_id: self.zone.clone(),
+            record_type,
+            name: full_name_to_relative(&record.name().to_lowercase().to_ascii(), &self.zone),
+            value,
+            ttl: record.ttl(),
+            priority,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+            created_at: None,
+            updated_at: None,
+        })
+    }
+
+    /// 将相对名称转换为本区域内的绝对域名
+    fn record_name(&self, name: &str) -> Result<Name> {
+        let fqdn = relative_to_full_name(name, &self.zone);
+        parse_fqdn(&fqdn).map_err(|detail| ProviderError::InvalidParameter {
+            provider: self.provider_name().to_string(),
+            param: "name".to_string(),
+            detail,
+        })
+    }
+
+    /// 构造只包含单个记录值的 RRset
+    fn record_set(record: Record) -> RecordSet {
+        let mut rrset =
+            RecordSet::with_ttl(record.name().clone(), record.record_type(), record.ttl());
+        rrset.add_rdata(record.data().clone());
+        rrset
+    }
+
+    /// 区域不是本账户管理的区域时返回 `DomainNotFound`
+    fn ensure_zone(&self, domain_id: &str) -> Result<()> {
+        if normalize_domain_name(domain_id).eq_ignore_ascii_case(&self.zone) {
+            return Ok(());
+        }
+        Err(ProviderError::DomainNotFound {
+            provider: self.provider_name().to_string(),
+            domain: domain_id.to_string(),
+            raw_message: None,
+        })
+    }
+
+    fn zone_domain(&self) -> ProviderDomain {
+        ProviderDomain {
+            id: self.zone.clone(),
+            name: self.zone.clone(),
+            provider: ProviderType::Rfc2136,
+            status: DomainStatus::Active,
+            record_count: None,
+            name_servers: vec![self.server.clone()],
+            created_at: None,
+            modified_at: None,
+        }
+    }
+
+    /// 构造查询区域 SOA 的请求（用于验证凭证）
+    fn soa_query(&self) -> Message {
+        let mut query = Query::query(self.zone_origin.clone(), RecordType::SOA);
+        query.set_query_class(DNSClass::IN);
+        let mut message = Message::new();
+        message
+            .set_id(rand_id())
+            .set_message_type(MessageType::Query)
+            .set_op_code(OpCode::Query)
+            .set_recursion_desired(false);
+        message.add_query(query);
+        message
+    }
+
+    /// 添加记录值；不要求 RRset 已存在，同一名称下可追加多个值
+    pub(crate) fn add_message(&self, record: Record) -> Message {
+        update_message::append(
+            Self::record_set(record),
+            self.zone_origin.clone(),
+            false,
+            false,
+        )
+    }
+
+    /// 按 rdata 删除单个记录值
+    pub(crate) fn delete_message(&self, record: Record) -> Message {
+        update_message::delete_by_rdata(Self::record_set(record), self.zone_origin.clone(), false)
+    }
+
+    /// 替换记录值：同一条消息中先删除旧值再添加新值，服务器原子地应用
+    pub(crate) fn replace_message(&self, old: Record, new: Record) -> Message {
+        let mut message = self.delete_message(old);
+        message.add_update(new);
+        message
+    }
+
+    /// 从记录 ID 还原待删除的记录，ID 无效时返回 `RecordNotFound`
+    fn existing_record(&self, record_id: &str) -> Result<Record> {
+        self.parse_record_id(record_id)
+            .ok_or_else(|| ProviderError::RecordNotFound {
+                provider: self.provider_name().to_string(),
+                record_id: record_id.to_string(),
+                raw_message: None,
+            })
+    }
+
+    fn request_record(
+        &self,
+        name: &str,
+        record_type: &DnsRecordType,
+        value: &str,
+        ttl: u32,
+        priority: Option<u16>,
+    ) -> Result<Record> {
+        let rdata = Self::build_rdata(record_type, value, priority)?;
+        Ok(Record::from_rdata(self.record_name(name)?, ttl, rdata))
+    }
+
+    /// 拉取区域全部记录（AXFR，需服务器允许该 TSIG 密钥进行区域传送）
+    async fn transfer_zone(&self) -> Result<Vec<Record>> {
+        let message = update_message::zone_transfer(self.zone_origin.clone(), None);
+        let responses = self.exchange(&message, self.domain_ctx()).await?;
+        Ok(responses
+            .into_iter()
+            .flat_map(|mut response| response.take_answers())
+            .collect())
+    }
+
+    fn domain_ctx(&self) -> ErrorContext {
+        ErrorContext {
+            domain: Some(self.zone.clone()),
+            ..Default::default()
+        }
+    }
+}
+
+/// 随机消息 ID
+fn rand_id() -> u16 {
+    let bytes = uuid::Uuid::new_v4().into_bytes();
+    u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+impl ProviderDescriptor for Rfc2136Provider {
+    fn metadata() -> ProviderMetadata {
+        ProviderMetadata {
+            id: ProviderType::Rfc2136,
+            name: "RFC 2136".to_string(),
+            description: "自建权威 DNS（BIND/Knot/PowerDNS）TSIG 动态更新".to_string(),
+            required_fields: vec![
+                ProviderCredentialField {
+                    key: "server".to_string(),
+                    label: "Server".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("主服务器地址，如 ns1.example.com".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "port".to_string(),
+                    label: "Port".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("53".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "zone".to_string(),
+                    label: "Zone".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("example.com".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "tsigKeyName".to_string(),
+                    label: "TSIG Key Name".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("输入 TSIG 密钥名称".to_string()),
+                    help_text: None,
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "tsigAlgorithm".to_string(),
+                    label: "TSIG Algorithm".to_string(),
+                    field_type: FieldType::Text,
+                    placeholder: Some("hmac-sha256".to_string()),
+                    help_text: Some("支持 hmac-sha256、hmac-sha384、hmac-sha512".to_string()),
+                    group: None,
+                },
+                ProviderCredentialField {
+                    key: "tsigSecret".to_string(),
+                    label: "TSIG Secret".to_string(),
+                    field_type: FieldType::Password,
+                    placeholder: Some("输入 Base64 格式的 TSIG 密钥".to_string()),
+                    help_text: Some(
+                        "记录列表通过区域传送（AXFR）获取，需允许该密钥传送区域".to_string(),
+                    ),
+                    group: None,
+                },
+            ],
+            supported_record_types: vec![
+                DnsRecordType::A,
+                DnsRecordType::Aaaa,
+                DnsRecordType::Cname,
+                DnsRecordType::Mx,
+                DnsRecordType::Txt,
+                DnsRecordType::Ns,
+                DnsRecordType::Srv,
+                DnsRecordType::Caa,
+                DnsRecordType::Ptr,
+            ],
+            features: ProviderFeatures::default(),
+            limits: ProviderLimits {
+                max_page_size_domains: 1,
+                max_page_size_records: MAX_PAGE_SIZE,
+                min_ttl: 0,
+                max_ttl: 2_147_483_647,
+                default_ttl: 3600,
+            },
+            pagination: PaginationMode::Page,
+        }
+    }
+}
+
+#[async_trait]
+impl DnsProvider for Rfc2136Provider {
+    fn id(&self) -> &'static str {
+        "rfc2136"
+    }
+
+    /// 发送签名的 SOA 查询，服务器接受 TSIG 签名即视为凭证有效
+    async fn validate_credentials(&self) -> Result<bool> {
+        match self.exchange(&self.soa_query(), self.domain_ctx()).await {
+            Ok(_) => Ok(true),
+            Err(ProviderError::InvalidCredentials { .. }) => Ok(false),
+            Err(e) => {
+                log::warn!("凭证验证失败: {e}");
+                Ok(false)
+            }
+        }
+    }
+
+    /// 每个账户对应一个区域
+    async fn list_domains(
+        &self,
+        params: &PaginationParams,
+    ) -> Result<PaginatedResponse<ProviderDomain>> {
+        let domains = filter_domains_by_name(vec![self.zone_domain()], params);
+        Ok(paginate_locally(domains, params.page, params.page_size))
+    }
+
+    async fn get_domain(&self, domain_id: &str) -> Result<ProviderDomain> {
+        self.ensure_zone(domain_id)?;
+        Ok(self.zone_domain())
+    }
+
+    /// 区域传送一次返回全部记录，本地搜索与分页
+    async fn list_records(
+        &self,
+        domain_id: &str,
+        params: &RecordQueryParams,
+    ) -> Result<PaginatedResponse<DnsRecord>> {
+        self.ensure_zone(domain_id)?;
+        let records = self
+            .transfer_zone()
+            .await?
+            .iter()
+            .filter_map(|r| self.convert_record(r))
+            .collect();
+
+        Ok(query_records_locally(records, params))
+    }
+
+    async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord> {
+        self.ensure_zone(&req.domain_id)?;
+        let record = self.request_record(
+            &req.name,
+            &req.record_type,
+            &req.value,
+            req.ttl,
+            req.priority,
+        )?;
+
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            domain: Some(req.domain_id.clone()),
+            ..Default::default()
+        };
+        self.exchange(&self.add_message(record.clone()), ctx)
+            .await?;
+
+        let now = chrono::Utc::now();
+        let mut created = self
+            .convert_record(&record)
+            .ok_or_else(|| unsupported_record_type(&req.record_type, self.provider_name()))?;
+        created.name = req.name.clone();
+        created.created_at = Some(now);
+        created.updated_at = Some(now);
+        Ok(created)
+    }
+
+    /// 在同一条 UPDATE 消息中删除旧值并添加新值
+    async fn update_record(
+        &self,
+        record_id: &str,
+        req: &UpdateDnsRecordRequest,
+    ) -> Result<DnsRecord> {
+        self.ensure_zone(&req.domain_id)?;
+        let ctx = ErrorContext {
+            record_name: Some(req.name.clone()),
+            record_id: Some(record_id.to_string()),
+            domain: Some(req.domain_id.clone()),
+        };
+        let old = self.existing_record(record_id)?;
+        let record = self.request_record(
+            &req.name,
+            &req.record_type,
+            &req.value,
+            req.ttl,
+            req.priority,
+        )?;
+
+        self.exchange(&self.replace_message(old, record.clone()), ctx)
+            .await?;
+
+        let mut updated = self
+            .convert_record(&record)
+            .ok_or_else(|| unsupported_record_type(&req.record_type, self.provider_name()))?;
+        updated.name = req.name.clone();
+        updated.updated_at = Some(chrono::Utc::now());
+        Ok(updated)
+    }
+
+    /// 按 ID 中保存的 rdata 删除单个记录值
+    async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()> {
+        self.ensure_zone(domain_id)?;
+        let ctx = ErrorContext {
+            record_id: Some(record_id.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        let old = self.existing_record(record_id)?;
+        self.exchange(&self.delete_message(old), ctx).await?;
+        Ok(())
+    }
+
+    /// 一条 UPDATE 消息删除同名同类型的整个 RRset
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        self.ensure_zone(domain_id)?;
+        let record_type: RecordType = record_type_to_string(record_type)
+            .parse()
+            .map_err(|_| unsupported_record_type(record_type, self.provider_name()))?;
+        let record = Record::update0(self.record_name(name)?, 0, record_type);
+        let message = update_message::delete_rrset(record, self.zone_origin.clone(), false);
+        let ctx = ErrorContext {
+            record_name: Some(name.to_string()),
+            domain: Some(domain_id.to_string()),
+            ..Default::default()
+        };
+        self.exchange(&message, ctx).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hickory_proto::dnssec::rdata::DNSSECRData;
+    use hickory_proto::dnssec::rdata::tsig::{TSIG, TsigAlgorithm, make_tsig_record};
+    use hickory_proto::dnssec::tsig::TSigner;
+    use hickory_proto::op::ResponseCode;
+    use hickory_proto::rr::rdata::SOA;
+    use hickory_proto::serialize::binary::BinEncoder;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    use super::*;
+    use crate::providers::common::TXT_CHUNK_LEN;
+
+    fn provider(server: &str, port: u16) -> Rfc2136Provider {
+        Rfc2136Provider::builder(
+            server.to_string(),
+            port,
+            "Example.com.",
+            "update-key",
+            "hmac-sha256",
+            "c2VjcmV0",
+        )
+        .unwrap()
+        .max_retries(0)
+        .build()
+    }
+
+    fn record(
+        provider: &Rfc2136Provider,
+        record_type: DnsRecordType,
+        name: &str,
+        value: &str,
+        priority: Option<u16>,
+    ) -> Record {
+        provider
+            .request_record(name, &record_type, value, 600, priority)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_record_id_round_trip() {
+        let provider = provider("127.0.0.1", 53);
+        let cases = [
+            (DnsRecordType::A, "www", "192.0.2.1", None),
+            (DnsRecordType::Mx, "@", "mail.example.com.", Some(10)),
+            (
+                DnsRecordType::Srv,
+                "_sip._tcp",
+                "5 5060 sip.example.com",
+                Some(1),
+            ),
+            (
+                DnsRecordType::Txt,
+                "*",
+                "v=spf1 include:example.net ~all",
+                None,
+            ),
+            (DnsRecordType::Caa, "@", "0 issue \"letsencrypt.org\"", None),
+            (DnsRecordType::Ptr, "1", "host.example.net.", None),
+        ];
+
+        for (record_type, name, value, priority) in cases {
+            let record = record(&provider, record_type.clone(), name, value, priority);
+            let converted = provider.convert_record(&record).unwrap();
+            assert_eq!(converted.record_type, record_type);
+            assert_eq!(converted.name, name);
+            assert_eq!(converted.priority, priority);
+            assert_eq!(converted.domain_id, "example.com");
+
+            let parsed = provider.parse_record_id(&converted.id).unwrap();
+            assert_eq!(parsed.name(), record.name());
+            assert_eq!(parsed.data(), record.data());
+        }
+
+        let mx = record(
+            &provider,
+            DnsRecordType::Mx,
+            "@",
+            "mail.example.com.",
+            Some(10),
+        );
+        assert_eq!(
+            provider.convert_record(&mx).unwrap().value,
+            "mail.example.com"
+        );
+    }
+
+    #[test]
+    fn test_invalid_record_id_rejected() {
+        let provider = provider("127.0.0.1", 53);
+        let other_zone = record(&provider, DnsRecordType::A, "www", "192.0.2.1", None);
+        let id = Rfc2136Provider::record_id(&other_zone)
+            .unwrap()
+            .replace("example.com", "example.net");
+
+        assert!(provider.parse_record_id(&id).is_none());
+        assert!(provider.parse_record_id("www.example.com/A/zz").is_none());
+        assert!(provider.parse_record_id("legacy").is_none());
+    }
+
+    #[test]
+    fn test_long_txt_split_into_chunks() {
+        let value = "a".repeat(300);
+        let rdata = Rfc2136Provider::build_rdata(&DnsRecordType::Txt, &value, None).unwrap();
+        let RData::TXT(txt) = &rdata else {
+            panic!("unexpected rdata: {rdata:?}");
+        };
+        assert_eq!(txt.txt_data().len(), 2);
+        assert_eq!(txt.txt_data()[0].len(), TXT_CHUNK_LEN);
+
+        let (_, parsed, _) = Rfc2136Provider::parse_rdata(&rdata).unwrap();
+        assert_eq!(parsed, value);
+    }
+
+    #[test]
+    fn test_replace_message_deletes_then_adds() {
+        let provider = provider("127.0.0.1", 53);
+        let old = record(&provider, DnsRecordType::A, "www", "192.0.2.1", None);
+        let new = record(&provider, DnsRecordType::A, "www", "192.0.2.2", None);
+        let message = provider.replace_message(old, new);
+
+        assert_eq!(message.op_code(), OpCode::Update);
+        let updates = message.updates();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].dns_class(), DNSClass::NONE);
+        assert_eq!(updates[0].ttl(), 0);
+        assert_eq!(updates[1].dns_class(), DNSClass::IN);
+        assert_eq!(updates[1].ttl(), 600);
+    }
+
+    #[test]
+    fn test_map_response_codes() {
+        let provider = provider("127.0.0.1", 53);
+        let ctx = provider.domain_ctx();
+
+        assert!(matches!(
+            provider.response_error(ResponseCode::NotAuth, ctx.clone()),
+            ProviderError::InvalidCredentials { .. }
+        ));
+        assert!(matches!(
+            provider.response_error(ResponseCode::NotZone, ctx.clone()),
+            ProviderError::DomainNotFound { ref domain, .. } if domain == "example.com"
+        ));
+        assert!(matches!(
+            provider.response_error(ResponseCode::ServFail, ctx),
+            ProviderError::Unknown { ref raw_code, .. } if raw_code.as_deref() == Some("2")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_signed_update_refused_by_server() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut len = [0u8; 2];
+            stream.read_exact(&mut len).await.unwrap();
+            let mut frame = vec![0u8; usize::from(u16::from_be_bytes(len))];
+            stream.read_exact(&mut frame).await.unwrap();
+            let request = Message::from_vec(&frame).unwrap();
+
+            let mut response = Message::new();
+            response
+                .set_id(request.id())
+                .set_message_type(MessageType::Response)
+                .set_op_code(OpCode::Update)
+                .set_response_code(ResponseCode::Refused);
+            let bytes = response.to_vec().unwrap();
+            stream
+                .write_all(&u16::try_from(bytes.len()).unwrap().to_be_bytes())
+                .await
+                .unwrap();
+            stream.write_all(&bytes).await.unwrap();
+            request
+        });
+
+        let provider = provider("127.0.0.1", port);
+        let err = provider
+            .delete_rrset("example.com", "www", &DnsRecordType::A)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, ProviderError::PermissionDenied { .. }),
+            "{err:?}"
+        );
+
+        let request = server.await.unwrap();
+        assert_eq!(request.op_code(), OpCode::Update);
+        assert_eq!(request.signature().len(), 1);
+        assert_eq!(request.signature()[0].record_type(), RecordType::TSIG);
+    }
+
+    /// 与测试 Provider 相同的 TSIG 密钥（`c2VjcmV0` 即 `secret`）
+    fn server_signer() -> TSigner {
+        TSigner::new(
+            b"secret".to_vec(),
+            TsigAlgorithm::HmacSha256,
+            Name::from_ascii("update-key.").unwrap(),
+            300,
+        )
+        .unwrap()
+    }
+
+    /// 按 RFC 8945 对响应逐条签名：首条消息链接请求的 MAC 并带完整 TSIG 变量，
+    /// 后续消息链接上一条的 MAC，只带时间和 fudge
+    fn sign_responses(
+        signer: &TSigner,
+        request: &Message,
+        responses: Vec<Message>,
+    ) -> Vec<Vec<u8>> {
+        let RData::DNSSEC(DNSSECRData::TSIG(request_tsig)) = request.signature()[0].data() else {
+            panic!("request is not TSIG signed");
+        };
+        let mut previous_mac = request_tsig.mac().to_vec();
+        let now = u64::try_from(chrono::Utc::now().timestamp()).unwrap();
+
+        let mut frames = Vec::new();
+        for (index, mut response) in responses.into_iter().enumerate() {
+            let pre_tsig = TSIG::new(
+                TsigAlgorithm::HmacSha256,
+                now,
+                300,
+                Vec::new(),
+                response.id(),
+                0,
+                Vec::new(),
+            );
+            // 待签名数据：上一条 MAC + 消息本身 + TSIG 变量（单独编码，避免前缀影响名称压缩指针）
+            let mut tbs = u16::try_from(previous_mac.len())
+                .unwrap()
+                .to_be_bytes()
+                .to_vec();
+            tbs.extend_from_slice(&previous_mac);
+            tbs.extend_from_slice(&response.to_vec().unwrap());
+            if index == 0 {
+                let mut variables = Vec::new();
+                pre_tsig
+                    .emit_tsig_for_mac(&mut BinEncoder::new(&mut variables), signer.signer_name())
+                    .unwrap();
+                tbs.extend_from_slice(&variables);
+            } else {
+                tbs.extend_from_slice(&u16::try_from(now >> 32).unwrap().to_be_bytes());
+                tbs.extend_from_slice(
+                    &u32::try_from(now & u64::from(u32::MAX))
+                        .unwrap()
+                        .to_be_bytes(),
+                );
+                tbs.extend_from_slice(&300u16.to_be_bytes());
+            }
+            let mac = signer.sign(&tbs).unwrap();
+            response.add_tsig(make_tsig_record(
+                signer.signer_name().clone(),
+                pre_tsig.set_mac(mac.clone()),
+            ));
+            previous_mac = mac;
+            frames.push(response.to_vec().unwrap());
+        }
+        frames
+    }
+
+    /// 启动只处理一个请求的 DNS 服务，`respond` 根据请求生成（未签名的）响应消息，
+    /// 由服务端签名后逐帧返回；返回端口和收到的请求
+    async fn serve_signed(
+        signer: TSigner,
+        respond: impl FnOnce(&Message) -> Vec<Message> + Send + 'static,
+    ) -> (u16, tokio::task::JoinHandle<Message>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut len = [0u8; 2];
+            stream.read_exact(&mut len).await.unwrap();
+            let mut frame = vec![0u8; usize::from(u16::from_be_bytes(len))];
+            stream.read_exact(&mut frame).await.unwrap();
+            let request = Message::from_vec(&frame).unwrap();
+
+            for bytes in sign_responses(&signer, &request, respond(&request)) {
+                stream
+                    .write_all(&u16::try_from(bytes.len()).unwrap().to_be_bytes())
+                    .await
+                    .unwrap();
+                stream.write_all(&bytes).await.unwrap();
+            }
+            request
+        });
+        (port, server)
+    }
+
+    fn response_to(request: &Message) -> Message {
+        let mut response = Message::new();
+        response
+            .set_id(request.id())
+            .set_message_type(MessageType::Response)
+            .set_op_code(request.op_code())
+            .set_response_code(ResponseCode::NoError);
+        response
+    }
+
+    #[tokio::test]
+    async fn test_signed_update_accepted_by_server() {
+        let (port, server) =
+            serve_signed(server_signer(), |request| vec![response_to(request)]).await;
+
+        let provider = provider("127.0.0.1", port);
+        let req = CreateDnsRecordRequest {
+            domain_id: "example.com".to_string(),
+            record_type: DnsRecordType::A,
+            name: "www".to_string(),
+            value: "192.0.2.1".to_string(),
+            ttl: 600,
+            priority: None,
+            proxied: None,
+            line: None,
+            weight: None,
+            comment: None,
+            tags: None,
+        };
+        let created = provider.create_record(&req).await.unwrap();
+        assert_eq!(created.name, "www");
+        assert_eq!(created.value, "192.0.2.1");
+
+        let request = server.await.unwrap();
+        assert_eq!(request.op_code(), OpCode::Update);
+        assert_eq!(request.updates().len(), 1);
+        assert_eq!(request.updates()[0].record_type(), RecordType::A);
+    }
+
+    #[tokio::test]
+    async fn test_response_signed_with_wrong_key_rejected() {
+        let wrong = TSigner::new(
+            b"other".to_vec(),
+            TsigAlgorithm::HmacSha256,
+            Name::from_ascii("update-key.").unwrap(),
+            300,
+        )
+        .unwrap();
+        let (port, _server) = serve_signed(wrong, |request| vec![response_to(request)]).await;
+
+        let err = provider("127.0.0.1", port)
+            .delete_rrset("example.com", "www", &DnsRecordType::A)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, ProviderError::InvalidCredentials { .. }),
+            "{err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_zone_transfer_across_multiple_frames() {
+        let (port, server) = serve_signed(server_signer(), |request| {
+            let origin = Name::from_ascii("example.com.").unwrap();
+            let soa = Record::from_rdata(
+                origin.clone(),
+                3600,
+                RData::SOA(SOA::new(
+                    Name::from_ascii("ns1.example.com.").unwrap(),
+                    Name::from_ascii("admin.example.com.").unwrap(),
+                    1,
+                    3600,
+                    600,
+                    86400,
+                    300,
+                )),
+            );
+            let a = |name: &str, ip: [u8; 4]| {
+                Record::from_rdata(
+                    Name::from_ascii(name).unwrap(),
+                    600,
+                    RData::A(A(Ipv4Addr::from(ip))),
+                )
+            };
+
+            // 首帧以 SOA 开始，末帧以同一 SOA 结束
+            let mut first = response_to(request);
+            first.add_query(request.queries()[0].clone());
+            first.add_answer(soa.clone());
+            first.add_answer(a("www.example.com.", [192, 0, 2, 1]));
+            let mut last = response_to(request);
+            last.add_answer(a("mail.example.com.", [192, 0, 2, 2]));
+            last.add_answer(soa);
+            vec![first, last]
+        })
+        .await;
+
+        let records = provider("127.0.0.1", port)
+            .list_records("example.com", &RecordQueryParams::default())
+            .await
+            .unwrap();
+        let mut names: Vec<_> = records.items.iter().map(|r| r.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(names, ["mail", "www"]);
+
+        let request = server.await.unwrap();
+        assert_eq!(request.queries()[0].query_type(), RecordType::AXFR);
+    }
+}
@@ -0,0 +1,157 @@
+//! RFC 2136 消息收发
+//!
+//! DNS UPDATE 与 AXFR 均通过 TCP 发送，每条消息前带两字节长度前缀（RFC 1035 4.2.2）；
+//! 请求使用 TSIG 签名，响应逐条校验签名
+//!
+//! 没有使用 hickory-client：它的 TCP 客户端要求常驻的 `DnsExchange` 后台任务和多路复用连接，
+//! 而这里每次操作只收发一条消息（或一次区域传送），单独建立连接即可。帧格式只有长度前缀，
+//! 消息编解码、TSIG 签名与校验都直接复用 hickory-proto，自己实现的部分仅限读写帧与判断
+//! 区域传送何时结束
+
+use hickory_proto::op::{Message, ResponseCode};
+use hickory_proto::rr::RecordType;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::error::{ProviderError, Result};
+use crate::traits::{ErrorContext, ProviderErrorMapper};
+
+use super::Rfc2136Provider;
+
+impl Rfc2136Provider {
+    /// 签名并发送消息，返回已通过 TSIG 校验的响应
+    ///
+    /// 区域传送的响应可能分为多条消息，收到结束的 SOA 后一并返回；
+    /// 仅重试连接/传输失败，DNS UPDATE 的增删操作本身是幂等的
+    pub(crate) async fn exchange(
+        &self,
+        message: &Message,
+        ctx: ErrorContext,
+    ) -> Result<Vec<Message>> {
+        let max_attempts = self.retry_policy.max_attempts.max(1);
+        let mut attempt = 0;
+
+        loop {
+            attempt += 1;
+            self.rate_limiter.acquire().await;
+            let result = self.exchange_once(message.clone(), &ctx).await;
+
+            let reason = match &result {
+                Err(ProviderError::NetworkError { detail, .. }) => detail.clone(),
+                _ => return result,
+            };
+            if attempt >= max_attempts {
+                return result;
+            }
+
+            let delay = self.retry_policy.delay(attempt - 1);
+            log::warn!(
+                "[{}] 请求失败（尝试 {}/{}），{:.1}秒后重试: {}",
+                self.provider_name(),
+                attempt,
+                max_attempts,
+                delay.as_secs_f32(),
+                reason
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn exchange_once(
+        &self,
+        mut message: Message,
+        ctx: &ErrorContext,
+    ) -> Result<Vec<Message>> {
+        let now = u32::try_from(chrono::Utc::now().timestamp()).unwrap_or(u32::MAX);
+        let mut verifier = message
+            .finalize(&self.signer, now)
+            .map_err(|e| self.serialization_error(e))?;
+        let request = message.to_vec().map_err(|e| self.serialization_error(e))?;
+        let is_transfer = message
+            .queries()
+            .first()
+            .is_some_and(|q| q.query_type() == RecordType::AXFR);
+
+        let address = (self.server.as_str(), self.port);
+        let mut stream = tokio::time::timeout(self.connect_timeout, TcpStream::connect(address))
+            .await
+            .map_err(|_| self.network_error(format!("连接 {}:{} 超时", self.server, self.port)))?
+            .map_err(|e| self.network_error(e))?;
+
+        let exchange = async {
+            write_frame(&mut stream, &request)
+                .await
+                .map_err(|e| self.network_error(e))?;
+
+            let mut responses = Vec::new();
+            let mut soa_count = 0;
+            loop {
+                let frame = read_frame(&mut stream)
+                    .await
+                    .map_err(|e| self.network_error(e))?;
+                let response = Message::from_vec(&frame).map_err(|e| self.parse_error(e))?;
+                if response.id() != message.id() {
+                    return Err(self.parse_error(format!(
+                        "响应 ID {} 与请求 ID {} 不匹配",
+                        response.id(),
+                        message.id()
+                    )));
+                }
+
+                // 服务器无法识别密钥时返回的错误响应不带签名，先按响应码处理
+                if response.response_code() != ResponseCode::NoError {
+                    return Err(self.response_error(response.response_code(), ctx.clone()));
+                }
+                if let Some(verify) = verifier.as_mut() {
+                    verify(&frame).map_err(|e| ProviderError::InvalidCredentials {
+                        provider: self.provider_name().to_string(),
+                        raw_message: Some(format!("响应 TSIG 校验失败: {e}")),
+                    })?;
+                }
+
+                // 区域传送以 SOA 开始、以同一 SOA 结束
+                soa_count += response
+                    .answers()
+                    .iter()
+                    .filter(|r| r.record_type() == RecordType::SOA)
+                    .count();
+                let done = !is_transfer || response.answers().is_empty() || soa_count >= 2;
+                responses.push(response);
+                if done {
+                    return Ok(responses);
+                }
+            }
+        };
+
+        tokio::time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| self.network_error("等待 DNS 服务器响应超时"))?
+    }
+
+    fn serialization_error(&self, detail: impl ToString) -> ProviderError {
+        ProviderError::SerializationError {
+            provider: self.provider_name().to_string(),
+            detail: detail.to_string(),
+        }
+    }
+}
+
+/// 写入一条带长度前缀的消息
+async fn write_frame(stream: &mut TcpStream, message: &[u8]) -> std::io::Result<()> {
+    let len = u16::try_from(message.len()).map_err(|_| {
+        std::io::Error::new(std::io::ErrorKind::InvalidInput, "DNS 消息超过 65535 字节")
+    })?;
+    let mut frame = Vec::with_capacity(message.len() + 2);
+    frame.extend_from_slice(&len.to_be_bytes());
+    frame.extend_from_slice(message);
+    stream.write_all(&frame).await
+}
+
+/// 读取一条带长度前缀的消息
+async fn read_frame(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut len = [0u8; 2];
+    stream.read_exact(&mut len).await?;
+    let mut frame = vec![0u8; usize::from(u16::from_be_bytes(len))];
+    stream.read_exact(&mut frame).await?;
+    Ok(frame)
+}
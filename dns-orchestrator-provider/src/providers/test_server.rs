@@ -0,0 +1,127 @@
+//! 测试用本地 HTTP 服务
+//!
+//! 按收到的顺序记录请求，响应由处理函数根据请求生成，用于在不访问服务商的情况下
+//! 走通完整的请求/响应流程（含分页、批量等多次请求的场景）。
+
+use std::sync::{Arc, Mutex};
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+/// 收到的请求
+#[derive(Debug, Clone)]
+pub(crate) struct TestRequest {
+    pub method: String,
+    /// 路径与查询字符串
+    pub path: String,
+    /// 请求头（名称为小写）
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl TestRequest {
+    /// 按名称（不区分大小写）取请求头
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+type Handler = dyn Fn(&TestRequest) -> (u16, String) + Send + Sync;
+
+/// 本地 HTTP 服务
+pub(crate) struct TestServer {
+    /// 服务根地址（不带末尾的 `/`）
+    pub url: String,
+    requests: Arc<Mutex<Vec<TestRequest>>>,
+}
+
+impl TestServer {
+    /// 启动服务，`handler` 返回 (状态码, 响应体)
+    pub async fn start(
+        handler: impl Fn(&TestRequest) -> (u16, String) + Send + Sync + 'static,
+    ) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let log = requests.clone();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                tokio::spawn(serve_connection(socket, handler.clone(), log.clone()));
+            }
+        });
+
+        Self { url, requests }
+    }
+
+    /// 已收到的全部请求（按到达顺序）
+    pub fn requests(&self) -> Vec<TestRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+/// 处理一个连接上的全部请求（支持长连接）
+async fn serve_connection(
+    mut socket: TcpStream,
+    handler: Arc<Handler>,
+    log: Arc<Mutex<Vec<TestRequest>>>,
+) {
+    let mut buf = Vec::new();
+    let mut chunk = [0_u8; 8192];
+    loop {
+        let request = loop {
+            if let Some((request, consumed)) = parse_request(&buf) {
+                buf.drain(..consumed);
+                break request;
+            }
+            match socket.read(&mut chunk).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => buf.extend_from_slice(&chunk[..n]),
+            }
+        };
+
+        log.lock().unwrap().push(request.clone());
+        let (status, body) = handler(&request);
+        let response = format!(
+            "HTTP/1.1 {status} Test\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        if socket.write_all(response.as_bytes()).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// 从缓冲区解析一个完整请求，返回请求和消耗的字节数
+fn parse_request(buf: &[u8]) -> Option<(TestRequest, usize)> {
+    let header_end = buf.windows(4).position(|w| w == b"\r\n\r\n")?;
+    let head = String::from_utf8_lossy(&buf[..header_end]);
+    let mut lines = head.lines();
+    let mut request_line = lines.next()?.split_whitespace();
+    let method = request_line.next()?.to_string();
+    let path = request_line.next()?.to_string();
+    let headers: Vec<(String, String)> = lines
+        .filter_map(|l| l.split_once(':'))
+        .map(|(name, value)| (name.trim().to_ascii_lowercase(), value.trim().to_string()))
+        .collect();
+    let content_length = headers
+        .iter()
+        .find(|(name, _)| name == "content-length")
+        .map_or(0, |(_, v)| v.parse().unwrap_or(0));
+
+    let body_start = header_end + 4;
+    let body = buf.get(body_start..body_start + content_length)?;
+    Some((
+        TestRequest {
+            method,
+            path,
+            headers,
+            body: String::from_utf8_lossy(body).to_string(),
+        },
+        body_start + content_length,
+    ))
+}
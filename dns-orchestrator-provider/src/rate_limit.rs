@@ -0,0 +1,192 @@
+//! 请求速率限制
+//!
+//! 各服务商对 API 调用频率有配额（如 Cloudflare 每 5 分钟 1200 次），
+//! 批量操作并发请求时容易触发限流。每个 Provider 实例持有一个令牌桶，
+//! 同一账户的所有并发请求在发送前共享等待。
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::types::ProviderType;
+
+/// 速率限制配置：`period` 内最多 `max_requests` 次请求
+///
+/// 令牌桶容量为 `max_requests`，令牌以 `max_requests / period` 的速率匀速补充
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// 时间窗口内允许的最大请求数（同时也是突发容量）
+    pub max_requests: u32,
+    /// 时间窗口
+    pub period: Duration,
+}
+
+impl RateLimit {
+    /// 创建速率限制
+    pub fn new(max_requests: u32, period: Duration) -> Self {
+        Self {
+            max_requests,
+            period,
+        }
+    }
+
+    /// 每秒最多 `max_requests` 次
+    pub fn per_second(max_requests: u32) -> Self {
+        Self::new(max_requests, Duration::from_secs(1))
+    }
+
+    /// 各服务商的默认速率限制（略低于官方配额）
+    pub fn default_for(provider: &ProviderType) -> Self {
+        match provider {
+            // 1200 次 / 5 分钟
+            #[cfg(feature = "cloudflare")]
+            ProviderType::Cloudflare => Self::new(1200, Duration::from_secs(300)),
+            #[cfg(feature = "aliyun")]
+            ProviderType::Aliyun => Self::per_second(10),
+            // 单接口 20 次/秒
+            #[cfg(feature = "dnspod")]
+            ProviderType::Dnspod => Self::per_second(20),
+            #[cfg(feature = "huaweicloud")]
+            ProviderType::Huaweicloud => Self::per_second(10),
+            #[cfg(feature = "gclouddns")]
+            ProviderType::GcloudDns => Self::per_second(10),
+            // 20 次 / 分钟
+            #[cfg(feature = "namecheap")]
+            ProviderType::Namecheap => Self::new(20, Duration::from_secs(60)),
+            #[cfg(feature = "porkbun")]
+            ProviderType::Porkbun => Self::new(60, Duration::from_secs(60)),
+            // 自建服务器，无官方配额
+            #[cfg(feature = "rfc2136")]
+            ProviderType::Rfc2136 => Self::per_second(20),
+            // 内存实现，不限流
+            #[cfg(feature = "mock")]
+            ProviderType::Mock => Self::per_second(0),
+        }
+    }
+
+    /// 每秒补充的令牌数
+    fn refill_per_sec(&self) -> f64 {
+        f64::from(self.max_requests) / self.period.as_secs_f64()
+    }
+}
+
+/// 令牌桶状态
+#[derive(Debug)]
+struct Bucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    /// 当前令牌数，为负表示已有请求排队等待
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    /// 预占一个令牌，返回需要等待的时长
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.refill_per_sec).min(self.capacity);
+        self.last_refill = now;
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_per_sec)
+        }
+    }
+}
+
+/// 令牌桶限流器（克隆后共享同一个桶）
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter {
+    bucket: Option<Arc<Mutex<Bucket>>>,
+}
+
+impl RateLimiter {
+    /// 按配置创建限流器；`max_requests` 或 `period` 为 0 时不限流
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        if limit.max_requests == 0 || limit.period.is_zero() {
+            return Self::default();
+        }
+        let capacity = f64::from(limit.max_requests);
+        Self {
+            bucket: Some(Arc::new(Mutex::new(Bucket {
+                capacity,
+                refill_per_sec: limit.refill_per_sec(),
+                tokens: capacity,
+                last_refill: Instant::now(),
+            }))),
+        }
+    }
+
+    /// 等待直到允许发送下一个请求
+    pub(crate) async fn acquire(&self) {
+        let Some(bucket) = &self.bucket else {
+            return;
+        };
+        let wait = match bucket.lock() {
+            Ok(mut bucket) => bucket.reserve(Instant::now()),
+            Err(_) => Duration::ZERO,
+        };
+        if !wait.is_zero() {
+            log::debug!("触发速率限制，等待 {:.2} 秒", wait.as_secs_f32());
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_limit_is_unlimited() {
+        assert!(RateLimiter::new(RateLimit::per_second(0)).bucket.is_none());
+        assert!(
+            RateLimiter::new(RateLimit::new(5, Duration::ZERO))
+                .bucket
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_acquire_respects_rate() {
+        let limit = RateLimit::new(5, Duration::from_millis(200));
+        let limiter = RateLimiter::new(limit);
+        let start = Instant::now();
+
+        let handles: Vec<_> = (0..15)
+            .map(|_| {
+                let limiter = limiter.clone();
+                tokio::spawn(async move {
+                    limiter.acquire().await;
+                    Instant::now()
+                })
+            })
+            .collect();
+        let mut times = Vec::new();
+        for handle in handles {
+            times.push(handle.await.unwrap().duration_since(start));
+        }
+        times.sort();
+
+        // 令牌桶保证任意时间窗口 [t_i, t_j] 内的请求数不超过 容量 + 速率 * 窗口长度
+        let rate = limit.refill_per_sec();
+        for i in 0..times.len() {
+            for j in i..times.len() {
+                let window = (times[j] - times[i]).as_secs_f64();
+                let allowed = f64::from(limit.max_requests) + rate * window;
+                assert!(
+                    (j - i + 1) as f64 <= allowed + 1.0,
+                    "{} requests within {window:.3}s exceed the limit",
+                    j - i + 1
+                );
+            }
+        }
+
+        // 首批 5 个立即放行，其余 10 个需要等待 2 个完整周期
+        assert!(times[4] < Duration::from_millis(50));
+        assert!(times[14] >= Duration::from_millis(390));
+    }
+}
@@ -1,12 +1,20 @@
 use async_trait::async_trait;
+use futures::StreamExt;
 
 use crate::error::{ProviderError, Result};
+use crate::identity::{is_apex_name, same_record_name};
 use crate::types::{
-    BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
-    CreateDnsRecordRequest, DnsRecord, PaginatedResponse, PaginationParams, ProviderDomain,
-    ProviderMetadata, RecordQueryParams, UpdateDnsRecordRequest,
+    BatchCreateFailure, BatchCreateResult, BatchDeleteResult, BatchUpdateItem, BatchUpdateResult,
+    CreateDnsRecordRequest, DnsRecord, DnsRecordType, PaginatedResponse, PaginationParams,
+    ProviderDomain, ProviderMetadata, RecordQueryParams, UpdateDnsRecordRequest,
 };
 
+/// `delete_rrset` 默认实现拉取记录时的分页大小
+const RRSET_PAGE_SIZE: u32 = 100;
+
+/// `batch_create_records` 默认实现的最大并发数
+const BATCH_CREATE_CONCURRENCY: usize = 5;
+
 /// 原始 API 错误（内部使用）
 #[derive(Debug, Clone)]
 pub(crate) struct RawApiError {
@@ -53,7 +61,8 @@ pub(crate) trait ProviderErrorMapper {
     /// 将原始 API 错误映射到统一错误类型
     fn map_error(&self, raw: RawApiError, context: ErrorContext) -> ProviderError;
 
-    /// 快捷方法：网络错误
+    /// 快捷方法：网络错误（HTTP Provider 的网络错误由 `HttpUtils` 生成）
+    #[cfg_attr(not(feature = "rfc2136"), allow(dead_code))]
     fn network_error(&self, detail: impl ToString) -> ProviderError {
         ProviderError::NetworkError {
             provider: self.provider_name().to_string(),
@@ -79,20 +88,24 @@ pub(crate) trait ProviderErrorMapper {
     }
 }
 
+/// 服务商类型级元数据
+///
+/// 由具体服务商实现，装饰层等包装 `dyn DnsProvider` 的类型无需实现；
+/// 只持有 Provider 实例时，按 `DnsProvider::id()` 通过 [`crate::provider_metadata`] 查找
+pub trait ProviderDescriptor {
+    /// 获取 Provider 元数据
+    ///
+    /// 返回该 Provider 的元数据，包括名称、描述、凭证字段等。
+    /// 此方法不需要实例，可以在创建 Provider 之前调用。
+    fn metadata() -> ProviderMetadata;
+}
+
 /// DNS 提供商 Trait
 #[async_trait]
 pub trait DnsProvider: Send + Sync {
     /// 提供商标识符
     fn id(&self) -> &'static str;
 
-    /// 获取 Provider 元数据（类型级别）
-    ///
-    /// 返回该 Provider 的元数据，包括名称、描述、凭证字段等。
-    /// 此方法不需要实例，可以在创建 Provider 之前调用。
-    fn metadata() -> ProviderMetadata
-    where
-        Self: Sized;
-
     /// 验证凭证是否有效
     async fn validate_credentials(&self) -> Result<bool>;
 
@@ -112,6 +125,39 @@ pub trait DnsProvider: Send + Sync {
         params: &RecordQueryParams,
     ) -> Result<PaginatedResponse<DnsRecord>>;
 
+    /// 按 ID 获取单条 DNS 记录
+    ///
+    /// 默认实现分页拉取记录后按 ID 查找；
+    /// 服务商提供单条查询 API 时应覆盖此方法。
+    async fn get_record(&self, record_id: &str, domain_id: &str) -> Result<DnsRecord> {
+        let mut page = 1;
+        let mut cursor = None;
+        loop {
+            let params = RecordQueryParams {
+                page,
+                page_size: RRSET_PAGE_SIZE,
+                keyword: None,
+                record_type: None,
+                cursor,
+            };
+            let response = self.list_records(domain_id, &params).await?;
+            if let Some(record) = response.items.into_iter().find(|r| r.id == record_id) {
+                return Ok(record);
+            }
+            if !response.has_more {
+                break;
+            }
+            page += 1;
+            cursor = response.next_cursor;
+        }
+
+        Err(ProviderError::RecordNotFound {
+            provider: self.id().to_string(),
+            record_id: record_id.to_string(),
+            raw_message: None,
+        })
+    }
+
     /// 创建 DNS 记录
     async fn create_record(&self, req: &CreateDnsRecordRequest) -> Result<DnsRecord>;
 
@@ -123,30 +169,100 @@ pub trait DnsProvider: Send + Sync {
     ) -> Result<DnsRecord>;
 
     /// 删除 DNS 记录
+    ///
+    /// 只删除 ID 对应的单个记录值。同名同类型的多个值（RRset）中，其余值保持不变；
+    /// 如需删除整个 RRset，请使用 [`DnsProvider::delete_rrset`]。
     async fn delete_record(&self, record_id: &str, domain_id: &str) -> Result<()>;
 
-    /// 批量创建 DNS 记录
+    /// 删除整个 RRset（指定名称 + 类型下的全部记录值）
     ///
-    /// # 实现状态
-    /// 当前为占位实现，调用会 panic。
-    ///
-    /// # TODO - 实现计划
-    /// - [ ] Cloudflare: 使用 `POST /zones/{zone_id}/dns_records/batch` API
-    ///       文档: https://developers.cloudflare.com/api/resources/dns/subresources/records/methods/batch/
-    ///       预期性能: 10,000 条记录快 850 倍
-    /// - [ ] DNSPod: 使用 `CreateRecordBatch` API（异步任务）
-    ///       文档: https://cloud.tencent.com/document/product/1427/56194
-    /// - [ ] Aliyun: 调研批量 API 支持
-    ///       文档: https://help.aliyun.com/zh/dns/pubz-batch-operation/
-    /// - [ ] Huaweicloud: 调研批量 API 支持
+    /// 默认实现先列出匹配的记录再逐条调用 `delete_record`；
+    /// 服务商提供按名称/类型批量删除的原生 API 时应覆盖此方法。
+    async fn delete_rrset(
+        &self,
+        domain_id: &str,
+        name: &str,
+        record_type: &DnsRecordType,
+    ) -> Result<()> {
+        // 根记录无法通过关键词搜索，需全量拉取后本地过滤
+        let keyword = (!is_apex_name(name)).then(|| name.to_string());
+        let mut record_ids = Vec::new();
+        let mut page = 1;
+        let mut cursor = None;
+
+        // 先收集全部 ID 再删除，避免边删边翻页导致遗漏
+        loop {
+            let params = RecordQueryParams {
+                page,
+                page_size: RRSET_PAGE_SIZE,
+                keyword: keyword.clone(),
+                record_type: Some(record_type.clone()),
+                cursor,
+            };
+            let response = self.list_records(domain_id, &params).await?;
+            record_ids.extend(
+                response
+                    .items
+                    .into_iter()
+                    .filter(|r| r.record_type == *record_type && same_record_name(&r.name, name))
+                    .map(|r| r.id),
+            );
+            if !response.has_more {
+                break;
+            }
+            page += 1;
+            cursor = response.next_cursor;
+        }
+
+        for record_id in record_ids {
+            self.delete_record(&record_id, domain_id).await?;
+        }
+        Ok(())
+    }
+
+    /// 批量创建 DNS 记录
     ///
-    /// # 未来优化
-    /// 默认实现可使用客户端并行循环 `join_all(create_record())`
+    /// 单条失败不影响其他记录：失败项按请求下标记入 `failures`，`created_records` 按请求顺序排列
+    /// （不含失败项）。凭证失效导致全部失败时整体返回 `InvalidCredentials` 错误。
+    /// 默认实现以有限并发逐条调用 `create_record`；服务商提供原生批量创建 API 时应覆盖此方法，
+    /// 并在元数据中声明 `ProviderFeatures::batch_native`
     async fn batch_create_records(
         &self,
-        _requests: &[CreateDnsRecordRequest],
+        requests: &[CreateDnsRecordRequest],
     ) -> Result<BatchCreateResult> {
-        unimplemented!("批量创建 API 待实现 - 请查看 trait 文档中的 TODO 列表")
+        let results: Vec<_> = futures::stream::iter(0..requests.len())
+            .map(|i| self.create_record(&requests[i]))
+            .buffered(BATCH_CREATE_CONCURRENCY)
+            .collect()
+            .await;
+
+        let mut created_records = Vec::with_capacity(requests.len());
+        let mut failures = Vec::new();
+        let mut credentials_error = None;
+        for (request_index, (req, result)) in requests.iter().zip(results).enumerate() {
+            match result {
+                Ok(record) => created_records.push(record),
+                Err(e) => {
+                    failures.push(BatchCreateFailure {
+                        request_index,
+                        record_name: req.name.clone(),
+                        reason: e.to_string(),
+                    });
+                    if matches!(e, ProviderError::InvalidCredentials { .. }) {
+                        credentials_error = Some(e);
+                    }
+                }
+            }
+        }
+        if let Some(e) = credentials_error.filter(|_| created_records.is_empty()) {
+            return Err(e);
+        }
+        Ok(BatchCreateResult {
+            success_count: created_records.len(),
+            failed_count: failures.len(),
+            created_records,
+            failures,
+        })
     }
 
     /// 批量更新 DNS 记录
@@ -8,6 +8,11 @@ use serde::{Deserialize, Serialize};
 pub struct PaginationParams {
     pub page: u32,
     pub page_size: u32,
+    /// 域名名称过滤（包含匹配，忽略大小写）
+    ///
+    /// 服务商支持时下推到上游查询，否则在本地过滤
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_filter: Option<String>,
 }
 
 impl Default for PaginationParams {
@@ -15,10 +20,21 @@ impl Default for PaginationParams {
         Self {
             page: 1,
             page_size: 20,
+            name_filter: None,
         }
     }
 }
 
+impl PaginationParams {
+    /// 有效的名称过滤词（去除首尾空白，空字符串视为不过滤）
+    pub fn name_filter(&self) -> Option<&str> {
+        self.name_filter
+            .as_deref()
+            .map(str::trim)
+            .filter(|f| !f.is_empty())
+    }
+}
+
 /// DNS 记录查询参数（包含搜索和过滤）
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -31,6 +47,10 @@ pub struct RecordQueryParams {
     /// 记录类型过滤
     #[serde(skip_serializing_if = "Option::is_none")]
     pub record_type: Option<DnsRecordType>,
+    /// 游标（上一页响应的 `next_cursor`）；仅 [`PaginationMode::Cursor`] 的服务商使用，
+    /// 设置后忽略 `page`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
 }
 
 impl Default for RecordQueryParams {
@@ -40,6 +60,7 @@ impl Default for RecordQueryParams {
             page_size: 20,
             keyword: None,
             record_type: None,
+            cursor: None,
         }
     }
 }
@@ -50,6 +71,7 @@ impl RecordQueryParams {
         PaginationParams {
             page: self.page,
             page_size: self.page_size,
+            name_filter: None,
         }
     }
 }
@@ -63,6 +85,9 @@ pub struct PaginatedResponse<T> {
     pub page_size: u32,
     pub total_count: u32,
     pub has_more: bool,
+    /// 下一页游标（仅游标分页模式返回）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
 }
 
 impl<T> PaginatedResponse<T> {
@@ -74,8 +99,19 @@ impl<T> PaginatedResponse<T> {
             page_size,
             total_count,
             has_more,
+            next_cursor: None,
         }
     }
+
+    /// 设置下一页游标；有游标时 `has_more` 以游标为准
+    #[must_use]
+    pub fn with_next_cursor(mut self, next_cursor: Option<String>) -> Self {
+        if next_cursor.is_some() {
+            self.has_more = true;
+        }
+        self.next_cursor = next_cursor;
+        self
+    }
 }
 
 // ============ Provider 相关类型 ============
@@ -92,6 +128,16 @@ pub enum ProviderType {
     Dnspod,
     #[cfg(feature = "huaweicloud")]
     Huaweicloud,
+    #[cfg(feature = "gclouddns")]
+    GcloudDns,
+    #[cfg(feature = "namecheap")]
+    Namecheap,
+    #[cfg(feature = "porkbun")]
+    Porkbun,
+    #[cfg(feature = "rfc2136")]
+    Rfc2136,
+    #[cfg(feature = "mock")]
+    Mock,
 }
 
 impl std::fmt::Display for ProviderType {
@@ -105,6 +151,16 @@ impl std::fmt::Display for ProviderType {
             Self::Dnspod => write!(f, "dnspod"),
             #[cfg(feature = "huaweicloud")]
             Self::Huaweicloud => write!(f, "huaweicloud"),
+            #[cfg(feature = "gclouddns")]
+            Self::GcloudDns => write!(f, "gclouddns"),
+            #[cfg(feature = "namecheap")]
+            Self::Namecheap => write!(f, "namecheap"),
+            #[cfg(feature = "porkbun")]
+            Self::Porkbun => write!(f, "porkbun"),
+            #[cfg(feature = "rfc2136")]
+            Self::Rfc2136 => write!(f, "rfc2136"),
+            #[cfg(feature = "mock")]
+            Self::Mock => write!(f, "mock"),
         }
     }
 }
@@ -129,6 +185,15 @@ pub struct ProviderDomain {
     pub status: DomainStatus,
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
+    /// 服务商分配的权威 DNS 服务器（可用于核对注册商处的委派是否一致）
+    #[serde(rename = "nameServers", default, skip_serializing_if = "Vec::is_empty")]
+    pub name_servers: Vec<String>,
+    #[serde(rename = "createdAt", default, with = "crate::utils::datetime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "modifiedAt", default, with = "crate::utils::datetime")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub modified_at: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 // ============ DNS 记录相关类型 ============
@@ -144,6 +209,14 @@ pub enum DnsRecordType {
     Ns,
     Srv,
     Caa,
+    Ptr,
+    /// HTTPS（RFC 9460，类型 65）
+    Https,
+    /// SVCB（RFC 9460，类型 64）
+    Svcb,
+    /// ALIAS / ANAME（根域名 CNAME 拉平）；Porkbun、Namecheap 原生支持，
+    /// Cloudflare 以根域名 CNAME 提交，并将根域名 CNAME 读回为 ALIAS
+    Alias,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -158,12 +231,22 @@ pub struct DnsRecord {
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
-    #[serde(rename = "createdAt")]
-    #[serde(with = "crate::utils::datetime")]
+    /// 解析线路（阿里云 `Line` / DNSPod `RecordLine`），不支持线路的服务商为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 记录权重（负载均衡），不支持权重的服务商为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// 记录备注（Cloudflare `comment`），不支持备注的服务商为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// 记录标签（Cloudflare `tags`），不支持标签的服务商为 `None`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    #[serde(rename = "createdAt", default, with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<chrono::DateTime<chrono::Utc>>,
-    #[serde(rename = "updatedAt")]
-    #[serde(with = "crate::utils::datetime")]
+    #[serde(rename = "updatedAt", default, with = "crate::utils::datetime")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
 }
@@ -176,9 +259,23 @@ pub struct CreateDnsRecordRequest {
     pub record_type: DnsRecordType,
     pub name: String,
     pub value: String,
+    /// TTL（秒）；省略或为 0 时使用服务商默认 TTL（见 `ProviderLimits::default_ttl`）
+    #[serde(default)]
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
+    /// 解析线路，不支持线路的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 记录权重，仅支持权重的服务商可设置
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// 记录备注，不支持备注的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// 记录标签，不支持标签的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -192,6 +289,33 @@ pub struct UpdateDnsRecordRequest {
     pub ttl: u32,
     pub priority: Option<u16>,
     pub proxied: Option<bool>,
+    /// 解析线路，不支持线路的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// 记录权重，仅支持权重的服务商可设置
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// 记录备注，不支持备注的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// 记录标签，不支持标签的服务商忽略该字段
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// 乐观并发：读取记录时的版本（`DnsRecord::version`），记录已被修改时拒绝更新；
+    /// 服务商不返回修改时间时更新会被拒绝，应改用 `expected_value`
+    #[serde(
+        rename = "expectedVersion",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expected_version: Option<String>,
+    /// 乐观并发：读取记录时的值，用于不返回修改时间的服务商
+    #[serde(
+        rename = "expectedValue",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub expected_value: Option<String>,
 }
 
 // ============ 批量操作类型 ============
@@ -280,6 +404,9 @@ pub struct ProviderCredentialField {
     pub placeholder: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub help_text: Option<String>,
+    /// 认证方式分组（同一服务商的不同分组互斥，只需填写其中一组；None 表示始终必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group: Option<String>,
 }
 
 /// 提供商支持的功能
@@ -288,6 +415,20 @@ pub struct ProviderCredentialField {
 pub struct ProviderFeatures {
     /// 是否支持代理功能 (如 Cloudflare 的 CDN 代理)
     pub proxy: bool,
+    /// 是否支持解析线路（智能解析）
+    pub line: bool,
+    /// 是否支持记录权重
+    pub weight: bool,
+    /// 是否支持记录备注
+    pub comment: bool,
+    /// 是否原生支持批量操作（否则逐条调用）
+    pub batch_native: bool,
+    /// 是否支持 DNSSEC 管理
+    pub dnssec: bool,
+    /// 是否支持创建/删除 Zone
+    pub zone_management: bool,
+    /// 是否允许在根域名（@）上添加 CNAME（CNAME 拉平）
+    pub apex_cname: bool,
 }
 
 /// 提供商分页限制
@@ -298,6 +439,23 @@ pub struct ProviderLimits {
     pub max_page_size_domains: u32,
     /// DNS 记录列表的最大分页大小
     pub max_page_size_records: u32,
+    /// 最小 TTL（秒）
+    pub min_ttl: u32,
+    /// 最大 TTL（秒）
+    pub max_ttl: u32,
+    /// 创建记录时省略 TTL 所用的默认值（秒）
+    pub default_ttl: u32,
+}
+
+/// 列表分页模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PaginationMode {
+    /// 页码分页（`page` + `page_size`）
+    #[default]
+    Page,
+    /// 游标分页（`cursor` + `page_size`），同时兼容页码分页
+    Cursor,
 }
 
 /// 提供商元数据
@@ -308,12 +466,29 @@ pub struct ProviderMetadata {
     pub name: String,
     pub description: String,
     pub required_fields: Vec<ProviderCredentialField>,
+    /// 支持的记录类型
+    pub supported_record_types: Vec<DnsRecordType>,
     pub features: ProviderFeatures,
     pub limits: ProviderLimits,
+    /// DNS 记录列表的分页模式
+    pub pagination: PaginationMode,
 }
 
 // ============ 凭证类型 ============
 
+/// Cloudflare API Token 字段名
+#[cfg(feature = "cloudflare")]
+const CF_API_TOKEN_KEY: &str = "apiToken";
+/// Cloudflare 账户邮箱字段名（Global API Key 模式）
+#[cfg(feature = "cloudflare")]
+const CF_API_EMAIL_KEY: &str = "apiEmail";
+/// Cloudflare Global API Key 字段名
+#[cfg(feature = "cloudflare")]
+const CF_API_KEY_KEY: &str = "globalApiKey";
+/// Google Cloud 服务账号 JSON 字段名
+#[cfg(feature = "gclouddns")]
+const GCLOUD_SERVICE_ACCOUNT_KEY: &str = "serviceAccountJson";
+
 /// 凭证验证错误
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "type", rename_all = "camelCase")]
@@ -337,6 +512,11 @@ pub enum CredentialValidationError {
         label: String,
         reason: String,
     },
+    /// 互斥的认证方式字段被同时提供
+    ConflictingFields {
+        provider: ProviderType,
+        fields: Vec<String>,
+    },
 }
 
 impl std::fmt::Display for CredentialValidationError {
@@ -345,19 +525,40 @@ impl std::fmt::Display for CredentialValidationError {
             Self::MissingField { label, .. } => write!(f, "缺少必填字段: {label}"),
             Self::EmptyField { label, .. } => write!(f, "字段不能为空: {label}"),
             Self::InvalidFormat { label, reason, .. } => write!(f, "{label}: {reason}"),
+            Self::ConflictingFields { fields, .. } => {
+                write!(
+                    f,
+                    "只能选择一种认证方式，以下字段不能同时提供: {}",
+                    fields.join(", ")
+                )
+            }
         }
     }
 }
 
 impl std::error::Error for CredentialValidationError {}
 
+/// Cloudflare 认证方式（二选一）
+#[cfg(feature = "cloudflare")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CloudflareAuth {
+    /// API Token（推荐）
+    ApiToken { api_token: String },
+    /// Global API Key + 账户邮箱（旧版）
+    GlobalApiKey { api_email: String, api_key: String },
+}
+
 /// 凭证枚举 - 类型安全的凭证定义
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "provider", content = "credentials")]
 pub enum ProviderCredentials {
     #[cfg(feature = "cloudflare")]
     #[serde(rename = "cloudflare")]
-    Cloudflare { api_token: String },
+    Cloudflare {
+        #[serde(flatten)]
+        auth: CloudflareAuth,
+    },
 
     #[cfg(feature = "aliyun")]
     #[serde(rename = "aliyun")]
@@ -379,6 +580,53 @@ pub enum ProviderCredentials {
         access_key_id: String,
         secret_access_key: String,
     },
+
+    #[cfg(feature = "gclouddns")]
+    #[serde(rename = "gclouddns")]
+    GcloudDns {
+        project_id: String,
+        /// 服务账号密钥 JSON（原文保存）
+        service_account_json: String,
+    },
+
+    #[cfg(feature = "namecheap")]
+    #[serde(rename = "namecheap")]
+    Namecheap {
+        api_user: String,
+        api_key: String,
+        username: String,
+        /// 已加入 API 白名单的调用方公网 IP
+        client_ip: String,
+    },
+
+    #[cfg(feature = "porkbun")]
+    #[serde(rename = "porkbun")]
+    Porkbun {
+        api_key: String,
+        secret_api_key: String,
+    },
+
+    #[cfg(feature = "rfc2136")]
+    #[serde(rename = "rfc2136")]
+    Rfc2136 {
+        /// 主服务器地址（主机名或 IP）
+        server: String,
+        port: u16,
+        zone: String,
+        tsig_key_name: String,
+        /// hmac-sha256 / hmac-sha384 / hmac-sha512
+        tsig_algorithm: String,
+        /// Base64 格式的 TSIG 密钥
+        tsig_secret: String,
+    },
+
+    #[cfg(feature = "mock")]
+    #[serde(rename = "mock")]
+    Mock {
+        /// 预置域名（逗号分隔），为空时使用默认演示数据
+        #[serde(default)]
+        seed: Option<String>,
+    },
 }
 
 impl ProviderCredentials {
@@ -390,7 +638,7 @@ impl ProviderCredentials {
         match provider {
             #[cfg(feature = "cloudflare")]
             ProviderType::Cloudflare => Ok(Self::Cloudflare {
-                api_token: Self::get_required_field(provider, map, "apiToken", "API Token")?,
+                auth: Self::cloudflare_auth_from_map(provider, map)?,
             }),
             #[cfg(feature = "aliyun")]
             ProviderType::Aliyun => Ok(Self::Aliyun {
@@ -427,6 +675,58 @@ impl ProviderCredentials {
                     "Secret Access Key",
                 )?,
             }),
+            #[cfg(feature = "gclouddns")]
+            ProviderType::GcloudDns => Ok(Self::GcloudDns {
+                project_id: Self::get_required_field(provider, map, "projectId", "Project ID")?,
+                service_account_json: Self::gcloud_service_account_from_map(provider, map)?,
+            }),
+            #[cfg(feature = "namecheap")]
+            ProviderType::Namecheap => Ok(Self::Namecheap {
+                api_user: Self::get_required_field(provider, map, "apiUser", "API User")?,
+                api_key: Self::get_required_field(provider, map, "apiKey", "API Key")?,
+                username: Self::get_required_field(provider, map, "username", "Username")?,
+                client_ip: Self::namecheap_client_ip_from_map(provider, map)?,
+            }),
+            #[cfg(feature = "porkbun")]
+            ProviderType::Porkbun => Ok(Self::Porkbun {
+                api_key: Self::get_required_field(provider, map, "apiKey", "API Key")?,
+                secret_api_key: Self::get_required_field(
+                    provider,
+                    map,
+                    "secretApiKey",
+                    "Secret API Key",
+                )?,
+            }),
+            #[cfg(feature = "rfc2136")]
+            ProviderType::Rfc2136 => Ok(Self::Rfc2136 {
+                server: Self::get_required_field(provider, map, "server", "Server")?,
+                port: Self::rfc2136_port_from_map(provider, map)?,
+                zone: Self::get_required_field(provider, map, "zone", "Zone")?,
+                tsig_key_name: Self::get_required_field(
+                    provider,
+                    map,
+                    "tsigKeyName",
+                    "TSIG Key Name",
+                )?,
+                tsig_algorithm: Self::rfc2136_checked_field(
+                    provider,
+                    map,
+                    "tsigAlgorithm",
+                    "TSIG Algorithm",
+                    crate::providers::rfc2136_tsig_algorithm,
+                )?,
+                tsig_secret: Self::rfc2136_checked_field(
+                    provider,
+                    map,
+                    "tsigSecret",
+                    "TSIG Secret",
+                    crate::providers::rfc2136_tsig_secret,
+                )?,
+            }),
+            #[cfg(feature = "mock")]
+            ProviderType::Mock => Ok(Self::Mock {
+                seed: map.get("seed").filter(|s| !s.trim().is_empty()).cloned(),
+            }),
             #[allow(unreachable_patterns)]
             _ => Err(CredentialValidationError::InvalidFormat {
                 provider: provider.clone(),
@@ -440,6 +740,119 @@ impl ProviderCredentials {
         }
     }
 
+    /// 解析 Cloudflare 认证方式：API Token 与 Global API Key + 邮箱必须且只能提供一组
+    #[cfg(feature = "cloudflare")]
+    fn cloudflare_auth_from_map(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+    ) -> Result<CloudflareAuth, CredentialValidationError> {
+        let present = |key: &str| map.get(key).is_some_and(|v| !v.trim().is_empty());
+        let has_token = present(CF_API_TOKEN_KEY);
+        let global_fields: Vec<&str> = [CF_API_EMAIL_KEY, CF_API_KEY_KEY]
+            .into_iter()
+            .filter(|k| present(k))
+            .collect();
+
+        if has_token && !global_fields.is_empty() {
+            return Err(CredentialValidationError::ConflictingFields {
+                provider: provider.clone(),
+                fields: std::iter::once(CF_API_TOKEN_KEY)
+                    .chain(global_fields)
+                    .map(str::to_string)
+                    .collect(),
+            });
+        }
+
+        if global_fields.is_empty() {
+            return Ok(CloudflareAuth::ApiToken {
+                api_token: Self::get_required_field(provider, map, CF_API_TOKEN_KEY, "API Token")?,
+            });
+        }
+
+        Ok(CloudflareAuth::GlobalApiKey {
+            api_email: Self::get_required_field(provider, map, CF_API_EMAIL_KEY, "Email")?,
+            api_key: Self::get_required_field(provider, map, CF_API_KEY_KEY, "Global API Key")?,
+        })
+    }
+
+    /// 读取 Google Cloud 服务账号 JSON，并校验包含签名所需的字段
+    #[cfg(feature = "gclouddns")]
+    fn gcloud_service_account_from_map(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+    ) -> Result<String, CredentialValidationError> {
+        let json = Self::get_required_field(
+            provider,
+            map,
+            GCLOUD_SERVICE_ACCOUNT_KEY,
+            "Service Account JSON",
+        )?;
+        crate::providers::gclouddns_service_account(&json).map_err(|reason| {
+            CredentialValidationError::InvalidFormat {
+                provider: provider.clone(),
+                field: GCLOUD_SERVICE_ACCOUNT_KEY.to_string(),
+                label: "Service Account JSON".to_string(),
+                reason,
+            }
+        })?;
+        Ok(json)
+    }
+
+    /// 读取 Namecheap 白名单 IP，校验为合法的 IP 地址
+    #[cfg(feature = "namecheap")]
+    fn namecheap_client_ip_from_map(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+    ) -> Result<String, CredentialValidationError> {
+        let client_ip = Self::get_required_field(provider, map, "clientIp", "Client IP")?;
+        match client_ip.trim().parse::<std::net::IpAddr>() {
+            Ok(ip) => Ok(ip.to_string()),
+            Err(e) => Err(CredentialValidationError::InvalidFormat {
+                provider: provider.clone(),
+                field: "clientIp".to_string(),
+                label: "Client IP".to_string(),
+                reason: e.to_string(),
+            }),
+        }
+    }
+
+    /// 读取 RFC 2136 服务器端口，校验为 1-65535 的整数
+    #[cfg(feature = "rfc2136")]
+    fn rfc2136_port_from_map(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+    ) -> Result<u16, CredentialValidationError> {
+        let port = Self::get_required_field(provider, map, "port", "Port")?;
+        match port.trim().parse::<u16>() {
+            Ok(port) if port > 0 => Ok(port),
+            _ => Err(CredentialValidationError::InvalidFormat {
+                provider: provider.clone(),
+                field: "port".to_string(),
+                label: "Port".to_string(),
+                reason: format!("端口必须是 1-65535 之间的整数: {port}"),
+            }),
+        }
+    }
+
+    /// 读取 RFC 2136 必需字段并用 `check` 校验格式
+    #[cfg(feature = "rfc2136")]
+    fn rfc2136_checked_field(
+        provider: &ProviderType,
+        map: &std::collections::HashMap<String, String>,
+        key: &str,
+        label: &str,
+        check: fn(&str) -> Result<(), String>,
+    ) -> Result<String, CredentialValidationError> {
+        let value = Self::get_required_field(provider, map, key, label)?;
+        check(&value).map_err(|reason| CredentialValidationError::InvalidFormat {
+            provider: provider.clone(),
+            field: key.to_string(),
+            label: label.to_string(),
+            reason,
+        })?;
+        Ok(value)
+    }
+
     /// 从 HashMap 中获取必需字段，校验非空
     fn get_required_field(
         provider: &ProviderType,
@@ -465,7 +878,16 @@ impl ProviderCredentials {
     /// 转换为 HashMap（保存时用，保持存储格式兼容）
     pub fn to_map(&self) -> std::collections::HashMap<String, String> {
         match self {
-            Self::Cloudflare { api_token } => [("apiToken".to_string(), api_token.clone())].into(),
+            Self::Cloudflare {
+                auth: CloudflareAuth::ApiToken { api_token },
+            } => [(CF_API_TOKEN_KEY.to_string(), api_token.clone())].into(),
+            Self::Cloudflare {
+                auth: CloudflareAuth::GlobalApiKey { api_email, api_key },
+            } => [
+                (CF_API_EMAIL_KEY.to_string(), api_email.clone()),
+                (CF_API_KEY_KEY.to_string(), api_key.clone()),
+            ]
+            .into(),
             Self::Aliyun {
                 access_key_id,
                 access_key_secret,
@@ -490,6 +912,58 @@ impl ProviderCredentials {
                 ("secretAccessKey".to_string(), secret_access_key.clone()),
             ]
             .into(),
+            Self::GcloudDns {
+                project_id,
+                service_account_json,
+            } => [
+                ("projectId".to_string(), project_id.clone()),
+                (
+                    GCLOUD_SERVICE_ACCOUNT_KEY.to_string(),
+                    service_account_json.clone(),
+                ),
+            ]
+            .into(),
+            Self::Namecheap {
+                api_user,
+                api_key,
+                username,
+                client_ip,
+            } => [
+                ("apiUser".to_string(), api_user.clone()),
+                ("apiKey".to_string(), api_key.clone()),
+                ("username".to_string(), username.clone()),
+                ("clientIp".to_string(), client_ip.clone()),
+            ]
+            .into(),
+            Self::Porkbun {
+                api_key,
+                secret_api_key,
+            } => [
+                ("apiKey".to_string(), api_key.clone()),
+                ("secretApiKey".to_string(), secret_api_key.clone()),
+            ]
+            .into(),
+            Self::Rfc2136 {
+                server,
+                port,
+                zone,
+                tsig_key_name,
+                tsig_algorithm,
+                tsig_secret,
+            } => [
+                ("server".to_string(), server.clone()),
+                ("port".to_string(), port.to_string()),
+                ("zone".to_string(), zone.clone()),
+                ("tsigKeyName".to_string(), tsig_key_name.clone()),
+                ("tsigAlgorithm".to_string(), tsig_algorithm.clone()),
+                ("tsigSecret".to_string(), tsig_secret.clone()),
+            ]
+            .into(),
+            #[cfg(feature = "mock")]
+            Self::Mock { seed } => seed
+                .iter()
+                .map(|s| ("seed".to_string(), s.clone()))
+                .collect(),
         }
     }
 
@@ -500,6 +974,165 @@ impl ProviderCredentials {
             Self::Aliyun { .. } => ProviderType::Aliyun,
             Self::Dnspod { .. } => ProviderType::Dnspod,
             Self::Huaweicloud { .. } => ProviderType::Huaweicloud,
+            Self::GcloudDns { .. } => ProviderType::GcloudDns,
+            Self::Namecheap { .. } => ProviderType::Namecheap,
+            Self::Porkbun { .. } => ProviderType::Porkbun,
+            Self::Rfc2136 { .. } => ProviderType::Rfc2136,
+            #[cfg(feature = "mock")]
+            Self::Mock { .. } => ProviderType::Mock,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn cf_map(fields: &[(&str, &str)]) -> HashMap<String, String> {
+        fields
+            .iter()
+            .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+            .collect()
+    }
+
+    fn cf_auth(fields: &[(&str, &str)]) -> Result<CloudflareAuth, CredentialValidationError> {
+        match ProviderCredentials::from_map(&ProviderType::Cloudflare, &cf_map(fields))? {
+            ProviderCredentials::Cloudflare { auth } => Ok(auth),
+            other => panic!("unexpected credentials: {other:?}"),
         }
     }
+
+    #[test]
+    fn test_cloudflare_token_mode() {
+        let auth = cf_auth(&[("apiToken", "t")]).unwrap();
+        assert!(matches!(auth, CloudflareAuth::ApiToken { api_token } if api_token == "t"));
+
+        // 空的另一组字段视为未提供
+        let auth = cf_auth(&[("apiToken", "t"), ("apiEmail", ""), ("globalApiKey", " ")]).unwrap();
+        assert!(matches!(auth, CloudflareAuth::ApiToken { .. }));
+    }
+
+    #[test]
+    fn test_cloudflare_global_key_mode() {
+        let auth = cf_auth(&[("apiEmail", "a@b.c"), ("globalApiKey", "k")]).unwrap();
+        assert!(matches!(
+            auth,
+            CloudflareAuth::GlobalApiKey { api_email, api_key } if api_email == "a@b.c" && api_key == "k"
+        ));
+    }
+
+    #[test]
+    fn test_cloudflare_mixed_modes_rejected() {
+        let err = cf_auth(&[("apiToken", "t"), ("apiEmail", "a@b.c")]).unwrap_err();
+        assert!(
+            matches!(err, CredentialValidationError::ConflictingFields { fields, .. } if fields == ["apiToken", "apiEmail"])
+        );
+
+        let err = cf_auth(&[
+            ("apiToken", "t"),
+            ("apiEmail", "a@b.c"),
+            ("globalApiKey", "k"),
+        ])
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            CredentialValidationError::ConflictingFields { .. }
+        ));
+    }
+
+    #[test]
+    fn test_cloudflare_incomplete_rejected() {
+        let err = cf_auth(&[("apiEmail", "a@b.c")]).unwrap_err();
+        assert!(
+            matches!(err, CredentialValidationError::MissingField { field, .. } if field == "globalApiKey")
+        );
+
+        let err = cf_auth(&[("globalApiKey", "k")]).unwrap_err();
+        assert!(
+            matches!(err, CredentialValidationError::MissingField { field, .. } if field == "apiEmail")
+        );
+
+        let err = cf_auth(&[]).unwrap_err();
+        assert!(
+            matches!(err, CredentialValidationError::MissingField { field, .. } if field == "apiToken")
+        );
+
+        let err = cf_auth(&[("apiToken", "")]).unwrap_err();
+        assert!(matches!(err, CredentialValidationError::EmptyField { .. }));
+    }
+
+    #[test]
+    fn test_cloudflare_credentials_serde_shape() {
+        let creds = ProviderCredentials::Cloudflare {
+            auth: CloudflareAuth::ApiToken {
+                api_token: "t".to_string(),
+            },
+        };
+        let json = serde_json::to_value(&creds).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "provider": "cloudflare", "credentials": { "api_token": "t" } })
+        );
+
+        let back: ProviderCredentials = serde_json::from_value(json).unwrap();
+        assert_eq!(back.to_map(), cf_map(&[("apiToken", "t")]));
+    }
+
+    #[cfg(feature = "rfc2136")]
+    #[test]
+    fn test_rfc2136_credentials_validated() {
+        let fields = [
+            ("server", "ns1.example.com"),
+            ("port", "53"),
+            ("zone", "example.com"),
+            ("tsigKeyName", "update-key"),
+            ("tsigAlgorithm", "hmac-sha256"),
+            ("tsigSecret", "c2VjcmV0"),
+        ];
+        let map = cf_map(&fields);
+        let creds = ProviderCredentials::from_map(&ProviderType::Rfc2136, &map).unwrap();
+        assert!(matches!(
+            creds,
+            ProviderCredentials::Rfc2136 { port: 53, .. }
+        ));
+        assert_eq!(creds.to_map(), map);
+
+        for (key, value) in [
+            ("port", "0"),
+            ("port", "dns"),
+            ("tsigAlgorithm", "hmac-md5"),
+            ("tsigSecret", "not base64!"),
+        ] {
+            let mut invalid = map.clone();
+            invalid.insert(key.to_string(), value.to_string());
+            let err = ProviderCredentials::from_map(&ProviderType::Rfc2136, &invalid).unwrap_err();
+            assert!(
+                matches!(err, CredentialValidationError::InvalidFormat { ref field, .. } if field == key),
+                "{key}={value}: {err:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cursor_pagination() {
+        // 旧客户端不带 cursor 字段时仍可反序列化
+        let params: RecordQueryParams =
+            serde_json::from_value(serde_json::json!({ "page": 2, "pageSize": 50 })).unwrap();
+        assert_eq!(params.cursor, None);
+
+        let page = PaginatedResponse::new(vec![1, 2], 1, 2, 0);
+        assert!(!page.has_more);
+        assert!(
+            serde_json::to_value(&page)
+                .unwrap()
+                .get("nextCursor")
+                .is_none()
+        );
+
+        let page = page.with_next_cursor(Some("abc".to_string()));
+        assert!(page.has_more);
+        assert_eq!(serde_json::to_value(&page).unwrap()["nextCursor"], "abc");
+    }
 }
@@ -0,0 +1,411 @@
+//! 记录内容校验
+//!
+//! 在请求发往服务商之前，按记录类型检查名称和值的格式，
+//! 避免 A 记录填主机名、AAAA 填 IPv4 这类错误只能得到服务商含糊的报错。
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::error::{ProviderError, Result};
+use crate::factory::provider_metadata;
+use crate::identity::is_apex_name;
+use crate::providers::common::{
+    CaaValue, SrvValue, SvcbValue, record_type_to_string, unsupported_record_type,
+};
+use crate::types::DnsRecordType;
+
+/// 单个 TXT 字符串的最大长度（RFC 1035）
+const TXT_STRING_MAX_LEN: usize = 255;
+
+/// TXT 记录值的最大总长度
+const TXT_MAX_LEN: usize = 4096;
+
+/// 完整域名的最大长度
+const HOSTNAME_MAX_LEN: usize = 253;
+
+/// 单个标签的最大长度
+const LABEL_MAX_LEN: usize = 63;
+
+/// 校验记录的名称、值和优先级是否符合记录类型
+///
+/// - A / AAAA：值必须是对应版本的 IP 地址
+/// - CNAME / NS / PTR：值必须是合法主机名
+/// - MX：必须有优先级（单独给出或写在值前），目标为主机名或 `.`（Null MX）
+/// - SRV：必须有优先级，值为 `<weight> <port> <target>`
+/// - TXT：每个带引号的字符串不超过 255 字节，总长不超过 4096 字节
+/// - CAA：值为 `<flags> <tag> <value>`
+/// - HTTPS / SVCB：值为 `<priority> <target> [<key>=<value> ...]`，目标为 `.` 或主机名
+/// - ALIAS：只能位于根域名（`@`），值必须是合法主机名
+pub fn validate_record(
+    record_type: &DnsRecordType,
+    name: &str,
+    value: &str,
+    priority: Option<u16>,
+    provider: &str,
+) -> Result<()> {
+    let invalid = |detail: String| ProviderError::InvalidRecord {
+        provider: provider.to_string(),
+        record_type: record_type_to_string(record_type).to_string(),
+        detail,
+    };
+
+    if !is_valid_record_name(name) {
+        return Err(invalid(format!("记录名称无效: {name}")));
+    }
+
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(invalid("记录值不能为空".to_string()));
+    }
+
+    match record_type {
+        DnsRecordType::A => {
+            if value.parse::<Ipv4Addr>().is_err() {
+                return Err(invalid(format!("A 记录值必须是 IPv4 地址: {value}")));
+            }
+        }
+        DnsRecordType::Aaaa => {
+            if value.parse::<Ipv6Addr>().is_err() {
+                return Err(invalid(format!("AAAA 记录值必须是 IPv6 地址: {value}")));
+            }
+        }
+        DnsRecordType::Cname | DnsRecordType::Ns | DnsRecordType::Ptr => {
+            if !is_valid_hostname(value) {
+                return Err(invalid(format!("记录值必须是合法主机名: {value}")));
+            }
+        }
+        DnsRecordType::Mx => {
+            let (priority, target) = match value.split_whitespace().collect::<Vec<_>>()[..] {
+                [p, target] => (p.parse::<u16>().ok(), target),
+                _ => (priority, value),
+            };
+            if priority.is_none() {
+                return Err(invalid("MX 记录必须指定优先级".to_string()));
+            }
+            if target != "." && !is_valid_hostname(target) {
+                return Err(invalid(format!("MX 目标必须是合法主机名: {target}")));
+            }
+        }
+        DnsRecordType::Srv => {
+            let srv = SrvValue::parse(value, priority).ok_or_else(|| {
+                invalid(format!(
+                    "SRV 记录值格式应为 \"<weight> <port> <target>\" 并指定优先级: {value}"
+                ))
+            })?;
+            if !is_valid_hostname(&srv.target) {
+                return Err(invalid(format!("SRV 目标必须是合法主机名: {}", srv.target)));
+            }
+        }
+        DnsRecordType::Alias => {
+            if !is_apex_name(name) {
+                return Err(invalid(format!("ALIAS 记录只能添加在根域名（@）: {name}")));
+            }
+            if !is_valid_hostname(value) {
+                return Err(invalid(format!("记录值必须是合法主机名: {value}")));
+            }
+        }
+        DnsRecordType::Txt => validate_txt(value).map_err(invalid)?,
+        DnsRecordType::Caa => {
+            let caa = CaaValue::parse(value).ok_or_else(|| {
+                invalid(format!(
+                    "CAA 记录值格式应为 <flags> <tag> \"<value>\": {value}"
+                ))
+            })?;
+            if caa.value.is_empty() && caa.tag != "issue" && caa.tag != "issuewild" {
+                return Err(invalid(format!("CAA {} 的值不能为空", caa.tag)));
+            }
+        }
+        DnsRecordType::Https | DnsRecordType::Svcb => {
+            let svcb = SvcbValue::parse(value).ok_or_else(|| {
+                invalid(format!(
+                    "记录值格式应为 \"<priority> <target> <params>\": {value}"
+                ))
+            })?;
+            if svcb.target != "." && !is_valid_hostname(&svcb.target) {
+                return Err(invalid(format!(
+                    "目标必须是 . 或合法主机名: {}",
+                    svcb.target
+                )));
+            }
+            if let Some(key) = invalid_svc_param_key(&svcb.params) {
+                return Err(invalid(format!("无效的服务参数: {key}")));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 按服务商元数据检查记录是否可提交
+///
+/// - 记录类型必须在 `supported_record_types` 中
+/// - 权重仅支持 `features.weight` 的服务商可设置，且只适用于 A/AAAA/CNAME 记录
+/// - TTL 不能超过 `limits.max_ttl`；低于 `limits.min_ttl` 只记录警告，
+///   最小值通常取决于套餐（如付费版允许更低的 TTL），由服务商最终判断。
+///   0（省略）与服务商的默认 TTL（如 Cloudflare 用 1 表示"自动"）总是允许
+///
+/// 未知的服务商（如自定义实现）不做检查
+pub fn validate_record_support(
+    provider: &str,
+    record_type: &DnsRecordType,
+    weight: Option<u16>,
+    ttl: u32,
+) -> Result<()> {
+    let Some(metadata) = provider_metadata(provider) else {
+        return Ok(());
+    };
+    let invalid = |detail: String| ProviderError::InvalidRecord {
+        provider: provider.to_string(),
+        record_type: record_type_to_string(record_type).to_string(),
+        detail,
+    };
+
+    if !metadata.supported_record_types.contains(record_type) {
+        return Err(unsupported_record_type(record_type, provider));
+    }
+
+    if weight.is_some() {
+        if !metadata.features.weight {
+            return Err(invalid("该服务商不支持记录权重".to_string()));
+        }
+        if !matches!(
+            record_type,
+            DnsRecordType::A | DnsRecordType::Aaaa | DnsRecordType::Cname
+        ) {
+            return Err(invalid("仅 A/AAAA/CNAME 记录支持设置权重".to_string()));
+        }
+    }
+
+    let limits = &metadata.limits;
+    if ttl == 0 || ttl == limits.default_ttl {
+        return Ok(());
+    }
+    if ttl > limits.max_ttl {
+        return Err(invalid(format!(
+            "TTL 不能超过 {} 秒: {ttl}",
+            limits.max_ttl
+        )));
+    }
+    if ttl < limits.min_ttl {
+        log::warn!(
+            "[{provider}] TTL {ttl} 低于默认套餐的最小值 {} 秒，可能被服务商拒绝",
+            limits.min_ttl
+        );
+    }
+    Ok(())
+}
+
+/// 查找第一个不合法的服务参数名（小写字母、数字和 `-`），引号内的空格不视为分隔
+fn invalid_svc_param_key(params: &str) -> Option<&str> {
+    let mut in_quote = false;
+    for token in params.split_whitespace() {
+        if !in_quote {
+            let key = token.split('=').next().unwrap_or_default();
+            let valid = !key.is_empty()
+                && key
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+            if !valid {
+                return Some(token);
+            }
+        }
+        if token.matches('"').count() % 2 == 1 {
+            in_quote = !in_quote;
+        }
+    }
+    None
+}
+
+/// 校验 TXT 记录值长度
+fn validate_txt(value: &str) -> std::result::Result<(), String> {
+    if value.len() > TXT_MAX_LEN {
+        return Err(format!(
+            "TXT 记录值过长（{} 字节，最多 {TXT_MAX_LEN}）",
+            value.len()
+        ));
+    }
+    // 带引号时按字符串逐段检查；未加引号的长值由服务商自动拆分
+    if value.starts_with('"') {
+        for segment in value.split('"').skip(1).step_by(2) {
+            if segment.len() > TXT_STRING_MAX_LEN {
+                return Err(format!(
+                    "TXT 记录的单个字符串不能超过 {TXT_STRING_MAX_LEN} 字节"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 记录名称：`@`、相对名称或完整域名，首个标签可为通配符 `*`
+fn is_valid_record_name(name: &str) -> bool {
+    let name = name.trim();
+    if name.is_empty() || name == "@" || name == "*" {
+        return true;
+    }
+    let rest = name.strip_prefix("*.").unwrap_or(name);
+    is_valid_hostname(rest)
+}
+
+/// 主机名：可带末尾的点，标签由字母、数字、`-`、`_` 组成，不以 `-` 开头或结尾
+fn is_valid_hostname(host: &str) -> bool {
+    let host = host.strip_suffix('.').unwrap_or(host);
+    if host.is_empty() || host.len() > HOSTNAME_MAX_LEN {
+        return false;
+    }
+    host.split('.').all(|label| {
+        !label.is_empty()
+            && label.len() <= LABEL_MAX_LEN
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(record_type: DnsRecordType, value: &str, priority: Option<u16>) -> Result<()> {
+        validate_record(&record_type, "www", value, priority, "test")
+    }
+
+    fn assert_invalid(result: Result<()>) {
+        assert!(
+            matches!(result, Err(ProviderError::InvalidRecord { .. })),
+            "expected InvalidRecord, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_record_name() {
+        for name in [
+            "@",
+            "",
+            "www",
+            "*",
+            "*.dev",
+            "_sip._tcp",
+            "a.b.example.com.",
+        ] {
+            assert!(
+                validate_record(&DnsRecordType::A, name, "1.1.1.1", None, "test").is_ok(),
+                "{name}"
+            );
+        }
+        for name in ["-www", "a..b", "www.*", &"a".repeat(64)] {
+            assert_invalid(validate_record(
+                &DnsRecordType::A,
+                name,
+                "1.1.1.1",
+                None,
+                "test",
+            ));
+        }
+    }
+
+    #[test]
+    fn test_a_and_aaaa() {
+        assert!(check(DnsRecordType::A, "192.0.2.1", None).is_ok());
+        assert_invalid(check(DnsRecordType::A, "example.com", None));
+        assert_invalid(check(DnsRecordType::A, "2001:db8::1", None));
+        assert_invalid(check(DnsRecordType::A, "", None));
+
+        assert!(check(DnsRecordType::Aaaa, "2001:db8::1", None).is_ok());
+        assert_invalid(check(DnsRecordType::Aaaa, "192.0.2.1", None));
+    }
+
+    #[test]
+    fn test_hostname_targets() {
+        for record_type in [DnsRecordType::Cname, DnsRecordType::Ns, DnsRecordType::Ptr] {
+            assert!(check(record_type.clone(), "target.example.com.", None).is_ok());
+            assert_invalid(check(record_type.clone(), "not a host", None));
+            assert_invalid(check(record_type, "http://example.com", None));
+        }
+    }
+
+    #[test]
+    fn test_mx() {
+        assert!(check(DnsRecordType::Mx, "mx.example.com", Some(10)).is_ok());
+        assert!(check(DnsRecordType::Mx, "10 mx.example.com.", None).is_ok());
+        assert!(check(DnsRecordType::Mx, ".", Some(0)).is_ok());
+        assert_invalid(check(DnsRecordType::Mx, "mx.example.com", None));
+        assert_invalid(check(DnsRecordType::Mx, "192.0.2.1:25", Some(10)));
+    }
+
+    #[test]
+    fn test_srv() {
+        assert!(check(DnsRecordType::Srv, "5 5060 sip.example.com", Some(1)).is_ok());
+        assert!(check(DnsRecordType::Srv, "1 5 5060 sip.example.com.", None).is_ok());
+        assert_invalid(check(DnsRecordType::Srv, "5 5060 sip.example.com", None));
+        assert_invalid(check(
+            DnsRecordType::Srv,
+            "5 99999 sip.example.com",
+            Some(1),
+        ));
+        assert_invalid(check(DnsRecordType::Srv, "sip.example.com", Some(1)));
+    }
+
+    #[test]
+    fn test_txt() {
+        assert!(check(DnsRecordType::Txt, "v=spf1 -all", None).is_ok());
+        // 未加引号的长值（如 DKIM 公钥）允许超过 255 字节
+        assert!(check(DnsRecordType::Txt, &"k".repeat(400), None).is_ok());
+        let split = format!("\"{}\" \"{}\"", "a".repeat(255), "b".repeat(100));
+        assert!(check(DnsRecordType::Txt, &split, None).is_ok());
+
+        assert_invalid(check(
+            DnsRecordType::Txt,
+            &format!("\"{}\"", "a".repeat(256)),
+            None,
+        ));
+        assert_invalid(check(DnsRecordType::Txt, &"k".repeat(4097), None));
+    }
+
+    #[test]
+    fn test_https_svcb() {
+        assert!(check(DnsRecordType::Https, "1 . alpn=\"h3,h2\"", None).is_ok());
+        assert!(check(DnsRecordType::Https, "0 cdn.example.com.", None).is_ok());
+        assert!(
+            check(
+                DnsRecordType::Svcb,
+                "1 svc.example.net alpn=\"h2 h3\" port=8443 key65000=x",
+                None
+            )
+            .is_ok()
+        );
+        assert_invalid(check(DnsRecordType::Https, "cdn.example.com", None));
+        assert_invalid(check(DnsRecordType::Https, "70000 . alpn=h3", None));
+        assert_invalid(check(DnsRecordType::Svcb, "1 bad_host! alpn=h3", None));
+        assert_invalid(check(DnsRecordType::Svcb, "1 . ALPN=h3", None));
+    }
+
+    #[test]
+    fn test_alias_only_at_apex() {
+        for name in ["@", ""] {
+            assert!(
+                validate_record(&DnsRecordType::Alias, name, "lb.example.net", None, "test")
+                    .is_ok()
+            );
+        }
+        assert_invalid(check(DnsRecordType::Alias, "lb.example.net", None));
+        assert_invalid(validate_record(
+            &DnsRecordType::Alias,
+            "@",
+            "192.0.2.1:80",
+            None,
+            "test",
+        ));
+    }
+
+    #[test]
+    fn test_caa() {
+        assert!(check(DnsRecordType::Caa, "0 issue \"letsencrypt.org\"", None).is_ok());
+        assert!(check(DnsRecordType::Caa, "0 issue letsencrypt.org", None).is_ok());
+        assert!(check(DnsRecordType::Caa, "0 issue \";\"", None).is_ok());
+        assert_invalid(check(DnsRecordType::Caa, "letsencrypt.org", None));
+        assert_invalid(check(DnsRecordType::Caa, "256 issue \"ca.example\"", None));
+        assert_invalid(check(DnsRecordType::Caa, "0 iss-ue \"ca.example\"", None));
+    }
+}
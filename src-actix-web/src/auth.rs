@@ -0,0 +1,130 @@
+//! API 密钥认证中间件
+//!
+//! 除健康检查外的所有请求都需携带 `Authorization: Bearer <key>`，否则返回 401。
+//! 未配置密钥时默认拒绝全部请求，仅在显式开启 `security.allow_anonymous` 时放行；
+//! 管理接口（`/admin/` 下）始终要求有效密钥。
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header;
+use actix_web::middleware::Next;
+use actix_web::{Error, web};
+
+use crate::error::ApiError;
+use crate::state::AppState;
+
+/// 无需认证的路径（供负载均衡 / 编排系统探测）
+const PUBLIC_PATHS: &[&str] = &["/health", "/ready"];
+
+/// 始终需要认证的路径前缀（不受 `allow_anonymous` 影响）
+const ADMIN_PREFIX: &str = "/admin/";
+
+/// API 密钥认证
+pub async fn require_api_key(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let Some(state) = req.app_data::<web::Data<AppState>>().cloned() else {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    };
+    let is_admin = req.path().starts_with(ADMIN_PREFIX);
+    if (state.allows_anonymous() && !is_admin) || PUBLIC_PATHS.contains(&req.path()) {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    }
+
+    let token = req
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim);
+
+    let Some(label) = token.and_then(|t| state.authenticate(t)) else {
+        let response = ApiError::Unauthorized.response_for(req.request());
+        return Ok(req.into_response(response).map_into_right_body());
+    };
+    tracing::debug!(api_key = label, "authenticated");
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_left_body)
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::middleware::from_fn;
+    use actix_web::test as actix_test;
+    use actix_web::{App, HttpResponse};
+
+    use super::*;
+    use crate::config::{ApiKeyConfig, AppConfig};
+
+    fn config(keys: &[&str]) -> AppConfig {
+        let mut config = AppConfig::default();
+        config.security.api_keys = keys
+            .iter()
+            .map(|key| ApiKeyConfig {
+                label: "test".to_string(),
+                key: (*key).to_string(),
+            })
+            .collect();
+        config
+    }
+
+    async fn status(config: &AppConfig, path: &str, token: Option<&str>) -> u16 {
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(web::Data::new(AppState::new(config, None)))
+                .wrap(from_fn(require_api_key))
+                .route("/health", web::get().to(HttpResponse::Ok))
+                .route("/ping", web::get().to(HttpResponse::Ok))
+                .route("/admin/maintenance", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        let mut req = actix_test::TestRequest::get().uri(path);
+        if let Some(token) = token {
+            req = req.insert_header((header::AUTHORIZATION, format!("Bearer {token}")));
+        }
+        actix_test::call_service(&app, req.to_request())
+            .await
+            .status()
+            .as_u16()
+    }
+
+    #[actix_web::test]
+    async fn test_api_key_checked() {
+        let config = config(&["old-key", "new-key"]);
+        assert_eq!(status(&config, "/ping", None).await, 401);
+        assert_eq!(status(&config, "/ping", Some("wrong")).await, 401);
+        assert_eq!(status(&config, "/ping", Some("old-key")).await, 200);
+        assert_eq!(status(&config, "/ping", Some("new-key")).await, 200);
+        assert_eq!(status(&config, "/health", None).await, 200);
+    }
+
+    #[actix_web::test]
+    async fn test_no_keys_fails_closed() {
+        let mut config = config(&[]);
+        assert_eq!(status(&config, "/ping", None).await, 401);
+        assert_eq!(status(&config, "/ping", Some("anything")).await, 401);
+        assert_eq!(status(&config, "/health", None).await, 200);
+
+        config.security.allow_anonymous = true;
+        assert_eq!(status(&config, "/ping", None).await, 200);
+    }
+
+    #[actix_web::test]
+    async fn test_admin_requires_key() {
+        let mut anonymous = config(&[]);
+        anonymous.security.allow_anonymous = true;
+        assert_eq!(status(&anonymous, "/admin/maintenance", None).await, 401);
+
+        let keyed = config(&["key"]);
+        assert_eq!(status(&keyed, "/admin/maintenance", None).await, 401);
+        assert_eq!(status(&keyed, "/admin/maintenance", Some("key")).await, 200);
+    }
+}
@@ -0,0 +1,256 @@
+//! 服务配置
+//!
+//! 从 TOML 文件加载，文件不存在时使用默认值。
+//! 配置文件路径可通过环境变量 `DNS_ORCHESTRATOR_CONFIG` 指定，默认为 `config.toml`。
+
+use std::path::Path;
+
+use actix_web::middleware::{Compress, Condition};
+use anyhow::Context;
+use serde::Deserialize;
+
+/// 配置文件路径环境变量
+const CONFIG_PATH_ENV: &str = "DNS_ORCHESTRATOR_CONFIG";
+
+/// 默认配置文件路径
+const DEFAULT_CONFIG_PATH: &str = "config.toml";
+
+/// 应用配置
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    /// HTTP 服务配置
+    pub server: ServerConfig,
+    /// 维护模式配置
+    pub maintenance: MaintenanceConfig,
+    /// 数据库配置
+    pub database: DatabaseConfig,
+    /// 安全配置
+    pub security: SecurityConfig,
+}
+
+/// 安全配置
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct SecurityConfig {
+    /// API 密钥（请求需携带 `Authorization: Bearer <key>`），支持多个密钥以便轮换。
+    /// 为空且未开启 `allow_anonymous` 时，除健康检查外的请求一律返回 401
+    pub api_keys: Vec<ApiKeyConfig>,
+    /// 未配置 API 密钥时允许匿名访问（仅用于本地开发，管理接口仍不可用）
+    pub allow_anonymous: bool,
+}
+
+/// API 密钥
+#[derive(Clone, Deserialize)]
+pub struct ApiKeyConfig {
+    /// 标签（用于日志区分调用方，如 `ci`、`grafana`）
+    pub label: String,
+    /// 密钥
+    pub key: String,
+}
+
+impl std::fmt::Debug for ApiKeyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ApiKeyConfig")
+            .field("label", &self.label)
+            .field("key", &"***")
+            .finish()
+    }
+}
+
+/// 数据库配置
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DatabaseConfig {
+    /// 连接串（如 `sqlite://data.db?mode=rwc`），未配置时不连接数据库
+    pub url: Option<String>,
+}
+
+/// 维护模式配置
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MaintenanceConfig {
+    /// 以只读模式启动（可通过 `PUT /admin/maintenance` 在运行时切换）
+    pub read_only: bool,
+}
+
+/// HTTP 服务配置
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ServerConfig {
+    /// 监听地址
+    pub host: String,
+    /// 监听端口
+    pub port: u16,
+    /// 工作线程数（0 表示使用 CPU 核心数）
+    pub workers: usize,
+    /// 是否启用响应压缩（gzip/brotli/zstd，按 `Accept-Encoding` 协商），调试时可关闭
+    pub compression: bool,
+    /// 跨域配置
+    pub cors: CorsConfig,
+    /// 优雅停机超时秒数：收到 SIGTERM/SIGINT 后停止接受新连接，
+    /// 最多等待该时长让进行中的请求完成，超时后强制关闭
+    pub shutdown_timeout: u64,
+}
+
+/// 跨域（CORS）配置
+///
+/// 默认不允许任何跨域来源（仅同源访问）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct CorsConfig {
+    /// 允许的来源（如 `https://dns.example.com`），`"*"` 表示任意来源（仅用于开发）
+    pub allowed_origins: Vec<String>,
+    /// 允许的方法
+    pub allowed_methods: Vec<String>,
+    /// 是否允许携带凭据（Cookie / `Authorization`），开启时不能使用 `"*"` 来源
+    pub allow_credentials: bool,
+    /// 预检结果缓存秒数
+    pub max_age: u32,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: ["GET", "POST", "PUT", "DELETE"].map(String::from).to_vec(),
+            allow_credentials: false,
+            max_age: 600,
+        }
+    }
+}
+
+impl CorsConfig {
+    /// 是否允许任意来源
+    pub fn allows_any_origin(&self) -> bool {
+        self.allowed_origins.iter().any(|o| o == "*")
+    }
+
+    /// 是否允许该来源
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allows_any_origin()
+            || self
+                .allowed_origins
+                .iter()
+                .any(|o| o.trim_end_matches('/').eq_ignore_ascii_case(origin))
+    }
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 8080,
+            workers: 0,
+            compression: true,
+            cors: CorsConfig::default(),
+            shutdown_timeout: 30,
+        }
+    }
+}
+
+impl ServerConfig {
+    /// 实际使用的工作线程数
+    pub fn worker_count(&self) -> usize {
+        if self.workers == 0 {
+            num_cpus::get()
+        } else {
+            self.workers
+        }
+    }
+
+    /// 响应压缩中间件（按 `compression` 开关启用）
+    pub fn compress(&self) -> Condition<Compress> {
+        Condition::new(self.compression, Compress::default())
+    }
+}
+
+impl AppConfig {
+    /// 加载配置（环境变量指定的路径优先）
+    pub fn load() -> anyhow::Result<Self> {
+        let path = std::env::var(CONFIG_PATH_ENV).unwrap_or_else(|_| DEFAULT_CONFIG_PATH.into());
+        Self::load_from(Path::new(&path))
+    }
+
+    /// 从指定路径加载配置，文件不存在时返回默认配置
+    pub fn load_from(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            tracing::info!("配置文件 {} 不存在，使用默认配置", path.display());
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("读取配置文件失败: {}", path.display()))?;
+        let config: Self = toml::from_str(&content)
+            .with_context(|| format!("解析配置文件失败: {}", path.display()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 校验配置
+    fn validate(&self) -> anyhow::Result<()> {
+        // CORS 规范不允许携带凭据时使用通配来源
+        anyhow::ensure!(
+            !(self.server.cors.allow_credentials && self.server.cors.allows_any_origin()),
+            "server.cors.allow_credentials 开启时 allowed_origins 不能包含 \"*\""
+        );
+        for api_key in &self.security.api_keys {
+            anyhow::ensure!(
+                !api_key.key.trim().is_empty(),
+                "API 密钥 {} 不能为空",
+                api_key.label
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::http::header;
+    use actix_web::test as actix_test;
+    use actix_web::{App, HttpResponse, web};
+
+    use super::*;
+
+    #[test]
+    fn test_compression_enabled_by_default() {
+        let config = AppConfig::load_from(Path::new("does-not-exist.toml")).unwrap_or_default();
+        assert!(config.server.compression);
+    }
+
+    #[test]
+    fn test_compression_can_be_disabled() {
+        let config: AppConfig =
+            toml::from_str("[server]\ncompression = false\n").unwrap_or_default();
+        assert!(!config.server.compression);
+    }
+
+    async fn content_encoding(compression: bool) -> Option<String> {
+        let server = ServerConfig {
+            compression,
+            ..ServerConfig::default()
+        };
+        let app = actix_test::init_service(App::new().wrap(server.compress()).route(
+            "/data",
+            web::get().to(|| async { HttpResponse::Ok().body("x".repeat(4096)) }),
+        ))
+        .await;
+
+        let req = actix_test::TestRequest::get()
+            .uri("/data")
+            .insert_header((header::ACCEPT_ENCODING, "gzip"))
+            .to_request();
+        let res = actix_test::call_service(&app, req).await;
+        res.headers()
+            .get(header::CONTENT_ENCODING)
+            .and_then(|v| v.to_str().ok())
+            .map(ToString::to_string)
+    }
+
+    #[actix_web::test]
+    async fn test_compress_follows_config() {
+        assert_eq!(content_encoding(true).await.as_deref(), Some("gzip"));
+        assert_eq!(content_encoding(false).await, None);
+    }
+}
@@ -0,0 +1,184 @@
+//! 跨域（CORS）中间件
+//!
+//! 按 `server.cors` 配置处理跨域请求：
+//! - 允许的来源：预检请求直接返回 204，普通请求在响应上附加 `Access-Control-Allow-*` 头
+//! - 不允许的来源：预检请求返回 403，普通请求照常处理但不附加 CORS 头（由浏览器拦截）
+//! - 无 `Origin` 头的同源请求不受影响
+
+use actix_web::body::{EitherBody, MessageBody};
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::Method;
+use actix_web::http::header::{self, HeaderMap, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpResponse, web};
+
+use crate::config::CorsConfig;
+use crate::state::AppState;
+
+/// CORS 中间件
+pub async fn cors(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<EitherBody<impl MessageBody>>, Error> {
+    let origin = req
+        .headers()
+        .get(header::ORIGIN)
+        .and_then(|v| v.to_str().ok())
+        .map(ToString::to_string);
+    let (Some(origin), Some(state)) = (origin, req.app_data::<web::Data<AppState>>().cloned())
+    else {
+        return next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_left_body);
+    };
+    let config = state.cors();
+    let allowed = config.allows_origin(&origin);
+
+    let is_preflight = req.method() == Method::OPTIONS
+        && req
+            .headers()
+            .contains_key(header::ACCESS_CONTROL_REQUEST_METHOD);
+    if is_preflight {
+        let response = if allowed {
+            let mut response = HttpResponse::NoContent().finish();
+            let headers = response.headers_mut();
+            apply_origin_headers(headers, config, &origin);
+            apply_preflight_headers(headers, config, req.headers());
+            response
+        } else {
+            HttpResponse::Forbidden().finish()
+        };
+        return Ok(req.into_response(response).map_into_right_body());
+    }
+
+    let mut res = next.call(req).await?;
+    if allowed {
+        apply_origin_headers(res.headers_mut(), config, &origin);
+    }
+    Ok(res.map_into_left_body())
+}
+
+/// 附加来源相关的响应头
+fn apply_origin_headers(headers: &mut HeaderMap, config: &CorsConfig, origin: &str) {
+    let allow_origin = if config.allows_any_origin() {
+        HeaderValue::from_static("*")
+    } else {
+        match HeaderValue::from_str(origin) {
+            Ok(value) => value,
+            Err(_) => return,
+        }
+    };
+    headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, allow_origin);
+    headers.append(header::VARY, HeaderValue::from_static("Origin"));
+    if config.allow_credentials {
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_CREDENTIALS,
+            HeaderValue::from_static("true"),
+        );
+    }
+}
+
+/// 附加预检响应头：允许的方法、请求头与缓存时间
+fn apply_preflight_headers(headers: &mut HeaderMap, config: &CorsConfig, request: &HeaderMap) {
+    if let Ok(methods) = HeaderValue::from_str(&config.allowed_methods.join(", ")) {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_METHODS, methods);
+    }
+    if let Some(requested) = request.get(header::ACCESS_CONTROL_REQUEST_HEADERS) {
+        headers.insert(header::ACCESS_CONTROL_ALLOW_HEADERS, requested.clone());
+    }
+    headers.insert(
+        header::ACCESS_CONTROL_MAX_AGE,
+        HeaderValue::from(config.max_age),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::App;
+    use actix_web::middleware::from_fn;
+    use actix_web::test as actix_test;
+
+    use super::*;
+    use crate::config::AppConfig;
+
+    fn config(origins: &[&str]) -> AppConfig {
+        let mut config = AppConfig::default();
+        config.server.cors.allowed_origins = origins.iter().map(ToString::to_string).collect();
+        config
+    }
+
+    #[actix_web::test]
+    async fn test_allowed_and_disallowed_origin() {
+        let state = web::Data::new(AppState::new(&config(&["https://dns.example.com"]), None));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(state)
+                .wrap(from_fn(cors))
+                .route("/ping", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let req = actix_test::TestRequest::get()
+            .uri("/ping")
+            .insert_header((header::ORIGIN, "https://dns.example.com"))
+            .to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert_eq!(
+            res.headers()
+                .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .and_then(|v| v.to_str().ok()),
+            Some("https://dns.example.com")
+        );
+
+        let req = actix_test::TestRequest::get()
+            .uri("/ping")
+            .insert_header((header::ORIGIN, "https://evil.example"))
+            .to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert!(res.status().is_success());
+        assert!(
+            !res.headers()
+                .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        );
+
+        let req = actix_test::TestRequest::default()
+            .method(Method::OPTIONS)
+            .uri("/ping")
+            .insert_header((header::ORIGIN, "https://dns.example.com"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, "PUT"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization"))
+            .to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert_eq!(res.status(), 204);
+        assert_eq!(
+            res.headers()
+                .get(header::ACCESS_CONTROL_ALLOW_HEADERS)
+                .and_then(|v| v.to_str().ok()),
+            Some("authorization")
+        );
+
+        let req = actix_test::TestRequest::default()
+            .method(Method::OPTIONS)
+            .uri("/ping")
+            .insert_header((header::ORIGIN, "https://evil.example"))
+            .insert_header((header::ACCESS_CONTROL_REQUEST_METHOD, "PUT"))
+            .to_request();
+        let res = actix_test::call_service(&app, req).await;
+        assert_eq!(res.status(), 403);
+    }
+
+    #[test]
+    fn test_origin_matching() {
+        let cors = config(&["https://dns.example.com/"]).server.cors;
+        assert!(cors.allows_origin("https://dns.example.com"));
+        assert!(!cors.allows_origin("https://dns.example.com.evil"));
+        assert!(!CorsConfig::default().allows_origin("https://dns.example.com"));
+        assert!(
+            config(&["*"])
+                .server
+                .cors
+                .allows_origin("http://localhost:5173")
+        );
+    }
+}
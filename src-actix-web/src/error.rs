@@ -0,0 +1,149 @@
+//! HTTP 接口错误
+//!
+//! 错误响应统一为 `{ "success": false, "code": "...", "message": "..." }`，
+//! `code` 为稳定的机器可读错误码，客户端应据此分支，而不是解析 `message`；
+//! 通过 [`ApiError::into_response`] 构造时附带 `requestId`，便于对照服务端日志。
+
+use std::fmt;
+
+use actix_web::error::InternalError;
+use actix_web::http::{StatusCode, header};
+use actix_web::{HttpRequest, HttpResponse, ResponseError};
+use serde::Serialize;
+
+use crate::request_id::RequestId;
+
+/// 接口错误
+#[derive(Debug)]
+pub enum ApiError {
+    /// 请求体无法解析或字段不合法
+    InvalidRequest(String),
+    /// 缺少或无效的 API 密钥
+    Unauthorized,
+}
+
+impl ApiError {
+    /// 机器可读错误码
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidRequest(_) => "INVALID_REQUEST",
+            Self::Unauthorized => "UNAUTHORIZED",
+        }
+    }
+
+    /// 转换为带请求 ID 的错误响应
+    pub fn into_response(self, req: &HttpRequest) -> actix_web::Error {
+        let response = self.build_response(RequestId::of(req));
+        InternalError::from_response(self, response).into()
+    }
+
+    /// 构造带请求 ID 的错误响应（供中间件直接返回）
+    pub fn response_for(&self, req: &HttpRequest) -> HttpResponse {
+        self.build_response(RequestId::of(req))
+    }
+
+    fn build_response(&self, request_id: Option<String>) -> HttpResponse {
+        let mut builder = HttpResponse::build(self.status_code());
+        if matches!(self, Self::Unauthorized) {
+            builder.insert_header((header::WWW_AUTHENTICATE, "Bearer"));
+        }
+        builder.json(ErrorResponse {
+            success: false,
+            code: self.code(),
+            message: &self.to_string(),
+            request_id,
+        })
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidRequest(msg) => write!(f, "请求无效: {msg}"),
+            Self::Unauthorized => write!(f, "缺少或无效的 API 密钥"),
+        }
+    }
+}
+
+/// 错误响应体
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorResponse<'a> {
+    success: bool,
+    code: &'static str,
+    message: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_id: Option<String>,
+}
+
+impl ResponseError for ApiError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidRequest(_) => StatusCode::BAD_REQUEST,
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        self.build_response(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::HttpMessage;
+    use actix_web::body::to_bytes;
+    use actix_web::test::TestRequest;
+    use serde_json::{Value, json};
+
+    use super::*;
+
+    async fn body_json(response: HttpResponse) -> Value {
+        let bytes = to_bytes(response.into_body()).await.unwrap_or_default();
+        serde_json::from_slice(&bytes).unwrap_or_default()
+    }
+
+    #[actix_web::test]
+    async fn test_unauthorized_response() {
+        let req = TestRequest::default().to_http_request();
+        req.extensions_mut().insert(RequestId("req-1".to_string()));
+
+        let response = ApiError::Unauthorized.response_for(&req);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            response
+                .headers()
+                .get(header::WWW_AUTHENTICATE)
+                .and_then(|v| v.to_str().ok()),
+            Some("Bearer")
+        );
+        assert_eq!(
+            body_json(response).await,
+            json!({
+                "success": false,
+                "code": "UNAUTHORIZED",
+                "message": "缺少或无效的 API 密钥",
+                "requestId": "req-1",
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_request_response() {
+        let error = ApiError::InvalidRequest("缺少字段 readOnly".to_string());
+        assert_eq!(error.code(), "INVALID_REQUEST");
+
+        // 未经过请求 ID 中间件时不带 requestId
+        let response = error.error_response();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(response.headers().get(header::WWW_AUTHENTICATE).is_none());
+        assert_eq!(
+            body_json(response).await,
+            json!({
+                "success": false,
+                "code": "INVALID_REQUEST",
+                "message": "请求无效: 缺少字段 readOnly",
+            })
+        );
+    }
+}
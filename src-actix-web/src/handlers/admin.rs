@@ -0,0 +1,28 @@
+//! 管理接口
+
+use actix_web::{HttpResponse, put, web};
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// 维护模式请求 / 响应
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceMode {
+    /// 是否开启只读模式
+    read_only: bool,
+}
+
+/// 运行时切换维护模式：开启后拒绝所有写操作，读取与工具箱接口不受影响
+///
+/// 始终需要 API 密钥认证，见 [`crate::auth`]
+#[put("/admin/maintenance")]
+pub async fn set_maintenance_mode(
+    state: web::Data<AppState>,
+    body: web::Json<MaintenanceMode>,
+) -> HttpResponse {
+    state.set_read_only(body.read_only);
+    HttpResponse::Ok().json(MaintenanceMode {
+        read_only: state.is_read_only(),
+    })
+}
@@ -0,0 +1,70 @@
+//! 健康检查
+//!
+//! - `GET /health`：存活探针，不访问任何依赖，立即返回 200
+//! - `GET /ready`：就绪探针，对数据库执行 `SELECT 1`，失败时返回 503
+
+use actix_web::{HttpResponse, get, web};
+use sea_orm::{ConnectionTrait, Statement};
+use serde::Serialize;
+
+use crate::state::AppState;
+
+/// 健康检查响应
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct HealthResponse {
+    status: &'static str,
+    /// 是否处于维护模式（只读）
+    read_only: bool,
+}
+
+/// 就绪检查响应
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ReadyResponse {
+    /// `ready` / `unavailable`
+    status: &'static str,
+    /// 数据库状态：`ok` / `error` / `not_configured`
+    database: &'static str,
+    /// 数据库检查失败原因
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// 健康检查，同时返回当前维护模式
+#[get("/health")]
+pub async fn health(state: web::Data<AppState>) -> HttpResponse {
+    HttpResponse::Ok().json(HealthResponse {
+        status: "ok",
+        read_only: state.is_read_only(),
+    })
+}
+
+/// 就绪检查：数据库不可用时返回 503
+#[get("/ready")]
+pub async fn ready(state: web::Data<AppState>) -> HttpResponse {
+    let Some(db) = state.db() else {
+        return HttpResponse::Ok().json(ReadyResponse {
+            status: "ready",
+            database: "not_configured",
+            error: None,
+        });
+    };
+
+    let stmt = Statement::from_string(db.get_database_backend(), "SELECT 1");
+    match db.execute_raw(stmt).await {
+        Ok(_) => HttpResponse::Ok().json(ReadyResponse {
+            status: "ready",
+            database: "ok",
+            error: None,
+        }),
+        Err(e) => {
+            tracing::warn!("Readiness check failed: {e}");
+            HttpResponse::ServiceUnavailable().json(ReadyResponse {
+                status: "unavailable",
+                database: "error",
+                error: Some(e.to_string()),
+            })
+        }
+    }
+}
@@ -0,0 +1,19 @@
+//! 指标
+//!
+//! `GET /metrics`：Prometheus 文本格式的请求数、耗时分布与维护模式状态
+
+use actix_web::{HttpResponse, get, web};
+
+use crate::state::AppState;
+
+/// Prometheus 文本格式的 Content-Type
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// 输出 Prometheus 指标
+#[get("/metrics")]
+pub async fn metrics(state: web::Data<AppState>) -> HttpResponse {
+    let body = state.metrics().render(state.is_read_only());
+    HttpResponse::Ok()
+        .content_type(PROMETHEUS_CONTENT_TYPE)
+        .body(body)
+}
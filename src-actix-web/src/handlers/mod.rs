@@ -0,0 +1,17 @@
+//! HTTP 路由处理
+
+mod admin;
+mod health;
+mod metrics;
+mod schema;
+
+use actix_web::web;
+
+/// 注册所有路由
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(health::health)
+        .service(health::ready)
+        .service(admin::set_maintenance_mode)
+        .service(metrics::metrics)
+        .service(schema::schema);
+}
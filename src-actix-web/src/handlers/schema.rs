@@ -0,0 +1,263 @@
+//! API 描述
+//!
+//! `GET /schema`：返回 `OpenAPI` 3 文档，描述本服务提供的全部路由及请求 / 响应结构，
+//! 供 TypeScript / Python 等客户端生成类型，无需阅读 Rust 源码。
+
+use actix_web::{HttpResponse, get};
+use serde_json::{Value, json};
+
+/// 返回 `OpenAPI` 文档
+#[get("/schema")]
+pub async fn schema() -> HttpResponse {
+    HttpResponse::Ok().json(openapi_document())
+}
+
+/// 构建 `OpenAPI` 文档
+///
+/// 新增路由时需同步在此补充对应的 path 与 schema，测试会按本文档逐一请求各路由并校验响应
+fn openapi_document() -> Value {
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "DNS Orchestrator Web API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        // 除 /health、/ready 外均需 Bearer 认证
+        "security": [{ "bearerAuth": [] }],
+        "paths": paths(),
+        "components": components(),
+    })
+}
+
+/// 各路由描述
+fn paths() -> Value {
+    json!({
+        "/health": {
+            "get": {
+                "security": [],
+                "summary": "存活探针，同时返回当前维护模式",
+                "responses": {
+                    "200": json_response("#/components/schemas/HealthResponse"),
+                },
+            },
+        },
+        "/ready": {
+            "get": {
+                "security": [],
+                "summary": "就绪探针，数据库不可用时返回 503",
+                "responses": {
+                    "200": json_response("#/components/schemas/ReadyResponse"),
+                    "503": json_response("#/components/schemas/ReadyResponse"),
+                },
+            },
+        },
+        "/admin/maintenance": {
+            "put": {
+                "summary": "运行时切换维护模式（只读）",
+                "requestBody": {
+                    "required": true,
+                    "content": {
+                        "application/json": {
+                            "schema": { "$ref": "#/components/schemas/MaintenanceMode" },
+                        },
+                    },
+                },
+                "responses": {
+                    "200": json_response("#/components/schemas/MaintenanceMode"),
+                    "400": json_response("#/components/schemas/ErrorResponse"),
+                },
+            },
+        },
+        "/metrics": {
+            "get": {
+                "summary": "Prometheus 指标",
+                "responses": {
+                    "200": {
+                        "description": "Prometheus 文本格式",
+                        "content": { "text/plain": { "schema": { "type": "string" } } },
+                    },
+                },
+            },
+        },
+        "/schema": {
+            "get": {
+                "summary": "本文档",
+                "responses": {
+                    "200": { "description": "OpenAPI 3 文档" },
+                },
+            },
+        },
+    })
+}
+
+/// 请求 / 响应结构描述
+fn components() -> Value {
+    json!({
+        "securitySchemes": {
+            "bearerAuth": { "type": "http", "scheme": "bearer" },
+        },
+        "schemas": {
+            "HealthResponse": {
+                "type": "object",
+                "required": ["status", "readOnly"],
+                "properties": {
+                    "status": { "type": "string", "enum": ["ok"] },
+                    "readOnly": { "type": "boolean" },
+                },
+            },
+            "ReadyResponse": {
+                "type": "object",
+                "required": ["status", "database"],
+                "properties": {
+                    "status": { "type": "string", "enum": ["ready", "unavailable"] },
+                    "database": {
+                        "type": "string",
+                        "enum": ["ok", "error", "not_configured"],
+                    },
+                    "error": { "type": "string" },
+                },
+            },
+            "ErrorResponse": {
+                "type": "object",
+                "required": ["success", "code", "message"],
+                "properties": {
+                    "success": { "type": "boolean", "enum": [false] },
+                    "code": { "type": "string", "enum": ["INVALID_REQUEST", "UNAUTHORIZED"] },
+                    "message": { "type": "string" },
+                    "requestId": { "type": "string" },
+                },
+            },
+            "MaintenanceMode": {
+                "type": "object",
+                "required": ["readOnly"],
+                "properties": {
+                    "readOnly": { "type": "boolean" },
+                },
+            },
+        },
+    })
+}
+
+/// JSON 响应体描述
+fn json_response(schema_ref: &str) -> Value {
+    json!({
+        "description": "OK",
+        "content": {
+            "application/json": {
+                "schema": { "$ref": schema_ref },
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::test as actix_test;
+    use actix_web::{App, web};
+    use serde_json::{Value, json};
+
+    use super::openapi_document;
+    use crate::config::AppConfig;
+    use crate::state::AppState;
+
+    /// 解析 `#/components/schemas/...` 引用
+    fn resolve<'a>(doc: &'a Value, reference: &str) -> &'a Value {
+        let schema = doc.pointer(reference.trim_start_matches('#'));
+        assert!(schema.is_some(), "无法解析引用 {reference}");
+        schema.unwrap_or(&Value::Null)
+    }
+
+    /// 按 schema 构造最小请求体（仅填充必填字段）
+    fn example(doc: &Value, schema: &Value) -> Value {
+        if let Some(reference) = schema["$ref"].as_str() {
+            return example(doc, resolve(doc, reference));
+        }
+        match schema["type"].as_str() {
+            Some("object") => {
+                let required = schema["required"].as_array().cloned().unwrap_or_default();
+                required
+                    .iter()
+                    .filter_map(Value::as_str)
+                    .map(|name| (name.to_string(), example(doc, &schema["properties"][name])))
+                    .collect::<serde_json::Map<_, _>>()
+                    .into()
+            }
+            Some("boolean") => json!(false),
+            _ => schema["enum"][0].clone(),
+        }
+    }
+
+    /// 校验响应体：必填字段齐全、没有未记录的字段、枚举值合法
+    fn check_body(doc: &Value, reference: &str, body: &Value, route: &str) {
+        let schema = resolve(doc, reference);
+        let properties = schema["properties"]
+            .as_object()
+            .cloned()
+            .unwrap_or_default();
+        for name in schema["required"].as_array().into_iter().flatten() {
+            let name = name.as_str().unwrap_or_default();
+            assert!(
+                body.get(name).is_some(),
+                "{route} 缺少必填字段 {name}: {body}"
+            );
+        }
+        for (name, value) in body.as_object().into_iter().flatten() {
+            assert!(
+                properties.contains_key(name),
+                "{route} 返回了未记录的字段 {name}"
+            );
+            if let Some(allowed) = properties[name]["enum"].as_array() {
+                assert!(
+                    allowed.contains(value),
+                    "{route} 字段 {name} 的值 {value} 不在枚举中"
+                );
+            }
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_documented_routes_match_handlers() {
+        let doc = openapi_document();
+        let state = web::Data::new(AppState::new(&AppConfig::default(), None));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(state)
+                .configure(super::super::configure),
+        )
+        .await;
+
+        let paths = doc["paths"].as_object().cloned().unwrap_or_default();
+        assert!(!paths.is_empty());
+        for (path, item) in &paths {
+            for (method, operation) in item.as_object().into_iter().flatten() {
+                let route = format!("{} {path}", method.to_uppercase());
+                let mut req = match method.as_str() {
+                    "get" => actix_test::TestRequest::get(),
+                    "put" => actix_test::TestRequest::put(),
+                    other => unreachable!("未处理的方法 {other}"),
+                }
+                .uri(path);
+                let body_schema =
+                    &operation["requestBody"]["content"]["application/json"]["schema"];
+                if !body_schema.is_null() {
+                    req = req.set_json(example(&doc, body_schema));
+                }
+
+                let res = actix_test::call_service(&app, req.to_request()).await;
+                let status = res.status().as_u16().to_string();
+                let response = &operation["responses"][status.as_str()];
+                assert!(
+                    response.is_object(),
+                    "{route} 返回了未记录的状态码 {status}"
+                );
+
+                if let Some(reference) =
+                    response["content"]["application/json"]["schema"]["$ref"].as_str()
+                {
+                    let body: Value = actix_test::read_body_json(res).await;
+                    check_body(&doc, reference, &body, &route);
+                }
+            }
+        }
+    }
+}
@@ -1,3 +1,90 @@
-fn main() {
-    println!("Hello, world!");
+//! DNS Orchestrator Web 服务入口
+
+mod auth;
+mod config;
+mod cors;
+mod error;
+mod handlers;
+mod metrics;
+mod request_id;
+mod shutdown;
+mod state;
+
+use actix_web::middleware::from_fn;
+use actix_web::{App, HttpServer, web};
+use anyhow::Context;
+use tracing_subscriber::EnvFilter;
+
+use crate::config::AppConfig;
+use crate::error::ApiError;
+use crate::state::AppState;
+
+#[actix_web::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .init();
+
+    let config = AppConfig::load()?;
+    let server = config.server.clone();
+    let db = if let Some(url) = &config.database.url {
+        Some(
+            sea_orm::Database::connect(url.as_str())
+                .await
+                .context("连接数据库失败")?,
+        )
+    } else {
+        tracing::warn!("未配置数据库，/ready 将跳过数据库检查");
+        None
+    };
+    let state = web::Data::new(AppState::new(&config, db));
+
+    if config.security.api_keys.is_empty() {
+        if config.security.allow_anonymous {
+            tracing::warn!(
+                "未配置 security.api_keys 且开启了 allow_anonymous，所有接口无需认证即可访问"
+            );
+        } else {
+            tracing::error!("未配置 security.api_keys，除健康检查外的所有请求都将返回 401");
+        }
+    }
+
+    tracing::info!(
+        "Starting server on {}:{} (compression: {}, read-only: {})",
+        server.host,
+        server.port,
+        server.compression,
+        config.maintenance.read_only
+    );
+
+    let app_state = state.clone();
+    let http_server = HttpServer::new(move || {
+        App::new()
+            // 按 Accept-Encoding 协商压缩；流式响应（如 NDJSON）需逐块输出时，
+            // 在响应上设置 `ContentEncoding::Identity` 即可跳过压缩
+            .wrap(server.compress())
+            .wrap(from_fn(auth::require_api_key))
+            // 在认证之外：预检请求不携带 `Authorization`
+            .wrap(from_fn(cors::cors))
+            // 最外层：请求 ID 与访问日志覆盖其余中间件
+            .wrap(from_fn(request_id::request_id))
+            .app_data(state.clone())
+            .app_data(web::JsonConfig::default().error_handler(|err, req| {
+                ApiError::InvalidRequest(err.to_string()).into_response(req)
+            }))
+            .configure(handlers::configure)
+    })
+    .workers(config.server.worker_count())
+    .disable_signals()
+    .shutdown_timeout(config.server.shutdown_timeout)
+    .bind((config.server.host.as_str(), config.server.port))?
+    .run();
+    shutdown::spawn_signal_handler(http_server.handle(), config.server.shutdown_timeout);
+    http_server.await?;
+
+    // 所有 worker 已退出，释放数据库连接
+    app_state.close().await;
+    tracing::info!("Server stopped");
+
+    Ok(())
 }
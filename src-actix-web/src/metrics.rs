@@ -0,0 +1,230 @@
+//! Prometheus 指标
+//!
+//! 轻量的指标注册表：按方法、路由模板和状态码统计请求数与耗时分布，
+//! 由 `GET /metrics` 以 Prometheus 文本格式输出。路由使用匹配到的模板
+//! （如 `/admin/maintenance`），未匹配的路径统一记为 `unmatched`，避免标签基数失控。
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// 耗时直方图桶上界（秒）
+const DURATION_BUCKETS: [f64; 10] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0];
+
+/// 未匹配任何路由时的路由标签
+pub const UNMATCHED_ROUTE: &str = "unmatched";
+
+/// 请求指标标签
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct RequestLabels {
+    method: String,
+    route: String,
+    status: u16,
+}
+
+/// 单组标签下的累计值
+#[derive(Debug, Default)]
+struct RequestStats {
+    count: u64,
+    sum_seconds: f64,
+    /// 各桶的累计计数（不含 `+Inf`，即 `count`）
+    buckets: [u64; DURATION_BUCKETS.len()],
+}
+
+/// 指标注册表（存放在 `AppState` 中）
+#[derive(Debug, Default)]
+pub struct Metrics {
+    requests: Mutex<BTreeMap<RequestLabels, RequestStats>>,
+}
+
+impl Metrics {
+    /// 记录一次请求
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        let labels = RequestLabels {
+            method: method.to_string(),
+            route: route.to_string(),
+            status,
+        };
+
+        let mut requests = self
+            .requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let entry = requests.entry(labels).or_default();
+        entry.count += 1;
+        entry.sum_seconds += seconds;
+        for (bucket, upper) in entry.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if seconds <= upper {
+                *bucket += 1;
+            }
+        }
+    }
+
+    /// 以 Prometheus 文本格式输出全部指标
+    pub fn render(&self, read_only: bool) -> String {
+        let mut out = String::new();
+
+        let _ = writeln!(
+            out,
+            "# HELP dns_orchestrator_read_only Whether maintenance (read-only) mode is enabled."
+        );
+        let _ = writeln!(out, "# TYPE dns_orchestrator_read_only gauge");
+        let _ = writeln!(out, "dns_orchestrator_read_only {}", u8::from(read_only));
+
+        let requests = self
+            .requests
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+        let _ = writeln!(
+            out,
+            "# HELP http_requests_total Total number of HTTP requests."
+        );
+        let _ = writeln!(out, "# TYPE http_requests_total counter");
+        for (labels, stats) in requests.iter() {
+            let _ = writeln!(
+                out,
+                "http_requests_total{{{}}} {}",
+                format_labels(labels),
+                stats.count
+            );
+        }
+
+        let _ = writeln!(
+            out,
+            "# HELP http_request_duration_seconds HTTP request latency in seconds."
+        );
+        let _ = writeln!(out, "# TYPE http_request_duration_seconds histogram");
+        for (labels, stats) in requests.iter() {
+            let labels = format_labels(labels);
+            for (count, upper) in stats.buckets.iter().zip(DURATION_BUCKETS) {
+                let _ = writeln!(
+                    out,
+                    "http_request_duration_seconds_bucket{{{labels},le=\"{upper}\"}} {count}"
+                );
+            }
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_bucket{{{labels},le=\"+Inf\"}} {}",
+                stats.count
+            );
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_sum{{{labels}}} {}",
+                stats.sum_seconds
+            );
+            let _ = writeln!(
+                out,
+                "http_request_duration_seconds_count{{{labels}}} {}",
+                stats.count
+            );
+        }
+
+        out
+    }
+}
+
+/// 格式化标签（值按 Prometheus 规则转义）
+fn format_labels(labels: &RequestLabels) -> String {
+    format!(
+        "method=\"{}\",route=\"{}\",status=\"{}\"",
+        escape_label(&labels.method),
+        escape_label(&labels.route),
+        labels.status
+    )
+}
+
+/// 转义标签值中的 `\`、`"` 和换行
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_lines(output: &str, name: &str) -> Vec<String> {
+        output
+            .lines()
+            .filter(|l| l.starts_with(name))
+            .map(str::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn test_render_counter_and_gauge() {
+        let metrics = Metrics::default();
+        metrics.observe_request("GET", "/health", 200, Duration::from_millis(3));
+        metrics.observe_request("GET", "/health", 200, Duration::from_millis(3));
+        metrics.observe_request("PUT", "/admin/maintenance", 401, Duration::from_millis(1));
+
+        let output = metrics.render(true);
+        assert!(
+            output.contains(
+                "# TYPE dns_orchestrator_read_only gauge\ndns_orchestrator_read_only 1\n"
+            )
+        );
+        assert!(output.contains("# TYPE http_requests_total counter\n"));
+        assert_eq!(
+            sample_lines(&output, "http_requests_total{"),
+            [
+                r#"http_requests_total{method="GET",route="/health",status="200"} 2"#,
+                r#"http_requests_total{method="PUT",route="/admin/maintenance",status="401"} 1"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_histogram_buckets_cumulative() {
+        let metrics = Metrics::default();
+        metrics.observe_request("GET", "/metrics", 200, Duration::from_millis(20));
+        metrics.observe_request("GET", "/metrics", 200, Duration::from_secs(10));
+
+        let output = metrics.render(false);
+        assert!(output.contains("# TYPE http_request_duration_seconds histogram\n"));
+        let labels = r#"method="GET",route="/metrics",status="200""#;
+        let buckets = sample_lines(&output, "http_request_duration_seconds_bucket");
+        assert_eq!(buckets.len(), DURATION_BUCKETS.len() + 1);
+        assert!(buckets.contains(&format!(
+            r#"http_request_duration_seconds_bucket{{{labels},le="0.01"}} 0"#
+        )));
+        assert!(buckets.contains(&format!(
+            r#"http_request_duration_seconds_bucket{{{labels},le="0.025"}} 1"#
+        )));
+        assert!(buckets.contains(&format!(
+            r#"http_request_duration_seconds_bucket{{{labels},le="5"}} 1"#
+        )));
+        assert_eq!(
+            buckets.last().map(String::as_str),
+            Some(
+                format!(r#"http_request_duration_seconds_bucket{{{labels},le="+Inf"}} 2"#).as_str()
+            )
+        );
+        assert!(output.contains(&format!(
+            "http_request_duration_seconds_count{{{labels}}} 2\n"
+        )));
+        assert!(output.contains(&format!(
+            "http_request_duration_seconds_sum{{{labels}}} 10.02\n"
+        )));
+    }
+
+    #[test]
+    fn test_label_values_escaped() {
+        let metrics = Metrics::default();
+        metrics.observe_request("GET", "a\"b\\c\nd", 404, Duration::ZERO);
+
+        let output = metrics.render(false);
+        assert!(output.contains(r#"route="a\"b\\c\nd""#));
+        // 每个样本只占一行
+        assert!(
+            output
+                .lines()
+                .all(|l| l.starts_with('#') || l.split(' ').count() == 2)
+        );
+    }
+}
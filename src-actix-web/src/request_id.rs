@@ -0,0 +1,189 @@
+//! 请求 ID 中间件
+//!
+//! 为每个请求分配请求 ID（优先沿用合法的 `X-Request-Id` 请求头，否则生成 UUID），
+//! 在该请求的 tracing span 中记录，并通过 `X-Request-Id` 响应头返回，
+//! 便于把客户端报错与服务端日志对应起来。请求结束时同时记录到 [`Metrics`](crate::metrics::Metrics)。
+
+use std::time::Instant;
+
+use actix_web::body::MessageBody;
+use actix_web::dev::{ServiceRequest, ServiceResponse};
+use actix_web::http::header::{HeaderName, HeaderValue};
+use actix_web::middleware::Next;
+use actix_web::{Error, HttpMessage, HttpRequest, web};
+use tracing::Instrument;
+
+use crate::metrics::UNMATCHED_ROUTE;
+use crate::state::AppState;
+
+/// 请求 ID 头
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// 沿用客户端请求 ID 的最大长度
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// 当前请求的 ID（存放在请求 extensions 中）
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+impl RequestId {
+    /// 读取请求的 ID（未经过中间件时为 `None`）
+    pub fn of(req: &HttpRequest) -> Option<String> {
+        req.extensions().get::<Self>().map(|id| id.0.clone())
+    }
+}
+
+/// 请求 ID 与访问日志中间件
+pub async fn request_id(
+    req: ServiceRequest,
+    next: Next<impl MessageBody>,
+) -> Result<ServiceResponse<impl MessageBody>, Error> {
+    let request_id = req
+        .headers()
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .filter(|v| is_valid_request_id(v))
+        .map_or_else(|| uuid::Uuid::new_v4().to_string(), ToString::to_string);
+    req.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        method = %req.method(),
+        path = %req.path(),
+    );
+    let started = Instant::now();
+
+    let mut res = next.call(req).instrument(span.clone()).await?;
+
+    let status = res.status();
+    let elapsed = started.elapsed();
+    let duration_ms = elapsed.as_millis();
+    if let Some(state) = res.request().app_data::<web::Data<AppState>>() {
+        let route = res.request().match_pattern();
+        state.metrics().observe_request(
+            res.request().method().as_str(),
+            route.as_deref().unwrap_or(UNMATCHED_ROUTE),
+            status.as_u16(),
+            elapsed,
+        );
+    }
+    span.in_scope(|| {
+        if status.is_server_error() {
+            tracing::warn!(status = status.as_u16(), duration_ms, "request failed");
+        } else {
+            tracing::info!(status = status.as_u16(), duration_ms, "request completed");
+        }
+    });
+
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        res.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    Ok(res)
+}
+
+/// 客户端传入的请求 ID：非空、长度受限，仅含字母数字与 `-`、`_`、`.`
+fn is_valid_request_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LEN
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::middleware::from_fn;
+    use actix_web::test as actix_test;
+    use actix_web::{App, HttpResponse};
+
+    use super::*;
+    use crate::config::AppConfig;
+
+    /// 处理函数返回从 extensions 中读到的请求 ID，用于确认下游可见
+    async fn echo(req: HttpRequest) -> HttpResponse {
+        HttpResponse::Ok().body(RequestId::of(&req).unwrap_or_default())
+    }
+
+    /// 发送请求，返回 (响应头中的请求 ID, 处理函数看到的请求 ID)
+    async fn call(incoming: Option<&str>) -> (String, String) {
+        let state = web::Data::new(AppState::new(&AppConfig::default(), None));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(state)
+                .wrap(from_fn(request_id))
+                .route("/echo", web::get().to(echo)),
+        )
+        .await;
+
+        let mut req = actix_test::TestRequest::get().uri("/echo");
+        if let Some(id) = incoming {
+            req = req.insert_header((REQUEST_ID_HEADER, id));
+        }
+        let res = actix_test::call_service(&app, req.to_request()).await;
+        let header = res
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        let body = actix_test::read_body(res).await;
+        (header, String::from_utf8_lossy(&body).into_owned())
+    }
+
+    #[actix_web::test]
+    async fn test_generates_request_id() {
+        let (header, seen) = call(None).await;
+        assert!(uuid::Uuid::parse_str(&header).is_ok(), "{header}");
+        assert_eq!(seen, header);
+
+        let (other, _) = call(None).await;
+        assert_ne!(other, header);
+    }
+
+    #[actix_web::test]
+    async fn test_propagates_client_request_id() {
+        let (header, seen) = call(Some("client-trace_1.2")).await;
+        assert_eq!(header, "client-trace_1.2");
+        assert_eq!(seen, "client-trace_1.2");
+    }
+
+    #[actix_web::test]
+    async fn test_replaces_invalid_client_request_id() {
+        for invalid in ["has space", "a/b", &"x".repeat(MAX_REQUEST_ID_LEN + 1)] {
+            let (header, seen) = call(Some(invalid)).await;
+            assert!(
+                uuid::Uuid::parse_str(&header).is_ok(),
+                "{invalid} -> {header}"
+            );
+            assert_eq!(seen, header);
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_records_metrics_by_route() {
+        let state = web::Data::new(AppState::new(&AppConfig::default(), None));
+        let app = actix_test::init_service(
+            App::new()
+                .app_data(state.clone())
+                .wrap(from_fn(request_id))
+                .route("/items/{id}", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+        for uri in ["/items/1", "/items/2", "/missing"] {
+            let req = actix_test::TestRequest::get().uri(uri).to_request();
+            actix_test::call_service(&app, req).await;
+        }
+
+        let output = state.metrics().render(false);
+        assert!(
+            output.contains(
+                r#"http_requests_total{method="GET",route="/items/{id}",status="200"} 2"#
+            )
+        );
+        assert!(
+            output
+                .contains(r#"http_requests_total{method="GET",route="unmatched",status="404"} 1"#)
+        );
+    }
+}
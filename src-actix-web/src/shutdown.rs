@@ -0,0 +1,49 @@
+//! 优雅停机
+//!
+//! 关闭 actix 自带的信号处理（其 SIGINT 为强制停机），改为 SIGTERM 与 SIGINT
+//! 都走优雅停机：停止接受新连接，等待进行中的请求在 `server.shutdown_timeout`
+//! 内完成后再退出，避免服务商写操作被中途打断。所有 worker 退出后关闭数据库连接池。
+//!
+//! 手动验证：启动服务后发起一个耗时请求，在其返回前对进程发送 `kill -TERM`（或 Ctrl+C），
+//! 该请求仍会正常返回，日志依次输出 `graceful worker shutdown; finishing 1 connections`
+//! 与 `Server stopped`；停机期间新的连接会被拒绝。
+
+use actix_web::dev::ServerHandle;
+
+/// 等待停机信号（Unix 下为 SIGTERM / SIGINT，其他平台为 Ctrl+C）
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let (Ok(mut sigterm), Ok(mut sigint)) = (
+            signal(SignalKind::terminate()),
+            signal(SignalKind::interrupt()),
+        ) else {
+            tracing::error!("注册停机信号处理失败，将无法优雅停机");
+            return std::future::pending().await;
+        };
+        tokio::select! {
+            _ = sigterm.recv() => tracing::info!("收到 SIGTERM"),
+            _ = sigint.recv() => tracing::info!("收到 SIGINT"),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        if tokio::signal::ctrl_c().await.is_err() {
+            tracing::error!("注册停机信号处理失败，将无法优雅停机");
+            return std::future::pending().await;
+        }
+        tracing::info!("收到 Ctrl+C");
+    }
+}
+
+/// 后台监听停机信号，收到后优雅停止服务
+pub fn spawn_signal_handler(handle: ServerHandle, timeout_secs: u64) {
+    actix_web::rt::spawn(async move {
+        shutdown_signal().await;
+        tracing::info!("停止接受新连接，等待进行中的请求完成（最长 {timeout_secs} 秒）");
+        handle.stop(true).await;
+    });
+}
@@ -0,0 +1,96 @@
+//! 应用共享状态
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use sea_orm::DatabaseConnection;
+use subtle::ConstantTimeEq;
+
+use crate::config::{ApiKeyConfig, AppConfig, CorsConfig};
+use crate::metrics::Metrics;
+
+/// 应用共享状态（通过 `web::Data` 注入各 handler）
+#[derive(Debug)]
+pub struct AppState {
+    /// 维护模式（只读）：开启后拒绝所有写操作
+    read_only: AtomicBool,
+    /// 数据库连接（未配置数据库时为 `None`）
+    db: Option<DatabaseConnection>,
+    /// 请求指标
+    metrics: Metrics,
+    /// API 密钥
+    api_keys: Vec<ApiKeyConfig>,
+    /// 未配置密钥时允许匿名访问
+    allow_anonymous: bool,
+    /// 跨域配置
+    cors: CorsConfig,
+}
+
+impl AppState {
+    /// 根据配置创建共享状态
+    pub fn new(config: &AppConfig, db: Option<DatabaseConnection>) -> Self {
+        Self {
+            read_only: AtomicBool::new(config.maintenance.read_only),
+            db,
+            metrics: Metrics::default(),
+            api_keys: config.security.api_keys.clone(),
+            allow_anonymous: config.security.allow_anonymous,
+            cors: config.server.cors.clone(),
+        }
+    }
+
+    /// 数据库连接
+    pub fn db(&self) -> Option<&DatabaseConnection> {
+        self.db.as_ref()
+    }
+
+    /// 关闭数据库连接池（停机时调用，等待已提交的写入完成后释放连接）
+    pub async fn close(&self) {
+        if let Some(db) = &self.db
+            && let Err(e) = db.close_by_ref().await
+        {
+            tracing::warn!("关闭数据库连接失败: {e}");
+        }
+    }
+
+    /// 请求指标
+    pub fn metrics(&self) -> &Metrics {
+        &self.metrics
+    }
+
+    /// 跨域配置
+    pub fn cors(&self) -> &CorsConfig {
+        &self.cors
+    }
+
+    /// 是否允许匿名访问（未配置密钥且显式开启 `allow_anonymous`）
+    pub fn allows_anonymous(&self) -> bool {
+        self.api_keys.is_empty() && self.allow_anonymous
+    }
+
+    /// 校验 API 密钥，返回匹配密钥的标签
+    ///
+    /// 逐个以常量时间比较，且不提前退出，避免通过响应时间推测密钥
+    pub fn authenticate(&self, token: &str) -> Option<&str> {
+        let mut matched = None;
+        for api_key in &self.api_keys {
+            if bool::from(api_key.key.as_bytes().ct_eq(token.as_bytes())) {
+                matched = Some(api_key.label.as_str());
+            }
+        }
+        matched
+    }
+
+    /// 当前是否为只读模式
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    /// 切换只读模式
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.store(read_only, Ordering::SeqCst);
+        tracing::warn!(
+            "Maintenance mode {}",
+            if read_only { "enabled" } else { "disabled" }
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Tauri 幂等键仓库适配器
+//!
+//! 使用 tauri-plugin-store 保存幂等键及其首次执行的结果
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::{Mutex, RwLock};
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::IdempotencyRepository;
+use dns_orchestrator_core::types::IdempotencyEntry;
+
+const STORE_FILE_NAME: &str = "idempotency.json";
+const ENTRIES_KEY: &str = "entries";
+
+/// Tauri 幂等键仓库实现
+pub struct TauriIdempotencyRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<IdempotencyEntry>>>>,
+    /// 串行化"读取-修改-写入"，保证预占幂等键是原子的
+    write_lock: Mutex<()>,
+}
+
+impl TauriIdempotencyRepository {
+    /// 创建新的幂等键仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// 从 Store 加载幂等键
+    fn load_from_store(&self) -> CoreResult<Vec<IdempotencyEntry>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(ENTRIES_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存幂等键到 Store 并更新缓存
+    async fn persist(&self, entries: Vec<IdempotencyEntry>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(ENTRIES_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        *self.cache.write().await = Some(entries);
+        Ok(())
+    }
+
+    /// 获取全部幂等键
+    async fn find_all(&self) -> CoreResult<Vec<IdempotencyEntry>> {
+        {
+            let cache = self.cache.read().await;
+            if let Some(ref entries) = *cache {
+                return Ok(entries.clone());
+            }
+        }
+
+        let entries = self.load_from_store()?;
+        *self.cache.write().await = Some(entries.clone());
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for TauriIdempotencyRepository {
+    async fn find(&self, account_id: &str, key: &str) -> CoreResult<Option<IdempotencyEntry>> {
+        Ok(self
+            .find_all()
+            .await?
+            .into_iter()
+            .find(|e| e.account_id == account_id && e.key == key))
+    }
+
+    async fn save(&self, entry: &IdempotencyEntry) -> CoreResult<()> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        all.retain(|e| !(e.account_id == entry.account_id && e.key == entry.key));
+        all.push(entry.clone());
+        self.persist(all).await
+    }
+
+    async fn reserve(&self, entry: &IdempotencyEntry) -> CoreResult<Option<IdempotencyEntry>> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        if let Some(existing) = all
+            .iter()
+            .find(|e| e.account_id == entry.account_id && e.key == entry.key)
+        {
+            return Ok(Some(existing.clone()));
+        }
+        all.push(entry.clone());
+        self.persist(all).await?;
+        Ok(None)
+    }
+
+    async fn remove(&self, account_id: &str, key: &str) -> CoreResult<()> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        all.retain(|e| !(e.account_id == account_id && e.key == key));
+        self.persist(all).await
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        let before = all.len();
+        all.retain(|e| e.created_at >= cutoff);
+        let purged = before - all.len();
+        if purged > 0 {
+            self.persist(all).await?;
+        }
+        Ok(purged)
+    }
+}
@@ -2,6 +2,10 @@
 
 mod account_repository;
 mod credential_store;
+mod idempotency_repository;
+mod trash_repository;
 
 pub use account_repository::TauriAccountRepository;
 pub use credential_store::TauriCredentialStore;
+pub use idempotency_repository::TauriIdempotencyRepository;
+pub use trash_repository::TauriTrashRepository;
@@ -0,0 +1,135 @@
+//! Tauri 回收站仓库适配器
+//!
+//! 使用 tauri-plugin-store 保存已删除记录的快照
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+use tokio::sync::{Mutex, RwLock};
+
+use dns_orchestrator_core::error::{CoreError, CoreResult};
+use dns_orchestrator_core::traits::TrashRepository;
+use dns_orchestrator_core::types::TrashedRecord;
+
+const STORE_FILE_NAME: &str = "trash.json";
+const TRASH_KEY: &str = "records";
+
+/// Tauri 回收站仓库实现
+pub struct TauriTrashRepository {
+    app_handle: AppHandle,
+    /// 内存缓存
+    cache: Arc<RwLock<Option<Vec<TrashedRecord>>>>,
+    /// 串行化读-改-写，保证取出快照等操作是原子的
+    write_lock: Mutex<()>,
+}
+
+impl TauriTrashRepository {
+    /// 创建新的回收站仓库实例
+    pub fn new(app_handle: AppHandle) -> Self {
+        Self {
+            app_handle,
+            cache: Arc::new(RwLock::new(None)),
+            write_lock: Mutex::new(()),
+        }
+    }
+
+    /// 从 Store 加载快照
+    fn load_from_store(&self) -> CoreResult<Vec<TrashedRecord>> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let Some(value) = store.get(TRASH_KEY) else {
+            return Ok(Vec::new());
+        };
+
+        serde_json::from_value(value.clone())
+            .map_err(|e| CoreError::SerializationError(e.to_string()))
+    }
+
+    /// 保存快照到 Store 并更新缓存
+    async fn persist(&self, entries: Vec<TrashedRecord>) -> CoreResult<()> {
+        let store = self
+            .app_handle
+            .store(STORE_FILE_NAME)
+            .map_err(|e| CoreError::StorageError(format!("Failed to access store: {e}")))?;
+
+        let value = serde_json::to_value(&entries)
+            .map_err(|e| CoreError::SerializationError(e.to_string()))?;
+
+        store.set(TRASH_KEY.to_string(), value);
+        store
+            .save()
+            .map_err(|e| CoreError::StorageError(format!("Failed to save store: {e}")))?;
+
+        *self.cache.write().await = Some(entries);
+        Ok(())
+    }
+
+    /// 获取全部快照
+    async fn find_all(&self) -> CoreResult<Vec<TrashedRecord>> {
+        {
+            let cache = self.cache.read().await;
+            if let Some(ref entries) = *cache {
+                return Ok(entries.clone());
+            }
+        }
+
+        let entries = self.load_from_store()?;
+        *self.cache.write().await = Some(entries.clone());
+        Ok(entries)
+    }
+}
+
+#[async_trait]
+impl TrashRepository for TauriTrashRepository {
+    async fn find_by_account(&self, account_id: &str) -> CoreResult<Vec<TrashedRecord>> {
+        let mut entries: Vec<_> = self
+            .find_all()
+            .await?
+            .into_iter()
+            .filter(|e| e.account_id == account_id)
+            .collect();
+        entries.sort_by_key(|e| std::cmp::Reverse(e.deleted_at));
+        Ok(entries)
+    }
+
+    async fn save_all(&self, entries: &[TrashedRecord]) -> CoreResult<()> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        all.extend_from_slice(entries);
+        self.persist(all).await?;
+
+        log::debug!("Moved {} records to trash", entries.len());
+        Ok(())
+    }
+
+    async fn take(&self, account_id: &str, id: &str) -> CoreResult<Option<TrashedRecord>> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        let Some(index) = all
+            .iter()
+            .position(|e| e.id == id && e.account_id == account_id)
+        else {
+            return Ok(None);
+        };
+        let entry = all.remove(index);
+        self.persist(all).await?;
+        Ok(Some(entry))
+    }
+
+    async fn purge_before(&self, cutoff: DateTime<Utc>) -> CoreResult<usize> {
+        let _guard = self.write_lock.lock().await;
+        let mut all = self.find_all().await?;
+        let before = all.len();
+        all.retain(|e| e.deleted_at >= cutoff);
+        let purged = before - all.len();
+        if purged > 0 {
+            self.persist(all).await?;
+        }
+        Ok(purged)
+    }
+}
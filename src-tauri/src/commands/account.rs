@@ -1,10 +1,13 @@
+use std::collections::HashMap;
+
 use tauri::State;
 
 use crate::error::DnsError;
 use crate::types::{
-    Account, ApiResponse, BatchDeleteResult, CreateAccountRequest, ExportAccountsRequest,
-    ExportAccountsResponse, ImportAccountsRequest, ImportPreview, ImportResult, ProviderMetadata,
-    UpdateAccountRequest,
+    Account, ApiResponse, BatchDeleteResult, CreateAccountRequest, CredentialTestResult,
+    ExportAccountsRequest, ExportAccountsResponse, ImportAccountsRequest, ImportPreview,
+    ImportResult, ProviderCapabilityMatrix, ProviderMetadata, ProviderType, UpdateAccountRequest,
+    VerifyAllAccountsResult,
 };
 use crate::AppState;
 
@@ -16,13 +19,14 @@ fn convert_account(core_account: dns_orchestrator_core::types::Account) -> Accou
         provider: core_account.provider,
         created_at: core_account.created_at,
         updated_at: core_account.updated_at,
-        status: core_account.status.map(convert_account_status),
+        status: core_account.status.as_ref().map(convert_account_status),
         error: core_account.error,
+        tags: core_account.tags,
     }
 }
 
 fn convert_account_status(
-    status: dns_orchestrator_core::types::AccountStatus,
+    status: &dns_orchestrator_core::types::AccountStatus,
 ) -> crate::types::AccountStatus {
     match status {
         dns_orchestrator_core::types::AccountStatus::Active => crate::types::AccountStatus::Active,
@@ -41,6 +45,7 @@ fn convert_export_response(
 
 fn convert_import_preview(preview: dns_orchestrator_core::types::ImportPreview) -> ImportPreview {
     ImportPreview {
+        format: preview.format,
         encrypted: preview.encrypted,
         account_count: preview.account_count,
         accounts: preview.accounts.map(|accounts| {
@@ -71,11 +76,22 @@ fn convert_import_result(result: dns_orchestrator_core::types::ImportResult) ->
 }
 
 /// 列出所有账号
+///
+/// 提供 `tag` 时只返回带有该标签的账号（忽略大小写）
 #[tauri::command]
 pub async fn list_accounts(
     state: State<'_, AppState>,
+    tag: Option<String>,
 ) -> Result<ApiResponse<Vec<Account>>, DnsError> {
-    let accounts = state.account_metadata_service.list_accounts().await?;
+    let accounts = match tag {
+        Some(tag) => {
+            state
+                .account_metadata_service
+                .list_accounts_by_tag(&tag)
+                .await?
+        }
+        None => state.account_metadata_service.list_accounts().await?,
+    };
     let converted: Vec<Account> = accounts.into_iter().map(convert_account).collect();
     Ok(ApiResponse::success(converted))
 }
@@ -100,6 +116,36 @@ pub async fn create_account(
     Ok(ApiResponse::success(convert_account(account)))
 }
 
+/// 测试凭证连通性（不创建账号、不保存任何数据）
+///
+/// `check_read_access` 默认为 `true`，额外拉取第一页域名确认读取权限
+#[tauri::command]
+pub async fn test_credentials(
+    state: State<'_, AppState>,
+    provider_type: ProviderType,
+    credentials: HashMap<String, String>,
+    check_read_access: Option<bool>,
+) -> Result<ApiResponse<CredentialTestResult>, DnsError> {
+    let result = state
+        .credential_management_service
+        .test_credentials(
+            &provider_type,
+            &credentials,
+            check_read_access.unwrap_or(true),
+        )
+        .await;
+    Ok(ApiResponse::success(result))
+}
+
+/// 重新验证所有账号的凭证并更新状态（有界并发）
+#[tauri::command]
+pub async fn verify_all_accounts(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<VerifyAllAccountsResult>, DnsError> {
+    let result = state.account_health_service.verify_all().await?;
+    Ok(ApiResponse::success(result))
+}
+
 /// 删除账号
 #[tauri::command]
 pub async fn delete_account(
@@ -133,6 +179,20 @@ pub async fn update_account(
     Ok(ApiResponse::success(convert_account(account)))
 }
 
+/// 替换账号的分组标签（传入空列表即清空）
+#[tauri::command]
+pub async fn update_account_tags(
+    state: State<'_, AppState>,
+    account_id: String,
+    tags: Vec<String>,
+) -> Result<ApiResponse<Account>, DnsError> {
+    let account = state
+        .account_lifecycle_service
+        .update_account_tags(&account_id, tags)
+        .await?;
+    Ok(ApiResponse::success(convert_account(account)))
+}
+
 fn convert_batch_delete_result(
     result: dns_orchestrator_core::types::BatchDeleteResult,
 ) -> BatchDeleteResult {
@@ -147,6 +207,8 @@ fn convert_batch_delete_result(
                 reason: f.reason,
             })
             .collect(),
+        dry_run: result.dry_run,
+        targets: result.targets,
     }
 }
 
@@ -172,6 +234,15 @@ pub async fn list_providers(
     Ok(ApiResponse::success(providers))
 }
 
+/// 获取所有提供商的能力矩阵
+#[tauri::command]
+pub async fn provider_capability_matrix(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<ProviderCapabilityMatrix>, DnsError> {
+    let matrix = state.provider_metadata_service.capability_matrix();
+    Ok(ApiResponse::success(matrix))
+}
+
 /// 导出账号
 #[tauri::command]
 pub async fn export_accounts(
@@ -227,8 +298,37 @@ pub async fn import_accounts(
     Ok(ApiResponse::success(convert_import_result(result)))
 }
 
+/// 清理孤立凭证（没有对应账户的凭证条目），返回删除数量
+#[tauri::command]
+pub async fn cleanup_orphaned_credentials(
+    state: State<'_, AppState>,
+) -> Result<ApiResponse<usize>, DnsError> {
+    let removed = state
+        .account_bootstrap_service
+        .cleanup_orphaned_credentials()
+        .await?;
+
+    Ok(ApiResponse::success(removed))
+}
+
+/// 获取维护模式（只读）状态
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub fn get_maintenance_mode(state: State<'_, AppState>) -> ApiResponse<bool> {
+    ApiResponse::success(state.maintenance.is_read_only())
+}
+
+/// 切换维护模式：开启后拒绝所有写操作，读取和工具箱不受影响
+#[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
+pub fn set_maintenance_mode(state: State<'_, AppState>, enabled: bool) -> ApiResponse<bool> {
+    state.maintenance.set_read_only(enabled);
+    ApiResponse::success(enabled)
+}
+
 /// 检查账户恢复是否完成
 #[tauri::command]
+#[allow(clippy::needless_pass_by_value)]
 pub fn is_restore_completed(state: State<'_, AppState>) -> bool {
     state
         .restore_completed
@@ -1,12 +1,23 @@
+use dns_orchestrator_core::utils::idn;
 use tauri::State;
 
 use crate::error::DnsError;
 use crate::types::{
-    ApiResponse, BatchDeleteRequest, BatchDeleteResult, CreateDnsRecordRequest, DnsRecord,
-    DnsRecordType, PaginatedResponse, UpdateDnsRecordRequest,
+    AccountOverview, ApiResponse, BatchCreateRequest, BatchCreateResult, BatchDeleteRequest,
+    BatchDeleteResult, CloneZoneOptions, CloneZoneResult, CreateDnsRecordRequest, DnsRecord,
+    DnsRecordType, ListResponse, PatchDnsRecordRequest, ProviderSelfTestReport, RecordSearchMatch,
+    RecordSearchQuery, ReplaceRecordValueRequest, ReplaceRecordValueResult, TrashedRecord,
+    UpdateDnsRecordRequest, ZoneDiff, ZoneFileExport, ZoneFileImportResult, ZoneRecordTypeSummary,
+    ZoneStats,
 };
 use crate::AppState;
 
+/// 国际化记录名以 Unicode 展示
+fn display_record(mut record: DnsRecord) -> DnsRecord {
+    record.name = idn::to_unicode(&record.name);
+    record
+}
+
 // 从 core 类型转换到本地类型的辅助函数
 fn convert_batch_delete_result(
     result: dns_orchestrator_core::types::BatchDeleteResult,
@@ -22,11 +33,14 @@ fn convert_batch_delete_result(
                 reason: f.reason,
             })
             .collect(),
+        dry_run: result.dry_run,
+        targets: result.targets,
     }
 }
 
-/// 列出域名下的所有 DNS 记录（分页 + 搜索）
+/// 列出域名下的所有 DNS 记录（分页 + 搜索，`compact` 为 true 时返回列式紧凑格式）
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn list_dns_records(
     state: State<'_, AppState>,
     account_id: String,
@@ -35,8 +49,10 @@ pub async fn list_dns_records(
     page_size: Option<u32>,
     keyword: Option<String>,
     record_type: Option<DnsRecordType>,
-) -> Result<ApiResponse<PaginatedResponse<DnsRecord>>, DnsError> {
-    let response = state
+    cursor: Option<String>,
+    compact: Option<bool>,
+) -> Result<ApiResponse<ListResponse<DnsRecord>>, DnsError> {
+    let mut response = state
         .dns_service
         .list_records(
             &account_id,
@@ -45,65 +61,170 @@ pub async fn list_dns_records(
             page_size,
             keyword,
             record_type,
+            cursor,
         )
         .await?;
+    response.items = response.items.into_iter().map(display_record).collect();
 
-    Ok(ApiResponse::success(response))
+    Ok(ApiResponse::success(ListResponse::new(
+        response,
+        compact.unwrap_or(false),
+    )?))
 }
 
-/// 创建 DNS 记录
+/// 按 ID 获取单条 DNS 记录
 #[tauri::command]
-pub async fn create_dns_record(
+pub async fn get_dns_record(
     state: State<'_, AppState>,
     account_id: String,
-    request: CreateDnsRecordRequest,
+    record_id: String,
+    domain_id: String,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
     let record = state
         .dns_service
-        .create_record(&account_id, request)
+        .get_record(&account_id, &record_id, &domain_id)
         .await?;
 
-    Ok(ApiResponse::success(record))
+    Ok(ApiResponse::success(display_record(record)))
 }
 
-/// 更新 DNS 记录
+/// 创建 DNS 记录（`idempotent` 为 true 时已存在相同记录则直接返回；
+/// 提供 `idempotency_key` 时，窗口期内的重试直接返回首次的结果）
+#[tauri::command]
+pub async fn create_dns_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: CreateDnsRecordRequest,
+    idempotent: Option<bool>,
+    idempotency_key: Option<String>,
+) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let dns_service = &state.dns_service;
+    let payload = (request.clone(), idempotent);
+    let record = dns_service
+        .run_idempotent(
+            &account_id,
+            "create_dns_record",
+            idempotency_key.as_deref(),
+            &payload,
+            || async {
+                if idempotent.unwrap_or(false) {
+                    dns_service
+                        .create_record_idempotent(&account_id, request)
+                        .await
+                } else {
+                    dns_service.create_record(&account_id, request).await
+                }
+            },
+        )
+        .await?;
+
+    Ok(ApiResponse::success(display_record(record)))
+}
+
+/// 更新 DNS 记录（提供 `idempotency_key` 时，窗口期内的重试直接返回首次的结果）
 #[tauri::command]
 pub async fn update_dns_record(
     state: State<'_, AppState>,
     account_id: String,
     record_id: String,
     request: UpdateDnsRecordRequest,
+    idempotency_key: Option<String>,
+) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let dns_service = &state.dns_service;
+    let payload = (record_id.clone(), request.clone());
+    let record = dns_service
+        .run_idempotent(
+            &account_id,
+            "update_dns_record",
+            idempotency_key.as_deref(),
+            &payload,
+            || dns_service.update_record(&account_id, &record_id, request),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(display_record(record)))
+}
+
+/// 部分更新 DNS 记录（未提供的字段保留现有值）
+#[tauri::command]
+pub async fn patch_dns_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    record_id: String,
+    request: PatchDnsRecordRequest,
 ) -> Result<ApiResponse<DnsRecord>, DnsError> {
     let record = state
         .dns_service
-        .update_record(&account_id, &record_id, request)
+        .patch_record(&account_id, &record_id, request)
         .await?;
 
-    Ok(ApiResponse::success(record))
+    Ok(ApiResponse::success(display_record(record)))
 }
 
-/// 删除 DNS 记录
+/// 删除 DNS 记录（提供 `idempotency_key` 时，窗口期内的重试直接返回成功）
 #[tauri::command]
 pub async fn delete_dns_record(
     state: State<'_, AppState>,
     account_id: String,
     record_id: String,
     domain_id: String,
+    idempotency_key: Option<String>,
+) -> Result<ApiResponse<()>, DnsError> {
+    let dns_service = &state.dns_service;
+    let payload = (record_id.clone(), domain_id.clone());
+    dns_service
+        .run_idempotent(
+            &account_id,
+            "delete_dns_record",
+            idempotency_key.as_deref(),
+            &payload,
+            || dns_service.delete_record(&account_id, &record_id, &domain_id),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(()))
+}
+
+/// 删除整个 RRset（同名同类型的全部记录值）
+#[tauri::command]
+pub async fn delete_dns_rrset(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    name: String,
+    record_type: DnsRecordType,
 ) -> Result<ApiResponse<()>, DnsError> {
     state
         .dns_service
-        .delete_record(&account_id, &record_id, &domain_id)
+        .delete_rrset(&account_id, &domain_id, &name, &record_type)
         .await?;
 
     Ok(ApiResponse::success(()))
 }
 
-/// 批量删除 DNS 记录
+/// 批量创建 DNS 记录（部分失败不影响其他记录）
+#[tauri::command]
+pub async fn batch_create_dns_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: BatchCreateRequest,
+) -> Result<ApiResponse<BatchCreateResult>, DnsError> {
+    let mut result = state
+        .dns_service
+        .batch_create_records(&account_id, request)
+        .await?;
+    result.created = result.created.into_iter().map(display_record).collect();
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 批量删除 DNS 记录（`dry_run` 为 true 时只返回将被删除的记录）
 #[tauri::command]
 pub async fn batch_delete_dns_records(
     state: State<'_, AppState>,
     account_id: String,
     request: BatchDeleteRequest,
+    dry_run: Option<bool>,
 ) -> Result<ApiResponse<BatchDeleteResult>, DnsError> {
     // 转换请求类型
     let core_request = dns_orchestrator_core::types::BatchDeleteRequest {
@@ -113,8 +234,224 @@ pub async fn batch_delete_dns_records(
 
     let result = state
         .dns_service
-        .batch_delete_records(&account_id, core_request)
+        .batch_delete_records(&account_id, core_request, dry_run.unwrap_or(false))
         .await?;
 
     Ok(ApiResponse::success(convert_batch_delete_result(result)))
 }
+
+/// 跨域名搜索账号下的记录（会拉取全部域名的全部记录，开销较大）
+#[tauri::command]
+pub async fn search_records(
+    state: State<'_, AppState>,
+    account_id: String,
+    query: RecordSearchQuery,
+) -> Result<ApiResponse<Vec<RecordSearchMatch>>, DnsError> {
+    let matches = state
+        .dns_service
+        .search_records(&account_id, &query)
+        .await?;
+
+    Ok(ApiResponse::success(matches))
+}
+
+/// 批量替换记录值（未指定域名时遍历账号下全部域名，`dry_run` 为 true 时只返回将被更新的记录）
+#[tauri::command]
+pub async fn replace_record_value(
+    state: State<'_, AppState>,
+    account_id: String,
+    request: ReplaceRecordValueRequest,
+    dry_run: Option<bool>,
+) -> Result<ApiResponse<ReplaceRecordValueResult>, DnsError> {
+    let result = state
+        .dns_service
+        .replace_record_value(&account_id, request, dry_run.unwrap_or(false))
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 服务商自检：创建、读取、更新、删除临时 TXT 记录 `_dnso-test` 并返回各步骤耗时
+#[tauri::command]
+pub async fn provider_self_test(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<ProviderSelfTestReport>, DnsError> {
+    let report = state
+        .dns_service
+        .provider_self_test(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(report))
+}
+
+/// 账号概览：域名列表及每个域名的首页记录（单个域名失败记入 `partialErrors`）
+#[tauri::command]
+pub async fn get_account_overview(
+    state: State<'_, AppState>,
+    account_id: String,
+    page_size: Option<u32>,
+) -> Result<ApiResponse<AccountOverview>, DnsError> {
+    let overview = state
+        .dns_service
+        .get_account_overview(&account_id, page_size)
+        .await?;
+
+    Ok(ApiResponse::success(overview))
+}
+
+/// 导出域名下全部记录为 BIND 区域文件
+#[tauri::command]
+pub async fn export_zone_file(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<ZoneFileExport>, DnsError> {
+    let export = state
+        .dns_service
+        .export_zone_file(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(export))
+}
+
+/// 从 BIND 区域文件导入记录（`include_apex_ns` 默认 false，跳过顶点 NS；
+/// `dry_run` 为 true 时只校验并返回将被创建的记录）
+#[tauri::command]
+pub async fn import_zone_file(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+    content: String,
+    include_apex_ns: Option<bool>,
+    dry_run: Option<bool>,
+) -> Result<ApiResponse<ZoneFileImportResult>, DnsError> {
+    let result = state
+        .dns_service
+        .import_zone_file(
+            &account_id,
+            &domain_id,
+            &content,
+            include_apex_ns.unwrap_or(false),
+            dry_run.unwrap_or(false),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 统计域名下各记录类型的数量
+#[tauri::command]
+pub async fn zone_record_type_summary(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<ZoneRecordTypeSummary>, DnsError> {
+    let summary = state
+        .dns_service
+        .zone_record_type_summary(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(summary))
+}
+
+/// 获取域名的记录统计（总数、各类型数量、开启代理的记录数）
+#[tauri::command]
+pub async fn zone_stats(
+    state: State<'_, AppState>,
+    account_id: String,
+    domain_id: String,
+) -> Result<ApiResponse<ZoneStats>, DnsError> {
+    let zone_stats = state
+        .dns_service
+        .zone_stats(&account_id, &domain_id)
+        .await?;
+
+    Ok(ApiResponse::success(zone_stats))
+}
+
+/// 对比两个区域的记录（可跨账户、跨服务商，`ignore_ttl` 默认 false）
+#[tauri::command]
+pub async fn diff_zones(
+    state: State<'_, AppState>,
+    source_account_id: String,
+    source_domain_id: String,
+    target_account_id: String,
+    target_domain_id: String,
+    ignore_ttl: Option<bool>,
+) -> Result<ApiResponse<ZoneDiff>, DnsError> {
+    let diff = state
+        .dns_service
+        .diff_zones(
+            &source_account_id,
+            &source_domain_id,
+            &target_account_id,
+            &target_domain_id,
+            ignore_ttl.unwrap_or(false),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(diff))
+}
+
+/// 将源区域的记录复制到目标区域（可跨账户、跨服务商），未传 `options` 时使用默认选项
+#[tauri::command]
+pub async fn clone_zone(
+    state: State<'_, AppState>,
+    source_account_id: String,
+    source_domain_id: String,
+    target_account_id: String,
+    target_domain_id: String,
+    options: Option<CloneZoneOptions>,
+) -> Result<ApiResponse<CloneZoneResult>, DnsError> {
+    let result = state
+        .dns_service
+        .clone_zone(
+            &source_account_id,
+            &source_domain_id,
+            &target_account_id,
+            &target_domain_id,
+            &options.unwrap_or_default(),
+        )
+        .await?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 列出账户回收站中的已删除记录（未启用回收站时为空）
+#[tauri::command]
+pub async fn list_trash(
+    state: State<'_, AppState>,
+    account_id: String,
+) -> Result<ApiResponse<Vec<TrashedRecord>>, DnsError> {
+    let entries = state.dns_service.list_trash(&account_id).await?;
+
+    Ok(ApiResponse::success(entries))
+}
+
+/// 从回收站恢复记录（重新创建，记录 ID 会变化）
+#[tauri::command]
+pub async fn restore_record(
+    state: State<'_, AppState>,
+    account_id: String,
+    trash_id: String,
+) -> Result<ApiResponse<DnsRecord>, DnsError> {
+    let record = state
+        .dns_service
+        .restore_record(&account_id, &trash_id)
+        .await?;
+
+    Ok(ApiResponse::success(display_record(record)))
+}
+
+/// 清除回收站中超过保留天数的记录（默认 30 天），返回清除数量
+#[tauri::command]
+pub async fn purge_trash(
+    state: State<'_, AppState>,
+    retention_days: Option<u32>,
+) -> Result<ApiResponse<usize>, DnsError> {
+    let purged = state.dns_service.purge_trash(retention_days).await?;
+
+    Ok(ApiResponse::success(purged))
+}
@@ -1,32 +1,48 @@
+use dns_orchestrator_core::utils::idn;
 use tauri::State;
 
 use crate::error::DnsError;
-use crate::types::{ApiResponse, Domain, PaginatedResponse};
+use crate::types::{ApiResponse, Domain, ListResponse, PaginatedResponse};
 use crate::AppState;
 
 // 从 core 类型转换到本地类型的辅助函数
 fn convert_domain(core_domain: dns_orchestrator_core::types::AppDomain) -> Domain {
     Domain {
         id: core_domain.id,
-        name: core_domain.name,
+        // 国际化域名以 Unicode 展示
+        name: idn::to_unicode(&core_domain.name),
         account_id: core_domain.account_id,
         provider: core_domain.provider,
         status: core_domain.status,
         record_count: core_domain.record_count,
+        name_servers: core_domain.name_servers,
+        created_at: core_domain.created_at,
+        modified_at: core_domain.modified_at,
     }
 }
 
-/// 列出账号下的所有域名（分页）
+/// 列出账号下的所有域名（分页，`keyword` 按名称过滤，`compact` 为 true 时返回列式紧凑格式）
+///
+/// `include_record_count` 为 true 时为缺少记录数的域名逐个查询补全
 #[tauri::command]
 pub async fn list_domains(
     state: State<'_, AppState>,
     account_id: String,
     page: Option<u32>,
     page_size: Option<u32>,
-) -> Result<ApiResponse<PaginatedResponse<Domain>>, DnsError> {
+    keyword: Option<String>,
+    include_record_count: Option<bool>,
+    compact: Option<bool>,
+) -> Result<ApiResponse<ListResponse<Domain>>, DnsError> {
     let response = state
         .domain_service
-        .list_domains(&account_id, page, page_size)
+        .list_domains(
+            &account_id,
+            page,
+            page_size,
+            keyword,
+            include_record_count.unwrap_or(false),
+        )
         .await?;
 
     // 转换响应中的 Domain 类型
@@ -39,7 +55,10 @@ pub async fn list_domains(
         response.total_count,
     );
 
-    Ok(ApiResponse::success(result))
+    Ok(ApiResponse::success(ListResponse::new(
+        result,
+        compact.unwrap_or(false),
+    )?))
 }
 
 /// 获取域名详情
@@ -1,9 +1,12 @@
 use dns_orchestrator_core::services::ToolboxService;
+use tauri::State;
 
 use crate::types::{
-    ApiResponse, CertChainItem, DnsLookupRecord, DnsLookupResult, IpGeoInfo, IpLookupResult,
-    SslCertInfo, SslCheckResult, WhoisResult,
+    ApiResponse, BlacklistCheckResult, CertChainItem, DnsLookupRecord, DnsLookupResult,
+    DnsPropagationResult, DomainExpiryInfo, DomainOverview, IpGeoInfo, IpLookupResult,
+    MxCheckResult, PortCheckResult, SslCertInfo, SslCheckResult, SslExpiryInfo, WhoisResult,
 };
+use crate::AppState;
 
 // 类型转换辅助函数
 fn convert_whois_result(result: dns_orchestrator_core::types::WhoisResult) -> WhoisResult {
@@ -61,6 +64,7 @@ fn convert_ip_lookup_result(
                 org: r.org,
                 asn: r.asn,
                 as_name: r.as_name,
+                hostname: r.hostname,
             })
             .collect(),
     }
@@ -82,6 +86,9 @@ fn convert_ssl_check_result(
             days_remaining: info.days_remaining,
             is_expired: info.is_expired,
             is_valid: info.is_valid,
+            hostname_matches: info.hostname_matches,
+            chain_trusted: info.chain_trusted,
+            verification_error: info.verification_error,
             san: info.san,
             serial_number: info.serial_number,
             signature_algorithm: info.signature_algorithm,
@@ -123,10 +130,39 @@ pub async fn dns_lookup(
     Ok(ApiResponse::success(convert_dns_lookup_result(result)))
 }
 
+/// DNS 传播检查
+#[tauri::command]
+pub async fn dns_propagation_check(
+    domain: String,
+    record_type: String,
+) -> Result<ApiResponse<DnsPropagationResult>, String> {
+    let result = ToolboxService::dns_propagation_check(&domain, &record_type)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// 端口连通性检查
+#[tauri::command]
+pub async fn port_check(
+    host: String,
+    ports: Vec<u16>,
+) -> Result<ApiResponse<PortCheckResult>, String> {
+    let result = ToolboxService::port_check(&host, &ports)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
 /// IP/域名 地理位置查询
 #[tauri::command]
-pub async fn ip_lookup(query: String) -> Result<ApiResponse<IpLookupResult>, String> {
-    let result = ToolboxService::ip_lookup(&query)
+pub async fn ip_lookup(
+    state: State<'_, AppState>,
+    query: String,
+) -> Result<ApiResponse<IpLookupResult>, String> {
+    let result = ToolboxService::ip_lookup(&query, &state.ctx.provider_options.http)
         .await
         .map_err(|e| e.to_string())?;
 
@@ -145,3 +181,88 @@ pub async fn ssl_check(
 
     Ok(ApiResponse::success(convert_ssl_check_result(result)))
 }
+
+/// 批量检查域名到期时间
+///
+/// 传入 `account_id` 时会追加该账号下的全部域名
+#[tauri::command]
+pub async fn check_domain_expiry(
+    state: State<'_, AppState>,
+    domains: Option<Vec<String>>,
+    account_id: Option<String>,
+    warning_days: Option<u32>,
+) -> Result<ApiResponse<Vec<DomainExpiryInfo>>, String> {
+    let mut targets = domains.unwrap_or_default();
+    if let Some(account_id) = account_id {
+        let account_domains = state
+            .domain_service
+            .list_all_domains(&account_id)
+            .await
+            .map_err(|e| e.to_string())?;
+        targets.extend(account_domains.into_iter().map(|d| d.name));
+    }
+    targets.sort();
+    targets.dedup();
+
+    if targets.is_empty() {
+        return Err("请至少提供一个域名".to_string());
+    }
+
+    let results = ToolboxService::check_domain_expiry(&targets, warning_days).await;
+
+    Ok(ApiResponse::success(results))
+}
+
+/// 批量检查 SSL 证书到期时间
+#[tauri::command]
+pub async fn check_ssl_expiry(
+    targets: Vec<String>,
+    warning_days: Option<u32>,
+) -> Result<ApiResponse<Vec<SslExpiryInfo>>, String> {
+    if targets.is_empty() {
+        return Err("请至少提供一个检查目标".to_string());
+    }
+
+    let results = ToolboxService::check_ssl_expiry(&targets, warning_days).await;
+
+    Ok(ApiResponse::success(results))
+}
+
+/// 域名健康概览（WHOIS + NS 委派 + SOA + 常用记录 + SSL）
+#[tauri::command]
+pub async fn domain_overview(domain: String) -> Result<ApiResponse<DomainOverview>, String> {
+    let domain = domain.trim();
+    if domain.is_empty() {
+        return Err("域名不能为空".to_string());
+    }
+
+    Ok(ApiResponse::success(
+        ToolboxService::domain_overview(domain).await,
+    ))
+}
+
+/// MX 健康检查（邮件主机地址、PTR、FCrDNS，可选 25 端口连通性）
+#[tauri::command]
+pub async fn mx_check(
+    domain: String,
+    check_smtp: Option<bool>,
+) -> Result<ApiResponse<MxCheckResult>, String> {
+    let result = ToolboxService::mx_check(&domain, check_smtp)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
+
+/// DNSBL 黑名单检查（IP 或域名，`zones` 为空时使用内置黑名单列表）
+#[tauri::command]
+pub async fn blacklist_check(
+    target: String,
+    zones: Option<Vec<String>>,
+) -> Result<ApiResponse<BlacklistCheckResult>, String> {
+    let result = ToolboxService::blacklist_check(&target, zones)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(ApiResponse::success(result))
+}
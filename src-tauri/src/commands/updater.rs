@@ -2,16 +2,26 @@
 //!
 //! 仅在 Android 平台编译，提供应用内更新功能：
 //! 1. 检查更新 - 解析 latest.json
-//! 2. 下载 APK - 带进度回调
+//! 2. 下载 APK - 带进度回调，网络中断时通过 Range 请求断点续传
 //! 3. 安装 APK - 触发系统安装器
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::time::Duration;
 use tauri::Manager;
 
 const LATEST_JSON_URL: &str =
     "https://github.com/AptS-1547/dns-orchestrator/releases/latest/download/latest.json";
 
+/// APK 下载中断后的最大重试次数
+const MAX_DOWNLOAD_RETRIES: u32 = 5;
+
+/// 首次重试前的等待时间（之后每次翻倍）
+const RETRY_BASE_DELAY: Duration = Duration::from_secs(1);
+
+/// 重试等待时间上限
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(30);
+
 /// Android 更新信息
 #[derive(Debug, Clone, Serialize)]
 pub struct AndroidUpdate {
@@ -38,34 +48,108 @@ struct Platform {
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "event", content = "data")]
 pub enum DownloadProgress {
-    Started { content_length: u64 },
-    Progress { chunk_length: u64 },
+    Started {
+        content_length: u64,
+    },
+    Progress {
+        chunk_length: u64,
+    },
+    /// 下载中断，即将从已下载的位置续传（`attempt` 从 1 开始）
+    Retrying {
+        attempt: u32,
+    },
     Finished,
 }
 
-/// 比较版本号，返回 true 如果 remote > current
-fn is_newer_version(current: &str, remote: &str) -> bool {
-    let parse_version = |v: &str| -> Vec<u32> {
-        v.trim_start_matches('v')
-            .split('.')
-            .filter_map(|s| s.parse().ok())
-            .collect()
-    };
+/// 第 `attempt` 次重试前的等待时间（指数退避）
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .saturating_mul(1 << attempt.saturating_sub(1).min(16))
+        .min(RETRY_MAX_DELAY)
+}
 
-    let current_parts = parse_version(current);
-    let remote_parts = parse_version(remote);
+/// 语义化版本号（忽略构建元数据）
+#[derive(Debug, PartialEq, Eq)]
+struct Version {
+    core: [u64; 3],
+    /// 预发布标识，为空表示正式版
+    pre: Vec<PreIdentifier>,
+}
+
+/// 预发布标识段：数字段按数值比较且低于字母数字段
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PreIdentifier {
+    Numeric(u64),
+    AlphaNumeric(String),
+}
 
-    for i in 0..std::cmp::max(current_parts.len(), remote_parts.len()) {
-        let c = current_parts.get(i).unwrap_or(&0);
-        let r = remote_parts.get(i).unwrap_or(&0);
-        if r > c {
-            return true;
+impl Version {
+    /// 解析 `v1.2.3-rc.1+build5` 形式的版本号，缺失的次版本号和修订号视为 0
+    fn parse(v: &str) -> Option<Self> {
+        let v = v.trim().trim_start_matches('v');
+        let v = v.split_once('+').map_or(v, |(version, _build)| version);
+        let (core_part, pre_part) = match v.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (v, None),
+        };
+
+        let mut core = [0; 3];
+        let mut parts = core_part.split('.');
+        for slot in &mut core {
+            if let Some(part) = parts.next() {
+                *slot = part.parse().ok()?;
+            }
         }
-        if r < c {
-            return false;
+        if parts.next().is_some() {
+            return None;
         }
+
+        let pre = match pre_part {
+            Some(pre) => pre
+                .split('.')
+                .map(|id| match id.parse() {
+                    Ok(n) => Some(PreIdentifier::Numeric(n)),
+                    Err(_) if !id.is_empty() => Some(PreIdentifier::AlphaNumeric(id.to_string())),
+                    Err(_) => None,
+                })
+                .collect::<Option<Vec<_>>>()?,
+            None => Vec::new(),
+        };
+
+        Some(Self { core, pre })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        use std::cmp::Ordering;
+
+        self.core.cmp(&other.core).then_with(|| {
+            // 正式版高于同号的预发布版
+            match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// 比较版本号，返回 true 如果 remote > current（任一版本号无法解析时返回 false）
+fn is_newer_version(current: &str, remote: &str) -> bool {
+    if let (Some(current), Some(remote)) = (Version::parse(current), Version::parse(remote)) {
+        remote > current
+    } else {
+        log::warn!("Failed to compare versions: current={current}, remote={remote}");
+        false
     }
-    false
 }
 
 /// 检查 Android 更新
@@ -121,40 +205,118 @@ pub async fn check_android_update(
     }))
 }
 
+/// 单次下载尝试的错误
+enum AttemptError {
+    /// 网络中断等可续传的错误
+    Retryable(String),
+    /// HTTP 4xx、写文件失败等重试无意义的错误
+    Fatal(String),
+}
+
+/// 下载状态（跨重试保留）
+struct DownloadState {
+    file: std::fs::File,
+    /// 已写入文件的字节数
+    downloaded: u64,
+    /// 首次响应给出的文件总大小（0 表示未知）
+    content_length: Option<u64>,
+}
+
+/// 执行一次下载：`downloaded > 0` 时以 Range 请求续传并追加写入
+async fn download_attempt(
+    client: &reqwest::Client,
+    url: &str,
+    state: &mut DownloadState,
+    on_progress: &tauri::ipc::Channel<DownloadProgress>,
+) -> Result<(), AttemptError> {
+    use futures::StreamExt;
+    use reqwest::header::RANGE;
+    use reqwest::StatusCode;
+    use std::io::{Seek, Write};
+
+    let mut request = client.get(url);
+    if state.downloaded > 0 {
+        request = request.header(RANGE, format!("bytes={}-", state.downloaded));
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| AttemptError::Retryable(format!("Failed to download APK: {}", e)))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let message = format!("Failed to download APK: HTTP {}", status);
+        return Err(if status.is_server_error() {
+            AttemptError::Retryable(message)
+        } else {
+            AttemptError::Fatal(message)
+        });
+    }
+
+    if state.downloaded > 0 && status != StatusCode::PARTIAL_CONTENT {
+        // 服务器忽略了 Range，只能从头下载
+        log::warn!("Server ignored Range request, restarting APK download");
+        state
+            .file
+            .set_len(0)
+            .and_then(|()| state.file.rewind())
+            .map_err(|e| AttemptError::Fatal(format!("Failed to truncate APK file: {}", e)))?;
+        state.downloaded = 0;
+    }
+
+    if state.downloaded == 0 {
+        let content_length = response.content_length().unwrap_or(0);
+        state.content_length = Some(content_length);
+        // 发送开始事件
+        let _ = on_progress.send(DownloadProgress::Started { content_length });
+    }
+
+    // 流式下载
+    let mut stream = response.bytes_stream();
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| AttemptError::Retryable(format!("Download error: {}", e)))?;
+
+        state
+            .file
+            .write_all(&chunk)
+            .map_err(|e| AttemptError::Fatal(format!("Failed to write APK: {}", e)))?;
+        state.downloaded += chunk.len() as u64;
+
+        // 发送进度事件
+        let _ = on_progress.send(DownloadProgress::Progress {
+            chunk_length: chunk.len() as u64,
+        });
+    }
+
+    // 连接提前结束也按中断处理
+    match state.content_length {
+        Some(expected) if expected > 0 && state.downloaded < expected => {
+            Err(AttemptError::Retryable(format!(
+                "Download interrupted: {}/{} bytes",
+                state.downloaded, expected
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
 /// 下载 APK 文件到缓存目录
+///
+/// 网络中断时以 Range 请求从当前字节位置续传，最多重试 [`MAX_DOWNLOAD_RETRIES`] 次，
+/// 完成后校验文件大小与 `content_length` 一致
 #[tauri::command]
 pub async fn download_apk(
     app: tauri::AppHandle,
     url: String,
     on_progress: tauri::ipc::Channel<DownloadProgress>,
 ) -> Result<String, String> {
-    use futures::StreamExt;
-    use std::io::Write;
-
     let client = reqwest::Client::builder()
         .user_agent("DNS-Orchestrator-Updater")
         .build()
         .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
 
-    // 发起请求
-    let response = client
-        .get(&url)
-        .send()
-        .await
-        .map_err(|e| format!("Failed to download APK: {}", e))?;
-
-    if !response.status().is_success() {
-        return Err(format!(
-            "Failed to download APK: HTTP {}",
-            response.status()
-        ));
-    }
-
-    let content_length = response.content_length().unwrap_or(0);
-
-    // 发送开始事件
-    let _ = on_progress.send(DownloadProgress::Started { content_length });
-
     // 获取缓存目录
     let cache_dir = app
         .path()
@@ -167,22 +329,51 @@ pub async fn download_apk(
     let apk_path = cache_dir.join("update.apk");
 
     // 创建文件
-    let mut file = std::fs::File::create(&apk_path)
+    let file = std::fs::File::create(&apk_path)
         .map_err(|e| format!("Failed to create APK file: {}", e))?;
 
-    // 流式下载
-    let mut stream = response.bytes_stream();
-
-    while let Some(chunk) = stream.next().await {
-        let chunk = chunk.map_err(|e| format!("Download error: {}", e))?;
-
-        file.write_all(&chunk)
-            .map_err(|e| format!("Failed to write APK: {}", e))?;
+    let mut state = DownloadState {
+        file,
+        downloaded: 0,
+        content_length: None,
+    };
+    let mut attempt = 0;
+
+    loop {
+        match download_attempt(&client, &url, &mut state, &on_progress).await {
+            Ok(()) => break,
+            Err(AttemptError::Fatal(e)) => return Err(e),
+            Err(AttemptError::Retryable(e)) => {
+                attempt += 1;
+                if attempt > MAX_DOWNLOAD_RETRIES {
+                    return Err(e);
+                }
+                log::warn!(
+                    "APK download interrupted at {} bytes, retry {}/{}: {}",
+                    state.downloaded,
+                    attempt,
+                    MAX_DOWNLOAD_RETRIES,
+                    e
+                );
+                let _ = on_progress.send(DownloadProgress::Retrying { attempt });
+                tokio::time::sleep(retry_delay(attempt)).await;
+            }
+        }
+    }
 
-        // 发送进度事件
-        let _ = on_progress.send(DownloadProgress::Progress {
-            chunk_length: chunk.len() as u64,
-        });
+    // 校验文件大小
+    if let Some(expected) = state.content_length.filter(|len| *len > 0) {
+        let actual = state
+            .file
+            .metadata()
+            .map_err(|e| format!("Failed to read APK file: {}", e))?
+            .len();
+        if actual != expected {
+            return Err(format!(
+                "APK size mismatch: expected {} bytes, got {}",
+                expected, actual
+            ));
+        }
     }
 
     // 发送完成事件
@@ -216,5 +407,30 @@ mod tests {
         assert!(!is_newer_version("1.0.1", "1.0.0"));
         assert!(!is_newer_version("1.0.0", "1.0.0"));
         assert!(is_newer_version("1.0.7", "1.0.8"));
+
+        // 预发布版低于正式版
+        assert!(is_newer_version("1.2.0-rc.1", "1.2.0"));
+        assert!(!is_newer_version("1.2.0", "1.2.0-rc.1"));
+        assert!(is_newer_version("1.1.9", "1.2.0-beta.1"));
+        assert!(is_newer_version("1.2.0-alpha", "1.2.0-alpha.1"));
+        assert!(is_newer_version("1.2.0-alpha.1", "1.2.0-beta"));
+        assert!(is_newer_version("1.2.0-beta.2", "1.2.0-beta.11"));
+        assert!(is_newer_version("1.2.0-rc.1", "v1.2.0-rc.2"));
+
+        // 构建元数据不参与比较
+        assert!(!is_newer_version("1.2.0", "1.2.0+build5"));
+        assert!(!is_newer_version("1.2.0+build5", "1.2.0"));
+        assert!(is_newer_version("1.2.0+build9", "1.2.1+build1"));
+
+        // 无法解析时不提示更新
+        assert!(!is_newer_version("1.0.0", "latest"));
+    }
+
+    #[test]
+    fn test_retry_delay_backoff() {
+        assert_eq!(retry_delay(1), Duration::from_secs(1));
+        assert_eq!(retry_delay(2), Duration::from_secs(2));
+        assert_eq!(retry_delay(4), Duration::from_secs(8));
+        assert_eq!(retry_delay(10), RETRY_MAX_DELAY);
     }
 }
@@ -60,6 +60,14 @@ pub enum DnsError {
     #[error("Unsupported file version")]
     UnsupportedFileVersion,
 
+    /// 资源冲突（如同名记录已存在且值不同）
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// 服务处于维护模式（只读），拒绝写操作
+    #[error("Service is in read-only maintenance mode")]
+    ReadOnly,
+
     /// Provider 错误（从库转换）
     #[error("{0}")]
     Provider(#[from] ProviderError),
@@ -82,6 +90,8 @@ impl From<CoreError> for DnsError {
             CoreError::ImportExportError(s) => Self::ImportExportError(s),
             CoreError::NoAccountsSelected => Self::NoAccountsSelected,
             CoreError::UnsupportedFileVersion => Self::UnsupportedFileVersion,
+            CoreError::Conflict(s) => Self::Conflict(s),
+            CoreError::ReadOnly => Self::ReadOnly,
             CoreError::NetworkError(s) => Self::ApiError {
                 provider: "network".to_string(),
                 message: s,
@@ -12,18 +12,57 @@ use commands::{account, dns, domain, toolbox};
 use tauri::Manager;
 use tauri_plugin_log::{Target, TargetKind};
 
-use adapters::{TauriAccountRepository, TauriCredentialStore};
+use adapters::{
+    TauriAccountRepository, TauriCredentialStore, TauriIdempotencyRepository, TauriTrashRepository,
+};
 use dns_orchestrator_core::services::{
-    AccountBootstrapService, AccountLifecycleService, AccountMetadataService,
-    CredentialManagementService, DnsService, DomainService, ImportExportService,
-    ProviderMetadataService, ServiceContext,
+    AccountBootstrapService, AccountHealthService, AccountLifecycleService, AccountMetadataService,
+    CredentialManagementService, DnsService, DomainService, DomainsCache, ImportExportService,
+    MaintenanceMode, ProviderMetadataService, ServiceContext, WebhookConfig, WebhookNotifier,
+    DEFAULT_HEALTH_CHECK_INTERVAL,
 };
-use dns_orchestrator_core::traits::InMemoryProviderRegistry;
+use dns_orchestrator_core::traits::{ConcurrencyLimit, InMemoryProviderRegistry};
+use dns_orchestrator_provider::{ProviderOptions, ProxyConfig};
+
+/// 设置为 `1` 时启动恢复账户后自动清理孤立凭证
+const CLEANUP_CREDENTIALS_ENV: &str = "DNS_ORCHESTRATOR_CLEANUP_ORPHANED_CREDENTIALS";
+
+/// 设置为 `1` 时要求账户名称唯一（创建、重命名、导入时检查）
+const UNIQUE_ACCOUNT_NAMES_ENV: &str = "DNS_ORCHESTRATOR_UNIQUE_ACCOUNT_NAMES";
+
+/// 设置为 `1` 时以维护模式（只读）启动
+const READ_ONLY_ENV: &str = "DNS_ORCHESTRATOR_READ_ONLY";
+
+/// 设置为 `1` 时启用记录回收站（删除前保存快照，可恢复）
+const RECORD_TRASH_ENV: &str = "DNS_ORCHESTRATOR_RECORD_TRASH";
+
+/// 单账户最大并发请求数（正整数，未设置时不限制）
+const MAX_IN_FLIGHT_ENV: &str = "DNS_ORCHESTRATOR_MAX_IN_FLIGHT_PER_ACCOUNT";
+
+/// 账户凭证后台检查间隔（秒），`0` 表示关闭，未设置时使用默认间隔
+const HEALTH_CHECK_INTERVAL_ENV: &str = "DNS_ORCHESTRATOR_HEALTH_CHECK_INTERVAL_SECS";
+
+/// 记录变更 Webhook 接收 URL（未设置时不推送事件）
+const WEBHOOK_URL_ENV: &str = "DNS_ORCHESTRATOR_WEBHOOK_URL";
+
+/// 记录变更 Webhook 签名密钥（设置了 URL 时必须非空）
+const WEBHOOK_SECRET_ENV: &str = "DNS_ORCHESTRATOR_WEBHOOK_SECRET";
+
+/// 访问服务商 API 使用的上游代理地址（如 `http://proxy.internal:3128`），地址无效时拒绝启动
+const PROXY_URL_ENV: &str = "DNS_ORCHESTRATOR_PROXY_URL";
+
+/// 上游代理认证用户名（可选）
+const PROXY_USERNAME_ENV: &str = "DNS_ORCHESTRATOR_PROXY_USERNAME";
+
+/// 上游代理认证密码（可选）
+const PROXY_PASSWORD_ENV: &str = "DNS_ORCHESTRATOR_PROXY_PASSWORD";
 
 /// 应用全局状态
 pub struct AppState {
     /// 服务上下文
     pub ctx: Arc<ServiceContext>,
+    /// 维护模式开关（所有服务共享）
+    pub maintenance: MaintenanceMode,
     /// 账户元数据服务
     pub account_metadata_service: Arc<AccountMetadataService>,
     /// 凭证管理服务
@@ -32,6 +71,8 @@ pub struct AppState {
     pub account_lifecycle_service: Arc<AccountLifecycleService>,
     /// 账户启动恢复服务
     pub account_bootstrap_service: Arc<AccountBootstrapService>,
+    /// 账户健康检查服务
+    pub account_health_service: Arc<AccountHealthService>,
     /// Provider 元数据服务
     pub provider_metadata_service: ProviderMetadataService,
     /// 导入导出服务
@@ -44,8 +85,24 @@ pub struct AppState {
     pub restore_completed: AtomicBool,
 }
 
+/// 从环境变量读取 Provider 创建选项
+fn provider_options_from_env() -> Result<ProviderOptions, String> {
+    let mut options = ProviderOptions::default();
+    if let Ok(url) = std::env::var(PROXY_URL_ENV) {
+        let mut proxy = ProxyConfig::new(url).map_err(|e| format!("{PROXY_URL_ENV}: {e}"))?;
+        if let Ok(username) = std::env::var(PROXY_USERNAME_ENV) {
+            proxy = proxy.with_auth(
+                username,
+                std::env::var(PROXY_PASSWORD_ENV).unwrap_or_default(),
+            );
+        }
+        options.http.proxy = Some(proxy);
+    }
+    Ok(options)
+}
+
 impl AppState {
-    pub fn new(app_handle: tauri::AppHandle) -> Self {
+    pub fn new(app_handle: tauri::AppHandle) -> Result<Self, String> {
         // 创建适配器（Android 版本需要 AppHandle）
         #[cfg(not(target_os = "android"))]
         let credential_store = Arc::new(TauriCredentialStore::new());
@@ -53,49 +110,104 @@ impl AppState {
         #[cfg(target_os = "android")]
         let credential_store = Arc::new(TauriCredentialStore::new(app_handle.clone()));
 
+        let trash_repository = std::env::var(RECORD_TRASH_ENV)
+            .is_ok_and(|v| v == "1")
+            .then(|| Arc::new(TauriTrashRepository::new(app_handle.clone())));
+        let idempotency_repository = Arc::new(TauriIdempotencyRepository::new(app_handle.clone()));
         let account_repository = Arc::new(TauriAccountRepository::new(app_handle));
-        let provider_registry = Arc::new(InMemoryProviderRegistry::new());
+        let mut provider_registry = InMemoryProviderRegistry::new();
+        if let Ok(v) = std::env::var(MAX_IN_FLIGHT_ENV) {
+            match v.parse::<usize>() {
+                Ok(0) => {}
+                Ok(max_in_flight) => {
+                    provider_registry = provider_registry
+                        .with_concurrency_limit(ConcurrencyLimit::new(max_in_flight));
+                }
+                Err(_) => {
+                    log::warn!("Invalid {MAX_IN_FLIGHT_ENV}={v:?}, concurrency limit disabled");
+                }
+            }
+        }
+        let provider_registry = Arc::new(provider_registry);
+
+        let maintenance =
+            MaintenanceMode::new(std::env::var(READ_ONLY_ENV).is_ok_and(|v| v == "1"));
+        let domains_cache = DomainsCache::default();
 
         // 创建服务上下文
-        let ctx = Arc::new(ServiceContext::new(
-            credential_store.clone(),
+        let mut ctx = ServiceContext::new(
+            credential_store,
             account_repository.clone(),
-            provider_registry.clone(),
-        ));
+            provider_registry,
+        )
+        .with_maintenance(maintenance.clone())
+        .with_domains_cache(domains_cache)
+        .with_idempotency_repository(idempotency_repository)
+        .with_provider_options(provider_options_from_env()?);
+        if let Some(trash_repository) = trash_repository {
+            ctx = ctx.with_trash_repository(trash_repository);
+        }
+        if let Ok(url) = std::env::var(WEBHOOK_URL_ENV) {
+            // 没有签名密钥时接收端无法校验来源，不推送事件
+            match std::env::var(WEBHOOK_SECRET_ENV) {
+                Ok(secret) if !secret.is_empty() => {
+                    let (notifier, dispatcher) =
+                        WebhookNotifier::new(WebhookConfig::new(url, secret));
+                    tauri::async_runtime::spawn(dispatcher.run());
+                    ctx = ctx.with_webhook(notifier);
+                }
+                _ => log::error!(
+                    "{WEBHOOK_URL_ENV} is set but {WEBHOOK_SECRET_ENV} is empty, webhooks disabled"
+                ),
+            }
+        }
+        let ctx = Arc::new(ctx);
+
+        let unique_account_names = std::env::var(UNIQUE_ACCOUNT_NAMES_ENV).is_ok_and(|v| v == "1");
 
         // 创建细粒度账户服务
-        let account_metadata_service = Arc::new(AccountMetadataService::new(account_repository));
-        let credential_management_service = Arc::new(CredentialManagementService::new(
-            credential_store,
-            provider_registry,
-        ));
-        let account_lifecycle_service = Arc::new(AccountLifecycleService::new(
-            Arc::clone(&account_metadata_service),
-            Arc::clone(&credential_management_service),
-        ));
-        let account_bootstrap_service = Arc::new(AccountBootstrapService::new(
-            Arc::clone(&account_metadata_service),
-            Arc::clone(&credential_management_service),
-        ));
+        let account_metadata_service = Arc::new(
+            AccountMetadataService::new(account_repository).with_unique_names(unique_account_names),
+        );
+        let credential_management_service =
+            Arc::new(CredentialManagementService::from_context(&ctx));
+        let account_lifecycle_service = Arc::new(
+            AccountLifecycleService::new(
+                Arc::clone(&account_metadata_service),
+                Arc::clone(&credential_management_service),
+            )
+            .with_maintenance(maintenance.clone()),
+        );
+        let account_bootstrap_service = Arc::new(
+            AccountBootstrapService::new(
+                Arc::clone(&account_metadata_service),
+                Arc::clone(&credential_management_service),
+            )
+            .with_maintenance(maintenance.clone()),
+        );
+        let account_health_service = Arc::new(AccountHealthService::new(Arc::clone(&ctx)));
         let provider_metadata_service = ProviderMetadataService::new();
 
         // 创建其他服务
-        let import_export_service = ImportExportService::new(Arc::clone(&ctx));
+        let import_export_service =
+            ImportExportService::new(Arc::clone(&ctx)).with_unique_names(unique_account_names);
         let domain_service = DomainService::new(Arc::clone(&ctx));
         let dns_service = DnsService::new(Arc::clone(&ctx));
 
-        Self {
+        Ok(Self {
             ctx,
+            maintenance,
             account_metadata_service,
             credential_management_service,
             account_lifecycle_service,
             account_bootstrap_service,
+            account_health_service,
             provider_metadata_service,
             import_export_service,
             domain_service,
             dns_service,
             restore_completed: AtomicBool::new(false),
-        }
+        })
     }
 }
 
@@ -150,7 +262,7 @@ pub fn run() {
 
     let builder = builder.setup(|app| {
         // 创建 AppState（需要 AppHandle）
-        let state = AppState::new(app.handle().clone());
+        let state = AppState::new(app.handle().clone())?;
         app.manage(state);
 
         // 后台恢复账户，不阻塞启动
@@ -166,13 +278,56 @@ pub fn run() {
                         restore_result.success_count,
                         restore_result.error_count
                     );
+
+                    // 仅在账户列表成功加载后清理，避免误删凭证
+                    if std::env::var(CLEANUP_CREDENTIALS_ENV).is_ok_and(|v| v == "1") {
+                        match state
+                            .account_bootstrap_service
+                            .cleanup_orphaned_credentials()
+                            .await
+                        {
+                            Ok(removed) => {
+                                log::info!("Removed {removed} orphaned credential entries");
+                            }
+                            Err(e) => log::warn!("Failed to clean up orphaned credentials: {e}"),
+                        }
+                    }
                 }
                 Err(e) => {
                     log::error!("Failed to restore accounts: {e}");
                 }
             }
 
+            // 清理超过保留期的回收站记录
+            match state.dns_service.purge_trash(None).await {
+                Ok(0) => {}
+                Ok(purged) => log::info!("Purged {purged} expired records from trash"),
+                Err(e) => log::warn!("Failed to purge trash: {e}"),
+            }
+
             state.restore_completed.store(true, Ordering::SeqCst);
+
+            // 定期检查凭证，失效时标记账户状态
+            // 仅显式设为 0 时关闭；无法解析时回退到默认间隔
+            let interval = match std::env::var(HEALTH_CHECK_INTERVAL_ENV) {
+                Ok(v) => {
+                    if let Ok(secs) = v.parse() {
+                        std::time::Duration::from_secs(secs)
+                    } else {
+                        log::warn!(
+                            "Invalid {HEALTH_CHECK_INTERVAL_ENV}={v:?}, using default interval"
+                        );
+                        DEFAULT_HEALTH_CHECK_INTERVAL
+                    }
+                }
+                Err(_) => DEFAULT_HEALTH_CHECK_INTERVAL,
+            };
+            if !interval.is_zero() {
+                let health_service = Arc::clone(&state.account_health_service);
+                tauri::async_runtime::spawn(async move {
+                    health_service.run_periodic(interval).await;
+                });
+            }
         });
 
         Ok(())
@@ -183,28 +338,59 @@ pub fn run() {
         // Account commands
         account::list_accounts,
         account::create_account,
+        account::test_credentials,
+        account::verify_all_accounts,
         account::update_account,
+        account::update_account_tags,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
+        account::provider_capability_matrix,
         account::export_accounts,
         account::preview_import,
         account::import_accounts,
         account::is_restore_completed,
+        account::cleanup_orphaned_credentials,
+        account::get_maintenance_mode,
+        account::set_maintenance_mode,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
         // DNS commands
         dns::list_dns_records,
+        dns::get_dns_record,
         dns::create_dns_record,
         dns::update_dns_record,
+        dns::patch_dns_record,
         dns::delete_dns_record,
+        dns::delete_dns_rrset,
+        dns::batch_create_dns_records,
         dns::batch_delete_dns_records,
+        dns::search_records,
+        dns::replace_record_value,
+        dns::provider_self_test,
+        dns::get_account_overview,
+        dns::export_zone_file,
+        dns::import_zone_file,
+        dns::zone_record_type_summary,
+        dns::zone_stats,
+        dns::diff_zones,
+        dns::clone_zone,
+        dns::list_trash,
+        dns::restore_record,
+        dns::purge_trash,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
+        toolbox::dns_propagation_check,
         toolbox::ip_lookup,
+        toolbox::port_check,
         toolbox::ssl_check,
+        toolbox::check_domain_expiry,
+        toolbox::check_ssl_expiry,
+        toolbox::domain_overview,
+        toolbox::mx_check,
+        toolbox::blacklist_check,
     ]);
 
     #[cfg(target_os = "android")]
@@ -212,34 +398,66 @@ pub fn run() {
         // Account commands
         account::list_accounts,
         account::create_account,
+        account::test_credentials,
+        account::verify_all_accounts,
         account::update_account,
+        account::update_account_tags,
         account::delete_account,
         account::batch_delete_accounts,
         account::list_providers,
+        account::provider_capability_matrix,
         account::export_accounts,
         account::preview_import,
         account::import_accounts,
         account::is_restore_completed,
+        account::cleanup_orphaned_credentials,
+        account::get_maintenance_mode,
+        account::set_maintenance_mode,
         // Domain commands
         domain::list_domains,
         domain::get_domain,
         // DNS commands
         dns::list_dns_records,
+        dns::get_dns_record,
         dns::create_dns_record,
         dns::update_dns_record,
+        dns::patch_dns_record,
         dns::delete_dns_record,
+        dns::delete_dns_rrset,
+        dns::batch_create_dns_records,
         dns::batch_delete_dns_records,
+        dns::search_records,
+        dns::replace_record_value,
+        dns::provider_self_test,
+        dns::get_account_overview,
+        dns::export_zone_file,
+        dns::import_zone_file,
+        dns::zone_record_type_summary,
+        dns::zone_stats,
+        dns::diff_zones,
+        dns::clone_zone,
+        dns::list_trash,
+        dns::restore_record,
+        dns::purge_trash,
         // Toolbox commands
         toolbox::whois_lookup,
         toolbox::dns_lookup,
+        toolbox::dns_propagation_check,
         toolbox::ip_lookup,
+        toolbox::port_check,
         toolbox::ssl_check,
+        toolbox::check_domain_expiry,
+        toolbox::check_ssl_expiry,
+        toolbox::domain_overview,
+        toolbox::mx_check,
+        toolbox::blacklist_check,
         // Android updater commands
         updater::check_android_update,
         updater::download_apk,
         updater::install_apk,
     ]);
 
+    #[allow(clippy::expect_used)]
     builder
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -19,6 +19,59 @@ pub use dns_orchestrator_provider::{
     UpdateDnsRecordRequest,
 };
 
+pub use dns_orchestrator_core::types::{
+    // 账号概览
+    AccountOverview,
+    // 批量创建记录
+    BatchCreateRequest,
+    BatchCreateResult,
+    // DNSBL 黑名单检查
+    BlacklistCheckResult,
+    // 克隆区域
+    CloneZoneOptions,
+    CloneZoneResult,
+    // 凭证连通性测试
+    CredentialTestResult,
+    // DNS 传播检查
+    DnsPropagationResult,
+    // 工具箱监控类型
+    DomainExpiryInfo,
+    DomainOverview,
+    // 导入格式
+    ImportFormat,
+    // 紧凑列表响应
+    ListResponse,
+    // MX 健康检查
+    MxCheckResult,
+    // 部分更新记录
+    PatchDnsRecordRequest,
+    // 端口检查
+    PortCheckResult,
+    // 服务商能力矩阵
+    ProviderCapabilityMatrix,
+    // 服务商自检
+    ProviderSelfTestReport,
+    // 跨域名记录搜索
+    RecordSearchMatch,
+    RecordSearchQuery,
+    // 批量替换记录值
+    ReplaceRecordValueRequest,
+    ReplaceRecordValueResult,
+    SslExpiryInfo,
+    // 回收站
+    TrashedRecord,
+    // 批量验证账号凭证
+    VerifyAllAccountsResult,
+    // 区域对比
+    ZoneDiff,
+    // BIND 区域文件导出
+    ZoneFileExport,
+    ZoneFileImportResult,
+    // 记录统计
+    ZoneRecordTypeSummary,
+    ZoneStats,
+};
+
 // ============ 应用层 Provider 相关类型 ============
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +94,8 @@ pub struct Account {
     pub status: Option<AccountStatus>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,6 +127,16 @@ pub struct Domain {
     pub status: DomainStatus,
     #[serde(rename = "recordCount", skip_serializing_if = "Option::is_none")]
     pub record_count: Option<u32>,
+    #[serde(rename = "nameServers", default, skip_serializing_if = "Vec::is_empty")]
+    pub name_servers: Vec<String>,
+    #[serde(rename = "createdAt", default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(
+        rename = "modifiedAt",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub modified_at: Option<DateTime<Utc>>,
 }
 
 // ============ API 响应类型 ============
@@ -146,6 +211,8 @@ pub struct IpGeoInfo {
     pub org: Option<String>,
     pub asn: Option<String>,
     pub as_name: Option<String>,
+    /// 反向解析主机名（PTR）
+    pub hostname: Option<String>,
 }
 
 /// IP 查询结果（支持域名解析多个 IP）
@@ -163,6 +230,7 @@ pub struct IpLookupResult {
 /// SSL 证书信息
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_excessive_bools)]
 pub struct SslCertInfo {
     pub domain: String,
     pub issuer: String,
@@ -172,6 +240,9 @@ pub struct SslCertInfo {
     pub days_remaining: i64,
     pub is_expired: bool,
     pub is_valid: bool,
+    pub hostname_matches: bool,
+    pub chain_trusted: bool,
+    pub verification_error: Option<String>,
     pub san: Vec<String>,
     pub serial_number: String,
     pub signature_algorithm: String,
@@ -220,6 +291,11 @@ pub struct BatchDeleteResult {
     pub success_count: usize,
     pub failed_count: usize,
     pub failures: Vec<BatchDeleteFailure>,
+    /// 是否为预演（未实际删除）
+    pub dry_run: bool,
+    /// 预演时将被删除的记录
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub targets: Vec<DnsRecord>,
 }
 
 /// 批量删除失败项
@@ -270,6 +346,8 @@ pub struct ImportAccountsRequest {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ImportPreview {
+    /// 识别出的文件格式
+    pub format: ImportFormat,
     /// 文件是否加密
     pub encrypted: bool,
     /// 账号数量
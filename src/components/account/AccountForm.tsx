@@ -130,10 +130,18 @@ export function AccountForm({ open, onOpenChange, account }: AccountFormProps) {
     }
   }
 
-  // 创建模式：所有必填字段都要填写
+  // 创建模式：无分组字段都要填写，有分组时至少完整填写一组
   // 编辑模式：至少修改了名称或凭证中的任意一个
+  const isFilled = (key: string) => Boolean(credentials[key]?.trim())
+  const requiredFields = providerInfo?.requiredFields ?? []
+  const groups = [...new Set(requiredFields.flatMap((field) => (field.group ? [field.group] : [])))]
   const isValidForCreate =
-    providerInfo?.requiredFields.every((field) => credentials[field.key]?.trim()) ?? false
+    !!providerInfo &&
+    requiredFields.filter((field) => !field.group).every((field) => isFilled(field.key)) &&
+    (groups.length === 0 ||
+      groups.some((group) =>
+        requiredFields.filter((field) => field.group === group).every((field) => isFilled(field.key))
+      ))
   const isValidForEdit = name !== account?.name || Object.values(credentials).some((v) => v.trim())
   const isValid = isEditing ? isValidForEdit : isValidForCreate
 
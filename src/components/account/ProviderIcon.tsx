@@ -11,6 +11,11 @@ const PROVIDER_ICONS: Record<string, React.ComponentType<{ className?: string }>
   aliyun: Server,
   dnspod: Server,
   huaweicloud: Server,
+  gclouddns: Cloud,
+  namecheap: Globe,
+  porkbun: Globe,
+  rfc2136: Server,
+  mock: Server,
 }
 
 export function ProviderIcon({ provider, className }: ProviderIconProps) {
@@ -25,6 +30,11 @@ export function getProviderName(provider: string): string {
     aliyun: "阿里云",
     dnspod: "DNSPod",
     huaweicloud: "华为云",
+    gclouddns: "Google Cloud DNS",
+    namecheap: "Namecheap",
+    porkbun: "Porkbun",
+    rfc2136: "RFC 2136",
+    mock: "Mock",
   }
   return PROVIDER_NAMES[provider] || provider
 }
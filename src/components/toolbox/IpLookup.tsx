@@ -106,6 +106,14 @@ export function IpLookup() {
               </CopyableText>
             </div>
           )}
+          {ipInfo.hostname && (
+            <div className="sm:col-span-2">
+              <div className="text-muted-foreground text-sm">{t("toolbox.ip.hostname")}:</div>
+              <CopyableText value={ipInfo.hostname} className="break-all font-mono text-sm">
+                {ipInfo.hostname}
+              </CopyableText>
+            </div>
+          )}
           {ipInfo.isp && (
             <div className="sm:col-span-2">
               <div className="text-muted-foreground text-sm">{t("toolbox.ip.isp")}:</div>
@@ -67,7 +67,13 @@ function getStatusInfo(result: SslCheckResult | null, t: (key: string) => string
       bgColor: "bg-destructive/10",
       borderColor: "border-destructive/30",
       icon: XCircle,
-      label: cert.isExpired ? t("toolbox.ssl.expired") : t("toolbox.ssl.invalid"),
+      label: cert.isExpired
+        ? t("toolbox.ssl.expired")
+        : !cert.chainTrusted
+          ? t("toolbox.ssl.untrusted")
+          : !cert.hostnameMatches
+            ? t("toolbox.ssl.hostnameMismatch")
+            : t("toolbox.ssl.invalid"),
     }
   }
 
@@ -210,6 +216,11 @@ export function SslCheck() {
                       : t("toolbox.ssl.daysRemaining", { count: cert.daysRemaining })}
                   </div>
                 )}
+                {cert?.verificationError && (
+                  <div className="mt-1 break-all text-destructive text-sm">
+                    {cert.verificationError}
+                  </div>
+                )}
                 {result.error && (
                   <div className="mt-1 text-destructive text-sm">{result.error}</div>
                 )}
@@ -156,6 +156,10 @@ export default {
       NS: "Name Server",
       SRV: "Service Record",
       CAA: "CA Authorization",
+      PTR: "Reverse Pointer",
+      HTTPS: "HTTPS Service Binding",
+      SVCB: "Service Binding",
+      ALIAS: "Apex Alias (CNAME Flattening)",
     },
     // Toast messages
     fetchFailed: "Failed to fetch DNS records",
@@ -341,6 +345,7 @@ export default {
       isp: "ISP",
       org: "Organization",
       asn: "ASN",
+      hostname: "Reverse DNS",
       resolvedFrom: "Resolved from",
     },
     // SSL check result
@@ -350,6 +355,8 @@ export default {
       valid: "Valid",
       invalid: "Invalid",
       expired: "Expired",
+      untrusted: "Untrusted Certificate",
+      hostnameMismatch: "Hostname Mismatch",
       expiringSoon: "Expiring Soon",
       daysRemaining: "{{count}} days remaining",
       expiredDays: "Expired {{count}} days ago",
@@ -414,6 +421,7 @@ export default {
       missing: "{{label}} is required",
       empty: "{{label}} cannot be empty",
       invalid_format: "{{label}} format invalid: {{reason}}",
+      conflicting: "Only one authentication method is allowed; these fields cannot be combined: {{fields}}",
     },
     // Provider errors (unified error types)
     provider: {
@@ -424,6 +432,7 @@ export default {
         record_exists: 'Record "{{record_name}}" already exists',
         record_not_found: "Record not found",
         invalid_parameter: 'Invalid parameter "{{param}}": {{detail}}',
+        invalid_record: "Invalid {{record_type}} record: {{detail}}",
         quota_exceeded: "Quota exceeded",
         domain_not_found: 'Domain "{{domain}}" not found',
         domain_locked: 'Domain "{{domain}}" is locked or disabled',
@@ -447,6 +456,24 @@ export default {
       huaweicloud: {
         invalid_credentials: "AK/SK is invalid",
       },
+      // Google Cloud DNS specific errors
+      gclouddns: {
+        invalid_credentials: "Service account key is invalid or lacks access",
+      },
+      // Namecheap specific errors
+      namecheap: {
+        invalid_credentials: "API key is invalid or the client IP is not whitelisted",
+      },
+      // Porkbun specific errors
+      porkbun: {
+        invalid_credentials: "API key or secret API key is invalid",
+        permission_denied: "API access is not enabled for this domain",
+      },
+      // RFC 2136 specific errors
+      rfc2136: {
+        invalid_credentials: "TSIG key name, algorithm or secret is invalid",
+        permission_denied: "The DNS server refused updates or zone transfers for this key",
+      },
     },
   },
 }
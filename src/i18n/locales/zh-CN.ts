@@ -151,6 +151,10 @@ export default {
       NS: "名称服务器",
       SRV: "服务记录",
       CAA: "CA 授权",
+      PTR: "反向解析",
+      HTTPS: "HTTPS 服务绑定",
+      SVCB: "服务绑定",
+      ALIAS: "根域名别名（CNAME 拉平）",
     },
     // Toast messages
     fetchFailed: "获取 DNS 记录失败",
@@ -335,6 +339,7 @@ export default {
       isp: "运营商",
       org: "组织",
       asn: "ASN",
+      hostname: "反向解析",
       resolvedFrom: "解析自",
     },
     // SSL check result
@@ -344,6 +349,8 @@ export default {
       valid: "证书有效",
       invalid: "证书无效",
       expired: "证书已过期",
+      untrusted: "证书不受信任",
+      hostnameMismatch: "域名不匹配",
       expiringSoon: "即将过期",
       daysRemaining: "剩余 {{count}} 天",
       expiredDays: "已过期 {{count}} 天",
@@ -407,6 +414,7 @@ export default {
       missing: "{{label}} 不能为空",
       empty: "{{label}} 不能为空",
       invalid_format: "{{label}} 格式无效: {{reason}}",
+      conflicting: "只能选择一种认证方式，以下字段不能同时填写: {{fields}}",
     },
     // Provider 错误（统一错误类型）
     provider: {
@@ -417,6 +425,7 @@ export default {
         record_exists: '记录 "{{record_name}}" 已存在',
         record_not_found: "记录不存在",
         invalid_parameter: '参数 "{{param}}" 无效: {{detail}}',
+        invalid_record: "{{record_type}} 记录无效: {{detail}}",
         quota_exceeded: "配额已用尽",
         domain_not_found: '域名 "{{domain}}" 不存在',
         domain_locked: '域名 "{{domain}}" 已被锁定或禁用',
@@ -440,6 +449,24 @@ export default {
       huaweicloud: {
         invalid_credentials: "AK/SK 无效",
       },
+      // Google Cloud DNS 特定错误
+      gclouddns: {
+        invalid_credentials: "服务账号密钥无效或无访问权限",
+      },
+      // Namecheap 特定错误
+      namecheap: {
+        invalid_credentials: "API Key 无效或客户端 IP 未加入白名单",
+      },
+      // Porkbun 特定错误
+      porkbun: {
+        invalid_credentials: "API Key 或 Secret API Key 无效",
+        permission_denied: "该域名未开启 API 访问",
+      },
+      // RFC 2136 特定错误
+      rfc2136: {
+        invalid_credentials: "TSIG 密钥名称、算法或密钥无效",
+        permission_denied: "DNS 服务器拒绝了该密钥的更新或区域传送",
+      },
     },
   },
 }
@@ -234,5 +234,7 @@ export function getFieldErrorMessage(details: CredentialValidationDetails): stri
       return i18n.t("errors.field.empty", { label: details.label })
     case "invalidFormat":
       return i18n.t("errors.field.invalid_format", { label: details.label, reason: details.reason })
+    case "conflictingFields":
+      return i18n.t("errors.field.conflicting", { fields: details.fields.join(", ") })
   }
 }
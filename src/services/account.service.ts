@@ -7,6 +7,7 @@ import type {
   ApiResponse,
   BatchDeleteResult,
   CreateAccountRequest,
+  CredentialTestResult,
   ExportAccountsRequest,
   ExportAccountsResponse,
   ImportAccountsRequest,
@@ -14,18 +15,31 @@ import type {
   ImportResult,
   ProviderInfo,
   UpdateAccountRequest,
+  VerifyAllAccountsResult,
 } from "@/types"
 import { transport } from "./transport"
 
 class AccountService {
-  listAccounts(): Promise<ApiResponse<Account[]>> {
-    return transport.invoke("list_accounts")
+  listAccounts(tag?: string): Promise<ApiResponse<Account[]>> {
+    return transport.invoke("list_accounts", { tag })
   }
 
   createAccount(request: CreateAccountRequest): Promise<ApiResponse<Account>> {
     return transport.invoke("create_account", { request })
   }
 
+  testCredentials(
+    providerType: string,
+    credentials: Record<string, string>,
+    checkReadAccess = true
+  ): Promise<ApiResponse<CredentialTestResult>> {
+    return transport.invoke("test_credentials", { providerType, credentials, checkReadAccess })
+  }
+
+  verifyAllAccounts(): Promise<ApiResponse<VerifyAllAccountsResult>> {
+    return transport.invoke("verify_all_accounts")
+  }
+
   deleteAccount(accountId: string): Promise<ApiResponse<void>> {
     return transport.invoke("delete_account", { accountId })
   }
@@ -34,6 +48,10 @@ class AccountService {
     return transport.invoke("update_account", { request })
   }
 
+  updateAccountTags(accountId: string, tags: string[]): Promise<ApiResponse<Account>> {
+    return transport.invoke("update_account_tags", { accountId, tags })
+  }
+
   batchDeleteAccounts(accountIds: string[]): Promise<ApiResponse<BatchDeleteResult>> {
     return transport.invoke("batch_delete_accounts", { accountIds })
   }
@@ -3,13 +3,29 @@
  */
 
 import type {
+  AccountOverview,
   ApiResponse,
+  BatchCreateRequest,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
+  CloneZoneOptions,
+  CloneZoneResult,
   CreateDnsRecordRequest,
   DnsRecord,
   PaginatedResponse,
+  PatchDnsRecordRequest,
+  ProviderSelfTestReport,
+  RecordSearchMatch,
+  RecordSearchQuery,
+  ReplaceRecordValueRequest,
+  ReplaceRecordValueResult,
+  TrashedRecord,
   UpdateDnsRecordRequest,
+  ZoneDiff,
+  ZoneFileExport,
+  ZoneFileImportResult,
+  ZoneStats,
 } from "@/types"
 import { transport } from "./transport"
 
@@ -20,6 +36,8 @@ export interface ListDnsRecordsParams {
   pageSize?: number
   keyword?: string | null
   recordType?: string | null
+  /** 上一页返回的 nextCursor（仅游标分页的服务商） */
+  cursor?: string | null
 }
 
 class DnsService {
@@ -27,30 +45,161 @@ class DnsService {
     return transport.invoke("list_dns_records", params)
   }
 
+  getRecord(accountId: string, recordId: string, domainId: string): Promise<ApiResponse<DnsRecord>> {
+    return transport.invoke("get_dns_record", { accountId, recordId, domainId })
+  }
+
+  /** 创建记录；传入 `idempotencyKey` 时，重试不会重复创建 */
   createRecord(
     accountId: string,
-    request: CreateDnsRecordRequest
+    request: CreateDnsRecordRequest,
+    idempotencyKey?: string
   ): Promise<ApiResponse<DnsRecord>> {
-    return transport.invoke("create_dns_record", { accountId, request })
+    return transport.invoke("create_dns_record", { accountId, request, idempotencyKey })
   }
 
   updateRecord(
     accountId: string,
     recordId: string,
-    request: UpdateDnsRecordRequest
+    request: UpdateDnsRecordRequest,
+    idempotencyKey?: string
+  ): Promise<ApiResponse<DnsRecord>> {
+    return transport.invoke("update_dns_record", { accountId, recordId, request, idempotencyKey })
+  }
+
+  /** 部分更新记录，未提供的字段保留现有值 */
+  patchRecord(
+    accountId: string,
+    recordId: string,
+    request: PatchDnsRecordRequest
   ): Promise<ApiResponse<DnsRecord>> {
-    return transport.invoke("update_dns_record", { accountId, recordId, request })
+    return transport.invoke("patch_dns_record", { accountId, recordId, request })
+  }
+
+  deleteRecord(
+    accountId: string,
+    recordId: string,
+    domainId: string,
+    idempotencyKey?: string
+  ): Promise<ApiResponse<void>> {
+    return transport.invoke("delete_dns_record", { accountId, recordId, domainId, idempotencyKey })
   }
 
-  deleteRecord(accountId: string, recordId: string, domainId: string): Promise<ApiResponse<void>> {
-    return transport.invoke("delete_dns_record", { accountId, recordId, domainId })
+  batchCreateRecords(
+    accountId: string,
+    request: BatchCreateRequest
+  ): Promise<ApiResponse<BatchCreateResult>> {
+    return transport.invoke("batch_create_dns_records", { accountId, request })
   }
 
   batchDeleteRecords(
     accountId: string,
-    request: BatchDeleteRequest
+    request: BatchDeleteRequest,
+    dryRun?: boolean
   ): Promise<ApiResponse<BatchDeleteResult>> {
-    return transport.invoke("batch_delete_dns_records", { accountId, request })
+    return transport.invoke("batch_delete_dns_records", { accountId, request, dryRun })
+  }
+
+  /** 导出域名记录为 BIND 区域文件 */
+  exportZoneFile(accountId: string, domainId: string): Promise<ApiResponse<ZoneFileExport>> {
+    return transport.invoke("export_zone_file", { accountId, domainId })
+  }
+
+  /** 从 BIND 区域文件导入记录 */
+  importZoneFile(
+    accountId: string,
+    domainId: string,
+    content: string,
+    includeApexNs?: boolean,
+    dryRun?: boolean
+  ): Promise<ApiResponse<ZoneFileImportResult>> {
+    return transport.invoke("import_zone_file", {
+      accountId,
+      domainId,
+      content,
+      includeApexNs,
+      dryRun,
+    })
+  }
+
+  /** 获取域名记录统计（按类型计数） */
+  getZoneStats(accountId: string, domainId: string): Promise<ApiResponse<ZoneStats>> {
+    return transport.invoke("zone_stats", { accountId, domainId })
+  }
+
+  /** 对比两个区域的记录（可跨账号，用于迁移后核对） */
+  diffZones(
+    sourceAccountId: string,
+    sourceDomainId: string,
+    targetAccountId: string,
+    targetDomainId: string,
+    ignoreTtl?: boolean
+  ): Promise<ApiResponse<ZoneDiff>> {
+    return transport.invoke("diff_zones", {
+      sourceAccountId,
+      sourceDomainId,
+      targetAccountId,
+      targetDomainId,
+      ignoreTtl,
+    })
+  }
+
+  /** 将源区域的记录复制到目标区域（可跨账户、跨服务商） */
+  cloneZone(
+    sourceAccountId: string,
+    sourceDomainId: string,
+    targetAccountId: string,
+    targetDomainId: string,
+    options?: CloneZoneOptions
+  ): Promise<ApiResponse<CloneZoneResult>> {
+    return transport.invoke("clone_zone", {
+      sourceAccountId,
+      sourceDomainId,
+      targetAccountId,
+      targetDomainId,
+      options,
+    })
+  }
+
+  /** 跨域名搜索记录（会拉取账号下全部域名的记录，较慢） */
+  searchRecords(
+    accountId: string,
+    query: RecordSearchQuery
+  ): Promise<ApiResponse<RecordSearchMatch[]>> {
+    return transport.invoke("search_records", { accountId, query })
+  }
+
+  /** 批量替换记录值（dryRun 时只返回将被更新的记录） */
+  replaceRecordValue(
+    accountId: string,
+    request: ReplaceRecordValueRequest,
+    dryRun?: boolean
+  ): Promise<ApiResponse<ReplaceRecordValueResult>> {
+    return transport.invoke("replace_record_value", { accountId, request, dryRun })
+  }
+
+  /** 服务商自检：创建、读取、更新、删除临时记录 _dnso-test */
+  providerSelfTest(
+    accountId: string,
+    domainId: string
+  ): Promise<ApiResponse<ProviderSelfTestReport>> {
+    return transport.invoke("provider_self_test", { accountId, domainId })
+  }
+
+  getAccountOverview(accountId: string, pageSize?: number): Promise<ApiResponse<AccountOverview>> {
+    return transport.invoke("get_account_overview", { accountId, pageSize })
+  }
+
+  listTrash(accountId: string): Promise<ApiResponse<TrashedRecord[]>> {
+    return transport.invoke("list_trash", { accountId })
+  }
+
+  restoreRecord(accountId: string, trashId: string): Promise<ApiResponse<DnsRecord>> {
+    return transport.invoke("restore_record", { accountId, trashId })
+  }
+
+  purgeTrash(retentionDays?: number): Promise<ApiResponse<number>> {
+    return transport.invoke("purge_trash", { retentionDays })
   }
 }
 
@@ -9,9 +9,17 @@ class DomainService {
   listDomains(
     accountId: string,
     page?: number,
-    pageSize?: number
+    pageSize?: number,
+    keyword?: string,
+    includeRecordCount?: boolean
   ): Promise<ApiResponse<PaginatedResponse<Domain>>> {
-    return transport.invoke("list_domains", { accountId, page, pageSize })
+    return transport.invoke("list_domains", {
+      accountId,
+      page,
+      pageSize,
+      keyword,
+      includeRecordCount,
+    })
   }
 
   getDomain(accountId: string, domainId: string): Promise<ApiResponse<Domain>> {
@@ -4,8 +4,12 @@
 
 import type {
   ApiResponse,
+  BlacklistCheckResult,
   DnsLookupResult,
+  DnsPropagationResult,
   IpLookupResult,
+  MxCheckResult,
+  PortCheckResult,
   SslCheckResult,
   WhoisResult,
 } from "@/types"
@@ -24,6 +28,17 @@ class ToolboxService {
     return transport.invoke("dns_lookup", { domain, recordType, nameserver })
   }
 
+  dnsPropagationCheck(
+    domain: string,
+    recordType: string
+  ): Promise<ApiResponse<DnsPropagationResult>> {
+    return transport.invoke("dns_propagation_check", { domain, recordType })
+  }
+
+  portCheck(host: string, ports: number[]): Promise<ApiResponse<PortCheckResult>> {
+    return transport.invoke("port_check", { host, ports })
+  }
+
   ipLookup(query: string): Promise<ApiResponse<IpLookupResult>> {
     return transport.invoke("ip_lookup", { query })
   }
@@ -31,6 +46,14 @@ class ToolboxService {
   sslCheck(domain: string, port?: number): Promise<ApiResponse<SslCheckResult>> {
     return transport.invoke("ssl_check", { domain, port })
   }
+
+  mxCheck(domain: string, checkSmtp?: boolean): Promise<ApiResponse<MxCheckResult>> {
+    return transport.invoke("mx_check", { domain, checkSmtp })
+  }
+
+  blacklistCheck(target: string, zones?: string[]): Promise<ApiResponse<BlacklistCheckResult>> {
+    return transport.invoke("blacklist_check", { target, zones })
+  }
 }
 
 export const toolboxService = new ToolboxService()
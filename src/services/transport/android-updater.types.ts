@@ -14,10 +14,12 @@ export interface AndroidUpdate {
 
 /** 下载进度事件 */
 export interface DownloadProgress {
-  event: "Started" | "Progress" | "Finished"
+  event: "Started" | "Progress" | "Retrying" | "Finished"
   data: {
     content_length?: number
     chunk_length?: number
+    /** 续传重试次数（从 1 开始） */
+    attempt?: number
   }
 }
 
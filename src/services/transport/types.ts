@@ -5,12 +5,20 @@
 
 import type {
   Account,
+  AccountOverview,
   ApiResponse,
+  BatchCreateRequest,
+  BatchCreateResult,
   BatchDeleteRequest,
   BatchDeleteResult,
+  CloneZoneOptions,
+  CloneZoneResult,
+  BlacklistCheckResult,
   CreateAccountRequest,
   CreateDnsRecordRequest,
+  CredentialTestResult,
   DnsLookupResult,
+  DnsPropagationResult,
   DnsRecord,
   Domain,
   ExportAccountsRequest,
@@ -19,11 +27,25 @@ import type {
   ImportPreview,
   ImportResult,
   IpLookupResult,
+  MxCheckResult,
   PaginatedResponse,
+  PatchDnsRecordRequest,
+  ProviderSelfTestReport,
+  PortCheckResult,
   ProviderInfo,
+  RecordSearchMatch,
+  RecordSearchQuery,
+  ReplaceRecordValueRequest,
+  ReplaceRecordValueResult,
   SslCheckResult,
+  TrashedRecord,
   UpdateDnsRecordRequest,
+  VerifyAllAccountsResult,
   WhoisResult,
+  ZoneDiff,
+  ZoneFileExport,
+  ZoneFileImportResult,
+  ZoneStats,
 } from "@/types"
 
 // ============ Command 类型映射 ============
@@ -32,13 +54,29 @@ import type {
 export interface CommandMap {
   // Account commands
   list_accounts: {
-    args: Record<string, never>
+    args: { tag?: string }
     result: ApiResponse<Account[]>
   }
   create_account: {
     args: { request: CreateAccountRequest }
     result: ApiResponse<Account>
   }
+  test_credentials: {
+    args: {
+      providerType: string
+      credentials: Record<string, string>
+      checkReadAccess?: boolean
+    }
+    result: ApiResponse<CredentialTestResult>
+  }
+  verify_all_accounts: {
+    args: Record<string, never>
+    result: ApiResponse<VerifyAllAccountsResult>
+  }
+  update_account_tags: {
+    args: { accountId: string; tags: string[] }
+    result: ApiResponse<Account>
+  }
   delete_account: {
     args: { accountId: string }
     result: ApiResponse<void>
@@ -70,7 +108,13 @@ export interface CommandMap {
 
   // Domain commands
   list_domains: {
-    args: { accountId: string; page?: number; pageSize?: number }
+    args: {
+      accountId: string
+      page?: number
+      pageSize?: number
+      keyword?: string
+      includeRecordCount?: boolean
+    }
     result: ApiResponse<PaginatedResponse<Domain>>
   }
   get_domain: {
@@ -87,25 +131,114 @@ export interface CommandMap {
       pageSize?: number
       keyword?: string | null
       recordType?: string | null
+      cursor?: string | null
     }
     result: ApiResponse<PaginatedResponse<DnsRecord>>
   }
+  get_dns_record: {
+    args: { accountId: string; recordId: string; domainId: string }
+    result: ApiResponse<DnsRecord>
+  }
   create_dns_record: {
-    args: { accountId: string; request: CreateDnsRecordRequest }
+    args: {
+      accountId: string
+      request: CreateDnsRecordRequest
+      idempotent?: boolean
+      idempotencyKey?: string
+    }
     result: ApiResponse<DnsRecord>
   }
   update_dns_record: {
-    args: { accountId: string; recordId: string; request: UpdateDnsRecordRequest }
+    args: {
+      accountId: string
+      recordId: string
+      request: UpdateDnsRecordRequest
+      idempotencyKey?: string
+    }
+    result: ApiResponse<DnsRecord>
+  }
+  patch_dns_record: {
+    args: { accountId: string; recordId: string; request: PatchDnsRecordRequest }
     result: ApiResponse<DnsRecord>
   }
   delete_dns_record: {
-    args: { accountId: string; recordId: string; domainId: string }
+    args: { accountId: string; recordId: string; domainId: string; idempotencyKey?: string }
     result: ApiResponse<void>
   }
+  batch_create_dns_records: {
+    args: { accountId: string; request: BatchCreateRequest }
+    result: ApiResponse<BatchCreateResult>
+  }
   batch_delete_dns_records: {
-    args: { accountId: string; request: BatchDeleteRequest }
+    args: { accountId: string; request: BatchDeleteRequest; dryRun?: boolean }
     result: ApiResponse<BatchDeleteResult>
   }
+  export_zone_file: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<ZoneFileExport>
+  }
+  import_zone_file: {
+    args: {
+      accountId: string
+      domainId: string
+      content: string
+      includeApexNs?: boolean
+      dryRun?: boolean
+    }
+    result: ApiResponse<ZoneFileImportResult>
+  }
+  zone_stats: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<ZoneStats>
+  }
+  diff_zones: {
+    args: {
+      sourceAccountId: string
+      sourceDomainId: string
+      targetAccountId: string
+      targetDomainId: string
+      ignoreTtl?: boolean
+    }
+    result: ApiResponse<ZoneDiff>
+  }
+  clone_zone: {
+    args: {
+      sourceAccountId: string
+      sourceDomainId: string
+      targetAccountId: string
+      targetDomainId: string
+      options?: CloneZoneOptions
+    }
+    result: ApiResponse<CloneZoneResult>
+  }
+  search_records: {
+    args: { accountId: string; query: RecordSearchQuery }
+    result: ApiResponse<RecordSearchMatch[]>
+  }
+  replace_record_value: {
+    args: { accountId: string; request: ReplaceRecordValueRequest; dryRun?: boolean }
+    result: ApiResponse<ReplaceRecordValueResult>
+  }
+  provider_self_test: {
+    args: { accountId: string; domainId: string }
+    result: ApiResponse<ProviderSelfTestReport>
+  }
+  get_account_overview: {
+    args: { accountId: string; pageSize?: number }
+    result: ApiResponse<AccountOverview>
+  }
+  list_trash: {
+    args: { accountId: string }
+    result: ApiResponse<TrashedRecord[]>
+  }
+  restore_record: {
+    args: { accountId: string; trashId: string }
+    result: ApiResponse<DnsRecord>
+  }
+  purge_trash: {
+    args: { retentionDays?: number }
+    result: ApiResponse<number>
+  }
 
   // Toolbox commands
   whois_lookup: {
@@ -116,6 +249,14 @@ export interface CommandMap {
     args: { domain: string; recordType: string; nameserver: string | null }
     result: ApiResponse<DnsLookupResult>
   }
+  dns_propagation_check: {
+    args: { domain: string; recordType: string }
+    result: ApiResponse<DnsPropagationResult>
+  }
+  port_check: {
+    args: { host: string; ports: number[] }
+    result: ApiResponse<PortCheckResult>
+  }
   ip_lookup: {
     args: { query: string }
     result: ApiResponse<IpLookupResult>
@@ -124,6 +265,14 @@ export interface CommandMap {
     args: { domain: string; port?: number }
     result: ApiResponse<SslCheckResult>
   }
+  mx_check: {
+    args: { domain: string; checkSmtp?: boolean }
+    result: ApiResponse<MxCheckResult>
+  }
+  blacklist_check: {
+    args: { target: string; zones?: string[] }
+    result: ApiResponse<BlacklistCheckResult>
+  }
 }
 
 // ============ 类型工具 ============
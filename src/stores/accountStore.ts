@@ -127,7 +127,8 @@ export const useAccountStore = create<AccountState>((set, get) => ({
       if (response.error?.code === "CredentialValidation" && response.error.details) {
         const details = response.error.details as CredentialValidationDetails
         const fieldError = getFieldErrorMessage(details)
-        set({ fieldErrors: { [details.field]: fieldError } })
+        const field = details.type === "conflictingFields" ? details.fields[0] : details.field
+        set({ fieldErrors: { [field]: fieldError } })
         return null
       }
       // 其他错误
@@ -187,7 +188,8 @@ export const useAccountStore = create<AccountState>((set, get) => ({
       if (response.error?.code === "CredentialValidation" && response.error.details) {
         const details = response.error.details as CredentialValidationDetails
         const fieldError = getFieldErrorMessage(details)
-        set({ fieldErrors: { [details.field]: fieldError } })
+        const field = details.type === "conflictingFields" ? details.fields[0] : details.field
+        set({ fieldErrors: { [field]: fieldError } })
         return null
       }
       // 其他错误
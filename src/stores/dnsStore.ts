@@ -44,6 +44,8 @@ interface DnsState {
   pageSize: number
   hasMore: boolean
   totalCount: number
+  /** 下一页游标（游标分页的服务商返回，加载更多时优先使用） */
+  nextCursor: string | null
   // 搜索状态
   keyword: string
   recordType: string
@@ -90,6 +92,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
   pageSize: PAGINATION.PAGE_SIZE,
   hasMore: false,
   totalCount: 0,
+  nextCursor: null,
   keyword: "",
   recordType: "",
   selectedRecordIds: new Set(),
@@ -124,6 +127,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
       currentDomainId: domainId,
       page: 1,
       hasMore: false,
+      nextCursor: null,
       keyword: searchKeyword,
       recordType: searchRecordType,
       // 只有切换 domain 时才清空 records，搜索时保持旧数据
@@ -149,6 +153,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           page: response.data.page,
           hasMore: response.data.hasMore,
           totalCount: response.data.totalCount,
+          nextCursor: response.data.nextCursor ?? null,
         })
       } else {
         const msg = getErrorMessage(response.error)
@@ -170,7 +175,16 @@ export const useDnsStore = create<DnsState>((set, get) => ({
   },
 
   fetchMoreRecords: async (accountId, domainId) => {
-    const { isLoadingMore, hasMore, page, currentDomainId, records, keyword, recordType } = get()
+    const {
+      isLoadingMore,
+      hasMore,
+      page,
+      nextCursor,
+      currentDomainId,
+      records,
+      keyword,
+      recordType,
+    } = get()
     if (isLoadingMore || !hasMore || currentDomainId !== domainId) {
       return
     }
@@ -187,6 +201,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
         pageSize,
         keyword: keyword || null,
         recordType: recordType || null,
+        cursor: nextCursor,
       })
       // 验证请求是否仍然有效
       if (get().currentDomainId !== domainId) {
@@ -197,6 +212,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           records: [...records, ...response.data.items],
           page: response.data.page,
           hasMore: response.data.hasMore,
+          nextCursor: response.data.nextCursor ?? null,
         })
       }
     } catch (err) {
@@ -249,6 +265,7 @@ export const useDnsStore = create<DnsState>((set, get) => ({
           page: response.data.page,
           hasMore: response.data.hasMore,
           totalCount: response.data.totalCount,
+          nextCursor: response.data.nextCursor ?? null,
         })
       } else {
         const msg = getErrorMessage(response.error)
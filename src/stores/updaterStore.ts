@@ -286,13 +286,17 @@ export const useUpdaterStore = create<UpdaterState>((set, get) => ({
 
           // 创建进度回调 Channel
           const onProgress = new Channel<{
-            event: "Started" | "Progress" | "Finished"
-            data: { content_length?: number; chunk_length?: number }
+            event: "Started" | "Progress" | "Retrying" | "Finished"
+            data: { content_length?: number; chunk_length?: number; attempt?: number }
           }>()
           onProgress.onmessage = (event) => {
             if (event.event === "Started") {
+              // 服务器不支持续传时会从头下载并再次发送 Started
+              downloaded = 0
               contentLength = event.data.content_length ?? 0
               logger.debug("Download started, size:", contentLength)
+            } else if (event.event === "Retrying") {
+              logger.debug("Download interrupted, resuming, attempt:", event.data.attempt)
             } else if (event.event === "Progress") {
               downloaded += event.data.chunk_length ?? 0
               if (contentLength > 0) {
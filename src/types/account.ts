@@ -10,6 +10,8 @@ export interface Account {
   updatedAt: string
   status?: AccountStatus
   error?: string
+  /** 分组标签 */
+  tags?: string[]
 }
 
 /** 创建账号请求 */
@@ -19,6 +21,37 @@ export interface CreateAccountRequest {
   credentials: Record<string, string>
 }
 
+/** 凭证连通性测试结果 */
+export interface CredentialTestResult {
+  valid: boolean
+  /** 账号下的域名数量（检查读取权限时返回） */
+  domainCount?: number
+  error?: string
+}
+
+/** 单个账号的凭证验证结果 */
+export interface AccountVerifyResult {
+  accountId: string
+  valid: boolean
+  error?: string
+}
+
+/** 批量验证所有账号凭证的汇总 */
+export interface VerifyAllAccountsResult {
+  total: number
+  active: number
+  /** 验证失败的账号数，等于 invalid + unreachable + unregistered */
+  errored: number
+  /** 凭证被服务商拒绝的账号数 */
+  invalid: number
+  /** 因网络等临时错误无法验证的账号数（状态不变） */
+  unreachable: number
+  /** 没有已注册 Provider 的账号数 */
+  unregistered: number
+  /** 按账号 ID 排序 */
+  results: AccountVerifyResult[]
+}
+
 /** 更新账号请求 */
 export interface UpdateAccountRequest {
   id: string
@@ -47,8 +80,12 @@ export interface ImportAccountsRequest {
   password?: string
 }
 
+/** 导入文件格式 */
+export type ImportFormat = "native" | "legacyWeb" | "genericJson"
+
 /** 导入预览 */
 export interface ImportPreview {
+  format: ImportFormat
   encrypted: boolean
   accountCount: number
   accounts?: ImportPreviewAccount[]
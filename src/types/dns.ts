@@ -1,8 +1,11 @@
+import type { Domain } from "./domain"
+import type { PaginatedResponse } from "./index"
+
 /** DNS 记录类型枚举 */
-export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA"
+export type DnsRecordType = "A" | "AAAA" | "CNAME" | "MX" | "TXT" | "NS" | "SRV" | "CAA" | "PTR" | "HTTPS" | "SVCB" | "ALIAS"
 
 /** 所有可用的记录类型列表 */
-export const RECORD_TYPES: DnsRecordType[] = ["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA"]
+export const RECORD_TYPES: DnsRecordType[] = ["A", "AAAA", "CNAME", "MX", "TXT", "NS", "SRV", "CAA", "PTR", "HTTPS", "SVCB", "ALIAS"]
 
 /** DNS 记录 */
 export interface DnsRecord {
@@ -14,6 +17,12 @@ export interface DnsRecord {
   ttl: number
   priority?: number
   proxied?: boolean
+  line?: string
+  weight?: number
+  /** 记录备注（Cloudflare） */
+  comment?: string
+  /** 记录标签（Cloudflare，`name:value` 形式） */
+  tags?: string[]
   createdAt?: string
   updatedAt?: string
 }
@@ -24,9 +33,14 @@ export interface CreateDnsRecordRequest {
   type: DnsRecordType
   name: string
   value: string
-  ttl: number
+  /** 省略时使用服务商默认 TTL */
+  ttl?: number
   priority?: number
   proxied?: boolean
+  line?: string
+  weight?: number
+  comment?: string
+  tags?: string[]
 }
 
 /** 更新 DNS 记录请求 */
@@ -38,6 +52,223 @@ export interface UpdateDnsRecordRequest {
   ttl: number
   priority?: number
   proxied?: boolean
+  line?: string
+  weight?: number
+  comment?: string
+  tags?: string[]
+  /** 乐观并发：读取记录时的 updatedAt，记录已被修改时拒绝更新 */
+  expectedVersion?: string
+  /** 乐观并发：读取记录时的值（服务商不返回修改时间时使用） */
+  expectedValue?: string
+}
+
+/** 部分更新 DNS 记录请求（未提供的字段保留现有值） */
+export interface PatchDnsRecordRequest {
+  domainId: string
+  type?: DnsRecordType
+  name?: string
+  value?: string
+  ttl?: number
+  /** 修改类型且未提供时不沿用原优先级 */
+  priority?: number | null
+  proxied?: boolean
+  /** 传 null 恢复默认线路 */
+  line?: string | null
+  /** 以下可选属性传 null 时清除 */
+  weight?: number | null
+  comment?: string | null
+  tags?: string[] | null
+  expectedVersion?: string
+  expectedValue?: string
+}
+
+/** 跨域名记录搜索条件 */
+export interface RecordSearchQuery {
+  valueContains?: string | null
+  nameContains?: string | null
+  recordType?: string | null
+}
+
+/** 跨域名搜索命中的记录 */
+export interface RecordSearchMatch extends DnsRecord {
+  domainName: string
+}
+
+/** 批量替换记录值请求 */
+export interface ReplaceRecordValueRequest {
+  /** 为空时替换账号下全部域名 */
+  domainId?: string | null
+  recordType: DnsRecordType
+  fromValue: string
+  toValue: string
+}
+
+/** 批量替换记录值失败项 */
+export interface ReplaceRecordValueFailure {
+  domainName: string
+  recordId: string
+  name: string
+  reason: string
+}
+
+/** 批量替换记录值结果 */
+export interface ReplaceRecordValueResult {
+  changedCount: number
+  failedCount: number
+  failures: ReplaceRecordValueFailure[]
+  dryRun: boolean
+  /** 预演时将被更新的记录 */
+  targets?: RecordSearchMatch[]
+}
+
+/** 服务商自检步骤 */
+export type SelfTestStepKind = "create" | "read" | "update" | "delete"
+
+/** 服务商自检单个步骤的结果 */
+export interface SelfTestStep {
+  step: SelfTestStepKind
+  passed: boolean
+  durationMs: number
+  error?: string
+}
+
+/** 服务商自检报告 */
+export interface ProviderSelfTestReport {
+  provider: string
+  domainId: string
+  recordName: string
+  passed: boolean
+  steps: SelfTestStep[]
+}
+
+/** 单个域名的首页记录 */
+export interface DomainRecordsPreview {
+  domain: Domain
+  records: PaginatedResponse<DnsRecord>
+}
+
+/** 账号概览中单个域名的拉取错误 */
+export interface OverviewPartialError {
+  domainId: string
+  domainName: string
+  message: string
+}
+
+/** 账号概览：域名列表及每个域名的首页记录 */
+export interface AccountOverview {
+  accountId: string
+  /** 拉取失败的域名不在其中，见 partialErrors */
+  domains: DomainRecordsPreview[]
+  partialErrors: OverviewPartialError[]
+}
+
+/** 回收站中的已删除记录快照 */
+export interface TrashedRecord {
+  id: string
+  accountId: string
+  /** 删除时的完整记录 */
+  record: DnsRecord
+  deletedAt: string
+}
+
+/** 导出的 BIND 区域文件 */
+export interface ZoneFileExport {
+  filename: string
+  content: string
+  recordCount: number
+}
+
+/** BIND 区域文件导入结果 */
+export interface ZoneFileImportResult {
+  successCount: number
+  failedCount: number
+  created: DnsRecord[]
+  errors: string[]
+  skipped: string[]
+  /** 是否为预演（未实际创建） */
+  dryRun: boolean
+  /** 预演时将被创建的记录 */
+  planned?: CreateDnsRecordRequest[]
+}
+
+/** 域名记录统计 */
+export interface ZoneStats {
+  total: number
+  /** 各记录类型数量，键为类型名（如 "A"、"CNAME"） */
+  byType: Record<string, number>
+  /** 开启代理的记录数（仅 Cloudflare） */
+  proxied: number
+}
+
+/** 两个区域之间的记录差异（按名称、类型、值和优先级匹配） */
+export interface ZoneDiff {
+  onlyInSource: DnsRecord[]
+  onlyInTarget: DnsRecord[]
+  /** 两侧都存在但 TTL 或代理状态不同的记录 */
+  differing: RecordDifference[]
+}
+
+/** 两侧匹配但属性不同的记录 */
+export interface RecordDifference {
+  source: DnsRecord
+  target: DnsRecord
+}
+
+/** 克隆区域选项（未提供的字段使用默认值） */
+export interface CloneZoneOptions {
+  /** 删除目标同名同类型下源区域没有的值并同步 TTL，默认 false */
+  overwriteExisting?: boolean
+  /** 保留源记录 TTL，默认 true */
+  preserveTtl?: boolean
+  /** 不保留 TTL 时统一使用的 TTL，默认 600 */
+  ttl?: number
+  /** 复制顶点 NS 记录，默认 false */
+  includeApexNs?: boolean
+}
+
+/** 克隆区域时单条记录的处理状态 */
+export type CloneRecordStatus = "created" | "updated" | "deleted" | "skipped" | "failed"
+
+/** 克隆区域时单条记录的处理结果 */
+export interface CloneRecordResult {
+  name: string
+  recordType: DnsRecordType
+  value: string
+  status: CloneRecordStatus
+  /** 跳过或失败的原因 */
+  detail?: string
+}
+
+/** 克隆区域结果 */
+export interface CloneZoneResult {
+  createdCount: number
+  updatedCount: number
+  deletedCount: number
+  skippedCount: number
+  failedCount: number
+  results: CloneRecordResult[]
+}
+
+/** 批量创建请求 */
+export interface BatchCreateRequest {
+  domainId: string
+  records: CreateDnsRecordRequest[]
+}
+
+/** 批量创建结果 */
+export interface BatchCreateResult {
+  successCount: number
+  failedCount: number
+  created: DnsRecord[]
+  failures: BatchCreateFailure[]
+}
+
+/** 批量创建失败项 */
+export interface BatchCreateFailure {
+  /** 请求中的下标 */
+  requestIndex: number
+  recordName: string
+  reason: string
 }
 
 /** 批量删除请求 */
@@ -51,6 +282,10 @@ export interface BatchDeleteResult {
   successCount: number
   failedCount: number
   failures: BatchDeleteFailure[]
+  /** 是否为预演（未实际删除） */
+  dryRun: boolean
+  /** 预演时将被删除的记录 */
+  targets?: DnsRecord[]
 }
 
 /** 批量删除失败项 */
@@ -84,4 +319,8 @@ export const RECORD_TYPE_INFO: Record<DnsRecordType, { descriptionKey: string; e
     NS: { descriptionKey: "dns.recordTypes.NS", example: "ns1.example.com" },
     SRV: { descriptionKey: "dns.recordTypes.SRV", example: "0 5 5060 sip.example.com" },
     CAA: { descriptionKey: "dns.recordTypes.CAA", example: '0 issue "letsencrypt.org"' },
+    PTR: { descriptionKey: "dns.recordTypes.PTR", example: "host.example.com" },
+    HTTPS: { descriptionKey: "dns.recordTypes.HTTPS", example: '1 . alpn="h3,h2"' },
+    SVCB: { descriptionKey: "dns.recordTypes.SVCB", example: "1 svc.example.com port=853" },
+    ALIAS: { descriptionKey: "dns.recordTypes.ALIAS", example: "lb.example.net" },
   }
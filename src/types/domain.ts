@@ -9,5 +9,8 @@ export interface Domain {
   provider: string
   status: DomainStatus
   recordCount?: number
+  /** 服务商分配的权威 DNS 服务器 */
+  nameServers?: string[]
   createdAt?: string
+  modifiedAt?: string
 }
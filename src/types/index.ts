@@ -26,6 +26,8 @@ export type DnsErrorCode =
   | "ImportExportError"
   | "NoAccountsSelected" // 导出时没有选中账号
   | "UnsupportedFileVersion" // 导入时版本不支持
+  | "Conflict" // 资源冲突（如同名记录值不同）
+  | "ReadOnly" // 维护模式（只读），拒绝写操作
   | "Provider" // ProviderError 变体
 
 /** 凭证验证错误详情 */
@@ -33,6 +35,7 @@ export type CredentialValidationDetails =
   | { type: "missingField"; provider: string; field: string; label: string }
   | { type: "emptyField"; provider: string; field: string; label: string }
   | { type: "invalidFormat"; provider: string; field: string; label: string; reason: string }
+  | { type: "conflictingFields"; provider: string; fields: string[] }
 
 /** Provider 错误码 */
 export type ProviderErrorCode =
@@ -45,6 +48,9 @@ export type ProviderErrorCode =
   | "DomainNotFound"
   | "DomainLocked"
   | "PermissionDenied"
+  | "Conflict"
+  | "InvalidRecord"
+  | "UnsupportedRecordType"
   | "ParseError"
   | "SerializationError"
   | "Unknown"
@@ -75,6 +81,9 @@ export type ProviderErrorDetails =
   | { code: "DomainNotFound"; provider: string; domain: string; raw_message?: string }
   | { code: "DomainLocked"; provider: string; domain: string; raw_message?: string }
   | { code: "PermissionDenied"; provider: string; raw_message?: string }
+  | { code: "Conflict"; provider: string; record_id: string; detail: string }
+  | { code: "InvalidRecord"; provider: string; record_type: string; detail: string }
+  | { code: "UnsupportedRecordType"; provider: string; record_type: string }
   | { code: "ParseError"; provider: string; detail: string }
   | { code: "SerializationError"; provider: string; detail: string }
   | {
@@ -107,4 +116,21 @@ export interface PaginatedResponse<T> {
   pageSize: number
   totalCount: number
   hasMore: boolean
+  /** 下一页游标（仅游标分页模式返回） */
+  nextCursor?: string
+}
+
+/**
+ * 紧凑（列式）分页响应（列表命令传 compact: true 时返回）
+ * rows 中每行按 columns 顺序给出字段值，缺失字段为 null
+ */
+export interface CompactPage {
+  columns: string[]
+  rows: unknown[][]
+  page: number
+  pageSize: number
+  totalCount: number
+  hasMore: boolean
+  /** 下一页游标（仅游标分页模式返回） */
+  nextCursor?: string
 }
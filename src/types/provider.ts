@@ -1,3 +1,5 @@
+import type { DnsRecordType } from "./dns"
+
 /** 凭证字段定义 */
 export interface ProviderCredentialField {
   key: string
@@ -5,12 +7,28 @@ export interface ProviderCredentialField {
   type: "text" | "password"
   placeholder?: string
   helpText?: string
+  /** 认证方式分组（不同分组互斥，只需填写其中一组） */
+  group?: string
 }
 
 /** 提供商支持的功能 */
 export interface ProviderFeatures {
   /** 是否支持代理功能 (如 Cloudflare 的 CDN 代理) */
   proxy: boolean
+  /** 是否支持解析线路（智能解析） */
+  line: boolean
+  /** 是否支持记录权重 */
+  weight: boolean
+  /** 是否支持记录备注 */
+  comment: boolean
+  /** 是否原生支持批量操作 */
+  batchNative: boolean
+  /** 是否支持 DNSSEC 管理 */
+  dnssec: boolean
+  /** 是否支持创建/删除 Zone */
+  zoneManagement: boolean
+  /** 是否允许在根域名上添加 CNAME */
+  apexCname: boolean
 }
 
 /** 提供商分页限制 */
@@ -19,14 +37,26 @@ export interface ProviderLimits {
   maxPageSizeDomains: number
   /** DNS 记录列表的最大分页大小 */
   maxPageSizeRecords: number
+  /** 最小 TTL（秒） */
+  minTtl: number
+  /** 最大 TTL（秒） */
+  maxTtl: number
+  /** 省略 TTL 时的默认值（秒），Cloudflare 为 1 表示"自动" */
+  defaultTtl: number
 }
 
+/** 列表分页模式 */
+export type PaginationMode = "page" | "cursor"
+
 /** 提供商信息 (从后端获取) */
 export interface ProviderInfo {
   id: string
   name: string
   description: string
   requiredFields: ProviderCredentialField[]
+  supportedRecordTypes: DnsRecordType[]
   features: ProviderFeatures
   limits: ProviderLimits
+  /** DNS 记录列表的分页模式 */
+  pagination: PaginationMode
 }
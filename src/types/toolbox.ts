@@ -27,6 +27,96 @@ export interface DnsLookupResult {
   records: DnsLookupRecord[]
 }
 
+/** 单个公共 DNS 的传播检查结果 */
+export interface PropagationServerResult {
+  /** DNS 服务商名称 */
+  name: string
+  /** DNS 服务器地址 */
+  nameserver: string
+  /** 所在地区 */
+  region: string
+  records: DnsLookupRecord[]
+  /** 响应耗时（毫秒） */
+  responseTimeMs: number
+  /** 查询失败或超时的错误信息 */
+  error?: string
+}
+
+/** DNS 传播检查结果 */
+export interface DnsPropagationResult {
+  domain: string
+  recordType: string
+  results: PropagationServerResult[]
+  /** 所有服务器返回的结果是否一致 */
+  consistent: boolean
+}
+
+/** 单个端口的连通性 */
+export interface PortStatus {
+  port: number
+  open: boolean
+  /** 建立连接耗时（毫秒，仅可连接时有值） */
+  latencyMs?: number
+  error?: string
+}
+
+/** 端口连通性检查结果 */
+export interface PortCheckResult {
+  host: string
+  /** 实际连接的 IP */
+  ip: string
+  results: PortStatus[]
+}
+
+/** MX 主机单个地址的检查结果 */
+export interface MxAddressCheck {
+  ip: string
+  /** 反向解析得到的主机名 */
+  ptr: string[]
+  /** 正反向解析是否一致 */
+  fcrdnsOk: boolean
+  /** 25 端口是否可连接（未检查时为空） */
+  smtpReachable?: boolean
+}
+
+/** 单个 MX 主机的检查结果 */
+export interface MxHostCheck {
+  exchange: string
+  preference: number
+  addresses: MxAddressCheck[]
+  /** 所有地址均通过 FCrDNS 检查 */
+  fcrdnsOk: boolean
+  /** 主机无法检查的原因（Null MX、无地址等） */
+  error?: string
+}
+
+/** MX 健康检查结果 */
+export interface MxCheckResult {
+  domain: string
+  /** 各 MX 主机（按优先级升序） */
+  hosts: MxHostCheck[]
+}
+
+/** 单个 IP 在单个黑名单中的检查结果 */
+export interface BlacklistListing {
+  ip: string
+  /** 黑名单区域（如 zen.spamhaus.org） */
+  zone: string
+  listed: boolean
+  /** 列入原因（黑名单 TXT 记录） */
+  txt?: string
+  /** 查询失败原因（超时、查询被拒绝等） */
+  error?: string
+}
+
+/** DNSBL 黑名单检查结果 */
+export interface BlacklistCheckResult {
+  target: string
+  /** 目标是否为域名（域名会先解析 A 记录） */
+  isDomain: boolean
+  results: BlacklistListing[]
+}
+
 /** IP 地理位置信息 */
 export interface IpGeoInfo {
   ip: string
@@ -43,6 +133,8 @@ export interface IpGeoInfo {
   org?: string
   asn?: string
   asName?: string
+  /** 反向解析主机名（PTR） */
+  hostname?: string
 }
 
 /** IP 查询结果（支持域名解析多个 IP） */
@@ -65,6 +157,12 @@ export interface SslCertInfo {
   daysRemaining: number
   isExpired: boolean
   isValid: boolean
+  /** 查询的域名是否与 CN/SAN 匹配 */
+  hostnameMatches: boolean
+  /** 证书链是否通过信任根校验 */
+  chainTrusted: boolean
+  /** 证书链校验失败原因 */
+  verificationError?: string
   san: string[]
   serialNumber: string
   signatureAlgorithm: string
@@ -113,6 +211,11 @@ export const DNS_RECORD_TYPES = [
   "SRV",
   "CAA",
   "PTR",
+  "SSHFP",
+  "TLSA",
+  "NAPTR",
+  "HTTPS",
+  "SVCB",
   "ALL",
 ] as const
 